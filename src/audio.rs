@@ -25,8 +25,15 @@ pub mod confirmation;
 pub mod context;
 pub mod cpal;
 pub mod crossfade;
+pub mod eq;
+pub mod flac;
 pub mod format;
 pub mod health;
+#[cfg(feature = "jack")]
+pub mod jack;
+pub mod limiter;
+pub mod ltc;
+pub mod meters;
 pub mod metronome;
 pub mod midi_tempo;
 pub mod mixer;
@@ -34,6 +41,7 @@ pub mod mock;
 pub mod monoclip;
 pub mod pilot;
 pub mod sample_source;
+pub mod stretch;
 pub mod tempo_guess;
 pub mod track_gains;
 
@@ -41,6 +49,7 @@ pub mod track_gains;
 pub use context::PlaybackContext;
 pub use cpal::AudioDeviceInfo;
 pub use format::{SampleFormat, TargetFormat};
+pub use stretch::PlaybackRate;
 
 /// Global source ID counter shared by song playback and sample triggers so IDs are unique.
 static SOURCE_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
@@ -264,6 +273,10 @@ pub fn can_open_device(name: &str) -> bool {
     if name.starts_with("mock") {
         return true;
     }
+    #[cfg(feature = "jack")]
+    if let Some(client_name) = jack::client_name(name) {
+        return jack::connect(client_name).is_ok();
+    }
     cpal::Device::is_findable(name)
 }
 
@@ -373,6 +386,16 @@ pub fn probe_device(config: config::Audio) -> ProbeOutcome {
 
 /// Gets a device with the given name.
 pub fn get_device(config: Option<config::Audio>) -> Result<Arc<dyn Device>, AudioError> {
+    get_device_for_mappings(config, &HashMap::new())
+}
+
+/// Gets a device with the given name, for a profile routing tracks to
+/// channels as `mappings` says. Only a JACK device uses them, to decide which
+/// output ports to register and what to call them.
+pub fn get_device_for_mappings(
+    config: Option<config::Audio>,
+    mappings: &HashMap<String, Vec<u16>>,
+) -> Result<Arc<dyn Device>, AudioError> {
     let config = match config {
         Some(config) => config,
         None => {
@@ -393,6 +416,17 @@ pub fn get_device(config: Option<config::Audio>) -> Result<Arc<dyn Device>, Audi
     if device.starts_with("mock") {
         return Ok(Arc::new(mock::Device::get(device)));
     };
+    #[cfg(feature = "jack")]
+    if let Some(client_name) = jack::client_name(device) {
+        let client_name = client_name.to_string();
+        return Ok(Arc::new(cpal::Device::get_jack(
+            config,
+            &client_name,
+            mappings,
+        )?));
+    }
+    #[cfg(not(feature = "jack"))]
+    let _ = mappings;
 
     // Passed through rather than restated: `Device::get` is what knows whether
     // the device was absent or present-and-refusing, and flattening that here
@@ -20,6 +20,7 @@ use std::sync::Arc;
 
 use crate::audio::format::TargetFormat;
 use crate::audio::sample_source::BufferFillPool;
+use crate::audio::stretch::PlaybackRate;
 use crate::config::ResamplerType;
 
 /// Context passed into playback and source-creation paths so they can
@@ -40,6 +41,8 @@ pub struct PlaybackContext {
     /// Player-wide metronome defaults (master volume and click sounds), used
     /// for whatever a song's metronome config doesn't override.
     pub metronome_defaults: Option<crate::config::MetronomeDefaults>,
+    /// Rehearsal speed and transposition applied as tracks are decoded.
+    pub rate: PlaybackRate,
 }
 
 impl PlaybackContext {
@@ -56,6 +59,7 @@ impl PlaybackContext {
             buffer_fill_pool,
             resampler_type,
             metronome_defaults: None,
+            rate: PlaybackRate::NORMAL,
         }
     }
 
@@ -67,6 +71,12 @@ impl PlaybackContext {
         self.metronome_defaults = defaults;
         self
     }
+
+    /// Sets the speed and transposition to play at.
+    pub fn with_rate(mut self, rate: PlaybackRate) -> Self {
+        self.rate = rate;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -80,6 +90,7 @@ mod tests {
         assert_eq!(ctx.target_format, fmt);
         assert_eq!(ctx.buffer_size, 1024);
         assert!(ctx.buffer_fill_pool.is_none());
+        assert!(ctx.rate.is_normal());
     }
 
     #[test]
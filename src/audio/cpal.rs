@@ -20,7 +20,7 @@ mod profiler;
 pub(crate) mod stream;
 
 /// A shared notify handle: a boolean flag protected by a mutex with a condvar for signaling.
-type CondvarNotify = Arc<(Mutex<bool>, Condvar)>;
+pub(crate) type CondvarNotify = Arc<(Mutex<bool>, Condvar)>;
 
 // Re-export public types so external callers see the same paths as before.
 pub use device::list_device_info;
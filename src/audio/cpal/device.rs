@@ -620,8 +620,32 @@ fn build_available_hint() -> String {
     }
 }
 
-/// A small wrapper around a cpal::Device. Used for storing some extra
-/// data that makes multitrack playing more convenient.
+/// Where a device's output stream comes from.
+enum Backend {
+    /// A device found through one of cpal's hosts.
+    Cpal {
+        /// The host ID of the device.
+        host_id: cpal::HostId,
+        /// The underlying cpal device.
+        device: cpal::Device,
+    },
+    /// A JACK client. See [`crate::audio::jack`].
+    #[cfg(feature = "jack")]
+    Jack,
+}
+
+impl Backend {
+    fn name(&self) -> &'static str {
+        match self {
+            Backend::Cpal { host_id, .. } => host_id.name(),
+            #[cfg(feature = "jack")]
+            Backend::Jack => "JACK",
+        }
+    }
+}
+
+/// A small wrapper around a cpal::Device, or a JACK client. Used for storing
+/// some extra data that makes multitrack playing more convenient.
 pub struct Device {
     /// The name of the device.
     name: String,
@@ -629,10 +653,8 @@ pub struct Device {
     playback_delay: Duration,
     /// The maximum number of channels the device supports.
     max_channels: u16,
-    /// The host ID of the device.
-    host_id: cpal::HostId,
-    /// The underlying cpal device.
-    device: cpal::Device,
+    /// What the output stream is built on.
+    backend: Backend,
     /// The target format for this device.
     target_format: TargetFormat,
     /// The output stream manager for continuous playback.
@@ -654,7 +676,7 @@ impl fmt::Display for Device {
             "{} (Channels={}) ({})",
             self.name,
             self.max_channels,
-            self.host_id.name()
+            self.backend.name()
         )
     }
 }
@@ -722,8 +744,7 @@ impl Device {
                 name: device.id()?.to_string(),
                 playback_delay: Duration::ZERO,
                 max_channels,
-                host_id,
-                device,
+                backend: Backend::Cpal { host_id, device },
                 target_format: default_format,
                 output_manager: temp_output_manager,
                 audio_config: config::Audio::new("default"), // Default config for listing
@@ -765,8 +786,10 @@ impl Device {
             name: resolved.name,
             playback_delay: Duration::ZERO,
             max_channels,
-            host_id: resolved.host_id,
-            device: resolved.device,
+            backend: Backend::Cpal {
+                host_id: resolved.host_id,
+                device: resolved.device,
+            },
             target_format: default_format,
             output_manager,
             audio_config: config::Audio::new("default"),
@@ -826,6 +849,11 @@ impl Device {
     /// Split from [`Device::get`] so every failure past the point where the
     /// device was located shares one "found it, couldn't open it" message.
     fn open(mut device: Device, config: config::Audio) -> Result<Device, Box<dyn Error>> {
+        let cpal_device = match &device.backend {
+            Backend::Cpal { device, .. } => device.clone(),
+            #[cfg(feature = "jack")]
+            Backend::Jack => return Err("a JACK device is opened with Device::get_jack".into()),
+        };
         device.playback_delay = config.playback_delay()?;
 
         device.target_format = TargetFormat::new(
@@ -840,7 +868,7 @@ impl Device {
 
         // Resolve stream buffer size for CPAL (default / min / fixed)
         let min_size =
-            min_supported_buffer_size(&device.device, &device.target_format, device.max_channels);
+            min_supported_buffer_size(&cpal_device, &device.target_format, device.max_channels);
         let output_buffer_size = resolve_buffer_size(
             config.stream_buffer_size(),
             config.buffer_size() as u32,
@@ -873,7 +901,7 @@ impl Device {
 
         // Start the output thread with resolved buffer size
         let factory = Box::new(CpalOutputStreamFactory::new(
-            device.device.clone(),
+            cpal_device,
             device.name.clone(),
             device.target_format.clone(),
             output_buffer_size,
@@ -886,6 +914,74 @@ impl Device {
 
         Ok(device)
     }
+
+    /// Connects to a JACK server as `client_name`, with an output port for
+    /// every channel `mappings` routes a track to.
+    ///
+    /// A server that isn't running is reported as not found rather than
+    /// unopenable: it may simply not have started yet, and hardware init
+    /// should keep waiting for it.
+    #[cfg(feature = "jack")]
+    pub(crate) fn get_jack(
+        config: config::Audio,
+        client_name: &str,
+        mappings: &HashMap<String, Vec<u16>>,
+    ) -> Result<Device, crate::audio::AudioError> {
+        let client = crate::audio::jack::connect(client_name).map_err(|e| {
+            crate::audio::AudioError::DeviceNotFound(format!(
+                "no JACK server to connect to as '{client_name}': {e}"
+            ))
+        })?;
+        let name = config.device().to_string();
+        Device::open_jack(client, config, mappings).map_err(|e| {
+            crate::audio::AudioError::DeviceUnopenable(format!(
+                "'{name}' was found but could not be opened: {e}"
+            ))
+        })
+    }
+
+    /// Starts the output stream on a connected JACK client.
+    ///
+    /// The server owns the clock, so its sample rate and period stand in for
+    /// the configured ones, and JACK ports are always 32-bit float.
+    #[cfg(feature = "jack")]
+    fn open_jack(
+        client: ::jack::Client,
+        config: config::Audio,
+        mappings: &HashMap<String, Vec<u16>>,
+    ) -> Result<Device, Box<dyn Error>> {
+        let playback_delay = config.playback_delay()?;
+        let sample_rate = client.sample_rate() as u32;
+        let buffer_size = client.buffer_size();
+        if sample_rate != config.sample_rate() {
+            info!(
+                configured = config.sample_rate(),
+                server = sample_rate,
+                "Using the JACK server's sample rate"
+            );
+        }
+        let name = format!("jack:{}", client.name());
+        let port_names = crate::audio::jack::port_names(mappings);
+        let max_channels = port_names.len() as u16;
+        let target_format = TargetFormat::new(sample_rate, SampleFormat::Float, 32)?;
+
+        let mut output_manager = OutputManager::new(max_channels, sample_rate)?;
+        output_manager.start_output_thread(Box::new(
+            crate::audio::jack::JackOutputStreamFactory::new(client, port_names),
+        ))?;
+
+        Ok(Device {
+            name,
+            playback_delay,
+            max_channels,
+            backend: Backend::Jack,
+            target_format,
+            output_manager: Arc::new(output_manager),
+            audio_config: config,
+            metronome_defaults: parking_lot::Mutex::new(None),
+            output_buffer_size: Some(buffer_size),
+        })
+    }
 }
 
 /// Constructs a `MixerActiveSource` and its finish flag.
@@ -920,6 +1016,7 @@ fn build_active_source(
     source: Box<dyn crate::audio::sample_source::ChannelMappedSampleSource + Send + Sync>,
     track_mappings: &HashMap<String, Vec<u16>>,
     cancel_handle: &CancelHandle,
+    clock: &crate::clock::PlaybackClock,
     gain_envelope: Option<Arc<crate::audio::crossfade::GainEnvelope>>,
 ) -> (MixerActiveSource, Arc<AtomicBool>) {
     let id = crate::audio::next_source_id();
@@ -941,6 +1038,7 @@ fn build_active_source(
         cancel_at_sample: Some(Arc::new(std::sync::atomic::AtomicU64::new(0))),
         gain: Default::default(),
         gain_envelope,
+        transport: Some(clock.clone()),
     };
     (active, flag)
 }
@@ -1047,7 +1145,8 @@ impl AudioDevice for Device {
             buffer_fill_pool,
             self.audio_config.resampler(),
         )
-        .with_metronome_defaults(self.metronome_defaults.lock().clone());
+        .with_metronome_defaults(self.metronome_defaults.lock().clone())
+        .with_rate(clock.rate());
 
         // Create channel mapped sources for each track in the song, starting from start_time.
         let channel_mapped_sources = song
@@ -1071,28 +1170,53 @@ impl AudioDevice for Device {
         // Create sources and track their finish flags (no locks needed for monitoring)
         let mut source_finish_flags = Vec::new();
 
-        let song_crossfade_envelope = if has_existing_sources {
-            let cs = crate::audio::crossfade::default_crossfade_samples(
-                self.output_manager.mixer.sample_rate(),
-            );
-            // Linear is fine for ≤5ms crossfades — perceptual difference
-            // from EqualPower is inaudible at this duration, and Linear
-            // is cheaper (no trig).
-            Some(Arc::new(crate::audio::crossfade::GainEnvelope::fade_in(
-                cs,
-                crate::audio::crossfade::CrossfadeCurve::Linear,
-            )))
-        } else {
-            None
-        };
+        // A clock started on a schedule is a hand-off from the previous song:
+        // the sources go live on the exact sample the clock does (less the
+        // usual playback delay), and fade in only if the songs crossfade.
+        // The outgoing song is cut or faded at that same sample.
+        let entry = clock.scheduled_entry();
+        let entry_start_sample = entry.map(|entry| {
+            entry.start_sample
+                + (self.playback_delay.as_secs_f64()
+                    * self.output_manager.mixer.sample_rate() as f64)
+                    .round() as u64
+        });
+
+        let song_crossfade_envelope =
+            if let (Some(entry), Some(start_sample)) = (entry, entry_start_sample) {
+                (entry.fade_in_samples > 0).then(|| {
+                    Arc::new(
+                        crate::audio::crossfade::GainEnvelope::fade_in(
+                            entry.fade_in_samples,
+                            crate::audio::crossfade::CrossfadeCurve::EqualPower,
+                        )
+                        .with_start_sample(start_sample),
+                    )
+                })
+            } else if has_existing_sources {
+                let cs = crate::audio::crossfade::default_crossfade_samples(
+                    self.output_manager.mixer.sample_rate(),
+                );
+                // Linear is fine for ≤5ms crossfades — perceptual difference
+                // from EqualPower is inaudible at this duration, and Linear
+                // is cheaper (no trig).
+                Some(Arc::new(crate::audio::crossfade::GainEnvelope::fade_in(
+                    cs,
+                    crate::audio::crossfade::CrossfadeCurve::Linear,
+                )))
+            } else {
+                None
+            };
 
         for source in channel_mapped_sources.into_iter() {
-            let (active_source, flag) = build_active_source(
+            let (mut active_source, flag) = build_active_source(
                 source,
                 mappings,
                 &cancel_handle,
+                &clock,
                 song_crossfade_envelope.clone(),
             );
+            active_source.start_at_sample = entry_start_sample;
             source_finish_flags.push(flag);
             self.output_manager
                 .add_source(active_source)
@@ -1118,9 +1242,21 @@ impl AudioDevice for Device {
         // inherit the same alignment, so all handoffs — first included — stay
         // seamless. (The resampler group delay is symmetric between the outgoing
         // and incoming sources and cancels, so it needs no correction.)
-        let audio_clock_skew_samples = (clock.elapsed().as_secs_f64()
+        //
+        // A scheduled start puts the sources live on a set sample instead, so
+        // the skew is however far that lands after the clock's own start.
+        // Real time, not song time: at a rehearsal speed the clock runs at
+        // a different pace from the mixer.
+        let mut audio_clock_skew_samples = (clock.elapsed().as_secs_f64() / clock.rate().speed
             * self.output_manager.mixer.sample_rate() as f64)
             .round() as u64;
+        if let (Some(entry), Some(start_sample)) = (entry, entry_start_sample) {
+            audio_clock_skew_samples =
+                audio_clock_skew_samples.max(start_sample - entry.start_sample);
+        }
+        // Recorded on the clock so a hand-off to the next song can find the
+        // sample this song's audio actually ends on.
+        clock.set_audio_skew(audio_clock_skew_samples);
         debug!(
             audio_clock_skew_samples,
             skew_us = clock.elapsed().as_micros() as u64,
@@ -1240,7 +1376,7 @@ impl AudioDevice for Device {
             // Apply any deferred loop_time_consumed bumps whose sample has
             // been reached by the audio callback.
             if !pending_consumption.is_empty() {
-                let now_sample = self.output_manager.mixer.current_sample();
+                let now_sample = clock.transport_sample(self.output_manager.mixer.current_sample());
                 pending_consumption.retain(|&(apply_at, amount)| {
                     if now_sample >= apply_at {
                         *loop_time_consumed.lock() += amount;
@@ -1374,15 +1510,23 @@ impl AudioDevice for Device {
                         // We use the live sample counter plus the remaining
                         // delta so the result is robust to any small skew
                         // between the playback clock and the mixer counter.
-                        let now_sample = self.output_manager.mixer.current_sample();
+                        //
+                        // Everything below is scheduled in transport samples
+                        // (the mixer counter less time spent paused), which
+                        // is what the mixer measures this song's sources in:
+                        // a pause between here and the boundary moves the
+                        // boundary with it.
+                        let now_sample =
+                            clock.transport_sample(self.output_manager.mixer.current_sample());
                         // Correct for the startup skew so the boundary tracks the
                         // audio content, not the clock epoch (see where
                         // `audio_clock_skew_samples` is captured). The shift is a
                         // constant, so inter-boundary spacing is unchanged.
+                        let speed = clock.rate().speed;
                         let loop_sample = crate::section_loop::loop_boundary_sample(
                             now_sample,
-                            elapsed,
-                            trigger_time,
+                            elapsed.div_f64(speed),
+                            trigger_time.div_f64(speed),
                             mixer_sample_rate,
                         )
                         .saturating_add(audio_clock_skew_samples);
@@ -1457,8 +1601,13 @@ impl AudioDevice for Device {
                                     crossfade_samples,
                                     crate::audio::crossfade::CrossfadeCurve::Linear,
                                 )));
-                            let (mut active_source, flag) =
-                                build_active_source(source, mappings, &cancel_handle, fade_in);
+                            let (mut active_source, flag) = build_active_source(
+                                source,
+                                mappings,
+                                &cancel_handle,
+                                &clock,
+                                fade_in,
+                            );
                             active_source.start_at_sample = Some(loop_sample);
                             source_finish_flags.push(flag);
                             if let Err(e) = self.output_manager.add_source(active_source) {
@@ -1533,8 +1682,13 @@ impl AudioDevice for Device {
                 crate::audio::crossfade::CrossfadeCurve::Linear,
             )));
             for source in new_sources {
-                let (active_source, flag) =
-                    build_active_source(source, mappings, &cancel_handle, fade_in_envelope.clone());
+                let (active_source, flag) = build_active_source(
+                    source,
+                    mappings,
+                    &cancel_handle,
+                    &clock,
+                    fade_in_envelope.clone(),
+                );
                 new_finish_flags.push(flag);
                 if let Err(e) = self.output_manager.add_source(active_source) {
                     error!(err = %e, "Failed to add loop source to mixer");
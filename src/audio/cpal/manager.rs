@@ -392,6 +392,7 @@ mod test {
             cancel_at_sample: None,
             gain: Default::default(),
             gain_envelope: None,
+            transport: None,
         }
     }
 
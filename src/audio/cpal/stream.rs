@@ -329,7 +329,7 @@ fn error_handler(
 }
 
 /// Drains pending sources from the channel and adds them to the mixer.
-pub(super) fn drain_pending_sources(
+pub(crate) fn drain_pending_sources(
     mixer: &AudioMixer,
     source_rx: &crossbeam_channel::Receiver<PreparedSource>,
 ) {
@@ -513,6 +513,7 @@ pub(super) mod test {
             cancel_at_sample: None,
             gain: Default::default(),
             gain_envelope: None,
+            transport: None,
         }
     }
 
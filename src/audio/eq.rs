@@ -0,0 +1,355 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+
+//! Per-output-track parametric EQ for the mixer.
+//!
+//! Each band is a state-variable filter built on trapezoidal integrators
+//! (Andrew Simper's "linear trapezoidal optimised SVF"). It computes the same
+//! responses as the direct-form biquad used for trigger inputs
+//! (`trigger/filter.rs`), but its coefficients can be moved while audio runs
+//! through it without clicks or blow-ups, so the mixer ramps them across a
+//! batch exactly like it ramps track gains.
+
+use std::f64::consts::PI;
+
+use crate::config::trackeq::{FREQ_RANGE, Q_RANGE};
+use crate::config::{EqBand, EqBandKind};
+
+/// Coefficients for one state-variable filter band.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SvfCoeffs {
+    a1: f32,
+    a2: f32,
+    a3: f32,
+    m0: f32,
+    m1: f32,
+    m2: f32,
+}
+
+impl SvfCoeffs {
+    /// Passes the signal through untouched.
+    pub const IDENTITY: SvfCoeffs = SvfCoeffs {
+        a1: 1.0,
+        a2: 0.0,
+        a3: 0.0,
+        m0: 1.0,
+        m1: 0.0,
+        m2: 0.0,
+    };
+
+    /// No change per frame.
+    const ZERO: SvfCoeffs = SvfCoeffs {
+        a1: 0.0,
+        a2: 0.0,
+        a3: 0.0,
+        m0: 0.0,
+        m1: 0.0,
+        m2: 0.0,
+    };
+
+    /// Designs the coefficients for a band. The frequency is kept below
+    /// Nyquist so a band set for 48k can't misbehave on a 44.1k device.
+    pub fn design(band: &EqBand, sample_rate: u32) -> Self {
+        let sample_rate = sample_rate.max(1) as f64;
+        let max_freq = (sample_rate * 0.49).min(*FREQ_RANGE.end() as f64);
+        let freq = (band.freq() as f64).clamp((*FREQ_RANGE.start() as f64).min(max_freq), max_freq);
+        let q = (band.q() as f64).clamp(*Q_RANGE.start() as f64, *Q_RANGE.end() as f64);
+        let g = (PI * freq / sample_rate).tan();
+        let k = 1.0 / q;
+        // Amplitude for shelves and peaks: sqrt of the linear gain.
+        let a = 10f64.powf(band.gain() as f64 / 40.0);
+
+        let (g, k, m0, m1, m2) = match band.kind() {
+            EqBandKind::HighPass => (g, k, 1.0, -k, -1.0),
+            EqBandKind::LowShelf => (g / a.sqrt(), k, 1.0, k * (a - 1.0), a * a - 1.0),
+            EqBandKind::HighShelf => (g * a.sqrt(), k, a * a, k * (1.0 - a) * a, 1.0 - a * a),
+            EqBandKind::Peak => {
+                let k = k / a;
+                (g, k, 1.0, k * (a * a - 1.0), 0.0)
+            }
+        };
+        let a1 = 1.0 / (1.0 + g * (g + k));
+        let a2 = g * a1;
+        let a3 = g * a2;
+        SvfCoeffs {
+            a1: a1 as f32,
+            a2: a2 as f32,
+            a3: a3 as f32,
+            m0: m0 as f32,
+            m1: m1 as f32,
+            m2: m2 as f32,
+        }
+    }
+
+    /// The per-frame step from `self` to `target` over `frames` frames.
+    fn step_to(&self, target: &SvfCoeffs, frames: usize) -> SvfCoeffs {
+        let n = frames.max(1) as f32;
+        SvfCoeffs {
+            a1: (target.a1 - self.a1) / n,
+            a2: (target.a2 - self.a2) / n,
+            a3: (target.a3 - self.a3) / n,
+            m0: (target.m0 - self.m0) / n,
+            m1: (target.m1 - self.m1) / n,
+            m2: (target.m2 - self.m2) / n,
+        }
+    }
+
+    /// `self + inc * t`.
+    fn advanced(&self, inc: &SvfCoeffs, t: f32) -> SvfCoeffs {
+        SvfCoeffs {
+            a1: self.a1 + inc.a1 * t,
+            a2: self.a2 + inc.a2 * t,
+            a3: self.a3 + inc.a3 * t,
+            m0: self.m0 + inc.m0 * t,
+            m1: self.m1 + inc.m1 * t,
+            m2: self.m2 + inc.m2 * t,
+        }
+    }
+}
+
+/// One band's integrator state and coefficient ramp.
+#[derive(Clone, Copy, Debug)]
+struct SvfBand {
+    ic1eq: f32,
+    ic2eq: f32,
+    cur: SvfCoeffs,
+    inc: SvfCoeffs,
+    target: SvfCoeffs,
+}
+
+impl SvfBand {
+    #[inline]
+    fn tick(&mut self, x: f32, c: &SvfCoeffs) -> f32 {
+        let v3 = x - self.ic2eq;
+        let v1 = c.a1 * self.ic1eq + c.a2 * v3;
+        let v2 = self.ic2eq + c.a2 * self.ic1eq + c.a3 * v3;
+        self.ic1eq = 2.0 * v1 - self.ic1eq;
+        self.ic2eq = 2.0 * v2 - self.ic2eq;
+        c.m0 * x + c.m1 * v1 + c.m2 * v2
+    }
+}
+
+/// A track's chain of bands as run over one source channel, owned by the
+/// audio callback. Built with the band count on the producer thread; the
+/// coefficients are designed on the first batch.
+pub struct EqChain {
+    bands: Vec<SvfBand>,
+    /// The parameter generation the targets were designed from; `None` until
+    /// the first batch.
+    generation: Option<u64>,
+    /// Frames left before the coefficients reach their targets.
+    ramp_frames: usize,
+}
+
+impl EqChain {
+    /// Creates a chain of `bands` pass-through bands.
+    pub fn new(bands: usize) -> Self {
+        let band = SvfBand {
+            ic1eq: 0.0,
+            ic2eq: 0.0,
+            cur: SvfCoeffs::IDENTITY,
+            inc: SvfCoeffs::ZERO,
+            target: SvfCoeffs::IDENTITY,
+        };
+        EqChain {
+            bands: vec![band; bands],
+            generation: None,
+            ramp_frames: 0,
+        }
+    }
+
+    /// Picks up parameters published since the last batch and ramps toward
+    /// them across the next `frames` frames. `band` returns the band at an
+    /// index and is only called when `generation` has moved. The first call
+    /// starts at the targets with no ramp, so a source begins filtered.
+    pub fn retarget(
+        &mut self,
+        generation: u64,
+        band: impl Fn(usize) -> EqBand,
+        sample_rate: u32,
+        frames: usize,
+    ) {
+        let first = self.generation.is_none();
+        if self.generation != Some(generation) {
+            self.generation = Some(generation);
+            for (i, state) in self.bands.iter_mut().enumerate() {
+                state.target = SvfCoeffs::design(&band(i), sample_rate);
+                if first {
+                    state.cur = state.target;
+                }
+            }
+            self.ramp_frames = if first { 0 } else { frames };
+        } else if self.ramp_frames > 0 {
+            // A short batch left a ramp unfinished; finish it over this one.
+            self.ramp_frames = frames;
+        }
+        for state in &mut self.bands {
+            state.inc = state.cur.step_to(&state.target, self.ramp_frames);
+        }
+    }
+
+    /// Filters one channel of an interleaved batch into `out` (one sample per
+    /// frame), advancing the coefficient ramps by the frames processed.
+    pub fn process(&mut self, batch: &[f32], stride: usize, channel: usize, out: &mut [f32]) {
+        let ramping = self.ramp_frames > 0;
+        for (frame, y) in out.iter_mut().enumerate() {
+            let mut x = batch[frame * stride + channel];
+            let t = frame as f32;
+            for band in &mut self.bands {
+                let c = if ramping {
+                    band.cur.advanced(&band.inc, t)
+                } else {
+                    band.cur
+                };
+                x = band.tick(x, &c);
+            }
+            *y = x;
+        }
+        let frames = out.len();
+        for band in &mut self.bands {
+            if frames >= self.ramp_frames {
+                band.cur = band.target;
+            } else {
+                band.cur = band.cur.advanced(&band.inc, frames as f32);
+            }
+            // Flush decaying state before it turns denormal.
+            if band.ic1eq.abs() < 1e-20 {
+                band.ic1eq = 0.0;
+            }
+            if band.ic2eq.abs() < 1e-20 {
+                band.ic2eq = 0.0;
+            }
+        }
+        self.ramp_frames = self.ramp_frames.saturating_sub(frames);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 48000;
+
+    /// Steady-state amplitude of a sine at `freq` through `chain`, from its
+    /// RMS over 100 ms (a whole number of periods for the test frequencies).
+    fn amplitude(chain: &mut EqChain, freq: f64) -> f32 {
+        let settle = RATE as usize / 4;
+        let measure = RATE as usize / 10;
+        let input: Vec<f32> = (0..settle + measure)
+            .map(|i| (2.0 * PI * freq * i as f64 / RATE as f64).sin() as f32)
+            .collect();
+        let mut out = vec![0.0; input.len()];
+        chain.process(&input, 1, 0, &mut out);
+        let power = out[settle..].iter().map(|s| s * s).sum::<f32>() / measure as f32;
+        (2.0 * power).sqrt()
+    }
+
+    fn chain(band: EqBand) -> EqChain {
+        let mut chain = EqChain::new(1);
+        chain.retarget(0, |_| band, RATE, 512);
+        chain
+    }
+
+    fn db(linear: f32) -> f32 {
+        20.0 * linear.log10()
+    }
+
+    #[test]
+    fn high_pass_cuts_lows_and_passes_highs() {
+        let band = EqBand::new(EqBandKind::HighPass, 200.0, 0.0, None);
+        assert!(amplitude(&mut chain(band), 20.0) < 0.02);
+        assert!((amplitude(&mut chain(band), 5000.0) - 1.0).abs() < 0.01);
+        // -3 dB at the corner for the Butterworth default Q.
+        let corner = db(amplitude(&mut chain(band), 200.0));
+        assert!((corner + 3.0).abs() < 0.2, "corner at {corner} dB");
+    }
+
+    #[test]
+    fn peak_boosts_its_centre_only() {
+        let band = EqBand::new(EqBandKind::Peak, 1000.0, 6.0, Some(2.0));
+        let centre = db(amplitude(&mut chain(band), 1000.0));
+        assert!((centre - 6.0).abs() < 0.1, "centre at {centre} dB");
+        let far = db(amplitude(&mut chain(band), 50.0));
+        assert!(far.abs() < 0.1, "far at {far} dB");
+    }
+
+    #[test]
+    fn shelves_reach_their_gain() {
+        let low = EqBand::new(EqBandKind::LowShelf, 200.0, -6.0, None);
+        assert!((db(amplitude(&mut chain(low), 30.0)) + 6.0).abs() < 0.2);
+        assert!(db(amplitude(&mut chain(low), 8000.0)).abs() < 0.1);
+
+        let high = EqBand::new(EqBandKind::HighShelf, 4000.0, 4.0, None);
+        assert!((db(amplitude(&mut chain(high), 15000.0)) - 4.0).abs() < 0.3);
+        assert!(db(amplitude(&mut chain(high), 100.0)).abs() < 0.1);
+    }
+
+    #[test]
+    fn zero_gain_peak_is_transparent() {
+        let band = EqBand::new(EqBandKind::Peak, 1000.0, 0.0, None);
+        let mut chain = chain(band);
+        let input: Vec<f32> = (0..256).map(|i| ((i * 7) % 13) as f32 / 13.0).collect();
+        let mut out = vec![0.0; input.len()];
+        chain.process(&input, 1, 0, &mut out);
+        for (a, b) in input.iter().zip(&out) {
+            assert!((a - b).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn retarget_ramps_without_jumps() {
+        let mut chain = chain(EqBand::new(EqBandKind::Peak, 1000.0, 0.0, None));
+        let batch = 256;
+        let input: Vec<f32> = (0..batch * 8)
+            .map(|i| (2.0 * PI * 1000.0 * i as f64 / RATE as f64).sin() as f32)
+            .collect();
+        let mut out = vec![0.0; input.len()];
+        chain.process(&input[..batch], 1, 0, &mut out[..batch]);
+        // A 12 dB step in gain lands across the next batch, not at once.
+        chain.retarget(
+            1,
+            |_| EqBand::new(EqBandKind::Peak, 1000.0, 12.0, Some(1.0)),
+            RATE,
+            batch,
+        );
+        for b in 1..8 {
+            let range = b * batch..(b + 1) * batch;
+            chain.retarget(1, |_| unreachable!(), RATE, batch);
+            chain.process(&input[range.clone()], 1, 0, &mut out[range]);
+        }
+        let max_step = out
+            .windows(2)
+            .map(|w| (w[1] - w[0]).abs())
+            .fold(0.0, f32::max);
+        // A 1 kHz sine at 4x gain moves at most ~0.53 per sample at 48k.
+        assert!(max_step < 0.6, "step of {max_step}");
+        let settled = out[batch * 6..].iter().fold(0.0f32, |m, &s| m.max(s.abs()));
+        assert!(
+            (db(settled) - 12.0).abs() < 0.3,
+            "settled at {} dB",
+            db(settled)
+        );
+    }
+
+    #[test]
+    fn frequency_clamped_below_nyquist() {
+        let band = EqBand::new(EqBandKind::HighShelf, 20000.0, 6.0, None);
+        let coeffs = SvfCoeffs::design(&band, 22050);
+        for value in [
+            coeffs.a1, coeffs.a2, coeffs.a3, coeffs.m0, coeffs.m1, coeffs.m2,
+        ] {
+            assert!(value.is_finite());
+        }
+    }
+}
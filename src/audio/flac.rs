@@ -0,0 +1,555 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+
+//! A streaming FLAC encoder.
+//!
+//! [`FlacWriter`] encodes integer PCM into fixed-size blocks as they fill,
+//! choosing per channel between a constant, a verbatim, and the best of the
+//! fixed linear predictors with a single Rice partition. That leaves some
+//! compression on the table against the reference encoder's LPC search, but
+//! it is cheap enough to keep up with a live input on a background thread,
+//! and live inputs -- long silences between hits, low-passed room noise --
+//! compress well under fixed prediction anyway.
+//!
+//! The stream's total sample count is only known at the end, so the writer
+//! needs `Seek` to go back and fill it into STREAMINFO. The MD5 signature is
+//! left unset, which the format allows.
+
+use std::io::{self, Seek, SeekFrom, Write};
+
+/// Frames per block. The reference encoder's default.
+const BLOCK_SIZE: usize = 4096;
+
+/// The largest Rice parameter the 4-bit parameter field can carry; 15 is the
+/// escape code.
+const MAX_RICE_PARAMETER: u32 = 14;
+
+/// Highest fixed predictor order the format defines.
+const MAX_FIXED_ORDER: usize = 4;
+
+/// Byte offset of the sample rate, channel, depth and total sample fields
+/// in the stream: the "fLaC" marker, the metadata block header, and the
+/// block and frame size fields of STREAMINFO come first.
+const STREAMINFO_TOTALS_OFFSET: u64 = 4 + 4 + 10;
+
+/// Writes FLAC to a seekable stream.
+pub struct FlacWriter<W: Write + Seek> {
+    writer: W,
+    /// Where the stream began, to come back to for STREAMINFO.
+    origin: u64,
+    channels: usize,
+    sample_rate: u32,
+    bits_per_sample: u16,
+    /// Interleaved samples of the block being filled.
+    pending: Vec<i32>,
+    frame_number: u32,
+    total_frames: u64,
+}
+
+impl<W: Write + Seek> FlacWriter<W> {
+    /// Starts a stream of 1 to 8 channels of 16 or 24 bit audio.
+    pub fn new(
+        mut writer: W,
+        channels: u16,
+        sample_rate: u32,
+        bits_per_sample: u16,
+    ) -> io::Result<Self> {
+        if !(1..=8).contains(&channels) {
+            return Err(invalid_input(format!(
+                "FLAC supports 1 to 8 channels, not {}",
+                channels
+            )));
+        }
+        if bits_per_sample != 16 && bits_per_sample != 24 {
+            return Err(invalid_input(format!(
+                "FLAC recording supports 16 or 24 bits per sample, not {}",
+                bits_per_sample
+            )));
+        }
+        if sample_rate == 0 || sample_rate >= 1 << 20 {
+            return Err(invalid_input(format!(
+                "FLAC can't carry a sample rate of {}",
+                sample_rate
+            )));
+        }
+
+        let origin = writer.stream_position()?;
+        let mut flac = FlacWriter {
+            writer,
+            origin,
+            channels: channels as usize,
+            sample_rate,
+            bits_per_sample,
+            pending: Vec::with_capacity(BLOCK_SIZE * channels as usize),
+            frame_number: 0,
+            total_frames: 0,
+        };
+        flac.write_header()?;
+        Ok(flac)
+    }
+
+    /// Appends interleaved samples. Must hold whole frames.
+    pub fn write_samples(&mut self, samples: &[i32]) -> io::Result<()> {
+        debug_assert_eq!(samples.len() % self.channels, 0);
+        let block_len = BLOCK_SIZE * self.channels;
+        let mut rest = samples;
+        while !rest.is_empty() {
+            let take = (block_len - self.pending.len()).min(rest.len());
+            self.pending.extend_from_slice(&rest[..take]);
+            rest = &rest[take..];
+            if self.pending.len() == block_len {
+                self.flush_block()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Encodes what is left, fills in the stream totals and returns the
+    /// underlying writer.
+    pub fn finalize(mut self) -> io::Result<W> {
+        if !self.pending.is_empty() {
+            self.flush_block()?;
+        }
+        let end = self.writer.stream_position()?;
+        self.writer
+            .seek(SeekFrom::Start(self.origin + STREAMINFO_TOTALS_OFFSET))?;
+        self.writer.write_all(&self.totals())?;
+        self.writer.seek(SeekFrom::Start(end))?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    /// The number of frames written so far, pending ones included.
+    pub fn frames(&self) -> u64 {
+        self.total_frames + (self.pending.len() / self.channels) as u64
+    }
+
+    /// Writes the stream marker and a STREAMINFO block whose totals are
+    /// filled in by `finalize`.
+    fn write_header(&mut self) -> io::Result<()> {
+        let mut header = Vec::with_capacity(42);
+        header.extend_from_slice(b"fLaC");
+        // Last metadata block, type 0 (STREAMINFO), 34 bytes long.
+        header.extend_from_slice(&[0x80, 0x00, 0x00, 34]);
+        header.extend_from_slice(&(BLOCK_SIZE as u16).to_be_bytes());
+        header.extend_from_slice(&(BLOCK_SIZE as u16).to_be_bytes());
+        // Minimum and maximum frame sizes: unknown.
+        header.extend_from_slice(&[0; 6]);
+        header.extend_from_slice(&self.totals());
+        // MD5 signature: unset.
+        header.extend_from_slice(&[0; 16]);
+        self.writer.write_all(&header)
+    }
+
+    /// The packed sample rate, channel count, depth and total frames.
+    fn totals(&self) -> [u8; 8] {
+        let packed = (self.sample_rate as u64) << 44
+            | ((self.channels as u64 - 1) << 41)
+            | ((self.bits_per_sample as u64 - 1) << 36)
+            | (self.total_frames & 0xF_FFFF_FFFF);
+        packed.to_be_bytes()
+    }
+
+    /// Encodes the pending samples as one frame.
+    fn flush_block(&mut self) -> io::Result<()> {
+        let block_size = self.pending.len() / self.channels;
+        let mut bits = BitWriter::with_capacity(self.pending.len() * 3);
+
+        // Frame header: sync code, fixed-blocksize stream.
+        bits.write(0xFFF8, 16);
+        let block_size_code = if block_size == BLOCK_SIZE {
+            0b1100
+        } else {
+            0b0111
+        };
+        bits.write(block_size_code, 4);
+        bits.write(sample_rate_code(self.sample_rate), 4);
+        // Independent channels.
+        bits.write(self.channels as u64 - 1, 4);
+        bits.write(
+            if self.bits_per_sample == 16 {
+                0b100
+            } else {
+                0b110
+            },
+            3,
+        );
+        bits.write(0, 1);
+        write_utf8_number(&mut bits, self.frame_number);
+        if block_size_code == 0b0111 {
+            bits.write(block_size as u64 - 1, 16);
+        }
+        let crc = crc8(bits.bytes());
+        bits.write(crc as u64, 8);
+
+        let mut channel = Vec::with_capacity(block_size);
+        for c in 0..self.channels {
+            channel.clear();
+            channel.extend(
+                self.pending
+                    .iter()
+                    .skip(c)
+                    .step_by(self.channels)
+                    .map(|&s| s as i64),
+            );
+            write_subframe(&mut bits, &channel, self.bits_per_sample as u32);
+        }
+
+        bits.align();
+        let crc = crc16(bits.bytes());
+        bits.write(crc as u64, 16);
+        self.writer.write_all(bits.bytes())?;
+
+        self.frame_number += 1;
+        self.total_frames += block_size as u64;
+        self.pending.clear();
+        Ok(())
+    }
+}
+
+fn invalid_input(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+/// The frame header code for a sample rate, or 0 to read it from
+/// STREAMINFO.
+fn sample_rate_code(sample_rate: u32) -> u64 {
+    match sample_rate {
+        88_200 => 0b1000,
+        44_100 => 0b1001,
+        48_000 => 0b1010,
+        96_000 => 0b1011,
+        _ => 0b0000,
+    }
+}
+
+/// Writes a frame number in FLAC's UTF-8-like variable length coding.
+fn write_utf8_number(bits: &mut BitWriter, n: u32) {
+    if n < 0x80 {
+        bits.write(n as u64, 8);
+        return;
+    }
+    let continuation_bytes = match n {
+        0..0x800 => 1,
+        0x800..0x1_0000 => 2,
+        0x1_0000..0x20_0000 => 3,
+        0x20_0000..0x400_0000 => 4,
+        _ => 5,
+    };
+    // Leading ones count the bytes, then a zero, then the top payload bits.
+    let lead_bits = continuation_bytes + 2;
+    let payload_bits = 8 - lead_bits;
+    let prefix = (0xFFu64 << (8 - (continuation_bytes + 1))) & 0xFF;
+    let top = (n as u64) >> (6 * continuation_bytes);
+    bits.write(prefix | (top & ((1 << payload_bits) - 1)), 8);
+    for i in (0..continuation_bytes).rev() {
+        bits.write(0x80 | ((n as u64 >> (6 * i)) & 0x3F), 8);
+    }
+}
+
+/// Residuals of a fixed predictor, or None if any don't fit the 32 bits the
+/// format allows.
+fn fixed_residuals(samples: &[i64], order: usize) -> Option<Vec<i64>> {
+    let residuals: Vec<i64> = (order..samples.len())
+        .map(|i| {
+            let x = |k: usize| samples[i - k];
+            match order {
+                0 => x(0),
+                1 => x(0) - x(1),
+                2 => x(0) - 2 * x(1) + x(2),
+                3 => x(0) - 3 * x(1) + 3 * x(2) - x(3),
+                _ => x(0) - 4 * x(1) + 6 * x(2) - 4 * x(3) + x(4),
+            }
+        })
+        .collect();
+    residuals
+        .iter()
+        .all(|&r| i32::try_from(r).is_ok())
+        .then_some(residuals)
+}
+
+/// Maps a signed residual onto the unsigned values Rice coding takes.
+fn zigzag(r: i64) -> u64 {
+    ((r << 1) ^ (r >> 63)) as u64
+}
+
+/// The Rice parameter for a set of residuals, estimated from their mean.
+fn rice_parameter(residuals: &[i64]) -> u32 {
+    let sum: u64 = residuals.iter().map(|&r| zigzag(r)).sum();
+    let mean = sum / residuals.len().max(1) as u64;
+    if mean == 0 {
+        0
+    } else {
+        (63 - mean.leading_zeros()).min(MAX_RICE_PARAMETER)
+    }
+}
+
+/// Bits a Rice-coded residual section takes, method and partition fields
+/// included.
+fn rice_cost(residuals: &[i64], parameter: u32) -> u64 {
+    2 + 4
+        + 4
+        + residuals
+            .iter()
+            .map(|&r| 1 + parameter as u64 + (zigzag(r) >> parameter))
+            .sum::<u64>()
+}
+
+/// Encodes one channel of a block as the cheapest subframe.
+fn write_subframe(bits: &mut BitWriter, samples: &[i64], bits_per_sample: u32) {
+    let mask = (1u64 << bits_per_sample) - 1;
+
+    if samples.iter().all(|&s| s == samples[0]) {
+        write_subframe_header(bits, 0b000000);
+        bits.write(samples[0] as u64 & mask, bits_per_sample);
+        return;
+    }
+
+    let verbatim_cost = samples.len() as u64 * bits_per_sample as u64;
+    let best = (0..=MAX_FIXED_ORDER.min(samples.len() - 1))
+        .filter_map(|order| {
+            let residuals = fixed_residuals(samples, order)?;
+            let parameter = rice_parameter(&residuals);
+            let cost = order as u64 * bits_per_sample as u64 + rice_cost(&residuals, parameter);
+            Some((cost, order, parameter, residuals))
+        })
+        .min_by_key(|(cost, ..)| *cost);
+
+    match best {
+        Some((cost, order, parameter, residuals)) if cost < verbatim_cost => {
+            write_subframe_header(bits, 0b001000 | order as u64);
+            for &warmup in &samples[..order] {
+                bits.write(warmup as u64 & mask, bits_per_sample);
+            }
+            // Rice coding with 4-bit parameters, a single partition.
+            bits.write(0b00, 2);
+            bits.write(0, 4);
+            bits.write(parameter as u64, 4);
+            for &r in &residuals {
+                let u = zigzag(r);
+                bits.write_unary(u >> parameter);
+                bits.write(u & ((1 << parameter) - 1), parameter);
+            }
+        }
+        _ => {
+            write_subframe_header(bits, 0b000001);
+            for &s in samples {
+                bits.write(s as u64 & mask, bits_per_sample);
+            }
+        }
+    }
+}
+
+/// Writes a subframe header: constant (0), verbatim (1) or fixed predictor
+/// (8 + order), with no wasted bits.
+fn write_subframe_header(bits: &mut BitWriter, kind: u64) {
+    bits.write(0, 1);
+    bits.write(kind, 6);
+    bits.write(0, 1);
+}
+
+/// CRC-8 over a frame header, polynomial x^8 + x^2 + x + 1.
+fn crc8(data: &[u8]) -> u8 {
+    data.iter().fold(0u8, |mut crc, &byte| {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    })
+}
+
+/// CRC-16 over a whole frame, polynomial x^16 + x^15 + x^2 + 1.
+fn crc16(data: &[u8]) -> u16 {
+    data.iter().fold(0u16, |mut crc, &byte| {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x8005
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    })
+}
+
+/// Packs values most significant bit first.
+struct BitWriter {
+    bytes: Vec<u8>,
+    accumulator: u64,
+    /// Bits held in the accumulator, always fewer than 8 between writes.
+    held: u32,
+}
+
+impl BitWriter {
+    fn with_capacity(bytes: usize) -> Self {
+        BitWriter {
+            bytes: Vec::with_capacity(bytes),
+            accumulator: 0,
+            held: 0,
+        }
+    }
+
+    /// Writes the low `count` bits of `value`, up to 32 at a time.
+    fn write(&mut self, value: u64, count: u32) {
+        debug_assert!(count <= 32);
+        if count == 0 {
+            return;
+        }
+        self.accumulator = (self.accumulator << count) | (value & ((1 << count) - 1));
+        self.held += count;
+        while self.held >= 8 {
+            self.held -= 8;
+            self.bytes.push((self.accumulator >> self.held) as u8);
+        }
+        self.accumulator &= (1 << self.held) - 1;
+    }
+
+    /// Writes `n` zeros and a terminating one.
+    fn write_unary(&mut self, mut n: u64) {
+        while n >= 32 {
+            self.write(0, 32);
+            n -= 32;
+        }
+        self.write(1, n as u32 + 1);
+    }
+
+    /// Pads with zeros to the next byte boundary.
+    fn align(&mut self) {
+        if self.held > 0 {
+            self.write(0, 8 - self.held);
+        }
+    }
+
+    /// The complete bytes written so far.
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::audio::sample_source::create_sample_source_from_file;
+
+    /// Encodes `samples` to a FLAC file and decodes it back with symphonia.
+    fn round_trip(samples: &[i32], channels: u16, bits_per_sample: u16) -> Vec<i32> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("round_trip.flac");
+        let file = std::fs::File::create(&path).unwrap();
+        let mut flac = FlacWriter::new(file, channels, 48_000, bits_per_sample).unwrap();
+        // Uneven writes, so blocks fill across calls.
+        for chunk in samples.chunks(channels as usize * 1000) {
+            flac.write_samples(chunk).unwrap();
+        }
+        flac.finalize().unwrap();
+
+        let mut source = create_sample_source_from_file(&path, None, 1024).unwrap();
+        assert_eq!(source.channel_count(), channels);
+        assert_eq!(source.sample_rate(), 48_000);
+        let scale = (1i64 << (bits_per_sample - 1)) as f32;
+        let mut decoded = Vec::new();
+        while let Some(sample) = source.next_sample().unwrap() {
+            decoded.push((sample * scale).round() as i32);
+        }
+        decoded
+    }
+
+    #[test]
+    fn decodes_to_the_samples_written() {
+        // A decaying tone, then silence, then noise: every subframe type.
+        let mut samples: Vec<i32> = (0..6000)
+            .map(|i| {
+                let t = i as f32 / 48_000.0;
+                let envelope = (-t * 20.0).exp();
+                ((t * 440.0 * std::f32::consts::TAU).sin() * envelope * 30_000.0) as i32
+            })
+            .collect();
+        samples.extend(std::iter::repeat_n(0, 5000));
+        let mut seed = 1u32;
+        samples.extend((0..3000).map(|_| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (seed >> 16) as i16 as i32
+        }));
+
+        assert_eq!(round_trip(&samples, 1, 16), samples);
+    }
+
+    #[test]
+    fn decodes_interleaved_24_bit_channels() {
+        let samples: Vec<i32> = (0..9000)
+            .flat_map(|i| {
+                let t = i as f32 / 48_000.0;
+                let a = ((t * 110.0 * std::f32::consts::TAU).sin() * 8_000_000.0) as i32;
+                [a, -a / 3, (i % 7) - 3]
+            })
+            .collect();
+
+        assert_eq!(round_trip(&samples, 3, 24), samples);
+    }
+
+    #[test]
+    fn an_empty_stream_is_valid() {
+        let mut cursor = Cursor::new(Vec::new());
+        let flac = FlacWriter::new(&mut cursor, 1, 44_100, 16).unwrap();
+        assert_eq!(flac.frames(), 0);
+        flac.finalize().unwrap();
+        let bytes = cursor.into_inner();
+        assert_eq!(&bytes[..4], b"fLaC");
+        assert_eq!(bytes.len(), 42);
+    }
+
+    #[test]
+    fn totals_are_filled_in() {
+        let mut cursor = Cursor::new(Vec::new());
+        let mut flac = FlacWriter::new(&mut cursor, 2, 44_100, 16).unwrap();
+        flac.write_samples(&[1; 2 * 5000]).unwrap();
+        assert_eq!(flac.frames(), 5000);
+        flac.finalize().unwrap();
+        let bytes = cursor.into_inner();
+        let offset = STREAMINFO_TOTALS_OFFSET as usize;
+        let packed = u64::from_be_bytes(bytes[offset..offset + 8].try_into().unwrap());
+        assert_eq!(packed >> 44, 44_100);
+        assert_eq!((packed >> 41) & 0x7, 1);
+        assert_eq!((packed >> 36) & 0x1F, 15);
+        assert_eq!(packed & 0xF_FFFF_FFFF, 5000);
+    }
+
+    #[test]
+    fn rejects_unsupported_layouts() {
+        assert!(FlacWriter::new(Cursor::new(Vec::new()), 9, 48_000, 16).is_err());
+        assert!(FlacWriter::new(Cursor::new(Vec::new()), 1, 48_000, 32).is_err());
+        assert!(FlacWriter::new(Cursor::new(Vec::new()), 0, 48_000, 16).is_err());
+    }
+
+    #[test]
+    fn frame_numbers_use_utf8_coding() {
+        let encode = |n| {
+            let mut bits = BitWriter::with_capacity(8);
+            write_utf8_number(&mut bits, n);
+            bits.bytes().to_vec()
+        };
+        assert_eq!(encode(0x45), vec![0x45]);
+        assert_eq!(encode(0x80), vec![0xC2, 0x80]);
+        assert_eq!(encode(0x800), vec![0xE0, 0xA0, 0x80]);
+        assert_eq!(encode(0x1_0000), vec![0xF0, 0x90, 0x80, 0x80]);
+    }
+}
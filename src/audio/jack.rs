@@ -0,0 +1,386 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+
+//! JACK output, for rigs that share an interface with other software through
+//! a JACK server or PipeWire's JACK API.
+//!
+//! A JACK device is the same mixer and playback logic as a cpal one, with a
+//! JACK client standing in for the cpal stream: see [`JackOutputStreamFactory`].
+//! Each mapped channel gets its own named output port, the mixer's sample
+//! counter follows JACK's frame time, and xruns are counted in the device's
+//! [`OutputHealth`].
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use ::jack::{
+    AudioOut, Client, ClientOptions, ClientStatus, Control, Frames, NotificationHandler, Port,
+    ProcessHandler, ProcessScope,
+};
+use tracing::error;
+
+use crate::audio::cpal::stream::{drain_pending_sources, OutputStream, OutputStreamFactory};
+use crate::audio::cpal::CondvarNotify;
+use crate::audio::health::{has_output_signal, OutputHealth};
+use crate::audio::mixer::{AudioMixer, PreparedSource};
+
+/// The client name used when the device is just `jack`.
+const DEFAULT_CLIENT_NAME: &str = "mtrack";
+
+/// How many ports to register when the profile maps no tracks at all.
+const DEFAULT_CHANNELS: u16 = 2;
+
+/// Port names are cut to this many characters. JACK limits the full
+/// `client:port` name, and a channel carrying a dozen tracks would pass it.
+const MAX_PORT_NAME: usize = 64;
+
+/// The JACK client name an audio device name asks for: `jack` for the
+/// default, or `jack:<client name>`. None for any other device.
+pub(crate) fn client_name(device: &str) -> Option<&str> {
+    let device = device.trim();
+    if device.eq_ignore_ascii_case("jack") {
+        return Some(DEFAULT_CLIENT_NAME);
+    }
+    let (prefix, name) = device.split_once(':')?;
+    (prefix.eq_ignore_ascii_case("jack") && !name.trim().is_empty()).then(|| name.trim())
+}
+
+/// Connects to a running JACK server. Never starts one: a server started
+/// on mtrack's behalf would take the interface away from whatever the rig
+/// expects to be running it.
+pub(crate) fn connect(client_name: &str) -> Result<Client, ::jack::Error> {
+    let (client, _) = Client::new(client_name, ClientOptions::NO_START_SERVER)?;
+    Ok(client)
+}
+
+/// One output port name per channel, up to the highest channel `mappings`
+/// routes a track to. Each is named for its channel and the tracks on it, so
+/// a patchbay shows what it is connecting.
+pub(crate) fn port_names(mappings: &HashMap<String, Vec<u16>>) -> Vec<String> {
+    let channels = mappings
+        .values()
+        .flatten()
+        .copied()
+        .max()
+        .unwrap_or(DEFAULT_CHANNELS);
+    (1..=channels)
+        .map(|channel| {
+            let mut tracks: Vec<&str> = mappings
+                .iter()
+                .filter(|(_, channels)| channels.contains(&channel))
+                .map(|(track, _)| track.as_str())
+                .collect();
+            tracks.sort_unstable();
+            let name = if tracks.is_empty() {
+                format!("out_{channel}")
+            } else {
+                format!("out_{channel}_{}", tracks.join("+"))
+            };
+            // The colon separates client from port in a full port name.
+            name.replace(':', "_").chars().take(MAX_PORT_NAME).collect()
+        })
+        .collect()
+}
+
+/// Keeps the mixer's sample counter on JACK's frame time.
+///
+/// The mixer advances its counter by each period it renders. JACK's frame time
+/// also moves on through periods nobody rendered, the ones an xrun lost, so
+/// the difference goes onto the counter before mixing and playback clocks
+/// stay on the server's timeline.
+#[derive(Default)]
+struct FrameTime {
+    /// The frame time the next period should start at.
+    next: Option<Frames>,
+}
+
+impl FrameTime {
+    /// How many frames went unrendered before a period starting at `start`
+    /// and lasting `frames`. Frame time is 32 bits and wraps within a day.
+    fn skipped(&mut self, start: Frames, frames: Frames) -> u64 {
+        let skipped = self.next.map_or(0, |next| start.wrapping_sub(next));
+        self.next = Some(start.wrapping_add(frames));
+        // A period starting before the last one ended is the server
+        // restarting its count, not half a day lost.
+        if skipped > Frames::MAX / 2 {
+            return 0;
+        }
+        u64::from(skipped)
+    }
+}
+
+/// The realtime half: mixes a period and spreads it across the ports.
+struct Process {
+    ports: Vec<Port<AudioOut>>,
+    /// One period of interleaved mix. Only resized from the buffer size
+    /// callback, which JACK runs outside the process thread.
+    buffer: Vec<f32>,
+    mixer: AudioMixer,
+    sample_counter: Arc<AtomicU64>,
+    source_rx: crossbeam_channel::Receiver<PreparedSource>,
+    frame_time: FrameTime,
+    health: Arc<OutputHealth>,
+}
+
+impl ProcessHandler for Process {
+    fn process(&mut self, _: &Client, scope: &ProcessScope) -> Control {
+        let frames = scope.n_frames();
+        let channels = self.ports.len();
+        let skipped = self.frame_time.skipped(scope.last_frame_time(), frames);
+        if skipped > 0 {
+            self.sample_counter.fetch_add(skipped, Ordering::Relaxed);
+        }
+
+        let Some(mix) = self.buffer.get_mut(..frames as usize * channels) else {
+            // A period longer than the server said it would be. Play silence
+            // rather than allocate here; the buffer size callback catches up.
+            for port in &mut self.ports {
+                port.as_mut_slice(scope).fill(0.0);
+            }
+            return Control::Continue;
+        };
+        drain_pending_sources(&self.mixer, &self.source_rx);
+        self.mixer.process_into_output(mix, frames as usize);
+        self.health.record_callback(has_output_signal(mix));
+
+        for (channel, port) in self.ports.iter_mut().enumerate() {
+            let out = port.as_mut_slice(scope);
+            for (sample, frame) in out.iter_mut().zip(mix.chunks_exact(channels)) {
+                *sample = frame[channel];
+            }
+        }
+        Control::Continue
+    }
+
+    fn buffer_size(&mut self, _: &Client, size: Frames) -> Control {
+        self.buffer.resize(size as usize * self.ports.len(), 0.0);
+        Control::Continue
+    }
+}
+
+/// Server notifications: xruns are counted, and a server shutting down wakes
+/// the output thread to reconnect.
+struct Notifications {
+    health: Arc<OutputHealth>,
+    error_notify: CondvarNotify,
+}
+
+impl NotificationHandler for Notifications {
+    fn xrun(&mut self, _: &Client) -> Control {
+        // JACK recovers from these by itself, as ALSA does under cpal: count
+        // it and carry on rather than rebuilding.
+        self.health.record_underrun();
+        Control::Continue
+    }
+
+    fn shutdown(&mut self, _: ClientStatus, reason: &str) {
+        error!(
+            reason,
+            "JACK server shut the client down (will attempt to reconnect)"
+        );
+        self.health
+            .record_stream_error(&format!("JACK server shut down: {reason}"));
+        let (mutex, condvar) = &*self.error_notify;
+        *mutex.lock() = true;
+        condvar.notify_one();
+    }
+}
+
+/// An active JACK client. Dropping it deactivates the client and closes it.
+struct JackOutputStream {
+    _client: ::jack::AsyncClient<Notifications, Process>,
+}
+
+impl OutputStream for JackOutputStream {}
+
+/// Builds output streams as JACK clients.
+pub(crate) struct JackOutputStreamFactory {
+    client_name: String,
+    /// The client the device was opened with, to read the server's format.
+    /// The first stream activates it; any rebuild connects afresh.
+    client: parking_lot::Mutex<Option<Client>>,
+    port_names: Vec<String>,
+}
+
+impl JackOutputStreamFactory {
+    pub(crate) fn new(client: Client, port_names: Vec<String>) -> Self {
+        Self {
+            client_name: client.name().to_string(),
+            client: parking_lot::Mutex::new(Some(client)),
+            port_names,
+        }
+    }
+}
+
+impl OutputStreamFactory for JackOutputStreamFactory {
+    fn build_stream(
+        &self,
+        mixer: AudioMixer,
+        source_rx: crossbeam_channel::Receiver<PreparedSource>,
+        num_channels: u16,
+        error_notify: CondvarNotify,
+        health: Arc<OutputHealth>,
+    ) -> Result<Box<dyn OutputStream>, Box<dyn Error>> {
+        let client = match self.client.lock().take() {
+            Some(client) => client,
+            None => connect(&self.client_name)?,
+        };
+        // The mixer is fixed at the rate it was opened with, and a server
+        // restarted at another one would play everything at the wrong speed.
+        let server_rate = client.sample_rate() as u32;
+        if server_rate != mixer.sample_rate() {
+            return Err(format!(
+                "the JACK server runs at {server_rate} Hz, but playback was opened at {} Hz",
+                mixer.sample_rate()
+            )
+            .into());
+        }
+        if self.port_names.len() != num_channels as usize {
+            return Err(format!(
+                "{} JACK ports for {num_channels} channels",
+                self.port_names.len()
+            )
+            .into());
+        }
+
+        let ports = self
+            .port_names
+            .iter()
+            .map(|name| client.register_port(name, AudioOut))
+            .collect::<Result<Vec<_>, _>>()?;
+        let process = Process {
+            buffer: vec![0.0; client.buffer_size() as usize * ports.len()],
+            ports,
+            sample_counter: mixer.sample_counter(),
+            mixer,
+            source_rx,
+            frame_time: FrameTime::default(),
+            health: health.clone(),
+        };
+        let notifications = Notifications {
+            health,
+            error_notify,
+        };
+        let client = client.activate_async(notifications, process)?;
+        Ok(Box::new(JackOutputStream { _client: client }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mappings(entries: &[(&str, &[u16])]) -> HashMap<String, Vec<u16>> {
+        entries
+            .iter()
+            .map(|(track, channels)| (track.to_string(), channels.to_vec()))
+            .collect()
+    }
+
+    #[test]
+    fn client_names() {
+        assert_eq!(client_name("jack"), Some("mtrack"));
+        assert_eq!(client_name(" JACK "), Some("mtrack"));
+        assert_eq!(client_name("jack:foh"), Some("foh"));
+        assert_eq!(client_name("jack:"), None);
+        assert_eq!(client_name("hw:CARD=UMC1820"), None);
+        assert_eq!(client_name("jackson"), None);
+    }
+
+    #[test]
+    fn a_port_per_channel_named_for_its_tracks() {
+        let names = port_names(&mappings(&[
+            ("click", &[1]),
+            ("kick", &[3]),
+            ("snare", &[3]),
+            ("keys", &[4, 5]),
+        ]));
+        assert_eq!(
+            names,
+            [
+                "out_1_click",
+                "out_2",
+                "out_3_kick+snare",
+                "out_4_keys",
+                "out_5_keys"
+            ]
+        );
+    }
+
+    #[test]
+    fn port_names_are_safe_for_jack() {
+        let names = port_names(&mappings(&[("a:b", &[1]), (&"x".repeat(100), &[2])]));
+        assert_eq!(names[0], "out_1_a_b");
+        assert_eq!(names[1].chars().count(), MAX_PORT_NAME);
+        assert!(names[1].starts_with("out_2_"));
+    }
+
+    #[test]
+    fn unmapped_profile_gets_stereo() {
+        assert_eq!(port_names(&HashMap::new()), ["out_1", "out_2"]);
+    }
+
+    #[test]
+    fn frame_time_counts_lost_periods() {
+        let mut time = FrameTime::default();
+        assert_eq!(time.skipped(1000, 256), 0);
+        assert_eq!(time.skipped(1256, 256), 0);
+        // An xrun lost two periods.
+        assert_eq!(time.skipped(2024, 256), 512);
+        assert_eq!(time.skipped(2280, 256), 0);
+    }
+
+    #[test]
+    fn frame_time_wraps() {
+        let mut time = FrameTime::default();
+        assert_eq!(time.skipped(Frames::MAX - 99, 256), 0);
+        assert_eq!(time.skipped(156, 256), 0);
+        assert_eq!(time.skipped(412 + 128, 256), 128);
+    }
+
+    #[test]
+    fn frame_time_restarting_is_not_a_loss() {
+        let mut time = FrameTime::default();
+        assert_eq!(time.skipped(48_000, 256), 0);
+        assert_eq!(time.skipped(0, 256), 0);
+        assert_eq!(time.skipped(256, 256), 0);
+    }
+
+    /// Plays through a real server, e.g. `jackd -d dummy -r 48000 -p 256`.
+    #[test]
+    #[ignore = "needs a running JACK server: start `jackd -d dummy` and run with --ignored"]
+    fn plays_through_a_server() {
+        let config = crate::config::Audio::new("jack:mtrack-test");
+        let device = crate::audio::get_device_for_mappings(
+            Some(config),
+            &mappings(&[("click", &[1]), ("keys", &[2])]),
+        )
+        .expect("a JACK server should be running");
+        let counter = device.sample_counter().unwrap();
+        let start = counter.load(Ordering::Relaxed);
+        std::thread::sleep(std::time::Duration::from_millis(500));
+
+        let health = device.output_health().unwrap();
+        assert!(health.callbacks > 0, "the process callback should run");
+        let rate = device.sample_rate().unwrap() as u64;
+        let advanced = counter.load(Ordering::Relaxed) - start;
+        assert!(
+            advanced > rate / 4,
+            "the sample counter should follow the server, advanced {advanced}"
+        );
+        assert!(device.to_string().contains("JACK"));
+    }
+}
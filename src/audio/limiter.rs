@@ -0,0 +1,295 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+//! Look-ahead brickwall limiter for the mixer's output channels.
+//!
+//! Each channel is delayed by the look-ahead so the gain can start coming
+//! down before a peak reaches the output. The gain a sample needs to stay
+//! under the ceiling is held at its minimum across the look-ahead window and
+//! then averaged over the same window, which ramps the gain down to exactly
+//! that minimum by the time the peak leaves the delay line. Recovery is
+//! smoothed separately by the release. Channels are limited independently,
+//! so a hot in-ear mix doesn't duck the others.
+//!
+//! All buffers are sized when the limiter is built; processing allocates
+//! nothing.
+use std::time::Duration;
+
+/// Limiter settings, resolved against the output sample rate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LimiterSettings {
+    /// Linear ceiling no output sample exceeds.
+    pub ceiling: f32,
+    /// How far ahead the limiter looks, in frames. Also the delay it adds.
+    pub lookahead_frames: usize,
+    /// Per-frame smoothing factor for gain recovery.
+    pub release_coeff: f32,
+}
+
+impl LimiterSettings {
+    /// Resolves a ceiling in dBFS, a look-ahead and a release time at the
+    /// given sample rate. The look-ahead is at least one frame.
+    pub fn new(ceiling_db: f32, lookahead: Duration, release: Duration, sample_rate: u32) -> Self {
+        let frames = |d: Duration| d.as_secs_f64() * f64::from(sample_rate);
+        let release_frames = frames(release).max(1.0);
+        Self {
+            ceiling: 10.0f32.powf(ceiling_db / 20.0),
+            lookahead_frames: (frames(lookahead).round() as usize).max(1),
+            release_coeff: (1.0 - (-1.0 / release_frames).exp()) as f32,
+        }
+    }
+}
+
+/// A fixed-capacity sliding window minimum (monotonic deque over a ring).
+struct WindowMin {
+    /// (frame index, value), values increasing from front to back.
+    entries: Vec<(u64, f32)>,
+    head: usize,
+    len: usize,
+    window: u64,
+}
+
+impl WindowMin {
+    fn new(window: usize) -> Self {
+        Self {
+            entries: vec![(0, 0.0); window],
+            head: 0,
+            len: 0,
+            window: window as u64,
+        }
+    }
+
+    fn slot(&self, offset: usize) -> usize {
+        (self.head + offset) % self.entries.len()
+    }
+
+    /// Adds the value for frame `index` and returns the minimum over the
+    /// last `window` frames.
+    fn push(&mut self, index: u64, value: f32) -> f32 {
+        while self.len > 0 && self.entries[self.slot(self.len - 1)].1 >= value {
+            self.len -= 1;
+        }
+        if self.len > 0 && self.entries[self.head].0 + self.window <= index {
+            self.head = self.slot(1);
+            self.len -= 1;
+        }
+        let tail = self.slot(self.len);
+        self.entries[tail] = (index, value);
+        self.len += 1;
+        self.entries[self.head].1
+    }
+}
+
+/// One channel's limiter state.
+struct ChannelLimiter {
+    /// Delayed input samples, `lookahead_frames - 1` long.
+    delay: Vec<f32>,
+    /// Minimum-held gains feeding the moving average, `lookahead_frames` long.
+    held: Vec<f32>,
+    held_sum: f64,
+    min: WindowMin,
+    /// Gain applied to the last output sample. Kept in `f64` so a slow
+    /// release can still creep the last few millionths back to unity.
+    gain: f64,
+}
+
+impl ChannelLimiter {
+    fn new(lookahead_frames: usize) -> Self {
+        Self {
+            delay: vec![0.0; lookahead_frames - 1],
+            held: vec![1.0; lookahead_frames],
+            held_sum: lookahead_frames as f64,
+            min: WindowMin::new(lookahead_frames),
+            gain: 1.0,
+        }
+    }
+}
+
+/// Limits every output channel of an interleaved buffer.
+pub struct Limiter {
+    settings: LimiterSettings,
+    channels: Vec<ChannelLimiter>,
+    /// Frames processed, indexing the delay lines and windows.
+    frame: u64,
+}
+
+impl Limiter {
+    pub fn new(num_channels: usize, settings: LimiterSettings) -> Self {
+        Self {
+            settings,
+            channels: (0..num_channels)
+                .map(|_| ChannelLimiter::new(settings.lookahead_frames))
+                .collect(),
+            frame: 0,
+        }
+    }
+
+    /// The delay the limiter adds, in frames.
+    pub fn latency_frames(&self) -> usize {
+        self.settings.lookahead_frames - 1
+    }
+
+    /// Limits an interleaved buffer in place, returning how many times a
+    /// channel went from passing signal untouched to reducing it.
+    pub fn process(&mut self, output: &mut [f32], num_channels: usize) -> u64 {
+        let ceiling = self.settings.ceiling;
+        let release = f64::from(self.settings.release_coeff);
+        let lookahead = self.settings.lookahead_frames;
+        let mut engagements = 0;
+        let frames = output.len() / num_channels.max(1);
+        for frame_idx in 0..frames {
+            let index = self.frame + frame_idx as u64;
+            let base = frame_idx * num_channels;
+            for (channel, state) in self.channels.iter_mut().enumerate().take(num_channels) {
+                let input = output[base + channel];
+                let magnitude = input.abs();
+                let needed = if magnitude > ceiling {
+                    ceiling / magnitude
+                } else {
+                    1.0
+                };
+                let held = state.min.push(index, needed);
+                let slot = (index % lookahead as u64) as usize;
+                state.held_sum += f64::from(held) - f64::from(state.held[slot]);
+                state.held[slot] = held;
+                let target = state.held_sum / lookahead as f64;
+
+                let was_limiting = state.gain < 1.0;
+                state.gain = if target < state.gain {
+                    target
+                } else {
+                    state.gain + (target - state.gain) * release
+                };
+                // Snap a recovered gain to exactly unity, so an engagement
+                // ends rather than trailing off forever.
+                if state.gain > 1.0 - 1e-6 {
+                    state.gain = 1.0;
+                }
+                if !was_limiting && state.gain < 1.0 {
+                    engagements += 1;
+                }
+
+                let delayed = if state.delay.is_empty() {
+                    input
+                } else {
+                    let slot = (index % state.delay.len() as u64) as usize;
+                    std::mem::replace(&mut state.delay[slot], input)
+                };
+                output[base + channel] = (delayed * state.gain as f32).clamp(-ceiling, ceiling);
+            }
+        }
+        self.frame += frames as u64;
+        engagements
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(lookahead_frames: usize) -> LimiterSettings {
+        LimiterSettings {
+            ceiling: 0.5,
+            lookahead_frames,
+            release_coeff: 0.01,
+        }
+    }
+
+    #[test]
+    fn settings_resolve_against_the_sample_rate() {
+        let settings = LimiterSettings::new(
+            -6.0,
+            Duration::from_millis(2),
+            Duration::from_millis(100),
+            48000,
+        );
+        assert!((settings.ceiling - 0.5012).abs() < 1e-3);
+        assert_eq!(settings.lookahead_frames, 96);
+        assert!(settings.release_coeff > 0.0 && settings.release_coeff < 0.001);
+
+        let zero = LimiterSettings::new(0.0, Duration::ZERO, Duration::ZERO, 48000);
+        assert_eq!(zero.lookahead_frames, 1);
+        assert!(zero.release_coeff <= 1.0);
+    }
+
+    #[test]
+    fn window_min_tracks_the_window() {
+        let mut min = WindowMin::new(3);
+        let values = [5.0, 3.0, 4.0, 6.0, 7.0, 1.0, 2.0];
+        let expected = [5.0, 3.0, 3.0, 3.0, 4.0, 1.0, 1.0];
+        for (i, (value, want)) in values.iter().zip(expected).enumerate() {
+            assert_eq!(min.push(i as u64, *value), want, "frame {i}");
+        }
+    }
+
+    #[test]
+    fn quiet_signal_passes_delayed_and_untouched() {
+        let mut limiter = Limiter::new(1, settings(4));
+        let mut buffer: Vec<f32> = (0..16).map(|i| i as f32 * 0.01).collect();
+        let input = buffer.clone();
+        assert_eq!(limiter.process(&mut buffer, 1), 0);
+        let latency = limiter.latency_frames();
+        assert_eq!(latency, 3);
+        assert_eq!(&buffer[latency..], &input[..input.len() - latency]);
+    }
+
+    #[test]
+    fn peaks_never_pass_the_ceiling() {
+        let mut limiter = Limiter::new(2, settings(8));
+        // A quiet bed with a burst on channel 1 only.
+        let mut buffer: Vec<f32> = (0..256)
+            .flat_map(|i| {
+                let burst = if (100..110).contains(&i) { 2.0 } else { 0.1 };
+                [if i % 2 == 0 { burst } else { -burst }, 0.1]
+            })
+            .collect();
+        let engagements = limiter.process(&mut buffer, 2);
+        assert_eq!(engagements, 1);
+        assert!(buffer.iter().all(|s| s.abs() <= 0.5));
+        // The burst arrives at its delayed position at the ceiling...
+        let latency = limiter.latency_frames();
+        assert!((buffer[(100 + latency) * 2].abs() - 0.5).abs() < 1e-4);
+        // ...while the other channel is left alone.
+        assert!(buffer.chunks(2).skip(latency).all(|f| f[1] == 0.1));
+    }
+
+    #[test]
+    fn gain_ramps_down_ahead_of_a_peak_and_recovers() {
+        let mut limiter = Limiter::new(1, settings(8));
+        let mut buffer = vec![0.25f32; 2048];
+        buffer[500] = 1.0;
+        limiter.process(&mut buffer, 1);
+        let latency = limiter.latency_frames();
+        let peak = 500 + latency;
+        // Smooth descent: each sample before the peak at or below the last.
+        for i in peak - 7..peak {
+            assert!(buffer[i] <= buffer[i - 1] + 1e-6, "frame {i}");
+        }
+        assert!(buffer[peak - 8] > 0.249);
+        assert!((buffer[peak] - 0.5).abs() < 1e-4);
+        assert!((buffer[2047] - 0.25).abs() < 1e-3, "gain recovers");
+    }
+
+    #[test]
+    fn engagements_count_each_episode() {
+        let mut limiter = Limiter::new(1, settings(2));
+        let mut buffer = vec![0.0f32; 4000];
+        buffer[100] = 1.0;
+        buffer[2000] = 1.0;
+        assert_eq!(limiter.process(&mut buffer, 1), 2);
+        // Held state carries across buffers.
+        let mut next = vec![1.0f32; 64];
+        assert_eq!(limiter.process(&mut next, 1), 1);
+        assert!(next.iter().all(|s| s.abs() <= 0.5));
+    }
+}
@@ -0,0 +1,395 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+
+//! The virtual linear timecode track.
+//!
+//! [`LtcSource`] renders SMPTE linear timecode as a mono [`SampleSource`]
+//! that participates in the mixer like any file-backed track. Every sample is
+//! derived from its absolute position in the song, with exact integer frame
+//! arithmetic, so a source started mid-song (a seek, a section loop coming
+//! back round) renders the very samples a source started from the top would
+//! have at that point.
+
+use std::time::Duration;
+
+use super::sample_source::error::SampleSourceError;
+use super::sample_source::traits::SampleSource;
+use crate::config::{MtcFrameRate, Timecode};
+use crate::midi::mtc::{from_frame_count, to_frame_count};
+
+/// Bits in an LTC frame.
+pub const BITS_PER_FRAME: usize = 80;
+
+/// Biphase mark code splits each bit into two halves.
+const HALF_BITS_PER_FRAME: u64 = 2 * BITS_PER_FRAME as u64;
+
+/// Peak level of the square wave, about -6 dBFS: hot enough for any LTC
+/// reader, with headroom left for track gain.
+const LEVEL: f32 = 0.5;
+
+/// The sync word closing every frame, bits 64-79.
+pub const SYNC_WORD: [bool; 16] = [
+    false, false, true, true, true, true, true, true, true, true, true, true, true, true, false,
+    true,
+];
+
+/// Encodes `timecode` as the 80 bits of an LTC frame, in transmission order.
+pub fn frame_bits(timecode: Timecode, rate: MtcFrameRate) -> [bool; BITS_PER_FRAME] {
+    let mut bits = [false; BITS_PER_FRAME];
+    let mut put = |offset: usize, width: usize, value: u8| {
+        for i in 0..width {
+            bits[offset + i] = value >> i & 1 == 1;
+        }
+    };
+    put(0, 4, timecode.frames % 10);
+    put(8, 2, timecode.frames / 10);
+    put(16, 4, timecode.seconds % 10);
+    put(24, 3, timecode.seconds / 10);
+    put(32, 4, timecode.minutes % 10);
+    put(40, 3, timecode.minutes / 10);
+    put(48, 4, timecode.hours % 10);
+    put(56, 2, timecode.hours / 10);
+    bits[10] = rate == MtcFrameRate::Fps2997DropFrame;
+    bits[64..].copy_from_slice(&SYNC_WORD);
+
+    // The polarity correction bit keeps the count of ones even, so every
+    // frame starts on the same level.
+    let parity_bit = if rate == MtcFrameRate::Fps25 { 59 } else { 27 };
+    bits[parity_bit] = bits.iter().filter(|bit| **bit).count() % 2 == 1;
+    bits
+}
+
+/// Reads the timecode and drop-frame flag back out of an LTC frame's 80
+/// bits. None unless it ends in the sync word and every field is in range.
+pub fn decode_frame_bits(bits: &[bool; BITS_PER_FRAME]) -> Option<(Timecode, bool)> {
+    if bits[64..] != SYNC_WORD {
+        return None;
+    }
+    let field = |offset: usize, width: usize| -> u8 {
+        (0..width).map(|i| u8::from(bits[offset + i]) << i).sum()
+    };
+    let digits = |units: usize, tens: usize, tens_width: usize, limit: u8| {
+        let (units, tens) = (field(units, 4), field(tens, tens_width));
+        (units < 10 && tens * 10 + units < limit).then_some(tens * 10 + units)
+    };
+    let timecode = Timecode::new(
+        digits(48, 56, 2, 24)?,
+        digits(32, 40, 3, 60)?,
+        digits(16, 24, 3, 60)?,
+        digits(0, 8, 2, 30)?,
+    );
+    Some((timecode, bits[10]))
+}
+
+/// The level of each half bit of a frame, biphase mark coded: the level
+/// flips at the start of every bit, and again halfway through a one.
+fn frame_levels(bits: &[bool; BITS_PER_FRAME]) -> [bool; 2 * BITS_PER_FRAME] {
+    let mut levels = [false; 2 * BITS_PER_FRAME];
+    let mut level = false;
+    for (i, bit) in bits.iter().enumerate() {
+        level = !level;
+        levels[2 * i] = level;
+        if *bit {
+            level = !level;
+        }
+        levels[2 * i + 1] = level;
+    }
+    levels
+}
+
+/// Frames per second as an exact fraction.
+fn frames_per_second(rate: MtcFrameRate) -> (u64, u64) {
+    match rate {
+        MtcFrameRate::Fps2997DropFrame => (30_000, 1_001),
+        rate => (rate.frames_per_second(), 1),
+    }
+}
+
+/// A virtual track rendering linear timecode.
+pub struct LtcSource {
+    rate: MtcFrameRate,
+    /// The song's start, in frames from 00:00:00:00.
+    start_frames: u64,
+    /// Frames per second, as numerator and denominator.
+    fps: (u64, u64),
+    /// Current position in samples from the top of the song.
+    position: u64,
+    /// The source is exhausted at this position (song end).
+    end_position: u64,
+    /// The frame `levels` holds.
+    frame: Option<u64>,
+    levels: [bool; 2 * BITS_PER_FRAME],
+    sample_rate: u32,
+    /// Samples per second of song time; differs from `sample_rate` when
+    /// playing at a rehearsal speed.
+    render_rate: u32,
+}
+
+impl LtcSource {
+    /// Creates a timecode source for a song whose top reads `start`.
+    ///
+    /// `start_time` is the playback start offset within the song: rendering
+    /// picks up exactly where it would be had playback started from the top.
+    pub fn new(
+        rate: MtcFrameRate,
+        start: Timecode,
+        sample_rate: u32,
+        start_time: Duration,
+        song_duration: Duration,
+    ) -> LtcSource {
+        let samples = |duration: Duration| (duration.as_secs_f64() * sample_rate as f64).round();
+        LtcSource {
+            rate,
+            start_frames: to_frame_count(start, rate),
+            fps: frames_per_second(rate),
+            position: samples(start_time) as u64,
+            end_position: samples(song_duration) as u64,
+            frame: None,
+            levels: [false; 2 * BITS_PER_FRAME],
+            sample_rate,
+            render_rate: sample_rate,
+        }
+    }
+
+    /// Renders timecode for playback at `speed`, so the code keeps reading
+    /// song time while the track plays slower or faster.
+    pub fn with_speed(mut self, speed: f64) -> LtcSource {
+        let render_rate = (self.sample_rate as f64 / speed).round() as u32;
+        let rescale = |samples: u64| samples * render_rate as u64 / self.render_rate as u64;
+        self.position = rescale(self.position);
+        self.end_position = rescale(self.end_position);
+        self.render_rate = render_rate;
+        self
+    }
+}
+
+impl SampleSource for LtcSource {
+    fn next_sample(&mut self) -> Result<Option<f32>, SampleSourceError> {
+        if self.position >= self.end_position {
+            return Ok(None);
+        }
+
+        let (numerator, denominator) = self.fps;
+        let half_bits = self.position * numerator * HALF_BITS_PER_FRAME
+            / (self.render_rate as u64 * denominator);
+        let frame = half_bits / HALF_BITS_PER_FRAME;
+        if self.frame != Some(frame) {
+            let timecode = from_frame_count(self.start_frames + frame, self.rate);
+            self.levels = frame_levels(&frame_bits(timecode, self.rate));
+            self.frame = Some(frame);
+        }
+
+        self.position += 1;
+        let high = self.levels[(half_bits % HALF_BITS_PER_FRAME) as usize];
+        Ok(Some(if high { LEVEL } else { -LEVEL }))
+    }
+
+    fn channel_count(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn bits_per_sample(&self) -> u16 {
+        32
+    }
+
+    fn sample_format(&self) -> crate::audio::SampleFormat {
+        crate::audio::SampleFormat::Float
+    }
+
+    fn duration(&self) -> Option<Duration> {
+        Some(Duration::from_secs_f64(
+            self.end_position.saturating_sub(self.position) as f64 / self.render_rate as f64,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 48000;
+
+    fn render(source: &mut LtcSource) -> Vec<f32> {
+        let mut rendered = Vec::new();
+        while let Some(sample) = source.next_sample().unwrap() {
+            rendered.push(sample);
+        }
+        rendered
+    }
+
+    /// Decodes the timecode of each whole frame in a rendering, by timing the
+    /// transitions the way a reader would.
+    fn decode(samples: &[f32], rate: MtcFrameRate) -> Vec<Timecode> {
+        let (numerator, denominator) = frames_per_second(rate);
+        let bit_length = RATE as f64 * denominator as f64 / (numerator as f64 * 80.0);
+        // The first half bit is already a flip from the idle level.
+        let mut edges = vec![0];
+        for i in 1..samples.len() {
+            if (samples[i] > 0.0) != (samples[i - 1] > 0.0) {
+                edges.push(i);
+            }
+        }
+        // A long interval is a zero; two short ones are a one.
+        let mut bits = Vec::new();
+        let mut i = 1;
+        while i < edges.len() {
+            let interval = (edges[i] - edges[i - 1]) as f64;
+            if interval > bit_length * 0.75 {
+                bits.push(false);
+                i += 1;
+            } else {
+                bits.push(true);
+                i += 2;
+            }
+        }
+        let mut frames = Vec::new();
+        let mut end = 80;
+        while end <= bits.len() {
+            let frame: &[bool; BITS_PER_FRAME] = bits[end - 80..end].try_into().unwrap();
+            if let Some((timecode, _)) = decode_frame_bits(frame) {
+                frames.push(timecode);
+                end += 80;
+            } else {
+                end += 1;
+            }
+        }
+        frames
+    }
+
+    #[test]
+    fn frame_layout() {
+        let bits = frame_bits(Timecode::new(12, 34, 56, 23), MtcFrameRate::Fps30);
+        let field = |offset: usize, width: usize| -> u8 {
+            (0..width).map(|i| u8::from(bits[offset + i]) << i).sum()
+        };
+        assert_eq!((field(0, 4), field(8, 2)), (3, 2));
+        assert_eq!((field(16, 4), field(24, 3)), (6, 5));
+        assert_eq!((field(32, 4), field(40, 3)), (4, 3));
+        assert_eq!((field(48, 4), field(56, 2)), (2, 1));
+        assert!(!bits[10]);
+        assert_eq!(bits[64..], SYNC_WORD);
+        assert_eq!(bits.iter().filter(|bit| **bit).count() % 2, 0);
+
+        let bits = frame_bits(Timecode::new(1, 0, 0, 2), MtcFrameRate::Fps2997DropFrame);
+        assert!(bits[10], "drop frame flag");
+        assert_eq!(bits.iter().filter(|bit| **bit).count() % 2, 0);
+    }
+
+    #[test]
+    fn decodes_its_own_frames() {
+        let timecode = Timecode::new(23, 59, 58, 24);
+        let bits = frame_bits(timecode, MtcFrameRate::Fps25);
+        assert_eq!(decode_frame_bits(&bits), Some((timecode, false)));
+
+        let bits = frame_bits(timecode, MtcFrameRate::Fps2997DropFrame);
+        assert_eq!(decode_frame_bits(&bits), Some((timecode, true)));
+
+        // Out of range fields, or no sync word, aren't a frame.
+        let mut bits = frame_bits(timecode, MtcFrameRate::Fps25);
+        bits[3] = true;
+        assert_eq!(decode_frame_bits(&bits), None);
+        let mut bits = frame_bits(timecode, MtcFrameRate::Fps25);
+        bits[79] = false;
+        assert_eq!(decode_frame_bits(&bits), None);
+    }
+
+    #[test]
+    fn every_frame_starts_on_the_same_level() {
+        for frames in 0..30 {
+            let bits = frame_bits(Timecode::new(0, 0, 1, frames), MtcFrameRate::Fps30);
+            let levels = frame_levels(&bits);
+            // Ends low, so the next frame's first flip takes it high again.
+            assert!(levels[0]);
+            assert!(!levels[159]);
+        }
+    }
+
+    #[test]
+    fn renders_decodable_timecode_from_the_start() {
+        for rate in [
+            MtcFrameRate::Fps24,
+            MtcFrameRate::Fps25,
+            MtcFrameRate::Fps2997DropFrame,
+            MtcFrameRate::Fps30,
+        ] {
+            let start = Timecode::new(1, 0, 0, 0);
+            let mut source =
+                LtcSource::new(rate, start, RATE, Duration::ZERO, Duration::from_secs(1));
+            let rendered = render(&mut source);
+            assert_eq!(rendered.len(), RATE as usize);
+            let frames = decode(&rendered, rate);
+            assert_eq!(frames[0], start, "{rate:?}");
+            for (i, frame) in frames.iter().enumerate() {
+                assert_eq!(
+                    *frame,
+                    from_frame_count(to_frame_count(start, rate) + i as u64, rate),
+                    "{rate:?}"
+                );
+            }
+            assert!(frames.len() >= rate.frames_per_second() as usize - 1);
+        }
+    }
+
+    #[test]
+    fn seeking_renders_the_same_samples() {
+        let rate = MtcFrameRate::Fps2997DropFrame;
+        let start = Timecode::new(0, 9, 59, 0);
+        let duration = Duration::from_secs(3);
+        let mut from_top = LtcSource::new(rate, start, RATE, Duration::ZERO, duration);
+        let whole = render(&mut from_top);
+
+        // Starting partway — as a seek or a section loop does — picks up on
+        // the exact sample, even mid-bit.
+        let offset = Duration::from_micros(1_234_567);
+        let mut seeked = LtcSource::new(rate, start, RATE, offset, duration);
+        let tail = render(&mut seeked);
+        let skip = (offset.as_secs_f64() * RATE as f64).round() as usize;
+        assert_eq!(tail.len(), whole.len() - skip);
+        assert_eq!(tail, whole[skip..]);
+    }
+
+    #[test]
+    fn half_speed_stretches_the_same_timecode() {
+        let rate = MtcFrameRate::Fps25;
+        let start = Timecode::new(0, 1, 0, 0);
+        let offset = Duration::from_millis(500);
+        let duration = Duration::from_secs(2);
+        let normal = render(&mut LtcSource::new(rate, start, RATE, offset, duration));
+        let slow = render(&mut LtcSource::new(rate, start, RATE, offset, duration).with_speed(0.5));
+        assert_eq!(slow.len(), 2 * normal.len());
+        let every_other: Vec<f32> = slow.iter().step_by(2).copied().collect();
+        assert_eq!(every_other, normal);
+    }
+
+    #[test]
+    fn crosses_the_drop_frame_minute() {
+        let rate = MtcFrameRate::Fps2997DropFrame;
+        let mut source = LtcSource::new(
+            rate,
+            Timecode::new(0, 0, 59, 28),
+            RATE,
+            Duration::ZERO,
+            Duration::from_millis(200),
+        );
+        let frames = decode(&render(&mut source), rate);
+        assert_eq!(frames[0], Timecode::new(0, 0, 59, 28));
+        assert_eq!(frames[1], Timecode::new(0, 0, 59, 29));
+        // Frames 00 and 01 of minute one don't exist.
+        assert_eq!(frames[2], Timecode::new(0, 1, 0, 2));
+    }
+}
@@ -0,0 +1,288 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+//! Lock-free peak/RMS/clip meters for output channels and tracks.
+//!
+//! The audio callback accumulates levels for a batch in a plain
+//! [`LevelAccumulator`] and folds it into the shared meter once per batch, so
+//! the per-sample cost is a few float ops and the atomics are touched once
+//! per callback. A reader takes the levels on its own schedule: peak and RMS
+//! cover the span since the previous read, while clip counts keep running so
+//! a reader that misses an update still sees that something clipped.
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+
+/// Levels at or below this are reported as this value, so silence has a
+/// finite reading.
+pub const METER_FLOOR_DB: f32 = -100.0;
+
+/// Converts a linear level to dBFS, floored at `METER_FLOOR_DB`.
+pub fn to_db(linear: f32) -> f32 {
+    if linear <= 0.0 {
+        return METER_FLOOR_DB;
+    }
+    (20.0 * linear.log10()).max(METER_FLOOR_DB)
+}
+
+/// Levels for one batch of samples, accumulated by the audio callback
+/// without touching shared state.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LevelAccumulator {
+    peak: f32,
+    sum_squares: f64,
+    samples: u64,
+    clips: u64,
+}
+
+impl LevelAccumulator {
+    /// Adds one sample. Anything past full scale counts as a clip; a
+    /// non-finite sample counts as a clip and is otherwise ignored.
+    #[inline]
+    pub fn add(&mut self, sample: f32) {
+        let magnitude = sample.abs();
+        if !magnitude.is_finite() {
+            self.clips += 1;
+            return;
+        }
+        if magnitude > self.peak {
+            self.peak = magnitude;
+        }
+        self.sum_squares += f64::from(sample * sample);
+        self.samples += 1;
+        if magnitude > 1.0 {
+            self.clips += 1;
+        }
+    }
+}
+
+/// One meter's shared state.
+#[derive(Default)]
+struct Meter {
+    /// Peak magnitude as `f32` bits. Non-negative floats order the same as
+    /// their bits, so `fetch_max` keeps the peak without a CAS loop.
+    peak_bits: AtomicU32,
+    /// Sum of squares as `f64` bits.
+    sum_squares_bits: AtomicU64,
+    samples: AtomicU64,
+    clips: AtomicU64,
+}
+
+impl Meter {
+    fn record(&self, levels: &LevelAccumulator) {
+        if levels.clips > 0 {
+            self.clips.fetch_add(levels.clips, Ordering::Relaxed);
+        }
+        if levels.samples == 0 {
+            return;
+        }
+        self.peak_bits
+            .fetch_max(levels.peak.to_bits(), Ordering::Relaxed);
+        let _ = self
+            .sum_squares_bits
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |bits| {
+                Some((f64::from_bits(bits) + levels.sum_squares).to_bits())
+            });
+        self.samples.fetch_add(levels.samples, Ordering::Relaxed);
+    }
+
+    /// Reads and resets the peak and RMS. The fields are swapped one at a
+    /// time, so a batch recorded mid-read may split across two readings;
+    /// for a meter that is harmless.
+    fn take(&self) -> MeterLevel {
+        let peak = f32::from_bits(self.peak_bits.swap(0, Ordering::Relaxed));
+        let sum_squares = f64::from_bits(self.sum_squares_bits.swap(0, Ordering::Relaxed));
+        let samples = self.samples.swap(0, Ordering::Relaxed);
+        let rms = if samples > 0 {
+            (sum_squares / samples as f64).sqrt() as f32
+        } else {
+            0.0
+        };
+        MeterLevel {
+            peak_db: to_db(peak),
+            rms_db: to_db(rms),
+            clips: self.clips.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// One meter reading.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MeterLevel {
+    /// Peak level since the previous reading, in dBFS.
+    pub peak_db: f32,
+    /// RMS level since the previous reading, in dBFS.
+    pub rms_db: f32,
+    /// Samples past full scale since the meters were built.
+    pub clips: u64,
+}
+
+/// A reading of every meter.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MeterSnapshot {
+    /// Output channels, in channel order (index 0 is channel 1).
+    pub channels: Vec<MeterLevel>,
+    /// Tracks as (name, level) pairs, in `TrackGains` slot order.
+    pub tracks: Vec<(String, MeterLevel)>,
+}
+
+/// Shared meters for every output channel and every track.
+///
+/// Built at hardware init alongside `TrackGains`, whose slots index the
+/// track meters, and installed into the mixer.
+pub struct Meters {
+    channels: Vec<Meter>,
+    tracks: Vec<Meter>,
+    track_names: Vec<String>,
+}
+
+impl Meters {
+    /// Creates meters for `num_channels` output channels and the given
+    /// tracks, in slot order.
+    pub fn new(num_channels: u16, track_names: &[String]) -> Self {
+        Self {
+            channels: (0..num_channels).map(|_| Meter::default()).collect(),
+            tracks: track_names.iter().map(|_| Meter::default()).collect(),
+            track_names: track_names.to_vec(),
+        }
+    }
+
+    /// Number of track meters.
+    pub fn num_tracks(&self) -> usize {
+        self.tracks.len()
+    }
+
+    /// Folds a batch into a track's meter. Unknown slots are ignored.
+    pub fn record_track(&self, slot: usize, levels: &LevelAccumulator) {
+        if let Some(meter) = self.tracks.get(slot) {
+            meter.record(levels);
+        }
+    }
+
+    /// Meters an interleaved output buffer, one accumulator per channel.
+    pub fn record_output(&self, output: &[f32], num_channels: usize) {
+        if num_channels == 0 {
+            return;
+        }
+        for (channel, meter) in self.channels.iter().enumerate().take(num_channels) {
+            let mut levels = LevelAccumulator::default();
+            for &sample in output.iter().skip(channel).step_by(num_channels) {
+                levels.add(sample);
+            }
+            meter.record(&levels);
+        }
+    }
+
+    /// Reads every meter, resetting peak and RMS for the next reading.
+    pub fn snapshot(&self) -> MeterSnapshot {
+        MeterSnapshot {
+            channels: self.channels.iter().map(Meter::take).collect(),
+            tracks: self
+                .track_names
+                .iter()
+                .zip(&self.tracks)
+                .map(|(name, meter)| (name.clone(), meter.take()))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accumulate(samples: &[f32]) -> LevelAccumulator {
+        let mut levels = LevelAccumulator::default();
+        for &sample in samples {
+            levels.add(sample);
+        }
+        levels
+    }
+
+    #[test]
+    fn db_conversion() {
+        assert_eq!(to_db(1.0), 0.0);
+        assert!((to_db(0.5) - -6.0206).abs() < 1e-3);
+        assert_eq!(to_db(0.0), METER_FLOOR_DB);
+        assert_eq!(to_db(1e-9), METER_FLOOR_DB);
+    }
+
+    #[test]
+    fn silence_reads_the_floor() {
+        let meters = Meters::new(2, &["kick".to_string()]);
+        let snapshot = meters.snapshot();
+        assert_eq!(snapshot.channels.len(), 2);
+        for level in &snapshot.channels {
+            assert_eq!(level.peak_db, METER_FLOOR_DB);
+            assert_eq!(level.rms_db, METER_FLOOR_DB);
+            assert_eq!(level.clips, 0);
+        }
+        assert_eq!(snapshot.tracks[0].0, "kick");
+        assert_eq!(snapshot.tracks[0].1.peak_db, METER_FLOOR_DB);
+    }
+
+    #[test]
+    fn output_is_metered_per_channel() {
+        let meters = Meters::new(2, &[]);
+        // Channel 1 at 0.5 constant, channel 2 alternating +/-0.25.
+        let output: Vec<f32> = (0..64)
+            .flat_map(|i| [0.5, if i % 2 == 0 { 0.25 } else { -0.25 }])
+            .collect();
+        meters.record_output(&output, 2);
+        let snapshot = meters.snapshot();
+        let left = snapshot.channels[0];
+        let right = snapshot.channels[1];
+        assert!((left.peak_db - to_db(0.5)).abs() < 1e-4);
+        assert!((left.rms_db - to_db(0.5)).abs() < 1e-4);
+        assert!((right.peak_db - to_db(0.25)).abs() < 1e-4);
+        assert!((right.rms_db - to_db(0.25)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn reading_resets_levels_but_not_clips() {
+        let meters = Meters::new(1, &[]);
+        meters.record_output(&[1.5, 0.2, -2.0], 1);
+        let first = meters.snapshot().channels[0];
+        assert!(first.peak_db > 0.0);
+        assert_eq!(first.clips, 2);
+
+        let second = meters.snapshot().channels[0];
+        assert_eq!(second.peak_db, METER_FLOOR_DB);
+        assert_eq!(second.clips, 2);
+    }
+
+    #[test]
+    fn batches_combine_between_readings() {
+        let meters = Meters::new(0, &["a".to_string(), "b".to_string()]);
+        meters.record_track(1, &accumulate(&[0.1, 0.1]));
+        meters.record_track(1, &accumulate(&[0.8, 0.0]));
+        // Out of range slots are dropped rather than panicking.
+        meters.record_track(7, &accumulate(&[1.0]));
+        let snapshot = meters.snapshot();
+        let (name, level) = &snapshot.tracks[1];
+        assert_eq!(name, "b");
+        assert!((level.peak_db - to_db(0.8)).abs() < 1e-4);
+        let rms = ((0.01f32 + 0.01 + 0.64) / 4.0).sqrt();
+        assert!((level.rms_db - to_db(rms)).abs() < 1e-3);
+        assert_eq!(snapshot.tracks[0].1.peak_db, METER_FLOOR_DB);
+    }
+
+    #[test]
+    fn non_finite_samples_clip_without_poisoning_levels() {
+        let levels = accumulate(&[f32::NAN, f32::INFINITY, 0.5]);
+        let meters = Meters::new(0, &["a".to_string()]);
+        meters.record_track(0, &levels);
+        let level = meters.snapshot().tracks[0].1;
+        assert_eq!(level.clips, 2);
+        assert!((level.peak_db - to_db(0.5)).abs() < 1e-4);
+        assert!(level.rms_db.is_finite());
+    }
+}
@@ -12,8 +12,11 @@
 // this program. If not, see <https://www.gnu.org/licenses/>.
 //
 // Core audio mixing logic that can be used by both CPAL and test implementations
+use crate::audio::eq::EqChain;
+use crate::audio::limiter::Limiter;
+use crate::audio::meters::{LevelAccumulator, Meters};
 use crate::audio::sample_source::ChannelMappedSampleSource;
-use crate::audio::track_gains::TrackGains;
+use crate::audio::track_gains::{EdgePosition, TrackGains};
 use parking_lot::{Mutex, RwLock};
 use std::cell::RefCell;
 use std::collections::HashMap;
@@ -28,9 +31,13 @@ use tracing::debug;
 // Thread-local scratch buffers for process_into_output.
 // BATCH_READ_SCRATCH: 8192 samples covers 512 frames * 16 channels; resized if needed.
 // SOURCES_SCRATCH: reuses the Vec across callbacks to avoid per-callback heap allocation.
+// METER_SCRATCH: per-source track level accumulators, indexed like `SourceGain`.
+// EQ_SCRATCH: filtered samples per EQ lane, lane-major; resized if needed.
 thread_local! {
     static BATCH_READ_SCRATCH: RefCell<Vec<f32>> = RefCell::new(vec![0.0; 8192]);
     static SOURCES_SCRATCH: RefCell<Vec<Arc<Mutex<ActiveSource>>>> = const { RefCell::new(Vec::new()) };
+    static METER_SCRATCH: RefCell<Vec<LevelAccumulator>> = const { RefCell::new(Vec::new()) };
+    static EQ_SCRATCH: RefCell<Vec<f32>> = const { RefCell::new(Vec::new()) };
 }
 
 /// A source that has been through `prepare_source` and can be inserted by the
@@ -51,6 +58,14 @@ pub struct AudioMixer {
     /// Shared per-output-track gains. Read once per callback (no per-sample
     /// atomics); `None` until installed at hardware init (e.g. mock devices).
     track_gains: Arc<RwLock<Option<Arc<TrackGains>>>>,
+    /// Shared level meters, read once per callback like `track_gains`;
+    /// `None` until installed at hardware init.
+    meters: Arc<RwLock<Option<Arc<Meters>>>>,
+    /// Master gain and limiter applied to the finished mix; `None` until
+    /// installed at hardware init.
+    output_stage: Arc<Mutex<Option<OutputStage>>>,
+    /// Times any output channel's limiter engaged.
+    limiter_engagements: Arc<AtomicU64>,
     /// Performance monitoring (test only)
     #[cfg(test)]
     frame_count: Arc<AtomicUsize>,
@@ -75,23 +90,43 @@ pub struct OutputMapping {
     /// the mixer's constant fast path; not meaningful while a fade or gain
     /// ramp is active.
     pub cached_gain: f32,
+    /// Which filtered signal the edge carries: 0 for the raw source channel,
+    /// otherwise 1 + an index into `SourceGain::eq`.
+    pub lane: u32,
 }
 
 /// Per-source track gain state, owned by the audio callback.
 ///
-/// `slot_ids`, `cur`, and `inc` are parallel arrays indexed by
+/// `slot_ids`, `positions`, `cur`, and `inc` are parallel arrays indexed by
 /// `OutputMapping::gain_idx`. Entry 0 is a synthetic unity slot
-/// (`cur[0] == 1.0`, `inc[0] == 0.0`, `slot_ids[0]` unused). `cur` ramps
-/// linearly toward the target read from `TrackGains` once per callback batch,
-/// avoiding both per-sample atomic loads and zipper noise.
+/// (`cur[0] == 1.0`, `inc[0] == 0.0`, `slot_ids[0]` unused). There is one
+/// entry per distinct (track, edge position), since pan, polarity and width
+/// give a track's edges different gains. `cur` ramps linearly toward the
+/// target read from `TrackGains` once per callback batch, avoiding both
+/// per-sample atomic loads and zipper noise. `eq` holds the filter state for
+/// each (source channel, track) with an EQ chain.
 #[derive(Default)]
 pub struct SourceGain {
     /// Local gain table index -> global `TrackGains` slot.
     pub slot_ids: Vec<usize>,
+    /// Local gain table index -> where its edges sit within the track.
+    pub positions: Vec<EdgePosition>,
     /// Current linear gain per table entry.
     pub cur: Vec<f32>,
     /// Per-frame linear gain increment for the current batch.
     pub inc: Vec<f32>,
+    /// EQ lanes, indexed by `OutputMapping::lane - 1`.
+    pub eq: Vec<EqLane>,
+}
+
+/// One source channel run through one track's EQ chain.
+pub struct EqLane {
+    /// The source channel filtered.
+    pub source_channel: usize,
+    /// The track's `TrackGains` slot, where its EQ parameters live.
+    pub slot: usize,
+    /// Filter state, owned by the audio callback.
+    pub chain: EqChain,
 }
 
 /// Represents an active audio source in the mixer
@@ -124,6 +159,11 @@ pub struct ActiveSource {
     /// Used for crossfading during loop transitions and song-to-song transitions.
     /// When a fade-out envelope completes (end_gain = 0), the source is auto-finished.
     pub gain_envelope: Option<Arc<crate::audio::crossfade::GainEnvelope>>,
+    /// The song clock this source follows, if any. While that clock is
+    /// paused the source is parked: it is ramped out ahead of the hold, not
+    /// read at all while held, and ramped back in on resume. Samples and
+    /// notifications leave this as None so they keep sounding over a pause.
+    pub transport: Option<crate::clock::PlaybackClock>,
 }
 
 impl AudioMixer {
@@ -135,6 +175,9 @@ impl AudioMixer {
             sample_rate,
             sample_counter: Arc::new(AtomicU64::new(0)),
             track_gains: Arc::new(RwLock::new(None)),
+            meters: Arc::new(RwLock::new(None)),
+            output_stage: Arc::new(Mutex::new(None)),
+            limiter_engagements: Arc::new(AtomicU64::new(0)),
             #[cfg(test)]
             frame_count: Arc::new(AtomicUsize::new(0)),
             #[cfg(test)]
@@ -161,13 +204,29 @@ impl AudioMixer {
         *self.track_gains.write() = Some(track_gains);
     }
 
+    /// Installs the shared level meters. Track meters are indexed by
+    /// `TrackGains` slot, so these should be built from the installed gains.
+    pub fn set_meters(&self, meters: Arc<Meters>) {
+        *self.meters.write() = Some(meters);
+    }
+
+    /// Installs the master gain and limiter applied to the finished mix.
+    pub fn set_output_stage(&self, output_stage: OutputStage) {
+        *self.output_stage.lock() = Some(output_stage);
+    }
+
+    /// Returns how many times an output channel's limiter has engaged.
+    pub fn limiter_engagements(&self) -> u64 {
+        self.limiter_engagements.load(Ordering::Relaxed)
+    }
+
     /// Precomputes channel mappings for optimal performance during mixing.
     ///
     /// Also builds the source's local gain table: one entry per distinct
-    /// track gain slot referenced by this source's labels, with entry 0 as
-    /// the synthetic unity slot. The current gain is initialized from the
-    /// target so sources start at the set gain with no ramp (this is how
-    /// gains set while stopped apply to the next song).
+    /// track gain slot and edge position referenced by this source's labels,
+    /// with entry 0 as the synthetic unity slot. The current gain is
+    /// initialized from the target so sources start at the set gain with no
+    /// ramp (this is how gains set while stopped apply to the next song).
     fn precompute_channel_mappings(
         source: &dyn ChannelMappedSampleSource,
         track_mappings: &HashMap<String, Vec<u16>>,
@@ -177,39 +236,91 @@ impl AudioMixer {
         let mut channel_mappings = Vec::with_capacity(source_channel_count);
         let mut gain = SourceGain {
             slot_ids: vec![usize::MAX],
+            positions: vec![EdgePosition::default()],
             cur: vec![1.0],
             inc: vec![0.0],
+            eq: Vec::new(),
         };
-        // Dedup: global TrackGains slot -> local gain table index.
-        let mut local_by_slot: HashMap<usize, u32> = HashMap::new();
+        // Dedup: (global TrackGains slot, edge position) -> local gain table index.
+        let mut local_by_slot: HashMap<(usize, EdgePosition), u32> = HashMap::new();
+        // Which source channels carry each label, so a stereo track knows
+        // which side each of its channels is.
+        let mut channels_by_label: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (source_channel, labels) in source.channel_mappings().iter().enumerate() {
+            for label in labels {
+                channels_by_label
+                    .entry(label.as_str())
+                    .or_default()
+                    .push(source_channel);
+            }
+        }
 
+        let mixes = track_gains.map_or(&[][..], |tg| tg.mixes());
         for source_channel in 0..source_channel_count {
             let mut output_mappings = Vec::new();
 
             // Get the labels for this source channel
             if let Some(labels) = source.channel_mappings().get(source_channel) {
-                // For each label, find the corresponding output channels
                 for label in labels {
-                    if let Some(track_channels) = track_mappings.get(label) {
-                        let gain_idx = match track_gains.and_then(|tg| tg.slot(label)) {
-                            Some(slot) => *local_by_slot.entry(slot).or_insert_with(|| {
-                                let idx = gain.slot_ids.len() as u32;
-                                let target =
-                                    track_gains.expect("slot implies track_gains").linear(slot);
-                                gain.slot_ids.push(slot);
-                                gain.cur.push(target);
-                                gain.inc.push(0.0);
-                                idx
-                            }),
-                            None => 0,
-                        };
+                    let label_channels = &channels_by_label[label.as_str()];
+                    let source_position = label_channels
+                        .iter()
+                        .position(|&ch| ch == source_channel)
+                        .unwrap_or(0);
+                    // Tracks with an EQ chain get a lane of their own, shared
+                    // by the main routing and every mix.
+                    let main_slot = track_gains.and_then(|tg| tg.slot(label));
+                    let lane = match (track_gains, main_slot) {
+                        (Some(tg), Some(slot)) if tg.eq_len(slot) > 0 => {
+                            gain.eq.push(EqLane {
+                                source_channel,
+                                slot,
+                                chain: EqChain::new(tg.eq_len(slot)),
+                            });
+                            gain.eq.len() as u32
+                        }
+                        _ => 0,
+                    };
+                    // The main routing, then each monitor mix the track is in.
+                    let routes = track_mappings
+                        .get(label)
+                        .map(|channels| (main_slot, channels))
+                        .into_iter()
+                        .chain(mixes.iter().enumerate().filter_map(|(mix, route)| {
+                            let slot = track_gains?.mix_slot(mix, label)?;
+                            Some((Some(slot), &route.channels))
+                        }));
+                    for (slot, track_channels) in routes {
                         // Convert 1-indexed track channels to 0-indexed output indices
-                        for &track_channel in track_channels {
+                        for (output_position, &track_channel) in track_channels.iter().enumerate() {
+                            let position = EdgePosition {
+                                source: source_position as u16,
+                                sources: label_channels.len() as u16,
+                                output: output_position as u16,
+                                outputs: track_channels.len() as u16,
+                            };
+                            let gain_idx = match slot {
+                                Some(slot) => {
+                                    *local_by_slot.entry((slot, position)).or_insert_with(|| {
+                                        let idx = gain.slot_ids.len() as u32;
+                                        let target = track_gains
+                                            .expect("slot implies track_gains")
+                                            .edge_gain(slot, position);
+                                        gain.slot_ids.push(slot);
+                                        gain.positions.push(position);
+                                        gain.cur.push(target);
+                                        gain.inc.push(0.0);
+                                        idx
+                                    })
+                                }
+                                None => 0,
+                            };
                             let output_index = (track_channel - 1) as usize;
                             output_mappings.push(OutputMapping {
                                 output_index,
                                 gain_idx,
                                 cached_gain: gain.cur[gain_idx as usize],
+                                lane,
                             });
                         }
                     }
@@ -284,6 +395,23 @@ impl AudioMixer {
         }
     }
 
+    /// Returns the IDs of the active sources following the given transport:
+    /// the song sources of one playback.
+    pub fn source_ids_following(&self, clock: &crate::clock::PlaybackClock) -> Vec<u64> {
+        let sources = self.active_sources.read();
+        sources
+            .iter()
+            .filter_map(|source_arc| {
+                let source = source_arc.lock();
+                source
+                    .transport
+                    .as_ref()
+                    .is_some_and(|t| t.is_same(clock))
+                    .then_some(source.id)
+            })
+            .collect()
+    }
+
     /// Sets a gain envelope on all active sources matching the given IDs.
     /// Each source gets its own envelope instance so they don't share the
     /// atomic position counter (which would cause the fade to advance
@@ -352,7 +480,10 @@ impl AudioMixer {
             // frame-level assertions stay simple.
             if let Some(tg) = self.track_gains.read().as_deref() {
                 for i in 1..active_source.gain.slot_ids.len() {
-                    let target = tg.linear(active_source.gain.slot_ids[i]);
+                    let target = tg.edge_gain(
+                        active_source.gain.slot_ids[i],
+                        active_source.gain.positions[i],
+                    );
                     active_source.gain.cur[i] = target;
                 }
             }
@@ -466,10 +597,12 @@ impl AudioMixer {
         }
 
         let mut finished_source_ids: Vec<u64> = Vec::new();
+        let mut eq_scratch = EQ_SCRATCH.with(|cell| std::mem::take(&mut *cell.borrow_mut()));
 
         // Snapshot the shared track gains once per callback. Individual gain
         // targets are read once per source below — never per sample.
         let track_gains = self.track_gains.read().clone();
+        let meters = self.meters.read().clone();
 
         // Process each active source across all frames
         for active_source_arc in sources_to_process.iter() {
@@ -499,10 +632,34 @@ impl AudioMixer {
                 continue;
             }
 
+            // Sources following a paused transport hold in place: no read, so
+            // the source's position stays exactly where the hold landed. This
+            // comes before any scheduling, which is measured in transport
+            // samples and so stands still while held.
+            let hold = active_source
+                .transport
+                .as_ref()
+                .map(|t| t.hold_window())
+                .unwrap_or_default();
+            if hold.hold_at.is_some_and(|h| current_sample >= h) {
+                // A held source is already silent, so a stop or seek can drop
+                // it without waiting on a fade that would never advance.
+                if active_source.cancel_handle.is_cancelled() {
+                    active_source.is_finished.store(true, Ordering::Relaxed);
+                    finished_source_ids.push(active_source.id);
+                }
+                continue;
+            }
+            // Scheduled samples (start, cancel, envelope anchors) are in the
+            // transport's frame: the mixer sample less any time spent held.
+            // Sources without a transport see the two as the same.
+            let transport_sample = hold.transport_sample(current_sample);
+            let transport_end_sample = transport_sample + num_frames as u64;
+
             // Check if this source has a scheduled cancellation time
             if let Some(ref cancel_at) = active_source.cancel_at_sample {
                 let cancel_sample = cancel_at.load(Ordering::Relaxed);
-                if cancel_sample > 0 && current_sample >= cancel_sample {
+                if cancel_sample > 0 && transport_sample >= cancel_sample {
                     debug!(
                         source_id = active_source.id,
                         cancel_sample,
@@ -517,13 +674,13 @@ impl AudioMixer {
 
             // Check if this source should start playing yet (fixed-latency scheduling)
             let start_frame = if let Some(start_at) = active_source.start_at_sample {
-                if start_at >= buffer_end_sample {
+                if start_at >= transport_end_sample {
                     // Source hasn't reached its start time yet, skip entirely
                     continue;
                 }
                 // Calculate which frame in this buffer to start at
-                if start_at > current_sample {
-                    (start_at - current_sample) as usize
+                if start_at > transport_sample {
+                    (start_at - transport_sample) as usize
                 } else {
                     0 // Start time already passed, play from beginning of buffer
                 }
@@ -535,17 +692,22 @@ impl AudioMixer {
             let end_frame = if let Some(ref cancel_at) = active_source.cancel_at_sample {
                 let cancel_sample = cancel_at.load(Ordering::Relaxed);
                 if cancel_sample > 0
-                    && cancel_sample > current_sample
-                    && cancel_sample < buffer_end_sample
+                    && cancel_sample > transport_sample
+                    && cancel_sample < transport_end_sample
                 {
                     // Source should stop partway through this buffer
-                    (cancel_sample - current_sample) as usize
+                    (cancel_sample - transport_sample) as usize
                 } else {
                     num_frames
                 }
             } else {
                 num_frames
             };
+            // A hold landing inside this buffer ends the source's batch there.
+            let end_frame = match hold.hold_at {
+                Some(h) if h < buffer_end_sample => end_frame.min((h - current_sample) as usize),
+                _ => end_frame,
+            };
 
             // cancel_at_sample can be set by another thread to a value before
             // start_at_sample, making end_frame < start_frame. Skip gracefully.
@@ -564,7 +726,10 @@ impl AudioMixer {
             // avoids zipper noise without per-frame atomics.
             if let Some(ref tg) = track_gains {
                 for i in 1..active_source.gain.slot_ids.len() {
-                    let target = tg.linear(active_source.gain.slot_ids[i]);
+                    let target = tg.edge_gain(
+                        active_source.gain.slot_ids[i],
+                        active_source.gain.positions[i],
+                    );
                     let cur = active_source.gain.cur[i];
                     if (target - cur).abs() < 1e-6 {
                         active_source.gain.cur[i] = target;
@@ -590,6 +755,32 @@ impl AudioMixer {
 
                 match active_source.source.read_frames(batch_buf, frames_needed) {
                     Ok(frames_got) => {
+                        // Run each EQ lane over its source channel once,
+                        // ahead of routing. Coefficients ramp toward any new
+                        // settings across the batch, like track gains.
+                        let lanes = active_source.gain.eq.len();
+                        if eq_scratch.len() < lanes * frames_got {
+                            eq_scratch.resize(lanes * frames_got, 0.0);
+                        }
+                        if let Some(ref tg) = track_gains {
+                            let read = &batch_buf[..frames_got * source_channel_count];
+                            for (lane, eq) in active_source.gain.eq.iter_mut().enumerate() {
+                                let slot = eq.slot;
+                                eq.chain.retarget(
+                                    tg.eq_generation(slot),
+                                    |band| tg.eq_band(slot, band),
+                                    self.sample_rate,
+                                    frames_needed,
+                                );
+                                eq.chain.process(
+                                    read,
+                                    source_channel_count,
+                                    eq.source_channel,
+                                    &mut eq_scratch[lane * frames_got..(lane + 1) * frames_got],
+                                );
+                            }
+                        }
+                        let eq_buf = &eq_scratch[..lanes * frames_got];
                         // Gain handling. The default crossfade (~240 samples at
                         // 5ms / 48k) is much shorter than a typical device
                         // buffer (1024 frames), so a single once-per-batch gain
@@ -609,8 +800,13 @@ impl AudioMixer {
                         // constant fast path below.
                         let track_ramp_active =
                             active_source.gain.inc.iter().any(|&inc| inc != 0.0);
+                        // Ramping out ahead of a pause or back in after a resume.
+                        let hold_ramp_active = hold.is_ramping(
+                            current_sample + start_frame as u64,
+                            current_sample + end_frame as u64,
+                        );
 
-                        if !fade_active && !track_ramp_active {
+                        if !fade_active && !track_ramp_active && !hold_ramp_active {
                             // Fast path: all gains constant across this batch.
                             // Fold envelope constant and track gain into each
                             // mapping edge once, leaving the inner loop a
@@ -635,6 +831,13 @@ impl AudioMixer {
                                     {
                                         for m in output_mappings {
                                             if m.output_index < channels {
+                                                let sample = match m.lane {
+                                                    0 => sample,
+                                                    lane => {
+                                                        eq_buf[(lane as usize - 1) * frames_got
+                                                            + frame_idx]
+                                                    }
+                                                };
                                                 output[dst_base + m.output_index] +=
                                                     sample * m.cached_gain;
                                             }
@@ -644,6 +847,8 @@ impl AudioMixer {
                             }
                         } else {
                             for frame_idx in 0..frames_got {
+                                let offset = (start_frame + frame_idx) as u64;
+                                let global = current_sample + offset;
                                 let gain = if fade_active {
                                     // `advance_at` consumes one frame: for a
                                     // sample-anchored envelope it maps the frame's
@@ -651,11 +856,15 @@ impl AudioMixer {
                                     // one it simply steps the position. Either way the
                                     // ramp tracks the actual audio sample-for-sample.
                                     let env = envelope.as_ref().expect("fade_active implies Some");
-                                    let global = current_sample + (start_frame + frame_idx) as u64;
-                                    env.advance_at(global, 1)
+                                    env.advance_at(transport_sample + offset, 1)
                                 } else {
                                     constant_gain
                                 };
+                                let gain = if hold_ramp_active {
+                                    gain * hold.gain_at(global)
+                                } else {
+                                    gain
+                                };
                                 let src_offset = frame_idx * source_channel_count;
                                 let dst_base = (start_frame + frame_idx) * channels;
                                 let frame_f = frame_idx as f32;
@@ -675,6 +884,13 @@ impl AudioMixer {
                                                 let idx = m.gain_idx as usize;
                                                 let track_gain = active_source.gain.cur[idx]
                                                     + active_source.gain.inc[idx] * frame_f;
+                                                let sample = match m.lane {
+                                                    0 => sample,
+                                                    lane => {
+                                                        eq_buf[(lane as usize - 1) * frames_got
+                                                            + frame_idx]
+                                                    }
+                                                };
                                                 output[dst_base + m.output_index] +=
                                                     sample * gain * track_gain;
                                             }
@@ -693,6 +909,25 @@ impl AudioMixer {
                             active_source.gain.cur[i] = advanced;
                         }
 
+                        if let Some(ref meters) = meters {
+                            // Meter at the gain the batch ended on; only a
+                            // fade or ramp makes that differ within it.
+                            let mut batch_gain = match envelope {
+                                Some(ref env) if fade_active => env.gain_at(env.position()),
+                                _ => constant_gain,
+                            };
+                            if hold_ramp_active {
+                                batch_gain *= hold.gain_at(current_sample + end_frame as u64);
+                            }
+                            meter_source_batch(
+                                meters,
+                                &active_source,
+                                &batch_buf[..frames_got * source_channel_count],
+                                eq_buf,
+                                batch_gain,
+                            );
+                        }
+
                         // Auto-finish sources whose fade-out envelope has completed.
                         if let Some(ref env) = active_source.gain_envelope {
                             if env.is_finished() && env.end_gain() == 0.0 {
@@ -733,6 +968,18 @@ impl AudioMixer {
             });
         }
 
+        if let Some(ref mut stage) = *self.output_stage.lock() {
+            let engagements = stage.process(output, channels);
+            if engagements > 0 {
+                self.limiter_engagements
+                    .fetch_add(engagements, Ordering::Relaxed);
+            }
+        }
+
+        if let Some(ref meters) = meters {
+            meters.record_output(output, channels);
+        }
+
         // Increment the sample counter
         self.sample_counter
             .fetch_add(num_frames as u64, Ordering::Relaxed);
@@ -747,11 +994,14 @@ impl AudioMixer {
             self.remove_sources(&finished_source_ids);
         }
 
-        // Put back the scratch Vec for reuse (drop Arc refs first)
+        // Put back the scratch Vecs for reuse (drop Arc refs first)
         sources_to_process.clear();
         SOURCES_SCRATCH.with(|cell| {
             *cell.borrow_mut() = sources_to_process;
         });
+        EQ_SCRATCH.with(|cell| {
+            *cell.borrow_mut() = eq_scratch;
+        });
     }
 
     /// Gets the number of output channels
@@ -770,6 +1020,106 @@ impl AudioMixer {
     }
 }
 
+/// Per-output-channel master gain and an optional limiter, applied to the
+/// finished mix before it is metered and handed to the device.
+pub struct OutputStage {
+    /// Linear gain per output channel, in channel order. Channels past the
+    /// end are left at unity.
+    gains: Vec<f32>,
+    limiter: Option<Limiter>,
+}
+
+impl OutputStage {
+    pub fn new(gains: Vec<f32>, limiter: Option<Limiter>) -> Self {
+        Self { gains, limiter }
+    }
+
+    /// Applies the stage to an interleaved buffer, returning the number of
+    /// limiter engagements.
+    fn process(&mut self, output: &mut [f32], num_channels: usize) -> u64 {
+        if num_channels == 0 {
+            return 0;
+        }
+        if self.gains.iter().any(|&gain| gain != 1.0) {
+            for frame in output.chunks_exact_mut(num_channels) {
+                for (sample, gain) in frame.iter_mut().zip(&self.gains) {
+                    *sample *= gain;
+                }
+            }
+        }
+        self.limiter
+            .as_mut()
+            .map_or(0, |limiter| limiter.process(output, num_channels))
+    }
+}
+
+/// Meters one mixed source batch into the meters of the tracks it feeds,
+/// each sample scaled by `batch_gain` and the track's current gain. A source
+/// channel reaching one track on several outputs is counted once, at the
+/// loudest of them, so a panned track reads its level on the near side.
+/// Tracks with an EQ are metered after it, from `eq` (laid out as in the
+/// mixer's EQ scratch).
+fn meter_source_batch(
+    meters: &Meters,
+    source: &ActiveSource,
+    batch: &[f32],
+    eq: &[f32],
+    batch_gain: f32,
+) {
+    let gain = &source.gain;
+    let source_channels = source.cached_source_channel_count as usize;
+    if gain.slot_ids.len() <= 1 || source_channels == 0 {
+        return;
+    }
+    METER_SCRATCH.with(|cell| {
+        let mut levels = cell.borrow_mut();
+        levels.clear();
+        levels.resize(meters.num_tracks(), LevelAccumulator::default());
+        let mut add = |slot: usize, sample: f32| {
+            if let Some(level) = levels.get_mut(slot) {
+                level.add(sample);
+            }
+        };
+        let frames = batch.len() / source_channels;
+        for (frame_idx, frame) in batch.chunks_exact(source_channels).enumerate() {
+            for (&raw, mappings) in frame.iter().zip(&source.channel_mappings) {
+                // A label's edges are contiguous: (slot, loudest gain so far,
+                // the sample they carry).
+                let mut pending: Option<(usize, f32, f32)> = None;
+                for m in mappings {
+                    let idx = m.gain_idx as usize;
+                    if idx == 0 {
+                        continue;
+                    }
+                    let slot = gain.slot_ids[idx];
+                    let edge_gain = gain.cur[idx].abs();
+                    let sample = match m.lane {
+                        0 => raw,
+                        lane => eq[(lane as usize - 1) * frames + frame_idx],
+                    };
+                    match pending {
+                        Some((pending_slot, ref mut loudest, _)) if pending_slot == slot => {
+                            *loudest = loudest.max(edge_gain);
+                        }
+                        _ => {
+                            if let Some((pending_slot, loudest, sample)) = pending {
+                                add(pending_slot, sample * batch_gain * loudest);
+                            }
+                            pending = Some((slot, edge_gain, sample));
+                        }
+                    }
+                }
+                if let Some((pending_slot, loudest, sample)) = pending {
+                    add(pending_slot, sample * batch_gain * loudest);
+                }
+            }
+        }
+        for (slot, level) in levels.iter().enumerate() {
+            meters.record_track(slot, level);
+        }
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -811,6 +1161,7 @@ mod tests {
             cancel_at_sample: None,
             gain: Default::default(),
             gain_envelope: None,
+            transport: None,
         }
     }
 
@@ -1741,6 +2092,226 @@ mod tests {
             mixer.process_into_output(&mut out, 8);
             assert!(out.iter().all(|&s| (s - 1.0).abs() < 1e-6));
         }
+
+        #[test]
+        fn pan_and_polarity_place_mono_track() {
+            let mixer = AudioMixer::new(2, 48000);
+            let mappings: HashMap<String, Vec<u16>> =
+                HashMap::from([("t".to_string(), vec![1, 2])]);
+            let tg = install_gains(&mixer, &mappings);
+            tg.set_pan("t", -1.0).unwrap();
+            tg.set_inverted("t", true).unwrap();
+
+            mixer.add_source(make_active_source(1, ones_source(4), mappings));
+            let mut out = vec![0.0f32; 8];
+            mixer.process_into_output(&mut out, 4);
+            for frame in out.chunks(2) {
+                assert!(
+                    (frame[0] + 1.0).abs() < 1e-6,
+                    "left inverted, got {frame:?}"
+                );
+                assert_eq!(frame[1], 0.0, "right panned out");
+            }
+        }
+
+        #[test]
+        fn monitor_mix_renders_its_own_blend() {
+            use crate::config::MonitorMix;
+            use indexmap::IndexMap;
+
+            let mixer = AudioMixer::new(3, 48000);
+            let mappings = t_mappings();
+            let tg = Arc::new(TrackGains::from_config(&mappings, None).with_monitor_mixes(
+                &IndexMap::from([(
+                    "drummer".to_string(),
+                    MonitorMix::new(
+                        vec![2, 3],
+                        IndexMap::from([("t".to_string(), -6.0)]),
+                        Vec::new(),
+                    ),
+                )]),
+            ));
+            mixer.set_track_gains(tg.clone());
+
+            mixer.add_source(make_active_source(1, ones_source(12), mappings));
+            let mut out = vec![0.0f32; 12];
+            mixer.process_into_output(&mut out, 4);
+            for frame in out.chunks(3) {
+                assert!((frame[0] - 1.0).abs() < 1e-6, "main at unity: {frame:?}");
+                assert!((frame[1] - 0.5012).abs() < 1e-3, "mix left: {frame:?}");
+                assert!((frame[2] - 0.5012).abs() < 1e-3, "mix right: {frame:?}");
+            }
+
+            // Muting the track in the mix leaves the main routing alone. The
+            // mute ramps out over one batch.
+            tg.set_mix_muted(0, "t", true).unwrap();
+            let mut out = vec![0.0f32; 12];
+            mixer.process_into_output(&mut out, 4);
+            mixer.process_into_output(&mut out, 4);
+            for frame in out.chunks(3) {
+                assert!((frame[0] - 1.0).abs() < 1e-6);
+                assert_eq!(&frame[1..], &[0.0, 0.0]);
+            }
+        }
+
+        #[test]
+        fn eq_filters_only_its_track() {
+            use crate::config::{EqBand, EqBandKind};
+            use indexmap::IndexMap;
+
+            let mixer = AudioMixer::new(2, 48000);
+            let mappings: HashMap<String, Vec<u16>> =
+                HashMap::from([("bgv".to_string(), vec![1]), ("click".to_string(), vec![2])]);
+            let tg = Arc::new(
+                TrackGains::from_config(&mappings, None).with_eq(&IndexMap::from([(
+                    "bgv".to_string(),
+                    vec![EqBand::new(EqBandKind::HighPass, 200.0, 0.0, None)],
+                )])),
+            );
+            mixer.set_track_gains(tg.clone());
+
+            // DC on both channels: the high-pass drains it from `bgv` only.
+            let frames = 4800;
+            let source = create_test_source(
+                vec![1.0; frames * 2],
+                2,
+                vec![vec!["bgv".to_string()], vec!["click".to_string()]],
+            );
+            mixer.add_source(make_active_source(1, source, mappings));
+            let mut out = vec![0.0f32; 2 * 480];
+            for _ in 0..frames / 480 {
+                mixer.process_into_output(&mut out, 480);
+            }
+            let last = &out[out.len() - 2..];
+            assert!(last[0].abs() < 1e-3, "DC should be filtered, got {last:?}");
+            assert!(
+                (last[1] - 1.0).abs() < 1e-6,
+                "click untouched, got {last:?}"
+            );
+        }
+
+        #[test]
+        fn zero_width_folds_stereo_track_to_mono() {
+            let mixer = AudioMixer::new(2, 48000);
+            let mappings: HashMap<String, Vec<u16>> =
+                HashMap::from([("st".to_string(), vec![1, 2])]);
+            let tg = install_gains(&mixer, &mappings);
+            tg.set_width("st", 0.0).unwrap();
+
+            // Left carries signal, right is silent.
+            let source = create_test_source(
+                vec![1.0, 0.0, 1.0, 0.0],
+                2,
+                vec![vec!["st".to_string()], vec!["st".to_string()]],
+            );
+            mixer.add_source(make_active_source(1, source, mappings));
+            let mut out = vec![0.0f32; 4];
+            mixer.process_into_output(&mut out, 2);
+            for s in out {
+                assert!((s - 0.5).abs() < 1e-6, "expected centred 0.5, got {s}");
+            }
+        }
+
+        #[test]
+        fn meters_follow_tracks_and_channels() {
+            use crate::audio::meters::{to_db, Meters, METER_FLOOR_DB};
+
+            let mixer = AudioMixer::new(2, 48000);
+            let mappings: HashMap<String, Vec<u16>> = HashMap::from([
+                ("main".to_string(), vec![1, 2]),
+                ("monitor".to_string(), vec![2]),
+            ]);
+            let tg = install_gains(&mixer, &mappings);
+            let meters = Arc::new(Meters::new(2, tg.names()));
+            mixer.set_meters(meters.clone());
+            tg.set_db("main", -6.0).unwrap();
+            tg.set_db("monitor", MIN_GAIN_DB).unwrap();
+
+            let source = create_test_source(vec![1.0; 8], 1, vec![vec!["main".to_string()]]);
+            mixer.add_source(make_active_source(1, source, mappings));
+            let mut out = vec![0.0f32; 16];
+            mixer.process_into_output(&mut out, 8);
+
+            let snapshot = meters.snapshot();
+            let level = |name: &str| {
+                snapshot
+                    .tracks
+                    .iter()
+                    .find(|(n, _)| n == name)
+                    .map(|(_, level)| *level)
+                    .unwrap()
+            };
+            assert!((level("main").peak_db - -6.0).abs() < 0.01);
+            assert!((level("main").rms_db - -6.0).abs() < 0.01);
+            assert_eq!(level("monitor").peak_db, METER_FLOOR_DB);
+            for channel in &snapshot.channels {
+                assert!((channel.peak_db - to_db(0.5012)).abs() < 0.01);
+                assert_eq!(channel.clips, 0);
+            }
+        }
+
+        #[test]
+        fn meters_count_output_clips() {
+            use crate::audio::meters::Meters;
+
+            let mixer = AudioMixer::new(1, 48000);
+            let tg = install_gains(&mixer, &t_mappings());
+            let meters = Arc::new(Meters::new(1, tg.names()));
+            mixer.set_meters(meters.clone());
+            // Two sources at unity on one channel sum past full scale.
+            mixer.add_source(make_active_source(1, ones_source(4), t_mappings()));
+            mixer.add_source(make_active_source(2, ones_source(4), t_mappings()));
+            let mut out = vec![0.0f32; 4];
+            mixer.process_into_output(&mut out, 4);
+
+            let snapshot = meters.snapshot();
+            assert_eq!(snapshot.channels[0].clips, 4);
+            // Each track feed alone stays at full scale without clipping.
+            assert_eq!(snapshot.tracks[0].1.clips, 0);
+        }
+
+        #[test]
+        fn output_stage_applies_master_gain_per_channel() {
+            let mixer = AudioMixer::new(2, 48000);
+            let mappings: HashMap<String, Vec<u16>> =
+                HashMap::from([("main".to_string(), vec![1, 2])]);
+            mixer.set_output_stage(OutputStage::new(vec![0.5], None));
+            let source = create_test_source(vec![1.0; 4], 1, vec![vec!["main".to_string()]]);
+            mixer.add_source(make_active_source(1, source, mappings));
+            let mut out = vec![0.0f32; 8];
+            mixer.process_into_output(&mut out, 4);
+            for frame in out.chunks(2) {
+                assert!((frame[0] - 0.5).abs() < 1e-6);
+                assert!((frame[1] - 1.0).abs() < 1e-6, "unlisted channel at unity");
+            }
+            assert_eq!(mixer.limiter_engagements(), 0);
+        }
+
+        #[test]
+        fn output_stage_limits_before_metering() {
+            use crate::audio::limiter::{Limiter, LimiterSettings};
+            use crate::audio::meters::Meters;
+
+            let mixer = AudioMixer::new(1, 48000);
+            let tg = install_gains(&mixer, &t_mappings());
+            let meters = Arc::new(Meters::new(1, tg.names()));
+            mixer.set_meters(meters.clone());
+            let settings = LimiterSettings {
+                ceiling: 0.9,
+                lookahead_frames: 1,
+                release_coeff: 0.01,
+            };
+            mixer.set_output_stage(OutputStage::new(vec![1.0], Some(Limiter::new(1, settings))));
+            // Two sources at unity would clip without the limiter.
+            mixer.add_source(make_active_source(1, ones_source(8), t_mappings()));
+            mixer.add_source(make_active_source(2, ones_source(8), t_mappings()));
+            let mut out = vec![0.0f32; 8];
+            mixer.process_into_output(&mut out, 8);
+
+            assert!(out.iter().all(|&s| (s - 0.9).abs() < 1e-6));
+            assert_eq!(mixer.limiter_engagements(), 1);
+            assert_eq!(meters.snapshot().channels[0].clips, 0);
+        }
     }
 
     mod remove_sources_tests {
@@ -2202,4 +2773,228 @@ mod tests {
             assert!(output[0] > 0.0, "source should play when cancel_at is 0");
         }
     }
+
+    mod transport_hold_tests {
+        use super::*;
+        use crate::audio::crossfade::{CrossfadeCurve, GainEnvelope};
+        use crate::clock::PlaybackClock;
+
+        /// 10ms at 48k, the fade on either side of a hold.
+        const FADE: usize = 480;
+
+        fn tm() -> HashMap<String, Vec<u16>> {
+            let mut tm = HashMap::new();
+            tm.insert("t".to_string(), vec![1]);
+            tm
+        }
+
+        /// A mono source whose every sample is distinct, so the read
+        /// position can be recovered from the output.
+        fn ramp_source(len: usize) -> Box<dyn ChannelMappedSampleSource> {
+            let samples = (0..len).map(|i| (i + 1) as f32 / len as f32).collect();
+            create_test_source(samples, 1, vec![vec!["t".to_string()]])
+        }
+
+        fn ramp_value(i: usize, len: usize) -> f32 {
+            (i + 1) as f32 / len as f32
+        }
+
+        /// Mixes `frames` mono frames in 100-frame callbacks.
+        fn render(mixer: &AudioMixer, frames: usize) -> Vec<f32> {
+            let mut out = vec![0.0f32; frames];
+            for chunk in out.chunks_mut(100) {
+                mixer.process_into_output(chunk, chunk.len());
+            }
+            out
+        }
+
+        fn started_clock(mixer: &AudioMixer) -> PlaybackClock {
+            let clock = PlaybackClock::from_sample_counter(mixer.sample_counter(), 48_000);
+            clock.start();
+            clock
+        }
+
+        #[test]
+        fn held_source_is_silent_and_resumes_where_it_held() {
+            let mixer = AudioMixer::new(1, 48_000);
+            let clock = started_clock(&mixer);
+            let len = 10_000;
+            let mut active = make_active_source(1, ramp_source(len), tm());
+            active.transport = Some(clock.clone());
+            mixer.add_source(active);
+
+            let before = render(&mixer, 1000);
+            assert_eq!(before[999], ramp_value(999, len));
+
+            // Hold lands at 1480, after a fade-out from full gain.
+            assert!(clock.pause());
+            let paused = render(&mixer, 2000);
+            for (k, &sample) in paused.iter().enumerate().take(FADE) {
+                let expected = ramp_value(1000 + k, len) * (FADE - k) as f32 / FADE as f32;
+                assert!((sample - expected).abs() < 1e-6, "fade-out at {k}");
+            }
+            assert!(
+                paused[FADE..].iter().all(|&s| s == 0.0),
+                "output must be silent past the hold"
+            );
+
+            // Resume at 3000: the source picks up at its 1480th sample and
+            // ramps in sample-exactly.
+            assert!(clock.resume());
+            let resumed = render(&mixer, 1000);
+            for (k, &sample) in resumed.iter().enumerate() {
+                let expected = ramp_value(1480 + k, len);
+                if k < FADE {
+                    let expected = expected * k as f32 / FADE as f32;
+                    assert!((sample - expected).abs() < 1e-6, "fade-in at {k}");
+                } else {
+                    assert_eq!(sample, expected, "position at {k}");
+                }
+            }
+        }
+
+        #[test]
+        fn stop_while_held_finishes_source() {
+            let mixer = AudioMixer::new(1, 48_000);
+            let clock = started_clock(&mixer);
+            let mut active = make_active_source(1, ramp_source(100_000), tm());
+            active.transport = Some(clock.clone());
+            let finished = active.is_finished.clone();
+            let cancel = active.cancel_handle.clone();
+            // A fade-out in flight would normally keep a cancelled source
+            // alive until it completes; held, it would never complete.
+            active.gain_envelope = Some(Arc::new(GainEnvelope::fade_out(
+                10_000,
+                CrossfadeCurve::Linear,
+            )));
+            mixer.add_source(active);
+
+            render(&mixer, 500);
+            clock.pause();
+            render(&mixer, 1000);
+            assert!(!finished.load(Ordering::Relaxed));
+
+            cancel.cancel();
+            render(&mixer, 100);
+            assert!(finished.load(Ordering::Relaxed));
+            assert!(mixer.get_active_sources().read().is_empty());
+        }
+
+        #[test]
+        fn sources_without_transport_ignore_pause() {
+            let mixer = AudioMixer::new(1, 48_000);
+            let clock = started_clock(&mixer);
+            let len = 10_000;
+            mixer.add_source(make_active_source(1, ramp_source(len), tm()));
+
+            render(&mixer, 1000);
+            clock.pause();
+            let out = render(&mixer, 2000);
+            assert_eq!(out[1999], ramp_value(2999, len));
+        }
+
+        #[test]
+        fn section_boundary_armed_before_pause_moves_with_it() {
+            // The section-loop monitor arms a boundary ahead of time: the
+            // outgoing source fades out and is cut there, and the incoming
+            // one starts there. A pause inside that lookahead must carry the
+            // boundary with it, not fire it on resume.
+            let mixer = AudioMixer::new(1, 48_000);
+            let clock = started_clock(&mixer);
+            let crossfade = 10u64;
+            let loop_sample = 2000u64;
+
+            let mut old = make_active_source(
+                1,
+                create_test_source(vec![1.0; 100_000], 1, vec![vec!["t".to_string()]]),
+                tm(),
+            );
+            old.transport = Some(clock.clone());
+            old.cancel_at_sample = Some(Arc::new(AtomicU64::new(0)));
+            mixer.add_source(old);
+
+            render(&mixer, 1000);
+            mixer.set_gain_envelope(
+                &[1],
+                Arc::new(
+                    GainEnvelope::fade_out(crossfade, CrossfadeCurve::Linear)
+                        .with_start_sample(loop_sample),
+                ),
+            );
+            mixer.set_cancel_at_sample(&[1], loop_sample + crossfade);
+            let mut new = make_active_source(
+                2,
+                create_test_source(vec![0.5; 100_000], 1, vec![vec!["t".to_string()]]),
+                tm(),
+            );
+            new.transport = Some(clock.clone());
+            new.start_at_sample = Some(loop_sample);
+            new.cancel_at_sample = Some(Arc::new(AtomicU64::new(0)));
+            new.gain_envelope = Some(Arc::new(GainEnvelope::fade_in(
+                crossfade,
+                CrossfadeCurve::Linear,
+            )));
+            mixer.add_source(new);
+
+            // Hold at 1480, well inside the lookahead, for 8520 samples.
+            clock.pause();
+            render(&mixer, 9000);
+            assert_eq!(mixer.get_active_sources().read().len(), 2);
+            clock.resume();
+
+            // 520 transport samples remain before the boundary, the first
+            // 480 of them ramping back in.
+            let out = render(&mixer, 1000);
+            assert_eq!(out[519], 1.0, "outgoing source plays up to the boundary");
+            assert_eq!(out[520], 1.0, "crossfade starts on the boundary");
+            assert!(
+                out[525] < 1.0 && out[525] > 0.5,
+                "mid-crossfade: {}",
+                out[525]
+            );
+            assert_eq!(out[600], 0.5, "incoming source alone after the boundary");
+            assert_eq!(mixer.get_active_sources().read().len(), 1);
+        }
+
+        #[test]
+        fn gapless_hand_off_joins_on_the_sample() {
+            // The next song is queued on its own clock ahead of time; the
+            // outgoing song is cut on the sample the next one starts.
+            let mixer = AudioMixer::new(1, 48_000);
+            let outgoing_clock = started_clock(&mixer);
+            let mut old = make_active_source(
+                1,
+                create_test_source(vec![1.0; 100_000], 1, vec![vec!["t".to_string()]]),
+                tm(),
+            );
+            old.transport = Some(outgoing_clock.clone());
+            old.cancel_at_sample = Some(Arc::new(AtomicU64::new(0)));
+            mixer.add_source(old);
+
+            render(&mixer, 1000);
+            let handoff = 1750u64;
+            let ids = mixer.source_ids_following(&outgoing_clock);
+            assert_eq!(ids, vec![1]);
+            mixer.set_cancel_at_sample(&ids, outgoing_clock.transport_sample(handoff));
+
+            let incoming_clock = PlaybackClock::from_sample_counter(mixer.sample_counter(), 48_000);
+            incoming_clock.schedule_start(handoff, 0);
+            incoming_clock.start();
+            let len = 10_000;
+            let mut new = make_active_source(2, ramp_source(len), tm());
+            new.transport = Some(incoming_clock.clone());
+            new.start_at_sample = Some(handoff);
+            mixer.add_source(new);
+
+            let out = render(&mixer, 1000);
+            assert!(out[..750].iter().all(|&s| s == 1.0));
+            for (k, &sample) in out[750..].iter().enumerate() {
+                assert_eq!(sample, ramp_value(k, len), "incoming at {k}");
+            }
+            assert_eq!(
+                mixer.source_ids_following(&outgoing_clock),
+                Vec::<u64>::new()
+            );
+        }
+    }
 }
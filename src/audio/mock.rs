@@ -19,6 +19,7 @@ use std::{
         mpsc, Arc,
     },
     thread,
+    time::Duration,
 };
 
 use tracing::{info, span, Level};
@@ -97,7 +98,21 @@ impl crate::audio::Device for Device {
                     return;
                 }
 
-                let _ = sleep_rx.recv_timeout(remaining_duration);
+                // Follow the playback clock rather than sleeping the whole
+                // song, so a paused transport holds the mock too.
+                loop {
+                    let elapsed = clock.elapsed();
+                    if elapsed >= remaining_duration {
+                        break;
+                    }
+                    let wait = (remaining_duration - elapsed).min(Duration::from_millis(20));
+                    if !matches!(
+                        sleep_rx.recv_timeout(wait),
+                        Err(mpsc::RecvTimeoutError::Timeout)
+                    ) {
+                        break;
+                    }
+                }
 
                 // Expire at the end of playback.
                 finished.store(true, Ordering::Relaxed);
@@ -111,9 +126,8 @@ impl crate::audio::Device for Device {
         // This ensures tests can check is_playing immediately after stop() without races
         self.is_playing.store(false, Ordering::Relaxed);
 
-        sleep_tx
-            .send(())
-            .map_err(|e| crate::audio::AudioError::Playback(e.to_string()))?;
+        // The thread has already gone when the song played to its end.
+        let _ = sleep_tx.send(());
         let join_result = join_handle.join();
 
         if join_result.is_err() {
@@ -0,0 +1,575 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+//! Rehearsal speed and transposition.
+//!
+//! [`StretchSource`] wraps a decoded track and plays it at a [`PlaybackRate`]:
+//! WSOLA (waveform-similarity overlap-add) changes the duration without
+//! changing pitch, and a cubic resampler after it trades duration for pitch.
+//! Transposing by `p` stretches by `p` and then resamples by `1/p`, so the two
+//! stages together consume input at `speed` whatever the pitch. It runs on
+//! the buffer fill pool along with decoding, never in the audio callback.
+
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+use super::sample_source::error::SampleSourceError;
+use super::sample_source::traits::SampleSource;
+
+/// The range playback speed is limited to, as a multiple of normal speed.
+pub const SPEED_RANGE: RangeInclusive<f64> = 0.5..=1.5;
+
+/// The range transposition is limited to, in semitones.
+pub const SEMITONES_RANGE: RangeInclusive<f64> = -12.0..=12.0;
+
+/// Overlap-add segment length. Long enough to hold a couple of periods of a
+/// low bass note, short enough that transients don't audibly double.
+const SEGMENT: Duration = Duration::from_millis(40);
+
+/// How far either side of its nominal position a segment may be moved to
+/// line up with the waveform it continues.
+const TOLERANCE: Duration = Duration::from_millis(12);
+
+/// Offset and sample stride of the coarse similarity search, refined to
+/// single samples around the best coarse match.
+const COARSE_STEP: usize = 4;
+
+/// Speed and pitch for one playback.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlaybackRate {
+    /// Multiple of normal speed; below 1 plays slower.
+    pub speed: f64,
+    /// Transposition in semitones.
+    pub semitones: f64,
+}
+
+impl Default for PlaybackRate {
+    fn default() -> Self {
+        PlaybackRate::NORMAL
+    }
+}
+
+impl PlaybackRate {
+    /// Normal speed, untransposed.
+    pub const NORMAL: PlaybackRate = PlaybackRate {
+        speed: 1.0,
+        semitones: 0.0,
+    };
+
+    /// Validates a speed and transposition.
+    pub fn new(speed: f64, semitones: f64) -> Result<PlaybackRate, String> {
+        if !SPEED_RANGE.contains(&speed) {
+            return Err(format!(
+                "speed {} out of range [{}, {}]",
+                speed,
+                SPEED_RANGE.start(),
+                SPEED_RANGE.end()
+            ));
+        }
+        if !SEMITONES_RANGE.contains(&semitones) {
+            return Err(format!(
+                "transposition {} semitones out of range [{}, {}]",
+                semitones,
+                SEMITONES_RANGE.start(),
+                SEMITONES_RANGE.end()
+            ));
+        }
+        Ok(PlaybackRate { speed, semitones })
+    }
+
+    /// Whether this leaves audio untouched.
+    pub fn is_normal(&self) -> bool {
+        *self == PlaybackRate::NORMAL
+    }
+
+    /// The frequency ratio of the transposition.
+    pub fn pitch_ratio(&self) -> f64 {
+        2f64.powf(self.semitones / 12.0)
+    }
+
+    /// The same speed without transposition, for cues such as the click and
+    /// the voice pilot that should keep their pitch.
+    pub fn untransposed(&self) -> PlaybackRate {
+        PlaybackRate {
+            speed: self.speed,
+            semitones: 0.0,
+        }
+    }
+}
+
+/// Time-stretches interleaved audio by overlap-adding Hann-windowed segments
+/// at half-window hops, each nudged to where it best continues the last.
+struct Wsola {
+    channels: usize,
+    /// Synthesis hop in frames: half the window.
+    hop: usize,
+    tolerance: i64,
+    /// Input frames advanced per output frame.
+    tempo: f64,
+    /// Periodic Hann window, `2 * hop` long, so halves overlap to unity.
+    window: Vec<f32>,
+    /// Buffered input, interleaved, starting at frame `input_start`.
+    input: Vec<f32>,
+    /// Channel sum of `input`, for the similarity search.
+    mono: Vec<f32>,
+    input_start: i64,
+    input_done: bool,
+    /// Where the next segment would start without adjustment.
+    nominal: f64,
+    /// Where the last segment started.
+    previous: i64,
+    /// The last segment's windowed second half, awaiting the next segment.
+    tail: Vec<f32>,
+    primed: bool,
+    finished: bool,
+    /// Output frames written so far.
+    emitted: usize,
+}
+
+impl Wsola {
+    fn new(channels: usize, sample_rate: u32, tempo: f64) -> Wsola {
+        let frames = |d: Duration| (d.as_secs_f64() * sample_rate as f64).round() as usize;
+        let hop = (frames(SEGMENT) / 2).max(COARSE_STEP);
+        let window = (0..2 * hop)
+            .map(|n| {
+                let phase = std::f64::consts::PI * n as f64 / hop as f64;
+                (0.5 - 0.5 * phase.cos()) as f32
+            })
+            .collect();
+        Wsola {
+            channels,
+            hop,
+            tolerance: frames(TOLERANCE).max(1) as i64,
+            tempo,
+            window,
+            input: Vec::new(),
+            mono: Vec::new(),
+            input_start: 0,
+            input_done: false,
+            nominal: 0.0,
+            previous: 0,
+            tail: vec![0.0; hop * channels],
+            primed: false,
+            finished: false,
+            emitted: 0,
+        }
+    }
+
+    fn input_end(&self) -> i64 {
+        self.input_start + self.mono.len() as i64
+    }
+
+    fn sample(&self, frame: i64, channel: usize) -> f32 {
+        let index = frame - self.input_start;
+        if index < 0 || index >= self.mono.len() as i64 {
+            return 0.0;
+        }
+        self.input[index as usize * self.channels + channel]
+    }
+
+    fn mono(&self, frame: i64) -> f32 {
+        let index = frame - self.input_start;
+        if index < 0 || index >= self.mono.len() as i64 {
+            return 0.0;
+        }
+        self.mono[index as usize]
+    }
+
+    /// Reads from `inner` until input reaches `end` or runs out.
+    fn fill_to(&mut self, inner: &mut dyn SampleSource, end: i64) -> Result<(), SampleSourceError> {
+        while !self.input_done && self.input_end() < end {
+            let mut sum = 0.0;
+            for channel in 0..self.channels {
+                match inner.next_sample()? {
+                    Some(sample) => {
+                        self.input.push(sample);
+                        sum += sample;
+                    }
+                    None => {
+                        // Drop a partial frame.
+                        self.input.truncate(self.mono.len() * self.channels);
+                        self.input_done = true;
+                        break;
+                    }
+                }
+                if channel + 1 == self.channels {
+                    self.mono.push(sum);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// How well a segment at `candidate` continues the one at `natural`,
+    /// comparing every `stride`th frame of the overlap.
+    fn similarity(&self, candidate: i64, natural: i64, stride: usize) -> f32 {
+        let mut correlation = 0.0;
+        let mut energy = 1e-9;
+        for j in (0..self.hop as i64).step_by(stride) {
+            let c = self.mono(candidate + j);
+            correlation += c * self.mono(natural + j);
+            energy += c * c;
+        }
+        correlation / energy.sqrt()
+    }
+
+    /// The segment start within tolerance of `nominal` that best continues
+    /// the waveform from `natural`.
+    fn best_start(&self, nominal: i64, natural: i64) -> i64 {
+        let low = (nominal - self.tolerance).max(0);
+        let high = nominal + self.tolerance;
+        let mut best = (f32::MIN, nominal.max(0));
+        for candidate in (low..=high).step_by(COARSE_STEP) {
+            let score = self.similarity(candidate, natural, COARSE_STEP);
+            if score > best.0 {
+                best = (score, candidate);
+            }
+        }
+        let coarse = best.1;
+        let step = COARSE_STEP as i64;
+        for candidate in (coarse - step + 1).max(low)..=(coarse + step - 1).min(high) {
+            let score = self.similarity(candidate, natural, 1);
+            if score > best.0 {
+                best = (score, candidate);
+            }
+        }
+        best.1
+    }
+
+    /// Appends the next hop of output frames to `out`. Returns false once
+    /// the input is exhausted and the last segment has been written.
+    fn step(
+        &mut self,
+        inner: &mut dyn SampleSource,
+        out: &mut Vec<f32>,
+    ) -> Result<bool, SampleSourceError> {
+        let before = out.len();
+        if !self.overlap_add(inner, out)? {
+            return Ok(false);
+        }
+        // The last segments run on past the end of the input into silence;
+        // stop where the input stretched by `tempo` would.
+        if self.input_done {
+            let limit = (self.input_end() as f64 / self.tempo).round() as usize;
+            let allowed = limit.saturating_sub(self.emitted);
+            if out.len() - before >= allowed * self.channels {
+                out.truncate(before + allowed * self.channels);
+                self.finished = true;
+            }
+        }
+        self.emitted += (out.len() - before) / self.channels;
+        Ok(out.len() > before)
+    }
+
+    /// Overlap-adds the next segment, appending a hop of output to `out`.
+    fn overlap_add(
+        &mut self,
+        inner: &mut dyn SampleSource,
+        out: &mut Vec<f32>,
+    ) -> Result<bool, SampleSourceError> {
+        if self.finished {
+            return Ok(false);
+        }
+        let hop = self.hop as i64;
+        if !self.primed {
+            // Start as if a segment had just ended at frame 0, so the first
+            // output frame is the first input frame rather than a fade-in.
+            self.fill_to(inner, hop)?;
+            for j in 0..self.hop {
+                for channel in 0..self.channels {
+                    self.tail[j * self.channels + channel] =
+                        self.sample(j as i64, channel) * self.window[self.hop + j];
+                }
+            }
+            self.previous = -hop;
+            self.primed = true;
+        }
+
+        let nominal = self.nominal.round() as i64;
+        let natural = self.previous + hop;
+        self.fill_to(
+            inner,
+            (nominal + self.tolerance + 2 * hop).max(natural + hop),
+        )?;
+        if self.input_done && nominal >= self.input_end() {
+            out.extend_from_slice(&self.tail);
+            self.finished = true;
+            return Ok(true);
+        }
+
+        let start = self.best_start(nominal, natural);
+        for j in 0..self.hop {
+            for channel in 0..self.channels {
+                let index = j * self.channels + channel;
+                out.push(
+                    self.tail[index] + self.sample(start + j as i64, channel) * self.window[j],
+                );
+                self.tail[index] =
+                    self.sample(start + hop + j as i64, channel) * self.window[self.hop + j];
+            }
+        }
+        self.previous = start;
+        self.nominal += self.hop as f64 * self.tempo;
+
+        // Keep what the next search and continuation can still reach.
+        let keep = ((self.nominal.round() as i64) - self.tolerance)
+            .min(self.previous + hop)
+            .clamp(self.input_start, self.input_end());
+        let drop = (keep - self.input_start) as usize;
+        if drop > 0 {
+            self.input.drain(..drop * self.channels);
+            self.mono.drain(..drop);
+            self.input_start = keep;
+        }
+        Ok(true)
+    }
+}
+
+/// A sample source played back at a [`PlaybackRate`].
+pub struct StretchSource {
+    inner: Box<dyn SampleSource>,
+    channels: usize,
+    speed: f64,
+    /// Stretched frames advanced per output frame; 1 skips resampling.
+    pitch: f64,
+    wsola: Wsola,
+    /// Stretched frames awaiting resampling, interleaved. Frame 0 is the one
+    /// before `phase`'s integer part, kept for the interpolator.
+    stretched: Vec<f32>,
+    phase: f64,
+    /// Whether the stretched stream has been padded out after its end.
+    drained: bool,
+    output: Vec<f32>,
+    output_pos: usize,
+}
+
+impl StretchSource {
+    /// Wraps `inner` to play at `rate`.
+    pub fn new(inner: Box<dyn SampleSource>, rate: PlaybackRate) -> StretchSource {
+        let channels = inner.channel_count().max(1) as usize;
+        let pitch = rate.pitch_ratio();
+        let wsola = Wsola::new(channels, inner.sample_rate(), rate.speed / pitch);
+        StretchSource {
+            inner,
+            channels,
+            speed: rate.speed,
+            pitch,
+            wsola,
+            // One silent frame ahead of the first so the interpolator has a
+            // predecessor; `phase` starts past it.
+            stretched: vec![0.0; channels],
+            phase: 1.0,
+            drained: false,
+            output: Vec::new(),
+            output_pos: 0,
+        }
+    }
+
+    /// Refills `output`. Returns false once everything has been played.
+    fn refill(&mut self) -> Result<bool, SampleSourceError> {
+        self.output.clear();
+        self.output_pos = 0;
+        if self.pitch == 1.0 {
+            return self.wsola.step(self.inner.as_mut(), &mut self.output);
+        }
+        while self.output.is_empty() {
+            let frames = self.stretched.len() / self.channels;
+            while (self.phase as usize) + 2 < frames {
+                let index = self.phase as usize;
+                let t = (self.phase - index as f64) as f32;
+                for channel in 0..self.channels {
+                    let at = |i: usize| self.stretched[i * self.channels + channel];
+                    self.output.push(cubic(
+                        at(index - 1),
+                        at(index),
+                        at(index + 1),
+                        at(index + 2),
+                        t,
+                    ));
+                }
+                self.phase += self.pitch;
+            }
+            let consumed = (self.phase as usize).saturating_sub(1);
+            self.stretched.drain(..consumed * self.channels);
+            self.phase -= consumed as f64;
+
+            if self.output.is_empty()
+                && !self.wsola.step(self.inner.as_mut(), &mut self.stretched)?
+            {
+                if self.drained {
+                    return Ok(false);
+                }
+                // Let the interpolator run off the end of the last frames.
+                self.stretched
+                    .extend(std::iter::repeat_n(0.0, 2 * self.channels));
+                self.drained = true;
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Wraps `source` to play at `rate`, or returns it untouched at normal rate.
+pub fn at_rate(source: Box<dyn SampleSource>, rate: PlaybackRate) -> Box<dyn SampleSource> {
+    if rate.is_normal() {
+        source
+    } else {
+        Box::new(StretchSource::new(source, rate))
+    }
+}
+
+/// Catmull-Rom interpolation between `y1` and `y2`.
+fn cubic(y0: f32, y1: f32, y2: f32, y3: f32, t: f32) -> f32 {
+    let a = -0.5 * y0 + 1.5 * y1 - 1.5 * y2 + 0.5 * y3;
+    let b = y0 - 2.5 * y1 + 2.0 * y2 - 0.5 * y3;
+    let c = -0.5 * y0 + 0.5 * y2;
+    ((a * t + b) * t + c) * t + y1
+}
+
+impl SampleSource for StretchSource {
+    fn next_sample(&mut self) -> Result<Option<f32>, SampleSourceError> {
+        if self.output_pos >= self.output.len() && !self.refill()? {
+            return Ok(None);
+        }
+        let sample = self.output[self.output_pos];
+        self.output_pos += 1;
+        Ok(Some(sample))
+    }
+
+    fn channel_count(&self) -> u16 {
+        self.inner.channel_count()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.inner.sample_rate()
+    }
+
+    fn bits_per_sample(&self) -> u16 {
+        self.inner.bits_per_sample()
+    }
+
+    fn sample_format(&self) -> crate::audio::SampleFormat {
+        self.inner.sample_format()
+    }
+
+    fn duration(&self) -> Option<Duration> {
+        self.inner
+            .duration()
+            .map(|duration| duration.div_f64(self.speed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::sample_source::MemorySampleSource;
+
+    const RATE: u32 = 48000;
+
+    fn sine(freq: f64, seconds: f64, channels: usize) -> Vec<f32> {
+        let frames = (seconds * RATE as f64) as usize;
+        let mut samples = Vec::with_capacity(frames * channels);
+        for n in 0..frames {
+            let value =
+                (0.5 * (std::f64::consts::TAU * freq * n as f64 / RATE as f64).sin()) as f32;
+            samples.push(value);
+            // Extra channels stay silent.
+            samples.extend(std::iter::repeat_n(0.0, channels - 1));
+        }
+        samples
+    }
+
+    fn stretch(samples: Vec<f32>, channels: u16, rate: PlaybackRate) -> Vec<f32> {
+        let inner = MemorySampleSource::new(samples, channels, RATE);
+        let mut source = StretchSource::new(Box::new(inner), rate);
+        let mut out = Vec::new();
+        while let Some(sample) = source.next_sample().unwrap() {
+            out.push(sample);
+        }
+        out
+    }
+
+    /// Frequency estimated from rising zero crossings, away from the ends.
+    fn frequency(samples: &[f32]) -> f64 {
+        let body = &samples[samples.len() / 4..samples.len() * 3 / 4];
+        let crossings = body
+            .windows(2)
+            .filter(|w| w[0] < 0.0 && w[1] >= 0.0)
+            .count();
+        crossings as f64 * RATE as f64 / body.len() as f64
+    }
+
+    #[test]
+    fn rate_validation() {
+        assert!(PlaybackRate::new(0.8, -2.0).is_ok());
+        assert!(PlaybackRate::new(0.2, 0.0).is_err());
+        assert!(PlaybackRate::new(1.0, 13.0).is_err());
+        assert!(PlaybackRate::NORMAL.is_normal());
+        assert!((PlaybackRate::new(1.0, 12.0).unwrap().pitch_ratio() - 2.0).abs() < 1e-9);
+        assert_eq!(
+            PlaybackRate::new(0.8, 3.0).unwrap().untransposed(),
+            PlaybackRate::new(0.8, 0.0).unwrap()
+        );
+    }
+
+    #[test]
+    fn unity_reconstructs_the_input() {
+        let input = sine(440.0, 0.5, 1);
+        let out = stretch(input.clone(), 1, PlaybackRate::new(1.0, 0.0).unwrap());
+        assert!(out.len() >= input.len());
+        for (i, (a, b)) in input.iter().zip(&out).enumerate() {
+            assert!((a - b).abs() < 1e-4, "frame {i}: {a} vs {b}");
+        }
+    }
+
+    #[test]
+    fn slower_keeps_pitch() {
+        let input = sine(440.0, 1.0, 1);
+        let out = stretch(input.clone(), 1, PlaybackRate::new(0.8, 0.0).unwrap());
+        let ratio = out.len() as f64 / input.len() as f64;
+        assert!((ratio - 1.25).abs() < 0.03, "length ratio {ratio}");
+        let freq = frequency(&out);
+        assert!((freq - 440.0).abs() < 5.0, "frequency {freq}");
+    }
+
+    #[test]
+    fn transposing_keeps_length() {
+        let input = sine(440.0, 1.0, 1);
+        let out = stretch(input.clone(), 1, PlaybackRate::new(1.0, 12.0).unwrap());
+        let ratio = out.len() as f64 / input.len() as f64;
+        assert!((ratio - 1.0).abs() < 0.03, "length ratio {ratio}");
+        let freq = frequency(&out);
+        assert!((freq - 880.0).abs() < 10.0, "frequency {freq}");
+
+        let out = stretch(input.clone(), 1, PlaybackRate::new(1.25, -12.0).unwrap());
+        let ratio = out.len() as f64 / input.len() as f64;
+        assert!((ratio - 0.8).abs() < 0.03, "length ratio {ratio}");
+        let freq = frequency(&out);
+        assert!((freq - 220.0).abs() < 5.0, "frequency {freq}");
+    }
+
+    #[test]
+    fn channels_stay_separate() {
+        let out = stretch(sine(440.0, 0.5, 2), 2, PlaybackRate::new(0.7, 2.0).unwrap());
+        assert_eq!(out.len() % 2, 0);
+        assert!(out.iter().skip(1).step_by(2).all(|s| *s == 0.0));
+        assert!(out.iter().step_by(2).any(|s| s.abs() > 0.4));
+    }
+
+    #[test]
+    fn duration_scales_with_speed() {
+        let inner = MemorySampleSource::new(sine(440.0, 1.0, 1), 1, RATE);
+        let source = StretchSource::new(Box::new(inner), PlaybackRate::new(0.5, 0.0).unwrap());
+        assert_eq!(source.duration(), Some(Duration::from_secs(2)));
+    }
+}
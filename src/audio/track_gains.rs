@@ -11,18 +11,37 @@
 // You should have received a copy of the GNU General Public License along with
 // this program. If not, see <https://www.gnu.org/licenses/>.
 //
-//! Runtime-adjustable per-output-track gain.
+//! Runtime-adjustable per-output-track gain, pan, polarity and width.
 //!
 //! Gains are expressed in dB at the API/config level and stored alongside a
 //! precomputed linear multiplier for the audio callback. Values are stored as
 //! `f32` bit patterns in atomics so the callback can read them lock-free.
+//!
+//! Pan, polarity and width don't scale a track as a whole: they depend on
+//! which of the track's channels a mapping edge carries and which of its
+//! outputs it lands on, described by an [`EdgePosition`]. The mixer keeps one
+//! gain per distinct (track, position) and reads it through
+//! [`TrackGains::edge_gain`], so pan moves ramp exactly like fader moves.
+//!
+//! Monitor mixes get a block of slots of their own, one per track, holding
+//! that mix's gain and mute for the track. Pan, polarity and width stay a
+//! property of the track, so a mix's edges share the main slot's placement.
+//!
+//! Each track may also carry an EQ chain. Its band shapes are fixed by the
+//! config; frequency, gain and Q are atomics like the rest, with a per-track
+//! generation counter bumped after every change so the mixer only redesigns
+//! filter coefficients when something actually moved.
 use std::collections::HashMap;
 use std::fmt;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 
 use indexmap::IndexMap;
 use tracing::warn;
 
+use crate::config::trackeq::{EQ_GAIN_RANGE, FREQ_RANGE, MAX_BANDS, Q_RANGE};
+use crate::config::trackmix::{PAN_RANGE, WIDTH_RANGE};
+use crate::config::{EqBand, EqBandKind, MonitorMix, PanLaw, TrackMix};
+
 /// Gains at or below this value are treated as -inf (linear 0.0).
 pub const MIN_GAIN_DB: f32 = -60.0;
 /// Maximum allowed boost.
@@ -46,6 +65,79 @@ pub fn db_to_linear(db: f32) -> f32 {
     }
 }
 
+/// Clamps a pan position to [-1, 1]. NaN becomes centre.
+pub fn clamp_pan(pan: f32) -> f32 {
+    if pan.is_nan() {
+        return 0.0;
+    }
+    pan.clamp(*PAN_RANGE.start(), *PAN_RANGE.end())
+}
+
+/// Clamps a stereo width to [0, 2]. NaN becomes full (unaltered) width.
+pub fn clamp_width(width: f32) -> f32 {
+    if width.is_nan() {
+        return 1.0;
+    }
+    width.clamp(*WIDTH_RANGE.start(), *WIDTH_RANGE.end())
+}
+
+/// Where one mapping edge sits within its track.
+///
+/// A track may be carried by several channels of one source (a stereo sample
+/// on a single output track) and mapped to several output channels. Pan
+/// applies across a track's outputs when there are exactly two; width
+/// applies when there are also exactly two source channels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EdgePosition {
+    /// Which of the track's source channels feeds this edge, 0-indexed.
+    pub source: u16,
+    /// How many of the source's channels carry the track.
+    pub sources: u16,
+    /// Which of the track's output channels this edge lands on, 0-indexed.
+    pub output: u16,
+    /// How many output channels the track is mapped to.
+    pub outputs: u16,
+}
+
+/// The gain pan, polarity and width give one edge, before the track gain.
+fn placement_gain(
+    pan: f32,
+    law: PanLaw,
+    inverted: bool,
+    width: Option<f32>,
+    position: EdgePosition,
+) -> f32 {
+    let mut gain = if inverted { -1.0 } else { 1.0 };
+    if position.outputs != 2 {
+        return gain;
+    }
+    // Mid/side width as a cross-feed: each channel keeps (1 + w) / 2 on its
+    // own side and sends (1 - w) / 2 to the other. 0 is mono, 1 is as
+    // recorded, 2 subtracts the opposite channel to widen.
+    if let Some(width) = width.filter(|_| position.sources == 2) {
+        gain *= if position.source == position.output {
+            (1.0 + width) / 2.0
+        } else {
+            (1.0 - width) / 2.0
+        };
+    }
+    let right = position.output == 1;
+    gain * match law {
+        PanLaw::Balance if right => (1.0 + pan).min(1.0),
+        PanLaw::Balance => (1.0 - pan).min(1.0),
+        PanLaw::ConstantPower => {
+            let angle = (pan + 1.0) * std::f32::consts::FRAC_PI_4;
+            if right {
+                angle.sin()
+            } else {
+                angle.cos()
+            }
+        }
+        PanLaw::Linear if right => (1.0 + pan) / 2.0,
+        PanLaw::Linear => (1.0 - pan) / 2.0,
+    }
+}
+
 /// Error returned when setting the gain of a track that has no slot.
 #[derive(Debug)]
 pub struct UnknownTrackError(pub String);
@@ -58,6 +150,28 @@ impl fmt::Display for UnknownTrackError {
 
 impl std::error::Error for UnknownTrackError {}
 
+/// A monitor mix's routing and where its slots start.
+#[derive(Clone, Debug)]
+pub struct MixRoute {
+    /// The mix name.
+    pub name: String,
+    /// The 1-indexed output channels the mix is sent to.
+    pub channels: Vec<u16>,
+    /// The slot of the mix's first track; track `n` is at `first_slot + n`.
+    first_slot: usize,
+}
+
+/// One monitor mix's channels and every track's level in it, for reporting.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MixSnapshot {
+    /// The mix name.
+    pub name: String,
+    /// The 1-indexed output channels the mix is sent to.
+    pub channels: Vec<u16>,
+    /// (track, dB, muted) in slot order.
+    pub tracks: Vec<(String, f32, bool)>,
+}
+
 /// Shared per-output-track gain state.
 ///
 /// Built once at hardware init from the active profile's `track_mappings`
@@ -75,6 +189,60 @@ pub struct TrackGains {
     /// Per-slot mute flags. Kept separate from the gain so muting preserves
     /// the fader value and is never persisted to the profile.
     muted: Vec<AtomicBool>,
+    /// Per-slot pan position as `f32` bits.
+    pan_bits: Vec<AtomicU32>,
+    /// Per-slot pan law. Config only, so not atomic.
+    pan_laws: Vec<PanLaw>,
+    /// Per-slot polarity inversion.
+    inverted: Vec<AtomicBool>,
+    /// Per-slot stereo width as `f32` bits; NaN leaves the width unset.
+    width_bits: Vec<AtomicU32>,
+    /// Per-slot EQ bands, in chain order.
+    eq: Vec<Vec<EqCell>>,
+    /// Per-slot EQ generation, bumped after every band change.
+    eq_generation: Vec<AtomicU64>,
+    /// Monitor mixes. Their slots follow the tracks' in `gain_bits` and
+    /// `muted`; the placement and EQ vectors above cover the tracks only.
+    mixes: Vec<MixRoute>,
+}
+
+/// One live EQ band: a fixed shape with atomic parameters.
+struct EqCell {
+    kind: EqBandKind,
+    /// Frequency in Hz as `f32` bits.
+    freq: AtomicU32,
+    /// Gain in dB as `f32` bits.
+    gain: AtomicU32,
+    /// Q as `f32` bits.
+    q: AtomicU32,
+}
+
+impl EqCell {
+    fn new(band: &EqBand) -> Self {
+        EqCell {
+            kind: band.kind(),
+            freq: AtomicU32::new(clamp_to(band.freq(), FREQ_RANGE).to_bits()),
+            gain: AtomicU32::new(clamp_to(band.gain(), EQ_GAIN_RANGE).to_bits()),
+            q: AtomicU32::new(clamp_to(band.q(), Q_RANGE).to_bits()),
+        }
+    }
+
+    fn band(&self) -> EqBand {
+        EqBand::new(
+            self.kind,
+            f32::from_bits(self.freq.load(Ordering::Relaxed)),
+            f32::from_bits(self.gain.load(Ordering::Relaxed)),
+            Some(f32::from_bits(self.q.load(Ordering::Relaxed))),
+        )
+    }
+}
+
+/// Clamps a finite value into `range`, mapping NaN to the range start.
+fn clamp_to(value: f32, range: std::ops::RangeInclusive<f32>) -> f32 {
+    if value.is_nan() {
+        return *range.start();
+    }
+    value.clamp(*range.start(), *range.end())
 }
 
 /// Packs a (dB, linear) gain pair into a single u64.
@@ -138,11 +306,103 @@ impl TrackGains {
 
         let muted = names.iter().map(|_| AtomicBool::new(false)).collect();
         Self {
+            pan_bits: names
+                .iter()
+                .map(|_| AtomicU32::new(0.0f32.to_bits()))
+                .collect(),
+            pan_laws: vec![PanLaw::default(); names.len()],
+            inverted: names.iter().map(|_| AtomicBool::new(false)).collect(),
+            width_bits: names
+                .iter()
+                .map(|_| AtomicU32::new(f32::NAN.to_bits()))
+                .collect(),
+            eq: names.iter().map(|_| Vec::new()).collect(),
+            eq_generation: names.iter().map(|_| AtomicU64::new(0)).collect(),
             slots,
             names,
             gain_bits,
             muted,
+            mixes: Vec::new(),
+        }
+    }
+
+    /// Adds a block of slots per monitor mix, one for every track, with the
+    /// mix's configured gains and mutes. Entries for unknown tracks are
+    /// ignored with a warning; out-of-range gains are clamped.
+    pub fn with_monitor_mixes(mut self, mixes: &IndexMap<String, MonitorMix>) -> Self {
+        for (name, mix) in mixes {
+            let first_slot = self.gain_bits.len();
+            for track in &self.names {
+                let db = clamp_db(mix.gains().get(track).copied().unwrap_or(0.0));
+                self.gain_bits
+                    .push(AtomicU64::new(pack_gain(db, db_to_linear(db))));
+                self.muted
+                    .push(AtomicBool::new(mix.muted().contains(track)));
+            }
+            for track in mix.gains().keys().chain(mix.muted()) {
+                if !self.slots.contains_key(track) {
+                    warn!(
+                        mix = name.as_str(),
+                        track = track.as_str(),
+                        "monitor mix entry for unknown track, ignoring"
+                    );
+                }
+            }
+            self.mixes.push(MixRoute {
+                name: name.clone(),
+                channels: mix.channels().to_vec(),
+                first_slot,
+            });
+        }
+        self
+    }
+
+    /// Applies the configured pan, polarity and width. Entries for tracks
+    /// without a slot are ignored with a warning; out-of-range values are
+    /// clamped.
+    pub fn with_mix(mut self, track_mix: &IndexMap<String, TrackMix>) -> Self {
+        for (name, mix) in track_mix {
+            let Some(slot) = self.slot(name) else {
+                warn!(
+                    track = name.as_str(),
+                    "track_mix for unknown track, ignoring"
+                );
+                continue;
+            };
+            self.pan_laws[slot] = mix.pan_law();
+            if let Some(pan) = mix.pan() {
+                self.pan_bits[slot] = AtomicU32::new(clamp_pan(pan).to_bits());
+            }
+            self.inverted[slot] = AtomicBool::new(mix.invert());
+            if let Some(width) = mix.width() {
+                self.width_bits[slot] = AtomicU32::new(clamp_width(width).to_bits());
+            }
+        }
+        self
+    }
+
+    /// Applies the configured EQ chains. Entries for tracks without a slot
+    /// are ignored with a warning; chains past `MAX_BANDS` are truncated and
+    /// out-of-range parameters clamped.
+    pub fn with_eq(mut self, track_eq: &IndexMap<String, Vec<EqBand>>) -> Self {
+        for (name, bands) in track_eq {
+            let Some(slot) = self.slot(name) else {
+                warn!(
+                    track = name.as_str(),
+                    "track_eq for unknown track, ignoring"
+                );
+                continue;
+            };
+            if bands.len() > MAX_BANDS {
+                warn!(
+                    track = name.as_str(),
+                    bands = bands.len(),
+                    "track_eq has too many bands, truncating"
+                );
+            }
+            self.eq[slot] = bands.iter().take(MAX_BANDS).map(EqCell::new).collect();
         }
+        self
     }
 
     /// Returns the slot index for a track name, if known.
@@ -160,6 +420,112 @@ impl TrackGains {
         self.names.is_empty()
     }
 
+    /// Track names in slot order.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// The monitor mixes, in config order.
+    pub fn mixes(&self) -> &[MixRoute] {
+        &self.mixes
+    }
+
+    /// Returns the index of a monitor mix by name, if known.
+    pub fn mix_index(&self, mix: &str) -> Option<usize> {
+        self.mixes.iter().position(|route| route.name == mix)
+    }
+
+    /// Returns the slot holding a track's gain in a monitor mix, if both are
+    /// known.
+    pub fn mix_slot(&self, mix: usize, track: &str) -> Option<usize> {
+        let route = self.mixes.get(mix)?;
+        self.slot(track).map(|slot| route.first_slot + slot)
+    }
+
+    /// Sets a track's gain in a monitor mix in dB, returning the (clamped)
+    /// applied value.
+    pub fn set_mix_db(&self, mix: usize, track: &str, db: f32) -> Result<f32, UnknownTrackError> {
+        let slot = self
+            .mix_slot(mix, track)
+            .ok_or_else(|| UnknownTrackError(track.to_string()))?;
+        let clamped = clamp_db(db);
+        self.gain_bits[slot].store(pack_gain(clamped, db_to_linear(clamped)), Ordering::Relaxed);
+        Ok(clamped)
+    }
+
+    /// Mutes or unmutes a track in a monitor mix, returning the applied
+    /// state.
+    pub fn set_mix_muted(
+        &self,
+        mix: usize,
+        track: &str,
+        muted: bool,
+    ) -> Result<bool, UnknownTrackError> {
+        let slot = self
+            .mix_slot(mix, track)
+            .ok_or_else(|| UnknownTrackError(track.to_string()))?;
+        self.muted[slot].store(muted, Ordering::Relaxed);
+        Ok(muted)
+    }
+
+    /// Snapshots a monitor mix as (name, dB, muted) per track, in slot order.
+    pub fn snapshot_mix_tracks(&self, mix: usize) -> Vec<(String, f32, bool)> {
+        let Some(route) = self.mixes.get(mix) else {
+            return Vec::new();
+        };
+        self.names
+            .iter()
+            .enumerate()
+            .map(|(slot, name)| {
+                let slot = route.first_slot + slot;
+                (
+                    name.clone(),
+                    unpack_db(self.gain_bits[slot].load(Ordering::Relaxed)),
+                    self.muted[slot].load(Ordering::Relaxed),
+                )
+            })
+            .collect()
+    }
+
+    /// Snapshots every monitor mix with every track's level, in config
+    /// order.
+    pub fn snapshot_mixes(&self) -> Vec<MixSnapshot> {
+        self.mixes
+            .iter()
+            .enumerate()
+            .map(|(mix, route)| MixSnapshot {
+                name: route.name.clone(),
+                channels: route.channels.clone(),
+                tracks: self.snapshot_mix_tracks(mix),
+            })
+            .collect()
+    }
+
+    /// Snapshots every monitor mix for persistence, omitting unity gains.
+    pub fn snapshot_monitor_mixes(&self) -> IndexMap<String, MonitorMix> {
+        self.mixes
+            .iter()
+            .enumerate()
+            .map(|(mix, route)| {
+                let tracks = self.snapshot_mix_tracks(mix);
+                let gains = tracks
+                    .iter()
+                    .filter(|(_, db, _)| *db != 0.0)
+                    .map(|(name, db, _)| (name.clone(), *db))
+                    .collect();
+                let muted = tracks
+                    .into_iter()
+                    .filter(|(_, _, muted)| *muted)
+                    .map(|(name, _, _)| name)
+                    .collect();
+                (
+                    route.name.clone(),
+                    MonitorMix::new(route.channels.clone(), gains, muted),
+                )
+            })
+            .collect()
+    }
+
     /// Sets the gain of a track in dB, returning the (clamped) applied value.
     pub fn set_db(&self, track: &str, db: f32) -> Result<f32, UnknownTrackError> {
         let slot = self
@@ -185,6 +551,157 @@ impl TrackGains {
         unpack_linear(self.gain_bits[slot].load(Ordering::Relaxed))
     }
 
+    /// Gets the linear multiplier for one mapping edge of a slot: the track
+    /// gain (honoring mute) with pan, polarity and width for the edge's
+    /// position. Hot path: a handful of relaxed loads.
+    pub fn edge_gain(&self, slot: usize, position: EdgePosition) -> f32 {
+        let linear = self.linear(slot);
+        if linear == 0.0 {
+            return 0.0;
+        }
+        // A monitor mix slot takes its placement from the track's main slot.
+        let slot = slot % self.names.len();
+        linear
+            * placement_gain(
+                f32::from_bits(self.pan_bits[slot].load(Ordering::Relaxed)),
+                self.pan_laws[slot],
+                self.inverted[slot].load(Ordering::Relaxed),
+                self.width(slot),
+                position,
+            )
+    }
+
+    fn width(&self, slot: usize) -> Option<f32> {
+        Some(f32::from_bits(
+            self.width_bits[slot].load(Ordering::Relaxed),
+        ))
+        .filter(|w| !w.is_nan())
+    }
+
+    /// Sets the pan of a track, returning the (clamped) applied value.
+    pub fn set_pan(&self, track: &str, pan: f32) -> Result<f32, UnknownTrackError> {
+        let slot = self
+            .slot(track)
+            .ok_or_else(|| UnknownTrackError(track.to_string()))?;
+        let clamped = clamp_pan(pan);
+        self.pan_bits[slot].store(clamped.to_bits(), Ordering::Relaxed);
+        Ok(clamped)
+    }
+
+    /// Sets the stereo width of a track, returning the (clamped) applied
+    /// value.
+    pub fn set_width(&self, track: &str, width: f32) -> Result<f32, UnknownTrackError> {
+        let slot = self
+            .slot(track)
+            .ok_or_else(|| UnknownTrackError(track.to_string()))?;
+        let clamped = clamp_width(width);
+        self.width_bits[slot].store(clamped.to_bits(), Ordering::Relaxed);
+        Ok(clamped)
+    }
+
+    /// Inverts a track's polarity or puts it back, returning the applied
+    /// state.
+    pub fn set_inverted(&self, track: &str, inverted: bool) -> Result<bool, UnknownTrackError> {
+        let slot = self
+            .slot(track)
+            .ok_or_else(|| UnknownTrackError(track.to_string()))?;
+        self.inverted[slot].store(inverted, Ordering::Relaxed);
+        Ok(inverted)
+    }
+
+    /// Snapshots every track's pan, polarity and width as (name, mix) pairs
+    /// in slot order.
+    pub fn snapshot_mix(&self) -> Vec<(String, TrackMix)> {
+        self.names
+            .iter()
+            .enumerate()
+            .map(|(slot, name)| {
+                let pan = f32::from_bits(self.pan_bits[slot].load(Ordering::Relaxed));
+                let law = self.pan_laws[slot];
+                let mix = TrackMix::new(
+                    Some(pan).filter(|&pan| pan != 0.0),
+                    Some(law).filter(|&law| law != PanLaw::default()),
+                    self.inverted[slot].load(Ordering::Relaxed),
+                    self.width(slot),
+                );
+                (name.clone(), mix)
+            })
+            .collect()
+    }
+
+    /// Snapshots mixes for persistence, omitting tracks left as routed.
+    pub fn snapshot_mix_map(&self) -> IndexMap<String, TrackMix> {
+        self.snapshot_mix()
+            .into_iter()
+            .filter(|(_, mix)| !mix.is_default())
+            .collect()
+    }
+
+    /// Returns how many EQ bands a slot's track has. Monitor mix slots have
+    /// none of their own.
+    pub fn eq_len(&self, slot: usize) -> usize {
+        self.eq.get(slot).map_or(0, Vec::len)
+    }
+
+    /// Returns the EQ generation for a slot with bands. Hot path: one load,
+    /// paired with the release in `set_eq_band`.
+    pub fn eq_generation(&self, slot: usize) -> u64 {
+        self.eq_generation[slot].load(Ordering::Acquire)
+    }
+
+    /// Returns one EQ band of a slot's track.
+    pub fn eq_band(&self, slot: usize, band: usize) -> EqBand {
+        self.eq[slot][band].band()
+    }
+
+    /// Updates a track's EQ band (0-indexed), leaving parameters passed as
+    /// `None` as they are. Returns the (clamped) applied band, or `None` when
+    /// the track has no such band.
+    pub fn set_eq_band(
+        &self,
+        track: &str,
+        band: usize,
+        freq: Option<f32>,
+        gain: Option<f32>,
+        q: Option<f32>,
+    ) -> Result<Option<EqBand>, UnknownTrackError> {
+        let slot = self
+            .slot(track)
+            .ok_or_else(|| UnknownTrackError(track.to_string()))?;
+        let Some(cell) = self.eq[slot].get(band) else {
+            return Ok(None);
+        };
+        if let Some(freq) = freq {
+            cell.freq
+                .store(clamp_to(freq, FREQ_RANGE).to_bits(), Ordering::Relaxed);
+        }
+        if let Some(gain) = gain {
+            cell.gain
+                .store(clamp_to(gain, EQ_GAIN_RANGE).to_bits(), Ordering::Relaxed);
+        }
+        if let Some(q) = q {
+            cell.q
+                .store(clamp_to(q, Q_RANGE).to_bits(), Ordering::Relaxed);
+        }
+        self.eq_generation[slot].fetch_add(1, Ordering::Release);
+        Ok(Some(cell.band()))
+    }
+
+    /// Snapshots the EQ chain of every track that has one, in slot order.
+    pub fn snapshot_eq(&self) -> Vec<(String, Vec<EqBand>)> {
+        self.names
+            .iter()
+            .zip(&self.eq)
+            .filter(|(_, cells)| !cells.is_empty())
+            .map(|(name, cells)| (name.clone(), cells.iter().map(EqCell::band).collect()))
+            .collect()
+    }
+
+    /// Snapshots EQ chains for persistence.
+    pub fn snapshot_eq_map(&self) -> IndexMap<String, Vec<EqBand>> {
+        self.snapshot_eq().into_iter().collect()
+    }
+
     /// Mutes or unmutes a track without touching its gain, returning the
     /// applied state. Mute state is runtime-only and never persisted.
     pub fn set_muted(&self, track: &str, muted: bool) -> Result<bool, UnknownTrackError> {
@@ -341,4 +858,200 @@ mod tests {
         // Full snapshot includes everything.
         assert_eq!(gains.snapshot_db().len(), 3);
     }
+
+    fn position(source: u16, sources: u16, output: u16, outputs: u16) -> EdgePosition {
+        EdgePosition {
+            source,
+            sources,
+            output,
+            outputs,
+        }
+    }
+
+    #[test]
+    fn pan_laws() {
+        let left = position(0, 1, 0, 2);
+        let right = position(0, 1, 1, 2);
+        let at = |law, pan| {
+            (
+                placement_gain(pan, law, false, None, left),
+                placement_gain(pan, law, false, None, right),
+            )
+        };
+        assert_eq!(at(PanLaw::Balance, 0.0), (1.0, 1.0));
+        assert_eq!(at(PanLaw::Balance, 0.5), (0.5, 1.0));
+        assert_eq!(at(PanLaw::Balance, -1.0), (1.0, 0.0));
+        let (l, r) = at(PanLaw::ConstantPower, 0.0);
+        assert!(
+            (l - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-4
+                && (r - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-4
+        );
+        let (l, r) = at(PanLaw::ConstantPower, 1.0);
+        assert!(l.abs() < 1e-6 && (r - 1.0).abs() < 1e-6);
+        assert_eq!(at(PanLaw::Linear, 0.0), (0.5, 0.5));
+        assert_eq!(at(PanLaw::Linear, -0.5), (0.75, 0.25));
+    }
+
+    #[test]
+    fn pan_needs_a_stereo_pair() {
+        for outputs in [1, 3] {
+            let edge = position(0, 1, 0, outputs);
+            assert_eq!(placement_gain(1.0, PanLaw::Linear, false, None, edge), 1.0);
+        }
+    }
+
+    #[test]
+    fn width_cross_feeds_a_stereo_track() {
+        let gain = |width, source, output| {
+            placement_gain(
+                0.0,
+                PanLaw::Balance,
+                false,
+                Some(width),
+                position(source, 2, output, 2),
+            )
+        };
+        // As recorded: each channel to its own side only.
+        assert_eq!((gain(1.0, 0, 0), gain(1.0, 0, 1)), (1.0, 0.0));
+        // Mono: each channel half to both sides.
+        assert_eq!((gain(0.0, 1, 0), gain(0.0, 1, 1)), (0.5, 0.5));
+        // Extra wide: subtracts from the opposite side.
+        assert_eq!((gain(2.0, 0, 0), gain(2.0, 0, 1)), (1.5, -0.5));
+        // A mono track ignores width.
+        assert_eq!(
+            placement_gain(0.0, PanLaw::Balance, false, Some(0.0), position(0, 1, 1, 2)),
+            1.0
+        );
+    }
+
+    #[test]
+    fn edge_gain_combines_gain_and_placement() {
+        let gains =
+            TrackGains::from_config(&mappings(&["vox", "bass"]), None).with_mix(&IndexMap::from([
+                (
+                    "vox".to_string(),
+                    TrackMix::new(Some(-5.0), Some(PanLaw::Linear), false, None),
+                ),
+                ("bass".to_string(), TrackMix::new(None, None, true, None)),
+                ("nope".to_string(), TrackMix::default()),
+            ]));
+        let vox = gains.slot("vox").unwrap();
+        let bass = gains.slot("bass").unwrap();
+        // Pan clamped to hard left.
+        assert_eq!(gains.edge_gain(vox, position(0, 1, 0, 2)), 1.0);
+        assert_eq!(gains.edge_gain(vox, position(0, 1, 1, 2)), 0.0);
+        assert_eq!(gains.edge_gain(bass, position(0, 1, 0, 1)), -1.0);
+
+        gains.set_db("bass", -6.0).unwrap();
+        assert!((gains.edge_gain(bass, position(0, 1, 0, 1)) + 0.5012).abs() < 1e-3);
+        gains.set_muted("bass", true).unwrap();
+        assert_eq!(gains.edge_gain(bass, position(0, 1, 0, 1)), 0.0);
+
+        assert_eq!(gains.set_pan("vox", 0.0).unwrap(), 0.0);
+        assert_eq!(gains.edge_gain(vox, position(0, 1, 1, 2)), 0.5);
+        assert!(!gains.set_inverted("bass", false).unwrap());
+        assert_eq!(gains.set_width("vox", 9.0).unwrap(), 2.0);
+        assert!(gains.set_pan("nope", 0.0).is_err());
+    }
+
+    #[test]
+    fn snapshot_mix_map_omits_untouched_tracks() {
+        let gains = TrackGains::from_config(&mappings(&["vox", "bass", "keys"]), None);
+        assert!(gains.snapshot_mix_map().is_empty());
+        gains.set_pan("vox", 0.25).unwrap();
+        gains.set_inverted("bass", true).unwrap();
+        let map = gains.snapshot_mix_map();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["vox"].pan(), Some(0.25));
+        assert!(map["bass"].invert());
+        assert_eq!(gains.snapshot_mix().len(), 3);
+    }
+
+    #[test]
+    fn monitor_mixes_have_their_own_levels() {
+        let gains = TrackGains::from_config(&mappings(&["click", "keys"]), None)
+            .with_mix(&IndexMap::from([(
+                "keys".to_string(),
+                TrackMix::new(None, None, true, None),
+            )]))
+            .with_monitor_mixes(&IndexMap::from([(
+                "drummer".to_string(),
+                MonitorMix::new(
+                    vec![3, 4],
+                    IndexMap::from([("click".to_string(), 6.0), ("nope".to_string(), 1.0)]),
+                    vec!["keys".to_string()],
+                ),
+            )]));
+        assert_eq!(gains.len(), 2);
+        assert_eq!(gains.mix_index("drummer"), Some(0));
+        assert_eq!(gains.mix_index("singer"), None);
+        assert_eq!(gains.mixes()[0].channels, vec![3, 4]);
+
+        let click = gains.mix_slot(0, "click").unwrap();
+        let keys = gains.mix_slot(0, "keys").unwrap();
+        assert!(click >= gains.len() && keys >= gains.len());
+        assert!((gains.edge_gain(click, position(0, 1, 0, 2)) - 1.9953).abs() < 1e-3);
+        assert_eq!(gains.edge_gain(keys, position(0, 1, 0, 2)), 0.0);
+        // The main mix is untouched, and the track's polarity carries over.
+        assert_eq!(gains.get_db("click"), Some(0.0));
+        gains.set_mix_muted(0, "keys", false).unwrap();
+        assert_eq!(gains.edge_gain(keys, position(0, 1, 0, 2)), -1.0);
+
+        assert_eq!(gains.set_mix_db(0, "keys", -80.0).unwrap(), MIN_GAIN_DB);
+        assert!(gains.set_mix_db(0, "nope", 0.0).is_err());
+        assert!(gains.set_mix_db(1, "keys", 0.0).is_err());
+
+        let snapshot = gains.snapshot_mixes();
+        assert_eq!(
+            snapshot[0].tracks,
+            vec![
+                ("click".to_string(), 6.0, false),
+                ("keys".to_string(), MIN_GAIN_DB, false)
+            ]
+        );
+        let persisted = gains.snapshot_monitor_mixes();
+        assert_eq!(persisted["drummer"].channels(), &[3, 4]);
+        assert_eq!(persisted["drummer"].gains().len(), 2);
+        assert!(persisted["drummer"].muted().is_empty());
+    }
+
+    #[test]
+    fn eq_bands_clamp_and_bump_generation() {
+        let track_eq = IndexMap::from([
+            (
+                "bgv".to_string(),
+                vec![
+                    EqBand::new(EqBandKind::HighPass, 5.0, 0.0, None),
+                    EqBand::new(EqBandKind::Peak, 3000.0, -3.0, Some(2.0)),
+                ],
+            ),
+            ("ghost".to_string(), vec![]),
+        ]);
+        let tg = TrackGains::from_config(&mappings(&["bgv", "click"]), None).with_eq(&track_eq);
+        let slot = tg.slot("bgv").unwrap();
+        assert_eq!(tg.eq_len(slot), 2);
+        assert_eq!(tg.eq_len(tg.slot("click").unwrap()), 0);
+        // Clamped on load.
+        assert_eq!(tg.eq_band(slot, 0).freq(), 20.0);
+
+        let generation = tg.eq_generation(slot);
+        let applied = tg
+            .set_eq_band("bgv", 1, None, Some(40.0), Some(0.5))
+            .unwrap()
+            .unwrap();
+        assert_eq!(applied.freq(), 3000.0);
+        assert_eq!(applied.gain(), 24.0);
+        assert_eq!(applied.q(), 0.5);
+        assert!(tg.eq_generation(slot) > generation);
+
+        assert_eq!(
+            tg.set_eq_band("bgv", 2, Some(100.0), None, None).unwrap(),
+            None
+        );
+        assert!(tg.set_eq_band("nope", 0, None, None, None).is_err());
+
+        let persisted = tg.snapshot_eq_map();
+        assert_eq!(persisted.len(), 1);
+        assert_eq!(persisted["bgv"][1].gain(), 24.0);
+    }
 }
@@ -198,6 +198,44 @@ enum Commands {
         #[arg(short = 'H', long)]
         host_port: Option<String>,
     },
+    /// Pauses the currently playing song in place.
+    Pause {
+        /// The host and port of the gRPC server.
+        #[arg(short = 'H', long)]
+        host_port: Option<String>,
+    },
+    /// Resumes a paused song from where it was paused.
+    Resume {
+        /// The host and port of the gRPC server.
+        #[arg(short = 'H', long)]
+        host_port: Option<String>,
+    },
+    /// Sets the rehearsal speed and transposition songs play at. With
+    /// neither option, prints the current rate.
+    Rate {
+        /// The host and port of the gRPC server.
+        #[arg(short = 'H', long)]
+        host_port: Option<String>,
+        /// Multiple of normal speed, 0.5 to 1.5, without changing pitch.
+        #[arg(long)]
+        speed: Option<f64>,
+        /// Transposition in semitones, -12 to 12, without changing speed.
+        #[arg(long, allow_negative_numbers = true)]
+        semitones: Option<f64>,
+    },
+    /// Arms or disarms recording of the trigger inputs. With neither option,
+    /// prints whether recording is armed and the take in progress.
+    Record {
+        /// The host and port of the gRPC server.
+        #[arg(short = 'H', long)]
+        host_port: Option<String>,
+        /// Records every song from the next one that starts.
+        #[arg(long, conflicts_with = "disarm")]
+        arm: bool,
+        /// Stops recording, finishing the take in progress.
+        #[arg(long)]
+        disarm: bool,
+    },
     /// Switches to the given playlist.
     SwitchToPlaylist {
         /// The host and port of the gRPC server.
@@ -247,6 +285,31 @@ enum Commands {
         #[arg(long, default_value = "lighting/fixture_types")]
         fixture_types_dir: String,
     },
+    /// Imports an MVR venue: writes a fixture type per referenced GDTF mode
+    /// and seeds a .venue file with every fixture's address and position.
+    ImportMvr {
+        /// Path to the .mvr file to import.
+        mvr_path: String,
+        /// Name for the venue (defaults to the MVR's file name).
+        #[arg(short, long)]
+        name: Option<String>,
+        /// Scene fixture whose spot on the deck becomes the stage origin
+        /// (downstage-center).
+        #[arg(long)]
+        origin_fixture: Option<String>,
+        /// Scene point, in meters as "x,y,z", that becomes the stage origin.
+        #[arg(long, conflicts_with = "origin_fixture", allow_hyphen_values = true)]
+        origin: Option<String>,
+        /// Project directory the import writes into.
+        #[arg(short, long, default_value = ".")]
+        project: String,
+        /// Fixture types directory, relative to the project.
+        #[arg(long, default_value = "lighting/fixture_types")]
+        fixture_types_dir: String,
+        /// Venues directory, relative to the project.
+        #[arg(long, default_value = "lighting/venues")]
+        venues_dir: String,
+    },
     /// Verifies the syntax of a light show file.
     VerifyLightShow {
         /// The path to the light show file to verify.
@@ -456,6 +519,25 @@ pub async fn run(tui_mode: bool) -> Result<(), Box<dyn Error>> {
         Commands::Previous { host_port } => remote::previous(host_port).await?,
         Commands::Next { host_port } => remote::next(host_port).await?,
         Commands::Stop { host_port } => remote::stop(host_port).await?,
+        Commands::Pause { host_port } => remote::pause(host_port).await?,
+        Commands::Resume { host_port } => remote::resume(host_port).await?,
+        Commands::Rate {
+            host_port,
+            speed,
+            semitones,
+        } => remote::rate(host_port, speed, semitones).await?,
+        Commands::Record {
+            host_port,
+            arm,
+            disarm,
+        } => {
+            let armed = match (arm, disarm) {
+                (true, _) => Some(true),
+                (_, true) => Some(false),
+                _ => None,
+            };
+            remote::record(host_port, armed).await?
+        }
         Commands::SwitchToPlaylist {
             host_port,
             playlist_name,
@@ -495,6 +577,23 @@ pub async fn run(tui_mode: bool) -> Result<(), Box<dyn Error>> {
             &project,
             &fixture_types_dir,
         )?,
+        Commands::ImportMvr {
+            mvr_path,
+            name,
+            origin_fixture,
+            origin,
+            project,
+            fixture_types_dir,
+            venues_dir,
+        } => local::import_mvr(
+            &mvr_path,
+            name.as_deref(),
+            origin_fixture.as_deref(),
+            origin.as_deref(),
+            &project,
+            &fixture_types_dir,
+            &venues_dir,
+        )?,
         Commands::VerifyLightShow { show_path, config } => {
             local::verify_light_show(&show_path, config.as_deref())?
         }
@@ -1241,6 +1340,56 @@ mod tests {
             }
         }
 
+        #[test]
+        fn parse_pause_and_resume_commands() {
+            let cli = Cli::try_parse_from(["mtrack", "pause"]).unwrap();
+            assert!(matches!(cli.command, Commands::Pause { host_port: None }));
+            let cli = Cli::try_parse_from(["mtrack", "resume", "-H", "10.0.0.1:43234"]).unwrap();
+            match cli.command {
+                Commands::Resume { host_port } => {
+                    assert_eq!(host_port.as_deref(), Some("10.0.0.1:43234"));
+                }
+                _ => panic!("expected Resume command"),
+            }
+        }
+
+        #[test]
+        fn parse_rate_command() {
+            let cli =
+                Cli::try_parse_from(["mtrack", "rate", "--speed", "0.8", "--semitones", "-2"])
+                    .unwrap();
+            match cli.command {
+                Commands::Rate {
+                    host_port,
+                    speed,
+                    semitones,
+                } => {
+                    assert!(host_port.is_none());
+                    assert_eq!(speed, Some(0.8));
+                    assert_eq!(semitones, Some(-2.0));
+                }
+                _ => panic!("expected Rate command"),
+            }
+        }
+
+        #[test]
+        fn parse_record_command() {
+            let cli = Cli::try_parse_from(["mtrack", "record", "--arm"]).unwrap();
+            match cli.command {
+                Commands::Record {
+                    host_port,
+                    arm,
+                    disarm,
+                } => {
+                    assert!(host_port.is_none());
+                    assert!(arm);
+                    assert!(!disarm);
+                }
+                _ => panic!("expected Record command"),
+            }
+            assert!(Cli::try_parse_from(["mtrack", "record", "--arm", "--disarm"]).is_err());
+        }
+
         #[test]
         fn parse_status_command() {
             let cli = Cli::try_parse_from(["mtrack", "status"]).unwrap();
@@ -277,7 +277,8 @@ pub async fn start(
     player.set_default_metronome(default_metronome);
 
     // Create the state watch channel upfront. The sampler will be started
-    // by init_hardware_async when the DMX engine becomes available.
+    // by init_hardware_async when the DMX engine or audio meters become
+    // available.
     let (state_tx, state_rx) =
         tokio::sync::watch::channel(std::sync::Arc::new(crate::state::StateSnapshot::default()));
     player.set_state_tx(state_tx);
@@ -677,6 +678,104 @@ pub fn import_gdtf(
     Ok(())
 }
 
+/// Imports an MVR venue and prints the import report.
+pub fn import_mvr(
+    mvr_path: &str,
+    name: Option<&str>,
+    origin_fixture: Option<&str>,
+    origin: Option<&str>,
+    project: &str,
+    fixture_types_dir: &str,
+    venues_dir: &str,
+) -> Result<(), Box<dyn Error>> {
+    use crate::lighting::import::{parse_origin_point, MvrOrigin};
+
+    let origin = match (origin_fixture, origin) {
+        (Some(fixture), _) => Some(MvrOrigin::Fixture(fixture.to_string())),
+        (None, Some(point)) => Some(MvrOrigin::Point(parse_origin_point(point)?)),
+        (None, None) => None,
+    };
+    let report = crate::lighting::import::import_mvr(
+        Path::new(mvr_path),
+        name,
+        origin.as_ref(),
+        Path::new(project),
+        fixture_types_dir,
+        venues_dir,
+    )?;
+
+    println!(
+        "Imported venue \"{}\" into {}:",
+        report.venue_name, report.venue_file
+    );
+    for fixture_type in &report.fixture_types {
+        println!(
+            "  fixture type \"{}\" ({} mode \"{}\"): {}{}",
+            fixture_type.type_name,
+            fixture_type.gdtf,
+            fixture_type.mode,
+            fixture_type.fixture_file,
+            if fixture_type.import.is_none() {
+                " (existing)"
+            } else {
+                ""
+            }
+        );
+    }
+    for fixture in &report.fixtures {
+        let [x, y, z] = fixture.position;
+        println!(
+            "  fixture \"{}\" {} @ {}:{} at ({x}, {y}, {z})",
+            fixture.name, fixture.fixture_type, fixture.universe, fixture.address
+        );
+    }
+    for todo in &report.todos {
+        println!("  TODO \"{}\": {}", todo.fixture, todo.reason);
+    }
+    let distill_warnings = report
+        .fixture_types
+        .iter()
+        .filter_map(|t| t.import.as_ref())
+        .flat_map(|import| import.warnings.iter());
+    let warnings: Vec<&String> = report.warnings.iter().chain(distill_warnings).collect();
+    if warnings.is_empty() {
+        println!("  no import warnings");
+    } else {
+        println!("  {} warning(s):", warnings.len());
+        for warning in warnings {
+            println!("    {warning}");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod import_mvr_tests {
+    use super::*;
+
+    #[test]
+    fn importing_delegates_to_the_shared_importer() {
+        let dir = tempfile::tempdir().unwrap();
+        let mvr = dir.path().join("club.mvr");
+        std::fs::write(&mvr, crate::lighting::mvr::synthetic_mvr()).unwrap();
+        let project = dir.path().join("project");
+        std::fs::create_dir_all(&project).unwrap();
+
+        import_mvr(
+            mvr.to_str().unwrap(),
+            None,
+            None,
+            Some("-2,1.5,0"),
+            project.to_str().unwrap(),
+            "lighting/fixture_types",
+            "lighting/venues",
+        )
+        .unwrap();
+        let venue = std::fs::read_to_string(project.join("lighting/venues/club.venue")).unwrap();
+        assert!(venue.contains("position (0, 0, 6)"), "{venue}");
+    }
+}
+
 #[cfg(test)]
 mod import_gdtf_tests {
     use super::*;
@@ -16,9 +16,10 @@ use crate::config;
 use crate::lighting::parser::utils::parse_time_string;
 use crate::proto::player::v1::player_service_client::PlayerServiceClient;
 use crate::proto::player::v1::{
-    GetActiveEffectsRequest, GetCuesRequest, NextRequest, PlayFromRequest, PlayRequest,
-    PreviousRequest, SeekRequest, SeekToSectionRequest, Song, StatusRequest, StopRequest,
-    SwitchToPlaylistRequest,
+    GetActiveEffectsRequest, GetCuesRequest, GetPlaybackRateRequest, GetRecordArmRequest,
+    NextRequest, PauseRequest, PlayFromRequest, PlayRequest, PreviousRequest, ResumeRequest,
+    SeekRequest, SeekToSectionRequest, SetPlaybackRateRequest, SetRecordArmRequest, Song,
+    StatusRequest, StopRequest, SwitchToPlaylistRequest,
 };
 use crate::util;
 use std::error::Error;
@@ -123,6 +124,79 @@ pub async fn stop(host_port: Option<String>) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+pub async fn pause(host_port: Option<String>) -> Result<(), Box<dyn Error>> {
+    let mut client = connect(host_port).await?;
+    let response = client.pause(Request::new(PauseRequest {})).await?;
+    println!("The song was paused:");
+    print_song(response.into_inner().song)?;
+    Ok(())
+}
+
+pub async fn resume(host_port: Option<String>) -> Result<(), Box<dyn Error>> {
+    let mut client = connect(host_port).await?;
+    let response = client.resume(Request::new(ResumeRequest {})).await?;
+    println!("The song was resumed:");
+    print_song(response.into_inner().song)?;
+    Ok(())
+}
+
+pub async fn rate(
+    host_port: Option<String>,
+    speed: Option<f64>,
+    semitones: Option<f64>,
+) -> Result<(), Box<dyn Error>> {
+    let mut client = connect(host_port).await?;
+    let rate = if speed.is_none() && semitones.is_none() {
+        client
+            .get_playback_rate(Request::new(GetPlaybackRateRequest {}))
+            .await?
+            .into_inner()
+            .rate
+    } else {
+        client
+            .set_playback_rate(Request::new(SetPlaybackRateRequest { speed, semitones }))
+            .await?
+            .into_inner()
+            .rate
+    }
+    .unwrap_or_default();
+    println!("Speed: {}%", (rate.speed * 100.0).round());
+    println!("Transpose: {:+} semitones", rate.semitones);
+    Ok(())
+}
+
+pub async fn record(host_port: Option<String>, armed: Option<bool>) -> Result<(), Box<dyn Error>> {
+    let mut client = connect(host_port).await?;
+    let record = match armed {
+        Some(armed) => {
+            client
+                .set_record_arm(Request::new(SetRecordArmRequest { armed }))
+                .await?
+                .into_inner()
+                .record
+        }
+        None => {
+            client
+                .get_record_arm(Request::new(GetRecordArmRequest {}))
+                .await?
+                .into_inner()
+                .record
+        }
+    };
+    let Some(record) = record else {
+        println!("Recording is not configured");
+        return Ok(());
+    };
+    println!(
+        "Recording: {}",
+        if record.armed { "armed" } else { "disarmed" }
+    );
+    if let Some(take) = record.take {
+        println!("Take: {}", take);
+    }
+    Ok(())
+}
+
 pub async fn switch_to_playlist(
     host_port: Option<String>,
     playlist_name: &str,
@@ -152,6 +226,7 @@ pub async fn status(host_port: Option<String>) -> Result<(), Box<dyn Error>> {
         println!("Elapsed: {}/{}", elapsed, song_duration);
     }
     println!("Playing: {}", response.playing);
+    println!("Paused: {}", response.paused);
     println!("Playlist name: {}", response.playlist_name);
     Ok(())
 }
@@ -303,6 +378,30 @@ mod tests {
             assert!(result.is_err());
         }
 
+        #[tokio::test]
+        async fn pause_fails_without_server() {
+            let result = pause(Some(unused_addr())).await;
+            assert!(result.is_err());
+        }
+
+        #[tokio::test]
+        async fn resume_fails_without_server() {
+            let result = resume(Some(unused_addr())).await;
+            assert!(result.is_err());
+        }
+
+        #[tokio::test]
+        async fn rate_fails_without_server() {
+            let result = rate(Some(unused_addr()), Some(0.8), None).await;
+            assert!(result.is_err());
+        }
+
+        #[tokio::test]
+        async fn record_fails_without_server() {
+            let result = record(Some(unused_addr()), Some(true)).await;
+            assert!(result.is_err());
+        }
+
         #[tokio::test]
         async fn status_fails_without_server() {
             let result = status(Some(unused_addr())).await;
@@ -11,10 +11,11 @@
 // You should have received a copy of the GNU General Public License along with
 // this program. If not, see <https://www.gnu.org/licenses/>.
 //
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Condvar, Mutex};
 use std::time::{Duration, Instant};
 
+use crate::audio::PlaybackRate;
 use crate::playsync::CancelHandle;
 
 /// A playback clock that provides elapsed time since playback started.
@@ -22,10 +23,16 @@ use crate::playsync::CancelHandle;
 /// When an audio device is present, the clock derives time from the audio
 /// interface's hardware sample counter, ensuring MIDI and DMX stay synchronized
 /// with audio. When no audio device is present, the clock falls back to
-/// `Instant::now()` (system monotonic clock).
+/// `Instant::now()` (system monotonic clock), or follows an external transport
+/// when mtrack is chasing one.
 ///
 /// The clock must be `start()`ed (typically right after the playback barrier
 /// releases) before `elapsed()` returns meaningful values.
+///
+/// The clock can also be paused. A paused clock holds `elapsed()` where it
+/// stopped, so everything chasing it — MIDI events, the lighting timeline, the
+/// mixer's song sources — holds with it, and picks up from the same point on
+/// `resume()`.
 #[derive(Clone)]
 pub struct PlaybackClock {
     inner: Arc<ClockShared>,
@@ -36,6 +43,13 @@ struct ClockShared {
     /// Condvar+Mutex used to wake threads waiting for `start()`.
     start_condvar: Condvar,
     start_mutex: Mutex<bool>,
+    /// Set by `hold_at_start()`: the next `start()` begins paused. Only
+    /// written under `start_mutex`.
+    start_held: AtomicBool,
+    /// Rehearsal speed and transposition, as f64 bits. Speed scales
+    /// `elapsed()` so song positions advance at the rate the audio plays.
+    speed: AtomicU64,
+    semitones: AtomicU64,
 }
 
 enum ClockSource {
@@ -45,14 +59,126 @@ enum ClockSource {
         sample_rate: u32,
         /// Sentinel value u64::MAX means "not started yet".
         start_sample: AtomicU64,
+        /// Sample at which the transport holds. u64::MAX means "running".
+        hold_sample: AtomicU64,
+        /// Sample the fade-in after the last resume is anchored to. u64::MAX
+        /// means "no resume yet".
+        resume_sample: AtomicU64,
+        /// Total samples spent held since `start()`. Subtracting this from a
+        /// mixer sample gives the transport position anything scheduled
+        /// against this clock is measured in.
+        held_samples: AtomicU64,
+        /// Serializes pause/resume against `elapsed()`. Resuming moves both
+        /// the start and the hold sample, and a reader that saw one without
+        /// the other would see the position jump.
+        pause_lock: parking_lot::Mutex<()>,
+        /// Sample a scheduled `start()` begins counting at. u64::MAX means
+        /// "start immediately".
+        entry_sample: AtomicU64,
+        /// Fade-in length for the song's audio from a scheduled start.
+        entry_fade_samples: AtomicU64,
+        /// How far the song's audio runs behind the clock, in samples.
+        audio_skew: AtomicU64,
     },
     /// Falls back to system monotonic clock.
     Wall {
         /// None means "not started yet".
         start_instant: parking_lot::Mutex<Option<Instant>>,
+        /// When the clock was paused. Always locked after `start_instant`.
+        paused_at: parking_lot::Mutex<Option<Instant>>,
+    },
+    /// Follows an external transport.
+    External {
+        time: Arc<dyn ExternalTime>,
+        /// The external position `elapsed()` counts from.
+        origin: Duration,
+        /// The last elapsed time read. None means "not started yet". Held
+        /// while paused, and while the external transport has no position.
+        last: parking_lot::Mutex<Option<Duration>>,
+        paused: AtomicBool,
     },
 }
 
+/// A time source outside mtrack that a clock can follow.
+pub trait ExternalTime: Send + Sync {
+    /// Where the external transport is now, or None while it is stopped or
+    /// hasn't been located.
+    fn position(&self) -> Option<Duration>;
+}
+
+/// A start scheduled ahead of the sample counter: the sample the clock begins
+/// counting at, and how long the song's audio fades in over from there.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScheduledEntry {
+    pub start_sample: u64,
+    pub fade_in_samples: u64,
+}
+
+/// Where a sample-clocked transport holds and where it last resumed, read by
+/// the mixer to park song sources and ramp them out and back in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HoldWindow {
+    /// Sample at which playback holds, if a pause is in effect.
+    pub hold_at: Option<u64>,
+    /// Sample the fade-in after the last resume starts from.
+    pub resumed_at: Option<u64>,
+    /// Length of the fade-out before `hold_at` and the fade-in after
+    /// `resumed_at`, in samples.
+    pub fade_samples: u64,
+    /// Total samples the transport has spent held.
+    pub held_samples: u64,
+}
+
+impl HoldWindow {
+    /// Gain to apply to a held source at the given sample: ramps to zero
+    /// over the fade before the hold point and back up after a resume.
+    pub fn gain_at(&self, sample: u64) -> f32 {
+        if self.fade_samples == 0 {
+            return if self.hold_at.is_some_and(|h| sample >= h) {
+                0.0
+            } else {
+                1.0
+            };
+        }
+        let fade = self.fade_samples as f32;
+        let mut gain = 1.0;
+        if let Some(hold_at) = self.hold_at {
+            gain *= (hold_at.saturating_sub(sample) as f32 / fade).min(1.0);
+        }
+        if let Some(resumed_at) = self.resumed_at {
+            if sample >= resumed_at {
+                gain *= ((sample - resumed_at) as f32 / fade).min(1.0);
+            } else {
+                gain = 0.0;
+            }
+        }
+        gain
+    }
+
+    /// Maps a mixer sample to the transport sample it plays: the mixer
+    /// sample less the time spent held, stopping at the hold while paused.
+    /// Boundaries scheduled in transport samples land on the same musical
+    /// point however long the song was paused before reaching them.
+    pub fn transport_sample(&self, mixer_sample: u64) -> u64 {
+        let sample = match self.hold_at {
+            Some(h) => mixer_sample.min(h),
+            None => mixer_sample,
+        };
+        sample.saturating_sub(self.held_samples)
+    }
+
+    /// Whether either fade overlaps the sample range `[from, to)`.
+    pub fn is_ramping(&self, from: u64, to: u64) -> bool {
+        let fading_out = self
+            .hold_at
+            .is_some_and(|h| to > h.saturating_sub(self.fade_samples));
+        let fading_in = self
+            .resumed_at
+            .is_some_and(|r| from < r.saturating_add(self.fade_samples));
+        fading_out || fading_in
+    }
+}
+
 impl PlaybackClock {
     /// Creates a clock backed by an audio mixer's sample counter.
     pub fn from_sample_counter(sample_counter: Arc<AtomicU64>, sample_rate: u32) -> Self {
@@ -63,9 +189,19 @@ impl PlaybackClock {
                     sample_counter,
                     sample_rate,
                     start_sample: AtomicU64::new(u64::MAX),
+                    hold_sample: AtomicU64::new(u64::MAX),
+                    resume_sample: AtomicU64::new(u64::MAX),
+                    held_samples: AtomicU64::new(0),
+                    pause_lock: parking_lot::Mutex::new(()),
+                    entry_sample: AtomicU64::new(u64::MAX),
+                    entry_fade_samples: AtomicU64::new(0),
+                    audio_skew: AtomicU64::new(0),
                 },
                 start_condvar: Condvar::new(),
                 start_mutex: Mutex::new(false),
+                start_held: AtomicBool::new(false),
+                speed: AtomicU64::new(1f64.to_bits()),
+                semitones: AtomicU64::new(0f64.to_bits()),
             }),
         }
     }
@@ -76,37 +212,253 @@ impl PlaybackClock {
             inner: Arc::new(ClockShared {
                 source: ClockSource::Wall {
                     start_instant: parking_lot::Mutex::new(None),
+                    paused_at: parking_lot::Mutex::new(None),
                 },
                 start_condvar: Condvar::new(),
                 start_mutex: Mutex::new(false),
+                start_held: AtomicBool::new(false),
+                speed: AtomicU64::new(1f64.to_bits()),
+                semitones: AtomicU64::new(0f64.to_bits()),
             }),
         }
     }
 
+    /// Creates a clock that follows an external transport, reading zero when
+    /// the transport is at `origin`.
+    ///
+    /// It only ever moves forwards: a transport that steps backwards holds the
+    /// clock until it catches up, and relocating is left to whoever is chasing
+    /// the transport.
+    pub fn external(time: Arc<dyn ExternalTime>, origin: Duration) -> Self {
+        PlaybackClock {
+            inner: Arc::new(ClockShared {
+                source: ClockSource::External {
+                    time,
+                    origin,
+                    last: parking_lot::Mutex::new(None),
+                    paused: AtomicBool::new(false),
+                },
+                start_condvar: Condvar::new(),
+                start_mutex: Mutex::new(false),
+                start_held: AtomicBool::new(false),
+                speed: AtomicU64::new(1f64.to_bits()),
+                semitones: AtomicU64::new(0f64.to_bits()),
+            }),
+        }
+    }
+
+    /// Sets the speed and transposition playback runs at. Set before
+    /// `start()`; an external clock keeps the transport's own pace.
+    pub fn set_rate(&self, rate: PlaybackRate) {
+        self.inner
+            .speed
+            .store(rate.speed.to_bits(), Ordering::Relaxed);
+        self.inner
+            .semitones
+            .store(rate.semitones.to_bits(), Ordering::Relaxed);
+    }
+
+    /// The speed and transposition playback runs at.
+    pub fn rate(&self) -> PlaybackRate {
+        PlaybackRate {
+            speed: self.speed(),
+            semitones: f64::from_bits(self.inner.semitones.load(Ordering::Relaxed)),
+        }
+    }
+
+    /// Song seconds per second of real time.
+    fn speed(&self) -> f64 {
+        match &self.inner.source {
+            ClockSource::External { .. } => 1.0,
+            ClockSource::Audio { .. } | ClockSource::Wall { .. } => {
+                f64::from_bits(self.inner.speed.load(Ordering::Relaxed))
+            }
+        }
+    }
+
+    /// How long in real time until `elapsed()` reaches `target`, assuming
+    /// the clock isn't paused before then.
+    pub fn wall_until(&self, target: Duration) -> Duration {
+        target.saturating_sub(self.elapsed()).div_f64(self.speed())
+    }
+
+    /// Whether this clock follows an external transport.
+    pub fn is_external(&self) -> bool {
+        matches!(self.inner.source, ClockSource::External { .. })
+    }
+
     /// Marks the clock's start point, resetting the epoch for a new song.
     /// Called by `play_files` once all subsystems have signaled readiness.
     /// Subsystems wait for `elapsed() > Duration::ZERO` as the "go" signal.
     pub fn start(&self) {
+        // Held across the whole start so `hold_at_start()` either lands
+        // before it or sees the clock running and pauses it instead.
+        let mut started = self.inner.start_mutex.lock().unwrap();
+        let held = self.inner.start_held.swap(false, Ordering::Relaxed);
         match &self.inner.source {
             ClockSource::Audio {
                 sample_counter,
                 start_sample,
+                hold_sample,
+                resume_sample,
+                held_samples,
+                pause_lock,
+                entry_sample,
                 ..
             } => {
+                let _guard = pause_lock.lock();
                 let current = sample_counter.load(Ordering::Relaxed);
+                // A scheduled start that the counter has already passed
+                // starts late rather than jumping into the song.
+                let entry = entry_sample.load(Ordering::Relaxed);
+                let current = if entry != u64::MAX {
+                    let start = entry.max(current);
+                    entry_sample.store(start, Ordering::Relaxed);
+                    start
+                } else {
+                    current
+                };
                 start_sample.store(current, Ordering::Relaxed);
+                resume_sample.store(u64::MAX, Ordering::Relaxed);
+                held_samples.store(0, Ordering::Relaxed);
+                // Starting held still lets one sample through, so `elapsed()`
+                // turns non-zero as the "go" signal and every subsystem arms
+                // at the start position before holding there.
+                let hold = if held { current + 1 } else { u64::MAX };
+                hold_sample.store(hold, Ordering::Release);
             }
-            ClockSource::Wall { start_instant } => {
+            ClockSource::Wall {
+                start_instant,
+                paused_at,
+            } => {
                 let mut guard = start_instant.lock();
-                *guard = Some(Instant::now());
+                let now = Instant::now();
+                *guard = Some(now);
+                *paused_at.lock() = held.then(|| now + Duration::from_micros(1));
+            }
+            ClockSource::External { last, paused, .. } => {
+                *last.lock() = Some(Duration::ZERO);
+                paused.store(held, Ordering::Relaxed);
             }
         }
         // Wake any threads blocked in wait_for_start_or_cancel.
+        *started = true;
+        drop(started);
+        self.inner.start_condvar.notify_all();
+    }
+
+    /// Schedules the next `start()` to begin counting at a mixer sample ahead
+    /// of the counter, with the song's audio fading in over
+    /// `fade_in_samples` from there. Subsystems arm as soon as `start()` is
+    /// called and go on the scheduled sample, which is how one song hands
+    /// off to the next without a gap. A wall clock has no samples to
+    /// schedule on and starts immediately.
+    pub fn schedule_start(&self, sample: u64, fade_in_samples: u64) {
+        if let ClockSource::Audio {
+            entry_sample,
+            entry_fade_samples,
+            ..
+        } = &self.inner.source
         {
-            let mut started = self.inner.start_mutex.lock().unwrap();
-            *started = true;
+            entry_fade_samples.store(fade_in_samples, Ordering::Relaxed);
+            entry_sample.store(sample, Ordering::Relaxed);
         }
-        self.inner.start_condvar.notify_all();
+    }
+
+    /// The scheduled start this clock began on, if it was started on one.
+    pub fn scheduled_entry(&self) -> Option<ScheduledEntry> {
+        match &self.inner.source {
+            ClockSource::Audio {
+                entry_sample,
+                entry_fade_samples,
+                ..
+            } => {
+                let start_sample = entry_sample.load(Ordering::Relaxed);
+                (start_sample != u64::MAX).then(|| ScheduledEntry {
+                    start_sample,
+                    fade_in_samples: entry_fade_samples.load(Ordering::Relaxed),
+                })
+            }
+            ClockSource::Wall { .. } | ClockSource::External { .. } => None,
+        }
+    }
+
+    /// How long until a scheduled start begins counting. Zero once the
+    /// clock is running, or if it was started immediately.
+    pub fn until_running(&self) -> Duration {
+        match &self.inner.source {
+            ClockSource::Audio {
+                sample_counter,
+                sample_rate,
+                start_sample,
+                ..
+            } => {
+                let start = start_sample.load(Ordering::Relaxed);
+                if start == u64::MAX {
+                    return Duration::ZERO;
+                }
+                let ahead = start.saturating_sub(sample_counter.load(Ordering::Relaxed));
+                Duration::from_secs_f64(ahead as f64 / *sample_rate as f64)
+            }
+            ClockSource::Wall { .. } | ClockSource::External { .. } => Duration::ZERO,
+        }
+    }
+
+    /// The mixer sample at which `elapsed()` will read `elapsed`, assuming
+    /// the clock isn't paused before then. None for a wall clock, or one
+    /// that hasn't started or is paused.
+    pub fn sample_at(&self, elapsed: Duration) -> Option<u64> {
+        match &self.inner.source {
+            ClockSource::Audio {
+                sample_rate,
+                start_sample,
+                hold_sample,
+                pause_lock,
+                ..
+            } => {
+                let _guard = pause_lock.lock();
+                let start = start_sample.load(Ordering::Relaxed);
+                if start == u64::MAX || hold_sample.load(Ordering::Relaxed) != u64::MAX {
+                    return None;
+                }
+                let real = elapsed.as_secs_f64() / self.speed();
+                Some(start + (real * *sample_rate as f64).round() as u64)
+            }
+            ClockSource::Wall { .. } | ClockSource::External { .. } => None,
+        }
+    }
+
+    /// Records how many samples the song's audio runs behind the clock: the
+    /// time between `start()` and the audio device putting its sources live.
+    pub fn set_audio_skew(&self, samples: u64) {
+        if let ClockSource::Audio { audio_skew, .. } = &self.inner.source {
+            audio_skew.store(samples, Ordering::Relaxed);
+        }
+    }
+
+    /// How many samples the song's audio runs behind the clock.
+    pub fn audio_skew(&self) -> u64 {
+        match &self.inner.source {
+            ClockSource::Audio { audio_skew, .. } => audio_skew.load(Ordering::Relaxed),
+            ClockSource::Wall { .. } | ClockSource::External { .. } => 0,
+        }
+    }
+
+    /// Whether both handles refer to the same clock.
+    pub fn is_same(&self, other: &PlaybackClock) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Arms the clock to start paused, for restarting a paused song at a new
+    /// position without letting it play. If the clock has already started
+    /// it is paused instead. Returns false if it was already paused.
+    pub fn hold_at_start(&self) -> bool {
+        let started = self.inner.start_mutex.lock().unwrap();
+        if *started {
+            drop(started);
+            return self.pause();
+        }
+        !self.inner.start_held.swap(true, Ordering::Relaxed)
     }
 
     /// Whether [`PlaybackClock::start`] has been called.
@@ -165,7 +517,8 @@ impl PlaybackClock {
         }
     }
 
-    /// Returns the elapsed time since `start()` was called.
+    /// Returns the elapsed time since `start()` was called, less any time
+    /// spent paused, scaled by the playback speed so it reads song time.
     /// Returns `Duration::ZERO` if `start()` has not been called yet.
     pub fn elapsed(&self) -> Duration {
         match &self.inner.source {
@@ -173,22 +526,263 @@ impl PlaybackClock {
                 sample_counter,
                 sample_rate,
                 start_sample,
+                hold_sample,
+                pause_lock,
+                ..
             } => {
+                let _guard = pause_lock.lock();
                 let start = start_sample.load(Ordering::Relaxed);
                 if start == u64::MAX {
                     return Duration::ZERO;
                 }
-                let current = sample_counter.load(Ordering::Relaxed);
+                let current = sample_counter
+                    .load(Ordering::Relaxed)
+                    .min(hold_sample.load(Ordering::Relaxed));
                 let delta = current.saturating_sub(start);
-                Duration::from_secs_f64(delta as f64 / *sample_rate as f64)
+                Duration::from_secs_f64(delta as f64 / *sample_rate as f64 * self.speed())
             }
-            ClockSource::Wall { start_instant } => {
+            ClockSource::Wall {
+                start_instant,
+                paused_at,
+            } => {
                 let guard = start_instant.lock();
-                match *guard {
-                    Some(instant) => instant.elapsed(),
+                let real = match *guard {
+                    Some(instant) => match *paused_at.lock() {
+                        Some(paused) => paused.saturating_duration_since(instant),
+                        None => instant.elapsed(),
+                    },
                     None => Duration::ZERO,
+                };
+                real.mul_f64(self.speed())
+            }
+            ClockSource::External {
+                time,
+                origin,
+                last,
+                paused,
+            } => {
+                let mut last = last.lock();
+                let Some(previous) = *last else {
+                    return Duration::ZERO;
+                };
+                if paused.load(Ordering::Relaxed) {
+                    return previous;
+                }
+                // Never zero once started: that is the "go" signal.
+                let now = time
+                    .position()
+                    .map_or(previous, |position| position.saturating_sub(*origin))
+                    .max(previous)
+                    .max(Duration::from_nanos(1));
+                *last = Some(now);
+                now
+            }
+        }
+    }
+
+    /// Pauses the clock. Returns false if the clock hasn't started or is
+    /// already paused.
+    ///
+    /// An audio clock doesn't stop dead: the hold lands one crossfade length
+    /// ahead of the current sample, so the mixer has room to ramp the song
+    /// sources down to silence instead of cutting them mid-waveform.
+    pub fn pause(&self) -> bool {
+        match &self.inner.source {
+            ClockSource::Audio {
+                sample_counter,
+                sample_rate,
+                start_sample,
+                hold_sample,
+                pause_lock,
+                ..
+            } => {
+                let _guard = pause_lock.lock();
+                if start_sample.load(Ordering::Relaxed) == u64::MAX
+                    || hold_sample.load(Ordering::Relaxed) != u64::MAX
+                {
+                    return false;
+                }
+                let fade = crate::audio::crossfade::default_crossfade_samples(*sample_rate);
+                let current = sample_counter.load(Ordering::Relaxed);
+                hold_sample.store(current.saturating_add(fade), Ordering::Release);
+                true
+            }
+            ClockSource::Wall {
+                start_instant,
+                paused_at,
+            } => {
+                let guard = start_instant.lock();
+                let mut paused = paused_at.lock();
+                if guard.is_none() || paused.is_some() {
+                    return false;
+                }
+                *paused = Some(Instant::now());
+                true
+            }
+            ClockSource::External { last, paused, .. } => {
+                if last.lock().is_none() || paused.load(Ordering::Relaxed) {
+                    return false;
+                }
+                // Read once more so the hold lands where the transport is now.
+                self.elapsed();
+                paused.store(true, Ordering::Relaxed);
+                true
+            }
+        }
+    }
+
+    /// Resumes a paused clock from the position it held at. Returns false if
+    /// the clock isn't paused.
+    pub fn resume(&self) -> bool {
+        {
+            let started = self.inner.start_mutex.lock().unwrap();
+            if !*started {
+                return self.inner.start_held.swap(false, Ordering::Relaxed);
+            }
+        }
+        match &self.inner.source {
+            ClockSource::Audio {
+                sample_counter,
+                start_sample,
+                hold_sample,
+                resume_sample,
+                held_samples,
+                pause_lock,
+                ..
+            } => {
+                let _guard = pause_lock.lock();
+                let hold = hold_sample.load(Ordering::Relaxed);
+                if hold == u64::MAX {
+                    return false;
+                }
+                let current = sample_counter.load(Ordering::Relaxed);
+                if current >= hold {
+                    // Shift the epoch by the time spent held so elapsed()
+                    // carries on from the held position.
+                    start_sample.fetch_add(current - hold, Ordering::Relaxed);
+                    held_samples.fetch_add(current - hold, Ordering::Relaxed);
+                    resume_sample.store(current, Ordering::Relaxed);
+                } else {
+                    // Resumed before the fade-out finished. The clock never
+                    // actually held, so the epoch stays put; anchor the fade-in
+                    // so it picks up at the gain the fade-out had reached:
+                    // the samples left before the hold are the fade-in
+                    // samples already "spent".
+                    resume_sample.store(current.saturating_sub(hold - current), Ordering::Relaxed);
+                }
+                // Released last: the lock-free reader in `hold_window()` must
+                // not see the hold lift without the fade-in and offset that
+                // go with it, or it plays a buffer at full gain and clicks.
+                hold_sample.store(u64::MAX, Ordering::Release);
+                true
+            }
+            ClockSource::Wall {
+                start_instant,
+                paused_at,
+            } => {
+                let mut guard = start_instant.lock();
+                let mut paused = paused_at.lock();
+                let Some(paused_instant) = paused.take() else {
+                    return false;
+                };
+                if let Some(start) = guard.as_mut() {
+                    *start += paused_instant.elapsed();
+                }
+                true
+            }
+            // The transport didn't stop for us, so there is nothing to carry
+            // on from: the clock picks up wherever it has got to.
+            ClockSource::External { paused, .. } => paused.swap(false, Ordering::Relaxed),
+        }
+    }
+
+    /// The position the clock holds at while paused, or None if it is
+    /// running. For an audio clock this can still be slightly ahead of
+    /// `elapsed()` while the fade-out ahead of the hold plays.
+    pub fn hold_position(&self) -> Option<Duration> {
+        match &self.inner.source {
+            ClockSource::Audio {
+                sample_rate,
+                start_sample,
+                hold_sample,
+                pause_lock,
+                ..
+            } => {
+                let _guard = pause_lock.lock();
+                let hold = hold_sample.load(Ordering::Relaxed);
+                if hold == u64::MAX {
+                    return None;
+                }
+                let delta = hold.saturating_sub(start_sample.load(Ordering::Relaxed));
+                Some(Duration::from_secs_f64(
+                    delta as f64 / *sample_rate as f64 * self.speed(),
+                ))
+            }
+            ClockSource::Wall {
+                start_instant,
+                paused_at,
+            } => {
+                let guard = start_instant.lock();
+                let paused = (*paused_at.lock())?;
+                guard.map(|start| {
+                    paused
+                        .saturating_duration_since(start)
+                        .mul_f64(self.speed())
+                })
+            }
+            ClockSource::External { last, paused, .. } => {
+                if !paused.load(Ordering::Relaxed) {
+                    return None;
+                }
+                *last.lock()
+            }
+        }
+    }
+
+    /// Whether the clock is currently paused, or armed to start paused.
+    pub fn is_paused(&self) -> bool {
+        if self.inner.start_held.load(Ordering::Relaxed) {
+            return true;
+        }
+        match &self.inner.source {
+            ClockSource::Audio { hold_sample, .. } => {
+                hold_sample.load(Ordering::Acquire) != u64::MAX
+            }
+            ClockSource::Wall { paused_at, .. } => paused_at.lock().is_some(),
+            ClockSource::External { paused, .. } => paused.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Maps a mixer sample to this clock's transport sample. See
+    /// [`HoldWindow::transport_sample`].
+    pub fn transport_sample(&self, mixer_sample: u64) -> u64 {
+        self.hold_window().transport_sample(mixer_sample)
+    }
+
+    /// The current hold window, for mixing sources that follow this clock.
+    /// Lock-free, so it is safe to call from the audio callback. A wall
+    /// clock has no samples to hold at and always reports an empty window.
+    pub fn hold_window(&self) -> HoldWindow {
+        match &self.inner.source {
+            ClockSource::Audio {
+                sample_rate,
+                hold_sample,
+                resume_sample,
+                held_samples,
+                ..
+            } => {
+                // Hold first: its Acquire pairs with the Release in
+                // `resume()`, so a lifted hold comes with its fade-in.
+                let hold = hold_sample.load(Ordering::Acquire);
+                let resumed = resume_sample.load(Ordering::Relaxed);
+                HoldWindow {
+                    hold_at: (hold != u64::MAX).then_some(hold),
+                    resumed_at: (resumed != u64::MAX).then_some(resumed),
+                    fade_samples: crate::audio::crossfade::default_crossfade_samples(*sample_rate),
+                    held_samples: held_samples.load(Ordering::Relaxed),
                 }
             }
+            ClockSource::Wall { .. } | ClockSource::External { .. } => HoldWindow::default(),
         }
     }
 }
@@ -266,6 +860,107 @@ mod tests {
         );
     }
 
+    #[test]
+    fn scheduled_start_counts_from_entry() {
+        let counter = Arc::new(AtomicU64::new(1000));
+        let clock = PlaybackClock::from_sample_counter(counter.clone(), 48000);
+        clock.schedule_start(49_000, 480);
+        clock.start();
+        assert!(clock.has_started());
+        assert_eq!(
+            clock.scheduled_entry(),
+            Some(ScheduledEntry {
+                start_sample: 49_000,
+                fade_in_samples: 480
+            })
+        );
+        assert_eq!(clock.elapsed(), Duration::ZERO);
+        assert_eq!(clock.until_running(), Duration::from_secs(1));
+
+        counter.store(49_000 + 24_000, Ordering::Relaxed);
+        assert_eq!(clock.until_running(), Duration::ZERO);
+        assert_eq!(clock.elapsed(), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn rate_scales_song_time() {
+        let counter = Arc::new(AtomicU64::new(0));
+        let clock = PlaybackClock::from_sample_counter(counter.clone(), 48000);
+        clock.set_rate(PlaybackRate::new(0.5, -3.0).unwrap());
+        assert_eq!(clock.rate(), PlaybackRate::new(0.5, -3.0).unwrap());
+        clock.start();
+
+        // Two seconds of audio play one second of the song.
+        counter.store(96_000, Ordering::Relaxed);
+        assert_eq!(clock.elapsed(), Duration::from_secs(1));
+        assert_eq!(clock.sample_at(Duration::from_secs(2)), Some(192_000));
+        assert_eq!(
+            clock.wall_until(Duration::from_secs(2)),
+            Duration::from_secs(2)
+        );
+
+        clock.pause();
+        assert_eq!(
+            clock.hold_position(),
+            Some(Duration::from_secs_f64(96_480.0 / 48_000.0 * 0.5))
+        );
+    }
+
+    #[test]
+    fn external_clock_ignores_rate() {
+        let (transport, clock) = external_clock(Duration::ZERO);
+        clock.set_rate(PlaybackRate::new(0.5, 0.0).unwrap());
+        clock.start();
+        *transport.0.lock() = Some(Duration::from_secs(3));
+        assert_eq!(clock.elapsed(), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn late_scheduled_start_starts_now() {
+        let counter = Arc::new(AtomicU64::new(10_000));
+        let clock = PlaybackClock::from_sample_counter(counter.clone(), 48000);
+        clock.schedule_start(5_000, 0);
+        clock.start();
+        assert_eq!(clock.scheduled_entry().unwrap().start_sample, 10_000);
+        counter.store(34_000, Ordering::Relaxed);
+        assert_eq!(clock.elapsed(), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn wall_clock_ignores_schedule() {
+        let clock = PlaybackClock::wall();
+        clock.schedule_start(1_000_000, 0);
+        clock.start();
+        assert_eq!(clock.scheduled_entry(), None);
+        assert_eq!(clock.until_running(), Duration::ZERO);
+        assert_eq!(clock.sample_at(Duration::from_secs(1)), None);
+    }
+
+    #[test]
+    fn sample_at_follows_held_time() {
+        let counter = Arc::new(AtomicU64::new(1000));
+        let clock = PlaybackClock::from_sample_counter(counter.clone(), 48000);
+        assert_eq!(clock.sample_at(Duration::from_secs(1)), None);
+        clock.start();
+        assert_eq!(clock.sample_at(Duration::from_secs(1)), Some(49_000));
+
+        // Paused: no prediction until the transport is running again.
+        assert!(clock.pause());
+        assert_eq!(clock.sample_at(Duration::from_secs(1)), None);
+        counter.store(1480 + 48_000, Ordering::Relaxed);
+        assert!(clock.resume());
+        assert_eq!(clock.sample_at(Duration::from_secs(1)), Some(97_000));
+    }
+
+    #[test]
+    fn is_same_distinguishes_clocks() {
+        let counter = Arc::new(AtomicU64::new(0));
+        let clock = PlaybackClock::from_sample_counter(counter.clone(), 48000);
+        let other = PlaybackClock::from_sample_counter(counter, 48000);
+        assert!(clock.is_same(&clock.clone()));
+        assert!(!clock.is_same(&other));
+    }
+
     #[test]
     fn clone_shares_state() {
         let counter = Arc::new(AtomicU64::new(0));
@@ -328,4 +1023,265 @@ mod tests {
         let clock2 = PlaybackClock::wall();
         assert_eq!(clock2.elapsed(), Duration::ZERO);
     }
+
+    #[test]
+    fn audio_clock_pause_holds_after_fade() {
+        let counter = Arc::new(AtomicU64::new(0));
+        let clock = PlaybackClock::from_sample_counter(counter.clone(), 48000);
+        clock.start();
+        counter.store(48000, Ordering::Relaxed);
+        assert!(clock.pause());
+        assert!(clock.is_paused());
+        // Pausing twice is a no-op.
+        assert!(!clock.pause());
+
+        // The hold lands one crossfade (10ms = 480 samples) ahead.
+        let window = clock.hold_window();
+        assert_eq!(window.hold_at, Some(48480));
+        assert_eq!(window.fade_samples, 480);
+
+        counter.store(96000, Ordering::Relaxed);
+        assert_eq!(clock.elapsed(), Duration::from_secs_f64(48480.0 / 48000.0));
+        assert_eq!(clock.hold_position(), Some(clock.elapsed()));
+    }
+
+    #[test]
+    fn audio_clock_resume_continues_from_hold() {
+        let counter = Arc::new(AtomicU64::new(0));
+        let clock = PlaybackClock::from_sample_counter(counter.clone(), 48000);
+        clock.start();
+        counter.store(48000, Ordering::Relaxed);
+        clock.pause();
+        counter.store(480000, Ordering::Relaxed);
+        let held = clock.elapsed();
+        assert!(clock.resume());
+        assert!(!clock.is_paused());
+        assert!(!clock.resume());
+        assert_eq!(clock.elapsed(), held);
+        assert_eq!(clock.hold_window().resumed_at, Some(480000));
+
+        // The transport picks up where it held, not where the mixer is.
+        assert_eq!(clock.hold_window().held_samples, 480000 - 48480);
+        assert_eq!(clock.transport_sample(480000), 48480);
+
+        counter.store(480000 + 24000, Ordering::Relaxed);
+        assert!(
+            (clock.elapsed().as_secs_f64() - (held.as_secs_f64() + 0.5)).abs() < 0.001,
+            "elapsed: {:?}",
+            clock.elapsed()
+        );
+    }
+
+    #[test]
+    fn audio_clock_resume_during_fade_out_keeps_epoch() {
+        let counter = Arc::new(AtomicU64::new(0));
+        let clock = PlaybackClock::from_sample_counter(counter.clone(), 48000);
+        clock.start();
+        counter.store(48000, Ordering::Relaxed);
+        clock.pause();
+        // Resume a quarter of the way into the fade-out.
+        counter.store(48120, Ordering::Relaxed);
+        assert!(clock.resume());
+        assert_eq!(clock.elapsed(), Duration::from_secs_f64(48120.0 / 48000.0));
+        // The fade-in is anchored so it starts at the fade-out's gain (0.75).
+        let window = clock.hold_window();
+        assert!((window.gain_at(48120) - 0.75).abs() < 1e-6);
+    }
+
+    #[test]
+    fn transport_sample_stops_at_hold() {
+        let counter = Arc::new(AtomicU64::new(0));
+        let clock = PlaybackClock::from_sample_counter(counter.clone(), 48000);
+        clock.start();
+        assert_eq!(clock.transport_sample(1000), 1000);
+        counter.store(1000, Ordering::Relaxed);
+        clock.pause();
+        // Up to the hold the transport tracks the mixer, then stands still.
+        assert_eq!(clock.transport_sample(1200), 1200);
+        assert_eq!(clock.transport_sample(90000), 1480);
+
+        // Held time accumulates over repeated pauses.
+        counter.store(2480, Ordering::Relaxed);
+        clock.resume();
+        counter.store(3000, Ordering::Relaxed);
+        clock.pause();
+        counter.store(5480, Ordering::Relaxed);
+        clock.resume();
+        assert_eq!(clock.hold_window().held_samples, 1000 + 2000);
+        assert_eq!(clock.transport_sample(5480), 2480);
+
+        // A new start resets it.
+        clock.start();
+        assert_eq!(clock.hold_window().held_samples, 0);
+    }
+
+    #[test]
+    fn audio_clock_pause_before_start_is_rejected() {
+        let counter = Arc::new(AtomicU64::new(0));
+        let clock = PlaybackClock::from_sample_counter(counter, 48000);
+        assert!(!clock.pause());
+        assert!(!clock.is_paused());
+    }
+
+    #[test]
+    fn start_clears_pause() {
+        let counter = Arc::new(AtomicU64::new(0));
+        let clock = PlaybackClock::from_sample_counter(counter.clone(), 48000);
+        clock.start();
+        clock.pause();
+        clock.start();
+        assert!(!clock.is_paused());
+        assert_eq!(clock.hold_window().hold_at, None);
+    }
+
+    #[test]
+    fn hold_at_start_starts_paused() {
+        let counter = Arc::new(AtomicU64::new(1000));
+        let clock = PlaybackClock::from_sample_counter(counter.clone(), 48000);
+        assert!(clock.hold_at_start());
+        assert!(!clock.hold_at_start());
+        assert!(clock.is_paused());
+        clock.start();
+        assert!(clock.is_paused());
+        // One sample gets through, so the "go" signal still fires.
+        counter.store(5000, Ordering::Relaxed);
+        assert_eq!(clock.elapsed(), Duration::from_secs_f64(1.0 / 48000.0));
+        assert_eq!(clock.hold_window().hold_at, Some(1001));
+
+        assert!(clock.resume());
+        assert_eq!(clock.hold_window().resumed_at, Some(5000));
+        assert_eq!(clock.transport_sample(5000), 1001);
+    }
+
+    #[test]
+    fn hold_at_start_disarmed_by_resume() {
+        let clock = PlaybackClock::wall();
+        assert!(clock.hold_at_start());
+        assert!(clock.resume());
+        assert!(!clock.is_paused());
+        clock.start();
+        assert!(!clock.is_paused());
+    }
+
+    #[test]
+    fn hold_at_start_after_start_pauses() {
+        let clock = PlaybackClock::wall();
+        clock.start();
+        assert!(clock.hold_at_start());
+        assert!(clock.is_paused());
+        assert!(clock.hold_position().is_some());
+    }
+
+    #[test]
+    fn wall_clock_pause_and_resume() {
+        let clock = PlaybackClock::wall();
+        clock.start();
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(clock.pause());
+        let held = clock.elapsed();
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(clock.elapsed(), held);
+        assert!(clock.resume());
+        let resumed = clock.elapsed();
+        assert!(resumed >= held);
+        assert!(resumed < held + Duration::from_millis(15));
+        assert_eq!(clock.hold_window(), HoldWindow::default());
+    }
+
+    #[test]
+    fn hold_window_gain_ramps() {
+        let window = HoldWindow {
+            hold_at: Some(1000),
+            resumed_at: None,
+            fade_samples: 100,
+            held_samples: 0,
+        };
+        assert_eq!(window.gain_at(800), 1.0);
+        assert!((window.gain_at(950) - 0.5).abs() < 1e-6);
+        assert_eq!(window.gain_at(1000), 0.0);
+        assert!(!window.is_ramping(0, 900));
+        assert!(window.is_ramping(0, 901));
+
+        let window = HoldWindow {
+            hold_at: None,
+            resumed_at: Some(5000),
+            fade_samples: 100,
+            held_samples: 0,
+        };
+        assert_eq!(window.gain_at(5000), 0.0);
+        assert!((window.gain_at(5025) - 0.25).abs() < 1e-6);
+        assert_eq!(window.gain_at(6000), 1.0);
+        assert!(window.is_ramping(5050, 5100));
+        assert!(!window.is_ramping(5100, 5200));
+    }
+
+    /// An external transport whose position the test sets directly.
+    struct FakeTransport(parking_lot::Mutex<Option<Duration>>);
+
+    impl ExternalTime for FakeTransport {
+        fn position(&self) -> Option<Duration> {
+            *self.0.lock()
+        }
+    }
+
+    fn external_clock(origin: Duration) -> (Arc<FakeTransport>, PlaybackClock) {
+        let transport = Arc::new(FakeTransport(parking_lot::Mutex::new(None)));
+        let clock = PlaybackClock::external(transport.clone(), origin);
+        (transport, clock)
+    }
+
+    #[test]
+    fn external_clock_follows_the_transport() {
+        let (transport, clock) = external_clock(Duration::from_secs(10));
+        *transport.0.lock() = Some(Duration::from_secs(12));
+        assert_eq!(clock.elapsed(), Duration::ZERO);
+        assert!(clock.is_external());
+
+        clock.start();
+        assert_eq!(clock.elapsed(), Duration::from_secs(2));
+        *transport.0.lock() = Some(Duration::from_millis(12_500));
+        assert_eq!(clock.elapsed(), Duration::from_millis(2500));
+    }
+
+    #[test]
+    fn external_clock_goes_on_start_before_the_origin() {
+        let (transport, clock) = external_clock(Duration::from_secs(10));
+        clock.start();
+        assert!(clock.elapsed() > Duration::ZERO);
+        *transport.0.lock() = Some(Duration::from_secs(9));
+        assert!(clock.elapsed() > Duration::ZERO);
+    }
+
+    #[test]
+    fn external_clock_only_moves_forwards() {
+        let (transport, clock) = external_clock(Duration::ZERO);
+        clock.start();
+        *transport.0.lock() = Some(Duration::from_secs(3));
+        assert_eq!(clock.elapsed(), Duration::from_secs(3));
+        // Stopped, then stepped backwards: hold until it catches up.
+        *transport.0.lock() = None;
+        assert_eq!(clock.elapsed(), Duration::from_secs(3));
+        *transport.0.lock() = Some(Duration::from_secs(1));
+        assert_eq!(clock.elapsed(), Duration::from_secs(3));
+        *transport.0.lock() = Some(Duration::from_secs(4));
+        assert_eq!(clock.elapsed(), Duration::from_secs(4));
+    }
+
+    #[test]
+    fn external_clock_pause_and_resume() {
+        let (transport, clock) = external_clock(Duration::ZERO);
+        assert!(!clock.pause());
+        clock.start();
+        *transport.0.lock() = Some(Duration::from_secs(1));
+        assert!(clock.pause());
+        assert!(clock.is_paused());
+        assert_eq!(clock.hold_position(), Some(Duration::from_secs(1)));
+        *transport.0.lock() = Some(Duration::from_secs(2));
+        assert_eq!(clock.elapsed(), Duration::from_secs(1));
+
+        assert!(clock.resume());
+        assert!(!clock.resume());
+        assert_eq!(clock.hold_position(), None);
+        assert_eq!(clock.elapsed(), Duration::from_secs(2));
+    }
 }
@@ -17,11 +17,13 @@ mod dmx;
 mod error;
 mod hostname;
 pub mod lighting;
+pub mod ltc;
 pub mod metronome;
 #[cfg(test)]
 pub mod midi;
 #[cfg(not(test))]
 mod midi;
+mod monitormix;
 pub mod notification;
 pub mod pilot;
 mod player;
@@ -33,10 +35,12 @@ mod statusevents;
 pub mod store;
 mod tempo;
 mod track;
+pub mod trackeq;
 mod trackmappings;
+pub mod trackmix;
 pub mod trigger;
 
-pub use self::audio::{Audio, ResamplerType, StreamBufferSize};
+pub use self::audio::{Audio, Limiter, ResamplerType, StreamBufferSize};
 pub use self::controller::Controller;
 pub use self::controller::CustomModel;
 pub use self::controller::GrpcController;
@@ -47,8 +51,10 @@ pub use self::controller::MorningstarModel;
 pub use self::controller::OscController;
 pub use self::controller::DEFAULT_GRPC_PORT;
 pub use self::controller::DEFAULT_MCP_PORT;
+pub use self::dmx::ArtNet;
 pub use self::dmx::Dmx;
 pub use self::dmx::Universe;
+pub use self::dmx::{Sacn, SacnSource};
 // Re-exported for the hardware harness, which needs the default port to probe
 // for a running OLA daemon. Gated to match the constant itself, which (with
 // `Dmx::ola_port`) is excluded from test builds; the harness links the
@@ -57,27 +63,33 @@ pub use self::dmx::Universe;
 pub use self::dmx::DEFAULT_OLA_PORT;
 pub use self::error::ConfigError;
 pub use self::lighting::Lighting;
+pub use self::ltc::LtcConfig;
 pub use self::metronome::MetronomeConfig;
 pub use self::midi::Midi;
 pub use self::midi::MidiTransformer;
 pub use self::midi::ToMidiEvent;
+pub use self::midi::{Chase, ChaseSource, Mtc, MtcFrameRate, Timecode};
+pub use self::monitormix::MonitorMix;
 pub use self::pilot::PilotConfig;
 pub use self::player::{MetronomeDefaults, Player};
-pub use self::playlist::Playlist;
+pub use self::playlist::{AutoAdvance, Playlist, SongTransition};
 // Sample types are exported for external configuration
 pub use self::hostname::resolve_hostname;
 pub use self::notification::{NotificationConfig, SongNotificationConfig};
 pub use self::profile::Profile;
 #[allow(unused_imports)]
 pub use self::samples::{
-    ReleaseBehavior, RetriggerBehavior, SampleDefinition, SampleTrigger, SamplesConfig,
-    VelocityConfig, VelocityLayer, VelocityMode,
+    ChokeConfig, KitConfig, KitsConfig, PitchConfig, ReleaseBehavior, RetriggerBehavior,
+    SampleDefinition, SampleTrigger, SamplesConfig, SustainLoop, VariationMode, VelocityConfig,
+    VelocityLayer, VelocityMode,
 };
 pub use self::song::{LightShow, LightingShow, MidiPlayback, Section, Song};
 pub use self::statusevents::StatusEvents;
 pub use self::store::ConfigStore;
 pub use self::tempo::{parse_time_signature, TempoChangeConfig, TempoConfig, TransitionConfig};
 pub use self::track::Track;
+pub use self::trackeq::{EqBand, EqBandKind};
+pub use self::trackmix::{PanLaw, TrackMix};
 
 mod kind;
 pub use self::kind::{peek_kind, ConfigKind};
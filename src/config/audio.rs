@@ -11,16 +11,23 @@
 // You should have received a copy of the GNU General Public License along with
 // this program. If not, see <https://www.gnu.org/licenses/>.
 //
-use std::{error::Error, str::FromStr, time::Duration};
+use std::{collections::HashMap, error::Error, str::FromStr, time::Duration};
 
 use duration_string::DurationString;
 use serde::{Deserialize, Serialize};
 
+use crate::audio::track_gains::{clamp_db, db_to_linear};
 use crate::audio::SampleFormat;
 
 const DEFAULT_AUDIO_PLAYBACK_DELAY: Duration = Duration::ZERO;
 const DEFAULT_BUFFER_SIZE: usize = 1024;
 const DEFAULT_BUFFER_THREADS: usize = 2;
+const DEFAULT_METER_INTERVAL: Duration = Duration::from_millis(50);
+const DEFAULT_LIMITER_CEILING_DB: f32 = -1.0;
+const DEFAULT_LIMITER_LOOKAHEAD: Duration = Duration::from_micros(1500);
+const DEFAULT_LIMITER_RELEASE: Duration = Duration::from_millis(100);
+/// Look-ahead past this adds more latency than any in-ear mix tolerates.
+const MAX_LIMITER_LOOKAHEAD: Duration = Duration::from_millis(20);
 
 /// Which resampling algorithm to use when source and output sample rates differ.
 #[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -102,6 +109,85 @@ impl<'de> Deserialize<'de> for StreamBufferSize {
     }
 }
 
+/// A look-ahead brickwall limiter on every output channel.
+///
+/// ```yaml
+/// limiter:
+///   ceiling_db: -1.0   # no output sample goes above this (default: -1.0)
+///   lookahead: 1.5ms   # also the latency the limiter adds (default: 1.5ms)
+///   release: 100ms     # how quickly the gain recovers (default: 100ms)
+/// ```
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq)]
+pub struct Limiter {
+    /// Output ceiling in dBFS.
+    ceiling_db: Option<f32>,
+
+    /// How far ahead the limiter looks for peaks.
+    lookahead: Option<String>,
+
+    /// How long the gain takes to recover after a peak.
+    release: Option<String>,
+}
+
+impl Limiter {
+    /// Returns the ceiling in dBFS (default: -1.0), never above 0.
+    pub fn ceiling_db(&self) -> f32 {
+        self.ceiling_db
+            .filter(|db| db.is_finite())
+            .unwrap_or(DEFAULT_LIMITER_CEILING_DB)
+            .min(0.0)
+    }
+
+    /// Returns the look-ahead (default: 1.5ms). An invalid value, which
+    /// validation reports, falls back to the default.
+    pub fn lookahead(&self) -> Duration {
+        super::parse_playback_delay(&self.lookahead, DEFAULT_LIMITER_LOOKAHEAD)
+            .unwrap_or(DEFAULT_LIMITER_LOOKAHEAD)
+            .min(MAX_LIMITER_LOOKAHEAD)
+    }
+
+    /// Returns the release time (default: 100ms). An invalid value, which
+    /// validation reports, falls back to the default.
+    pub fn release(&self) -> Duration {
+        super::parse_playback_delay(&self.release, DEFAULT_LIMITER_RELEASE)
+            .unwrap_or(DEFAULT_LIMITER_RELEASE)
+    }
+
+    fn validate(&self, errors: &mut Vec<String>) {
+        if let Some(ceiling) = self.ceiling_db {
+            if !ceiling.is_finite() || ceiling > 0.0 {
+                errors.push(format!(
+                    "audio limiter ceiling_db {} must be at or below 0",
+                    ceiling
+                ));
+            }
+        }
+        if let Some(ref lookahead) = self.lookahead {
+            match DurationString::from_string(lookahead.clone()) {
+                Ok(parsed) if Duration::from(parsed) > MAX_LIMITER_LOOKAHEAD => {
+                    errors.push(format!(
+                        "audio limiter lookahead '{}' must be at most {:?}",
+                        lookahead, MAX_LIMITER_LOOKAHEAD
+                    ));
+                }
+                Ok(_) => {}
+                Err(_) => errors.push(format!(
+                    "audio limiter lookahead '{}' is not a valid duration",
+                    lookahead
+                )),
+            }
+        }
+        if let Some(ref release) = self.release {
+            if DurationString::from_string(release.clone()).is_err() {
+                errors.push(format!(
+                    "audio limiter release '{}' is not a valid duration",
+                    release
+                ));
+            }
+        }
+    }
+}
+
 /// A YAML representation of the audio configuration.
 #[derive(Deserialize, Serialize, Clone)]
 pub struct Audio {
@@ -135,6 +221,17 @@ pub struct Audio {
 
     /// Resampling algorithm: "sinc" (default, high quality) or "fft" (faster on low-power hardware).
     resampler: Option<ResamplerType>,
+
+    /// How often level meters are read and published (default: 50ms).
+    meter_interval: Option<String>,
+
+    /// Master gain in dB per output channel, keyed by 1-indexed channel.
+    /// Channels not listed stay at 0 dB.
+    master_gains: Option<HashMap<u16, f32>>,
+
+    /// Look-ahead brickwall limiter at the end of the mix. Off unless
+    /// configured, since its look-ahead adds latency.
+    limiter: Option<Limiter>,
 }
 
 impl Audio {
@@ -150,6 +247,9 @@ impl Audio {
             stream_buffer_size: None,
             buffer_threads: None,
             resampler: None,
+            meter_interval: None,
+            master_gains: None,
+            limiter: None,
         }
     }
 
@@ -202,6 +302,34 @@ impl Audio {
         self.resampler.unwrap_or_default()
     }
 
+    /// Returns how often level meters are published (default: 50ms). An
+    /// unparseable or zero value, which validation reports, falls back to
+    /// the default.
+    pub fn meter_interval(&self) -> Duration {
+        super::parse_playback_delay(&self.meter_interval, DEFAULT_METER_INTERVAL)
+            .ok()
+            .filter(|interval| !interval.is_zero())
+            .unwrap_or(DEFAULT_METER_INTERVAL)
+    }
+
+    /// Returns the linear master gain for each of `num_channels` output
+    /// channels, in channel order. Gains are clamped like track gains.
+    pub fn master_gains(&self, num_channels: u16) -> Vec<f32> {
+        (1..=num_channels)
+            .map(|channel| {
+                self.master_gains
+                    .as_ref()
+                    .and_then(|gains| gains.get(&channel))
+                    .map_or(1.0, |&db| db_to_linear(clamp_db(db)))
+            })
+            .collect()
+    }
+
+    /// Returns the limiter configuration, if the limiter is enabled.
+    pub fn limiter(&self) -> Option<&Limiter> {
+        self.limiter.as_ref()
+    }
+
     /// Sets the target sample rate.
     #[allow(dead_code)]
     pub fn with_sample_rate(mut self, sample_rate: u32) -> Self {
@@ -282,6 +410,36 @@ impl Audio {
                 errors.push("audio buffer_size must be greater than 0".to_string());
             }
         }
+        if let Some(ref interval) = self.meter_interval {
+            match DurationString::from_string(interval.clone()) {
+                Ok(parsed) if Duration::from(parsed).is_zero() => {
+                    errors.push("audio meter_interval must be greater than 0".to_string());
+                }
+                Ok(_) => {}
+                Err(_) => errors.push(format!(
+                    "audio meter_interval '{}' is not a valid duration",
+                    interval
+                )),
+            }
+        }
+        if let Some(ref gains) = self.master_gains {
+            let mut channels: Vec<_> = gains.iter().collect();
+            channels.sort_by_key(|(channel, _)| **channel);
+            for (channel, db) in channels {
+                if *channel == 0 {
+                    errors.push("audio master_gains channels start at 1".to_string());
+                }
+                if db.is_nan() {
+                    errors.push(format!(
+                        "audio master_gains channel {} gain is not a number",
+                        channel
+                    ));
+                }
+            }
+        }
+        if let Some(ref limiter) = self.limiter {
+            limiter.validate(&mut errors);
+        }
 
         if errors.is_empty() {
             Ok(())
@@ -365,6 +523,100 @@ mod test {
         assert!(audio.playback_delay().is_err());
     }
 
+    #[test]
+    fn meter_interval_default_and_custom() {
+        assert_eq!(
+            Audio::new("dev").meter_interval(),
+            Duration::from_millis(50)
+        );
+        let audio = Audio {
+            meter_interval: Some("100ms".to_string()),
+            ..Audio::new("dev")
+        };
+        assert_eq!(audio.meter_interval(), Duration::from_millis(100));
+        assert!(audio.validate().is_ok());
+    }
+
+    #[test]
+    fn meter_interval_invalid() {
+        for raw in ["soon", "0ms"] {
+            let audio = Audio {
+                meter_interval: Some(raw.to_string()),
+                ..Audio::new("dev")
+            };
+            assert_eq!(audio.meter_interval(), Duration::from_millis(50));
+            let errors = audio.validate().unwrap_err();
+            assert!(errors[0].contains("meter_interval"), "{errors:?}");
+        }
+    }
+
+    #[test]
+    fn master_gains_from_yaml() {
+        let audio = from_yaml("device: dev\nmaster_gains:\n  1: -6\n  3: 40.0\n");
+        let gains = audio.master_gains(4);
+        assert_eq!(gains.len(), 4);
+        assert!((gains[0] - 0.5012).abs() < 1e-3);
+        assert_eq!(gains[1], 1.0);
+        // Boost is clamped like a track gain.
+        assert!((gains[2] - db_to_linear(12.0)).abs() < 1e-4);
+        assert_eq!(gains[3], 1.0);
+        assert!(audio.validate().is_ok());
+    }
+
+    #[test]
+    fn master_gains_channel_zero_invalid() {
+        let audio = Audio {
+            master_gains: Some(HashMap::from([(0, -3.0)])),
+            ..Audio::new("dev")
+        };
+        let errors = audio.validate().unwrap_err();
+        assert!(errors[0].contains("master_gains"), "{errors:?}");
+    }
+
+    #[test]
+    fn limiter_off_by_default_with_defaults_when_enabled() {
+        assert!(Audio::new("dev").limiter().is_none());
+        let audio = from_yaml("device: dev\nlimiter: {}\n");
+        let limiter = audio.limiter().expect("limiter enabled");
+        assert_eq!(limiter.ceiling_db(), -1.0);
+        assert_eq!(limiter.lookahead(), Duration::from_micros(1500));
+        assert_eq!(limiter.release(), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn limiter_custom() {
+        let audio = from_yaml(
+            "device: dev\nlimiter:\n  ceiling_db: -3\n  lookahead: 5ms\n  release: 250ms\n",
+        );
+        let limiter = audio.limiter().unwrap();
+        assert_eq!(limiter.ceiling_db(), -3.0);
+        assert_eq!(limiter.lookahead(), Duration::from_millis(5));
+        assert_eq!(limiter.release(), Duration::from_millis(250));
+        assert!(audio.validate().is_ok());
+    }
+
+    #[test]
+    fn limiter_invalid() {
+        let audio = Audio {
+            limiter: Some(Limiter {
+                ceiling_db: Some(3.0),
+                lookahead: Some("1s".to_string()),
+                release: Some("eventually".to_string()),
+            }),
+            ..Audio::new("dev")
+        };
+        let errors = audio.validate().unwrap_err();
+        assert_eq!(errors.len(), 3, "{errors:?}");
+        assert!(errors[0].contains("ceiling_db"));
+        assert!(errors[1].contains("lookahead"));
+        assert!(errors[2].contains("release"));
+        // Accessors stay usable with what validation rejected.
+        let limiter = audio.limiter().unwrap();
+        assert_eq!(limiter.ceiling_db(), 0.0);
+        assert_eq!(limiter.lookahead(), MAX_LIMITER_LOOKAHEAD);
+        assert_eq!(limiter.release(), DEFAULT_LIMITER_RELEASE);
+    }
+
     #[test]
     fn buffer_threads_clamped_to_one() {
         let audio = Audio {
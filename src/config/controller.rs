@@ -35,6 +35,9 @@ fn default_osc_next() -> String {
 fn default_osc_stop() -> String {
     "/mtrack/stop".to_string()
 }
+fn default_osc_pause() -> String {
+    "/mtrack/pause".to_string()
+}
 fn default_osc_all_songs() -> String {
     "/mtrack/all_songs".to_string()
 }
@@ -62,12 +65,42 @@ fn default_osc_seek_section() -> String {
 fn default_osc_track_gain() -> String {
     "/mtrack/track/*/gain".to_string()
 }
+fn default_osc_track_pan() -> String {
+    "/mtrack/track/*/pan".to_string()
+}
+fn default_osc_track_width() -> String {
+    "/mtrack/track/*/width".to_string()
+}
+fn default_osc_track_polarity() -> String {
+    "/mtrack/track/*/polarity".to_string()
+}
+fn default_osc_track_eq_freq() -> String {
+    "/mtrack/track/*/eq/*/freq".to_string()
+}
+fn default_osc_track_eq_gain() -> String {
+    "/mtrack/track/*/eq/*/gain".to_string()
+}
+fn default_osc_track_eq_q() -> String {
+    "/mtrack/track/*/eq/*/q".to_string()
+}
+fn default_osc_mix_track_gain() -> String {
+    "/mtrack/mix/*/track/*/gain".to_string()
+}
+fn default_osc_mix_track_mute() -> String {
+    "/mtrack/mix/*/track/*/mute".to_string()
+}
 fn default_osc_status() -> String {
     "/mtrack/status".to_string()
 }
 fn default_osc_audio_health() -> String {
     "/mtrack/audio/health".to_string()
 }
+fn default_osc_channel_meter() -> String {
+    "/mtrack/meters/channel/*".to_string()
+}
+fn default_osc_track_meter() -> String {
+    "/mtrack/meters/track/*".to_string()
+}
 fn default_osc_playlist_current() -> String {
     "/mtrack/playlist/current".to_string()
 }
@@ -110,6 +143,9 @@ pub struct MidiController {
     /// The MIDI event to break out of the current section loop.
     #[serde(default)]
     stop_section_loop: Option<midi::Event>,
+    /// The MIDI event to pause the playing song, or resume a paused one.
+    #[serde(default)]
+    pause: Option<midi::Event>,
     /// Optional Morningstar controller integration for automatic preset naming.
     #[serde(default)]
     morningstar: Option<MorningstarConfig>,
@@ -134,6 +170,7 @@ impl MidiController {
             playlist,
             section_ack: None,
             stop_section_loop: None,
+            pause: None,
             morningstar: None,
         }
     }
@@ -183,6 +220,11 @@ impl MidiController {
             .transpose()
     }
 
+    /// Gets the pause event, if configured.
+    pub fn pause(&self) -> Result<Option<LiveEvent<'static>>, Box<dyn Error>> {
+        self.pause.as_ref().map(|e| e.to_midi_event()).transpose()
+    }
+
     /// Gets the optional Morningstar configuration.
     pub fn morningstar(&self) -> Option<&MorningstarConfig> {
         self.morningstar.as_ref()
@@ -333,6 +375,10 @@ pub struct OscController {
     /// The OSC address to look for to stop playback.
     #[serde(default = "default_osc_stop")]
     stop: String,
+    /// The OSC address to look for to pause the playing song, or resume a
+    /// paused one.
+    #[serde(default = "default_osc_pause")]
+    pause: String,
     /// The OSC address to look for to switch from the current playlist to an all songs playlist.
     #[serde(default = "default_osc_all_songs")]
     all_songs: String,
@@ -364,6 +410,43 @@ pub struct OscController {
     /// the name substituted) is used for gain feedback broadcasts.
     #[serde(default = "default_osc_track_gain")]
     track_gain: String,
+    /// The OSC address pattern to pan an output track, from -1 (left) to 1
+    /// (right). Addressed and fed back like `track_gain`.
+    #[serde(default = "default_osc_track_pan")]
+    track_pan: String,
+    /// The OSC address pattern to set an output track's stereo width, from 0
+    /// (mono) to 2. Addressed and fed back like `track_gain`.
+    #[serde(default = "default_osc_track_width")]
+    track_width: String,
+    /// The OSC address pattern to invert an output track's polarity (takes a
+    /// bool, int or float arg; non-zero inverts). Addressed and fed back like
+    /// `track_gain`.
+    #[serde(default = "default_osc_track_polarity")]
+    track_polarity: String,
+    /// The OSC address pattern to set the frequency in Hz of a band of an
+    /// output track's EQ. The first `*` segment carries the track name and
+    /// the second the 1-indexed band number; current values are fed back on
+    /// the same addresses.
+    #[serde(default = "default_osc_track_eq_freq")]
+    track_eq_freq: String,
+    /// The OSC address pattern to set the gain in dB of an EQ band.
+    /// Addressed and fed back like `track_eq_freq`.
+    #[serde(default = "default_osc_track_eq_gain")]
+    track_eq_gain: String,
+    /// The OSC address pattern to set the Q of an EQ band. Addressed and fed
+    /// back like `track_eq_freq`.
+    #[serde(default = "default_osc_track_eq_q")]
+    track_eq_q: String,
+    /// The OSC address pattern to set a track's gain in dB within a monitor
+    /// mix. The first `*` segment carries the mix name and the second the
+    /// track name; current gains are fed back on the same addresses.
+    #[serde(default = "default_osc_mix_track_gain")]
+    mix_track_gain: String,
+    /// The OSC address pattern to mute a track within a monitor mix (takes a
+    /// bool, int or float arg; non-zero mutes). Addressed and fed back like
+    /// `mix_track_gain`.
+    #[serde(default = "default_osc_mix_track_mute")]
+    mix_track_mute: String,
     /// The OSC address to broadcast to display the current player status.
     #[serde(default = "default_osc_status")]
     status: String,
@@ -375,6 +458,16 @@ pub struct OscController {
     /// output is stalled, and that combination is the one worth a red light.
     #[serde(default = "default_osc_audio_health")]
     audio_health: String,
+    /// The OSC address pattern to broadcast output channel levels to: peak
+    /// and RMS in dBFS (floats) and the clip count (int). The `*` segment is
+    /// the 1-indexed channel number. Empty disables the broadcast.
+    #[serde(default = "default_osc_channel_meter")]
+    channel_meter: String,
+    /// The OSC address pattern to broadcast output track levels to, with the
+    /// same arguments as `channel_meter`. The `*` segment is the track name.
+    /// Empty disables the broadcast.
+    #[serde(default = "default_osc_track_meter")]
+    track_meter: String,
     /// The OSC address to broadcast the current playlist songs.
     #[serde(default = "default_osc_playlist_current")]
     playlist_current: String,
@@ -399,6 +492,7 @@ impl Default for OscController {
             prev: default_osc_prev(),
             next: default_osc_next(),
             stop: default_osc_stop(),
+            pause: default_osc_pause(),
             all_songs: default_osc_all_songs(),
             playlist: default_osc_playlist(),
             stop_samples: default_osc_stop_samples(),
@@ -408,8 +502,18 @@ impl Default for OscController {
             seek: default_osc_seek(),
             seek_section: default_osc_seek_section(),
             track_gain: default_osc_track_gain(),
+            track_pan: default_osc_track_pan(),
+            track_width: default_osc_track_width(),
+            track_polarity: default_osc_track_polarity(),
+            track_eq_freq: default_osc_track_eq_freq(),
+            track_eq_gain: default_osc_track_eq_gain(),
+            track_eq_q: default_osc_track_eq_q(),
+            mix_track_gain: default_osc_mix_track_gain(),
+            mix_track_mute: default_osc_mix_track_mute(),
             status: default_osc_status(),
             audio_health: default_osc_audio_health(),
+            channel_meter: default_osc_channel_meter(),
+            track_meter: default_osc_track_meter(),
             playlist_current: default_osc_playlist_current(),
             playlist_current_song: default_osc_playlist_current_song(),
             playlist_current_song_elapsed: default_osc_playlist_current_song_elapsed(),
@@ -453,6 +557,11 @@ impl OscController {
         &self.stop
     }
 
+    /// Gets the pause OSC address.
+    pub fn pause(&self) -> &str {
+        &self.pause
+    }
+
     /// Gets the all songs OSC address.
     pub fn all_songs(&self) -> &str {
         &self.all_songs
@@ -498,6 +607,46 @@ impl OscController {
         &self.track_gain
     }
 
+    /// Gets the OSC address pattern for panning an output track.
+    pub fn track_pan(&self) -> &str {
+        &self.track_pan
+    }
+
+    /// Gets the OSC address pattern for setting an output track's width.
+    pub fn track_width(&self) -> &str {
+        &self.track_width
+    }
+
+    /// Gets the OSC address pattern for inverting an output track's polarity.
+    pub fn track_polarity(&self) -> &str {
+        &self.track_polarity
+    }
+
+    /// Gets the OSC address pattern for setting an EQ band's frequency.
+    pub fn track_eq_freq(&self) -> &str {
+        &self.track_eq_freq
+    }
+
+    /// Gets the OSC address pattern for setting an EQ band's gain.
+    pub fn track_eq_gain(&self) -> &str {
+        &self.track_eq_gain
+    }
+
+    /// Gets the OSC address pattern for setting an EQ band's Q.
+    pub fn track_eq_q(&self) -> &str {
+        &self.track_eq_q
+    }
+
+    /// Gets the OSC address pattern for setting a track's gain in a mix.
+    pub fn mix_track_gain(&self) -> &str {
+        &self.mix_track_gain
+    }
+
+    /// Gets the OSC address pattern for muting a track in a mix.
+    pub fn mix_track_mute(&self) -> &str {
+        &self.mix_track_mute
+    }
+
     /// Gets the player status.
     pub fn status(&self) -> &str {
         &self.status
@@ -508,6 +657,16 @@ impl OscController {
         &self.audio_health
     }
 
+    /// The address pattern output channel levels are broadcast to.
+    pub fn channel_meter(&self) -> &str {
+        &self.channel_meter
+    }
+
+    /// The address pattern output track levels are broadcast to.
+    pub fn track_meter(&self) -> &str {
+        &self.track_meter
+    }
+
     /// Gets the playlist current OSC address.
     pub fn playlist_current(&self) -> &str {
         &self.playlist_current
@@ -656,6 +815,7 @@ mod test {
         assert_eq!(osc.prev(), "/mtrack/prev");
         assert_eq!(osc.next(), "/mtrack/next");
         assert_eq!(osc.stop(), "/mtrack/stop");
+        assert_eq!(osc.pause(), "/mtrack/pause");
         assert_eq!(osc.all_songs(), "/mtrack/all_songs");
         assert_eq!(osc.playlist(), "/mtrack/playlist");
         assert_eq!(osc.stop_samples(), "/mtrack/samples/stop");
@@ -12,10 +12,15 @@
 // this program. If not, see <https://www.gnu.org/licenses/>.
 //
 
-use std::{error::Error, time::Duration};
+use std::{
+    error::Error,
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
 
 use duration_string::DurationString;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 use super::lighting::Lighting;
 
@@ -24,6 +29,12 @@ use super::lighting::Lighting;
 pub const DEFAULT_OLA_PORT: u16 = 9010;
 pub const DEFAULT_DMX_DIMMING_SPEED_MODIFIER: f64 = 1.0;
 pub const DEFAULT_DMX_PLAYBACK_DELAY: Duration = Duration::ZERO;
+/// The UDP port Art-Net nodes listen on.
+pub const DEFAULT_ARTNET_PORT: u16 = 6454;
+/// The UDP port sACN receivers listen on.
+pub const DEFAULT_SACN_PORT: u16 = 5568;
+/// The sACN priority receivers assume when none is given.
+pub const DEFAULT_SACN_PRIORITY: u8 = 100;
 
 /// A YAML representation of the DMX configuration.
 #[derive(Deserialize, Serialize, Clone)]
@@ -43,6 +54,10 @@ pub struct Dmx {
     /// The lighting configuration.
     lighting: Option<Lighting>,
 
+    /// How mtrack identifies itself as an sACN source.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sacn: Option<SacnSource>,
+
     /// When true, use a no-op OLA client if the OLA daemon is unavailable.
     /// Allows the effects engine to run (e.g. for TUI display) without OLA.
     #[serde(default)]
@@ -65,10 +80,18 @@ impl Dmx {
             ola_port,
             universes,
             lighting,
+            sacn: None,
             null_client: false,
         }
     }
 
+    /// Sets how mtrack identifies itself as an sACN source.
+    #[cfg(test)]
+    pub fn with_sacn(mut self, sacn: SacnSource) -> Dmx {
+        self.sacn = Some(sacn);
+        self
+    }
+
     /// Gets the OLA port field (for test builds to ensure field is not marked as dead code).
     #[cfg(test)]
     pub fn get_ola_port(&self) -> Option<u16> {
@@ -102,6 +125,11 @@ impl Dmx {
         self.lighting.as_ref()
     }
 
+    /// Gets how mtrack identifies itself as an sACN source.
+    pub fn sacn(&self) -> Option<&SacnSource> {
+        self.sacn.as_ref()
+    }
+
     /// Whether to fall back to a no-op OLA client when OLA is unavailable.
     pub fn null_client(&self) -> bool {
         self.null_client
@@ -133,6 +161,25 @@ impl Dmx {
             if universe.name.trim().is_empty() {
                 errors.push(format!("dmx universe[{}]: name must not be empty", i));
             }
+            if let Some(ref artnet) = universe.artnet {
+                for error in artnet.validate() {
+                    errors.push(format!("dmx universe '{}': {}", universe.name, error));
+                }
+            }
+            if let Some(ref sacn) = universe.sacn {
+                for error in sacn.validate() {
+                    errors.push(format!("dmx universe '{}': {}", universe.name, error));
+                }
+            }
+            if universe.artnet.is_some() && universe.sacn.is_some() {
+                errors.push(format!(
+                    "dmx universe '{}': artnet and sacn can't both be set",
+                    universe.name
+                ));
+            }
+        }
+        if let Some(ref sacn) = self.sacn {
+            errors.extend(sacn.validate());
         }
         // Check for duplicate universe names.
         let mut seen_names = std::collections::HashSet::new();
@@ -161,13 +208,40 @@ pub struct Universe {
 
     /// The name of this universe. Will be mapped to a universe by the player.
     name: String,
+
+    /// Sends this universe over Art-Net instead of through OLA.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    artnet: Option<ArtNet>,
+
+    /// Sends this universe over sACN (E1.31) instead of through OLA.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sacn: Option<Sacn>,
 }
 
 impl Universe {
     /// Creates a new universe configuration.
     #[cfg(test)]
     pub fn new(universe: u16, name: String) -> Universe {
-        Universe { universe, name }
+        Universe {
+            universe,
+            name,
+            artnet: None,
+            sacn: None,
+        }
+    }
+
+    /// Sends this universe over Art-Net.
+    #[cfg(test)]
+    pub fn with_artnet(mut self, artnet: ArtNet) -> Universe {
+        self.artnet = Some(artnet);
+        self
+    }
+
+    /// Sends this universe over sACN.
+    #[cfg(test)]
+    pub fn with_sacn(mut self, sacn: Sacn) -> Universe {
+        self.sacn = Some(sacn);
+        self
     }
 
     /// Gets the OpenLighting universe.
@@ -179,6 +253,267 @@ impl Universe {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    /// Gets the Art-Net output, if this universe is sent over Art-Net.
+    pub fn artnet(&self) -> Option<&ArtNet> {
+        self.artnet.as_ref()
+    }
+
+    /// Gets the sACN output, if this universe is sent over sACN.
+    pub fn sacn(&self) -> Option<&Sacn> {
+        self.sacn.as_ref()
+    }
+}
+
+/// A YAML representation of a universe's Art-Net output.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct ArtNet {
+    /// Where to send: a node's IP address for unicast, or a broadcast address
+    /// such as 2.255.255.255. The port defaults to 6454.
+    target: String,
+
+    /// The Art-Net net, 0-127.
+    #[serde(default)]
+    net: u8,
+
+    /// The Art-Net sub-net, 0-15.
+    #[serde(default)]
+    subnet: u8,
+
+    /// The Art-Net universe within the sub-net, 0-15.
+    #[serde(default)]
+    universe: u8,
+
+    /// Follows each frame with an ArtSync, so nodes output every synced
+    /// universe at once.
+    #[serde(default)]
+    sync: bool,
+}
+
+impl ArtNet {
+    /// Creates a new Art-Net output configuration.
+    #[cfg(test)]
+    pub fn new(target: &str, net: u8, subnet: u8, universe: u8, sync: bool) -> ArtNet {
+        ArtNet {
+            target: target.to_string(),
+            net,
+            subnet,
+            universe,
+            sync,
+        }
+    }
+
+    /// Gets the address to send to.
+    pub fn target(&self) -> Result<SocketAddr, Box<dyn Error>> {
+        parse_target(&self.target, DEFAULT_ARTNET_PORT).ok_or_else(|| {
+            format!(
+                "artnet target '{}' is not an IP address or IP:port",
+                self.target
+            )
+            .into()
+        })
+    }
+
+    /// Gets the 15-bit port-address: net, sub-net and universe together.
+    pub fn port_address(&self) -> u16 {
+        (u16::from(self.net & 0x7f) << 8)
+            | (u16::from(self.subnet & 0x0f) << 4)
+            | u16::from(self.universe & 0x0f)
+    }
+
+    /// Whether to follow each frame with an ArtSync.
+    pub fn sync(&self) -> bool {
+        self.sync
+    }
+
+    /// Returns the problems with this configuration, if any.
+    fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if let Err(e) = self.target() {
+            errors.push(e.to_string());
+        }
+        if self.net > 127 {
+            errors.push(format!("artnet net {} must be 0-127", self.net));
+        }
+        if self.subnet > 15 {
+            errors.push(format!("artnet subnet {} must be 0-15", self.subnet));
+        }
+        if self.universe > 15 {
+            errors.push(format!("artnet universe {} must be 0-15", self.universe));
+        }
+        errors
+    }
+}
+
+/// Parses a target address, with `default_port` when none is given.
+fn parse_target(target: &str, default_port: u16) -> Option<SocketAddr> {
+    target.parse::<SocketAddr>().ok().or_else(|| {
+        Some(SocketAddr::new(
+            target.parse::<IpAddr>().ok()?,
+            default_port,
+        ))
+    })
+}
+
+/// A YAML representation of a universe's sACN (E1.31) output.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq)]
+pub struct Sacn {
+    /// The sACN universe, 1-63999. Defaults to the universe's OLA number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    universe: Option<u16>,
+
+    /// The priority receivers merge this source at, 0-200. Defaults to 100.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    priority: Option<u8>,
+
+    /// Receivers to send to directly, as IP or IP:port. When empty the
+    /// universe is multicast to its sACN group.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    unicast: Vec<String>,
+}
+
+impl Sacn {
+    /// Creates a new sACN output configuration.
+    #[cfg(test)]
+    pub fn new(universe: Option<u16>, priority: Option<u8>, unicast: Vec<String>) -> Sacn {
+        Sacn {
+            universe,
+            priority,
+            unicast,
+        }
+    }
+
+    /// Gets the sACN universe, given the universe's OLA number.
+    pub fn universe(&self, ola_universe: u16) -> u16 {
+        self.universe.unwrap_or(ola_universe)
+    }
+
+    /// Gets the priority to send at.
+    pub fn priority(&self) -> u8 {
+        self.priority.unwrap_or(DEFAULT_SACN_PRIORITY)
+    }
+
+    /// Gets the addresses to send to: the unicast receivers, or the
+    /// universe's multicast group when there are none.
+    pub fn destinations(&self, ola_universe: u16) -> Result<Vec<SocketAddr>, Box<dyn Error>> {
+        if self.unicast.is_empty() {
+            let [high, low] = self.universe(ola_universe).to_be_bytes();
+            return Ok(vec![SocketAddr::new(
+                IpAddr::from([239, 255, high, low]),
+                DEFAULT_SACN_PORT,
+            )]);
+        }
+        self.unicast
+            .iter()
+            .map(|target| {
+                parse_target(target, DEFAULT_SACN_PORT).ok_or_else(|| {
+                    format!("sacn unicast '{}' is not an IP address or IP:port", target).into()
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the problems with this configuration, if any.
+    fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if let Some(universe) = self.universe {
+            if !(1..=63999).contains(&universe) {
+                errors.push(format!("sacn universe {} must be 1-63999", universe));
+            }
+        }
+        if self.priority() > 200 {
+            errors.push(format!("sacn priority {} must be 0-200", self.priority()));
+        }
+        if let Err(e) = self.destinations(1) {
+            errors.push(e.to_string());
+        }
+        errors
+    }
+}
+
+/// A YAML representation of how mtrack identifies itself as an sACN source.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq)]
+pub struct SacnSource {
+    /// The name shown on consoles and receivers, up to 63 bytes. Defaults to
+    /// "mtrack" and the hostname.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    source_name: Option<String>,
+
+    /// The source's CID, as a UUID. Defaults to one derived from the
+    /// hostname, so it stays the same across restarts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cid: Option<String>,
+}
+
+impl SacnSource {
+    /// Creates a new sACN source configuration.
+    #[cfg(test)]
+    pub fn new(source_name: Option<String>, cid: Option<String>) -> SacnSource {
+        SacnSource { source_name, cid }
+    }
+
+    /// Gets the source name.
+    pub fn source_name(&self) -> String {
+        match self.source_name {
+            Some(ref name) => name.clone(),
+            None => {
+                let mut name = format!("mtrack ({})", super::hostname::resolve_hostname());
+                while name.len() > 63 {
+                    name.pop();
+                }
+                name
+            }
+        }
+    }
+
+    /// Gets the CID. Unless configured, this is a name-based UUID from the
+    /// hostname.
+    pub fn cid(&self) -> Result<[u8; 16], Box<dyn Error>> {
+        if let Some(ref cid) = self.cid {
+            return parse_uuid(cid);
+        }
+        let digest = Sha256::digest(
+            format!("mtrack sACN {}", super::hostname::resolve_hostname()).as_bytes(),
+        );
+        let mut cid = [0u8; 16];
+        cid.copy_from_slice(&digest[..16]);
+        // Version 5 (name-based), RFC 4122 variant.
+        cid[6] = (cid[6] & 0x0f) | 0x50;
+        cid[8] = (cid[8] & 0x3f) | 0x80;
+        Ok(cid)
+    }
+
+    /// Returns the problems with this configuration, if any.
+    fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if let Some(ref name) = self.source_name {
+            if name.len() > 63 {
+                errors.push(format!(
+                    "dmx sacn source_name '{}' is longer than 63 bytes",
+                    name
+                ));
+            }
+        }
+        if let Err(e) = self.cid() {
+            errors.push(format!("dmx sacn {}", e));
+        }
+        errors
+    }
+}
+
+/// Parses a UUID in its usual 8-4-4-4-12 hex form.
+fn parse_uuid(uuid: &str) -> Result<[u8; 16], Box<dyn Error>> {
+    let invalid = || format!("cid '{}' is not a UUID", uuid);
+    let groups: Vec<&str> = uuid.split('-').collect();
+    if groups.iter().map(|g| g.len()).collect::<Vec<_>>() != [8, 4, 4, 4, 12] {
+        return Err(invalid().into());
+    }
+    let hex = groups.concat();
+    let mut bytes = [0u8; 16];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map_err(|_| invalid())?;
+    }
+    Ok(bytes)
 }
 
 #[cfg(test)]
@@ -301,6 +636,169 @@ mod tests {
         assert!(dmx.null_client());
     }
 
+    #[test]
+    fn serde_artnet_universe() {
+        let yaml = r#"
+            universes:
+              - universe: 1
+                name: main
+                artnet:
+                  target: 2.0.0.10
+                  net: 1
+                  subnet: 2
+                  universe: 3
+                  sync: true
+              - universe: 2
+                name: aux
+        "#;
+        let dmx: Dmx = config::Config::builder()
+            .add_source(config::File::from_str(yaml, config::FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+        assert!(dmx.validate().is_ok());
+        let artnet = dmx.universes()[0].artnet().unwrap();
+        assert_eq!(
+            artnet.target().unwrap(),
+            "2.0.0.10:6454".parse::<SocketAddr>().unwrap()
+        );
+        assert_eq!(artnet.port_address(), 0x0123);
+        assert!(artnet.sync());
+        assert!(dmx.universes()[1].artnet().is_none());
+    }
+
+    #[test]
+    fn artnet_target_with_port() {
+        let artnet = ArtNet::new("127.0.0.1:7000", 0, 0, 0, false);
+        assert_eq!(
+            artnet.target().unwrap(),
+            "127.0.0.1:7000".parse::<SocketAddr>().unwrap()
+        );
+        assert_eq!(artnet.port_address(), 0);
+    }
+
+    #[test]
+    fn validate_rejects_bad_artnet() {
+        let dmx = Dmx::new(
+            None,
+            None,
+            None,
+            vec![
+                Universe::new(1, "main".to_string()).with_artnet(ArtNet::new(
+                    "lights.local",
+                    128,
+                    16,
+                    16,
+                    false,
+                )),
+            ],
+            None,
+        );
+        let errors = dmx.validate().unwrap_err();
+        assert_eq!(errors.len(), 4, "{:?}", errors);
+        assert!(errors
+            .iter()
+            .all(|e| e.starts_with("dmx universe 'main': artnet")));
+    }
+
+    #[test]
+    fn serde_sacn_universe() {
+        let yaml = r#"
+            sacn:
+              source_name: mtrack FOH
+              cid: 6b7c1f0e-5a4d-4b3c-9e2f-0a1b2c3d4e5f
+            universes:
+              - universe: 1
+                name: main
+                sacn:
+                  priority: 150
+              - universe: 2
+                name: aux
+                sacn:
+                  universe: 300
+                  unicast: [10.0.0.5, "10.0.0.6:5569"]
+        "#;
+        let dmx: Dmx = config::Config::builder()
+            .add_source(config::File::from_str(yaml, config::FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+        assert!(dmx.validate().is_ok());
+
+        let source = dmx.sacn().unwrap();
+        assert_eq!(source.source_name(), "mtrack FOH");
+        let cid = source.cid().unwrap();
+        assert_eq!(cid[0], 0x6b);
+        assert_eq!(cid[15], 0x5f);
+
+        let main = dmx.universes()[0].sacn().unwrap();
+        assert_eq!(main.universe(1), 1);
+        assert_eq!(main.priority(), 150);
+        assert_eq!(
+            main.destinations(1).unwrap(),
+            vec!["239.255.0.1:5568".parse::<SocketAddr>().unwrap()]
+        );
+
+        let aux = dmx.universes()[1].sacn().unwrap();
+        assert_eq!(aux.universe(2), 300);
+        assert_eq!(aux.priority(), DEFAULT_SACN_PRIORITY);
+        assert_eq!(
+            aux.destinations(2).unwrap(),
+            vec![
+                "10.0.0.5:5568".parse::<SocketAddr>().unwrap(),
+                "10.0.0.6:5569".parse::<SocketAddr>().unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn sacn_source_defaults_are_stable() {
+        let source = SacnSource::default();
+        assert!(source.source_name().starts_with("mtrack ("));
+        let cid = source.cid().unwrap();
+        assert_eq!(cid, SacnSource::default().cid().unwrap());
+        assert_eq!(cid[6] >> 4, 5);
+        assert_eq!(cid[8] >> 6, 0b10);
+    }
+
+    #[test]
+    fn sacn_multicast_group_follows_universe() {
+        let sacn = Sacn::new(Some(0x1234), None, vec![]);
+        assert_eq!(
+            sacn.destinations(1).unwrap(),
+            vec!["239.255.18.52:5568".parse::<SocketAddr>().unwrap()]
+        );
+    }
+
+    #[test]
+    fn validate_rejects_bad_sacn() {
+        let dmx = Dmx::new(
+            None,
+            None,
+            None,
+            vec![
+                Universe::new(1, "main".to_string()).with_sacn(Sacn::new(
+                    Some(64000),
+                    Some(201),
+                    vec!["nowhere".to_string()],
+                )),
+                Universe::new(2, "both".to_string())
+                    .with_sacn(Sacn::default())
+                    .with_artnet(ArtNet::new("10.0.0.1", 0, 0, 0, false)),
+            ],
+            None,
+        )
+        .with_sacn(SacnSource::new(
+            Some("x".repeat(64)),
+            Some("not-a-uuid".to_string()),
+        ));
+        let errors = dmx.validate().unwrap_err();
+        assert_eq!(errors.len(), 6, "{:?}", errors);
+        assert!(errors[3].contains("can't both be set"));
+    }
+
     #[test]
     fn serde_minimal() {
         let yaml = r#"
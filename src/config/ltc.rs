@@ -0,0 +1,107 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+use serde::{Deserialize, Serialize};
+
+use super::midi::{MtcFrameRate, Timecode};
+
+/// A YAML representation of a song's linear timecode: SMPTE LTC rendered as
+/// audio onto a virtual track, for patching to a spare interface output that
+/// feeds a video rig or anything else that reads timecode off a cable.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct LtcConfig {
+    /// The output track name the timecode plays on. Route it by adding this
+    /// name to `track_mappings` in the player profile. Defaults to "ltc".
+    #[serde(
+        default = "default_ltc_track",
+        skip_serializing_if = "is_default_track"
+    )]
+    pub track: String,
+    /// The timecode frame rate. Defaults to 30 fps.
+    #[serde(default)]
+    pub frame_rate: MtcFrameRate,
+    /// The timecode at the top of the song. Defaults to the song's
+    /// `mtc_start`, so MIDI and linear timecode agree.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<Timecode>,
+}
+
+impl Default for LtcConfig {
+    fn default() -> Self {
+        LtcConfig {
+            track: default_ltc_track(),
+            frame_rate: MtcFrameRate::default(),
+            start: None,
+        }
+    }
+}
+
+fn default_ltc_track() -> String {
+    "ltc".to_string()
+}
+
+fn is_default_track(track: &String) -> bool {
+    track == "ltc"
+}
+
+impl LtcConfig {
+    /// Validates the linear timecode configuration.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.track.trim().is_empty() {
+            return Err("ltc track name must not be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::{Config, File, FileFormat};
+
+    fn deserialize(yaml: &str) -> LtcConfig {
+        Config::builder()
+            .add_source(File::from_str(yaml, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap()
+    }
+
+    #[test]
+    fn defaults() {
+        let config = deserialize("{}");
+        assert_eq!(config, LtcConfig::default());
+        assert_eq!(config.track, "ltc");
+        assert_eq!(config.frame_rate, MtcFrameRate::Fps30);
+        assert!(config.start.is_none());
+    }
+
+    #[test]
+    fn full_config() {
+        let config = deserialize("track: timecode\nframe_rate: 25\nstart: \"01:00:00:00\"\n");
+        assert_eq!(config.track, "timecode");
+        assert_eq!(config.frame_rate, MtcFrameRate::Fps25);
+        assert_eq!(config.start, Some(Timecode::new(1, 0, 0, 0)));
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_empty_track() {
+        let config = LtcConfig {
+            track: " ".to_string(),
+            ..LtcConfig::default()
+        };
+        assert!(config.validate().is_err());
+    }
+}
@@ -21,6 +21,7 @@ use midly::{
 use serde::{Deserialize, Serialize};
 
 const DEFAULT_MIDI_PLAYBACK_DELAY: Duration = Duration::ZERO;
+pub(super) const DEFAULT_CHASE_RESYNC_THRESHOLD: Duration = Duration::from_millis(100);
 
 /// A YAML representation of the MIDI configuration.
 #[derive(Deserialize, Serialize, Clone)]
@@ -39,6 +40,13 @@ pub struct Midi {
     /// enabled.
     persist_tempo: Option<bool>,
 
+    /// Enable MIDI Timecode output, locked to the playback clock.
+    mtc: Option<Mtc>,
+
+    /// Follow an external transport arriving on the MIDI input instead of
+    /// being the transport master.
+    chase: Option<Chase>,
+
     /// MIDI to DMX passthrough configurations.
     midi_to_dmx: Option<Vec<MidiToDmx>>,
 }
@@ -51,10 +59,18 @@ impl Midi {
             playback_delay,
             beat_clock: None,
             persist_tempo: None,
+            mtc: None,
+            chase: None,
             midi_to_dmx: None,
         }
     }
 
+    /// Sets the external transport to chase.
+    pub fn with_chase(mut self, chase: Option<Chase>) -> Midi {
+        self.chase = chase;
+        self
+    }
+
     /// Returns the device from the configuration.
     pub fn device(&self) -> &str {
         &self.device
@@ -76,6 +92,16 @@ impl Midi {
         self.persist_tempo.unwrap_or(false)
     }
 
+    /// Returns the MIDI Timecode configuration, if timecode output is enabled.
+    pub fn mtc(&self) -> Option<&Mtc> {
+        self.mtc.as_ref()
+    }
+
+    /// Returns the chase configuration, if mtrack follows an external transport.
+    pub fn chase(&self) -> Option<&Chase> {
+        self.chase.as_ref()
+    }
+
     /// Returns the MIDI to DMX configuration.
     pub fn midi_to_dmx(&self) -> &[MidiToDmx] {
         self.midi_to_dmx.as_deref().unwrap_or_default()
@@ -96,6 +122,18 @@ impl Midi {
                 ));
             }
         }
+        if let Some(threshold) = self
+            .chase
+            .as_ref()
+            .and_then(|c| c.resync_threshold.as_ref())
+        {
+            if DurationString::from_string(threshold.clone()).is_err() {
+                errors.push(format!(
+                    "midi chase resync_threshold '{}' is not a valid duration",
+                    threshold
+                ));
+            }
+        }
 
         if errors.is_empty() {
             Ok(())
@@ -105,6 +143,195 @@ impl Midi {
     }
 }
 
+/// Chase configuration: which external transport to follow and how far it may
+/// drift before playback relocates.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq)]
+pub struct Chase {
+    /// The transport to follow. Defaults to MIDI Timecode.
+    #[serde(default)]
+    source: ChaseSource,
+
+    /// How far playback may drift from the external transport before it
+    /// seeks back into line. Defaults to 100ms.
+    resync_threshold: Option<String>,
+}
+
+impl Chase {
+    /// Creates a new chase configuration.
+    pub fn new(source: ChaseSource, resync_threshold: Option<String>) -> Chase {
+        Chase {
+            source,
+            resync_threshold,
+        }
+    }
+
+    /// Returns the transport to follow.
+    pub fn source(&self) -> ChaseSource {
+        self.source
+    }
+
+    /// Returns the resync threshold.
+    pub fn resync_threshold(&self) -> Result<Duration, Box<dyn Error>> {
+        super::parse_playback_delay(&self.resync_threshold, DEFAULT_CHASE_RESYNC_THRESHOLD)
+    }
+}
+
+/// An external transport mtrack can follow.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChaseSource {
+    /// MIDI Timecode: quarter-frames while running, full frames to locate.
+    #[default]
+    Mtc,
+    /// MIDI beat clock: Song Position Pointer to locate, Start/Continue/Stop
+    /// for the transport and timing clocks for tempo.
+    Clock,
+}
+
+/// MIDI Timecode output configuration.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq)]
+pub struct Mtc {
+    /// The timecode frame rate. Defaults to 30 fps.
+    #[serde(default)]
+    frame_rate: MtcFrameRate,
+}
+
+impl Mtc {
+    /// Creates a new MIDI Timecode configuration.
+    pub fn new(frame_rate: MtcFrameRate) -> Mtc {
+        Mtc { frame_rate }
+    }
+
+    /// Returns the timecode frame rate.
+    pub fn frame_rate(&self) -> MtcFrameRate {
+        self.frame_rate
+    }
+}
+
+/// The frame rates MIDI Timecode can carry.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(try_from = "String", into = "String")]
+pub enum MtcFrameRate {
+    /// 24 fps (film).
+    Fps24,
+    /// 25 fps (PAL video).
+    Fps25,
+    /// 29.97 fps drop-frame (NTSC video).
+    Fps2997DropFrame,
+    /// 30 fps.
+    #[default]
+    Fps30,
+}
+
+impl MtcFrameRate {
+    /// Returns the number of frames counted in each timecode second.
+    pub fn frames_per_second(&self) -> u64 {
+        match self {
+            MtcFrameRate::Fps24 => 24,
+            MtcFrameRate::Fps25 => 25,
+            MtcFrameRate::Fps2997DropFrame | MtcFrameRate::Fps30 => 30,
+        }
+    }
+
+    /// Returns how many frames actually elapse per second of real time.
+    pub fn real_rate(&self) -> f64 {
+        match self {
+            MtcFrameRate::Fps2997DropFrame => 30000.0 / 1001.0,
+            rate => rate.frames_per_second() as f64,
+        }
+    }
+
+    /// Returns the rate code carried in the hours field of timecode messages.
+    pub fn rate_code(&self) -> u8 {
+        match self {
+            MtcFrameRate::Fps24 => 0,
+            MtcFrameRate::Fps25 => 1,
+            MtcFrameRate::Fps2997DropFrame => 2,
+            MtcFrameRate::Fps30 => 3,
+        }
+    }
+}
+
+impl TryFrom<String> for MtcFrameRate {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.trim() {
+            "24" => Ok(MtcFrameRate::Fps24),
+            "25" => Ok(MtcFrameRate::Fps25),
+            "29.97df" => Ok(MtcFrameRate::Fps2997DropFrame),
+            "30" => Ok(MtcFrameRate::Fps30),
+            other => Err(format!(
+                "unsupported MTC frame rate '{}' (expected 24, 25, 29.97df or 30)",
+                other
+            )),
+        }
+    }
+}
+
+impl From<MtcFrameRate> for String {
+    fn from(rate: MtcFrameRate) -> Self {
+        match rate {
+            MtcFrameRate::Fps24 => "24",
+            MtcFrameRate::Fps25 => "25",
+            MtcFrameRate::Fps2997DropFrame => "29.97df",
+            MtcFrameRate::Fps30 => "30",
+        }
+        .to_string()
+    }
+}
+
+/// A timecode position, written "HH:MM:SS:FF".
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(try_from = "String", into = "String")]
+pub struct Timecode {
+    pub hours: u8,
+    pub minutes: u8,
+    pub seconds: u8,
+    pub frames: u8,
+}
+
+impl Timecode {
+    /// Creates a new timecode.
+    pub fn new(hours: u8, minutes: u8, seconds: u8, frames: u8) -> Timecode {
+        Timecode {
+            hours,
+            minutes,
+            seconds,
+            frames,
+        }
+    }
+}
+
+impl TryFrom<String> for Timecode {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let invalid = || format!("timecode '{}' must be HH:MM:SS:FF", value);
+        let fields = value
+            .trim()
+            .split([':', ';'])
+            .map(|field| field.parse::<u8>().map_err(|_| invalid()))
+            .collect::<Result<Vec<u8>, String>>()?;
+        let [hours, minutes, seconds, frames] = fields[..] else {
+            return Err(invalid());
+        };
+        if hours > 23 || minutes > 59 || seconds > 59 || frames > 29 {
+            return Err(format!("timecode '{}' is out of range", value));
+        }
+        Ok(Timecode::new(hours, minutes, seconds, frames))
+    }
+}
+
+impl From<Timecode> for String {
+    fn from(timecode: Timecode) -> Self {
+        format!(
+            "{:02}:{:02}:{:02}:{:02}",
+            timecode.hours, timecode.minutes, timecode.seconds, timecode.frames
+        )
+    }
+}
+
 /// A YAML representation of the MIDI configuration.
 #[derive(Deserialize, Serialize, Clone)]
 pub struct MidiToDmx {
@@ -691,3 +918,116 @@ mod test {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::{Config, File, FileFormat};
+
+    fn deserialize(yaml: &str) -> Result<Midi, config::ConfigError> {
+        Config::builder()
+            .add_source(File::from_str(yaml, FileFormat::Yaml))
+            .build()?
+            .try_deserialize()
+    }
+
+    #[test]
+    fn chase_is_off_by_default() {
+        assert!(deserialize("device: x\n").unwrap().chase().is_none());
+    }
+
+    #[test]
+    fn chase_defaults() {
+        let midi = deserialize("device: x\nchase: {}\n").unwrap();
+        let chase = midi.chase().unwrap();
+        assert_eq!(chase.source(), ChaseSource::Mtc);
+        assert_eq!(
+            chase.resync_threshold().unwrap(),
+            Duration::from_millis(100)
+        );
+    }
+
+    #[test]
+    fn chase_clock_with_threshold() {
+        let midi =
+            deserialize("device: x\nchase:\n  source: clock\n  resync_threshold: 250ms\n").unwrap();
+        let chase = midi.chase().unwrap();
+        assert_eq!(chase.source(), ChaseSource::Clock);
+        assert_eq!(
+            chase.resync_threshold().unwrap(),
+            Duration::from_millis(250)
+        );
+        assert!(midi.validate().is_ok());
+    }
+
+    #[test]
+    fn chase_rejects_bad_threshold() {
+        let midi = deserialize("device: x\nchase:\n  resync_threshold: soon\n").unwrap();
+        let errors = midi.validate().unwrap_err();
+        assert!(errors[0].contains("resync_threshold"), "{errors:?}");
+    }
+
+    #[test]
+    fn mtc_is_off_by_default() {
+        assert!(deserialize("device: x\n").unwrap().mtc().is_none());
+    }
+
+    #[test]
+    fn mtc_frame_rates() {
+        for (yaml, rate) in [
+            ("24", MtcFrameRate::Fps24),
+            ("25", MtcFrameRate::Fps25),
+            ("\"29.97df\"", MtcFrameRate::Fps2997DropFrame),
+            ("30", MtcFrameRate::Fps30),
+        ] {
+            let midi = deserialize(&format!("device: x\nmtc:\n  frame_rate: {yaml}\n")).unwrap();
+            assert_eq!(midi.mtc().unwrap().frame_rate(), rate);
+        }
+        let midi = deserialize("device: x\nmtc: {}\n").unwrap();
+        assert_eq!(midi.mtc().unwrap().frame_rate(), MtcFrameRate::Fps30);
+    }
+
+    #[test]
+    fn mtc_rejects_unknown_frame_rates() {
+        assert!(deserialize("device: x\nmtc:\n  frame_rate: 29.97\n").is_err());
+    }
+
+    #[test]
+    fn mtc_round_trips() {
+        let mut midi = Midi::new("x", None);
+        midi.mtc = Some(Mtc::new(MtcFrameRate::Fps2997DropFrame));
+        let yaml = crate::util::to_yaml_string(&midi).unwrap();
+        assert!(yaml.contains("29.97df"), "{yaml}");
+        assert_eq!(deserialize(&yaml).unwrap().mtc(), midi.mtc());
+    }
+
+    #[test]
+    fn timecode_parses() {
+        assert_eq!(
+            Timecode::try_from("01:02:03:04".to_string()),
+            Ok(Timecode::new(1, 2, 3, 4))
+        );
+        // Drop-frame timecode is conventionally written with a semicolon.
+        assert_eq!(
+            Timecode::try_from("10:00:00;02".to_string()),
+            Ok(Timecode::new(10, 0, 0, 2))
+        );
+        assert_eq!(String::from(Timecode::new(1, 0, 0, 0)), "01:00:00:00");
+    }
+
+    #[test]
+    fn timecode_rejects_malformed_values() {
+        for value in [
+            "1:00:00",
+            "01:00:00:00:00",
+            "aa:00:00:00",
+            "24:00:00:00",
+            "00:60:00:00",
+        ] {
+            assert!(
+                Timecode::try_from(value.to_string()).is_err(),
+                "accepted {value}"
+            );
+        }
+    }
+}
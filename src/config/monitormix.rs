@@ -0,0 +1,152 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::audio::track_gains::{MAX_GAIN_DB, MIN_GAIN_DB};
+
+/// A named monitor mix: its own blend of every output track, sent to its own
+/// output channels alongside the main routing.
+///
+/// ```yaml
+/// mixes:
+///   drummer:
+///     channels: [7, 8]
+///     gains:
+///       click: 6.0
+///       keys: -12.0
+///     muted: [vocals]
+/// ```
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq)]
+pub struct MonitorMix {
+    /// The 1-indexed output channels the mix is sent to.
+    channels: Vec<u16>,
+
+    /// Per-track gain in dB within this mix. Tracks without an entry play at
+    /// unity.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    gains: IndexMap<String, f32>,
+
+    /// Tracks left out of this mix.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    muted: Vec<String>,
+}
+
+impl MonitorMix {
+    /// Creates a monitor mix.
+    pub fn new(channels: Vec<u16>, gains: IndexMap<String, f32>, muted: Vec<String>) -> Self {
+        MonitorMix {
+            channels,
+            gains,
+            muted,
+        }
+    }
+
+    /// Returns the output channels.
+    pub fn channels(&self) -> &[u16] {
+        &self.channels
+    }
+
+    /// Returns the per-track gains in dB.
+    pub fn gains(&self) -> &IndexMap<String, f32> {
+        &self.gains
+    }
+
+    /// Returns the muted tracks.
+    pub fn muted(&self) -> &[String] {
+        &self.muted
+    }
+
+    /// Validates the named mix.
+    pub fn validate(&self, name: &str, errors: &mut Vec<String>) {
+        if name.is_empty() || name.contains(['/', ' ', '*', '?', '[', ']', '{', '}', ',', '#']) {
+            errors.push(format!(
+                "mixes '{}': name must be non-empty and usable in an OSC address",
+                name
+            ));
+        }
+        if self.channels.is_empty() {
+            errors.push(format!(
+                "mixes '{}': at least one channel is required",
+                name
+            ));
+        }
+        for &ch in &self.channels {
+            if ch == 0 {
+                errors.push(format!(
+                    "mixes '{}': channel 0 is invalid (channels are 1-indexed)",
+                    name
+                ));
+            }
+        }
+        for (track, &db) in &self.gains {
+            if !(MIN_GAIN_DB..=MAX_GAIN_DB).contains(&db) {
+                errors.push(format!(
+                    "mixes '{}': gain {} dB for track '{}' out of range [{}, {}]",
+                    name, db, track, MIN_GAIN_DB, MAX_GAIN_DB
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::{Config, File, FileFormat};
+
+    fn parse(yaml: &str) -> MonitorMix {
+        Config::builder()
+            .add_source(File::from_str(yaml, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap()
+    }
+
+    #[test]
+    fn deserialize_full() {
+        let mix = parse("channels: [7, 8]\ngains:\n  click: 6.0\nmuted: [vocals]\n");
+        assert_eq!(mix.channels(), &[7, 8]);
+        assert_eq!(mix.gains()["click"], 6.0);
+        assert_eq!(mix.muted(), &["vocals".to_string()]);
+    }
+
+    #[test]
+    fn serializes_only_what_is_set() {
+        let mix = MonitorMix::new(vec![3], IndexMap::new(), Vec::new());
+        let yaml = crate::util::to_yaml_string(&mix).unwrap();
+        assert!(!yaml.contains("gains"), "{yaml}");
+        assert!(!yaml.contains("muted"), "{yaml}");
+    }
+
+    #[test]
+    fn validate_mix() {
+        let mut errors = Vec::new();
+        MonitorMix::new(vec![7, 8], IndexMap::new(), Vec::new()).validate("ok", &mut errors);
+        assert!(errors.is_empty(), "{errors:?}");
+
+        MonitorMix::new(
+            vec![0],
+            IndexMap::from([("click".to_string(), 20.0)]),
+            Vec::new(),
+        )
+        .validate("bad", &mut errors);
+        assert_eq!(errors.len(), 2, "{errors:?}");
+
+        errors.clear();
+        MonitorMix::new(Vec::new(), IndexMap::new(), Vec::new()).validate("a b", &mut errors);
+        assert_eq!(errors.len(), 2, "{errors:?}");
+    }
+}
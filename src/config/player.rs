@@ -17,7 +17,9 @@ use super::dmx::Dmx;
 use super::lighting::Lighting;
 use super::midi::Midi;
 use super::profile::{AudioConfig, Profile};
-use super::samples::{SampleDefinition, SampleTrigger, SamplesConfig, DEFAULT_MAX_SAMPLE_VOICES};
+use super::samples::{
+    KitsConfig, SampleDefinition, SampleTrigger, SamplesConfig, DEFAULT_MAX_SAMPLE_VOICES,
+};
 use super::statusevents::StatusEvents;
 use super::trackmappings::TrackMappings;
 use super::trigger::{MidiTriggerInput, TriggerConfig, TriggerInput};
@@ -127,6 +129,9 @@ pub struct Player {
     /// Maximum number of concurrent sample voices globally.
     #[serde(skip_serializing_if = "Option::is_none")]
     max_sample_voices: Option<u32>,
+    /// Inline sample kits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sample_kits: Option<KitsConfig>,
     /// Player-wide metronome defaults. Songs enable the metronome with a
     /// `metronome:` block; the volume and any sounds not overridden there
     /// fall back to these.
@@ -188,6 +193,7 @@ impl Default for Player {
             samples_file: None,
             sample_triggers: Vec::new(),
             max_sample_voices: None,
+            sample_kits: None,
             metronome: None,
         };
         player.normalize();
@@ -228,6 +234,7 @@ impl Player {
             samples_file: None,
             sample_triggers: Vec::new(),
             max_sample_voices: None,
+            sample_kits: None,
             metronome: None,
         };
         player.normalize();
@@ -622,7 +629,8 @@ impl Player {
             self.samples.clone(),
             Vec::new(),
             self.max_sample_voices.unwrap_or(DEFAULT_MAX_SAMPLE_VOICES),
-        );
+        )
+        .with_kits(self.sample_kits.clone().unwrap_or_default());
 
         // Load external samples file if specified
         if let Some(samples_file) = &self.samples_file {
@@ -1892,6 +1900,54 @@ samples:
         assert!(sc.samples().contains_key("snare"));
     }
 
+    #[test]
+    fn test_samples_config_kits_merge_with_external_file() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let samples_path = dir.path().join("samples.yaml");
+        std::fs::write(
+            &samples_path,
+            r#"
+sample_kits:
+  program_channel: 10
+  kits:
+    dry:
+      program: 0
+    wet:
+      program: 1
+"#,
+        )
+        .unwrap();
+
+        let config_path = dir.path().join("mtrack.yaml");
+        std::fs::write(
+            &config_path,
+            r#"
+songs: songs
+samples_file: samples.yaml
+sample_kits:
+  sections:
+    chorus: wet
+  kits:
+    wet:
+      program: 5
+"#,
+        )
+        .unwrap();
+
+        let player = Player::deserialize(&config_path).unwrap();
+        let sc = player.samples_config(&config_path).unwrap();
+        let kits = sc.sample_kits();
+        assert_eq!(kits.program_channel(), Some(10));
+        assert_eq!(kits.kits().len(), 2);
+        // The inline kit overrides the external one.
+        assert_eq!(kits.kits().get("wet").unwrap().program(), Some(5));
+        assert_eq!(
+            kits.sections().get("chorus").map(String::as_str),
+            Some("wet")
+        );
+    }
+
     #[test]
     fn test_profiles_dir_absolute_path() {
         let dir = tempfile::tempdir().unwrap();
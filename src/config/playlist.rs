@@ -11,9 +11,12 @@
 // You should have received a copy of the GNU General Public License along with
 // this program. If not, see <https://www.gnu.org/licenses/>.
 //
+use std::error::Error;
 use std::path::Path;
+use std::time::Duration;
 
 use config::{Config, File};
+use duration_string::DurationString;
 use serde::{Deserialize, Serialize};
 
 use super::error::ConfigError;
@@ -26,6 +29,9 @@ pub struct Playlist {
     kind: super::kind::ConfigKind,
     /// The songs that belong to this playlist.
     songs: Vec<String>,
+    /// Moves on to the next song by itself when a song finishes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    auto_advance: Option<AutoAdvance>,
 }
 
 impl Playlist {
@@ -34,9 +40,16 @@ impl Playlist {
         Playlist {
             kind: super::kind::ConfigKind::Playlist,
             songs: songs.to_owned(),
+            auto_advance: None,
         }
     }
 
+    /// Sets the auto-advance behavior.
+    pub fn with_auto_advance(mut self, auto_advance: Option<AutoAdvance>) -> Playlist {
+        self.auto_advance = auto_advance;
+        self
+    }
+
     /// Parse a playlist from a YAML file.
     pub fn deserialize(path: &Path) -> Result<Playlist, ConfigError> {
         Ok(Config::builder()
@@ -49,6 +62,94 @@ impl Playlist {
     pub fn songs(&self) -> &[String] {
         &self.songs
     }
+
+    /// Gets the auto-advance behavior, if the playlist advances by itself.
+    pub fn auto_advance(&self) -> Option<&AutoAdvance> {
+        self.auto_advance.as_ref()
+    }
+}
+
+/// How one song leads into the next.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SongTransition {
+    /// The next song starts after the outgoing one has finished, following
+    /// an optional gap of silence.
+    #[default]
+    Gap,
+    /// The next song starts on the exact sample the outgoing one ends.
+    Gapless,
+    /// The next song fades in while the outgoing one fades out.
+    Crossfade,
+}
+
+/// The default crossfade between songs.
+const DEFAULT_CROSSFADE: Duration = Duration::from_secs(2);
+
+/// Auto-advance configuration for a playlist.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq)]
+pub struct AutoAdvance {
+    /// How each song leads into the next. Defaults to a gap.
+    #[serde(default)]
+    transition: SongTransition,
+    /// Silence between songs for gap transitions. Defaults to none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    gap: Option<String>,
+    /// Length of the overlap for crossfade transitions. Defaults to 2s.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    crossfade: Option<String>,
+}
+
+impl AutoAdvance {
+    /// Creates a new auto-advance configuration.
+    pub fn new(transition: SongTransition, gap: Option<String>, crossfade: Option<String>) -> Self {
+        AutoAdvance {
+            transition,
+            gap,
+            crossfade,
+        }
+    }
+
+    /// Returns how each song leads into the next.
+    pub fn transition(&self) -> SongTransition {
+        self.transition
+    }
+
+    /// Returns the silence between songs for gap transitions.
+    pub fn gap(&self) -> Result<Duration, Box<dyn Error>> {
+        super::parse_playback_delay(&self.gap, Duration::ZERO)
+    }
+
+    /// Returns the length of the overlap for crossfade transitions.
+    pub fn crossfade(&self) -> Result<Duration, Box<dyn Error>> {
+        super::parse_playback_delay(&self.crossfade, DEFAULT_CROSSFADE)
+    }
+
+    /// Validates the auto-advance configuration.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        for (field, value) in [("gap", &self.gap), ("crossfade", &self.crossfade)] {
+            if let Some(value) = value {
+                if DurationString::from_string(value.clone()).is_err() {
+                    errors.push(format!(
+                        "auto_advance {} '{}' is not a valid duration",
+                        field, value
+                    ));
+                }
+            }
+        }
+        if self.transition == SongTransition::Crossfade
+            && self.crossfade().is_ok_and(|d| d.is_zero())
+        {
+            errors.push("auto_advance crossfade must be longer than zero".to_string());
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
 }
 
 fn default_playlist_kind() -> super::kind::ConfigKind {
@@ -131,6 +232,100 @@ songs: []
         assert_eq!(deserialized.songs()[2], "Charlie");
     }
 
+    #[test]
+    fn auto_advance_absent_by_default() {
+        let yaml = r#"
+songs:
+  - "Track A"
+"#;
+        let p: Playlist = Config::builder()
+            .add_source(File::from_str(yaml, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+        assert!(p.auto_advance().is_none());
+        let serialized = crate::util::to_yaml_string(&p).unwrap();
+        assert!(!serialized.contains("auto_advance"));
+    }
+
+    #[test]
+    fn deserialize_auto_advance() {
+        let yaml = r#"
+songs:
+  - "Track A"
+auto_advance:
+  transition: crossfade
+  crossfade: 4s
+"#;
+        let p: Playlist = Config::builder()
+            .add_source(File::from_str(yaml, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+        let auto_advance = p.auto_advance().unwrap();
+        assert_eq!(auto_advance.transition(), SongTransition::Crossfade);
+        assert_eq!(auto_advance.crossfade().unwrap(), Duration::from_secs(4));
+        assert_eq!(auto_advance.gap().unwrap(), Duration::ZERO);
+        assert!(auto_advance.validate().is_ok());
+    }
+
+    #[test]
+    fn auto_advance_defaults() {
+        let yaml = r#"
+songs: []
+auto_advance: {}
+"#;
+        let p: Playlist = Config::builder()
+            .add_source(File::from_str(yaml, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+        let auto_advance = p.auto_advance().unwrap();
+        assert_eq!(auto_advance.transition(), SongTransition::Gap);
+        assert_eq!(auto_advance.gap().unwrap(), Duration::ZERO);
+        assert_eq!(auto_advance.crossfade().unwrap(), DEFAULT_CROSSFADE);
+    }
+
+    #[test]
+    fn auto_advance_validate_rejects_bad_durations() {
+        let auto_advance = AutoAdvance::new(SongTransition::Gap, Some("soon".to_string()), None);
+        let errors = auto_advance.validate().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("gap 'soon'"));
+
+        let auto_advance =
+            AutoAdvance::new(SongTransition::Crossfade, None, Some("0s".to_string()));
+        let errors = auto_advance.validate().unwrap_err();
+        assert!(errors[0].contains("longer than zero"));
+    }
+
+    #[test]
+    fn auto_advance_roundtrip() {
+        let p = Playlist::new(&["Alpha".to_string()]).with_auto_advance(Some(AutoAdvance::new(
+            SongTransition::Gap,
+            Some("3s".to_string()),
+            None,
+        )));
+        let serialized = crate::util::to_yaml_string(&p).unwrap();
+        let deserialized: Playlist = config::Config::builder()
+            .add_source(config::File::from_str(
+                &serialized,
+                config::FileFormat::Yaml,
+            ))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+        assert_eq!(deserialized.auto_advance(), p.auto_advance());
+        assert_eq!(
+            deserialized.auto_advance().unwrap().gap().unwrap(),
+            Duration::from_secs(3)
+        );
+    }
+
     #[test]
     fn deserialize_single_song() {
         let yaml = r#"
@@ -20,7 +20,10 @@ use super::audio::Audio;
 use super::controller::Controller;
 use super::dmx::Dmx;
 use super::midi::Midi;
+use super::monitormix::MonitorMix;
 use super::notification::NotificationConfig;
+use super::trackeq::EqBand;
+use super::trackmix::TrackMix;
 use super::trigger::TriggerConfig;
 
 /// Audio configuration with track mappings.
@@ -39,6 +42,18 @@ pub struct AudioConfig {
     /// Per-output-track gain in dB. Tracks without an entry play at unity.
     #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     track_gains: IndexMap<String, f32>,
+    /// Per-output-track pan, polarity and stereo width. Tracks without an
+    /// entry play as routed.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    track_mix: IndexMap<String, TrackMix>,
+    /// Per-output-track EQ chains, applied before routing. Tracks without an
+    /// entry are unfiltered.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    track_eq: IndexMap<String, Vec<EqBand>>,
+    /// Named monitor mixes, each a separate blend of the tracks sent to its
+    /// own output channels.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    mixes: IndexMap<String, MonitorMix>,
 }
 
 impl AudioConfig {
@@ -48,6 +63,9 @@ impl AudioConfig {
             audio,
             track_mappings,
             track_gains: IndexMap::new(),
+            track_mix: IndexMap::new(),
+            track_eq: IndexMap::new(),
+            mixes: IndexMap::new(),
         }
     }
 
@@ -66,6 +84,36 @@ impl AudioConfig {
         self.track_gains = track_gains;
     }
 
+    /// Returns the per-track pan, polarity and width.
+    pub fn track_mix(&self) -> &IndexMap<String, TrackMix> {
+        &self.track_mix
+    }
+
+    /// Replaces the per-track pan, polarity and width.
+    pub fn set_track_mix(&mut self, track_mix: IndexMap<String, TrackMix>) {
+        self.track_mix = track_mix;
+    }
+
+    /// Returns the per-track EQ chains.
+    pub fn track_eq(&self) -> &IndexMap<String, Vec<EqBand>> {
+        &self.track_eq
+    }
+
+    /// Replaces the per-track EQ chains.
+    pub fn set_track_eq(&mut self, track_eq: IndexMap<String, Vec<EqBand>>) {
+        self.track_eq = track_eq;
+    }
+
+    /// Returns the monitor mixes.
+    pub fn mixes(&self) -> &IndexMap<String, MonitorMix> {
+        &self.mixes
+    }
+
+    /// Replaces the monitor mixes.
+    pub fn set_mixes(&mut self, mixes: IndexMap<String, MonitorMix>) {
+        self.mixes = mixes;
+    }
+
     /// Returns the audio configuration.
     pub fn audio(&self) -> &Audio {
         &self.audio
@@ -111,6 +159,34 @@ impl AudioConfig {
                 ));
             }
         }
+        for (name, mix) in &self.track_mix {
+            mix.validate(name, errors);
+        }
+        for (name, bands) in &self.track_eq {
+            EqBand::validate_chain(name, bands, errors);
+        }
+        // A channel carries either main routing or one mix, never both, so
+        // a performer's mix can't bleed into the mains or another mix.
+        let mut owners: HashMap<u16, String> = self
+            .track_mappings
+            .values()
+            .flatten()
+            .map(|&channel| (channel, "track_mappings".to_string()))
+            .collect();
+        for (name, mix) in &self.mixes {
+            mix.validate(name, errors);
+            for &channel in mix.channels() {
+                if let Some(owner) = owners.get(&channel) {
+                    errors.push(format!(
+                        "mixes '{}': channel {} is already used by {}",
+                        name, channel, owner
+                    ));
+                }
+            }
+            for &channel in mix.channels() {
+                owners.insert(channel, format!("mix '{}'", name));
+            }
+        }
     }
 
     /// Returns the track mappings as a HashMap (for runtime use where order doesn't matter).
@@ -120,6 +196,19 @@ impl AudioConfig {
             .map(|(k, v)| (k.clone(), v.clone()))
             .collect()
     }
+
+    /// Returns the track mappings plus each monitor mix's channels under the
+    /// mix's name, for sizing and naming the device's outputs.
+    pub fn output_mappings_hash(&self) -> HashMap<String, Vec<u16>> {
+        let mut mappings = self.track_mappings_hash();
+        for (name, mix) in &self.mixes {
+            mappings
+                .entry(name.clone())
+                .or_default()
+                .extend(mix.channels());
+        }
+        mappings
+    }
 }
 
 /// A unified hardware profile representing one complete host configuration.
@@ -388,6 +477,178 @@ mod tests {
         assert!(!serialized.contains("track_gains"));
     }
 
+    #[test]
+    fn test_track_mix_round_trip() {
+        let yaml = r#"
+            audio:
+              device: mock-device
+              track_mappings:
+                vocals: [1, 2]
+                bass: [3]
+              track_mix:
+                vocals:
+                  pan: -0.5
+                  pan_law: linear
+                bass:
+                  invert: true
+        "#;
+        let profile: Profile = Config::builder()
+            .add_source(File::from_str(yaml, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+
+        let audio_config = profile.audio_config().unwrap();
+        let vocals = &audio_config.track_mix()["vocals"];
+        assert_eq!(vocals.pan(), Some(-0.5));
+        assert_eq!(vocals.pan_law(), super::super::PanLaw::Linear);
+        assert!(audio_config.track_mix()["bass"].invert());
+        let mut errors = Vec::new();
+        audio_config.validate(&mut errors);
+        assert!(errors.is_empty(), "{errors:?}");
+
+        let serialized = crate::util::to_yaml_string(&profile).unwrap();
+        let reparsed: Profile = Config::builder()
+            .add_source(File::from_str(&serialized, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+        assert_eq!(
+            reparsed.audio_config().unwrap().track_mix(),
+            audio_config.track_mix()
+        );
+    }
+
+    #[test]
+    fn test_track_mix_validation() {
+        let mut audio_config = AudioConfig::new(Audio::new("dev"), IndexMap::new());
+        audio_config.set_track_mix(IndexMap::from([(
+            "vocals".to_string(),
+            TrackMix::new(Some(3.0), None, false, None),
+        )]));
+        let mut errors = Vec::new();
+        audio_config.validate(&mut errors);
+        assert!(
+            errors.iter().any(|e| e.contains("track_mix 'vocals'")),
+            "{errors:?}"
+        );
+    }
+
+    #[test]
+    fn test_track_eq_round_trip() {
+        let yaml = r#"
+            audio:
+              device: mock-device
+              track_mappings:
+                bgv: [1]
+              track_eq:
+                bgv:
+                  - type: high_pass
+                    freq: 120
+                  - type: peak
+                    freq: 3000
+                    gain: -3.0
+                    q: 2.0
+        "#;
+        let profile: Profile = Config::builder()
+            .add_source(File::from_str(yaml, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+
+        let audio_config = profile.audio_config().unwrap();
+        let bands = &audio_config.track_eq()["bgv"];
+        assert_eq!(bands.len(), 2);
+        assert_eq!(bands[0].kind(), super::super::EqBandKind::HighPass);
+        assert_eq!(bands[1].q(), 2.0);
+        let mut errors = Vec::new();
+        audio_config.validate(&mut errors);
+        assert!(errors.is_empty(), "{errors:?}");
+
+        let serialized = crate::util::to_yaml_string(&profile).unwrap();
+        let reparsed: Profile = Config::builder()
+            .add_source(File::from_str(&serialized, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+        assert_eq!(
+            reparsed.audio_config().unwrap().track_eq(),
+            audio_config.track_eq()
+        );
+    }
+
+    #[test]
+    fn test_mixes_round_trip() {
+        let yaml = r#"
+            audio:
+              device: mock-device
+              track_mappings:
+                click: [1]
+                keys: [2]
+              mixes:
+                drummer:
+                  channels: [3, 4]
+                  gains:
+                    click: 6.0
+                  muted: [keys]
+        "#;
+        let profile: Profile = Config::builder()
+            .add_source(File::from_str(yaml, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+
+        let audio_config = profile.audio_config().unwrap();
+        let drummer = &audio_config.mixes()["drummer"];
+        assert_eq!(drummer.channels(), &[3, 4]);
+        assert_eq!(drummer.gains()["click"], 6.0);
+        assert_eq!(drummer.muted(), &["keys".to_string()]);
+        let mut errors = Vec::new();
+        audio_config.validate(&mut errors);
+        assert!(errors.is_empty(), "{errors:?}");
+        assert_eq!(audio_config.output_mappings_hash()["drummer"], vec![3, 4]);
+
+        let serialized = crate::util::to_yaml_string(&profile).unwrap();
+        let reparsed: Profile = Config::builder()
+            .add_source(File::from_str(&serialized, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+        assert_eq!(
+            reparsed.audio_config().unwrap().mixes(),
+            audio_config.mixes()
+        );
+    }
+
+    #[test]
+    fn test_mixes_channel_overlap() {
+        let mut audio_config = AudioConfig::new(
+            Audio::new("dev"),
+            IndexMap::from([("click".to_string(), vec![1])]),
+        );
+        audio_config.set_mixes(IndexMap::from([
+            (
+                "drummer".to_string(),
+                MonitorMix::new(vec![1, 2], IndexMap::new(), Vec::new()),
+            ),
+            (
+                "singer".to_string(),
+                MonitorMix::new(vec![2], IndexMap::new(), Vec::new()),
+            ),
+        ]));
+        let mut errors = Vec::new();
+        audio_config.validate(&mut errors);
+        assert_eq!(errors.len(), 2, "{errors:?}");
+        assert!(errors[0].contains("channel 1 is already used by track_mappings"));
+        assert!(errors[1].contains("channel 2 is already used by mix 'drummer'"));
+    }
+
     #[test]
     fn test_track_mappings_absent_and_omitted() {
         // A profile mid-setup may have an audio device but no mappings yet;
@@ -29,6 +29,14 @@ pub struct SampleDefinition {
     /// The audio file for this sample (used when velocity mode is not "layers").
     file: Option<String>,
 
+    /// Variation files to choose between on each hit, used in place of `file`.
+    #[serde(default)]
+    files: Vec<String>,
+
+    /// How a hit chooses between variation files.
+    #[serde(default)]
+    variation: VariationMode,
+
     /// The output channels to route this sample to (1-indexed).
     #[serde(default)]
     output_channels: Vec<u16>,
@@ -57,6 +65,22 @@ pub struct SampleDefinition {
     /// Fade time in milliseconds for release_behavior: fade.
     #[serde(default = "default_fade_time_ms")]
     fade_time_ms: u32,
+
+    /// Tuning and key tracking.
+    #[serde(default)]
+    pitch: PitchConfig,
+
+    /// Milliseconds to skip at the start of the file on every hit.
+    #[serde(default)]
+    start_offset_ms: u32,
+
+    /// A region that repeats while the triggering note is held.
+    #[serde(default, rename = "loop")]
+    sustain_loop: Option<SustainLoop>,
+
+    /// Choke group membership, e.g. for hi-hats.
+    #[serde(default)]
+    choke: Option<ChokeConfig>,
 }
 
 fn default_fade_time_ms() -> u32 {
@@ -96,33 +120,71 @@ impl SampleDefinition {
         self.fade_time_ms
     }
 
-    /// Gets the file to play for a given velocity value.
-    /// Returns the file path and the volume scale factor (0.0 to 1.0).
-    pub fn file_for_velocity(&self, velocity: u8) -> Option<(&str, f32)> {
-        match &self.velocity.mode {
+    /// Gets the tuning and key tracking settings.
+    pub fn pitch(&self) -> &PitchConfig {
+        &self.pitch
+    }
+
+    /// Gets the number of milliseconds skipped at the start of the file.
+    pub fn start_offset_ms(&self) -> u32 {
+        self.start_offset_ms
+    }
+
+    /// Gets the sustain loop, if any.
+    pub fn sustain_loop(&self) -> Option<&SustainLoop> {
+        self.sustain_loop.as_ref()
+    }
+
+    /// Gets the choke settings, if any.
+    pub fn choke(&self) -> Option<&ChokeConfig> {
+        self.choke.as_ref()
+    }
+
+    /// Gets how a hit chooses between variation files.
+    pub fn variation(&self) -> VariationMode {
+        self.variation
+    }
+
+    /// Gets the number of variation groups: one per velocity layer, or a single
+    /// group when velocity mode is not "layers".
+    pub fn variation_groups(&self) -> usize {
+        match self.velocity.mode {
+            VelocityMode::Layers => self.velocity.layers.len(),
+            _ => 1,
+        }
+    }
+
+    /// Gets the files to choose between for a given velocity value.
+    /// Returns the index of the variation group (the matching layer, or 0 outside
+    /// "layers" mode), its files, and the volume scale factor (0.0 to 1.0).
+    pub fn variations_for_velocity(&self, velocity: u8) -> Option<(usize, &[String], f32)> {
+        let (group, files, volume) = match &self.velocity.mode {
             VelocityMode::Ignore => {
                 let volume = self.velocity.default.unwrap_or(DEFAULT_VELOCITY) as f32 / 127.0;
-                self.file.as_deref().map(|f| (f, volume))
+                (0, variation_files(&self.file, &self.files), volume)
             }
             VelocityMode::Scale => {
                 let volume = velocity as f32 / 127.0;
-                self.file.as_deref().map(|f| (f, volume))
+                (0, variation_files(&self.file, &self.files), volume)
             }
             VelocityMode::Layers => {
                 // Find the layer that matches this velocity
-                for layer in &self.velocity.layers {
-                    if velocity >= layer.range[0] && velocity <= layer.range[1] {
-                        let volume = if self.velocity.scale.unwrap_or(false) {
-                            velocity as f32 / 127.0
-                        } else {
-                            1.0
-                        };
-                        return Some((&layer.file, volume));
-                    }
-                }
-                None
+                let (index, layer) =
+                    self.velocity.layers.iter().enumerate().find(|(_, layer)| {
+                        velocity >= layer.range[0] && velocity <= layer.range[1]
+                    })?;
+                let volume = if self.velocity.scale.unwrap_or(false) {
+                    velocity as f32 / 127.0
+                } else {
+                    1.0
+                };
+                (index, variation_files(&layer.file, &layer.files), volume)
             }
+        };
+        if files.is_empty() {
+            return None;
         }
+        Some((group, files, volume))
     }
 
     /// Gets all files referenced by this sample definition (for preloading).
@@ -131,13 +193,26 @@ impl SampleDefinition {
         if let Some(file) = &self.file {
             files.push(file.as_str());
         }
+        files.extend(self.files.iter().map(String::as_str));
         for layer in &self.velocity.layers {
-            files.push(&layer.file);
+            if let Some(file) = &layer.file {
+                files.push(file.as_str());
+            }
+            files.extend(layer.files.iter().map(String::as_str));
         }
         files
     }
 }
 
+/// Gets the variation files for a sample or layer: `files` when set, otherwise `file`.
+fn variation_files<'a>(file: &'a Option<String>, files: &'a [String]) -> &'a [String] {
+    if !files.is_empty() {
+        files
+    } else {
+        file.as_ref().map(std::slice::from_ref).unwrap_or_default()
+    }
+}
+
 #[cfg(test)]
 impl SampleDefinition {
     /// Creates a new sample definition (test only).
@@ -153,6 +228,8 @@ impl SampleDefinition {
     ) -> Self {
         Self {
             file,
+            files: Vec::new(),
+            variation: VariationMode::default(),
             output_channels,
             output_track: None,
             velocity,
@@ -160,13 +237,43 @@ impl SampleDefinition {
             retrigger,
             max_voices,
             fade_time_ms,
+            pitch: PitchConfig::default(),
+            start_offset_ms: 0,
+            sustain_loop: None,
+            choke: None,
         }
     }
 
+    /// Sets the choke settings (test only).
+    pub fn with_choke(mut self, choke: ChokeConfig) -> Self {
+        self.choke = Some(choke);
+        self
+    }
+
+    /// Sets the pitch, start offset and sustain loop (test only).
+    pub fn with_playback(
+        mut self,
+        pitch: PitchConfig,
+        start_offset_ms: u32,
+        sustain_loop: Option<SustainLoop>,
+    ) -> Self {
+        self.pitch = pitch;
+        self.start_offset_ms = start_offset_ms;
+        self.sustain_loop = sustain_loop;
+        self
+    }
+
     /// Gets the audio file path (test only).
     pub fn file(&self) -> Option<&str> {
         self.file.as_deref()
     }
+
+    /// Sets the variation files and how they are chosen (test only).
+    pub fn with_variations(mut self, files: Vec<String>, variation: VariationMode) -> Self {
+        self.files = files;
+        self.variation = variation;
+        self
+    }
 }
 
 /// Configuration for velocity handling.
@@ -240,17 +347,195 @@ pub struct VelocityLayer {
     range: [u8; 2],
 
     /// The audio file for this velocity layer.
-    file: String,
+    #[serde(default)]
+    file: Option<String>,
+
+    /// Variation files for this velocity layer, used in place of `file`.
+    #[serde(default)]
+    files: Vec<String>,
 }
 
 #[cfg(test)]
 impl VelocityLayer {
     /// Creates a new velocity layer (test only).
     pub fn new(range: [u8; 2], file: String) -> Self {
-        Self { range, file }
+        Self {
+            range,
+            file: Some(file),
+            files: Vec::new(),
+        }
+    }
+
+    /// Creates a new velocity layer with variation files (test only).
+    pub fn with_files(range: [u8; 2], files: Vec<String>) -> Self {
+        Self {
+            range,
+            file: None,
+            files,
+        }
+    }
+}
+
+/// Tuning and key tracking for a sample.
+#[derive(Deserialize, Clone, Serialize, Debug, Default)]
+pub struct PitchConfig {
+    /// Fine tuning in cents (100 cents = 1 semitone).
+    #[serde(default)]
+    tuning_cents: f32,
+
+    /// The MIDI note at which the sample plays at its recorded pitch. When set,
+    /// the sample follows the pitch of the note that triggered it.
+    root_note: Option<u8>,
+
+    /// The notes [low, high] inclusive that play this sample from a note trigger
+    /// on the trigger's channel. Only used with key tracking.
+    key_range: Option<[u8; 2]>,
+}
+
+impl PitchConfig {
+    /// Gets the fine tuning in cents.
+    pub fn tuning_cents(&self) -> f32 {
+        self.tuning_cents
+    }
+
+    /// Gets the key tracking root note.
+    pub fn root_note(&self) -> Option<u8> {
+        self.root_note
+    }
+
+    /// Returns true if key tracking is on and the note falls in the key range.
+    pub fn plays_note(&self, note: u8) -> bool {
+        match (self.root_note, self.key_range) {
+            (Some(_), Some([low, high])) => note >= low && note <= high,
+            _ => false,
+        }
+    }
+
+    /// Gets the playback rate for the given triggering note: 1.0 plays the
+    /// sample at its recorded pitch, 2.0 an octave up.
+    pub fn rate_for_note(&self, note: Option<u8>) -> f64 {
+        let semitones = match (self.root_note, note) {
+            (Some(root), Some(note)) => note as f64 - root as f64,
+            _ => 0.0,
+        };
+        let cents = semitones * 100.0 + self.tuning_cents as f64;
+        2f64.powf(cents / 1200.0)
+    }
+}
+
+#[cfg(test)]
+impl PitchConfig {
+    /// Creates a new pitch config (test only).
+    pub fn new(tuning_cents: f32, root_note: Option<u8>, key_range: Option<[u8; 2]>) -> Self {
+        Self {
+            tuning_cents,
+            root_note,
+            key_range,
+        }
     }
 }
 
+/// A loop region held while the triggering note is down. Positions are sample
+/// frames in the original file, as shown by most sample editors.
+#[derive(Deserialize, Clone, Copy, Serialize, Debug, PartialEq, Eq)]
+pub struct SustainLoop {
+    /// The first frame of the loop.
+    start: u64,
+
+    /// The frame just past the end of the loop.
+    end: u64,
+}
+
+impl SustainLoop {
+    /// Gets the first frame of the loop.
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    /// Gets the frame just past the end of the loop.
+    pub fn end(&self) -> u64 {
+        self.end
+    }
+}
+
+#[cfg(test)]
+impl SustainLoop {
+    /// Creates a new sustain loop (test only).
+    pub fn new(start: u64, end: u64) -> Self {
+        Self { start, end }
+    }
+}
+
+/// Default fade time in milliseconds for a choked voice.
+pub const DEFAULT_CHOKE_FADE_MS: u32 = 10;
+
+/// Choke settings for a sample. A hit cuts the voices of other samples in the
+/// groups it chokes, fading each out over that sample's own fade time.
+#[derive(Deserialize, Clone, Serialize, Debug)]
+pub struct ChokeConfig {
+    /// The choke group this sample belongs to. Its voices are cut by hits that
+    /// choke this group.
+    group: String,
+
+    /// The groups a hit on this sample chokes. Defaults to its own group; an
+    /// empty list makes the sample choke nothing (e.g. an open hi-hat).
+    chokes: Option<Vec<String>>,
+
+    /// Fade time in milliseconds when this sample's voices are choked.
+    #[serde(default = "default_choke_fade_ms")]
+    fade_ms: u32,
+}
+
+fn default_choke_fade_ms() -> u32 {
+    DEFAULT_CHOKE_FADE_MS
+}
+
+impl ChokeConfig {
+    /// Gets the choke group this sample belongs to.
+    pub fn group(&self) -> &str {
+        &self.group
+    }
+
+    /// Gets the fade time in milliseconds when this sample's voices are choked.
+    pub fn fade_ms(&self) -> u32 {
+        self.fade_ms
+    }
+
+    /// Returns true if a hit on this sample chokes the given group.
+    pub fn chokes_group(&self, group: &str) -> bool {
+        match &self.chokes {
+            Some(chokes) => chokes.iter().any(|choked| choked == group),
+            None => self.group == group,
+        }
+    }
+}
+
+#[cfg(test)]
+impl ChokeConfig {
+    /// Creates a new choke config (test only).
+    pub fn new(group: &str, chokes: Option<Vec<&str>>, fade_ms: u32) -> Self {
+        Self {
+            group: group.to_string(),
+            chokes: chokes.map(|chokes| chokes.into_iter().map(str::to_string).collect()),
+            fade_ms,
+        }
+    }
+}
+
+/// How a hit chooses between a sample's variation files.
+#[derive(Deserialize, Clone, Copy, Serialize, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VariationMode {
+    /// Cycle through each layer's files in order, keeping a position per layer.
+    #[default]
+    RoundRobin,
+    /// Pick a file at random, never the same one twice in a row.
+    Random,
+    /// Step through the files with one position shared by all layers, so
+    /// consecutive hits move forward even when they land in different layers.
+    Sequential,
+}
+
 /// Behavior when a voice is released (e.g. Note Off, trigger release).
 #[derive(Deserialize, Clone, Copy, Serialize, Debug, Default, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -316,6 +601,10 @@ pub struct SamplesConfig {
     /// Maximum number of concurrent sample voices globally.
     #[serde(default = "default_max_sample_voices")]
     max_sample_voices: u32,
+
+    /// Named sample kits that can be switched in live.
+    #[serde(default, skip_serializing_if = "KitsConfig::is_empty")]
+    sample_kits: KitsConfig,
 }
 
 fn default_max_sample_voices() -> u32 {
@@ -333,9 +622,21 @@ impl SamplesConfig {
             samples,
             sample_triggers,
             max_sample_voices,
+            sample_kits: KitsConfig::default(),
         }
     }
 
+    /// Sets the sample kits.
+    pub fn with_kits(mut self, sample_kits: KitsConfig) -> Self {
+        self.sample_kits = sample_kits;
+        self
+    }
+
+    /// Gets the sample kits.
+    pub fn sample_kits(&self) -> &KitsConfig {
+        &self.sample_kits
+    }
+
     /// Gets the sample definitions.
     pub fn samples(&self) -> &HashMap<String, SampleDefinition> {
         &self.samples
@@ -370,6 +671,123 @@ impl SamplesConfig {
                 .retain(|t| t.trigger != other_trigger.trigger);
             self.sample_triggers.push(other_trigger);
         }
+
+        self.sample_kits.merge(other.sample_kits);
+    }
+}
+
+/// Named sample kits and how they are selected.
+#[derive(Deserialize, Clone, Serialize, Debug, Default)]
+pub struct KitsConfig {
+    /// MIDI channel (1-16) on which program changes select kits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    program_channel: Option<u8>,
+
+    /// Kits selected when playback enters a section, keyed by section name.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    sections: HashMap<String, String>,
+
+    /// Kit definitions by name.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    kits: HashMap<String, KitConfig>,
+}
+
+impl KitsConfig {
+    /// Gets the MIDI channel (1-16) on which program changes select kits.
+    pub fn program_channel(&self) -> Option<u8> {
+        self.program_channel
+    }
+
+    /// Gets the kits selected by section, keyed by section name.
+    pub fn sections(&self) -> &HashMap<String, String> {
+        &self.sections
+    }
+
+    /// Gets the kit definitions.
+    pub fn kits(&self) -> &HashMap<String, KitConfig> {
+        &self.kits
+    }
+
+    /// Returns true if nothing is configured.
+    pub fn is_empty(&self) -> bool {
+        self.program_channel.is_none() && self.sections.is_empty() && self.kits.is_empty()
+    }
+
+    /// Merges another kits config into this one. The other config's values override.
+    pub fn merge(&mut self, other: KitsConfig) {
+        if other.program_channel.is_some() {
+            self.program_channel = other.program_channel;
+        }
+        self.sections.extend(other.sections);
+        self.kits.extend(other.kits);
+    }
+}
+
+#[cfg(test)]
+impl KitsConfig {
+    /// Creates a new kits config (test only).
+    pub fn new(
+        program_channel: Option<u8>,
+        sections: HashMap<String, String>,
+        kits: HashMap<String, KitConfig>,
+    ) -> Self {
+        Self {
+            program_channel,
+            sections,
+            kits,
+        }
+    }
+}
+
+/// A named set of samples and triggers that replaces the base ones while it is
+/// the active kit.
+#[derive(Deserialize, Clone, Serialize, Debug, Default)]
+pub struct KitConfig {
+    /// MIDI program number (0-127) that selects this kit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    program: Option<u8>,
+
+    /// Sample definitions that replace base samples with the same name, or add
+    /// new ones, while the kit is active.
+    #[serde(default)]
+    samples: HashMap<String, SampleDefinition>,
+
+    /// Triggers that replace base triggers with the same MIDI event, or add new
+    /// ones, while the kit is active.
+    #[serde(default)]
+    sample_triggers: Vec<SampleTrigger>,
+}
+
+impl KitConfig {
+    /// Gets the MIDI program number that selects this kit.
+    pub fn program(&self) -> Option<u8> {
+        self.program
+    }
+
+    /// Gets the kit's sample definitions.
+    pub fn samples(&self) -> &HashMap<String, SampleDefinition> {
+        &self.samples
+    }
+
+    /// Gets the kit's sample triggers.
+    pub fn sample_triggers(&self) -> &[SampleTrigger] {
+        &self.sample_triggers
+    }
+}
+
+#[cfg(test)]
+impl KitConfig {
+    /// Creates a new kit config (test only).
+    pub fn new(
+        program: Option<u8>,
+        samples: HashMap<String, SampleDefinition>,
+        sample_triggers: Vec<SampleTrigger>,
+    ) -> Self {
+        Self {
+            program,
+            samples,
+            sample_triggers,
+        }
     }
 }
 
@@ -391,12 +809,12 @@ mod tests {
             50,
         );
 
-        let (file, volume) = def.file_for_velocity(50).unwrap();
-        assert_eq!(file, "test.wav");
+        let (_, files, volume) = def.variations_for_velocity(50).unwrap();
+        assert_eq!(files, ["test.wav"]);
         assert!((volume - 100.0 / 127.0).abs() < 0.001);
 
         // Velocity value doesn't matter in ignore mode
-        let (_, volume2) = def.file_for_velocity(127).unwrap();
+        let (_, _, volume2) = def.variations_for_velocity(127).unwrap();
         assert!((volume - volume2).abs() < 0.001);
     }
 
@@ -412,11 +830,11 @@ mod tests {
             50,
         );
 
-        let (file, volume) = def.file_for_velocity(64).unwrap();
-        assert_eq!(file, "test.wav");
+        let (_, files, volume) = def.variations_for_velocity(64).unwrap();
+        assert_eq!(files, ["test.wav"]);
         assert!((volume - 64.0 / 127.0).abs() < 0.001);
 
-        let (_, volume2) = def.file_for_velocity(127).unwrap();
+        let (_, _, volume2) = def.variations_for_velocity(127).unwrap();
         assert!((volume2 - 1.0).abs() < 0.001);
     }
 
@@ -438,15 +856,15 @@ mod tests {
             50,
         );
 
-        let (file, volume) = def.file_for_velocity(45).unwrap();
-        assert_eq!(file, "soft.wav");
+        let (_, files, volume) = def.variations_for_velocity(45).unwrap();
+        assert_eq!(files, ["soft.wav"]);
         assert!((volume - 1.0).abs() < 0.001); // No scaling
 
-        let (file, _) = def.file_for_velocity(80).unwrap();
-        assert_eq!(file, "medium.wav");
+        let (_, files, _) = def.variations_for_velocity(80).unwrap();
+        assert_eq!(files, ["medium.wav"]);
 
-        let (file, _) = def.file_for_velocity(120).unwrap();
-        assert_eq!(file, "hard.wav");
+        let (_, files, _) = def.variations_for_velocity(120).unwrap();
+        assert_eq!(files, ["hard.wav"]);
     }
 
     #[test]
@@ -466,12 +884,12 @@ mod tests {
             50,
         );
 
-        let (file, volume) = def.file_for_velocity(45).unwrap();
-        assert_eq!(file, "soft.wav");
+        let (_, files, volume) = def.variations_for_velocity(45).unwrap();
+        assert_eq!(files, ["soft.wav"]);
         assert!((volume - 45.0 / 127.0).abs() < 0.001); // Scaled to full range
 
-        let (file, volume) = def.file_for_velocity(100).unwrap();
-        assert_eq!(file, "hard.wav");
+        let (_, files, volume) = def.variations_for_velocity(100).unwrap();
+        assert_eq!(files, ["hard.wav"]);
         assert!((volume - 100.0 / 127.0).abs() < 0.001);
     }
 
@@ -499,6 +917,304 @@ mod tests {
         assert!(files.contains(&"hard.wav"));
     }
 
+    #[test]
+    fn test_variation_deserialization() {
+        let yaml = r#"
+            samples:
+              snare:
+                variation: random
+                velocity:
+                  mode: layers
+                  layers:
+                    - range: [1, 80]
+                      files: [soft-1.wav, soft-2.wav]
+                    - range: [81, 127]
+                      file: hard.wav
+              tom:
+                files: [tom-1.wav, tom-2.wav, tom-3.wav]
+        "#;
+        let config: SamplesConfig = Config::builder()
+            .add_source(File::from_str(yaml, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+
+        let snare = config.samples.get("snare").unwrap();
+        assert_eq!(snare.variation(), VariationMode::Random);
+        assert_eq!(snare.variation_groups(), 2);
+        let (group, files, _) = snare.variations_for_velocity(40).unwrap();
+        assert_eq!(group, 0);
+        assert_eq!(files, ["soft-1.wav", "soft-2.wav"]);
+        let (group, files, _) = snare.variations_for_velocity(100).unwrap();
+        assert_eq!(group, 1);
+        assert_eq!(files, ["hard.wav"]);
+
+        let tom = config.samples.get("tom").unwrap();
+        assert_eq!(tom.variation(), VariationMode::RoundRobin);
+        assert_eq!(tom.variation_groups(), 1);
+        let (group, files, _) = tom.variations_for_velocity(100).unwrap();
+        assert_eq!(group, 0);
+        assert_eq!(files, ["tom-1.wav", "tom-2.wav", "tom-3.wav"]);
+        assert_eq!(tom.all_files().len(), 3);
+    }
+
+    #[test]
+    fn test_variation_files_take_precedence_over_file() {
+        let def = SampleDefinition::new(
+            Some("single.wav".to_string()),
+            vec![1],
+            VelocityConfig::scale(),
+            ReleaseBehavior::PlayToCompletion,
+            RetriggerBehavior::Cut,
+            None,
+            50,
+        )
+        .with_variations(
+            vec!["a.wav".to_string(), "b.wav".to_string()],
+            VariationMode::Sequential,
+        );
+
+        let (_, files, _) = def.variations_for_velocity(64).unwrap();
+        assert_eq!(files, ["a.wav", "b.wav"]);
+    }
+
+    #[test]
+    fn test_layer_without_files_returns_none() {
+        let layers = vec![VelocityLayer::with_files([1, 127], Vec::new())];
+        let def = SampleDefinition::new(
+            None,
+            vec![1],
+            VelocityConfig::with_layers(layers, false),
+            ReleaseBehavior::PlayToCompletion,
+            RetriggerBehavior::Cut,
+            None,
+            50,
+        );
+
+        assert!(def.variations_for_velocity(64).is_none());
+    }
+
+    #[test]
+    fn test_playback_deserialization() {
+        let yaml = r#"
+            samples:
+              pad:
+                file: pad.wav
+                release_behavior: play_to_completion
+                start_offset_ms: 12
+                pitch:
+                  tuning_cents: -15
+                  root_note: 60
+                  key_range: [48, 72]
+                loop:
+                  start: 4410
+                  end: 88200
+              kick:
+                file: kick.wav
+        "#;
+        let config: SamplesConfig = Config::builder()
+            .add_source(File::from_str(yaml, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+
+        let pad = config.samples.get("pad").unwrap();
+        assert_eq!(pad.start_offset_ms(), 12);
+        assert_eq!(pad.pitch().tuning_cents(), -15.0);
+        assert_eq!(pad.pitch().root_note(), Some(60));
+        assert_eq!(pad.sustain_loop(), Some(&SustainLoop::new(4410, 88200)));
+
+        let kick = config.samples.get("kick").unwrap();
+        assert_eq!(kick.start_offset_ms(), 0);
+        assert_eq!(kick.pitch().root_note(), None);
+        assert!(kick.sustain_loop().is_none());
+        assert_eq!(kick.pitch().rate_for_note(Some(72)), 1.0);
+    }
+
+    #[test]
+    fn test_pitch_rate_for_note() {
+        let tracked = PitchConfig::new(0.0, Some(60), Some([48, 72]));
+        assert!((tracked.rate_for_note(Some(60)) - 1.0).abs() < 1e-9);
+        assert!((tracked.rate_for_note(Some(72)) - 2.0).abs() < 1e-9);
+        assert!((tracked.rate_for_note(Some(48)) - 0.5).abs() < 1e-9);
+        // Triggers without a note play at the root pitch.
+        assert!((tracked.rate_for_note(None) - 1.0).abs() < 1e-9);
+
+        let tuned = PitchConfig::new(100.0, None, None);
+        let semitone = 2f64.powf(1.0 / 12.0);
+        assert!((tuned.rate_for_note(Some(30)) - semitone).abs() < 1e-9);
+
+        let both = PitchConfig::new(-50.0, Some(60), None);
+        let expected = 2f64.powf(150.0 / 1200.0);
+        assert!((both.rate_for_note(Some(62)) - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pitch_plays_note() {
+        let tracked = PitchConfig::new(0.0, Some(60), Some([48, 72]));
+        assert!(tracked.plays_note(48));
+        assert!(tracked.plays_note(72));
+        assert!(!tracked.plays_note(47));
+        assert!(!tracked.plays_note(73));
+
+        // A key range without a root note does not extend the trigger.
+        let untracked = PitchConfig::new(0.0, None, Some([48, 72]));
+        assert!(!untracked.plays_note(60));
+    }
+
+    #[test]
+    fn test_choke_deserialization() {
+        let yaml = r#"
+            samples:
+              closed-hat:
+                file: closed.wav
+                choke:
+                  group: hihat
+              open-hat:
+                file: open.wav
+                choke:
+                  group: hihat
+                  chokes: []
+                  fade_ms: 40
+              crash:
+                file: crash.wav
+                choke:
+                  group: cymbals
+                  chokes: [cymbals, hihat]
+        "#;
+        let config: SamplesConfig = Config::builder()
+            .add_source(File::from_str(yaml, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+
+        let closed = config.samples.get("closed-hat").unwrap().choke().unwrap();
+        assert_eq!(closed.group(), "hihat");
+        assert_eq!(closed.fade_ms(), DEFAULT_CHOKE_FADE_MS);
+        assert!(closed.chokes_group("hihat"));
+        assert!(!closed.chokes_group("cymbals"));
+
+        let open = config.samples.get("open-hat").unwrap().choke().unwrap();
+        assert_eq!(open.fade_ms(), 40);
+        assert!(!open.chokes_group("hihat"));
+
+        let crash = config.samples.get("crash").unwrap().choke().unwrap();
+        assert!(crash.chokes_group("cymbals"));
+        assert!(crash.chokes_group("hihat"));
+    }
+
+    #[test]
+    fn test_kits_deserialization() {
+        let yaml = r#"
+            samples:
+              snare:
+                file: snare.wav
+            sample_kits:
+              program_channel: 16
+              sections:
+                chorus: big
+              kits:
+                big:
+                  program: 2
+                  samples:
+                    snare:
+                      file: snare-big.wav
+                  sample_triggers:
+                    - trigger:
+                        type: note_on
+                        channel: 10
+                        key: 40
+                      sample: snare
+                brushes:
+                  samples:
+                    snare:
+                      file: snare-brush.wav
+        "#;
+        let config: SamplesConfig = Config::builder()
+            .add_source(File::from_str(yaml, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+
+        let kits = config.sample_kits();
+        assert_eq!(kits.program_channel(), Some(16));
+        assert_eq!(
+            kits.sections().get("chorus").map(String::as_str),
+            Some("big")
+        );
+        let big = kits.kits().get("big").unwrap();
+        assert_eq!(big.program(), Some(2));
+        assert_eq!(
+            big.samples().get("snare").unwrap().file(),
+            Some("snare-big.wav")
+        );
+        assert_eq!(big.sample_triggers().len(), 1);
+        let brushes = kits.kits().get("brushes").unwrap();
+        assert_eq!(brushes.program(), None);
+        assert!(brushes.sample_triggers().is_empty());
+
+        // Without kits, nothing is configured.
+        let config: SamplesConfig = Config::builder()
+            .add_source(File::from_str("samples: {}", FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+        assert!(config.sample_kits().is_empty());
+    }
+
+    #[test]
+    fn test_merge_kits() {
+        let kit = |file: &str| {
+            KitConfig::new(
+                None,
+                HashMap::from([(
+                    "snare".to_string(),
+                    SampleDefinition::new(
+                        Some(file.to_string()),
+                        vec![1],
+                        VelocityConfig::ignore(None),
+                        ReleaseBehavior::PlayToCompletion,
+                        RetriggerBehavior::Cut,
+                        None,
+                        50,
+                    ),
+                )]),
+                Vec::new(),
+            )
+        };
+        let mut config = SamplesConfig::new(HashMap::new(), vec![], 32).with_kits(KitsConfig::new(
+            Some(10),
+            HashMap::from([("verse".to_string(), "dry".to_string())]),
+            HashMap::from([
+                ("dry".to_string(), kit("dry.wav")),
+                ("big".to_string(), kit("big.wav")),
+            ]),
+        ));
+        let other = SamplesConfig::new(HashMap::new(), vec![], 32).with_kits(KitsConfig::new(
+            None,
+            HashMap::from([("chorus".to_string(), "big".to_string())]),
+            HashMap::from([("big".to_string(), kit("bigger.wav"))]),
+        ));
+
+        config.merge(other);
+
+        let kits = config.sample_kits();
+        // The program channel is kept when the other config doesn't set one.
+        assert_eq!(kits.program_channel(), Some(10));
+        assert_eq!(kits.sections().len(), 2);
+        assert_eq!(kits.kits().len(), 2);
+        let big = kits.kits().get("big").unwrap();
+        assert_eq!(
+            big.samples().get("snare").unwrap().file(),
+            Some("bigger.wav")
+        );
+    }
+
     #[test]
     fn test_merge_configs() {
         let mut config1 = SamplesConfig::new(
@@ -711,9 +1427,9 @@ mod tests {
             50,
         );
         // Velocity 5 is below the only layer range [10, 50]
-        assert!(def.file_for_velocity(5).is_none());
+        assert!(def.variations_for_velocity(5).is_none());
         // Velocity 51 is above the only layer range
-        assert!(def.file_for_velocity(51).is_none());
+        assert!(def.variations_for_velocity(51).is_none());
     }
 
     #[test]
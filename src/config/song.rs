@@ -19,11 +19,12 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use super::{
+    ltc::LtcConfig,
     metronome::MetronomeConfig,
     midi::{self, ToMidiEvent},
     notification::SongNotificationConfig,
     pilot::PilotConfig,
-    samples::{SampleDefinition, SampleTrigger, SamplesConfig},
+    samples::{KitsConfig, SampleDefinition, SampleTrigger, SamplesConfig},
     tempo::TempoConfig,
     track::Track,
 };
@@ -54,6 +55,9 @@ pub struct Song {
     /// Song-specific sample trigger mappings (overrides global triggers with same MIDI event).
     #[serde(default)]
     sample_triggers: Vec<SampleTrigger>,
+    /// Song-specific sample kits (overrides global kits with the same name).
+    #[serde(default, skip_serializing_if = "KitsConfig::is_empty")]
+    sample_kits: KitsConfig,
     /// Whether this song should loop when it finishes playing.
     #[serde(default)]
     loop_playback: bool,
@@ -73,9 +77,16 @@ pub struct Song {
     /// audio samples rendered onto a dedicated virtual track.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pilot: Option<PilotConfig>,
+    /// The song's linear timecode: SMPTE LTC rendered onto a virtual track.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ltc: Option<LtcConfig>,
     /// Per-song notification audio overrides.
     #[serde(default)]
     notification_audio: Option<SongNotificationConfig>,
+    /// The MIDI Timecode the song starts at, so each song in a set can sit
+    /// at its own position (a distinct hour, say). Defaults to 00:00:00:00.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    mtc_start: Option<midi::Timecode>,
 }
 
 /// A named section of a song defined by measure boundaries.
@@ -126,12 +137,15 @@ impl Song {
             tracks,
             samples,
             sample_triggers,
+            sample_kits: KitsConfig::default(),
             loop_playback: false,
             sections: Vec::new(),
             tempo: None,
             metronome: None,
             pilot: None,
+            ltc: None,
             notification_audio: None,
+            mtc_start: None,
         }
     }
 
@@ -309,6 +323,30 @@ impl Song {
             }
         }
 
+        if let Some(ltc) = &self.ltc {
+            if let Err(err) = ltc.validate() {
+                errors.push(format!("ltc: {}", err));
+            }
+            let virtual_tracks = [
+                self.metronome.as_ref().map(|m| ("metronome", &m.track)),
+                self.pilot.as_ref().map(|p| ("pilot", &p.track)),
+            ];
+            if self.tracks.iter().any(|track| track.name() == ltc.track) {
+                errors.push(format!(
+                    "ltc: track name \"{}\" collides with an audio track",
+                    ltc.track
+                ));
+            }
+            for (kind, track) in virtual_tracks.into_iter().flatten() {
+                if *track == ltc.track {
+                    errors.push(format!(
+                        "ltc: track name \"{}\" collides with the {} track",
+                        ltc.track, kind
+                    ));
+                }
+            }
+        }
+
         if errors.is_empty() {
             Ok(())
         } else {
@@ -388,11 +426,21 @@ impl Song {
         self.pilot.as_ref()
     }
 
+    /// Gets the song's linear timecode configuration.
+    pub fn ltc(&self) -> Option<&LtcConfig> {
+        self.ltc.as_ref()
+    }
+
     /// Gets the per-song notification audio overrides.
     pub fn notification_audio(&self) -> Option<&SongNotificationConfig> {
         self.notification_audio.as_ref()
     }
 
+    /// Gets the MIDI Timecode the song starts at.
+    pub fn mtc_start(&self) -> Option<midi::Timecode> {
+        self.mtc_start
+    }
+
     /// Gets the song-specific samples configuration.
     /// Returns a SamplesConfig that can be merged with the global config.
     pub fn samples_config(&self) -> SamplesConfig {
@@ -401,6 +449,7 @@ impl Song {
             self.sample_triggers.clone(),
             0, // Per-song config doesn't set global max_voices
         )
+        .with_kits(self.sample_kits.clone())
     }
 }
 
@@ -697,6 +746,43 @@ mod tests {
         let sc = song.samples_config();
         assert!(sc.samples().is_empty());
         assert!(sc.sample_triggers().is_empty());
+        assert!(sc.sample_kits().is_empty());
+    }
+
+    #[test]
+    fn serde_deserialize_with_sample_kits() {
+        let yaml = r#"
+            name: "Kit Song"
+            tracks:
+              - name: track1
+                file: track1.wav
+            sample_kits:
+              sections:
+                verse: brushes
+              kits:
+                brushes:
+                  samples:
+                    snare:
+                      file: brush.wav
+        "#;
+        let song: Song = config::Config::builder()
+            .add_source(config::File::from_str(yaml, config::FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+        let sc = song.samples_config();
+        let kits = sc.sample_kits();
+        assert_eq!(
+            kits.sections().get("verse").map(String::as_str),
+            Some("brushes")
+        );
+        assert!(kits
+            .kits()
+            .get("brushes")
+            .unwrap()
+            .samples()
+            .contains_key("snare"));
     }
 
     #[test]
@@ -741,6 +827,25 @@ mod tests {
         assert_eq!(mp.exclude_midi_channels(), vec![9, 15]);
     }
 
+    #[test]
+    fn serde_deserialize_with_mtc_start() {
+        let yaml = r#"
+            name: "Timecoded Song"
+            tracks:
+              - name: track1
+                file: track1.wav
+            mtc_start: "02:00:00:00"
+        "#;
+        let song: Song = config::Config::builder()
+            .add_source(config::File::from_str(yaml, config::FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+        assert_eq!(song.mtc_start(), Some(midi::Timecode::new(2, 0, 0, 0)));
+        assert!(minimal_song().mtc_start().is_none());
+    }
+
     #[test]
     fn save_creates_file() {
         let song = minimal_song();
@@ -1129,4 +1234,20 @@ mod tests {
         let song = Song::deserialize(&path).unwrap();
         assert!(song.loop_playback());
     }
+
+    #[test]
+    fn validate_rejects_ltc_track_collision() {
+        let mut song = minimal_song();
+        song.ltc = Some(LtcConfig {
+            track: "track1".to_string(),
+            ..LtcConfig::default()
+        });
+        let errors = song.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.contains("collides with an audio track")));
+
+        song.ltc = Some(LtcConfig::default());
+        assert!(song.validate().is_ok());
+    }
 }
@@ -606,6 +606,50 @@ impl ConfigStore {
         &self,
         hostname: &str,
         gains: indexmap::IndexMap<String, f32>,
+    ) -> Result<(), ConfigError> {
+        self.update_active_audio(hostname, |audio| audio.set_track_gains(gains.clone()))
+            .await
+    }
+
+    /// Sets the per-track pan, polarity and width on the active profile and
+    /// persists them, the same way as [`ConfigStore::set_track_gains`].
+    pub async fn set_track_mix(
+        &self,
+        hostname: &str,
+        track_mix: indexmap::IndexMap<String, super::TrackMix>,
+    ) -> Result<(), ConfigError> {
+        self.update_active_audio(hostname, |audio| audio.set_track_mix(track_mix.clone()))
+            .await
+    }
+
+    /// Sets the per-track EQ chains on the active profile and persists them,
+    /// the same way as [`ConfigStore::set_track_gains`].
+    pub async fn set_track_eq(
+        &self,
+        hostname: &str,
+        track_eq: indexmap::IndexMap<String, Vec<super::EqBand>>,
+    ) -> Result<(), ConfigError> {
+        self.update_active_audio(hostname, |audio| audio.set_track_eq(track_eq.clone()))
+            .await
+    }
+
+    /// Sets the monitor mixes on the active profile and persists them, the
+    /// same way as [`ConfigStore::set_track_gains`].
+    pub async fn set_mixes(
+        &self,
+        hostname: &str,
+        mixes: indexmap::IndexMap<String, super::MonitorMix>,
+    ) -> Result<(), ConfigError> {
+        self.update_active_audio(hostname, |audio| audio.set_mixes(mixes.clone()))
+            .await
+    }
+
+    /// Applies `update` to the active profile's audio config, in memory and
+    /// on disk.
+    async fn update_active_audio(
+        &self,
+        hostname: &str,
+        update: impl Fn(&mut super::profile::AudioConfig),
     ) -> Result<(), ConfigError> {
         let mut guard = self.inner.write().await;
 
@@ -616,14 +660,14 @@ impl ConfigStore {
         let audio = profile.audio_config_mut().ok_or_else(|| {
             ConfigError::Validation("active profile has no audio config".to_string())
         })?;
-        audio.set_track_gains(gains.clone());
+        update(audio);
 
         // `directory_profiles` rather than `resolved_profiles_dir`: a
         // configured-but-empty directory falls back to inline profiles at load
         // time, and looking for an owning file in it would fail with "no
         // profile file matches" on a config that loaded perfectly well.
         if let Some(dir) = self.directory_profiles(&guard) {
-            persist_gains_to_profile_file(&dir, hostname, &gains)?;
+            persist_audio_to_profile_file(&dir, hostname, update)?;
         } else {
             let new_yaml = to_yaml_string(&*guard)
                 .map_err(|e| ConfigError::StoreSerialization(e.to_string()))?;
@@ -654,18 +698,18 @@ impl ConfigStore {
     }
 }
 
-/// Persists track gains into the profile file inside `dir` that owns the
-/// active profile: files are visited in the same sorted order used at load
-/// time, and the first profile matching the hostname rule wins.
-fn persist_gains_to_profile_file(
+/// Applies `update` to the audio config in the profile file inside `dir`
+/// that owns the active profile: files are visited in the same sorted order
+/// used at load time, and the first profile matching the hostname rule wins.
+fn persist_audio_to_profile_file(
     dir: &std::path::Path,
     hostname: &str,
-    gains: &indexmap::IndexMap<String, f32>,
+    update: impl Fn(&mut super::profile::AudioConfig),
 ) -> Result<(), ConfigError> {
     let yaml_paths = super::player::list_profile_files(dir)?;
 
     // A parse failure aborts (matching load behavior) rather than skipping:
-    // skipping an unparseable file could write the gains into the wrong
+    // skipping an unparseable file could write the change into the wrong
     // profile when the broken file is the active profile's.
     for path in &yaml_paths {
         let mut profile = config::Config::builder()
@@ -688,7 +732,7 @@ fn persist_gains_to_profile_file(
         let audio = profile.audio_config_mut().ok_or_else(|| {
             ConfigError::Validation("active profile has no audio config".to_string())
         })?;
-        audio.set_track_gains(gains.clone());
+        update(audio);
 
         let yaml =
             to_yaml_string(&profile).map_err(|e| ConfigError::StoreSerialization(e.to_string()))?;
@@ -1413,6 +1457,106 @@ profiles:
         assert!(store.set_track_gains("nope", gains).await.is_err());
     }
 
+    #[tokio::test]
+    async fn set_track_mix_keeps_gains() {
+        let yaml = r#"
+songs: songs
+profiles:
+  - hostname: mix-host
+    audio:
+      device: mock-device
+      track_mappings:
+        vocals: [1, 2]
+      track_gains:
+        vocals: -3.0
+"#;
+        let player = make_player(yaml);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.yaml");
+        std::fs::write(&path, yaml).unwrap();
+
+        let store = ConfigStore::new(player, path.clone());
+        let mix = indexmap::IndexMap::from([(
+            "vocals".to_string(),
+            crate::config::TrackMix::new(Some(-0.5), None, true, None),
+        )]);
+        store.set_track_mix("mix-host", mix).await.unwrap();
+
+        let mut reloaded = Player::deserialize(&path).unwrap();
+        let profile = reloaded.active_profile_mut("mix-host").unwrap();
+        let audio = profile.audio_config().unwrap();
+        assert_eq!(audio.track_mix()["vocals"].pan(), Some(-0.5));
+        assert!(audio.track_mix()["vocals"].invert());
+        assert_eq!(audio.track_gains()["vocals"], -3.0);
+    }
+
+    #[tokio::test]
+    async fn set_mixes_persists() {
+        let yaml = r#"
+songs: songs
+profiles:
+  - hostname: mix-host
+    audio:
+      device: mock-device
+      track_mappings:
+        click: [1]
+      mixes:
+        drummer:
+          channels: [2]
+"#;
+        let player = make_player(yaml);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.yaml");
+        std::fs::write(&path, yaml).unwrap();
+
+        let store = ConfigStore::new(player, path.clone());
+        let mixes = indexmap::IndexMap::from([(
+            "drummer".to_string(),
+            crate::config::MonitorMix::new(
+                vec![2],
+                indexmap::IndexMap::from([("click".to_string(), 6.0)]),
+                Vec::new(),
+            ),
+        )]);
+        store.set_mixes("mix-host", mixes).await.unwrap();
+
+        let mut reloaded = Player::deserialize(&path).unwrap();
+        let profile = reloaded.active_profile_mut("mix-host").unwrap();
+        let drummer = &profile.audio_config().unwrap().mixes()["drummer"];
+        assert_eq!(drummer.channels(), &[2]);
+        assert_eq!(drummer.gains()["click"], 6.0);
+    }
+
+    #[tokio::test]
+    async fn set_track_eq_persists() {
+        let yaml = r#"
+songs: songs
+profiles:
+  - hostname: eq-host
+    audio:
+      device: mock-device
+      track_mappings:
+        bgv: [1]
+"#;
+        let player = make_player(yaml);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.yaml");
+        std::fs::write(&path, yaml).unwrap();
+
+        let store = ConfigStore::new(player, path.clone());
+        let band =
+            crate::config::EqBand::new(crate::config::EqBandKind::HighPass, 120.0, 0.0, None);
+        let track_eq = indexmap::IndexMap::from([("bgv".to_string(), vec![band])]);
+        store.set_track_eq("eq-host", track_eq).await.unwrap();
+
+        let mut reloaded = Player::deserialize(&path).unwrap();
+        let profile = reloaded.active_profile_mut("eq-host").unwrap();
+        assert_eq!(
+            profile.audio_config().unwrap().track_eq()["bgv"],
+            vec![band]
+        );
+    }
+
     #[test]
     fn sha256_checksum_deterministic() {
         let yaml = "songs: songs\nprofiles:\n  - audio:\n      device: test\n";
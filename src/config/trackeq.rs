@@ -0,0 +1,238 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+use serde::{Deserialize, Serialize};
+
+/// The shape of one EQ band.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EqBandKind {
+    /// 12 dB/octave low cut below the frequency. Ignores gain.
+    HighPass,
+    /// Boosts or cuts everything below the frequency.
+    LowShelf,
+    /// Boosts or cuts everything above the frequency.
+    HighShelf,
+    /// Boosts or cuts a bell around the frequency, `q` wide.
+    Peak,
+}
+
+impl EqBandKind {
+    /// The Q a band of this kind uses when none is configured.
+    pub fn default_q(self) -> f32 {
+        match self {
+            EqBandKind::Peak => 1.0,
+            _ => std::f32::consts::FRAC_1_SQRT_2,
+        }
+    }
+
+    /// The name used in config files and over the wire.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EqBandKind::HighPass => "high_pass",
+            EqBandKind::LowShelf => "low_shelf",
+            EqBandKind::HighShelf => "high_shelf",
+            EqBandKind::Peak => "peak",
+        }
+    }
+}
+
+/// One band of a track's EQ chain.
+///
+/// ```yaml
+/// track_eq:
+///   backing-vocals:
+///     - type: high_pass
+///       freq: 120
+///     - type: peak
+///       freq: 3000
+///       gain: -3.0
+///       q: 1.4
+///   click:
+///     - type: high_shelf
+///       freq: 6000
+///       gain: -6.0
+/// ```
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq)]
+pub struct EqBand {
+    /// The band's shape.
+    #[serde(rename = "type")]
+    kind: EqBandKind,
+
+    /// Corner or centre frequency in Hz.
+    freq: f32,
+
+    /// Boost or cut in dB. Unused by high-pass bands.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    gain: f32,
+
+    /// Bandwidth (peak) or slope (shelves, high-pass). Defaults per kind.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    q: Option<f32>,
+}
+
+fn is_zero(value: &f32) -> bool {
+    *value == 0.0
+}
+
+/// The range band frequencies are clamped to, in Hz.
+pub const FREQ_RANGE: std::ops::RangeInclusive<f32> = 20.0..=20000.0;
+
+/// The range band gains are clamped to, in dB.
+pub const EQ_GAIN_RANGE: std::ops::RangeInclusive<f32> = -24.0..=24.0;
+
+/// The range band Qs are clamped to.
+pub const Q_RANGE: std::ops::RangeInclusive<f32> = 0.1..=10.0;
+
+/// The most bands a single track's chain may have.
+pub const MAX_BANDS: usize = 8;
+
+impl EqBand {
+    /// Creates an EQ band.
+    pub fn new(kind: EqBandKind, freq: f32, gain: f32, q: Option<f32>) -> Self {
+        EqBand {
+            kind,
+            freq,
+            gain,
+            q,
+        }
+    }
+
+    /// Returns the band's shape.
+    pub fn kind(&self) -> EqBandKind {
+        self.kind
+    }
+
+    /// Returns the frequency in Hz.
+    pub fn freq(&self) -> f32 {
+        self.freq
+    }
+
+    /// Returns the gain in dB.
+    pub fn gain(&self) -> f32 {
+        self.gain
+    }
+
+    /// Returns the Q, falling back to the kind's default.
+    pub fn q(&self) -> f32 {
+        self.q.unwrap_or_else(|| self.kind.default_q())
+    }
+
+    /// Validates a track's EQ chain.
+    pub fn validate_chain(track: &str, bands: &[EqBand], errors: &mut Vec<String>) {
+        if bands.len() > MAX_BANDS {
+            errors.push(format!(
+                "track_eq '{}': {} bands, at most {} are allowed",
+                track,
+                bands.len(),
+                MAX_BANDS
+            ));
+        }
+        for (i, band) in bands.iter().enumerate() {
+            let number = i + 1;
+            if !FREQ_RANGE.contains(&band.freq) {
+                errors.push(format!(
+                    "track_eq '{}' band {}: freq {} Hz out of range [20, 20000]",
+                    track, number, band.freq
+                ));
+            }
+            if !EQ_GAIN_RANGE.contains(&band.gain) {
+                errors.push(format!(
+                    "track_eq '{}' band {}: gain {} dB out of range [-24, 24]",
+                    track, number, band.gain
+                ));
+            }
+            if let Some(q) = band.q {
+                if !Q_RANGE.contains(&q) {
+                    errors.push(format!(
+                        "track_eq '{}' band {}: q {} out of range [0.1, 10]",
+                        track, number, q
+                    ));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::{Config, File, FileFormat};
+
+    fn parse(yaml: &str) -> Vec<EqBand> {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            bands: Vec<EqBand>,
+        }
+        Config::builder()
+            .add_source(File::from_str(yaml, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize::<Wrapper>()
+            .unwrap()
+            .bands
+    }
+
+    #[test]
+    fn deserialize_chain() {
+        let bands = parse(
+            "bands:\n  - type: high_pass\n    freq: 120\n  - type: peak\n    freq: 3000\n    gain: -3.0\n    q: 1.4\n",
+        );
+        assert_eq!(bands.len(), 2);
+        assert_eq!(bands[0].kind(), EqBandKind::HighPass);
+        assert_eq!(bands[0].freq(), 120.0);
+        assert_eq!(bands[0].gain(), 0.0);
+        assert_eq!(bands[0].q(), std::f32::consts::FRAC_1_SQRT_2);
+        assert_eq!(bands[1].kind(), EqBandKind::Peak);
+        assert_eq!(bands[1].gain(), -3.0);
+        assert_eq!(bands[1].q(), 1.4);
+    }
+
+    #[test]
+    fn serializes_only_what_is_set() {
+        let band = EqBand::new(EqBandKind::HighPass, 80.0, 0.0, None);
+        let yaml = crate::util::to_yaml_string(&band).unwrap();
+        assert!(yaml.contains("high_pass"), "{yaml}");
+        assert!(!yaml.contains("gain"), "{yaml}");
+        assert!(!yaml.lines().any(|l| l.starts_with("q:")), "{yaml}");
+    }
+
+    #[test]
+    fn validate_ranges() {
+        let mut errors = Vec::new();
+        EqBand::validate_chain(
+            "ok",
+            &[
+                EqBand::new(EqBandKind::HighPass, 20.0, 0.0, Some(0.5)),
+                EqBand::new(EqBandKind::LowShelf, 200.0, 24.0, None),
+            ],
+            &mut errors,
+        );
+        assert!(errors.is_empty(), "{errors:?}");
+
+        EqBand::validate_chain(
+            "bad",
+            &[
+                EqBand::new(EqBandKind::Peak, 10.0, 30.0, Some(20.0)),
+                EqBand::new(EqBandKind::Peak, f32::NAN, 0.0, None),
+            ],
+            &mut errors,
+        );
+        assert_eq!(errors.len(), 4, "{errors:?}");
+
+        errors.clear();
+        let band = EqBand::new(EqBandKind::Peak, 1000.0, 0.0, None);
+        EqBand::validate_chain("long", &[band; MAX_BANDS + 1], &mut errors);
+        assert_eq!(errors.len(), 1, "{errors:?}");
+    }
+}
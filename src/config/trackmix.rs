@@ -0,0 +1,187 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+use serde::{Deserialize, Serialize};
+
+/// How a panned track's level is split across a stereo output pair.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PanLaw {
+    /// Unity on both sides at centre; panning only turns the far side down.
+    /// Matches an unpanned track's level, so this is the default.
+    #[default]
+    Balance,
+    /// -3 dB on each side at centre, constant loudness across the sweep.
+    ConstantPower,
+    /// -6 dB on each side at centre, the two sides always summing to unity.
+    Linear,
+}
+
+/// Pan, polarity and stereo width for one output track.
+///
+/// ```yaml
+/// track_mix:
+///   vocals:
+///     pan: -0.25            # -1 (left) to 1 (right), for a track mapped to two channels
+///     pan_law: constant_power
+///   bass-di:
+///     invert: true          # flip polarity
+///   keys:
+///     width: 0.5            # 0 (mono) to 2 (extra wide), for a stereo track
+/// ```
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq)]
+pub struct TrackMix {
+    /// Position within a stereo output pair, from -1 (left) to 1 (right).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pan: Option<f32>,
+
+    /// Pan law used when panning.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pan_law: Option<PanLaw>,
+
+    /// Whether the track's polarity is inverted.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    invert: bool,
+
+    /// Stereo width of a track that carries two channels, from 0 (mono) to 2.
+    /// Unset leaves both channels feeding both outputs, as without a mix.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    width: Option<f32>,
+}
+
+/// The range pan positions are clamped to.
+pub const PAN_RANGE: std::ops::RangeInclusive<f32> = -1.0..=1.0;
+
+/// The range stereo widths are clamped to.
+pub const WIDTH_RANGE: std::ops::RangeInclusive<f32> = 0.0..=2.0;
+
+impl TrackMix {
+    /// Creates a track mix.
+    pub fn new(
+        pan: Option<f32>,
+        pan_law: Option<PanLaw>,
+        invert: bool,
+        width: Option<f32>,
+    ) -> Self {
+        TrackMix {
+            pan,
+            pan_law,
+            invert,
+            width,
+        }
+    }
+
+    /// Returns the pan position, if set.
+    pub fn pan(&self) -> Option<f32> {
+        self.pan
+    }
+
+    /// Returns the pan law (default: balance).
+    pub fn pan_law(&self) -> PanLaw {
+        self.pan_law.unwrap_or_default()
+    }
+
+    /// Returns whether the polarity is inverted.
+    pub fn invert(&self) -> bool {
+        self.invert
+    }
+
+    /// Returns the stereo width, if set.
+    pub fn width(&self) -> Option<f32> {
+        self.width
+    }
+
+    /// Whether this mix leaves the track exactly as it would be without one,
+    /// so it can be dropped when saved.
+    pub fn is_default(&self) -> bool {
+        self == &TrackMix::default()
+    }
+
+    /// Validates the mix for the named track.
+    pub fn validate(&self, track: &str, errors: &mut Vec<String>) {
+        if let Some(pan) = self.pan {
+            if !PAN_RANGE.contains(&pan) {
+                errors.push(format!(
+                    "track_mix '{}': pan {} out of range [-1, 1]",
+                    track, pan
+                ));
+            }
+        }
+        if let Some(width) = self.width {
+            if !WIDTH_RANGE.contains(&width) {
+                errors.push(format!(
+                    "track_mix '{}': width {} out of range [0, 2]",
+                    track, width
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::{Config, File, FileFormat};
+
+    fn parse(yaml: &str) -> TrackMix {
+        Config::builder()
+            .add_source(File::from_str(yaml, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap()
+    }
+
+    #[test]
+    fn deserialize_full() {
+        let mix = parse("pan: -0.5\npan_law: constant_power\ninvert: true\nwidth: 1.5\n");
+        assert_eq!(mix.pan(), Some(-0.5));
+        assert_eq!(mix.pan_law(), PanLaw::ConstantPower);
+        assert!(mix.invert());
+        assert_eq!(mix.width(), Some(1.5));
+    }
+
+    #[test]
+    fn defaults() {
+        let mix = parse("{}");
+        assert_eq!(mix.pan(), None);
+        assert_eq!(mix.pan_law(), PanLaw::Balance);
+        assert!(!mix.invert());
+        assert_eq!(mix.width(), None);
+        assert!(mix.is_default());
+    }
+
+    #[test]
+    fn serializes_only_what_is_set() {
+        let mix = TrackMix::new(Some(0.25), None, false, None);
+        let yaml = crate::util::to_yaml_string(&mix).unwrap();
+        assert!(yaml.contains("pan: 0.25"), "{yaml}");
+        assert!(!yaml.contains("invert"), "{yaml}");
+        assert!(!yaml.contains("width"), "{yaml}");
+    }
+
+    #[test]
+    fn validate_ranges() {
+        let mut errors = Vec::new();
+        TrackMix::new(Some(-1.0), None, true, Some(2.0)).validate("ok", &mut errors);
+        assert!(errors.is_empty(), "{errors:?}");
+        TrackMix::new(Some(1.5), None, false, Some(-0.1)).validate("bad", &mut errors);
+        assert_eq!(errors.len(), 2, "{errors:?}");
+        assert!(errors[0].contains("pan"));
+        assert!(errors[1].contains("width"));
+        // NaN is out of every range.
+        errors.clear();
+        TrackMix::new(Some(f32::NAN), None, false, None).validate("nan", &mut errors);
+        assert_eq!(errors.len(), 1);
+    }
+}
@@ -14,11 +14,16 @@
 
 //! Trigger configuration for audio and MIDI trigger inputs.
 //!
-//! Supports two input kinds:
+//! Supports three input kinds:
 //! - `kind: audio` — piezo drum triggers via audio input channels
 //! - `kind: midi` — MIDI event triggers (replaces legacy `sample_triggers`)
+//! - `kind: ltc` — linear timecode read off an audio input channel, to chase
+//!
+//! A `record` section captures input channels to disk during playback.
 
+use std::error::Error;
 use std::str::FromStr;
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 use tracing::warn;
@@ -50,6 +55,10 @@ fn default_noise_floor_decay_ms() -> u32 {
     200
 }
 
+fn default_record_bits_per_sample() -> u16 {
+    24
+}
+
 /// Configuration for the trigger system.
 #[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct TriggerConfig {
@@ -73,6 +82,8 @@ pub struct TriggerConfig {
     /// Threshold multiplier during crosstalk suppression window.
     /// Both `crosstalk_window_ms` and `crosstalk_threshold` must be set to enable suppression.
     crosstalk_threshold: Option<f32>,
+    /// Records input channels to disk while songs play.
+    record: Option<RecordConfig>,
 }
 
 impl TriggerConfig {
@@ -125,11 +136,46 @@ impl TriggerConfig {
         self.crosstalk_threshold
     }
 
-    /// Returns whether any audio inputs are configured.
+    /// Returns the input recording config, if configured.
+    pub fn record(&self) -> Option<&RecordConfig> {
+        self.record.as_ref()
+    }
+
+    /// Returns the 1-indexed input channels to record: those the record
+    /// config names, or else every channel an audio or timecode input reads.
+    pub fn recorded_channels(&self) -> Vec<u16> {
+        if let Some(channels) = self.record.as_ref().and_then(|r| r.channels()) {
+            return channels.to_vec();
+        }
+        let mut channels: Vec<u16> = self
+            .inputs
+            .iter()
+            .filter_map(|i| match i {
+                TriggerInput::Audio(audio) => Some(audio.channel()),
+                TriggerInput::Ltc(ltc) => Some(ltc.channel()),
+                TriggerInput::Midi(_) => None,
+            })
+            .collect();
+        channels.sort_unstable();
+        channels.dedup();
+        channels
+    }
+
+    /// Returns whether any inputs read from the audio device are configured:
+    /// audio triggers or a linear timecode reader.
     pub fn has_audio_inputs(&self) -> bool {
         self.inputs
             .iter()
-            .any(|i| matches!(i, TriggerInput::Audio(_)))
+            .any(|i| matches!(i, TriggerInput::Audio(_) | TriggerInput::Ltc(_)))
+    }
+
+    /// Returns the linear timecode input, if configured. Only the first is
+    /// read: there is one transport to chase.
+    pub fn ltc_input(&self) -> Option<&LtcTriggerInput> {
+        self.inputs.iter().find_map(|i| match i {
+            TriggerInput::Ltc(ltc) => Some(ltc),
+            _ => None,
+        })
     }
 
     /// Extracts MIDI inputs as `SampleTrigger` entries for the sample engine.
@@ -162,6 +208,7 @@ impl TriggerConfig {
             inputs,
             crosstalk_window_ms: None,
             crosstalk_threshold: None,
+            record: None,
         }
     }
 }
@@ -174,6 +221,8 @@ pub enum TriggerInput {
     Audio(AudioTriggerInput),
     /// MIDI event trigger input.
     Midi(MidiTriggerInput),
+    /// Linear timecode input that playback chases.
+    Ltc(LtcTriggerInput),
 }
 
 /// What action an input channel performs when triggered.
@@ -418,6 +467,118 @@ impl MidiTriggerInput {
     }
 }
 
+/// Configuration for an audio input channel carrying linear timecode from a
+/// house playback system. Playback starts, stops and relocates to follow it,
+/// and the timecode hour selects the song whose `mtc_start` has that hour.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct LtcTriggerInput {
+    /// 1-indexed input channel number.
+    channel: u16,
+    /// How far playback may drift from the incoming timecode before it seeks
+    /// back into line. Defaults to 100ms.
+    resync_threshold: Option<String>,
+}
+
+impl LtcTriggerInput {
+    /// Creates a new LtcTriggerInput.
+    pub fn new(channel: u16, resync_threshold: Option<String>) -> Self {
+        Self {
+            channel,
+            resync_threshold,
+        }
+    }
+
+    /// Returns the 1-indexed channel number.
+    pub fn channel(&self) -> u16 {
+        self.channel
+    }
+
+    /// Returns the resync threshold.
+    pub fn resync_threshold(&self) -> Result<Duration, Box<dyn Error>> {
+        super::parse_playback_delay(
+            &self.resync_threshold,
+            super::midi::DEFAULT_CHASE_RESYNC_THRESHOLD,
+        )
+    }
+}
+
+/// The file format input recordings are written in.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordFormat {
+    /// Uncompressed PCM WAV (default).
+    #[default]
+    Wav,
+    /// Lossless compressed FLAC.
+    Flac,
+}
+
+impl RecordFormat {
+    /// The file extension for this format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            RecordFormat::Wav => "wav",
+            RecordFormat::Flac => "flac",
+        }
+    }
+}
+
+/// Configuration for recording input channels to disk during playback.
+///
+/// Each take writes one mono file per channel, named after the song, the take
+/// and the channel.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct RecordConfig {
+    /// The directory recordings are written to. Created if missing.
+    directory: String,
+    /// 1-indexed input channels to record. Defaults to every channel an
+    /// audio or timecode input reads.
+    channels: Option<Vec<u16>>,
+    /// The file format. Defaults to WAV.
+    #[serde(default)]
+    format: RecordFormat,
+    /// Bits per sample written (16 or 24). Defaults to 24.
+    #[serde(default = "default_record_bits_per_sample")]
+    bits_per_sample: u16,
+}
+
+impl RecordConfig {
+    /// Creates a new RecordConfig.
+    pub fn new(
+        directory: &str,
+        channels: Option<Vec<u16>>,
+        format: RecordFormat,
+        bits_per_sample: u16,
+    ) -> Self {
+        Self {
+            directory: directory.to_string(),
+            channels,
+            format,
+            bits_per_sample,
+        }
+    }
+
+    /// Returns the directory recordings are written to.
+    pub fn directory(&self) -> &str {
+        &self.directory
+    }
+
+    /// Returns the explicitly configured channels, if any.
+    pub fn channels(&self) -> Option<&[u16]> {
+        self.channels.as_deref()
+    }
+
+    /// Returns the file format.
+    pub fn format(&self) -> RecordFormat {
+        self.format
+    }
+
+    /// Returns the bits per sample written.
+    pub fn bits_per_sample(&self) -> u16 {
+        self.bits_per_sample
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use config::{Config, File, FileFormat};
@@ -728,6 +889,39 @@ mod tests {
         assert_eq!(config.midi_triggers()[0].sample(), "snare");
     }
 
+    #[test]
+    fn test_ltc_input_deserialize() {
+        let yaml = r#"
+            device: "UltraLite-mk5"
+            inputs:
+              - kind: audio
+                channel: 1
+                sample: "kick"
+              - kind: ltc
+                channel: 8
+                resync_threshold: 40ms
+        "#;
+
+        let config: TriggerConfig = Config::builder()
+            .add_source(File::from_str(yaml, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+
+        assert!(config.has_audio_inputs());
+        assert!(config.midi_triggers().is_empty());
+        let ltc = config.ltc_input().expect("ltc input");
+        assert_eq!(ltc.channel(), 8);
+        assert_eq!(ltc.resync_threshold().unwrap(), Duration::from_millis(40));
+
+        let defaults = LtcTriggerInput::new(2, None);
+        assert_eq!(
+            defaults.resync_threshold().unwrap(),
+            Duration::from_millis(100)
+        );
+    }
+
     #[test]
     fn test_device_optional_for_midi_only() {
         let yaml = r#"
@@ -850,4 +1044,69 @@ mod tests {
         assert_eq!(input.noise_floor_decay_ms(), 200);
         assert_eq!(input.noise_floor_sensitivity(), None);
     }
+
+    #[test]
+    fn record_config_deserialize() {
+        let yaml = r#"
+            device: "UltraLite-mk5"
+            inputs:
+              - kind: audio
+                channel: 1
+                sample: "kick"
+            record:
+              directory: /var/lib/mtrack/recordings
+              channels: [1, 5, 6]
+              format: flac
+              bits_per_sample: 16
+        "#;
+
+        let config: TriggerConfig = Config::builder()
+            .add_source(File::from_str(yaml, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+
+        let record = config.record().expect("record config");
+        assert_eq!(record.directory(), "/var/lib/mtrack/recordings");
+        assert_eq!(record.format(), RecordFormat::Flac);
+        assert_eq!(record.bits_per_sample(), 16);
+        assert_eq!(config.recorded_channels(), vec![1, 5, 6]);
+    }
+
+    #[test]
+    fn record_config_defaults_to_input_channels() {
+        let yaml = r#"
+            device: "UltraLite-mk5"
+            inputs:
+              - kind: audio
+                channel: 3
+                sample: "snare"
+              - kind: audio
+                channel: 1
+                sample: "kick"
+              - kind: ltc
+                channel: 8
+              - kind: midi
+                event:
+                  type: note_on
+                  channel: 10
+                  key: 60
+                sample: kick
+            record:
+              directory: recordings
+        "#;
+
+        let config: TriggerConfig = Config::builder()
+            .add_source(File::from_str(yaml, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+
+        let record = config.record().expect("record config");
+        assert_eq!(record.format(), RecordFormat::Wav);
+        assert_eq!(record.bits_per_sample(), 24);
+        assert_eq!(config.recorded_channels(), vec![1, 3, 8]);
+    }
 }
@@ -11,8 +11,9 @@
 // You should have received a copy of the GNU General Public License along with
 // this program. If not, see <https://www.gnu.org/licenses/>.
 //
-use std::{error::Error, io, net::SocketAddr, sync::Arc};
+use std::{error::Error, io, net::SocketAddr, pin::Pin, sync::Arc};
 
+use futures_util::Stream;
 use tokio::task::JoinHandle;
 use tonic::{transport::Server, Request, Response, Status};
 use tracing::{info, span, warn, Level};
@@ -22,17 +23,25 @@ use crate::{
     player::Player,
     proto::player::v1::{
         player_service_server::{PlayerService, PlayerServiceServer},
-        AddProfileRequest, AudioHealth, Cue, GetActiveEffectsRequest, GetActiveEffectsResponse,
-        GetConfigRequest, GetConfigResponse, GetCuesRequest, GetCuesResponse, GetTrackGainsRequest,
-        GetTrackGainsResponse, LoopSectionRequest, LoopSectionResponse, NextRequest, NextResponse,
-        PlayFromRequest, PlayRequest, PlayResponse, PlaySongFromRequest, PreviousRequest,
-        PreviousResponse, RemoveProfileRequest, SectionAckRequest, SectionAckResponse, SeekRequest,
-        SeekResponse, SeekToSectionRequest, SetTrackGainRequest, SetTrackGainResponse,
+        AddProfileRequest, AudioHealth, ChannelMeter, Cue, EqBand, GetActiveEffectsRequest,
+        GetActiveEffectsResponse, GetConfigRequest, GetConfigResponse, GetCuesRequest,
+        GetCuesResponse, GetMonitorMixesRequest, GetMonitorMixesResponse, GetPlaybackRateRequest,
+        GetPlaybackRateResponse, GetRecordArmRequest, GetRecordArmResponse, GetTrackEqRequest,
+        GetTrackEqResponse, GetTrackGainsRequest, GetTrackGainsResponse, LoopSectionRequest,
+        LoopSectionResponse, MeterLevel, MetersUpdate, MonitorMix, NextRequest, NextResponse,
+        PauseRequest, PauseResponse, PlayFromRequest, PlayRequest, PlayResponse,
+        PlaySongFromRequest, PlaybackRate, PreviousRequest, PreviousResponse, RecordArm,
+        RemoveProfileRequest, ResumeRequest, ResumeResponse, SectionAckRequest, SectionAckResponse,
+        SeekRequest, SeekResponse, SeekToSectionRequest, SetMixTrackGainRequest,
+        SetMixTrackGainResponse, SetMixTrackMuteRequest, SetMixTrackMuteResponse,
+        SetPlaybackRateRequest, SetPlaybackRateResponse, SetRecordArmRequest, SetRecordArmResponse,
+        SetTrackEqBandRequest, SetTrackEqBandResponse, SetTrackGainRequest, SetTrackGainResponse,
         SetTrackMuteRequest, SetTrackMuteResponse, StatusRequest, StatusResponse, StopRequest,
         StopResponse, StopSamplesRequest, StopSamplesResponse, StopSectionLoopRequest,
-        StopSectionLoopResponse, SwitchToPlaylistRequest, SwitchToPlaylistResponse, TrackGain,
-        UpdateAudioRequest, UpdateConfigResponse, UpdateControllersRequest, UpdateDmxRequest,
-        UpdateMidiRequest, UpdateProfileRequest, FILE_DESCRIPTOR_SET,
+        StopSectionLoopResponse, SwitchToPlaylistRequest, SwitchToPlaylistResponse, TrackEq,
+        TrackGain, TrackMeter, UpdateAudioRequest, UpdateConfigResponse, UpdateControllersRequest,
+        UpdateDmxRequest, UpdateMidiRequest, UpdateProfileRequest, WatchMetersRequest,
+        FILE_DESCRIPTOR_SET,
     },
 };
 
@@ -370,6 +379,26 @@ impl PlayerService for PlayerServer {
         }
     }
 
+    async fn pause(&self, _: Request<PauseRequest>) -> Result<Response<PauseResponse>, Status> {
+        match self.player.pause().await {
+            Some(song) => Ok(Response::new(PauseResponse {
+                song: Some(song.to_proto()?),
+            })),
+            None => Err(Status::failed_precondition(
+                "song not playing or already paused",
+            )),
+        }
+    }
+
+    async fn resume(&self, _: Request<ResumeRequest>) -> Result<Response<ResumeResponse>, Status> {
+        match self.player.resume().await {
+            Some(song) => Ok(Response::new(ResumeResponse {
+                song: Some(song.to_proto()?),
+            })),
+            None => Err(Status::failed_precondition("song not paused")),
+        }
+    }
+
     async fn switch_to_playlist(
         &self,
         request: Request<SwitchToPlaylistRequest>,
@@ -427,6 +456,7 @@ impl PlayerService for PlayerServer {
                 recoveries: h.recoveries,
                 underruns: h.underruns,
                 last_error: h.last_error,
+                limiter_engagements: h.limiter_engagements,
             });
 
         Ok(Response::new(StatusResponse {
@@ -435,6 +465,8 @@ impl PlayerService for PlayerServer {
             playing,
             elapsed,
             audio_health,
+            paused: self.player.is_paused(),
+            playback_rate: Some(playback_rate(self.player.playback_rate())),
         }))
     }
 
@@ -653,15 +685,7 @@ impl PlayerService for PlayerServer {
         let applied = self
             .player
             .set_track_gain(&req.track, req.gain_db as f32)
-            .map_err(|e| match e {
-                crate::player::TrackGainError::NoAudioProfile => {
-                    Status::failed_precondition(e.to_string())
-                }
-                crate::player::TrackGainError::NonFinite(_)
-                | crate::player::TrackGainError::UnknownTrack(_) => {
-                    Status::invalid_argument(e.to_string())
-                }
-            })?;
+            .map_err(track_gain_status)?;
         Ok(Response::new(SetTrackGainResponse {
             applied_gain_db: applied as f64,
         }))
@@ -675,18 +699,149 @@ impl PlayerService for PlayerServer {
         let muted = self
             .player
             .set_track_mute(&req.track, req.muted)
-            .map_err(|e| match e {
-                crate::player::TrackGainError::NoAudioProfile => {
-                    Status::failed_precondition(e.to_string())
-                }
-                crate::player::TrackGainError::NonFinite(_)
-                | crate::player::TrackGainError::UnknownTrack(_) => {
-                    Status::invalid_argument(e.to_string())
-                }
-            })?;
+            .map_err(track_gain_status)?;
         Ok(Response::new(SetTrackMuteResponse { muted }))
     }
 
+    async fn set_mix_track_gain(
+        &self,
+        request: Request<SetMixTrackGainRequest>,
+    ) -> Result<Response<SetMixTrackGainResponse>, Status> {
+        let req = request.into_inner();
+        let applied = self
+            .player
+            .set_mix_track_gain(&req.mix, &req.track, req.gain_db as f32)
+            .map_err(track_gain_status)?;
+        Ok(Response::new(SetMixTrackGainResponse {
+            applied_gain_db: applied as f64,
+        }))
+    }
+
+    async fn set_mix_track_mute(
+        &self,
+        request: Request<SetMixTrackMuteRequest>,
+    ) -> Result<Response<SetMixTrackMuteResponse>, Status> {
+        let req = request.into_inner();
+        let muted = self
+            .player
+            .set_mix_track_mute(&req.mix, &req.track, req.muted)
+            .map_err(track_gain_status)?;
+        Ok(Response::new(SetMixTrackMuteResponse { muted }))
+    }
+
+    async fn get_monitor_mixes(
+        &self,
+        _: Request<GetMonitorMixesRequest>,
+    ) -> Result<Response<GetMonitorMixesResponse>, Status> {
+        let mixes = self
+            .player
+            .get_monitor_mixes()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|mix| MonitorMix {
+                name: mix.name,
+                channels: mix.channels.into_iter().map(u32::from).collect(),
+                tracks: mix
+                    .tracks
+                    .into_iter()
+                    .map(|(track, gain_db, muted)| TrackGain {
+                        track,
+                        gain_db: gain_db as f64,
+                        muted,
+                    })
+                    .collect(),
+            })
+            .collect();
+        Ok(Response::new(GetMonitorMixesResponse { mixes }))
+    }
+
+    async fn set_track_eq_band(
+        &self,
+        request: Request<SetTrackEqBandRequest>,
+    ) -> Result<Response<SetTrackEqBandResponse>, Status> {
+        let req = request.into_inner();
+        let band = self
+            .player
+            .set_track_eq_band(
+                &req.track,
+                req.band as usize,
+                req.frequency_hz.map(|v| v as f32),
+                req.gain_db.map(|v| v as f32),
+                req.q.map(|v| v as f32),
+            )
+            .map_err(track_gain_status)?;
+        Ok(Response::new(SetTrackEqBandResponse {
+            band: Some(eq_band(&band)),
+        }))
+    }
+
+    async fn get_track_eq(
+        &self,
+        _: Request<GetTrackEqRequest>,
+    ) -> Result<Response<GetTrackEqResponse>, Status> {
+        let tracks = self
+            .player
+            .get_track_eq()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(track, bands)| TrackEq {
+                track,
+                bands: bands.iter().map(eq_band).collect(),
+            })
+            .collect();
+        Ok(Response::new(GetTrackEqResponse { tracks }))
+    }
+
+    async fn set_playback_rate(
+        &self,
+        request: Request<SetPlaybackRateRequest>,
+    ) -> Result<Response<SetPlaybackRateResponse>, Status> {
+        let req = request.into_inner();
+        let current = self.player.playback_rate();
+        let rate = crate::audio::PlaybackRate::new(
+            req.speed.unwrap_or(current.speed),
+            req.semitones.unwrap_or(current.semitones),
+        )
+        .map_err(Status::invalid_argument)?;
+        self.player
+            .set_playback_rate(rate)
+            .await
+            .map_err(|e| Status::failed_precondition(e.to_string()))?;
+        Ok(Response::new(SetPlaybackRateResponse {
+            rate: Some(playback_rate(rate)),
+        }))
+    }
+
+    async fn get_playback_rate(
+        &self,
+        _: Request<GetPlaybackRateRequest>,
+    ) -> Result<Response<GetPlaybackRateResponse>, Status> {
+        Ok(Response::new(GetPlaybackRateResponse {
+            rate: Some(playback_rate(self.player.playback_rate())),
+        }))
+    }
+
+    async fn set_record_arm(
+        &self,
+        request: Request<SetRecordArmRequest>,
+    ) -> Result<Response<SetRecordArmResponse>, Status> {
+        self.player
+            .set_record_armed(request.into_inner().armed)
+            .map_err(|e| Status::failed_precondition(e.to_string()))?;
+        Ok(Response::new(SetRecordArmResponse {
+            record: self.player.record_status().map(record_arm),
+        }))
+    }
+
+    async fn get_record_arm(
+        &self,
+        _: Request<GetRecordArmRequest>,
+    ) -> Result<Response<GetRecordArmResponse>, Status> {
+        Ok(Response::new(GetRecordArmResponse {
+            record: self.player.record_status().map(record_arm),
+        }))
+    }
+
     async fn get_track_gains(
         &self,
         _: Request<GetTrackGainsRequest>,
@@ -710,6 +865,100 @@ impl PlayerService for PlayerServer {
             .collect();
         Ok(Response::new(GetTrackGainsResponse { gains }))
     }
+
+    type WatchMetersStream = Pin<Box<dyn Stream<Item = Result<MetersUpdate, Status>> + Send>>;
+
+    async fn watch_meters(
+        &self,
+        _: Request<WatchMetersRequest>,
+    ) -> Result<Response<Self::WatchMetersStream>, Status> {
+        let state_rx = self
+            .player
+            .state_rx()
+            .ok_or_else(|| Status::unavailable("metering is not available"))?;
+        // Each new reading goes out once; lighting ticks in between don't
+        // repeat it.
+        let stream =
+            futures_util::stream::unfold((state_rx, None), |(mut state_rx, mut last)| async move {
+                loop {
+                    state_rx.changed().await.ok()?;
+                    let snapshot = state_rx.borrow_and_update().clone();
+                    if snapshot.meters.is_some() && snapshot.meters != last {
+                        last = snapshot.meters.clone();
+                        let update = meters_update(last.as_ref()?);
+                        return Some((Ok(update), (state_rx, last)));
+                    }
+                }
+            });
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
+
+/// Maps a track gain error to a gRPC status.
+fn track_gain_status(e: crate::player::TrackGainError) -> Status {
+    match e {
+        crate::player::TrackGainError::NoAudioProfile => Status::failed_precondition(e.to_string()),
+        crate::player::TrackGainError::NonFinite(_)
+        | crate::player::TrackGainError::UnknownTrack(_)
+        | crate::player::TrackGainError::UnknownMix(_)
+        | crate::player::TrackGainError::UnknownEqBand { .. } => {
+            Status::invalid_argument(e.to_string())
+        }
+    }
+}
+
+/// Converts an EQ band to its protobuf form.
+fn eq_band(band: &config::EqBand) -> EqBand {
+    EqBand {
+        r#type: band.kind().as_str().to_string(),
+        frequency_hz: band.freq() as f64,
+        gain_db: band.gain() as f64,
+        q: band.q() as f64,
+    }
+}
+
+/// Converts a playback rate to its protobuf form.
+fn playback_rate(rate: crate::audio::PlaybackRate) -> PlaybackRate {
+    PlaybackRate {
+        speed: rate.speed,
+        semitones: rate.semitones,
+    }
+}
+
+/// Converts the input recorder's state to its protobuf form.
+fn record_arm(status: crate::trigger::RecordStatus) -> RecordArm {
+    RecordArm {
+        armed: status.armed,
+        take: status.take,
+    }
+}
+
+/// Converts a meter reading to its protobuf form.
+fn meters_update(meters: &crate::audio::meters::MeterSnapshot) -> MetersUpdate {
+    let level = |level: &crate::audio::meters::MeterLevel| MeterLevel {
+        peak_db: level.peak_db as f64,
+        rms_db: level.rms_db as f64,
+        clips: level.clips,
+    };
+    MetersUpdate {
+        channels: meters
+            .channels
+            .iter()
+            .enumerate()
+            .map(|(index, l)| ChannelMeter {
+                channel: index as u32 + 1,
+                level: Some(level(l)),
+            })
+            .collect(),
+        tracks: meters
+            .tracks
+            .iter()
+            .map(|(track, l)| TrackMeter {
+                track: track.clone(),
+                level: Some(level(l)),
+            })
+            .collect(),
+    }
 }
 
 #[cfg(test)]
@@ -732,9 +981,10 @@ mod test {
         playlist,
         playlist::Playlist,
         proto::player::v1::{
-            player_service_client::PlayerServiceClient, LoopSectionRequest, NextRequest,
-            PlayRequest, PreviousRequest, StatusRequest, StopRequest, StopSectionLoopRequest,
-            SwitchToPlaylistRequest,
+            player_service_client::PlayerServiceClient, GetPlaybackRateRequest,
+            GetRecordArmRequest, LoopSectionRequest, NextRequest, PauseRequest, PlayRequest,
+            PreviousRequest, ResumeRequest, SetPlaybackRateRequest, SetRecordArmRequest,
+            StatusRequest, StopRequest, StopSectionLoopRequest, SwitchToPlaylistRequest,
         },
         songs,
         testutil::eventually,
@@ -1169,6 +1419,126 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_grpc_record_arm_without_recorder() -> Result<(), Box<dyn Error>> {
+        let (_player, mut client, _device) = setup_grpc().await?;
+
+        let response = client
+            .get_record_arm(GetRecordArmRequest {})
+            .await?
+            .into_inner();
+        assert_eq!(response.record, None);
+
+        let status = client
+            .set_record_arm(SetRecordArmRequest { armed: true })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+        assert!(status.message().contains("not configured"));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_grpc_set_playback_rate() -> Result<(), Box<dyn Error>> {
+        let (player, mut client, _device) = setup_grpc().await?;
+
+        let rate = client
+            .set_playback_rate(SetPlaybackRateRequest {
+                speed: Some(0.8),
+                semitones: None,
+            })
+            .await?
+            .into_inner()
+            .rate
+            .unwrap();
+        assert_eq!((rate.speed, rate.semitones), (0.8, 0.0));
+
+        // Unset fields keep their values.
+        client
+            .set_playback_rate(SetPlaybackRateRequest {
+                speed: None,
+                semitones: Some(-2.0),
+            })
+            .await?;
+        let rate = client
+            .get_playback_rate(GetPlaybackRateRequest {})
+            .await?
+            .into_inner()
+            .rate
+            .unwrap();
+        assert_eq!((rate.speed, rate.semitones), (0.8, -2.0));
+        assert_eq!(
+            player.playback_rate(),
+            crate::audio::PlaybackRate::new(0.8, -2.0)?
+        );
+        let status = client.status(StatusRequest {}).await?.into_inner();
+        assert_eq!(status.playback_rate.map(|r| r.speed), Some(0.8));
+
+        let status = client
+            .set_playback_rate(SetPlaybackRateRequest {
+                speed: Some(3.0),
+                semitones: None,
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert_eq!(player.playback_rate().speed, 0.8);
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_grpc_pause_when_not_playing() -> Result<(), Box<dyn Error>> {
+        let (_player, mut client, _device) = setup_grpc().await?;
+
+        let result = client.pause(PauseRequest {}).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::FailedPrecondition);
+        let result = client.resume(ResumeRequest {}).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::FailedPrecondition);
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_grpc_pause_and_resume() -> Result<(), Box<dyn Error>> {
+        let (_player, mut client, device) = setup_grpc().await?;
+
+        client.play(PlayRequest {}).await?;
+        eventually(|| device.is_playing(), "Song never started playing");
+
+        // Pausing needs the clock running; wait until the player reports a position.
+        let deadline = std::time::Instant::now() + Duration::from_secs(3);
+        while client
+            .status(StatusRequest {})
+            .await?
+            .into_inner()
+            .elapsed
+            .is_none()
+        {
+            assert!(std::time::Instant::now() < deadline, "elapsed never set");
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        let resp = client.pause(PauseRequest {}).await?;
+        assert_eq!(resp.into_inner().song.unwrap().name, "Song 1");
+        let status = client.status(StatusRequest {}).await?.into_inner();
+        assert!(status.playing);
+        assert!(status.paused);
+
+        let result = client.pause(PauseRequest {}).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::FailedPrecondition);
+
+        let resp = client.resume(ResumeRequest {}).await?;
+        assert_eq!(resp.into_inner().song.unwrap().name, "Song 1");
+        let status = client.status(StatusRequest {}).await?.into_inner();
+        assert!(status.playing);
+        assert!(!status.paused);
+
+        client.stop(StopRequest {}).await?;
+        eventually(|| !device.is_playing(), "Song never stopped");
+        Ok(())
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_grpc_stop_samples() -> Result<(), Box<dyn Error>> {
         use crate::proto::player::v1::StopSamplesRequest;
@@ -1439,6 +1809,58 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_grpc_watch_meters() -> Result<(), Box<dyn Error>> {
+        use crate::audio::meters::{MeterLevel, MeterSnapshot};
+        use crate::proto::player::v1::WatchMetersRequest;
+        use crate::state::StateSnapshot;
+
+        let (player, mut client, _device) = setup_grpc().await?;
+
+        // Without a state channel there is nothing to watch.
+        let err = client
+            .watch_meters(WatchMetersRequest {})
+            .await
+            .expect_err("no state channel");
+        assert_eq!(err.code(), tonic::Code::Unavailable);
+
+        let (state_tx, _state_rx) = tokio::sync::watch::channel(Arc::new(StateSnapshot::default()));
+        player.set_state_tx(state_tx);
+        let mut stream = client
+            .watch_meters(WatchMetersRequest {})
+            .await?
+            .into_inner();
+
+        let level = MeterLevel {
+            peak_db: -6.0,
+            rms_db: -12.0,
+            clips: 2,
+        };
+        let tx = player.state_tx().expect("state channel");
+        // A snapshot without meters is skipped.
+        tx.send_replace(Arc::new(StateSnapshot::default()));
+        tx.send_replace(Arc::new(StateSnapshot {
+            meters: Some(MeterSnapshot {
+                channels: vec![level],
+                tracks: vec![("click".to_string(), level)],
+            }),
+            ..Default::default()
+        }));
+
+        let update = tokio::time::timeout(Duration::from_secs(2), stream.message())
+            .await?
+            .expect("stream open")
+            .expect("an update");
+        assert_eq!(update.channels.len(), 1);
+        assert_eq!(update.channels[0].channel, 1);
+        let channel = update.channels[0].level.expect("level");
+        assert_eq!(channel.peak_db, -6.0);
+        assert_eq!(channel.rms_db, -12.0);
+        assert_eq!(channel.clips, 2);
+        assert_eq!(update.tracks[0].track, "click");
+        Ok(())
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_grpc_stop_section_loop_succeeds() -> Result<(), Box<dyn Error>> {
         let (player, mut client, _device) = setup_grpc().await?;
@@ -740,6 +740,28 @@ async fn mcp_gdtf_import_flow() -> Result<(), Box<dyn Error>> {
         "{warnings:?}"
     );
 
+    // --- import_mvr seeds a venue and returns the same report as the CLI
+    // and the web API.
+    std::fs::write(
+        fixture.root.join("incoming/club.mvr"),
+        crate::lighting::mvr::synthetic_mvr(),
+    )?;
+    let report = tool_json(
+        &call_tool(
+            &client,
+            &url,
+            &session,
+            13,
+            "import_mvr",
+            json!({"path": "incoming/club.mvr", "origin_fixture": "Wash 1"}),
+        )
+        .await,
+    );
+    assert_eq!(report["venue_file"], "lighting/venues/club.venue");
+    assert_eq!(report["fixtures"][0]["position"], json!([0.0, 0.0, 6.0]));
+    assert_eq!(report["todos"][0]["fixture"], "Spare");
+    assert!(fixture.root.join("lighting/venues/club.venue").exists());
+
     Ok(())
 }
 
@@ -3230,6 +3252,60 @@ async fn mcp_playback_controls_drive_audio() -> Result<(), Box<dyn Error>> {
     );
     assert!(status["current_song"].is_object());
 
+    // pause + resume
+    let pause_resp = tool_json(&call_tool(&client, &url, &session, 210, "pause", json!({})).await);
+    assert!(
+        pause_resp["paused"].is_object(),
+        "pause didn't return a song: {pause_resp}"
+    );
+    let status = tool_json(&call_tool(&client, &url, &session, 211, "status", json!({})).await);
+    assert_eq!(status["paused"], true, "status didn't see pause: {status}");
+    let resume_resp =
+        tool_json(&call_tool(&client, &url, &session, 212, "resume", json!({})).await);
+    assert!(
+        resume_resp["resumed"].is_object(),
+        "resume didn't return a song: {resume_resp}"
+    );
+    let status = tool_json(&call_tool(&client, &url, &session, 213, "status", json!({})).await);
+    assert_eq!(status["paused"], false);
+
+    // rehearsal speed
+    let rate_resp = tool_json(
+        &call_tool(
+            &client,
+            &url,
+            &session,
+            214,
+            "set_playback_rate",
+            json!({"speed": 0.75}),
+        )
+        .await,
+    );
+    assert_eq!(rate_resp["playback_rate"]["speed"], 0.75);
+    assert_eq!(rate_resp["playback_rate"]["semitones"], 0.0);
+    let status = tool_json(&call_tool(&client, &url, &session, 215, "status", json!({})).await);
+    assert_eq!(status["playback_rate"]["speed"], 0.75);
+    // The test profile doesn't record.
+    assert!(status["record"].is_null());
+    let record_resp = call_tool(
+        &client,
+        &url,
+        &session,
+        216,
+        "set_record_arm",
+        json!({"armed": true}),
+    )
+    .await;
+    assert!(
+        record_resp.get("error").is_some()
+            || record_resp["result"]["isError"].as_bool() == Some(true),
+        "arming without a recorder should fail: {record_resp}"
+    );
+    crate::testutil::eventually(
+        || device.is_playing(),
+        "song never restarted at the new rate",
+    );
+
     // stop
     let stop_resp = tool_json(&call_tool(&client, &url, &session, 202, "stop", json!({})).await);
     assert!(
@@ -159,6 +159,25 @@ pub struct SeekToSectionArgs {
     pub section_name: String,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SetPlaybackRateArgs {
+    /// Multiple of normal speed, 0.5 to 1.5, without changing pitch. Omit to
+    /// keep the current speed.
+    #[serde(default)]
+    pub speed: Option<f64>,
+    /// Transposition in semitones, -12 to 12, without changing speed. Omit to
+    /// keep the current transposition.
+    #[serde(default)]
+    pub semitones: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SetRecordArmArgs {
+    /// True to record every song from the next one that starts; false to
+    /// stop recording and finish the take in progress.
+    pub armed: bool,
+}
+
 /// Schema helper for `body: serde_json::Value` fields in update-tool args.
 ///
 /// Without an explicit schema, schemars emits `true` (any value) for
@@ -335,6 +354,19 @@ pub struct ImportGdtfArgs {
     pub name: Option<String>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ImportMvrArgs {
+    /// Path to a .mvr file, relative to the project directory.
+    pub path: String,
+    /// Name for the seeded venue; defaults to the MVR's file name.
+    pub name: Option<String>,
+    /// Scene fixture whose spot on the deck becomes the stage origin
+    /// (downstage-center).
+    pub origin_fixture: Option<String>,
+    /// Scene point, in meters, that becomes the stage origin.
+    pub origin: Option<[f64; 3]>,
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct WriteSongLightingArgs {
     /// Song name as listed by `list_songs`.
@@ -656,6 +688,7 @@ impl McpServer {
 
     #[tool(description = "Seek within the current song. While playing, playback \
         restarts at the position with all subsystems re-synchronized; while \
+        paused, it restarts there and stays paused until `resume`; while \
         stopped, the position is used by the next play.")]
     async fn seek(
         &self,
@@ -680,6 +713,50 @@ impl McpServer {
         Ok(ok_json(json!({ "section": args.section_name })))
     }
 
+    #[tool(
+        description = "Set the rehearsal speed and transposition songs play at: \
+        `speed` slows down or speeds up without changing pitch, `semitones` \
+        transposes without changing speed. Lighting, MIDI and timecode follow \
+        the speed. A playing song restarts where it is at the new rate. Not \
+        persisted; set speed 1 and semitones 0 to return to normal."
+    )]
+    async fn set_playback_rate(
+        &self,
+        Parameters(args): Parameters<SetPlaybackRateArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        let current = self.player.playback_rate();
+        let rate = crate::audio::PlaybackRate::new(
+            args.speed.unwrap_or(current.speed),
+            args.semitones.unwrap_or(current.semitones),
+        )
+        .map_err(|e| McpError::invalid_params(e, None))?;
+        self.player
+            .set_playback_rate(rate)
+            .await
+            .map_err(internal_err)?;
+        Ok(ok_json(
+            json!({ "playback_rate": playback_rate_json(rate) }),
+        ))
+    }
+
+    #[tool(
+        description = "Arm or disarm recording of the trigger inputs. An armed \
+        recorder writes each song that starts to disk as a new take, one file \
+        per input channel; disarming finishes the take in progress. Fails if \
+        the profile doesn't configure recording."
+    )]
+    async fn set_record_arm(
+        &self,
+        Parameters(args): Parameters<SetRecordArmArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        self.player
+            .set_record_armed(args.armed)
+            .map_err(internal_err)?;
+        Ok(ok_json(
+            json!({ "record": self.player.record_status().map(record_json) }),
+        ))
+    }
+
     #[tool(description = "Stop playback of the currently playing song.")]
     async fn stop(&self) -> Result<CallToolResult, McpError> {
         let song = self.player.stop().await;
@@ -688,6 +765,24 @@ impl McpServer {
         })))
     }
 
+    #[tool(description = "Pause the currently playing song in place. Audio, \
+        MIDI, and the lighting timeline all hold their position; `resume` \
+        continues from exactly where they stopped.")]
+    async fn pause(&self) -> Result<CallToolResult, McpError> {
+        let song = self.player.pause().await;
+        Ok(ok_json(json!({
+            "paused": song.as_ref().map(|s| song_summary(s)),
+        })))
+    }
+
+    #[tool(description = "Resume a paused song from the position it was paused at.")]
+    async fn resume(&self) -> Result<CallToolResult, McpError> {
+        let song = self.player.resume().await;
+        Ok(ok_json(json!({
+            "resumed": song.as_ref().map(|s| song_summary(s)),
+        })))
+    }
+
     #[tool(
         description = "Advance the active playlist to the next song. Returns the \
         new current song."
@@ -1229,7 +1324,7 @@ impl McpServer {
     ) -> Result<Vec<Value>, McpError> {
         // Resolved before the song is looked up: `LintContext` borrows the
         // song, and a borrow of it must not be held across an await.
-        let group_fixture_counts = match self
+        let (group_fixture_counts, group_movement_limits) = match self
             .player
             .dmx_engine()
             .and_then(|dmx| dmx.broadcast_handles().lighting_system)
@@ -1241,21 +1336,36 @@ impl McpServer {
                 tokio::task::spawn_blocking(move || {
                     let mut guard = system.lock();
                     let mut counts = std::collections::HashMap::new();
+                    let mut movement = std::collections::HashMap::new();
                     // Only when a venue is actually loaded. Without one every
                     // group resolves to nothing, and reporting them all as empty
                     // would be noise rather than a finding.
                     if guard.get_current_venue().is_some() {
+                        let limits: std::collections::HashMap<_, _> = guard
+                            .get_current_venue_fixtures()
+                            .unwrap_or_default()
+                            .into_iter()
+                            .filter(|f| !f.movement.is_empty())
+                            .map(|f| (f.name, f.movement))
+                            .collect();
                         for name in names {
-                            let count = guard.resolve_logical_group_graceful(&name).len();
-                            counts.insert(name, count);
+                            let members = guard.resolve_logical_group_graceful(&name);
+                            let limited: Vec<_> = members
+                                .iter()
+                                .filter_map(|m| limits.get(m).map(|l| (m.clone(), *l)))
+                                .collect();
+                            counts.insert(name.clone(), members.len());
+                            if !limited.is_empty() {
+                                movement.insert(name, limited);
+                            }
                         }
                     }
-                    counts
+                    (counts, movement)
                 })
                 .await
                 .map_err(|e| McpError::internal_error(e.to_string(), None))?
             }
-            None => std::collections::HashMap::new(),
+            None => Default::default(),
         };
 
         let song = match song {
@@ -1272,6 +1382,7 @@ impl McpServer {
             song_duration: song.as_ref().map(|s| s.duration()),
             beat_grid: song.as_ref().and_then(|s| s.beat_grid()),
             group_fixture_counts,
+            group_movement_limits,
         };
 
         Ok(crate::lighting::lint::lint_shows(shows, &ctx)
@@ -1411,23 +1522,28 @@ impl McpServer {
         let evaluations = tokio::task::spawn_blocking(move || {
             // Resolve every group the show mentions once, up front, so the lock
             // is held for a short bounded step rather than across evaluation.
-            let (fixtures, group_map) = match &lighting_system {
+            let (fixtures, focus_points, group_map) = match &lighting_system {
                 Some(system) => {
                     let mut guard = system.lock();
                     let fixtures = guard.get_current_venue_fixtures().unwrap_or_default();
+                    let focus_points = guard
+                        .get_current_venue()
+                        .map(|venue| venue.focus_points().clone())
+                        .unwrap_or_default();
                     let mut group_map: HashMap<String, Vec<String>> = HashMap::new();
                     for name in group_names(&shows) {
                         let resolved = guard.resolve_logical_group_graceful(&name);
                         group_map.insert(name, resolved);
                     }
-                    (fixtures, group_map)
+                    (fixtures, focus_points, group_map)
                 }
-                None => (Vec::new(), HashMap::new()),
+                None => (Vec::new(), HashMap::new(), HashMap::new()),
             };
 
             crate::lighting::evaluate::evaluate_show(
                 shows,
                 &fixtures,
+                &focus_points,
                 fallback_tempo.as_ref(),
                 &times,
                 |mut effect| {
@@ -1816,6 +1932,46 @@ impl McpServer {
         })?))
     }
 
+    #[tool(description = "Import an MVR venue: one GDTF-referential fixture \
+        type per referenced GDTF mode, and a seeded lighting/venues/*.venue \
+        file patching every fixture at its universe, address and stage \
+        position. Sloppy mode names fall back from exact to normalized to \
+        channel-count matches (reported as warnings); fixtures that can't be \
+        patched become TODO comments in the venue and the report. Give \
+        origin_fixture or origin to move the MVR's origin to downstage-center. \
+        The file must already be inside the project directory.")]
+    async fn import_mvr(
+        &self,
+        Parameters(args): Parameters<ImportMvrArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        use crate::lighting::import::MvrOrigin;
+
+        let path = self.resolve_in_project(&args.path)?;
+        let project = crate::util::project_dir_of(self.config_store()?.path());
+        let origin = match (args.origin_fixture, args.origin) {
+            (Some(fixture), _) => Some(MvrOrigin::Fixture(fixture)),
+            (None, Some(point)) => Some(MvrOrigin::Point(point)),
+            (None, None) => None,
+        };
+        let report = tokio::task::spawn_blocking(move || {
+            crate::lighting::import::import_mvr(
+                &path,
+                args.name.as_deref(),
+                origin.as_ref(),
+                &project,
+                "lighting/fixture_types",
+                "lighting/venues",
+            )
+            .map_err(|e| e.to_string())
+        })
+        .await
+        .map_err(|e| McpError::internal_error(format!("import task failed: {e}"), None))?
+        .map_err(|e| McpError::invalid_params(e, None))?;
+        Ok(ok_json(serde_json::to_value(&report).map_err(|e| {
+            McpError::internal_error(format!("report serialization failed: {e}"), None)
+        })?))
+    }
+
     #[tool(description = "Read a lighting `.light` file referenced by a song. \
         The file lookup matches the basename against the song's loaded \
         lighting shows, so files at the song root or any subdirectory the \
@@ -3108,11 +3264,24 @@ async fn build_status_snapshot(player: &Player) -> Result<Value, McpError> {
         "playlist_name": playlist.name(),
         "current_song": current.as_ref().map(|s| song_summary(s)),
         "playing": playing,
+        "paused": player.is_paused(),
         "elapsed": format_duration(elapsed),
+        "playback_rate": playback_rate_json(player.playback_rate()),
+        "record": player.record_status().map(record_json),
         "lighting": lighting,
     }))
 }
 
+/// The JSON form of a playback rate.
+fn playback_rate_json(rate: crate::audio::PlaybackRate) -> Value {
+    json!({ "speed": rate.speed, "semitones": rate.semitones })
+}
+
+/// The JSON form of the input recorder's state.
+fn record_json(status: crate::trigger::RecordStatus) -> Value {
+    json!({ "armed": status.armed, "take": status.take })
+}
+
 impl McpServer {
     /// Builds the JSON snapshot returned by `tools/call status` and by
     /// reading the `mtrack://status` resource. Thin wrapper around
@@ -30,6 +30,7 @@ enum MidiAction {
     Playlist,
     SectionAck,
     StopSectionLoop,
+    Pause,
     Unrecognized,
 }
 
@@ -43,6 +44,7 @@ struct MidiEvents {
     playlist: LiveEvent<'static>,
     section_ack: Option<LiveEvent<'static>>,
     stop_section_loop: Option<LiveEvent<'static>>,
+    pause: Option<LiveEvent<'static>>,
 }
 
 /// Classifies a parsed MIDI event against the known controller events.
@@ -63,6 +65,8 @@ fn classify_midi_event(events: &MidiEvents, event: &LiveEvent<'_>) -> MidiAction
         MidiAction::SectionAck
     } else if events.stop_section_loop.as_ref() == Some(event) {
         MidiAction::StopSectionLoop
+    } else if events.pause.as_ref() == Some(event) {
+        MidiAction::Pause
     } else {
         MidiAction::Unrecognized
     }
@@ -104,6 +108,7 @@ impl Driver {
                         playlist: config.playlist()?,
                         section_ack: config.section_ack()?,
                         stop_section_loop: config.stop_section_loop()?,
+                        pause: config.pause()?,
                     },
                 }))
             }
@@ -129,6 +134,7 @@ impl super::Driver for Driver {
             playlist: self.events.playlist,
             section_ack: self.events.section_ack,
             stop_section_loop: self.events.stop_section_loop,
+            pause: self.events.pause,
         };
 
         tokio::task::spawn_blocking(move || {
@@ -207,6 +213,9 @@ impl super::Driver for Driver {
                     MidiAction::StopSectionLoop => {
                         player.stop_section_loop();
                     }
+                    MidiAction::Pause => {
+                        player.toggle_pause().await;
+                    }
                     MidiAction::Unrecognized => {}
                 }
             }
@@ -456,6 +465,7 @@ mod test {
                 playlist: note_on(16, 5, 127).to_midi_event().unwrap(),
                 section_ack: Some(note_on(16, 6, 127).to_midi_event().unwrap()),
                 stop_section_loop: Some(note_on(16, 7, 127).to_midi_event().unwrap()),
+                pause: Some(note_on(16, 8, 127).to_midi_event().unwrap()),
             }
         }
 
@@ -501,6 +511,13 @@ mod test {
             assert_eq!(classify_midi_event(&events, &event), MidiAction::Playlist);
         }
 
+        #[test]
+        fn recognizes_pause() {
+            let events = make_test_events();
+            let event = note_on(16, 8, 127).to_midi_event().unwrap();
+            assert_eq!(classify_midi_event(&events, &event), MidiAction::Pause);
+        }
+
         #[test]
         fn unrecognized_note() {
             let events = make_test_events();
@@ -44,6 +44,7 @@ const BROADCAST_SLEEP_DURATION: Duration = Duration::from_millis(500);
 /// Player status strings.
 const STATUS_STOPPED: &str = "Stopped";
 const STATUS_PLAYING: &str = "Playing";
+const STATUS_PAUSED: &str = "Paused";
 
 /// Recognized OSC command actions.
 #[derive(Debug, PartialEq)]
@@ -52,6 +53,7 @@ enum OscAction {
     Prev,
     Next,
     Stop,
+    Pause,
     AllSongs,
     Playlist,
     StopSamples,
@@ -61,6 +63,14 @@ enum OscAction {
     Seek,
     SeekToSection,
     SetTrackGain,
+    SetTrackPan,
+    SetTrackWidth,
+    SetTrackPolarity,
+    SetTrackEqFreq,
+    SetTrackEqGain,
+    SetTrackEqQ,
+    SetMixTrackGain,
+    SetMixTrackMute,
     Unrecognized,
 }
 
@@ -85,6 +95,8 @@ pub(super) struct OscEvents {
     next: Matcher,
     /// The OSC address to look for to stop playback.
     stop: Matcher,
+    /// The OSC address to look for to pause or resume playback.
+    pause: Matcher,
     /// The OSC address to look for to switch from the current playlist to an all songs playlist.
     all_songs: Matcher,
     /// The OSC address to look for to switch back to the current playlist.
@@ -106,10 +118,52 @@ pub(super) struct OscEvents {
     /// The raw track gain pattern; the `*` segment carries the track name and
     /// is substituted for gain feedback broadcasts.
     track_gain_pattern: String,
+    /// The OSC address pattern to set an output track's pan position.
+    track_pan: Matcher,
+    /// The raw track pan pattern, substituted like `track_gain_pattern`.
+    track_pan_pattern: String,
+    /// The OSC address pattern to set an output track's stereo width.
+    track_width: Matcher,
+    /// The raw track width pattern, substituted like `track_gain_pattern`.
+    track_width_pattern: String,
+    /// The OSC address pattern to set an output track's polarity.
+    track_polarity: Matcher,
+    /// The raw track polarity pattern, substituted like `track_gain_pattern`.
+    track_polarity_pattern: String,
+    /// The OSC address pattern to set a track EQ band's frequency.
+    track_eq_freq: Matcher,
+    /// The raw track EQ frequency pattern; its two `*` segments carry the
+    /// track name and the 1-based band number.
+    track_eq_freq_pattern: String,
+    /// The OSC address pattern to set a track EQ band's gain.
+    track_eq_gain: Matcher,
+    /// The raw track EQ gain pattern, substituted like
+    /// `track_eq_freq_pattern`.
+    track_eq_gain_pattern: String,
+    /// The OSC address pattern to set a track EQ band's Q.
+    track_eq_q: Matcher,
+    /// The raw track EQ Q pattern, substituted like `track_eq_freq_pattern`.
+    track_eq_q_pattern: String,
+    /// The OSC address pattern to set a track's gain within a monitor mix.
+    mix_track_gain: Matcher,
+    /// The raw mix track gain pattern; its two `*` segments carry the mix
+    /// and track names.
+    mix_track_gain_pattern: String,
+    /// The OSC address pattern to mute a track within a monitor mix.
+    mix_track_mute: Matcher,
+    /// The raw mix track mute pattern, substituted like
+    /// `mix_track_gain_pattern`.
+    mix_track_mute_pattern: String,
     /// The OSC address to use to broadcast the player status.
     status: String,
     /// The OSC address to use to broadcast the audio output's health verdict.
     audio_health: String,
+    /// The OSC address pattern to broadcast output channel levels to; the
+    /// `*` segment carries the channel number. Empty disables it.
+    channel_meter: String,
+    /// The OSC address pattern to broadcast output track levels to; the `*`
+    /// segment carries the track name. Empty disables it.
+    track_meter: String,
     /// The OSC address to use to broadcast the current playlist.
     playlist_current: String,
     /// The OSC address to use to broadcast the currently playing song.
@@ -140,6 +194,7 @@ impl Driver {
                 prev: Matcher::new(config.prev())?,
                 next: Matcher::new(config.next())?,
                 stop: Matcher::new(config.stop())?,
+                pause: Matcher::new(config.pause())?,
                 all_songs: Matcher::new(config.all_songs())?,
                 playlist: Matcher::new(config.playlist())?,
                 stop_samples: Matcher::new(config.stop_samples())?,
@@ -150,8 +205,26 @@ impl Driver {
                 seek_section: Matcher::new(config.seek_section())?,
                 track_gain: Matcher::new(config.track_gain())?,
                 track_gain_pattern: config.track_gain().to_string(),
+                track_pan: Matcher::new(config.track_pan())?,
+                track_pan_pattern: config.track_pan().to_string(),
+                track_width: Matcher::new(config.track_width())?,
+                track_width_pattern: config.track_width().to_string(),
+                track_polarity: Matcher::new(config.track_polarity())?,
+                track_polarity_pattern: config.track_polarity().to_string(),
+                track_eq_freq: Matcher::new(config.track_eq_freq())?,
+                track_eq_freq_pattern: config.track_eq_freq().to_string(),
+                track_eq_gain: Matcher::new(config.track_eq_gain())?,
+                track_eq_gain_pattern: config.track_eq_gain().to_string(),
+                track_eq_q: Matcher::new(config.track_eq_q())?,
+                track_eq_q_pattern: config.track_eq_q().to_string(),
+                mix_track_gain: Matcher::new(config.mix_track_gain())?,
+                mix_track_gain_pattern: config.mix_track_gain().to_string(),
+                mix_track_mute: Matcher::new(config.mix_track_mute())?,
+                mix_track_mute_pattern: config.mix_track_mute().to_string(),
                 status: config.status().to_string(),
                 audio_health: config.audio_health().to_string(),
+                channel_meter: config.channel_meter().to_string(),
+                track_meter: config.track_meter().to_string(),
                 playlist_current: config.playlist_current().to_string(),
                 playlist_current_song: config.playlist_current_song().to_string(),
                 playlist_current_song_elapsed: config.playlist_current_song_elapsed().to_string(),
@@ -219,6 +292,16 @@ impl super::Driver for Driver {
                 });
             }
 
+            // Level meters go out as each reading arrives rather than on the
+            // status interval, so meters on a surface move at the metering rate.
+            if let Some(state_rx) = player.state_rx() {
+                tokio::spawn(Self::broadcast_meters(
+                    state_rx,
+                    osc_events.clone(),
+                    tx_sender.clone(),
+                ));
+            }
+
             loop {
                 let packet = rx_receiver.recv().await;
                 let tx_sender = tx_sender.clone();
@@ -312,7 +395,9 @@ impl Driver {
         };
         let is_playing = player.is_playing().await;
         let elapsed = player.elapsed().await?;
-        let status_string = if is_playing {
+        let status_string = if player.is_paused() {
+            STATUS_PAUSED
+        } else if is_playing {
             STATUS_PLAYING
         } else {
             STATUS_STOPPED
@@ -355,6 +440,70 @@ impl Driver {
             }
         }
 
+        // Pan, width and polarity feedback, likewise. Width is only sent for
+        // tracks that set one, since unset means "leave the routing alone".
+        if let Some(mixes) = player.get_track_mix() {
+            for (name, mix) in mixes {
+                if !osc_addressable(&name) {
+                    continue;
+                }
+                packets.push(OscPacket::Message(OscMessage {
+                    addr: osc_events.track_pan_pattern.replacen('*', &name, 1),
+                    args: vec![OscType::Float(mix.pan().unwrap_or(0.0))],
+                }));
+                if let Some(width) = mix.width() {
+                    packets.push(OscPacket::Message(OscMessage {
+                        addr: osc_events.track_width_pattern.replacen('*', &name, 1),
+                        args: vec![OscType::Float(width)],
+                    }));
+                }
+                packets.push(OscPacket::Message(OscMessage {
+                    addr: osc_events.track_polarity_pattern.replacen('*', &name, 1),
+                    args: vec![OscType::Int(mix.invert() as i32)],
+                }));
+            }
+        }
+
+        // EQ feedback, one message per band parameter.
+        for (track, bands) in player.get_track_eq().unwrap_or_default() {
+            if !osc_addressable(&track) {
+                continue;
+            }
+            for (i, band) in bands.iter().enumerate() {
+                let number = (i + 1).to_string();
+                for (pattern, value) in [
+                    (&osc_events.track_eq_freq_pattern, band.freq()),
+                    (&osc_events.track_eq_gain_pattern, band.gain()),
+                    (&osc_events.track_eq_q_pattern, band.q()),
+                ] {
+                    packets.push(OscPacket::Message(OscMessage {
+                        addr: fill_pair(pattern, &track, &number),
+                        args: vec![OscType::Float(value)],
+                    }));
+                }
+            }
+        }
+
+        // Monitor mix feedback, so each performer's surface tracks their mix.
+        for mix in player.get_monitor_mixes().unwrap_or_default() {
+            if !osc_addressable(&mix.name) {
+                continue;
+            }
+            for (track, gain_db, muted) in mix.tracks {
+                if !osc_addressable(&track) {
+                    continue;
+                }
+                packets.push(OscPacket::Message(OscMessage {
+                    addr: fill_pair(&osc_events.mix_track_gain_pattern, &mix.name, &track),
+                    args: vec![OscType::Float(gain_db)],
+                }));
+                packets.push(OscPacket::Message(OscMessage {
+                    addr: fill_pair(&osc_events.mix_track_mute_pattern, &mix.name, &track),
+                    args: vec![OscType::Int(muted as i32)],
+                }));
+            }
+        }
+
         for packet in packets {
             tx_sender.send(packet).await?;
         }
@@ -362,6 +511,31 @@ impl Driver {
         Ok(())
     }
 
+    /// Broadcasts each new level meter reading until the state channel or
+    /// the UDP sender closes.
+    async fn broadcast_meters(
+        mut state_rx: tokio::sync::watch::Receiver<Arc<crate::state::StateSnapshot>>,
+        osc_events: Arc<OscEvents>,
+        tx_sender: Sender<OscPacket>,
+    ) {
+        let mut last = None;
+        while state_rx.changed().await.is_ok() {
+            let snapshot = state_rx.borrow_and_update().clone();
+            let Some(ref meters) = snapshot.meters else {
+                continue;
+            };
+            if last.as_ref() == Some(meters) {
+                continue;
+            }
+            for packet in build_meter_packets(&osc_events, meters) {
+                if tx_sender.send(packet).await.is_err() {
+                    return;
+                }
+            }
+            last = Some(meters.clone());
+        }
+    }
+
     /// Handles incoming OSC packets. Meant for responding to things like player
     /// commands (play, previous, next, stop).
     pub(super) async fn handle_packet(
@@ -407,6 +581,9 @@ impl Driver {
             OscAction::Stop => {
                 player.stop().await;
             }
+            OscAction::Pause => {
+                player.toggle_pause().await;
+            }
             OscAction::AllSongs => {
                 if let Err(e) = player.switch_to_playlist("all_songs").await {
                     error!("Failed to switch to all_songs: {}", e);
@@ -478,14 +655,7 @@ impl Driver {
             }
             OscAction::SetTrackGain => {
                 let track = extract_track_name(&osc_events.track_gain_pattern, &msg.addr);
-                // Accept any numeric OSC type as dB for controller compatibility.
-                let gain_db = msg.args.first().and_then(|arg| match arg {
-                    OscType::Float(f) => Some(*f),
-                    OscType::Double(d) => Some(*d as f32),
-                    OscType::Int(i) => Some(*i as f32),
-                    _ => None,
-                });
-                match (track, gain_db) {
+                match (track, numeric_arg(msg)) {
                     (Some(track), Some(gain_db)) => {
                         if let Err(e) = player.set_track_gain(&track, gain_db) {
                             error!("Failed to set gain for track '{}': {}", track, e);
@@ -500,10 +670,132 @@ impl Driver {
                     }
                 }
             }
+            OscAction::SetTrackPan => {
+                let track = extract_track_name(&osc_events.track_pan_pattern, &msg.addr);
+                match (track, numeric_arg(msg)) {
+                    (Some(track), Some(pan)) => {
+                        if let Err(e) = player.set_track_pan(&track, pan) {
+                            error!("Failed to set pan for track '{}': {}", track, e);
+                        }
+                    }
+                    (None, _) => error!(
+                        addr = msg.addr,
+                        "track_pan OSC message: could not extract track name"
+                    ),
+                    (_, None) => error!("track_pan OSC message missing numeric pan argument"),
+                }
+            }
+            OscAction::SetTrackWidth => {
+                let track = extract_track_name(&osc_events.track_width_pattern, &msg.addr);
+                match (track, numeric_arg(msg)) {
+                    (Some(track), Some(width)) => {
+                        if let Err(e) = player.set_track_width(&track, width) {
+                            error!("Failed to set width for track '{}': {}", track, e);
+                        }
+                    }
+                    (None, _) => error!(
+                        addr = msg.addr,
+                        "track_width OSC message: could not extract track name"
+                    ),
+                    (_, None) => {
+                        error!("track_width OSC message missing numeric width argument")
+                    }
+                }
+            }
+            OscAction::SetTrackPolarity => {
+                let track = extract_track_name(&osc_events.track_polarity_pattern, &msg.addr);
+                match (track, bool_arg(msg)) {
+                    (Some(track), Some(inverted)) => {
+                        if let Err(e) = player.set_track_polarity(&track, inverted) {
+                            error!("Failed to set polarity for track '{}': {}", track, e);
+                        }
+                    }
+                    (None, _) => error!(
+                        addr = msg.addr,
+                        "track_polarity OSC message: could not extract track name"
+                    ),
+                    (_, None) => {
+                        error!("track_polarity OSC message missing polarity argument")
+                    }
+                }
+            }
+            OscAction::SetTrackEqFreq => {
+                Self::set_eq_param(player, &osc_events.track_eq_freq_pattern, msg, "freq")
+            }
+            OscAction::SetTrackEqGain => {
+                Self::set_eq_param(player, &osc_events.track_eq_gain_pattern, msg, "gain")
+            }
+            OscAction::SetTrackEqQ => {
+                Self::set_eq_param(player, &osc_events.track_eq_q_pattern, msg, "q")
+            }
+            OscAction::SetMixTrackGain => {
+                let names = extract_mix_track(&osc_events.mix_track_gain_pattern, &msg.addr);
+                match (names, numeric_arg(msg)) {
+                    (Some((mix, track)), Some(gain_db)) => {
+                        if let Err(e) = player.set_mix_track_gain(&mix, &track, gain_db) {
+                            error!(
+                                "Failed to set gain for track '{}' in mix '{}': {}",
+                                track, mix, e
+                            );
+                        }
+                    }
+                    (None, _) => error!(
+                        addr = msg.addr,
+                        "mix_track_gain OSC message: could not extract mix and track names"
+                    ),
+                    (_, None) => {
+                        error!("mix_track_gain OSC message missing numeric gain argument")
+                    }
+                }
+            }
+            OscAction::SetMixTrackMute => {
+                let names = extract_mix_track(&osc_events.mix_track_mute_pattern, &msg.addr);
+                match (names, bool_arg(msg)) {
+                    (Some((mix, track)), Some(muted)) => {
+                        if let Err(e) = player.set_mix_track_mute(&mix, &track, muted) {
+                            error!(
+                                "Failed to set mute for track '{}' in mix '{}': {}",
+                                track, mix, e
+                            );
+                        }
+                    }
+                    (None, _) => error!(
+                        addr = msg.addr,
+                        "mix_track_mute OSC message: could not extract mix and track names"
+                    ),
+                    (_, None) => error!("mix_track_mute OSC message missing mute argument"),
+                }
+            }
             OscAction::Unrecognized => return Ok(false),
         }
         Ok(true)
     }
+
+    /// Applies one EQ band parameter (`freq`, `gain` or `q`) from an OSC
+    /// message addressed by track name and 1-based band number.
+    fn set_eq_param(player: &Player, pattern: &str, msg: &OscMessage, param: &str) {
+        match (extract_track_band(pattern, &msg.addr), numeric_arg(msg)) {
+            (Some((track, band)), Some(value)) => {
+                let value = Some(value);
+                let (freq, gain_db, q) = match param {
+                    "freq" => (value, None, None),
+                    "gain" => (None, value, None),
+                    _ => (None, None, value),
+                };
+                if let Err(e) = player.set_track_eq_band(&track, band, freq, gain_db, q) {
+                    error!(
+                        "Failed to set EQ {} for track '{}' band {}: {}",
+                        param, track, band, e
+                    );
+                }
+            }
+            (None, _) => error!(
+                addr = msg.addr,
+                "track_eq_{} OSC message: could not extract track name and band", param
+            ),
+            (_, None) => error!("track_eq_{} OSC message missing numeric argument", param),
+        }
+    }
 }
 
 /// Classifies an OSC address against the configured event matchers.
@@ -517,6 +809,8 @@ fn classify_message(osc_events: &OscEvents, addr: &str) -> Result<OscAction, Box
         Ok(OscAction::Next)
     } else if osc_events.stop.match_address(&address) {
         Ok(OscAction::Stop)
+    } else if osc_events.pause.match_address(&address) {
+        Ok(OscAction::Pause)
     } else if osc_events.all_songs.match_address(&address) {
         Ok(OscAction::AllSongs)
     } else if osc_events.playlist.match_address(&address) {
@@ -535,6 +829,22 @@ fn classify_message(osc_events: &OscEvents, addr: &str) -> Result<OscAction, Box
         Ok(OscAction::SeekToSection)
     } else if osc_events.track_gain.match_address(&address) {
         Ok(OscAction::SetTrackGain)
+    } else if osc_events.track_pan.match_address(&address) {
+        Ok(OscAction::SetTrackPan)
+    } else if osc_events.track_width.match_address(&address) {
+        Ok(OscAction::SetTrackWidth)
+    } else if osc_events.track_polarity.match_address(&address) {
+        Ok(OscAction::SetTrackPolarity)
+    } else if osc_events.track_eq_freq.match_address(&address) {
+        Ok(OscAction::SetTrackEqFreq)
+    } else if osc_events.track_eq_gain.match_address(&address) {
+        Ok(OscAction::SetTrackEqGain)
+    } else if osc_events.track_eq_q.match_address(&address) {
+        Ok(OscAction::SetTrackEqQ)
+    } else if osc_events.mix_track_gain.match_address(&address) {
+        Ok(OscAction::SetMixTrackGain)
+    } else if osc_events.mix_track_mute.match_address(&address) {
+        Ok(OscAction::SetMixTrackMute)
     } else {
         Ok(OscAction::Unrecognized)
     }
@@ -548,24 +858,74 @@ fn classify_message(osc_events: &OscEvents, addr: &str) -> Result<OscAction, Box
 /// Note: track names containing `/`, spaces, or OSC pattern metacharacters
 /// cannot be addressed this way.
 fn extract_track_name(pattern: &str, addr: &str) -> Option<String> {
+    let mut names = extract_wildcards(pattern, addr)?;
+    (names.len() == 1).then(|| names.remove(0))
+}
+
+/// Extracts the mix and track names from an OSC address, carried by the
+/// pattern's first and second wildcard segments. Returns None unless the
+/// pattern has exactly two.
+fn extract_mix_track(pattern: &str, addr: &str) -> Option<(String, String)> {
+    let [mix, track]: [String; 2] = extract_wildcards(pattern, addr)?.try_into().ok()?;
+    Some((mix, track))
+}
+
+/// Extracts the track name and 1-based band number from an OSC address,
+/// carried by the pattern's first and second wildcard segments. Returns None
+/// unless the pattern has exactly two and the band is a positive number.
+fn extract_track_band(pattern: &str, addr: &str) -> Option<(String, usize)> {
+    let [track, band]: [String; 2] = extract_wildcards(pattern, addr)?.try_into().ok()?;
+    let band = band.parse().ok().filter(|band| *band > 0)?;
+    Some((track, band))
+}
+
+/// Returns the address segments that line up with the pattern's wildcard
+/// segments, in order, or None if the literal segments don't match.
+fn extract_wildcards(pattern: &str, addr: &str) -> Option<Vec<String>> {
     let pattern_segments: Vec<&str> = pattern.split('/').collect();
     let addr_segments: Vec<&str> = addr.split('/').collect();
     if pattern_segments.len() != addr_segments.len() {
         return None;
     }
 
-    let mut name = None;
+    let mut names = Vec::new();
     for (pattern_segment, addr_segment) in pattern_segments.iter().zip(addr_segments.iter()) {
         if pattern_segment.contains(['*', '?', '[', '{']) {
-            if name.is_some() {
-                return None;
-            }
-            name = Some(addr_segment.to_string());
+            names.push(addr_segment.to_string());
         } else if pattern_segment != addr_segment {
             return None;
         }
     }
-    name
+    Some(names)
+}
+
+/// Fills a two-wildcard pattern's `*` segments in order (mix and track,
+/// or track and band), for feedback broadcasts.
+fn fill_pair(pattern: &str, first: &str, second: &str) -> String {
+    pattern.replacen('*', first, 1).replacen('*', second, 1)
+}
+
+/// Reads a message's first argument as a number, accepting any numeric OSC
+/// type for controller compatibility.
+fn numeric_arg(msg: &OscMessage) -> Option<f32> {
+    msg.args.first().and_then(|arg| match arg {
+        OscType::Float(f) => Some(*f),
+        OscType::Double(d) => Some(*d as f32),
+        OscType::Int(i) => Some(*i as f32),
+        _ => None,
+    })
+}
+
+/// Reads a message's first argument as a switch: toggle buttons send
+/// booleans or 0/1, and anything non-zero is on.
+fn bool_arg(msg: &OscMessage) -> Option<bool> {
+    msg.args.first().and_then(|arg| match arg {
+        OscType::Bool(b) => Some(*b),
+        OscType::Int(i) => Some(*i != 0),
+        OscType::Float(f) => Some(*f != 0.0),
+        OscType::Double(d) => Some(*d != 0.0),
+        _ => None,
+    })
 }
 
 /// Whether a track name can appear as a single OSC address segment.
@@ -626,6 +986,44 @@ fn build_broadcast_packets(
     packets
 }
 
+/// Builds the OSC packets for a level meter reading: peak and RMS in dBFS
+/// and the clip count, per output channel and per track.
+fn build_meter_packets(
+    osc_events: &OscEvents,
+    meters: &crate::audio::meters::MeterSnapshot,
+) -> Vec<OscPacket> {
+    let message = |addr: String, level: &crate::audio::meters::MeterLevel| {
+        OscPacket::Message(OscMessage {
+            addr,
+            args: vec![
+                OscType::Float(level.peak_db),
+                OscType::Float(level.rms_db),
+                OscType::Int(i32::try_from(level.clips).unwrap_or(i32::MAX)),
+            ],
+        })
+    };
+    let mut packets = Vec::new();
+    if !osc_events.channel_meter.is_empty() {
+        for (index, level) in meters.channels.iter().enumerate() {
+            let addr = osc_events
+                .channel_meter
+                .replacen('*', &(index + 1).to_string(), 1);
+            packets.push(message(addr, level));
+        }
+    }
+    if !osc_events.track_meter.is_empty() {
+        for (name, level) in &meters.tracks {
+            if osc_addressable(name) {
+                packets.push(message(
+                    osc_events.track_meter.replacen('*', name, 1),
+                    level,
+                ));
+            }
+        }
+    }
+    packets
+}
+
 #[cfg(test)]
 mod test {
     use std::{
@@ -1087,7 +1485,8 @@ mod test {
     }
 
     use super::{
-        build_broadcast_packets, classify_message, format_playlist_content, OscAction, OscEvents,
+        build_broadcast_packets, build_meter_packets, classify_message, format_playlist_content,
+        OscAction, OscEvents,
     };
     use rosc::address::Matcher;
 
@@ -1098,6 +1497,7 @@ mod test {
             prev: Matcher::new(config.prev()).unwrap(),
             next: Matcher::new(config.next()).unwrap(),
             stop: Matcher::new(config.stop()).unwrap(),
+            pause: Matcher::new(config.pause()).unwrap(),
             all_songs: Matcher::new(config.all_songs()).unwrap(),
             playlist: Matcher::new(config.playlist()).unwrap(),
             stop_samples: Matcher::new(config.stop_samples()).unwrap(),
@@ -1108,8 +1508,26 @@ mod test {
             seek_section: Matcher::new(config.seek_section()).unwrap(),
             track_gain: Matcher::new(config.track_gain()).unwrap(),
             track_gain_pattern: config.track_gain().to_string(),
+            track_pan: Matcher::new(config.track_pan()).unwrap(),
+            track_pan_pattern: config.track_pan().to_string(),
+            track_width: Matcher::new(config.track_width()).unwrap(),
+            track_width_pattern: config.track_width().to_string(),
+            track_polarity: Matcher::new(config.track_polarity()).unwrap(),
+            track_polarity_pattern: config.track_polarity().to_string(),
+            track_eq_freq: Matcher::new(config.track_eq_freq()).unwrap(),
+            track_eq_freq_pattern: config.track_eq_freq().to_string(),
+            track_eq_gain: Matcher::new(config.track_eq_gain()).unwrap(),
+            track_eq_gain_pattern: config.track_eq_gain().to_string(),
+            track_eq_q: Matcher::new(config.track_eq_q()).unwrap(),
+            track_eq_q_pattern: config.track_eq_q().to_string(),
+            mix_track_gain: Matcher::new(config.mix_track_gain()).unwrap(),
+            mix_track_gain_pattern: config.mix_track_gain().to_string(),
+            mix_track_mute: Matcher::new(config.mix_track_mute()).unwrap(),
+            mix_track_mute_pattern: config.mix_track_mute().to_string(),
             status: config.status().to_string(),
             audio_health: config.audio_health().to_string(),
+            channel_meter: config.channel_meter().to_string(),
+            track_meter: config.track_meter().to_string(),
             playlist_current: config.playlist_current().to_string(),
             playlist_current_song: config.playlist_current_song().to_string(),
             playlist_current_song_elapsed: config.playlist_current_song_elapsed().to_string(),
@@ -1173,6 +1591,15 @@ mod test {
             );
         }
 
+        #[test]
+        fn recognizes_pause() {
+            let events = make_default_osc_events();
+            assert_eq!(
+                classify_message(&events, "/mtrack/pause").unwrap(),
+                OscAction::Pause
+            );
+        }
+
         #[test]
         fn recognizes_all_songs() {
             let events = make_default_osc_events();
@@ -1223,6 +1650,38 @@ mod test {
                 classify_message(&events, "/mtrack/track/click/gain").unwrap(),
                 OscAction::SetTrackGain
             );
+            assert_eq!(
+                classify_message(&events, "/mtrack/track/click/pan").unwrap(),
+                OscAction::SetTrackPan
+            );
+            assert_eq!(
+                classify_message(&events, "/mtrack/track/click/width").unwrap(),
+                OscAction::SetTrackWidth
+            );
+            assert_eq!(
+                classify_message(&events, "/mtrack/track/click/polarity").unwrap(),
+                OscAction::SetTrackPolarity
+            );
+            assert_eq!(
+                classify_message(&events, "/mtrack/track/click/eq/1/freq").unwrap(),
+                OscAction::SetTrackEqFreq
+            );
+            assert_eq!(
+                classify_message(&events, "/mtrack/track/click/eq/1/gain").unwrap(),
+                OscAction::SetTrackEqGain
+            );
+            assert_eq!(
+                classify_message(&events, "/mtrack/track/click/eq/1/q").unwrap(),
+                OscAction::SetTrackEqQ
+            );
+            assert_eq!(
+                classify_message(&events, "/mtrack/mix/drummer/track/click/gain").unwrap(),
+                OscAction::SetMixTrackGain
+            );
+            assert_eq!(
+                classify_message(&events, "/mtrack/mix/drummer/track/click/mute").unwrap(),
+                OscAction::SetMixTrackMute
+            );
             // Missing track segment doesn't match.
             assert_eq!(
                 classify_message(&events, "/mtrack/track/gain").unwrap(),
@@ -1233,7 +1692,9 @@ mod test {
 
     mod track_gain_tests {
         use super::*;
-        use crate::controller::osc::{extract_track_name, osc_addressable};
+        use crate::controller::osc::{
+            extract_mix_track, extract_track_band, extract_track_name, fill_pair, osc_addressable,
+        };
 
         #[test]
         fn extract_track_name_basics() {
@@ -1251,6 +1712,47 @@ mod test {
             );
         }
 
+        #[test]
+        fn extract_mix_track_basics() {
+            let pattern = "/mtrack/mix/*/track/*/gain";
+            assert_eq!(
+                extract_mix_track(pattern, "/mtrack/mix/drummer/track/click/gain"),
+                Some(("drummer".to_string(), "click".to_string()))
+            );
+            assert_eq!(extract_mix_track(pattern, "/mtrack/mix/drummer/gain"), None);
+            // A single-wildcard pattern can't carry both names.
+            assert_eq!(
+                extract_mix_track("/mtrack/track/*/gain", "/mtrack/track/click/gain"),
+                None
+            );
+            assert_eq!(
+                fill_pair(pattern, "drummer", "click"),
+                "/mtrack/mix/drummer/track/click/gain"
+            );
+        }
+
+        #[test]
+        fn extract_track_band_basics() {
+            let pattern = "/mtrack/track/*/eq/*/freq";
+            assert_eq!(
+                extract_track_band(pattern, "/mtrack/track/bgv/eq/2/freq"),
+                Some(("bgv".to_string(), 2))
+            );
+            // Bands are numbered from 1.
+            assert_eq!(
+                extract_track_band(pattern, "/mtrack/track/bgv/eq/0/freq"),
+                None
+            );
+            assert_eq!(
+                extract_track_band(pattern, "/mtrack/track/bgv/eq/low/freq"),
+                None
+            );
+            assert_eq!(
+                fill_pair(pattern, "bgv", "1"),
+                "/mtrack/track/bgv/eq/1/freq"
+            );
+        }
+
         #[test]
         fn osc_addressable_names() {
             assert!(osc_addressable("click"));
@@ -1314,6 +1816,160 @@ mod test {
 
             Ok(())
         }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn handle_message_sets_mix_gain_and_mute() -> Result<(), Box<dyn Error>> {
+            let songs = songs::get_all_songs(Path::new("assets/songs"))?;
+            let mut playlists = HashMap::new();
+            playlists.insert(
+                "all_songs".to_string(),
+                playlist::from_songs(songs.clone())?,
+            );
+            let dir = tempfile::tempdir()?;
+            let path = dir.path().join("mtrack.yaml");
+            std::fs::write(
+                &path,
+                "songs: songs\nprofiles:\n  - audio:\n      device: mock-device\n      track_mappings:\n        click: [1]\n      mixes:\n        drummer:\n          channels: [2]\n",
+            )?;
+            let player = Player::new(
+                playlists,
+                "all_songs".to_string(),
+                &config::Player::deserialize(&path)?,
+                None,
+            )?;
+            player.await_hardware_ready().await;
+
+            let events = Arc::new(make_default_osc_events());
+            for (addr, arg) in [
+                ("/mtrack/mix/drummer/track/click/gain", OscType::Float(6.0)),
+                ("/mtrack/mix/drummer/track/click/mute", OscType::Int(1)),
+            ] {
+                let msg = OscMessage {
+                    addr: addr.to_string(),
+                    args: vec![arg],
+                };
+                assert!(Driver::handle_message(&player, &events, &msg).await?);
+            }
+
+            let mixes = player.get_monitor_mixes().unwrap();
+            assert_eq!(mixes.len(), 1);
+            assert_eq!(mixes[0].name, "drummer");
+            assert_eq!(mixes[0].tracks, vec![("click".to_string(), 6.0, true)]);
+            // The main mix is untouched.
+            let gains: HashMap<String, f32> =
+                player.get_track_gains().unwrap().into_iter().collect();
+            assert_eq!(gains["click"], 0.0);
+
+            Ok(())
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn handle_message_sets_eq_band() -> Result<(), Box<dyn Error>> {
+            let songs = songs::get_all_songs(Path::new("assets/songs"))?;
+            let mut playlists = HashMap::new();
+            playlists.insert(
+                "all_songs".to_string(),
+                playlist::from_songs(songs.clone())?,
+            );
+            let dir = tempfile::tempdir()?;
+            let path = dir.path().join("mtrack.yaml");
+            std::fs::write(
+                &path,
+                "songs: songs\nprofiles:\n  - audio:\n      device: mock-device\n      track_mappings:\n        click: [1]\n      track_eq:\n        click:\n          - type: peak\n            freq: 1000\n",
+            )?;
+            let player = Player::new(
+                playlists,
+                "all_songs".to_string(),
+                &config::Player::deserialize(&path)?,
+                None,
+            )?;
+            player.await_hardware_ready().await;
+
+            let events = Arc::new(make_default_osc_events());
+            for (addr, arg) in [
+                ("/mtrack/track/click/eq/1/freq", OscType::Float(2500.0)),
+                ("/mtrack/track/click/eq/1/gain", OscType::Int(-4)),
+                ("/mtrack/track/click/eq/1/q", OscType::Double(2.0)),
+                // No second band: logged and ignored.
+                ("/mtrack/track/click/eq/2/gain", OscType::Float(3.0)),
+            ] {
+                let msg = OscMessage {
+                    addr: addr.to_string(),
+                    args: vec![arg],
+                };
+                assert!(Driver::handle_message(&player, &events, &msg).await?);
+            }
+
+            let eq = player.get_track_eq().unwrap();
+            assert_eq!(eq.len(), 1);
+            assert_eq!(eq[0].0, "click");
+            assert_eq!(
+                eq[0].1,
+                vec![config::EqBand::new(
+                    config::EqBandKind::Peak,
+                    2500.0,
+                    -4.0,
+                    Some(2.0)
+                )]
+            );
+
+            Ok(())
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn handle_message_sets_pan_width_and_polarity() -> Result<(), Box<dyn Error>> {
+            let songs = songs::get_all_songs(Path::new("assets/songs"))?;
+            let mut playlists = HashMap::new();
+            playlists.insert(
+                "all_songs".to_string(),
+                playlist::from_songs(songs.clone())?,
+            );
+            let player = Player::new(
+                playlists,
+                "all_songs".to_string(),
+                &config::Player::new(
+                    vec![],
+                    Some(config::Audio::new("mock-device")),
+                    None,
+                    None,
+                    HashMap::from([("click".to_string(), vec![1, 2])]),
+                    "assets/songs",
+                ),
+                None,
+            )?;
+            player.await_hardware_ready().await;
+
+            let events = Arc::new(make_default_osc_events());
+            for (addr, arg) in [
+                ("/mtrack/track/click/pan", OscType::Float(-0.5)),
+                ("/mtrack/track/click/width", OscType::Int(0)),
+                ("/mtrack/track/click/polarity", OscType::Bool(true)),
+            ] {
+                let msg = OscMessage {
+                    addr: addr.to_string(),
+                    args: vec![arg],
+                };
+                assert!(Driver::handle_message(&player, &events, &msg).await?);
+            }
+
+            let mixes: HashMap<String, config::TrackMix> =
+                player.get_track_mix().unwrap().into_iter().collect();
+            assert_eq!(mixes["click"].pan(), Some(-0.5));
+            assert_eq!(mixes["click"].width(), Some(0.0));
+            assert!(mixes["click"].invert());
+
+            // An integer zero clears the inversion.
+            let msg = OscMessage {
+                addr: "/mtrack/track/click/polarity".to_string(),
+                args: vec![OscType::Int(0)],
+            };
+            Driver::handle_message(&player, &events, &msg).await?;
+            let mixes: HashMap<String, config::TrackMix> =
+                player.get_track_mix().unwrap().into_iter().collect();
+            assert!(!mixes["click"].invert());
+
+            Ok(())
+        }
     }
 
     mod format_playlist_content_tests {
@@ -1452,6 +2108,22 @@ mod test {
             assert!(result.unwrap(), "stop_samples should return true");
         }
 
+        #[tokio::test(flavor = "multi_thread")]
+        async fn handle_message_pause_when_stopped() {
+            let player = make_player().await;
+            let events = Arc::new(make_default_osc_events());
+
+            let packet = OscPacket::Message(OscMessage {
+                addr: "/mtrack/pause".to_string(),
+                args: vec![],
+            });
+
+            // Recognized, but nothing is playing so nothing pauses.
+            let result = Driver::handle_packet(&player, &events, &packet).await;
+            assert!(result.unwrap(), "pause should return true");
+            assert!(!player.is_paused());
+        }
+
         #[tokio::test(flavor = "multi_thread")]
         async fn handle_message_unrecognized_returns_false() {
             let player = make_player().await;
@@ -1762,4 +2434,59 @@ mod test {
             }
         }
     }
+
+    mod build_meter_packets_tests {
+        use super::{build_meter_packets, make_default_osc_events};
+        use crate::audio::meters::{MeterLevel, MeterSnapshot};
+        use rosc::{OscMessage, OscPacket, OscType};
+
+        fn reading() -> MeterSnapshot {
+            let level = MeterLevel {
+                peak_db: -3.0,
+                rms_db: -18.0,
+                clips: 5,
+            };
+            MeterSnapshot {
+                channels: vec![level, level],
+                tracks: vec![
+                    ("click".to_string(), level),
+                    ("bad name".to_string(), level),
+                ],
+            }
+        }
+
+        #[test]
+        fn channels_and_addressable_tracks_are_broadcast() {
+            let events = make_default_osc_events();
+            let packets = build_meter_packets(&events, &reading());
+            let args = vec![OscType::Float(-3.0), OscType::Float(-18.0), OscType::Int(5)];
+            assert_eq!(
+                packets,
+                vec![
+                    OscPacket::Message(OscMessage {
+                        addr: "/mtrack/meters/channel/1".to_string(),
+                        args: args.clone(),
+                    }),
+                    OscPacket::Message(OscMessage {
+                        addr: "/mtrack/meters/channel/2".to_string(),
+                        args: args.clone(),
+                    }),
+                    OscPacket::Message(OscMessage {
+                        addr: "/mtrack/meters/track/click".to_string(),
+                        args,
+                    }),
+                ]
+            );
+        }
+
+        #[test]
+        fn empty_patterns_disable_the_broadcast() {
+            let mut events = make_default_osc_events();
+            events.channel_meter.clear();
+            let packets = build_meter_packets(&events, &reading());
+            assert_eq!(packets.len(), 1);
+            events.track_meter.clear();
+            assert!(build_meter_packets(&events, &reading()).is_empty());
+        }
+    }
 }
@@ -12,14 +12,18 @@
 // this program. If not, see <https://www.gnu.org/licenses/>.
 //
 
+pub mod artnet;
 pub mod engine;
 pub mod midi_dmx_store;
 pub mod ola_client;
+pub mod sacn;
 pub mod universe;
 pub mod watcher;
 
 use crate::config;
+use crate::dmx::artnet::ArtNetClient;
 use crate::dmx::ola_client::OlaClientFactory;
+use crate::dmx::sacn::SacnClient;
 use engine::Engine;
 #[cfg(not(test))]
 use ola::client::StreamingClientConfig;
@@ -75,11 +79,19 @@ fn create_engine_inner(
     // Use the lighting config from the DMX config if available
     let lighting_config = config.lighting();
 
+    // With every universe on Art-Net or sACN there's nothing to send to
+    // OLA, and no reason to wait on a daemon the rig may not run.
+    let all_native = !config.universes().is_empty()
+        && config
+            .universes()
+            .iter()
+            .all(|u| u.artnet().is_some() || u.sacn().is_some());
+
     // Build a real OLA client and construct the engine
     // In test mode, use a mock client to avoid hanging on OLA connection
     #[cfg(test)]
     let ola_client = {
-        let _ = allow_null_client; // Only used in non-test builds
+        let _ = (allow_null_client, all_native); // Only used in non-test builds
         OlaClientFactory::create_mock_client_unconditional()
     };
 
@@ -87,6 +99,9 @@ fn create_engine_inner(
     let ola_client = if config.null_client() {
         info!("null_client enabled, skipping OLA connection");
         Box::new(ola_client::NullOlaClient) as Box<dyn ola_client::OlaClient>
+    } else if all_native {
+        info!("All universes use Art-Net or sACN, skipping OLA connection");
+        Box::new(ola_client::NullOlaClient) as Box<dyn ola_client::OlaClient>
     } else {
         let ola_client_config = StreamingClientConfig {
             server_port: config.ola_port(),
@@ -123,6 +138,20 @@ fn create_engine_inner(
         }
     };
 
+    let ola_client = if config.universes().iter().any(|u| u.sacn().is_some()) {
+        let source = config.sacn().cloned().unwrap_or_default();
+        Box::new(SacnClient::new(&source, config.universes(), ola_client)?)
+            as Box<dyn ola_client::OlaClient>
+    } else {
+        ola_client
+    };
+    let ola_client = if config.universes().iter().any(|u| u.artnet().is_some()) {
+        Box::new(ArtNetClient::new(config.universes(), ola_client)?)
+            as Box<dyn ola_client::OlaClient>
+    } else {
+        ola_client
+    };
+
     let engine = Arc::new(Engine::new(config, lighting_config, base_path, ola_client)?);
 
     info!(
@@ -158,4 +187,22 @@ mod test {
         let result = create_engine(Some(&dmx_config), None).unwrap();
         assert!(result.is_some());
     }
+
+    #[test]
+    fn create_engine_with_artnet_universe() {
+        let dmx_config =
+            config::Dmx::new(
+                None,
+                None,
+                None,
+                vec![
+                    config::Universe::new(1, "artnet".to_string())
+                        .with_artnet(config::ArtNet::new("127.0.0.1", 0, 0, 0, true)),
+                    config::Universe::new(2, "ola".to_string()),
+                ],
+                None,
+            );
+        let result = create_engine(Some(&dmx_config), None).unwrap();
+        assert!(result.is_some());
+    }
 }
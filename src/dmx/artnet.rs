@@ -0,0 +1,335 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+
+//! Native Art-Net output: ArtDmx and ArtSync over UDP, without OLA.
+
+use std::{
+    collections::HashMap,
+    error::Error,
+    net::{Ipv4Addr, SocketAddr, UdpSocket},
+};
+
+use tracing::info;
+
+use crate::config;
+
+use super::ola_client::OlaClient;
+
+/// Every Art-Net packet starts with this ID.
+const ARTNET_ID: &[u8; 8] = b"Art-Net\0";
+
+/// OpCode for a DMX frame.
+const OP_DMX: u16 = 0x5000;
+
+/// OpCode for the sync that latches the frames sent before it.
+const OP_SYNC: u16 = 0x5200;
+
+/// The Art-Net protocol revision.
+const PROTOCOL_VERSION: u16 = 14;
+
+/// One universe's Art-Net output.
+struct Output {
+    target: SocketAddr,
+    port_address: u16,
+    sync: bool,
+    /// Lets nodes reorder frames that arrive out of order. Runs 1-255: 0
+    /// tells the node not to reorder.
+    sequence: u8,
+}
+
+/// An [`OlaClient`] that sends universes configured for Art-Net straight to
+/// their nodes, and everything else on to another client (OLA, or nothing).
+pub struct ArtNetClient {
+    socket: UdpSocket,
+    /// Outputs by OLA universe number.
+    outputs: HashMap<u32, Output>,
+    /// Receives the universes that aren't sent over Art-Net.
+    fallback: Box<dyn OlaClient>,
+    /// Targets that have been sent a frame since the last sync.
+    unsynced: Vec<SocketAddr>,
+}
+
+impl ArtNetClient {
+    /// Creates a client for the Art-Net universes in `universes`, passing the
+    /// rest to `fallback`.
+    pub fn new(
+        universes: &[config::Universe],
+        fallback: Box<dyn OlaClient>,
+    ) -> Result<ArtNetClient, Box<dyn Error>> {
+        let mut outputs = HashMap::new();
+        for universe in universes {
+            let Some(artnet) = universe.artnet() else {
+                continue;
+            };
+            let target = artnet.target()?;
+            info!(
+                universe = universe.name(),
+                target = %target,
+                port_address = artnet.port_address(),
+                sync = artnet.sync(),
+                "Sending universe over Art-Net"
+            );
+            outputs.insert(
+                u32::from(universe.universe()),
+                Output {
+                    target,
+                    port_address: artnet.port_address(),
+                    sync: artnet.sync(),
+                    sequence: 0,
+                },
+            );
+        }
+
+        let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))?;
+        // Broadcast targets are refused without this.
+        socket.set_broadcast(true)?;
+        Ok(ArtNetClient {
+            socket,
+            outputs,
+            fallback,
+            unsynced: Vec::new(),
+        })
+    }
+}
+
+impl OlaClient for ArtNetClient {
+    fn send_dmx(&mut self, universe: u32, buffer: &ola::DmxBuffer) -> Result<(), Box<dyn Error>> {
+        let Some(output) = self.outputs.get_mut(&universe) else {
+            return self.fallback.send_dmx(universe, buffer);
+        };
+        output.sequence = output.sequence % 255 + 1;
+        let packet = art_dmx(output.sequence, output.port_address, buffer.as_slice());
+        self.socket.send_to(&packet, output.target)?;
+        if output.sync && !self.unsynced.contains(&output.target) {
+            self.unsynced.push(output.target);
+        }
+        Ok(())
+    }
+
+    fn sync(&mut self) -> Result<(), Box<dyn Error>> {
+        let packet = art_sync();
+        for target in self.unsynced.drain(..) {
+            self.socket.send_to(&packet, target)?;
+        }
+        self.fallback.sync()
+    }
+
+    fn reconnect(&mut self) -> Result<(), Box<dyn Error>> {
+        // UDP has no connection to lose; only the fallback may need one.
+        self.fallback.reconnect()
+    }
+
+    fn terminate(&mut self) {
+        self.fallback.terminate();
+    }
+}
+
+/// Writes the header shared by every Art-Net packet.
+fn header(packet: &mut Vec<u8>, op_code: u16) {
+    packet.extend_from_slice(ARTNET_ID);
+    packet.extend_from_slice(&op_code.to_le_bytes());
+    packet.extend_from_slice(&PROTOCOL_VERSION.to_be_bytes());
+}
+
+/// Builds an ArtDmx packet carrying `data` to `port_address`.
+fn art_dmx(sequence: u8, port_address: u16, data: &[u8]) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(18 + data.len());
+    header(&mut packet, OP_DMX);
+    packet.push(sequence);
+    // Physical input port: informational only.
+    packet.push(0);
+    // SubUni then Net: the port-address, low byte first.
+    packet.extend_from_slice(&port_address.to_le_bytes());
+    packet.extend_from_slice(&(data.len() as u16).to_be_bytes());
+    packet.extend_from_slice(data);
+    packet
+}
+
+/// Builds an ArtSync packet.
+fn art_sync() -> Vec<u8> {
+    let mut packet = Vec::with_capacity(14);
+    header(&mut packet, OP_SYNC);
+    // Aux1 and Aux2: reserved.
+    packet.extend_from_slice(&[0, 0]);
+    packet
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::UdpSocket, time::Duration};
+
+    use super::*;
+    use crate::dmx::ola_client::MockOlaClient;
+
+    /// A local node to send to.
+    fn listener() -> (UdpSocket, SocketAddr) {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        socket
+            .set_read_timeout(Some(Duration::from_secs(2)))
+            .unwrap();
+        let addr = socket.local_addr().unwrap();
+        (socket, addr)
+    }
+
+    fn receive(socket: &UdpSocket) -> Vec<u8> {
+        let mut buf = [0u8; 1024];
+        let len = socket.recv(&mut buf).unwrap();
+        buf[..len].to_vec()
+    }
+
+    fn artnet_universe(
+        universe: u16,
+        target: SocketAddr,
+        port_address: (u8, u8, u8),
+        sync: bool,
+    ) -> config::Universe {
+        let (net, subnet, artnet_universe) = port_address;
+        config::Universe::new(universe, format!("universe {}", universe)).with_artnet(
+            config::ArtNet::new(&target.to_string(), net, subnet, artnet_universe, sync),
+        )
+    }
+
+    #[test]
+    fn art_dmx_layout() {
+        let packet = art_dmx(7, 0x0123, &[10, 20, 30, 40]);
+        assert_eq!(&packet[..8], b"Art-Net\0");
+        assert_eq!(&packet[8..10], &[0x00, 0x50]);
+        assert_eq!(&packet[10..12], &[0, 14]);
+        assert_eq!(packet[12], 7);
+        assert_eq!(packet[13], 0);
+        // SubUni: sub-net 2, universe 3. Net: 1.
+        assert_eq!(&packet[14..16], &[0x23, 0x01]);
+        assert_eq!(&packet[16..18], &[0, 4]);
+        assert_eq!(&packet[18..], &[10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn art_sync_layout() {
+        assert_eq!(
+            art_sync(),
+            [b'A', b'r', b't', b'-', b'N', b'e', b't', 0, 0x00, 0x52, 0, 14, 0, 0]
+        );
+    }
+
+    #[test]
+    fn sends_art_dmx_to_target() -> Result<(), Box<dyn Error>> {
+        let (node, addr) = listener();
+        let mut client = ArtNetClient::new(
+            &[artnet_universe(1, addr, (1, 2, 3), false)],
+            Box::new(MockOlaClient::new()),
+        )?;
+
+        let mut buffer = ola::DmxBuffer::new();
+        buffer.set_channel(0, 255);
+        buffer.set_channel(511, 9);
+        client.send_dmx(1, &buffer)?;
+        client.send_dmx(1, &buffer)?;
+
+        let first = receive(&node);
+        assert_eq!(first.len(), 18 + 512);
+        assert_eq!(&first[..8], b"Art-Net\0");
+        assert_eq!(first[12], 1);
+        assert_eq!(&first[14..16], &[0x23, 0x01]);
+        assert_eq!(&first[16..18], &[0x02, 0x00]);
+        assert_eq!(first[18], 255);
+        assert_eq!(first[18 + 511], 9);
+        // The sequence counts up per frame.
+        assert_eq!(receive(&node)[12], 2);
+        Ok(())
+    }
+
+    #[test]
+    fn sequence_skips_zero_when_it_wraps() -> Result<(), Box<dyn Error>> {
+        let (_node, addr) = listener();
+        let mut client = ArtNetClient::new(
+            &[artnet_universe(1, addr, (0, 0, 0), false)],
+            Box::new(MockOlaClient::new()),
+        )?;
+        let buffer = ola::DmxBuffer::new();
+        for _ in 0..255 {
+            client.send_dmx(1, &buffer)?;
+        }
+        assert_eq!(client.outputs[&1].sequence, 255);
+        client.send_dmx(1, &buffer)?;
+        assert_eq!(client.outputs[&1].sequence, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn sync_follows_a_synced_frame_once() -> Result<(), Box<dyn Error>> {
+        let (node, addr) = listener();
+        let mut client = ArtNetClient::new(
+            &[
+                artnet_universe(1, addr, (0, 0, 0), true),
+                artnet_universe(2, addr, (0, 0, 1), true),
+            ],
+            Box::new(MockOlaClient::new()),
+        )?;
+
+        let buffer = ola::DmxBuffer::new();
+        client.send_dmx(1, &buffer)?;
+        client.send_dmx(2, &buffer)?;
+        client.sync()?;
+        // Nothing sent since: no second sync.
+        client.sync()?;
+        client.send_dmx(1, &buffer)?;
+
+        assert_eq!(&receive(&node)[8..10], &[0x00, 0x50]);
+        assert_eq!(&receive(&node)[8..10], &[0x00, 0x50]);
+        assert_eq!(receive(&node), art_sync());
+        assert_eq!(&receive(&node)[8..10], &[0x00, 0x50]);
+        Ok(())
+    }
+
+    #[test]
+    fn unsynced_universes_send_no_sync() -> Result<(), Box<dyn Error>> {
+        let (node, addr) = listener();
+        let mut client = ArtNetClient::new(
+            &[artnet_universe(1, addr, (0, 0, 0), false)],
+            Box::new(MockOlaClient::new()),
+        )?;
+
+        let buffer = ola::DmxBuffer::new();
+        client.send_dmx(1, &buffer)?;
+        client.sync()?;
+        client.send_dmx(1, &buffer)?;
+
+        assert_eq!(receive(&node)[12], 1);
+        assert_eq!(receive(&node)[12], 2);
+        Ok(())
+    }
+
+    #[test]
+    fn other_universes_go_to_the_fallback() -> Result<(), Box<dyn Error>> {
+        let (_node, addr) = listener();
+        let fallback = MockOlaClient::new();
+        let sent = fallback.sent_messages.clone();
+        let mut client = ArtNetClient::new(
+            &[
+                artnet_universe(1, addr, (0, 0, 0), false),
+                config::Universe::new(2, "ola".to_string()),
+            ],
+            Box::new(fallback),
+        )?;
+
+        let buffer = ola::DmxBuffer::new();
+        client.send_dmx(1, &buffer)?;
+        client.send_dmx(2, &buffer)?;
+
+        let sent = sent.lock();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].universe, 2);
+        Ok(())
+    }
+}
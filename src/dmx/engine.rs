@@ -19,11 +19,11 @@ use std::{
     panic::AssertUnwindSafe,
     sync::{
         atomic::{AtomicBool, AtomicU64, Ordering},
-        mpsc::{self, Receiver},
+        mpsc::{self, Receiver, RecvTimeoutError},
         Arc,
     },
     thread::{self, JoinHandle},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use super::midi_dmx_store::MidiDmxStore;
@@ -38,7 +38,7 @@ use crate::{
     playsync::CancelHandle,
 };
 
-use super::universe::Universe;
+use super::universe::{Universe, TARGET_HZ};
 
 mod midi_playback;
 mod playback;
@@ -116,6 +116,9 @@ pub struct Engine {
     /// write from a superseded one is dropped.
     pub(super) playback_generation: Arc<AtomicU64>,
     pub(super) timeline_finished: Arc<AtomicBool>,
+    /// Set while the song's transport is paused. Effects stop advancing so
+    /// the rig holds on the frame it paused on.
+    pub(super) transport_paused: AtomicBool,
     pub(super) timeline_cancel_handle: Arc<Mutex<Option<CancelHandle>>>,
     pub(super) broadcast_tx: Mutex<Option<tokio::sync::broadcast::Sender<String>>>,
     pub(super) watcher_handle: Mutex<Option<super::watcher::WatcherHandle>>,
@@ -228,6 +231,7 @@ impl Engine {
             playback_generation,
             current_song_time,
             timeline_finished,
+            transport_paused: AtomicBool::new(false),
             timeline_cancel_handle,
             broadcast_tx: Mutex::new(None),
             watcher_handle: Mutex::new(None),
@@ -439,8 +443,13 @@ impl Engine {
 
     /// Updates the effects engine and applies any generated commands to universes
     pub fn update_effects(&self) -> Result<(), Box<dyn std::error::Error>> {
-        // Update the effects engine with a frame time matching Universe TARGET_HZ
-        let dt = Duration::from_secs_f64(1.0 / super::universe::TARGET_HZ);
+        // Update the effects engine with a frame time matching Universe TARGET_HZ.
+        // A paused transport holds effects on their current frame.
+        let dt = if self.transport_paused.load(Ordering::Relaxed) {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(1.0 / super::universe::TARGET_HZ)
+        };
         // Subphase 1: about to acquire current_song_time lock.
         // Tempo-aware effects resolve their speeds against this, so it has to sit in
         // the same delayed time base as the cues that started them. Clamped rather
@@ -550,6 +559,12 @@ impl Engine {
             for fixture_info in fixture_infos {
                 effect_engine.register_fixture(fixture_info);
             }
+            effect_engine.set_focus_points(
+                lighting_system
+                    .get_current_venue()
+                    .map(|venue| venue.focus_points().clone())
+                    .unwrap_or_default(),
+            );
         }
         Ok(())
     }
@@ -585,18 +600,26 @@ impl Engine {
 
     /// Sends messages to OLA using the injected client.
     /// Handles connection failures by attempting to reconnect with backoff.
+    ///
+    /// Universe threads each send on their own 44Hz tick. Whatever is sent
+    /// within one tick is one frame, and the client is synced at the end of
+    /// it so outputs that support it (Art-Net's ArtSync) latch the frame's
+    /// universes together. The client is terminated once the universes are
+    /// gone.
     fn ola_thread(client: Arc<Mutex<Box<dyn OlaClient>>>, receiver: Receiver<DmxMessage>) {
         let mut disconnected = false;
-        let mut last_reconnect_attempt = std::time::Instant::now();
+        let mut last_reconnect_attempt = Instant::now();
         let reconnect_interval = Duration::from_secs(5);
+        let frame = Duration::from_secs(1).div_f64(TARGET_HZ);
+        let mut frame_end = Instant::now() + frame;
 
         loop {
-            match receiver.recv() {
+            match receiver.recv_timeout(frame_end.saturating_duration_since(Instant::now())) {
                 Ok(message) => {
                     if disconnected {
                         // While disconnected, attempt to reconnect periodically.
                         // Messages are dropped until the connection is restored.
-                        let now = std::time::Instant::now();
+                        let now = Instant::now();
                         if now.duration_since(last_reconnect_attempt) >= reconnect_interval {
                             last_reconnect_attempt = now;
                             let mut client = client.lock();
@@ -621,11 +644,30 @@ impl Engine {
                         if let Err(err) = client.send_dmx(message.universe, &message.buffer) {
                             error!("Lost connection to OLA: {}", err);
                             disconnected = true;
-                            last_reconnect_attempt = std::time::Instant::now();
+                            last_reconnect_attempt = Instant::now();
                         }
                     }
                 }
-                Err(_) => return,
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => {
+                    client.lock().terminate();
+                    return;
+                }
+            }
+
+            let now = Instant::now();
+            if now >= frame_end {
+                if !disconnected {
+                    if let Err(err) = client.lock().sync() {
+                        error!("Failed to sync DMX frame: {}", err);
+                        disconnected = true;
+                        last_reconnect_attempt = now;
+                    }
+                }
+                frame_end += frame;
+                if frame_end <= now {
+                    frame_end = now + frame;
+                }
             }
         }
     }
@@ -1001,6 +1043,25 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn frames_are_synced_and_terminated() -> Result<(), Box<dyn Error>> {
+        let mock = crate::dmx::ola_client::MockOlaClient::new();
+        let syncs = mock.syncs.clone();
+        let terminated = mock.terminated.clone();
+        let engine = Engine::new(&create_test_config(), None, None, Box::new(mock))?;
+
+        // Synced every tick, idle or not.
+        crate::testutil::eventually(
+            || syncs.load(std::sync::atomic::Ordering::Relaxed) > 1,
+            "Frames were never synced",
+        );
+        assert!(!terminated.load(std::sync::atomic::Ordering::Relaxed));
+
+        drop(engine);
+        assert!(terminated.load(std::sync::atomic::Ordering::Relaxed));
+        Ok(())
+    }
+
     fn create_test_config() -> config::Dmx {
         config::Dmx::new(
             Some(1.0),
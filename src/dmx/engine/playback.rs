@@ -641,9 +641,17 @@ impl Engine {
                     let song_time = start_offset + elapsed;
                     dmx_engine.update_song_time_for_generation(song_time, generation);
                 }
+                if generation == dmx_engine.playback_generation() {
+                    dmx_engine
+                        .transport_paused
+                        .store(clock.is_paused(), Ordering::Relaxed);
+                }
 
                 thread::sleep(Duration::from_millis(10));
             }
+            if generation == dmx_engine.playback_generation() {
+                dmx_engine.transport_paused.store(false, Ordering::Relaxed);
+            }
         })
     }
 }
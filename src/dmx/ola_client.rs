@@ -21,6 +21,17 @@ pub trait OlaClient: Send + Sync {
 
     /// Attempt to re-establish the connection after a failure
     fn reconnect(&mut self) -> Result<(), Box<dyn Error>>;
+
+    /// Marks the end of a frame: everything sent since the last sync should
+    /// be output together. Called once per engine tick, whether or not
+    /// anything was sent, so outputs can also keep idle universes alive.
+    fn sync(&mut self) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+
+    /// Tells receivers the output is going away. Called once, when the
+    /// engine shuts down.
+    fn terminate(&mut self) {}
 }
 
 /// Real OLA client implementation
@@ -57,6 +68,8 @@ impl OlaClient for RealOlaClient {
 #[cfg(test)]
 pub struct MockOlaClient {
     pub sent_messages: std::sync::Arc<parking_lot::Mutex<Vec<DmxMessage>>>,
+    pub syncs: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    pub terminated: std::sync::Arc<std::sync::atomic::AtomicBool>,
     pub should_fail: bool,
 }
 
@@ -79,6 +92,8 @@ impl MockOlaClient {
     pub fn new() -> Self {
         Self {
             sent_messages: std::sync::Arc::new(parking_lot::Mutex::new(Vec::new())),
+            syncs: Default::default(),
+            terminated: Default::default(),
             should_fail: false,
         }
     }
@@ -137,6 +152,16 @@ impl OlaClient for MockOlaClient {
         }
         Ok(())
     }
+    fn sync(&mut self) -> Result<(), Box<dyn Error>> {
+        self.syncs
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn terminate(&mut self) {
+        self.terminated
+            .store(true, std::sync::atomic::Ordering::Relaxed);
+    }
 }
 
 /// No-op OLA client that discards DMX data. Used when the simulator is running
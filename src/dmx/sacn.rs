@@ -0,0 +1,409 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+
+//! Native sACN (ANSI E1.31) output over UDP, without OLA.
+
+use std::{
+    collections::HashMap,
+    error::Error,
+    net::{Ipv4Addr, SocketAddr, UdpSocket},
+    time::{Duration, Instant},
+};
+
+use tracing::{info, warn};
+
+use crate::config;
+
+use super::ola_client::OlaClient;
+
+/// The ACN packet identifier that follows the preamble.
+const ACN_PACKET_IDENTIFIER: &[u8; 12] = b"ASC-E1.17\0\0\0";
+
+/// Root layer vector: an E1.31 data packet follows.
+const VECTOR_ROOT_E131_DATA: u32 = 0x0000_0004;
+
+/// Framing layer vector: DMX data follows.
+const VECTOR_E131_DATA_PACKET: u32 = 0x0000_0002;
+
+/// DMP layer vector: set property.
+const VECTOR_DMP_SET_PROPERTY: u8 = 0x02;
+
+/// Framing option bit telling receivers the source has stopped sending.
+const OPTION_STREAM_TERMINATED: u8 = 0x40;
+
+/// The length of a data packet carrying a full universe.
+const PACKET_LEN: usize = 638;
+
+/// Receivers treat a source as gone after 2.5s without data, so idle
+/// universes are resent at least this often to hold their priority.
+const KEEPALIVE: Duration = Duration::from_millis(800);
+
+/// How many stream-terminated packets are sent per universe on shutdown.
+const TERMINATE_COUNT: usize = 3;
+
+/// One universe's sACN output.
+struct Output {
+    universe: u16,
+    priority: u8,
+    destinations: Vec<SocketAddr>,
+    sequence: u8,
+    /// The last frame sent, resent to keep the stream alive.
+    last: Option<ola::DmxBuffer>,
+    last_sent: Instant,
+}
+
+/// What identifies mtrack on the wire, and the socket it sends from.
+struct Sender {
+    socket: UdpSocket,
+    source_name: [u8; 64],
+    cid: [u8; 16],
+}
+
+impl Sender {
+    /// Sends a universe's frame to each of its destinations.
+    fn transmit(
+        &self,
+        output: &mut Output,
+        buffer: &ola::DmxBuffer,
+        options: u8,
+    ) -> Result<(), Box<dyn Error>> {
+        let packet = data_packet(
+            &self.cid,
+            &self.source_name,
+            output.priority,
+            output.sequence,
+            options,
+            output.universe,
+            buffer,
+        );
+        output.sequence = output.sequence.wrapping_add(1);
+        output.last_sent = Instant::now();
+        for destination in &output.destinations {
+            self.socket.send_to(&packet, destination)?;
+        }
+        Ok(())
+    }
+}
+
+/// An [`OlaClient`] that sends universes configured for sACN straight to
+/// their receivers, and everything else on to another client.
+pub struct SacnClient {
+    sender: Sender,
+    /// Outputs by OLA universe number.
+    outputs: HashMap<u32, Output>,
+    /// Receives the universes that aren't sent over sACN.
+    fallback: Box<dyn OlaClient>,
+}
+
+impl SacnClient {
+    /// Creates a client for the sACN universes in `universes`, passing the
+    /// rest to `fallback`.
+    pub fn new(
+        source: &config::SacnSource,
+        universes: &[config::Universe],
+        fallback: Box<dyn OlaClient>,
+    ) -> Result<SacnClient, Box<dyn Error>> {
+        let name = source.source_name();
+        let mut source_name = [0u8; 64];
+        let len = name.len().min(63);
+        source_name[..len].copy_from_slice(&name.as_bytes()[..len]);
+
+        let mut outputs = HashMap::new();
+        for universe in universes {
+            let Some(sacn) = universe.sacn() else {
+                continue;
+            };
+            let destinations = sacn.destinations(universe.universe())?;
+            info!(
+                universe = universe.name(),
+                sacn_universe = sacn.universe(universe.universe()),
+                priority = sacn.priority(),
+                destinations = ?destinations,
+                "Sending universe over sACN"
+            );
+            outputs.insert(
+                u32::from(universe.universe()),
+                Output {
+                    universe: sacn.universe(universe.universe()),
+                    priority: sacn.priority(),
+                    destinations,
+                    sequence: 0,
+                    last: None,
+                    last_sent: Instant::now(),
+                },
+            );
+        }
+
+        Ok(SacnClient {
+            sender: Sender {
+                socket: UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))?,
+                source_name,
+                cid: source.cid()?,
+            },
+            outputs,
+            fallback,
+        })
+    }
+}
+
+impl OlaClient for SacnClient {
+    fn send_dmx(&mut self, universe: u32, buffer: &ola::DmxBuffer) -> Result<(), Box<dyn Error>> {
+        let Some(output) = self.outputs.get_mut(&universe) else {
+            return self.fallback.send_dmx(universe, buffer);
+        };
+        output.last = Some(buffer.clone());
+        self.sender.transmit(output, buffer, 0)
+    }
+
+    fn sync(&mut self) -> Result<(), Box<dyn Error>> {
+        for output in self.outputs.values_mut() {
+            if output.last_sent.elapsed() < KEEPALIVE {
+                continue;
+            }
+            if let Some(last) = output.last.clone() {
+                self.sender.transmit(output, &last, 0)?;
+            }
+        }
+        self.fallback.sync()
+    }
+
+    fn reconnect(&mut self) -> Result<(), Box<dyn Error>> {
+        // UDP has no connection to lose; only the fallback may need one.
+        self.fallback.reconnect()
+    }
+
+    fn terminate(&mut self) {
+        for output in self.outputs.values_mut() {
+            let Some(last) = output.last.clone() else {
+                continue;
+            };
+            for _ in 0..TERMINATE_COUNT {
+                if let Err(e) = self
+                    .sender
+                    .transmit(output, &last, OPTION_STREAM_TERMINATED)
+                {
+                    warn!(universe = output.universe, err = %e, "Failed to terminate sACN stream");
+                    break;
+                }
+            }
+        }
+        self.fallback.terminate();
+    }
+}
+
+/// Writes an ACN PDU's flags and length: the length counts from the start of
+/// the PDU to the end of the packet.
+fn flags_and_length(packet: &mut Vec<u8>) {
+    let length = (PACKET_LEN - packet.len()) as u16;
+    packet.extend_from_slice(&(0x7000 | length).to_be_bytes());
+}
+
+/// Builds an E1.31 data packet carrying a universe's slots.
+fn data_packet(
+    cid: &[u8; 16],
+    source_name: &[u8; 64],
+    priority: u8,
+    sequence: u8,
+    options: u8,
+    universe: u16,
+    data: &[u8; 512],
+) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(PACKET_LEN);
+
+    // Root layer.
+    packet.extend_from_slice(&0x0010u16.to_be_bytes());
+    packet.extend_from_slice(&0x0000u16.to_be_bytes());
+    packet.extend_from_slice(ACN_PACKET_IDENTIFIER);
+    flags_and_length(&mut packet);
+    packet.extend_from_slice(&VECTOR_ROOT_E131_DATA.to_be_bytes());
+    packet.extend_from_slice(cid);
+
+    // Framing layer.
+    flags_and_length(&mut packet);
+    packet.extend_from_slice(&VECTOR_E131_DATA_PACKET.to_be_bytes());
+    packet.extend_from_slice(source_name);
+    packet.push(priority);
+    // Synchronization address: not synchronized.
+    packet.extend_from_slice(&0u16.to_be_bytes());
+    packet.push(sequence);
+    packet.push(options);
+    packet.extend_from_slice(&universe.to_be_bytes());
+
+    // DMP layer.
+    flags_and_length(&mut packet);
+    packet.push(VECTOR_DMP_SET_PROPERTY);
+    // Address type and data type.
+    packet.push(0xa1);
+    // First property address.
+    packet.extend_from_slice(&0u16.to_be_bytes());
+    // Address increment.
+    packet.extend_from_slice(&1u16.to_be_bytes());
+    // Property value count: the start code and the slots.
+    packet.extend_from_slice(&513u16.to_be_bytes());
+    // DMX start code.
+    packet.push(0);
+    packet.extend_from_slice(data);
+
+    packet
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::UdpSocket;
+
+    use super::*;
+    use crate::dmx::ola_client::MockOlaClient;
+
+    const CID: &str = "6b7c1f0e-5a4d-4b3c-9e2f-0a1b2c3d4e5f";
+
+    /// A local receiver to send to.
+    fn listener() -> (UdpSocket, SocketAddr) {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        socket
+            .set_read_timeout(Some(Duration::from_secs(2)))
+            .unwrap();
+        let addr = socket.local_addr().unwrap();
+        (socket, addr)
+    }
+
+    fn receive(socket: &UdpSocket) -> Vec<u8> {
+        let mut buf = [0u8; 1024];
+        let len = socket.recv(&mut buf).unwrap();
+        buf[..len].to_vec()
+    }
+
+    fn client(addr: SocketAddr, fallback: MockOlaClient) -> Result<SacnClient, Box<dyn Error>> {
+        SacnClient::new(
+            &config::SacnSource::new(Some("mtrack test".to_string()), Some(CID.to_string())),
+            &[
+                config::Universe::new(1, "sacn".to_string()).with_sacn(config::Sacn::new(
+                    Some(7),
+                    Some(150),
+                    vec![addr.to_string()],
+                )),
+                config::Universe::new(2, "ola".to_string()),
+            ],
+            Box::new(fallback),
+        )
+    }
+
+    #[test]
+    fn data_packet_layout() {
+        let mut name = [0u8; 64];
+        name[..6].copy_from_slice(b"mtrack");
+        let mut data = [0u8; 512];
+        data[0] = 11;
+        data[511] = 22;
+        let packet = data_packet(&[0xab; 16], &name, 120, 9, 0, 0x0102, &data);
+
+        assert_eq!(packet.len(), PACKET_LEN);
+        assert_eq!(&packet[0..4], &[0x00, 0x10, 0x00, 0x00]);
+        assert_eq!(&packet[4..16], b"ASC-E1.17\0\0\0");
+        assert_eq!(&packet[16..18], &[0x72, 0x6e]);
+        assert_eq!(&packet[18..22], &[0, 0, 0, 4]);
+        assert_eq!(&packet[22..38], &[0xab; 16]);
+        assert_eq!(&packet[38..40], &[0x72, 0x58]);
+        assert_eq!(&packet[40..44], &[0, 0, 0, 2]);
+        assert_eq!(&packet[44..50], b"mtrack");
+        assert_eq!(packet[50], 0);
+        assert_eq!(packet[108], 120);
+        assert_eq!(&packet[109..111], &[0, 0]);
+        assert_eq!(packet[111], 9);
+        assert_eq!(packet[112], 0);
+        assert_eq!(&packet[113..115], &[0x01, 0x02]);
+        assert_eq!(&packet[115..117], &[0x72, 0x0b]);
+        assert_eq!(&packet[117..125], &[0x02, 0xa1, 0, 0, 0, 1, 0x02, 0x01]);
+        assert_eq!(packet[125], 0);
+        assert_eq!(packet[126], 11);
+        assert_eq!(packet[637], 22);
+    }
+
+    #[test]
+    fn sends_to_receiver_with_priority_and_sequence() -> Result<(), Box<dyn Error>> {
+        let (receiver, addr) = listener();
+        let mut client = client(addr, MockOlaClient::new())?;
+
+        let mut buffer = ola::DmxBuffer::new();
+        buffer.set_channel(0, 200);
+        client.send_dmx(1, &buffer)?;
+        client.send_dmx(1, &buffer)?;
+
+        let first = receive(&receiver);
+        assert_eq!(first.len(), PACKET_LEN);
+        assert_eq!(&first[22..26], &[0x6b, 0x7c, 0x1f, 0x0e]);
+        assert_eq!(&first[44..55], b"mtrack test");
+        assert_eq!(first[108], 150);
+        assert_eq!(first[111], 0);
+        assert_eq!(&first[113..115], &[0, 7]);
+        assert_eq!(first[126], 200);
+        assert_eq!(receive(&receiver)[111], 1);
+        Ok(())
+    }
+
+    #[test]
+    fn other_universes_go_to_the_fallback() -> Result<(), Box<dyn Error>> {
+        let (_receiver, addr) = listener();
+        let fallback = MockOlaClient::new();
+        let sent = fallback.sent_messages.clone();
+        let mut client = client(addr, fallback)?;
+
+        client.send_dmx(2, &ola::DmxBuffer::new())?;
+        assert_eq!(sent.lock().len(), 1);
+        assert_eq!(sent.lock()[0].universe, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn idle_universes_are_kept_alive() -> Result<(), Box<dyn Error>> {
+        let (receiver, addr) = listener();
+        let mut client = client(addr, MockOlaClient::new())?;
+
+        // Nothing to keep alive before the first frame.
+        client.sync()?;
+
+        let mut buffer = ola::DmxBuffer::new();
+        buffer.set_channel(3, 42);
+        client.send_dmx(1, &buffer)?;
+        // Recently sent: no keepalive yet.
+        client.sync()?;
+        client.outputs.get_mut(&1).unwrap().last_sent = Instant::now() - KEEPALIVE;
+        client.sync()?;
+
+        assert_eq!(receive(&receiver)[111], 0);
+        let keepalive = receive(&receiver);
+        assert_eq!(keepalive[111], 1);
+        assert_eq!(keepalive[129], 42);
+        Ok(())
+    }
+
+    #[test]
+    fn terminate_flags_the_stream() -> Result<(), Box<dyn Error>> {
+        let (receiver, addr) = listener();
+        let fallback = MockOlaClient::new();
+        let terminated = fallback.terminated.clone();
+        let mut client = client(addr, fallback)?;
+
+        client.send_dmx(1, &ola::DmxBuffer::new())?;
+        client.terminate();
+
+        assert_eq!(receive(&receiver)[112], 0);
+        for sequence in 1..=3 {
+            let packet = receive(&receiver);
+            assert_eq!(packet[111], sequence);
+            assert_eq!(packet[112], OPTION_STREAM_TERMINATED);
+        }
+        assert!(terminated.load(std::sync::atomic::Ordering::Relaxed));
+        Ok(())
+    }
+}
@@ -16,6 +16,8 @@ mod color;
 mod error;
 mod fixture;
 mod instance;
+mod movement;
+mod pointing;
 mod state;
 mod tempo_aware;
 mod types;
@@ -27,11 +29,15 @@ mod tests;
 pub use color::Color;
 pub use error::EffectError;
 pub use fixture::{
-    multiplier_key, FixtureCapabilities, FixtureInfo, FixtureProfile, StrobeStrategy,
+    multiplier_key, Aim, FixtureCapabilities, FixtureInfo, FixtureProfile, StrobeStrategy,
     MULTIPLIER_PREFIXES,
 };
 pub use instance::EffectInstance;
-pub use state::{is_multiplier_channel, ChannelState, DmxCommand, FixtureState};
+pub use movement::{MovementShape, SweepPattern};
+pub use pointing::{beam_direction, pan_tilt_towards};
+pub use state::{
+    is_multiplier_channel, is_position_channel, ChannelState, DmxCommand, FixtureState,
+};
 pub use tempo_aware::{TempoAwareFrequency, TempoAwareSpeed, TempoAwareValue};
 pub use types::{
     BlendMode, ChaseDirection, ChasePattern, CycleDirection, CycleTransition, DimmerCurve,
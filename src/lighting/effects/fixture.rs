@@ -15,8 +15,10 @@
 use std::collections::HashMap;
 
 use super::color::Color;
+use super::pointing;
 use super::state::ChannelState;
 use super::types::{BlendMode, EffectLayer};
+use crate::lighting::types::{MovementLimits, PhysicalRange, PhysicalUnit};
 
 /// Get the layer suffix for multiplier channel names
 #[inline]
@@ -461,6 +463,18 @@ pub struct FixtureInfo {
     pub max_strobe_frequency: Option<f64>,
     pub min_strobe_frequency: Option<f64>,
     pub strobe_dmx_offset: Option<u8>,
+    /// Fine-byte offsets of 16-bit channels, keyed by channel name.
+    pub fine_channels: HashMap<String, u16>,
+    /// Physical ranges the full DMX range of a channel maps onto, keyed by
+    /// channel name.
+    pub physical_ranges: HashMap<String, PhysicalRange>,
+    /// How fast the fixture can pan and tilt.
+    pub movement: MovementLimits,
+    /// Stage position in meters, if the venue places the fixture.
+    pub position: Option<[f64; 3]>,
+    /// Mounting rotation in degrees about stage X, Y and Z, if the venue
+    /// gives one.
+    pub rotation: Option<[f64; 3]>,
     /// Cached capabilities derived from channels (computed once at construction)
     cached_capabilities: FixtureCapabilities,
     /// Cached fixture profile (computed once at construction)
@@ -488,6 +502,11 @@ impl FixtureInfo {
             max_strobe_frequency,
             min_strobe_frequency: None,
             strobe_dmx_offset: None,
+            fine_channels: HashMap::new(),
+            physical_ranges: HashMap::new(),
+            movement: MovementLimits::default(),
+            position: None,
+            rotation: None,
             cached_capabilities: capabilities,
             cached_profile: profile,
         }
@@ -564,6 +583,81 @@ impl FixtureInfo {
     pub fn profile(&self) -> &FixtureProfile {
         &self.cached_profile
     }
+
+    /// The degree range a channel's full DMX range maps onto, if known.
+    pub fn degree_range(&self, channel: &str) -> Option<&PhysicalRange> {
+        self.physical_ranges
+            .get(channel)
+            .filter(|range| range.unit == PhysicalUnit::Degrees && range.from != range.to)
+    }
+
+    /// Resolves an angle on a channel into its normalized value, clamped to
+    /// what the fixture can reach. None when the channel has no degree range.
+    pub fn degrees_to_value(&self, channel: &str, degrees: f64) -> Option<f64> {
+        let range = self.degree_range(channel)?;
+        Some(((degrees - range.from) / (range.to - range.from)).clamp(0.0, 1.0))
+    }
+
+    /// The maximum speed of a position channel in degrees per second, if
+    /// configured.
+    pub fn max_speed(&self, channel: &str) -> Option<f64> {
+        match channel {
+            "pan" => self.movement.max_pan_speed,
+            "tilt" => self.movement.max_tilt_speed,
+            _ => None,
+        }
+    }
+
+    /// The pan and tilt, in degrees, that point this fixture at `target` on
+    /// stage. Of the ways to reach a direction, the one the fixture can reach
+    /// nearest the middle of its ranges wins; when none is reachable the
+    /// closest is clamped to the ranges and marked as such. None when the
+    /// venue does not place the fixture, or the target is where it hangs.
+    pub fn aim_at(&self, target: [f64; 3]) -> Option<Aim> {
+        let (pan, tilt) =
+            pointing::pan_tilt_towards(self.position?, self.rotation.unwrap_or_default(), target)?;
+
+        // Degrees past the end of an axis's range, and from its middle.
+        let fit = |channel: &str, degrees: f64| {
+            self.degree_range(channel).map_or((0.0, 0.0), |range| {
+                let (low, high) = (range.from.min(range.to), range.from.max(range.to));
+                let past = (low - degrees).max(degrees - high).max(0.0);
+                (past, (degrees - (low + high) / 2.0).abs())
+            })
+        };
+        let (pan, tilt, past) = pointing::equivalent_aims(pan, tilt)
+            .map(|(pan, tilt)| {
+                let (pan_past, pan_off) = fit("pan", pan);
+                let (tilt_past, tilt_off) = fit("tilt", tilt);
+                (pan, tilt, pan_past + tilt_past, pan_off + tilt_off)
+            })
+            .min_by(|a, b| (a.2, a.3).partial_cmp(&(b.2, b.3)).expect("finite angles"))
+            .map(|(pan, tilt, past, _)| (pan, tilt, past))
+            .expect("there is always an equivalent aim");
+
+        let clamp = |channel: &str, degrees: f64| {
+            self.degree_range(channel).map_or(degrees, |range| {
+                degrees.clamp(range.from.min(range.to), range.from.max(range.to))
+            })
+        };
+        Some(Aim {
+            pan: clamp("pan", pan),
+            tilt: clamp("tilt", tilt),
+            clamped: past > 1e-6,
+        })
+    }
+}
+
+/// Where a fixture has to point to hit a target, from [`FixtureInfo::aim_at`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aim {
+    /// Pan in degrees.
+    pub pan: f64,
+    /// Tilt in degrees.
+    pub tilt: f64,
+    /// The target is out of the fixture's reach, and this is as close as it
+    /// gets.
+    pub clamped: bool,
 }
 
 #[cfg(test)]
@@ -927,4 +1021,90 @@ mod tests {
         assert_eq!(f.max_strobe_frequency, Some(25.0));
         assert_eq!(f.channels.len(), 4);
     }
+
+    #[test]
+    fn degrees_resolve_through_the_channel_range() {
+        let mut f = full_fixture();
+        f.physical_ranges.insert(
+            "pan".to_string(),
+            PhysicalRange {
+                from: -270.0,
+                to: 270.0,
+                unit: PhysicalUnit::Degrees,
+            },
+        );
+        // A reversed range maps the other way round.
+        f.physical_ranges.insert(
+            "tilt".to_string(),
+            PhysicalRange {
+                from: 135.0,
+                to: -135.0,
+                unit: PhysicalUnit::Degrees,
+            },
+        );
+        assert_eq!(f.degrees_to_value("pan", 0.0), Some(0.5));
+        assert_eq!(f.degrees_to_value("pan", 135.0), Some(0.75));
+        assert_eq!(f.degrees_to_value("tilt", 135.0), Some(0.0));
+        // Beyond the mechanical range clamps rather than wrapping.
+        assert_eq!(f.degrees_to_value("pan", 400.0), Some(1.0));
+        assert_eq!(f.degrees_to_value("dimmer", 10.0), None);
+    }
+
+    #[test]
+    fn a_hertz_range_is_not_a_degree_range() {
+        let mut f = full_fixture();
+        f.physical_ranges.insert(
+            "pan".to_string(),
+            PhysicalRange {
+                from: 0.0,
+                to: 25.0,
+                unit: PhysicalUnit::Hertz,
+            },
+        );
+        assert!(f.degree_range("pan").is_none());
+    }
+
+    /// A head hung 5 m up at center stage, with 540° of pan and 270° of tilt.
+    fn hung_mover() -> FixtureInfo {
+        let mut f = full_fixture();
+        for (channel, half) in [("pan", 270.0), ("tilt", 135.0)] {
+            f.physical_ranges.insert(
+                channel.to_string(),
+                PhysicalRange {
+                    from: -half,
+                    to: half,
+                    unit: PhysicalUnit::Degrees,
+                },
+            );
+        }
+        f.position = Some([0.0, 0.0, 5.0]);
+        f
+    }
+
+    #[test]
+    fn aim_picks_the_reachable_solution_nearest_the_middle() {
+        let f = hung_mover();
+        // Downstage and below: pan a half turn and tilt 45°, or no pan and
+        // tilt -45°. The latter sits nearer the middle of the pan range.
+        let aim = f.aim_at([0.0, -5.0, 0.0]).unwrap();
+        assert!(aim.pan.abs() < 1e-9, "{aim:?}");
+        assert!((aim.tilt + 45.0).abs() < 1e-9, "{aim:?}");
+        assert!(!aim.clamped);
+    }
+
+    #[test]
+    fn aim_out_of_reach_clamps() {
+        let f = hung_mover();
+        // Straight up is 180° of tilt from a hung head's rest position.
+        let aim = f.aim_at([0.0, 0.0, 10.0]).unwrap();
+        assert!(aim.clamped);
+        assert_eq!(aim.tilt.abs(), 135.0);
+    }
+
+    #[test]
+    fn aim_needs_a_placement() {
+        let mut f = hung_mover();
+        f.position = None;
+        assert_eq!(f.aim_at([0.0, 0.0, 0.0]), None);
+    }
 }
@@ -0,0 +1,238 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+
+use std::f64::consts::TAU;
+
+use super::tempo_aware::TempoAwareSpeed;
+
+/// The path a movement effect traces, in physical degrees.
+///
+/// Shapes are authored once for a whole group and resolved per fixture: the
+/// same `tilt: 45deg` lands on different DMX values on a fixture with 270° of
+/// tilt and one with 180°, which is the point of authoring in degrees.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MovementShape {
+    /// Every fixture holds one position.
+    Position { pan: f64, tilt: f64 },
+
+    /// Every fixture traces the same closed path around a center.
+    Sweep {
+        pattern: SweepPattern,
+        pan: f64,
+        tilt: f64,
+        pan_radius: f64,
+        tilt_radius: f64,
+        speed: TempoAwareSpeed, // cycles per second (can be tempo-aware)
+    },
+
+    /// The group spreads evenly across a span centered on a position, in the
+    /// order the group lists its fixtures.
+    Fan {
+        pan: f64,
+        tilt: f64,
+        pan_spread: f64,
+        tilt_spread: f64,
+    },
+
+    /// Every fixture points at a named focus point of the venue, each
+    /// through its own mounting position and rotation.
+    Focus { point: String },
+}
+
+/// The closed path a sweep traces.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SweepPattern {
+    Circle,
+    /// A 1:2 Lissajous figure: tilt runs twice per pan cycle.
+    FigureEight,
+}
+
+impl MovementShape {
+    /// The keyword an author writes for this shape.
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            MovementShape::Position { .. } | MovementShape::Focus { .. } => "position",
+            MovementShape::Sweep { .. } => "sweep",
+            MovementShape::Fan { .. } => "fan",
+        }
+    }
+
+    /// The sweep speed, for shapes that move on their own.
+    pub fn speed(&self) -> Option<&TempoAwareSpeed> {
+        match self {
+            MovementShape::Sweep { speed, .. } => Some(speed),
+            _ => None,
+        }
+    }
+
+    /// Where fixture `index` of `count` points, as (pan, tilt) in degrees,
+    /// `cycles` sweep cycles into the effect. Shapes that hold still ignore
+    /// `cycles`. None for a focus, which each fixture resolves from where it
+    /// hangs.
+    pub fn target(&self, index: usize, count: usize, cycles: f64) -> Option<(f64, f64)> {
+        Some(match self {
+            MovementShape::Position { pan, tilt } => (*pan, *tilt),
+            MovementShape::Focus { .. } => return None,
+            MovementShape::Sweep {
+                pattern,
+                pan,
+                tilt,
+                pan_radius,
+                tilt_radius,
+                ..
+            } => {
+                let angle = cycles * TAU;
+                match pattern {
+                    SweepPattern::Circle => (
+                        pan + pan_radius * angle.cos(),
+                        tilt + tilt_radius * angle.sin(),
+                    ),
+                    SweepPattern::FigureEight => (
+                        pan + pan_radius * angle.sin(),
+                        tilt + tilt_radius * (2.0 * angle).sin(),
+                    ),
+                }
+            }
+            MovementShape::Fan {
+                pan,
+                tilt,
+                pan_spread,
+                tilt_spread,
+            } => {
+                // A lone fixture sits at the center rather than at one end.
+                let offset = if count > 1 {
+                    index as f64 / (count - 1) as f64 - 0.5
+                } else {
+                    0.0
+                };
+                (pan + pan_spread * offset, tilt + tilt_spread * offset)
+            }
+        })
+    }
+
+    /// The fastest each axis has to travel, as (pan, tilt) in degrees per
+    /// second, while the shape runs at `cycles_per_second`. Zero for shapes
+    /// that hold still.
+    pub fn peak_speed(&self, cycles_per_second: f64) -> (f64, f64) {
+        match self {
+            MovementShape::Sweep {
+                pattern,
+                pan_radius,
+                tilt_radius,
+                ..
+            } => {
+                let omega = cycles_per_second.abs() * TAU;
+                let tilt_rate = match pattern {
+                    SweepPattern::Circle => 1.0,
+                    SweepPattern::FigureEight => 2.0,
+                };
+                (
+                    pan_radius.abs() * omega,
+                    tilt_radius.abs() * omega * tilt_rate,
+                )
+            }
+            MovementShape::Position { .. }
+            | MovementShape::Fan { .. }
+            | MovementShape::Focus { .. } => (0.0, 0.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sweep(pattern: SweepPattern) -> MovementShape {
+        MovementShape::Sweep {
+            pattern,
+            pan: 10.0,
+            tilt: 45.0,
+            pan_radius: 30.0,
+            tilt_radius: 20.0,
+            speed: TempoAwareSpeed::Fixed(0.5),
+        }
+    }
+
+    fn close(a: (f64, f64), b: (f64, f64)) -> bool {
+        (a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9
+    }
+
+    #[test]
+    fn position_ignores_index_and_time() {
+        let shape = MovementShape::Position {
+            pan: -90.0,
+            tilt: 30.0,
+        };
+        assert_eq!(shape.target(0, 4, 0.0).unwrap(), (-90.0, 30.0));
+        assert_eq!(shape.target(3, 4, 2.7).unwrap(), (-90.0, 30.0));
+        assert_eq!(shape.peak_speed(5.0), (0.0, 0.0));
+    }
+
+    #[test]
+    fn focus_is_resolved_per_fixture() {
+        let shape = MovementShape::Focus {
+            point: "drummer".to_string(),
+        };
+        assert_eq!(shape.keyword(), "position");
+        assert_eq!(shape.target(0, 2, 0.0), None);
+        assert_eq!(shape.peak_speed(1.0), (0.0, 0.0));
+    }
+
+    #[test]
+    fn circle_quarter_points() {
+        let shape = sweep(SweepPattern::Circle);
+        assert!(close(shape.target(0, 1, 0.0).unwrap(), (40.0, 45.0)));
+        assert!(close(shape.target(0, 1, 0.25).unwrap(), (10.0, 65.0)));
+        assert!(close(shape.target(0, 1, 0.5).unwrap(), (-20.0, 45.0)));
+        // A whole cycle later it is back where it started.
+        assert!(close(
+            shape.target(0, 1, 1.0).unwrap(),
+            shape.target(0, 1, 0.0).unwrap()
+        ));
+    }
+
+    #[test]
+    fn figure_eight_crosses_center_twice_per_cycle() {
+        let shape = sweep(SweepPattern::FigureEight);
+        assert!(close(shape.target(0, 1, 0.0).unwrap(), (10.0, 45.0)));
+        assert!(close(shape.target(0, 1, 0.5).unwrap(), (10.0, 45.0)));
+        // The pan extreme sits on the tilt centerline.
+        assert!(close(shape.target(0, 1, 0.25).unwrap(), (40.0, 45.0)));
+    }
+
+    #[test]
+    fn fan_spreads_evenly_in_list_order() {
+        let shape = MovementShape::Fan {
+            pan: 0.0,
+            tilt: 50.0,
+            pan_spread: 90.0,
+            tilt_spread: 0.0,
+        };
+        for (i, pan) in [-45.0, -15.0, 15.0, 45.0].into_iter().enumerate() {
+            assert!(close(shape.target(i, 4, 0.0).unwrap(), (pan, 50.0)));
+        }
+        assert_eq!(shape.target(0, 1, 0.0).unwrap(), (0.0, 50.0));
+    }
+
+    #[test]
+    fn peak_speed_scales_with_radius_and_rate() {
+        let (pan, tilt) = sweep(SweepPattern::Circle).peak_speed(0.5);
+        assert!((pan - 30.0 * TAU * 0.5).abs() < 1e-9);
+        assert!((tilt - 20.0 * TAU * 0.5).abs() < 1e-9);
+
+        // Tilt runs at twice the rate on a figure-eight.
+        let (_, tilt) = sweep(SweepPattern::FigureEight).peak_speed(0.5);
+        assert!((tilt - 20.0 * TAU).abs() < 1e-9);
+    }
+}
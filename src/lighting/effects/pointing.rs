@@ -0,0 +1,171 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+
+//! Aiming a moving head at a point on stage.
+//!
+//! The head's own frame follows GDTF: hung with no rotation, the beam at pan 0
+//! and tilt 0 points straight down (-Z). Pan turns the head about its own Z
+//! axis, and tilt swings the beam about the panned X axis, towards +Y at pan 0.
+//! The venue's mounting rotation (degrees about stage X, then Y, then Z) takes
+//! that frame onto the stage.
+
+type Vector = [f64; 3];
+type Matrix = [[f64; 3]; 3];
+
+/// The rotation a fixture's mounting angles describe, R = Rz · Ry · Rx.
+fn mount_matrix(rotation: Vector) -> Matrix {
+    let [x, y, z] = rotation.map(f64::to_radians);
+    let (sx, cx) = x.sin_cos();
+    let (sy, cy) = y.sin_cos();
+    let (sz, cz) = z.sin_cos();
+    [
+        [cz * cy, cz * sy * sx - sz * cx, cz * sy * cx + sz * sx],
+        [sz * cy, sz * sy * sx + cz * cx, sz * sy * cx - cz * sx],
+        [-sy, cy * sx, cy * cx],
+    ]
+}
+
+fn multiply(m: &Matrix, v: Vector) -> Vector {
+    [0, 1, 2].map(|row| m[row][0] * v[0] + m[row][1] * v[1] + m[row][2] * v[2])
+}
+
+fn multiply_transposed(m: &Matrix, v: Vector) -> Vector {
+    [0, 1, 2].map(|col| m[0][col] * v[0] + m[1][col] * v[1] + m[2][col] * v[2])
+}
+
+/// The stage direction, as a unit vector, a fixture mounted at `rotation`
+/// points its beam at `pan` and `tilt` degrees.
+pub fn beam_direction(rotation: Vector, pan: f64, tilt: f64) -> Vector {
+    let (sp, cp) = pan.to_radians().sin_cos();
+    let (st, ct) = tilt.to_radians().sin_cos();
+    multiply(&mount_matrix(rotation), [-sp * st, cp * st, -ct])
+}
+
+/// The pan and tilt, in degrees, that point a fixture at `position`, mounted
+/// at `rotation`, towards `target`. Pan falls in (-180, 180] and tilt in
+/// [0, 180]; [`equivalent_aims`] gives the other ways to reach the same
+/// direction. `None` when the target is where the fixture is.
+pub fn pan_tilt_towards(position: Vector, rotation: Vector, target: Vector) -> Option<(f64, f64)> {
+    let offset = [0, 1, 2].map(|i| target[i] - position[i]);
+    let length = offset.iter().map(|c| c * c).sum::<f64>().sqrt();
+    if length < 1e-9 {
+        return None;
+    }
+    let [x, y, z] = multiply_transposed(&mount_matrix(rotation), offset.map(|c| c / length));
+    let tilt = (-z).clamp(-1.0, 1.0).acos().to_degrees();
+    // Straight along the rest axis, any pan will do; keep the head where it is.
+    let pan = if x.hypot(y) < 1e-12 {
+        0.0
+    } else {
+        (-x).atan2(y).to_degrees()
+    };
+    Some((pan, tilt))
+}
+
+/// Every (pan, tilt) within a turn and a half either side of `pan` that
+/// points the beam the same way: a half turn of pan with the tilt mirrored
+/// lands on the same direction.
+pub fn equivalent_aims(pan: f64, tilt: f64) -> impl Iterator<Item = (f64, f64)> {
+    (-3i32..=3).map(move |half_turns| {
+        let tilt = if half_turns % 2 == 0 { tilt } else { -tilt };
+        (pan + 180.0 * f64::from(half_turns), tilt)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn close(a: Vector, b: Vector) -> bool {
+        a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-9)
+    }
+
+    #[test]
+    fn rest_position_points_down() {
+        assert!(close(beam_direction([0.0; 3], 0.0, 0.0), [0.0, 0.0, -1.0]));
+        // Tilt swings towards +Y at pan 0, and pan turns that about Z.
+        assert!(close(beam_direction([0.0; 3], 0.0, 90.0), [0.0, 1.0, 0.0]));
+        assert!(close(
+            beam_direction([0.0; 3], 90.0, 90.0),
+            [-1.0, 0.0, 0.0]
+        ));
+    }
+
+    #[test]
+    fn floor_mounted_head_points_up() {
+        // Flipped over about X, a floor-standing head rests pointing up.
+        assert!(close(
+            beam_direction([180.0, 0.0, 0.0], 0.0, 0.0),
+            [0.0, 0.0, 1.0]
+        ));
+    }
+
+    #[test]
+    fn hung_head_aims_at_a_point_below_and_upstage() {
+        let (pan, tilt) = pan_tilt_towards([0.0, 0.0, 5.0], [0.0; 3], [0.0, 5.0, 0.0]).unwrap();
+        assert!(pan.abs() < 1e-9);
+        assert!((tilt - 45.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn target_at_the_fixture_has_no_aim() {
+        assert_eq!(
+            pan_tilt_towards([1.0, 2.0, 3.0], [0.0; 3], [1.0, 2.0, 3.0]),
+            None
+        );
+    }
+
+    #[test]
+    fn equivalent_aims_share_a_direction() {
+        let direction = beam_direction([0.0; 3], 30.0, 60.0);
+        for (pan, tilt) in equivalent_aims(30.0, 60.0) {
+            assert!(close(beam_direction([0.0; 3], pan, tilt), direction));
+        }
+    }
+
+    fn vector(range: f64) -> impl Strategy<Value = Vector> {
+        [-range..range, -range..range, -range..range]
+    }
+
+    proptest! {
+        /// Aim, convert to pan and tilt, turn that back into a beam: the
+        /// beam points at the target, wherever and however the fixture hangs.
+        #[test]
+        fn aim_round_trips_through_pan_and_tilt(
+            position in vector(20.0),
+            rotation in vector(180.0),
+            target in vector(20.0),
+        ) {
+            let offset = [0, 1, 2].map(|i| target[i] - position[i]);
+            let length = offset.iter().map(|c| c * c).sum::<f64>().sqrt();
+            prop_assume!(length > 1e-3);
+
+            let (pan, tilt) = pan_tilt_towards(position, rotation, target).unwrap();
+            prop_assert!((-180.0..=180.0).contains(&pan));
+            prop_assert!((0.0..=180.0).contains(&tilt));
+
+            let expected = offset.map(|c| c / length);
+            for (pan, tilt) in equivalent_aims(pan, tilt) {
+                let direction = beam_direction(rotation, pan, tilt);
+                for axis in 0..3 {
+                    prop_assert!(
+                        (direction[axis] - expected[axis]).abs() < 1e-6,
+                        "pan {pan} tilt {tilt} points {direction:?}, not {expected:?}"
+                    );
+                }
+            }
+        }
+    }
+}
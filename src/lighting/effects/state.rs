@@ -32,6 +32,13 @@ pub fn is_multiplier_channel(channel_name: &str) -> bool {
     })
 }
 
+/// Check if a channel name is a position channel (pan or tilt). Positions
+/// are angles, not levels: masters and fades must not scale them.
+#[inline]
+pub fn is_position_channel(channel_name: &str) -> bool {
+    channel_name == "pan" || channel_name == "tilt"
+}
+
 /// DMX command for sending to fixtures
 #[derive(Debug, Clone)]
 pub struct DmxCommand {
@@ -195,6 +202,23 @@ impl FixtureState {
             if let Some(&channel_offset) = fixture_info.channels.get(channel_name) {
                 let dmx_channel = fixture_info.address + channel_offset - 1;
                 let value = self.effective_channel_value(channel_name, state, has_dedicated_dimmer);
+
+                // A 16-bit channel fans out into its coarse and fine bytes.
+                if let Some(&fine_offset) = fixture_info.fine_channels.get(channel_name) {
+                    let wide = (value * 65535.0).round() as u16;
+                    commands.push(DmxCommand {
+                        universe: fixture_info.universe,
+                        channel: dmx_channel,
+                        value: (wide >> 8) as u8,
+                    });
+                    commands.push(DmxCommand {
+                        universe: fixture_info.universe,
+                        channel: fixture_info.address + fine_offset - 1,
+                        value: (wide & 0xff) as u8,
+                    });
+                    continue;
+                }
+
                 let dmx_value = (value * 255.0) as u8;
 
                 commands.push(DmxCommand {
@@ -633,4 +657,40 @@ mod tests {
         assert_eq!(cmds.len(), 1);
         assert_eq!(cmds[0].value, 127); // 0.5 * 255 = 127
     }
+
+    #[test]
+    fn to_dmx_commands_fans_out_fine_byte() {
+        let mut fixture = make_fixture_info(vec![("pan", 1), ("tilt", 3)], 10);
+        fixture.fine_channels.insert("pan".to_string(), 2);
+        let mut fs = FixtureState::new();
+        // 0x8040 / 0xffff: a value only 16 bits can land on.
+        fs.set_channel(
+            "pan".to_string(),
+            ChannelState::new(
+                0x8040 as f64 / 65535.0,
+                EffectLayer::Background,
+                BlendMode::Replace,
+            ),
+        );
+        fs.set_channel(
+            "tilt".to_string(),
+            ChannelState::new(0.5, EffectLayer::Background, BlendMode::Replace),
+        );
+        let mut cmds: Vec<(u16, u8)> = fs
+            .to_dmx_commands(&fixture)
+            .into_iter()
+            .map(|c| (c.channel, c.value))
+            .collect();
+        cmds.sort();
+        // Coarse at 10, fine at 11; tilt has no fine byte and stays 8-bit.
+        assert_eq!(cmds, vec![(10, 0x80), (11, 0x40), (12, 127)]);
+    }
+
+    #[test]
+    fn position_channels() {
+        assert!(is_position_channel("pan"));
+        assert!(is_position_channel("tilt"));
+        assert!(!is_position_channel("dimmer"));
+        assert!(!is_position_channel("pan_fine"));
+    }
 }
@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::time::Duration;
 
 use super::color::Color;
+use super::movement::MovementShape;
 use super::tempo_aware::{TempoAwareFrequency, TempoAwareSpeed};
 
 /// Core effect types for lighting.
@@ -75,6 +76,12 @@ pub enum EffectType {
         frequency: TempoAwareFrequency, // Hz (can be tempo-aware)
         duration: Duration,
     },
+
+    /// Pan/tilt movement authored in degrees
+    Movement {
+        shape: MovementShape,
+        duration: Duration,
+    },
 }
 
 impl EffectType {
@@ -87,7 +94,8 @@ impl EffectType {
             | EffectType::Dimmer { duration, .. }
             | EffectType::ColorCycle { duration, .. }
             | EffectType::Chase { duration, .. }
-            | EffectType::Rainbow { duration, .. } => *duration,
+            | EffectType::Rainbow { duration, .. }
+            | EffectType::Movement { duration, .. } => *duration,
         }
     }
 
@@ -165,6 +173,9 @@ impl EffectType {
                 out.insert("pulse_amplitude".to_string(), format!("{pulse_amplitude}"));
                 out.insert("frequency".to_string(), format!("{frequency:?}"));
             }
+            EffectType::Movement { shape, .. } => {
+                out.insert("shape".to_string(), format!("{shape:?}"));
+            }
         }
         out
     }
@@ -180,6 +191,7 @@ impl EffectType {
             EffectType::Chase { .. } => "Chase",
             EffectType::Rainbow { .. } => "Rainbow",
             EffectType::Pulse { .. } => "Pulse",
+            EffectType::Movement { .. } => "Movement",
         }
     }
 }
@@ -332,6 +344,19 @@ mod tests {
         assert_eq!(effect.duration(), Duration::from_secs(8));
     }
 
+    #[test]
+    fn effect_type_movement_duration() {
+        let effect = EffectType::Movement {
+            shape: MovementShape::Position {
+                pan: 0.0,
+                tilt: 45.0,
+            },
+            duration: Duration::from_secs(4),
+        };
+        assert_eq!(effect.duration(), Duration::from_secs(4));
+        assert_eq!(effect.name(), "Movement");
+    }
+
     #[test]
     fn effect_layer_ordering() {
         assert!(EffectLayer::Background < EffectLayer::Midground);
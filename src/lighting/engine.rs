@@ -14,6 +14,7 @@
 
 mod layers;
 mod processing;
+mod slew;
 mod validation;
 
 #[cfg(test)]
@@ -27,7 +28,7 @@ use std::sync::atomic::{AtomicU64, Ordering};
 
 use super::effects::*;
 use super::tempo::TempoMap;
-use tracing::debug;
+use tracing::{debug, warn};
 
 use crate::dmx::midi_dmx_store::MidiDmxStore;
 
@@ -173,6 +174,10 @@ pub struct EffectEngine {
     /// Reference to the MIDI DMX store for reading interpolated values each frame.
     midi_dmx_store: Option<Arc<parking_lot::RwLock<MidiDmxStore>>>,
     cache: EffectCache,
+    /// Holds pan/tilt to what each fixture's motors can follow.
+    slew: slew::SlewLimiter,
+    /// The venue's named focus points, in stage coordinates.
+    focus_points: HashMap<String, [f64; 3]>,
     /// Sub-phase indicator for update() progress.
     update_subphase: Arc<AtomicU64>,
 }
@@ -196,6 +201,8 @@ impl EffectEngine {
             last_song_time: None,
             midi_dmx_store: None,
             cache: EffectCache::new(),
+            slew: slew::SlewLimiter::default(),
+            focus_points: HashMap::new(),
             update_subphase: Arc::new(AtomicU64::new(0)),
         }
     }
@@ -218,6 +225,57 @@ impl EffectEngine {
         self.tempo_map = tempo_map;
     }
 
+    /// Sets the venue's named focus points, which `focus:` movement effects
+    /// aim at.
+    pub fn set_focus_points(&mut self, focus_points: HashMap<String, [f64; 3]>) {
+        self.focus_points = focus_points;
+        self.cache.invalidate();
+    }
+
+    /// Says, once as an effect starts, where a focus cannot be aimed as
+    /// written: a point the venue does not name, a fixture the venue does not
+    /// place, or a target out of a fixture's reach, which is clamped.
+    fn warn_about_focus(&self, effect: &EffectInstance) {
+        let EffectType::Movement {
+            shape: MovementShape::Focus { point },
+            ..
+        } = &effect.effect_type
+        else {
+            return;
+        };
+        let Some(&target) = self.focus_points.get(point) else {
+            warn!(
+                effect_id = %effect.id,
+                focus = point.as_str(),
+                "The venue has no focus point \"{point}\"; the fixtures are left where they are"
+            );
+            return;
+        };
+        for fixture in effect
+            .target_fixtures
+            .iter()
+            .filter_map(|name| self.fixtures.get(name))
+        {
+            match fixture.aim_at(target) {
+                None if fixture.position.is_none() => warn!(
+                    effect_id = %effect.id,
+                    fixture = fixture.name.as_str(),
+                    "The venue gives {} no position, so it cannot aim at \"{point}\"",
+                    fixture.name
+                ),
+                Some(aim) if aim.clamped => warn!(
+                    effect_id = %effect.id,
+                    fixture = fixture.name.as_str(),
+                    pan = aim.pan,
+                    tilt = aim.tilt,
+                    "\"{point}\" is out of {}'s reach; clamped to the nearest it can point",
+                    fixture.name
+                ),
+                _ => {}
+            }
+        }
+    }
+
     /// Returns whether a tempo map is currently set
     #[cfg(test)]
     pub fn has_tempo_map(&self) -> bool {
@@ -302,6 +360,10 @@ impl EffectEngine {
                     base_level, pulse_amplitude, frequency, duration
                 ),
             ),
+            EffectType::Movement { shape, duration } => (
+                "Movement",
+                format!("shape={:?}, duration={:?}", shape, duration),
+            ),
         }
     }
 
@@ -333,6 +395,7 @@ impl EffectEngine {
     pub fn start_effect(&mut self, mut effect: EffectInstance) -> Result<(), EffectError> {
         // Validate effect
         validation::validate_effect(self.fixtures.as_map(), &effect)?;
+        self.warn_about_focus(&effect);
 
         // Log effect parameters
         let (effect_kind, effect_params) = Self::format_effect_for_logging(&effect);
@@ -366,6 +429,7 @@ impl EffectEngine {
     ) -> Result<(), EffectError> {
         // Validate effect
         validation::validate_effect(self.fixtures.as_map(), &effect)?;
+        self.warn_about_focus(&effect);
 
         // Log effect parameters
         let (effect_kind, effect_params) = Self::format_effect_for_logging(&effect);
@@ -613,6 +677,7 @@ impl EffectEngine {
                 // Process the effect and get fixture states
                 if let Some(mut effect_states) = processing::process_effect(
                     self.fixtures.as_map(),
+                    &self.focus_points,
                     effect,
                     elapsed,
                     absolute_time,
@@ -621,8 +686,10 @@ impl EffectEngine {
                     // Apply the layer intensity master to effect states if not 1.0
                     if (layer_intensity - 1.0).abs() > f64::EPSILON {
                         for fixture_state in effect_states.values_mut() {
-                            for channel_state in fixture_state.channels.values_mut() {
-                                channel_state.value *= layer_intensity;
+                            for (channel_name, channel_state) in &mut fixture_state.channels {
+                                if !is_position_channel(channel_name) {
+                                    channel_state.value *= layer_intensity;
+                                }
                             }
                         }
                     }
@@ -663,7 +730,11 @@ impl EffectEngine {
 
         self.update_subphase.store(70, Ordering::Relaxed);
 
-        // Use current frame states directly — no persistent state merge needed
+        // Use current frame states directly — no persistent state merge needed,
+        // except where the heads themselves are: pan/tilt can only change as
+        // fast as the fixture moves.
+        self.slew
+            .apply(&mut current_fixture_states, self.fixtures.as_map(), dt);
 
         self.update_subphase.store(80, Ordering::Relaxed);
 
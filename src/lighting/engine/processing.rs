@@ -134,6 +134,7 @@ fn calculate_color_indices(
 /// Process a single effect and return fixture states
 pub(crate) fn process_effect(
     fixture_registry: &HashMap<String, FixtureInfo>,
+    focus_points: &HashMap<String, [f64; 3]>,
     effect: &EffectInstance,
     elapsed: Duration,
     engine_elapsed: Duration,
@@ -236,7 +237,74 @@ pub(crate) fn process_effect(
                 elapsed,
             )
         }
+        EffectType::Movement { shape, .. } => {
+            let current_speed = shape
+                .speed()
+                .map(|speed| speed.to_cycles_per_second(tempo_map, absolute_time))
+                .unwrap_or(0.0);
+            apply_movement(
+                fixture_registry,
+                focus_points,
+                effect,
+                shape,
+                current_speed,
+                elapsed,
+            )
+        }
+    }
+}
+
+/// Apply a movement effect and return fixture states.
+///
+/// The shape is authored in degrees and resolved per fixture through each
+/// channel's degree range, so a fixture without one is left alone rather
+/// than guessed at. Positions are not faded or blended: the head travels at
+/// its own pace, which the engine's slew limiter models. A focus the venue
+/// does not name, or a fixture the venue does not place, is left alone too;
+/// the engine says so when the effect starts.
+fn apply_movement(
+    fixture_registry: &HashMap<String, FixtureInfo>,
+    focus_points: &HashMap<String, [f64; 3]>,
+    effect: &EffectInstance,
+    shape: &MovementShape,
+    speed: f64,
+    elapsed: Duration,
+) -> Result<Option<HashMap<String, FixtureState>>, EffectError> {
+    let cycles = elapsed.as_secs_f64() * speed;
+    let count = effect.target_fixtures.len();
+
+    let mut fixture_states = HashMap::new();
+    for (index, fixture_name) in effect.target_fixtures.iter().enumerate() {
+        let Some(fixture) = fixture_registry.get(fixture_name) else {
+            continue;
+        };
+        let target = match shape {
+            MovementShape::Focus { point } => focus_points
+                .get(point)
+                .and_then(|&target| fixture.aim_at(target))
+                .map(|aim| (aim.pan, aim.tilt)),
+            _ => shape.target(index, count, cycles),
+        };
+        let Some((pan, tilt)) = target else {
+            continue;
+        };
+        let channels =
+            [("pan", pan), ("tilt", tilt)]
+                .into_iter()
+                .filter_map(|(channel, degrees)| {
+                    let value = fixture.degrees_to_value(channel, degrees)?;
+                    Some((
+                        channel.to_string(),
+                        ChannelState::new(value, effect.layer, BlendMode::Replace),
+                    ))
+                });
+        let state = FixtureState::from_channels(channels);
+        if !state.channels.is_empty() {
+            fixture_states.insert(fixture_name.clone(), state);
+        }
     }
+
+    Ok(Some(fixture_states))
 }
 
 /// Apply a static effect and return fixture states
@@ -986,8 +1054,15 @@ mod tests {
             None,
         );
         effect.enabled = false;
-        let result =
-            process_effect(&registry, &effect, Duration::ZERO, Duration::ZERO, None).unwrap();
+        let result = process_effect(
+            &registry,
+            &HashMap::new(),
+            &effect,
+            Duration::ZERO,
+            Duration::ZERO,
+            None,
+        )
+        .unwrap();
         assert!(result.is_none());
     }
 
@@ -1048,4 +1123,134 @@ mod tests {
         assert_eq!(states.len(), 1);
         assert!(states.contains_key("test_fix"));
     }
+
+    // ── apply_movement ───────────────────────────────────────────
+
+    fn mover(name: &str, pan_range: Option<(f64, f64)>) -> FixtureInfo {
+        let mut channels = HashMap::new();
+        channels.insert("pan".to_string(), 1u16);
+        channels.insert("tilt".to_string(), 3u16);
+        let mut fixture =
+            FixtureInfo::new(name.to_string(), 1, 1, "mover".to_string(), channels, None);
+        if let Some((from, to)) = pan_range {
+            fixture.physical_ranges.insert(
+                "pan".to_string(),
+                crate::lighting::types::PhysicalRange {
+                    from,
+                    to,
+                    unit: crate::lighting::types::PhysicalUnit::Degrees,
+                },
+            );
+        }
+        fixture
+    }
+
+    fn movement_effect(shape: MovementShape, fixtures: &[&str]) -> EffectInstance {
+        EffectInstance::new(
+            "move".to_string(),
+            EffectType::Movement {
+                shape,
+                duration: Duration::from_secs(10),
+            },
+            fixtures.iter().map(|f| f.to_string()).collect(),
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn movement_resolves_degrees_per_fixture() {
+        // The same angle lands on different values on fixtures with
+        // different ranges; a fixture without a range is left alone.
+        let mut registry = HashMap::new();
+        registry.insert("wide".to_string(), mover("wide", Some((-270.0, 270.0))));
+        registry.insert("narrow".to_string(), mover("narrow", Some((-90.0, 90.0))));
+        registry.insert("unknown".to_string(), mover("unknown", None));
+
+        let effect = movement_effect(
+            MovementShape::Position {
+                pan: 45.0,
+                tilt: 0.0,
+            },
+            &["wide", "narrow", "unknown"],
+        );
+        let states = process_effect(
+            &registry,
+            &HashMap::new(),
+            &effect,
+            Duration::ZERO,
+            Duration::ZERO,
+            None,
+        )
+        .unwrap()
+        .unwrap();
+
+        let pan = |name: &str| states[name].channels["pan"].value;
+        assert!((pan("wide") - 315.0 / 540.0).abs() < 1e-9);
+        assert!((pan("narrow") - 0.75).abs() < 1e-9);
+        assert!(!states.contains_key("unknown"));
+        // No tilt range anywhere, so no tilt channel is driven.
+        assert!(!states["wide"].channels.contains_key("tilt"));
+    }
+
+    #[test]
+    fn movement_fans_in_target_order() {
+        let mut registry = HashMap::new();
+        for name in ["a", "b", "c"] {
+            registry.insert(name.to_string(), mover(name, Some((-180.0, 180.0))));
+        }
+        let effect = movement_effect(
+            MovementShape::Fan {
+                pan: 0.0,
+                tilt: 0.0,
+                pan_spread: 180.0,
+                tilt_spread: 0.0,
+            },
+            &["a", "b", "c"],
+        );
+        let states = process_effect(
+            &registry,
+            &HashMap::new(),
+            &effect,
+            Duration::ZERO,
+            Duration::ZERO,
+            None,
+        )
+        .unwrap()
+        .unwrap();
+        let pan = |name: &str| states[name].channels["pan"].value;
+        assert!((pan("a") - 0.25).abs() < 1e-9);
+        assert!((pan("b") - 0.5).abs() < 1e-9);
+        assert!((pan("c") - 0.75).abs() < 1e-9);
+    }
+
+    #[test]
+    fn movement_sweep_follows_speed() {
+        let mut registry = HashMap::new();
+        registry.insert("m".to_string(), mover("m", Some((-180.0, 180.0))));
+        let effect = movement_effect(
+            MovementShape::Sweep {
+                pattern: SweepPattern::Circle,
+                pan: 0.0,
+                tilt: 0.0,
+                pan_radius: 90.0,
+                tilt_radius: 0.0,
+                speed: TempoAwareSpeed::Fixed(0.5),
+            },
+            &["m"],
+        );
+        // Half a cycle in at 0.5 cycles/s: the far side of the circle.
+        let states = process_effect(
+            &registry,
+            &HashMap::new(),
+            &effect,
+            Duration::from_secs(1),
+            Duration::from_secs(1),
+            None,
+        )
+        .unwrap()
+        .unwrap();
+        assert!((states["m"].channels["pan"].value - 0.25).abs() < 1e-9);
+    }
 }
@@ -0,0 +1,155 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use super::super::effects::{FixtureInfo, FixtureState};
+
+/// The position channels a slew limit applies to, in the order their last
+/// values are stored.
+const AXES: [&str; 2] = ["pan", "tilt"];
+
+/// Limits how fast pan and tilt may change to what each fixture can do.
+///
+/// Effects are stateless frame to frame; a moving head is not. It is still
+/// wherever the last frame sent it, and a target further away than its
+/// motors cover in one frame is approached at the fixture's configured
+/// speed rather than commanded outright. The first frame a fixture is seen
+/// has nothing to limit against and passes through unchanged.
+#[derive(Default)]
+pub(crate) struct SlewLimiter {
+    /// Last commanded normalized value per fixture, per axis.
+    last: HashMap<String, [Option<f64>; 2]>,
+}
+
+impl SlewLimiter {
+    /// Limits the position channels in `states` in place, `dt` after the
+    /// previous call.
+    pub(crate) fn apply(
+        &mut self,
+        states: &mut HashMap<String, FixtureState>,
+        fixture_registry: &HashMap<String, FixtureInfo>,
+        dt: Duration,
+    ) {
+        for (fixture_name, state) in states.iter_mut() {
+            let Some(fixture) = fixture_registry.get(fixture_name) else {
+                continue;
+            };
+            if fixture.movement.is_empty() {
+                continue;
+            }
+            if !self.last.contains_key(fixture_name) {
+                self.last.insert(fixture_name.clone(), [None; 2]);
+            }
+            let last = self.last.get_mut(fixture_name).expect("inserted above");
+
+            for (axis, channel) in AXES.iter().enumerate() {
+                let Some(channel_state) = state.channels.get_mut(*channel) else {
+                    continue;
+                };
+                let max_step = fixture
+                    .max_speed(channel)
+                    .zip(fixture.degree_range(channel))
+                    .map(|(speed, range)| speed * dt.as_secs_f64() / (range.to - range.from).abs());
+                if let (Some(previous), Some(max_step)) = (last[axis], max_step) {
+                    channel_state.value =
+                        previous + (channel_state.value - previous).clamp(-max_step, max_step);
+                }
+                last[axis] = Some(channel_state.value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lighting::effects::{BlendMode, ChannelState, EffectLayer};
+    use crate::lighting::types::{MovementLimits, PhysicalRange, PhysicalUnit};
+
+    fn registry(max_pan_speed: Option<f64>) -> HashMap<String, FixtureInfo> {
+        let mut channels = HashMap::new();
+        channels.insert("pan".to_string(), 1u16);
+        let mut fixture =
+            FixtureInfo::new("m".to_string(), 1, 1, "mover".to_string(), channels, None);
+        // 360° of pan: one degree is 1/360 of the range.
+        fixture.physical_ranges.insert(
+            "pan".to_string(),
+            PhysicalRange {
+                from: -180.0,
+                to: 180.0,
+                unit: PhysicalUnit::Degrees,
+            },
+        );
+        fixture.movement = MovementLimits {
+            max_pan_speed,
+            max_tilt_speed: None,
+        };
+        HashMap::from([("m".to_string(), fixture)])
+    }
+
+    fn pan_at(value: f64) -> HashMap<String, FixtureState> {
+        let state = FixtureState::from_channels([(
+            "pan".to_string(),
+            ChannelState::new(value, EffectLayer::Background, BlendMode::Replace),
+        )]);
+        HashMap::from([("m".to_string(), state)])
+    }
+
+    fn run(limiter: &mut SlewLimiter, registry: &HashMap<String, FixtureInfo>, target: f64) -> f64 {
+        let mut states = pan_at(target);
+        limiter.apply(&mut states, registry, Duration::from_millis(100));
+        states["m"].channels["pan"].value
+    }
+
+    #[test]
+    fn first_frame_passes_through() {
+        let registry = registry(Some(90.0));
+        let mut limiter = SlewLimiter::default();
+        assert_eq!(run(&mut limiter, &registry, 0.75), 0.75);
+    }
+
+    #[test]
+    fn large_moves_are_limited_to_the_fixture_speed() {
+        // 90°/s over 100 ms is 9°, i.e. 9/360 of the range per frame.
+        let registry = registry(Some(90.0));
+        let mut limiter = SlewLimiter::default();
+        run(&mut limiter, &registry, 0.5);
+        let step = 9.0 / 360.0;
+        let first = run(&mut limiter, &registry, 1.0);
+        assert!((first - (0.5 + step)).abs() < 1e-9);
+        let second = run(&mut limiter, &registry, 1.0);
+        assert!((second - (0.5 + 2.0 * step)).abs() < 1e-9);
+        // And the same the other way.
+        let back = run(&mut limiter, &registry, 0.0);
+        assert!((back - (0.5 + step)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn small_moves_arrive_in_one_frame() {
+        let registry = registry(Some(90.0));
+        let mut limiter = SlewLimiter::default();
+        run(&mut limiter, &registry, 0.5);
+        assert_eq!(run(&mut limiter, &registry, 0.51), 0.51);
+    }
+
+    #[test]
+    fn fixtures_without_limits_are_not_limited() {
+        let registry = registry(None);
+        let mut limiter = SlewLimiter::default();
+        run(&mut limiter, &registry, 0.0);
+        assert_eq!(run(&mut limiter, &registry, 1.0), 1.0);
+    }
+}
@@ -33,6 +33,8 @@ mod formatting_tests;
 #[cfg(test)]
 mod layer_commands_tests;
 #[cfg(test)]
+mod movement_tests;
+#[cfg(test)]
 mod pulse_tests;
 #[cfg(test)]
 mod rainbow_tests;
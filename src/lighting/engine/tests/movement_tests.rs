@@ -0,0 +1,215 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+use crate::lighting::effects::*;
+use crate::lighting::engine::EffectEngine;
+use crate::lighting::types::{MovementLimits, PhysicalRange, PhysicalUnit};
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// A mover at address 1: 16-bit pan on 1/2 over -270..270°, 8-bit tilt on 3
+/// over -135..135°, and a dimmer on 4.
+fn create_mover(name: &str, max_pan_speed: Option<f64>) -> FixtureInfo {
+    let mut channels = HashMap::new();
+    channels.insert("pan".to_string(), 1);
+    channels.insert("pan_fine".to_string(), 2);
+    channels.insert("tilt".to_string(), 3);
+    channels.insert("dimmer".to_string(), 4);
+    let mut fixture = FixtureInfo::new(
+        name.to_string(),
+        1,
+        1,
+        "MovingHead".to_string(),
+        channels,
+        None,
+    );
+    fixture.fine_channels.insert("pan".to_string(), 2);
+    for (channel, half) in [("pan", 270.0), ("tilt", 135.0)] {
+        fixture.physical_ranges.insert(
+            channel.to_string(),
+            PhysicalRange {
+                from: -half,
+                to: half,
+                unit: PhysicalUnit::Degrees,
+            },
+        );
+    }
+    fixture.movement = MovementLimits {
+        max_pan_speed,
+        max_tilt_speed: None,
+    };
+    fixture
+}
+
+fn position(pan: f64, tilt: f64) -> EffectInstance {
+    EffectInstance::new(
+        format!("position_{pan}_{tilt}"),
+        EffectType::Movement {
+            shape: MovementShape::Position { pan, tilt },
+            duration: Duration::from_secs(10),
+        },
+        vec!["mover".to_string()],
+        None,
+        None,
+        None,
+    )
+}
+
+fn channel(commands: &[DmxCommand], channel: u16) -> Option<u8> {
+    commands
+        .iter()
+        .find(|cmd| cmd.channel == channel)
+        .map(|cmd| cmd.value)
+}
+
+#[test]
+fn test_position_writes_coarse_and_fine() {
+    let mut engine = EffectEngine::new();
+    engine.register_fixture(create_mover("mover", None));
+    // 45° of a 540° range: 315/540 of full scale = 0x9555 in 16 bits.
+    engine.start_effect(position(45.0, 0.0)).unwrap();
+
+    let commands = engine.update(Duration::from_millis(0), None).unwrap();
+    let wide = (315.0 / 540.0 * 65535.0_f64).round() as u16;
+    assert_eq!(channel(commands, 1), Some((wide >> 8) as u8));
+    assert_eq!(channel(commands, 2), Some((wide & 0xff) as u8));
+    // Tilt is 8-bit: center of the range.
+    assert_eq!(channel(commands, 3), Some(127));
+    // Nothing else is driven.
+    assert_eq!(channel(commands, 4), None);
+}
+
+#[test]
+fn test_slew_limits_pan_to_the_fixture_speed() {
+    let mut engine = EffectEngine::new();
+    // 54°/s is a tenth of the pan range per second.
+    engine.register_fixture(create_mover("mover", Some(54.0)));
+    engine.start_effect(position(-270.0, 0.0)).unwrap();
+    let commands = engine.update(Duration::from_millis(0), None).unwrap();
+    assert_eq!(channel(commands, 1), Some(0));
+
+    // Retarget to the far end: one second in, the head is a tenth of the way.
+    engine.stop_all_effects();
+    engine.start_effect(position(270.0, 0.0)).unwrap();
+    let commands = engine.update(Duration::from_secs(1), None).unwrap();
+    let wide = (0.1 * 65535.0_f64).round() as u16;
+    assert_eq!(channel(commands, 1), Some((wide >> 8) as u8));
+    assert_eq!(channel(commands, 2), Some((wide & 0xff) as u8));
+
+    // Tilt has no limit and arrives at once.
+    assert_eq!(channel(commands, 3), Some(127));
+}
+
+#[test]
+fn test_layer_intensity_does_not_move_heads() {
+    let mut engine = EffectEngine::new();
+    engine.register_fixture(create_mover("mover", None));
+    engine.set_layer_intensity_master(EffectLayer::Background, 0.5);
+    engine.start_effect(position(0.0, 0.0)).unwrap();
+
+    let commands = engine.update(Duration::from_millis(0), None).unwrap();
+    assert_eq!(channel(commands, 1), Some(0x80));
+    assert_eq!(channel(commands, 3), Some(127));
+}
+
+fn focus(point: &str, targets: &[&str]) -> EffectInstance {
+    EffectInstance::new(
+        format!("focus_{point}"),
+        EffectType::Movement {
+            shape: MovementShape::Focus {
+                point: point.to_string(),
+            },
+            duration: Duration::from_secs(10),
+        },
+        targets.iter().map(|t| t.to_string()).collect(),
+        None,
+        None,
+        None,
+    )
+}
+
+/// A mover hung at `position`, patched at `address`.
+fn hung_mover(name: &str, address: u16, position: [f64; 3]) -> FixtureInfo {
+    let mut fixture = create_mover(name, None);
+    fixture.address = address;
+    fixture.position = Some(position);
+    fixture
+}
+
+/// The (pan, tilt) in degrees a mover at `address` is commanded to.
+fn commanded_aim(commands: &[DmxCommand], address: u16) -> (f64, f64) {
+    let wide = u16::from_be_bytes([
+        channel(commands, address).unwrap(),
+        channel(commands, address + 1).unwrap(),
+    ]);
+    let tilt = channel(commands, address + 2).unwrap();
+    (
+        -270.0 + 540.0 * f64::from(wide) / 65535.0,
+        -135.0 + 270.0 * f64::from(tilt) / 255.0,
+    )
+}
+
+#[test]
+fn test_focus_aims_each_mover_from_where_it_hangs() {
+    let mut engine = EffectEngine::new();
+    engine.register_fixture(hung_mover("left", 1, [-3.0, 0.0, 5.0]));
+    engine.register_fixture(hung_mover("right", 11, [3.0, 0.0, 5.0]));
+    let drummer = [0.5, 4.0, 1.2];
+    engine.set_focus_points(HashMap::from([("drummer".to_string(), drummer)]));
+    engine
+        .start_effect(focus("drummer", &["left", "right"]))
+        .unwrap();
+
+    let commands = engine.update(Duration::from_millis(0), None).unwrap();
+    for (address, position) in [(1, [-3.0, 0.0, 5.0]), (11, [3.0, 0.0, 5.0])] {
+        let (pan, tilt) = commanded_aim(commands, address);
+        let beam = beam_direction([0.0; 3], pan, tilt);
+        let offset: Vec<f64> = (0..3).map(|i| drummer[i] - position[i]).collect();
+        let length = offset.iter().map(|c| c * c).sum::<f64>().sqrt();
+        // Within the resolution of the 8-bit tilt channel.
+        for axis in 0..3 {
+            assert!(
+                (beam[axis] - offset[axis] / length).abs() < 0.02,
+                "mover at {address} points {beam:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_focus_out_of_reach_clamps() {
+    let mut engine = EffectEngine::new();
+    engine.register_fixture(hung_mover("mover", 1, [0.0, 0.0, 5.0]));
+    // Straight up from a hung head: 180° of tilt on a 270° head.
+    engine.set_focus_points(HashMap::from([("sky".to_string(), [0.0, 0.0, 20.0])]));
+    engine.start_effect(focus("sky", &["mover"])).unwrap();
+
+    let commands = engine.update(Duration::from_millis(0), None).unwrap();
+    let tilt = channel(commands, 3).unwrap();
+    assert!(
+        tilt == 0 || tilt == 255,
+        "tilt {tilt} is not at an end stop"
+    );
+}
+
+#[test]
+fn test_focus_on_an_unknown_point_leaves_movers_alone() {
+    let mut engine = EffectEngine::new();
+    engine.register_fixture(hung_mover("mover", 1, [0.0, 0.0, 5.0]));
+    engine.start_effect(focus("nowhere", &["mover"])).unwrap();
+
+    let commands = engine.update(Duration::from_millis(0), None).unwrap();
+    assert_eq!(channel(commands, 1), None);
+    assert_eq!(channel(commands, 3), None);
+}
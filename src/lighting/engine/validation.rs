@@ -194,6 +194,16 @@ pub(crate) fn validate_effect_compatibility(
                         )));
                     }
                 }
+                EffectType::Movement { .. } => {
+                    if !fixture_info.has_capability(FixtureCapabilities::PANNING)
+                        && !fixture_info.has_capability(FixtureCapabilities::TILTING)
+                    {
+                        return Err(EffectError::Parameter(format!(
+                            "Movement effect not compatible with fixture '{}' (no pan or tilt capability)",
+                            fixture_name
+                        )));
+                    }
+                }
                 _ => {} // Other effects are generally compatible
             }
         }
@@ -566,6 +576,26 @@ mod tests {
         );
     }
 
+    // ── Movement needs pan or tilt ───────────────────────────────────
+
+    #[test]
+    fn movement_requires_pan_or_tilt() {
+        let mover = make_fixture("m1", "Mover", vec![("pan", 1), ("tilt", 3)]);
+        let registry = registry_with(vec![mover, rgb_fixture("par1")]);
+        let movement = EffectType::Movement {
+            shape: crate::lighting::effects::MovementShape::Position {
+                pan: 0.0,
+                tilt: 45.0,
+            },
+            duration: Duration::from_secs(10),
+        };
+        let ok = make_effect_instance(movement.clone(), vec!["m1"]);
+        assert!(validate_effect_compatibility(&registry, &ok).is_ok());
+        let err = make_effect_instance(movement, vec!["par1"]);
+        let err = validate_effect_compatibility(&registry, &err).unwrap_err();
+        assert!(err.to_string().contains("Movement effect not compatible"));
+    }
+
     // ── Rainbow with non-RGB fixture ─────────────────────────────────
 
     #[test]
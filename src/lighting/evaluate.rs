@@ -96,6 +96,7 @@ impl Evaluation {
 pub fn evaluate_show<F>(
     shows: Vec<LightShow>,
     fixtures: &[FixtureInfo],
+    focus_points: &HashMap<String, [f64; 3]>,
     fallback_tempo_map: Option<&TempoMap>,
     times: &[Duration],
     mut resolve_groups: F,
@@ -123,6 +124,7 @@ where
         .map(|&time| {
             let mut engine = EffectEngine::new();
             engine.set_tempo_map(tempo_map.clone());
+            engine.set_focus_points(focus_points.clone());
             for fixture in fixtures {
                 engine.register_fixture(fixture.clone());
             }
@@ -333,7 +335,14 @@ mod tests {
 
     fn eval(source: &str, fixtures: &[FixtureInfo], times: &[f64]) -> Vec<Evaluation> {
         let times: Vec<Duration> = times.iter().map(|&t| Duration::from_secs_f64(t)).collect();
-        evaluate_show(shows(source), fixtures, None, &times, |e| e)
+        evaluate_show(
+            shows(source),
+            fixtures,
+            &HashMap::new(),
+            None,
+            &times,
+            |e| e,
+        )
     }
 
     fn channel(evaluation: &Evaluation, fixture: &str, channel: &str) -> u8 {
@@ -467,18 +476,32 @@ show "T" {
 "#;
         let times = [Duration::from_secs(1)];
 
-        let unresolved = evaluate_show(shows(source), &fixtures, None, &times, |e| e);
+        let unresolved = evaluate_show(
+            shows(source),
+            &fixtures,
+            &HashMap::new(),
+            None,
+            &times,
+            |e| e,
+        );
         assert!(
             unresolved[0].is_dark() == Some(true),
             "an unresolved group name matches no fixture"
         );
 
-        let resolved = evaluate_show(shows(source), &fixtures, None, &times, |mut e| {
-            if e.target_fixtures == ["all_wash"] {
-                e.target_fixtures = vec!["brick1".to_string(), "brick2".to_string()];
-            }
-            e
-        });
+        let resolved = evaluate_show(
+            shows(source),
+            &fixtures,
+            &HashMap::new(),
+            None,
+            &times,
+            |mut e| {
+                if e.target_fixtures == ["all_wash"] {
+                    e.target_fixtures = vec!["brick1".to_string(), "brick2".to_string()];
+                }
+                e
+            },
+        );
         assert_eq!(channel(&resolved[0], "brick1", "green"), 255);
         assert_eq!(channel(&resolved[0], "brick2", "green"), 255);
         assert_eq!(resolved[0].active_effects[0].fixtures, ["brick1", "brick2"]);
@@ -534,7 +557,14 @@ show "T" {
             Duration::from_secs_f64(6.5),
             Duration::from_secs_f64(8.5),
         ];
-        let results = evaluate_show(shows(source), &fixtures, Some(&slow), &times, |e| e);
+        let results = evaluate_show(
+            shows(source),
+            &fixtures,
+            &HashMap::new(),
+            Some(&slow),
+            &times,
+            |e| e,
+        );
 
         assert_eq!(channel(&results[0], "wash", "blue"), 255);
         assert!(
@@ -650,7 +680,14 @@ show "T" {
         .collect();
 
         let stepped = play_through(source, &fixtures, &times);
-        let reconstructed = evaluate_show(shows(source), &fixtures, None, &times, |e| e);
+        let reconstructed = evaluate_show(
+            shows(source),
+            &fixtures,
+            &HashMap::new(),
+            None,
+            &times,
+            |e| e,
+        );
 
         for (i, time) in times.iter().enumerate() {
             let expected = &stepped[i];
@@ -40,9 +40,9 @@ pub(crate) use archive::tests::build_zip;
 #[cfg(test)]
 pub(crate) use description::tests::SYNTHETIC_DESCRIPTION;
 
-pub use archive::read_description_xml;
+pub use archive::{read_description_xml, Archive};
 pub use description::{parse_description, Description};
-pub use distiller::{distill, mode_summaries, Distilled, ModeSummary};
+pub use distiller::{distill, match_mode, mode_summaries, Distilled, ModeMatch, ModeSummary};
 
 use std::error::Error;
 use std::fmt;
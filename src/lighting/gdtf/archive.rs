@@ -12,14 +12,15 @@
 // this program. If not, see <https://www.gnu.org/licenses/>.
 //
 
-//! Hardened access to a GDTF archive's `description.xml`.
+//! Hardened access to the entries of a GDTF or MVR archive.
 //!
-//! Only the description is ever read — nothing is extracted to disk, so
-//! zip-slip has no surface here. What remains is decompression abuse, capped
-//! hard: a bounded entry count, a bounded decompressed size for the
-//! description, and a strict read that refuses an entry lying about its
-//! size. Asset entries (models, thumbnails) are ignored entirely until the
-//! phase that consumes them.
+//! Entries are only ever read into memory — nothing is extracted to disk, so
+//! zip-slip has no surface here, and symlink entries are refused outright.
+//! What remains is decompression abuse, capped hard: a bounded entry count,
+//! a bounded decompressed size per entry and across the whole read, and a
+//! strict read that refuses an entry lying about its size. Asset entries
+//! (models, thumbnails) are ignored entirely until the phase that consumes
+//! them.
 
 use std::io::{Cursor, Read};
 
@@ -27,15 +28,15 @@ use zip::ZipArchive;
 
 use super::GdtfError;
 
-/// The most entries a plausible GDTF archive holds. Real manufacturer files
+/// The most entries a plausible archive holds. Real manufacturer GDTF files
 /// carry a description, thumbnails, and a models tree — tens of entries;
-/// wheel-heavy fixtures a few hundred.
+/// wheel-heavy fixtures a few hundred. An MVR adds one GDTF per fixture type.
 const MAX_ARCHIVE_ENTRIES: usize = 4096;
 
-/// The largest decompressed `description.xml` accepted. Real descriptions
-/// run tens of KB to a few MB (the Astera PixelBrick's is 315 KB); the cap
-/// leaves an order of magnitude of headroom while keeping a zip bomb's
-/// petabyte claims un-decompressed.
+/// The largest decompressed XML entry accepted. Real descriptions run tens
+/// of KB to a few MB (the Astera PixelBrick's is 315 KB); the cap leaves an
+/// order of magnitude of headroom while keeping a zip bomb's petabyte claims
+/// un-decompressed.
 const MAX_DESCRIPTION_BYTES: u64 = 64 * 1024 * 1024;
 
 /// The largest whole archive accepted. Real manufacturer files run 1–10 MB
@@ -43,59 +44,107 @@ const MAX_DESCRIPTION_BYTES: u64 = 64 * 1024 * 1024;
 /// directory parse and everything after it.
 const MAX_ARCHIVE_BYTES: usize = 256 * 1024 * 1024;
 
-/// Reads `description.xml` out of a GDTF archive held in memory.
-pub fn read_description_xml(bytes: &[u8]) -> Result<String, GdtfError> {
-    if bytes.len() > MAX_ARCHIVE_BYTES {
-        return Err(GdtfError::new(format!(
-            "archive is {} bytes; refusing more than {MAX_ARCHIVE_BYTES}",
-            bytes.len()
-        )));
+/// The most bytes decompressed across every entry read from one archive, so
+/// an MVR can't reach a bomb through many entries each under its own cap.
+const MAX_TOTAL_READ_BYTES: u64 = MAX_ARCHIVE_BYTES as u64;
+
+/// A zip archive held in memory, read under the layer's caps.
+pub struct Archive<'a> {
+    /// The opened archive.
+    zip: ZipArchive<Cursor<&'a [u8]>>,
+    /// What the archive should be ("GDTF", "MVR"), for error messages.
+    kind: &'static str,
+    /// Bytes decompressed so far, against `MAX_TOTAL_READ_BYTES`.
+    read_total: u64,
+}
+
+impl<'a> Archive<'a> {
+    /// Opens an archive, refusing oversized files and entry floods.
+    pub fn open(bytes: &'a [u8], kind: &'static str) -> Result<Archive<'a>, GdtfError> {
+        if bytes.len() > MAX_ARCHIVE_BYTES {
+            return Err(GdtfError::new(format!(
+                "archive is {} bytes; refusing more than {MAX_ARCHIVE_BYTES}",
+                bytes.len()
+            )));
+        }
+        let zip = ZipArchive::new(Cursor::new(bytes))
+            .map_err(|e| GdtfError::new(format!("not a readable {kind} archive: {e}")))?;
+
+        if zip.len() > MAX_ARCHIVE_ENTRIES {
+            return Err(GdtfError::new(format!(
+                "archive has {} entries; refusing more than {MAX_ARCHIVE_ENTRIES}",
+                zip.len()
+            )));
+        }
+        Ok(Archive {
+            zip,
+            kind,
+            read_total: 0,
+        })
     }
-    let mut archive = ZipArchive::new(Cursor::new(bytes))
-        .map_err(|e| GdtfError::new(format!("not a readable GDTF archive: {e}")))?;
-
-    if archive.len() > MAX_ARCHIVE_ENTRIES {
-        return Err(GdtfError::new(format!(
-            "archive has {} entries; refusing more than {MAX_ARCHIVE_ENTRIES}",
-            archive.len()
-        )));
+
+    /// Returns true if the archive has an entry with exactly this name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.zip.index_for_name(name).is_some()
     }
 
-    let mut entry = archive.by_name("description.xml").map_err(|e| {
-        GdtfError::new(format!(
-            "archive has no description.xml ({e}); is this a GDTF file?"
-        ))
-    })?;
-
-    if entry.size() > MAX_DESCRIPTION_BYTES {
-        return Err(GdtfError::new(format!(
-            "description.xml claims {} bytes; refusing more than {MAX_DESCRIPTION_BYTES}",
-            entry.size()
-        )));
+    /// Reads one entry's bytes, refusing more than `max` decompressed bytes.
+    pub fn read(&mut self, name: &str, max: u64) -> Result<Vec<u8>, GdtfError> {
+        let kind = self.kind;
+        let mut entry = self.zip.by_name(name).map_err(|e| {
+            GdtfError::new(format!(
+                "archive has no {name} ({e}); every {kind} archive carries one"
+            ))
+        })?;
+        if entry.is_symlink() || entry.is_dir() {
+            return Err(GdtfError::new(format!(
+                "archive entry {name} is not a regular file"
+            )));
+        }
+
+        let max = max.min(MAX_TOTAL_READ_BYTES - self.read_total);
+        if entry.size() > max {
+            return Err(GdtfError::new(format!(
+                "{name} claims {} bytes; refusing more than {max}",
+                entry.size()
+            )));
+        }
+
+        // The claimed size is untrusted; read through a hard limit so an entry
+        // lying about its size can't decompress past the cap either.
+        let mut content = Vec::new();
+        entry
+            .by_ref()
+            .take(max + 1)
+            .read_to_end(&mut content)
+            .map_err(|e| GdtfError::new(format!("failed to read {name}: {e}")))?;
+        if content.len() as u64 > max {
+            return Err(GdtfError::new(format!(
+                "{name} decompressed past the {max}-byte cap"
+            )));
+        }
+        self.read_total += content.len() as u64;
+        Ok(content)
     }
 
-    // The claimed size is untrusted; read through a hard limit so an entry
-    // lying about its size can't decompress past the cap either.
-    let mut content = Vec::new();
-    entry
-        .by_ref()
-        .take(MAX_DESCRIPTION_BYTES + 1)
-        .read_to_end(&mut content)
-        .map_err(|e| GdtfError::new(format!("failed to read description.xml: {e}")))?;
-    if content.len() as u64 > MAX_DESCRIPTION_BYTES {
-        return Err(GdtfError::new(format!(
-            "description.xml decompressed past the {MAX_DESCRIPTION_BYTES}-byte cap"
-        )));
+    /// Reads one UTF-8 XML entry.
+    pub fn read_xml(&mut self, name: &str) -> Result<String, GdtfError> {
+        let content = self.read(name, MAX_DESCRIPTION_BYTES)?;
+
+        // GDTF and MVR XML is UTF-8. Some tools emit a BOM; strip it rather
+        // than letting it poison the first tag name.
+        let content = if content.starts_with(&[0xEF, 0xBB, 0xBF]) {
+            content[3..].to_vec()
+        } else {
+            content
+        };
+        String::from_utf8(content).map_err(|_| GdtfError::new(format!("{name} is not valid UTF-8")))
     }
+}
 
-    // GDTF descriptions are UTF-8. Some tools emit a BOM; strip it rather
-    // than letting it poison the first tag name.
-    let content = if content.starts_with(&[0xEF, 0xBB, 0xBF]) {
-        content[3..].to_vec()
-    } else {
-        content
-    };
-    String::from_utf8(content).map_err(|_| GdtfError::new("description.xml is not valid UTF-8"))
+/// Reads `description.xml` out of a GDTF archive held in memory.
+pub fn read_description_xml(bytes: &[u8]) -> Result<String, GdtfError> {
+    Archive::open(bytes, "GDTF")?.read_xml("description.xml")
 }
 
 #[cfg(test)]
@@ -155,6 +204,32 @@ pub(super) mod tests {
         assert!(err.contains("not valid UTF-8"), "{err}");
     }
 
+    #[test]
+    fn total_reads_are_capped_across_entries() {
+        let bytes = build_zip(&[
+            ("a.gdtf", [0u8; 64].as_slice()),
+            ("b.gdtf", [0u8; 64].as_slice()),
+        ]);
+        let mut archive = Archive::open(&bytes, "MVR").unwrap();
+        archive.read_total = MAX_TOTAL_READ_BYTES - 100;
+        archive.read("a.gdtf", u64::MAX).unwrap();
+        let err = archive.read("b.gdtf", u64::MAX).unwrap_err().to_string();
+        assert!(err.contains("refusing"), "{err}");
+    }
+
+    #[test]
+    fn missing_entries_name_the_expected_kind() {
+        let bytes = build_zip(&[("description.xml", b"<GDTF/>".as_slice())]);
+        let mut archive = Archive::open(&bytes, "MVR").unwrap();
+        assert!(archive.contains("description.xml"));
+        assert!(!archive.contains("GeneralSceneDescription.xml"));
+        let err = archive
+            .read_xml("GeneralSceneDescription.xml")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("every MVR archive carries one"), "{err}");
+    }
+
     #[test]
     fn oversized_description_is_rejected_by_claimed_size() {
         // A genuinely huge (but honest) entry is refused before decompression.
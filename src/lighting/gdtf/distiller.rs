@@ -25,6 +25,8 @@
 
 use std::collections::HashMap;
 
+use serde::Serialize;
+
 use super::description::{Channel, Description};
 use super::GdtfError;
 use crate::lighting::types::{
@@ -71,6 +73,81 @@ pub fn mode_summaries(description: &Description) -> Vec<ModeSummary> {
         .collect()
 }
 
+/// How a patched mode string was matched to one of a GDTF's modes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModeMatch {
+    /// The names are identical, as the MVR spec requires.
+    Exact,
+    /// The names agree once case, whitespace and punctuation are ignored.
+    Normalized,
+    /// Only one mode's footprint matches a channel count named in the string.
+    Footprint,
+}
+
+/// Resolves a mode string from a patch (an MVR `GDTFMode`) to one of the
+/// description's modes. Console exports drift from the GDTF's exact names
+/// (truncation, re-punctuation), so an exact match falls back to a
+/// normalized one, then to the unique mode whose DMX footprint matches a
+/// channel count in the string. Anything else is an error listing the
+/// candidates with their footprints.
+pub fn match_mode(
+    description: &Description,
+    requested: &str,
+) -> Result<(String, ModeMatch), GdtfError> {
+    if description.modes.iter().any(|m| m.name == requested) {
+        return Ok((requested.to_string(), ModeMatch::Exact));
+    }
+
+    let wanted = normalize_mode_name(requested);
+    let normalized: Vec<&str> = description
+        .modes
+        .iter()
+        .filter(|m| !wanted.is_empty() && normalize_mode_name(&m.name) == wanted)
+        .map(|m| m.name.as_str())
+        .collect();
+    if let [name] = normalized.as_slice() {
+        return Ok((name.to_string(), ModeMatch::Normalized));
+    }
+
+    let summaries = mode_summaries(description);
+    let counts = channel_counts(requested);
+    let by_footprint: Vec<&ModeSummary> = summaries
+        .iter()
+        .filter(|summary| counts.contains(&summary.footprint))
+        .collect();
+    if let [summary] = by_footprint.as_slice() {
+        return Ok((summary.name.clone(), ModeMatch::Footprint));
+    }
+
+    let candidates: Vec<String> = summaries
+        .iter()
+        .map(|summary| format!("\"{}\" ({} ch)", summary.name, summary.footprint))
+        .collect();
+    Err(GdtfError::new(format!(
+        "GDTF \"{}\" has no mode matching \"{requested}\"; available modes: {}",
+        description.name,
+        candidates.join(", ")
+    )))
+}
+
+/// A mode name with case, whitespace and punctuation dropped.
+fn normalize_mode_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// The plausible DMX channel counts named in a mode string ("16ch", "Mode 2
+/// (24 channels)"): every run of digits within a universe's size.
+fn channel_counts(name: &str) -> Vec<u16> {
+    name.split(|c: char| !c.is_ascii_digit())
+        .filter_map(|digits| digits.parse::<u16>().ok())
+        .filter(|count| (1..=512).contains(count))
+        .collect()
+}
+
 /// Distills the named mode into a fixture type called `type_name`.
 pub fn distill(
     description: &Description,
@@ -453,6 +530,35 @@ mod tests {
         assert_eq!(ft.channels().get("tilt"), Some(&3));
     }
 
+    #[test]
+    fn mode_matching_falls_back_from_exact_to_normalized_to_footprint() {
+        let description = parse_description(SYNTHETIC_DESCRIPTION).unwrap();
+        let matched = |requested: &str| match_mode(&description, requested);
+
+        assert_eq!(
+            matched("8: RGBS").unwrap(),
+            ("8: RGBS".to_string(), ModeMatch::Exact)
+        );
+        assert_eq!(
+            matched("8 rgbs").unwrap(),
+            ("8: RGBS".to_string(), ModeMatch::Normalized)
+        );
+        assert_eq!(
+            matched("mover-16BIT").unwrap(),
+            ("Mover 16bit".to_string(), ModeMatch::Normalized)
+        );
+        // Truncated past recognition, but only one mode spans five addresses.
+        assert_eq!(
+            matched("Mov 5ch").unwrap(),
+            ("Mover 16bit".to_string(), ModeMatch::Footprint)
+        );
+
+        let err = matched("Mode 1").unwrap_err().to_string();
+        assert!(err.contains("no mode matching \"Mode 1\""), "{err}");
+        assert!(err.contains("\"8: RGBS\" (4 ch)"), "{err}");
+        assert!(err.contains("\"Mover 16bit\" (5 ch)"), "{err}");
+    }
+
     #[test]
     fn unknown_mode_lists_the_candidates() {
         let description = parse_description(SYNTHETIC_DESCRIPTION).unwrap();
@@ -12,20 +12,27 @@
 // this program. If not, see <https://www.gnu.org/licenses/>.
 //
 
-//! GDTF import: the one implementation behind the CLI command and the MCP
-//! tool.
+//! GDTF and MVR import: the one implementation behind the CLI commands, the
+//! web API and the MCP tools.
 //!
 //! An import copies the archive into `<project>/lighting/library/`, writes a
 //! GDTF-referential `.fixture` definition, and warms the expansion cache
 //! through the same code path the player's loader takes — so a successful
 //! import is, by construction, a fixture type that will load.
+//!
+//! An MVR import runs that GDTF import once per referenced mode, then seeds
+//! an owned `.venue` file patching every scene fixture at its address and
+//! stage position. Fixtures it can't patch become `TODO` comments in the
+//! venue rather than disappearing.
 
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::path::{Path, PathBuf};
 
 use serde::Serialize;
 
 use super::gdtf;
+use super::mvr;
 use super::system::LightingSystem;
 
 /// What an import did, for reporting: files written, the channels the
@@ -220,6 +227,453 @@ pub fn import_gdtf_bytes(
     })
 }
 
+/// Where an MVR import puts mtrack's stage origin. MVR files carry an
+/// author-chosen origin, so every venue's file is offset differently.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MvrOrigin {
+    /// A point in the scene's own frame, in meters.
+    Point([f64; 3]),
+    /// The deck directly below the named fixture.
+    Fixture(String),
+}
+
+/// What an MVR import did: the venue written, the fixtures placed in it,
+/// the fixture types behind them, and everything that needs human input.
+#[derive(Debug, Serialize)]
+pub struct MvrImport {
+    /// The seeded venue's name.
+    pub venue_name: String,
+    /// The written `.venue` file, project-relative.
+    pub venue_file: String,
+    /// The scene point moved to the stage origin, in the scene's meters.
+    pub origin: [f64; 3],
+    /// The fixtures patched into the venue, by universe and address.
+    pub fixtures: Vec<MvrFixture>,
+    /// The fixture types the venue's fixtures use.
+    pub fixture_types: Vec<MvrFixtureType>,
+    /// Scene fixtures written as `TODO` comments instead of patched.
+    pub todos: Vec<MvrTodo>,
+    /// Import warnings — approximated modes, renamed fixtures, and so on.
+    pub warnings: Vec<String>,
+}
+
+/// One fixture patched into the seeded venue.
+#[derive(Debug, Serialize)]
+pub struct MvrFixture {
+    pub name: String,
+    pub fixture_type: String,
+    pub universe: u16,
+    pub address: u16,
+    /// Meters, in mtrack's stage convention.
+    pub position: [f64; 3],
+    /// Degrees about X, then Y, then Z.
+    pub rotation: [f64; 3],
+}
+
+/// A fixture type used by the seeded venue.
+#[derive(Debug, Serialize)]
+pub struct MvrFixtureType {
+    pub type_name: String,
+    /// The GDTF archive within the MVR.
+    pub gdtf: String,
+    /// The mode as the scene named it.
+    pub requested_mode: String,
+    /// The GDTF mode it resolved to.
+    pub mode: String,
+    /// How the requested mode was matched.
+    pub mode_match: gdtf::ModeMatch,
+    /// The `.fixture` definition, project-relative.
+    pub fixture_file: String,
+    /// The GDTF import, or none when an identical definition already existed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub import: Option<GdtfImport>,
+}
+
+/// A scene fixture that couldn't be patched, and why.
+#[derive(Debug, Serialize)]
+pub struct MvrTodo {
+    pub fixture: String,
+    pub reason: String,
+}
+
+/// Parses an MVR origin point written as "x,y,z" in meters.
+pub fn parse_origin_point(point: &str) -> Result<[f64; 3], Box<dyn Error>> {
+    let error = || format!("origin \"{point}\" is not \"x,y,z\" in meters");
+    let values: Vec<f64> = point
+        .split(',')
+        .map(|value| value.trim().parse::<f64>())
+        .collect::<Result<_, _>>()
+        .map_err(|_| error())?;
+    match values.as_slice() {
+        [x, y, z] => Ok([*x, *y, *z]),
+        _ => Err(error().into()),
+    }
+}
+
+/// Imports an MVR scene into a project: one fixture type per referenced
+/// GDTF mode, and a seeded `.venue` file patching every fixture at its
+/// address and position.
+pub fn import_mvr(
+    mvr_path: &Path,
+    name: Option<&str>,
+    origin: Option<&MvrOrigin>,
+    project: &Path,
+    fixture_types_dir: &str,
+    venues_dir: &str,
+) -> Result<MvrImport, Box<dyn Error>> {
+    let bytes = std::fs::read(mvr_path)
+        .map_err(|e| format!("cannot read MVR file {}: {e}", mvr_path.display()))?;
+    let file_name = mvr_path
+        .file_name()
+        .ok_or("MVR path has no file name")?
+        .to_string_lossy()
+        .into_owned();
+    import_mvr_bytes(
+        &bytes,
+        &file_name,
+        name,
+        origin,
+        project,
+        fixture_types_dir,
+        venues_dir,
+    )
+}
+
+/// [`import_mvr`] over in-memory bytes. `file_name` names the venue when no
+/// `name` is given.
+pub fn import_mvr_bytes(
+    bytes: &[u8],
+    file_name: &str,
+    name: Option<&str>,
+    origin: Option<&MvrOrigin>,
+    project: &Path,
+    fixture_types_dir: &str,
+    venues_dir: &str,
+) -> Result<MvrImport, Box<dyn Error>> {
+    let mut archive = gdtf::Archive::open(bytes, "MVR")?;
+    let scene = mvr::parse_archive(&mut archive)?;
+
+    let venue_name = venue_string(name.unwrap_or_else(|| {
+        Path::new(file_name)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("venue")
+    }));
+    let venue_dir = project.join(venues_dir);
+    let venue_path = venue_dir.join(format!("{}.venue", fixture_filename_stem(&venue_name)));
+    if venue_path.exists() {
+        return Err(format!(
+            "{} already exists; remove it or import with a different name",
+            venue_path.display()
+        )
+        .into());
+    }
+
+    let mut warnings = Vec::new();
+    let origin = match origin {
+        Some(MvrOrigin::Point(point)) => *point,
+        Some(MvrOrigin::Fixture(fixture)) => {
+            let found = scene
+                .fixtures
+                .iter()
+                .find(|f| f.name == *fixture)
+                .ok_or_else(|| {
+                    format!("the MVR has no fixture named \"{fixture}\" to use as the origin")
+                })?;
+            [found.position[0], found.position[1], 0.0]
+        }
+        None => {
+            warnings.push(
+                "no origin given: positions keep the MVR's own origin; re-import with an \
+                 origin at downstage-center for stage-relative positions"
+                    .to_string(),
+            );
+            [0.0; 3]
+        }
+    };
+
+    // Resolve every (GDTF, mode) the scene patches to a fixture type, once.
+    let mut types: Vec<MvrFixtureType> = Vec::new();
+    let mut resolved: HashMap<(String, String), Result<String, String>> = HashMap::new();
+    let mut embedded: HashMap<String, Result<EmbeddedGdtf, String>> = HashMap::new();
+    let mut fixtures = Vec::new();
+    let mut todos = Vec::new();
+    let mut names = HashSet::new();
+
+    for scene_fixture in &scene.fixtures {
+        let fixture_name = unique_name(&venue_string(&scene_fixture.name), &mut names);
+        if fixture_name != scene_fixture.name {
+            warnings.push(format!(
+                "fixture \"{}\" is named \"{fixture_name}\" in the venue",
+                scene_fixture.name
+            ));
+        }
+        let todo = |reason: String| MvrTodo {
+            fixture: fixture_name.clone(),
+            reason,
+        };
+
+        let Some((universe, address)) = scene_fixture.address else {
+            todos.push(todo("no DMX address in the MVR".to_string()));
+            continue;
+        };
+        let key = (
+            scene_fixture.gdtf_spec.clone(),
+            scene_fixture.gdtf_mode.clone(),
+        );
+        if !resolved.contains_key(&key) {
+            let gdtf = embedded
+                .entry(scene_fixture.gdtf_spec.clone())
+                .or_insert_with(|| read_embedded_gdtf(&mut archive, &scene_fixture.gdtf_spec));
+            let result = match gdtf {
+                Ok(gdtf) => resolve_fixture_type(
+                    gdtf,
+                    &scene_fixture.gdtf_mode,
+                    &mut types,
+                    &mut warnings,
+                    project,
+                    fixture_types_dir,
+                ),
+                Err(reason) => Err(reason.clone()),
+            };
+            resolved.insert(key.clone(), result);
+        }
+        match &resolved[&key] {
+            Ok(fixture_type) => fixtures.push(MvrFixture {
+                name: fixture_name,
+                fixture_type: fixture_type.clone(),
+                universe,
+                address,
+                position: std::array::from_fn(|axis| {
+                    round_mm(scene_fixture.position[axis] - origin[axis])
+                }),
+                rotation: scene_fixture.rotation,
+            }),
+            Err(reason) => todos.push(todo(reason.clone())),
+        }
+    }
+    fixtures.sort_by_key(|fixture| (fixture.universe, fixture.address));
+
+    let mut venue = format!(
+        "# Seeded from {file_name}. This venue is yours to edit: tags, focus\n\
+         # points and position corrections layer on the import.\n\
+         # mtrack stage convention: meters, right-handed Z-up, origin at\n\
+         # downstage-center on the deck · +x stage-left · +y upstage · +z up\n\
+         venue \"{venue_name}\" {{\n"
+    );
+    for fixture in &fixtures {
+        let [x, y, z] = fixture.position;
+        let [rx, ry, rz] = fixture.rotation;
+        venue.push_str(&format!(
+            "  fixture \"{}\" \"{}\" @ {}:{}\n    position ({x}, {y}, {z})  rotation ({rx}, {ry}, {rz})\n",
+            fixture.name, fixture.fixture_type, fixture.universe, fixture.address
+        ));
+    }
+    for todo in &todos {
+        venue.push_str(&format!(
+            "  # TODO: fixture \"{}\" — {}\n",
+            todo.fixture,
+            todo.reason.replace('\n', " ")
+        ));
+    }
+    venue.push_str("}\n");
+
+    // Prove the seeded venue parses before writing it.
+    super::parser::parse_venues(&venue)
+        .map_err(|e| format!("seeded venue did not parse back: {e}"))?;
+    create_dir(&venue_dir)?;
+    write(&venue_path, venue.as_bytes())?;
+
+    Ok(MvrImport {
+        venue_name,
+        venue_file: format!(
+            "{}/{}",
+            venues_dir.trim_end_matches('/'),
+            venue_path.file_name().unwrap_or_default().display()
+        ),
+        origin,
+        fixtures,
+        fixture_types: types,
+        todos,
+        warnings,
+    })
+}
+
+/// A GDTF carried inside an MVR.
+struct EmbeddedGdtf {
+    /// The archive entry it was read from.
+    entry: String,
+    /// The GDTF archive's bytes.
+    bytes: Vec<u8>,
+    /// Its parsed description.
+    description: gdtf::Description,
+}
+
+/// Reads and parses a GDTF the scene references. Console exports sometimes
+/// drop the extension from `GDTFSpec`.
+fn read_embedded_gdtf(archive: &mut gdtf::Archive<'_>, spec: &str) -> Result<EmbeddedGdtf, String> {
+    if spec.is_empty() {
+        return Err("no GDTFSpec in the MVR".to_string());
+    }
+    let with_extension = format!("{spec}.gdtf");
+    let entry = [spec, with_extension.as_str()]
+        .into_iter()
+        .find(|name| archive.contains(name))
+        .ok_or_else(|| format!("GDTF \"{spec}\" is not in the MVR"))?
+        .to_string();
+    let bytes = archive
+        .read(&entry, u64::MAX)
+        .map_err(|e| format!("GDTF \"{spec}\": {e}"))?;
+    let description = gdtf::parse_archive(&bytes).map_err(|e| format!("GDTF \"{spec}\": {e}"))?;
+    Ok(EmbeddedGdtf {
+        entry,
+        bytes,
+        description,
+    })
+}
+
+/// Matches a requested mode and makes sure a fixture type exists for it,
+/// reusing an identical existing definition. Returns the type's name.
+fn resolve_fixture_type(
+    gdtf: &EmbeddedGdtf,
+    requested_mode: &str,
+    types: &mut Vec<MvrFixtureType>,
+    warnings: &mut Vec<String>,
+    project: &Path,
+    fixture_types_dir: &str,
+) -> Result<String, String> {
+    let EmbeddedGdtf {
+        entry,
+        bytes: gdtf_bytes,
+        description,
+    } = gdtf;
+    let (mode, mode_match) =
+        gdtf::match_mode(description, requested_mode).map_err(|e| e.to_string())?;
+    match mode_match {
+        gdtf::ModeMatch::Exact => {}
+        gdtf::ModeMatch::Normalized => warnings.push(format!(
+            "GDTF \"{entry}\": mode \"{requested_mode}\" matched \"{mode}\" ignoring case and punctuation"
+        )),
+        gdtf::ModeMatch::Footprint => warnings.push(format!(
+            "GDTF \"{entry}\": mode \"{requested_mode}\" matched \"{mode}\" by channel count only"
+        )),
+    }
+    if let Some(existing) = types.iter().find(|t| t.gdtf == *entry && t.mode == mode) {
+        return Ok(existing.type_name.clone());
+    }
+
+    // One type per GDTF keeps the GDTF's name; a second mode of the same
+    // GDTF gets its own name.
+    let mut type_name = venue_string(&description.name);
+    if types.iter().any(|t| t.type_name == type_name) {
+        type_name = venue_string(&format!("{} {mode}", description.name));
+    }
+    let archive_file_name = Path::new(entry)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| format!("GDTF \"{entry}\" has no usable file name"))?
+        .to_string();
+    let fixture_file = format!(
+        "{}/{}.fixture",
+        fixture_types_dir.trim_end_matches('/'),
+        fixture_filename_stem(&type_name)
+    );
+
+    let import = if defines_same_type(
+        project,
+        &fixture_file,
+        &type_name,
+        &archive_file_name,
+        &mode,
+        gdtf_bytes,
+    ) {
+        None
+    } else {
+        Some(
+            import_gdtf_bytes(
+                gdtf_bytes,
+                &archive_file_name,
+                &mode,
+                Some(&type_name),
+                project,
+                fixture_types_dir,
+            )
+            .map_err(|e| format!("GDTF \"{entry}\" mode \"{mode}\": {e}"))?,
+        )
+    };
+    types.push(MvrFixtureType {
+        type_name: type_name.clone(),
+        gdtf: entry.to_string(),
+        requested_mode: requested_mode.to_string(),
+        mode,
+        mode_match,
+        fixture_file,
+        import,
+    });
+    Ok(type_name)
+}
+
+/// Returns true if `fixture_file` already defines `type_name` from the same
+/// archive bytes and mode — a re-import of the same rig reuses it.
+fn defines_same_type(
+    project: &Path,
+    fixture_file: &str,
+    type_name: &str,
+    archive_file_name: &str,
+    mode: &str,
+    gdtf_bytes: &[u8],
+) -> bool {
+    let library_rel = format!("lighting/library/{archive_file_name}");
+    let Ok(content) = std::fs::read_to_string(project.join(fixture_file)) else {
+        return false;
+    };
+    let same_source = super::parser::parse_fixture_types(&content)
+        .ok()
+        .and_then(|types| types.get(type_name).and_then(|t| t.source().cloned()))
+        .is_some_and(|source| source.path == library_rel && source.mode == mode);
+    same_source && std::fs::read(project.join(&library_rel)).is_ok_and(|b| b == gdtf_bytes)
+}
+
+/// A name made safe for a quoted DSL string, which has no escapes.
+fn venue_string(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| match c {
+            '"' => '\'',
+            c if c.is_control() => ' ',
+            c => c,
+        })
+        .collect();
+    let name = name.trim();
+    if name.is_empty() {
+        "unnamed".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// `name`, suffixed until it is unused, and recorded as used.
+fn unique_name(name: &str, used: &mut HashSet<String>) -> String {
+    let mut candidate = name.to_string();
+    let mut suffix = 2;
+    while used.contains(&candidate) {
+        candidate = format!("{name} {suffix}");
+        suffix += 1;
+    }
+    used.insert(candidate.clone());
+    candidate
+}
+
+/// Rounds a re-origined coordinate to the millimeter.
+fn round_mm(meters: f64) -> f64 {
+    let rounded = (meters * 1000.0).round() / 1000.0;
+    if rounded == 0.0 {
+        0.0
+    } else {
+        rounded
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -412,6 +866,208 @@ mod tests {
         assert!(!project.join("lighting").exists());
     }
 
+    fn write_synthetic_mvr(dir: &Path, entries: &[(&str, &[u8])]) -> PathBuf {
+        let path = dir.join("kellys.mvr");
+        let bytes = if entries.is_empty() {
+            crate::lighting::mvr::synthetic_mvr()
+        } else {
+            crate::lighting::gdtf::build_zip(entries)
+        };
+        std::fs::write(&path, bytes).unwrap();
+        path
+    }
+
+    fn import_synthetic_mvr(
+        mvr: &Path,
+        name: Option<&str>,
+        project: &Path,
+    ) -> Result<MvrImport, Box<dyn Error>> {
+        import_mvr(
+            mvr,
+            name,
+            Some(&MvrOrigin::Fixture("Wash 1".to_string())),
+            project,
+            "lighting/fixture_types",
+            "lighting/venues",
+        )
+    }
+
+    #[test]
+    fn mvr_import_seeds_a_playable_venue() {
+        let dir = tempfile::tempdir().unwrap();
+        let mvr = write_synthetic_mvr(dir.path(), &[]);
+        let project = dir.path().join("project");
+        std::fs::create_dir_all(&project).unwrap();
+
+        let report = import_synthetic_mvr(&mvr, None, &project).unwrap();
+        assert_eq!(report.venue_name, "kellys");
+        assert_eq!(report.venue_file, "lighting/venues/kellys.venue");
+        assert_eq!(report.origin, [-2.0, 1.5, 0.0]);
+
+        // One type per GDTF mode; the sloppy mode string is matched and
+        // flagged.
+        let types: Vec<(&str, &str, &gdtf::ModeMatch)> = report
+            .fixture_types
+            .iter()
+            .map(|t| (t.type_name.as_str(), t.mode.as_str(), &t.mode_match))
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                ("Synth Brick", "8: RGBS", &gdtf::ModeMatch::Exact),
+                (
+                    "Synth Brick Mover 16bit",
+                    "Mover 16bit",
+                    &gdtf::ModeMatch::Normalized
+                ),
+            ]
+        );
+        assert!(report.fixture_types.iter().all(|t| t.import.is_some()));
+        assert!(
+            report.warnings.iter().any(|w| w.contains("mover 16 bit")),
+            "{:?}",
+            report.warnings
+        );
+
+        // The unpatched fixture is a TODO, not a silent drop.
+        assert_eq!(report.todos.len(), 1);
+        assert_eq!(report.todos[0].fixture, "Spare");
+
+        // The venue loads back with addresses and stage-relative placement.
+        let content = std::fs::read_to_string(project.join(&report.venue_file)).unwrap();
+        assert!(content.contains("# TODO: fixture \"Spare\""), "{content}");
+        let venues = crate::lighting::parser::parse_venues(&content).unwrap();
+        let venue = &venues["kellys"];
+        let wash = &venue.fixtures()["Wash 1"];
+        assert_eq!(wash.fixture_type(), "Synth Brick");
+        assert_eq!((wash.universe(), wash.start_channel()), (1, 1));
+        assert_eq!(wash.position(), Some([0.0, 0.0, 6.0]));
+        let spot = &venue.fixtures()["Spot & Beam"];
+        assert_eq!(spot.fixture_type(), "Synth Brick Mover 16bit");
+        assert_eq!((spot.universe(), spot.start_channel()), (2, 17));
+        assert_eq!(spot.position(), Some([3.5, -0.5, 6.0]));
+        assert_eq!(spot.rotation(), Some([180.0, 0.0, 0.0]));
+
+        // Both fixture types load through the player's expansion path.
+        for fixture_type in &report.fixture_types {
+            let content =
+                std::fs::read_to_string(project.join(&fixture_type.fixture_file)).unwrap();
+            let types = crate::lighting::parser::parse_fixture_types(&content).unwrap();
+            LightingSystem::expand_referential(
+                &fixture_type.type_name,
+                &types[&fixture_type.type_name],
+                &project,
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn mvr_reimport_reuses_fixture_types_and_refuses_the_same_venue() {
+        let dir = tempfile::tempdir().unwrap();
+        let mvr = write_synthetic_mvr(dir.path(), &[]);
+        let project = dir.path().join("project");
+        std::fs::create_dir_all(&project).unwrap();
+        import_synthetic_mvr(&mvr, None, &project).unwrap();
+
+        let err = import_synthetic_mvr(&mvr, None, &project)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("already exists"), "{err}");
+
+        let report = import_synthetic_mvr(&mvr, Some("Kelly's v2"), &project).unwrap();
+        assert_eq!(report.venue_file, "lighting/venues/kelly_s_v2.venue");
+        assert_eq!(report.fixture_types.len(), 2);
+        assert!(report.fixture_types.iter().all(|t| t.import.is_none()));
+    }
+
+    #[test]
+    fn mvr_fixtures_without_a_usable_gdtf_become_todos() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = dir.path().join("project");
+        std::fs::create_dir_all(&project).unwrap();
+
+        // No GDTF in the archive at all.
+        let scene = crate::lighting::mvr::tests::SYNTHETIC_SCENE;
+        let mvr = write_synthetic_mvr(
+            dir.path(),
+            &[(crate::lighting::mvr::SCENE_DESCRIPTION, scene.as_bytes())],
+        );
+        let report = import_synthetic_mvr(&mvr, Some("missing"), &project).unwrap();
+        assert!(report.fixtures.is_empty());
+        assert_eq!(report.todos.len(), 3);
+        assert!(report.todos[0].reason.contains("not in the MVR"));
+        let content = std::fs::read_to_string(project.join(&report.venue_file)).unwrap();
+        assert_eq!(content.matches("# TODO").count(), 3, "{content}");
+
+        // A mode matching nothing lists the candidates.
+        let gdtf = crate::lighting::gdtf::build_zip(&[(
+            "description.xml",
+            crate::lighting::gdtf::SYNTHETIC_DESCRIPTION.as_bytes(),
+        )]);
+        let scene = scene.replace("8: RGBS", "Mode 7");
+        let mvr = write_synthetic_mvr(
+            dir.path(),
+            &[
+                (crate::lighting::mvr::SCENE_DESCRIPTION, scene.as_bytes()),
+                ("synth.gdtf", &gdtf),
+            ],
+        );
+        let report = import_synthetic_mvr(&mvr, Some("bad mode"), &project).unwrap();
+        assert_eq!(report.fixtures.len(), 1);
+        let todo = &report.todos[0];
+        assert_eq!(todo.fixture, "Wash 1");
+        assert!(
+            todo.reason.contains("\"8: RGBS\" (4 ch)"),
+            "{}",
+            todo.reason
+        );
+    }
+
+    #[test]
+    fn mvr_origin_must_name_a_scene_fixture() {
+        let dir = tempfile::tempdir().unwrap();
+        let mvr = write_synthetic_mvr(dir.path(), &[]);
+        let project = dir.path().join("project");
+        std::fs::create_dir_all(&project).unwrap();
+
+        let err = import_mvr(
+            &mvr,
+            None,
+            Some(&MvrOrigin::Fixture("Nobody".to_string())),
+            &project,
+            "lighting/fixture_types",
+            "lighting/venues",
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("no fixture named \"Nobody\""), "{err}");
+        assert!(!project.join("lighting").exists());
+
+        // Without an origin the MVR's own is kept, and the report says so.
+        let report = import_mvr(
+            &mvr,
+            None,
+            None,
+            &project,
+            "lighting/fixture_types",
+            "lighting/venues",
+        )
+        .unwrap();
+        assert_eq!(report.origin, [0.0; 3]);
+        assert_eq!(report.fixtures[0].position, [-2.0, 1.5, 6.0]);
+        assert!(report
+            .warnings
+            .iter()
+            .any(|w| w.contains("no origin given")));
+    }
+
+    #[test]
+    fn origin_points_need_three_coordinates() {
+        assert_eq!(parse_origin_point("1, -2.5, 0").unwrap(), [1.0, -2.5, 0.0]);
+        assert!(parse_origin_point("1,2").is_err());
+        assert!(parse_origin_point("a,b,c").is_err());
+    }
     #[test]
     fn filename_stems() {
         assert_eq!(fixture_filename_stem("PB15 PixelBrick"), "pb15_pixelbrick");
@@ -29,6 +29,7 @@ use crate::audio::click_analysis::BeatGrid;
 use crate::lighting::effects::{BlendMode, EffectLayer, EffectType};
 use crate::lighting::parser::LayerCommandType;
 use crate::lighting::parser::LightShow;
+use crate::lighting::types::MovementLimits;
 
 /// A non-fatal finding.
 #[derive(Clone, Debug, PartialEq)]
@@ -58,6 +59,10 @@ pub struct LintContext<'a> {
     /// The song's click-derived beat grid, for checking a `tempo` block against
     /// what the audio actually does.
     pub beat_grid: Option<&'a BeatGrid>,
+    /// The movement limits of the fixtures each targeted group resolves to,
+    /// by fixture name. Fixtures with no configured limits are left out —
+    /// there is nothing to hold a cue against.
+    pub group_movement_limits: HashMap<String, Vec<(String, MovementLimits)>>,
 }
 
 /// Runs every applicable check over `shows`.
@@ -72,6 +77,7 @@ pub fn lint_shows(shows: &[LightShow], ctx: &LintContext) -> Vec<Warning> {
     // `clear` in one show of a file ends effects in its siblings.
     effects_past_end_of_song(shows, ctx, &mut warnings);
     parameters_the_effect_ignores(shows, &mut warnings);
+    movement_faster_than_the_fixture(shows, ctx, &mut warnings);
     // Across all shows at once, not per show: `LightingTimeline` merges every
     // show in a file into one cue list and plays them together, so two shows
     // both driving `wash` on the background layer stomp each other exactly as
@@ -90,6 +96,95 @@ fn dsl_keyword(effect_type: &EffectType) -> &'static str {
         EffectType::Chase { .. } => "chase",
         EffectType::Dimmer { .. } => "dimmer",
         EffectType::Rainbow { .. } => "rainbow",
+        EffectType::Movement { shape, .. } => shape.keyword(),
+    }
+}
+
+/// A movement cue asking a fixture to pan or tilt faster than it can.
+///
+/// The engine's slew limiter keeps the head honest, so the cue still plays —
+/// as a smaller, lagging shape than the one authored. Reported once per
+/// group, fixture and axis, at the most demanding cue, since a show looping
+/// the same sweep has one problem, not one per loop.
+fn movement_faster_than_the_fixture(
+    shows: &[LightShow],
+    ctx: &LintContext,
+    out: &mut Vec<Warning>,
+) {
+    if ctx.group_movement_limits.is_empty() {
+        return;
+    }
+    /// The most a cue asks of one axis, and what the fixture has.
+    struct Demand {
+        demanded: f64,
+        available: f64,
+        time: Duration,
+        keyword: &'static str,
+    }
+    // (group, fixture, axis) -> the worst demand on it.
+    let mut worst: BTreeMap<(String, String, &'static str), Demand> = BTreeMap::new();
+    for show in shows {
+        for cue in &show.cues {
+            for effect in &cue.effects {
+                let EffectType::Movement { shape, .. } = &effect.effect_type else {
+                    continue;
+                };
+                let Some(speed) = shape.speed() else {
+                    continue;
+                };
+                let cycles_per_second =
+                    speed.to_cycles_per_second(show.tempo_map.as_ref(), cue.time);
+                let (pan_speed, tilt_speed) = shape.peak_speed(cycles_per_second);
+                for group in &effect.groups {
+                    let Some(fixtures) = ctx.group_movement_limits.get(group) else {
+                        continue;
+                    };
+                    for (fixture, limits) in fixtures {
+                        for (axis, demanded, available) in [
+                            ("pan", pan_speed, limits.max_pan_speed),
+                            ("tilt", tilt_speed, limits.max_tilt_speed),
+                        ] {
+                            let Some(available) = available else {
+                                continue;
+                            };
+                            if demanded <= available {
+                                continue;
+                            }
+                            let demand = Demand {
+                                demanded,
+                                available,
+                                time: cue.time,
+                                keyword: shape.keyword(),
+                            };
+                            let key = (group.clone(), fixture.clone(), axis);
+                            match worst.get(&key) {
+                                Some(existing) if existing.demanded >= demanded => {}
+                                _ => {
+                                    worst.insert(key, demand);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    for ((group, fixture, axis), demand) in worst {
+        out.push(Warning::new(
+            "movement-too-fast",
+            format!(
+                "the {} on `{}` at {:.3}s needs {} to {} at {:.0}°/s, but it manages \
+                 {:.0}°/s — it will lag and trace a smaller shape; slow the speed or \
+                 shrink the radius",
+                demand.keyword,
+                group,
+                demand.time.as_secs_f64(),
+                fixture,
+                axis,
+                demand.demanded,
+                demand.available,
+            ),
+        ));
     }
 }
 
@@ -460,6 +555,69 @@ mod tests {
         }
     }
 
+    // ── movement speed ─────────────────────────────────────────────
+
+    fn movers_limited_to(pan: f64, tilt: f64) -> LintContext<'static> {
+        let limits = MovementLimits {
+            max_pan_speed: Some(pan),
+            max_tilt_speed: Some(tilt),
+        };
+        LintContext {
+            group_movement_limits: HashMap::from([(
+                "movers".to_string(),
+                vec![("Spot1".to_string(), limits)],
+            )]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn a_sweep_faster_than_the_fixture_is_reported_at_its_worst() {
+        // A 30° circle once a second needs ~188°/s; twice a second, ~377°/s.
+        let source = r#"
+show "T" {
+    @00:00.000
+    movers: sweep radius: 30deg, speed: 1, duration: 4s
+
+    @00:04.000
+    movers: sweep radius: 30deg, speed: 2, duration: 4s
+}
+"#;
+        let warnings = lint_shows(&shows(source), &movers_limited_to(120.0, 400.0));
+        assert_eq!(kinds(&warnings), ["movement-too-fast"]);
+        let message = &warnings[0].message;
+        assert!(message.contains("Spot1 to pan at 377°/s"), "{message}");
+        assert!(message.contains("at 4.000s"), "{message}");
+    }
+
+    #[test]
+    fn a_figure_eight_demands_twice_the_tilt_speed() {
+        // 20° radius at one cycle a second: pan ~126°/s, tilt ~251°/s.
+        let source = r#"
+show "T" {
+    @00:00.000
+    movers: sweep shape: figure_eight, radius: 20deg, speed: 1, duration: 4s
+}
+"#;
+        let warnings = lint_shows(&shows(source), &movers_limited_to(200.0, 200.0));
+        assert_eq!(kinds(&warnings), ["movement-too-fast"]);
+        assert!(warnings[0].message.contains("to tilt at 251°/s"));
+    }
+
+    #[test]
+    fn movement_within_the_fixture_speed_is_fine() {
+        let source = r#"
+show "T" {
+    @00:00.000
+    movers: sweep radius: 30deg, speed: 0.5, duration: 4s
+
+    @00:04.000
+    movers: position pan: 170deg, duration: 4s
+}
+"#;
+        assert!(lint_shows(&shows(source), &movers_limited_to(120.0, 120.0)).is_empty());
+    }
+
     // ── empty groups ───────────────────────────────────────────────
 
     #[test]
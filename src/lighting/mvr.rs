@@ -0,0 +1,543 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+
+//! MVR (My Virtual Rig) scene parsing.
+//!
+//! An `.mvr` file is a zip archive holding `GeneralSceneDescription.xml` —
+//! the patched rig: every fixture's GDTF, mode, DMX address and placement —
+//! plus the GDTF archives it references. This module reads the fixtures out
+//! of the scene description; the importer hands the embedded GDTFs to the
+//! existing distiller.
+//!
+//! MVR files are untrusted input in exactly the way GDTF files are, so they
+//! go through the same hardened archive layer and the XML walk caps nesting
+//! depth and expands no DTD entities.
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use super::gdtf::{Archive, GdtfError};
+
+#[cfg(test)]
+pub(crate) use tests::synthetic_mvr;
+
+/// The scene description's fixed name within an MVR archive.
+pub const SCENE_DESCRIPTION: &str = "GeneralSceneDescription.xml";
+
+/// The deepest element nesting accepted. Real scenes nest layers, groups
+/// and child lists a handful of levels deep.
+const MAX_DEPTH: usize = 64;
+
+/// The largest scene description accepted from a direct caller; the archive
+/// layer enforces its own cap.
+const MAX_XML_BYTES: usize = 64 * 1024 * 1024;
+
+/// Elements that carry their own `Matrix` and place their children.
+const PLACED_OBJECTS: &[&str] = &[
+    "Layer",
+    "GroupObject",
+    "Fixture",
+    "SceneObject",
+    "Truss",
+    "Support",
+    "VideoScreen",
+    "Projector",
+    "FocusPoint",
+];
+
+/// One patched fixture from the scene.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SceneFixture {
+    /// The fixture's `name` attribute.
+    pub name: String,
+    /// The fixture's `FixtureID`, if any.
+    pub fixture_id: String,
+    /// The referenced GDTF archive, as written in the scene.
+    pub gdtf_spec: String,
+    /// The requested DMX mode, as written in the scene.
+    pub gdtf_mode: String,
+    /// The first DMX break's (universe, address), both 1-based.
+    pub address: Option<(u16, u16)>,
+    /// World position in meters, in the scene's own frame.
+    pub position: [f64; 3],
+    /// World rotation in degrees about X, then Y, then Z.
+    pub rotation: [f64; 3],
+}
+
+/// The fixtures of an MVR scene, in document order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Scene {
+    pub fixtures: Vec<SceneFixture>,
+}
+
+/// Reads and parses the scene description out of an MVR archive.
+pub fn parse_archive(archive: &mut Archive<'_>) -> Result<Scene, GdtfError> {
+    let xml = archive.read_xml(SCENE_DESCRIPTION)?;
+    parse_scene(&xml)
+}
+
+/// An affine transform as MVR writes it: rotation columns u, v, w and an
+/// offset o, in millimeters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Transform {
+    /// Row-major rotation, whose columns are MVR's u, v and w vectors.
+    rotation: [[f64; 3]; 3],
+    /// The offset, in millimeters.
+    offset: [f64; 3],
+}
+
+impl Transform {
+    const IDENTITY: Transform = Transform {
+        rotation: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        offset: [0.0; 3],
+    };
+
+    /// Parses MVR's `{u1,u2,u3}{v1,v2,v3}{w1,w2,w3}{o1,o2,o3}` text.
+    fn parse(text: &str) -> Result<Transform, GdtfError> {
+        let error = || GdtfError::new(format!("unparseable MVR matrix \"{}\"", text.trim()));
+        let groups: Vec<[f64; 3]> = text
+            .split('}')
+            .map(|group| group.trim().trim_start_matches('{'))
+            .filter(|group| !group.is_empty())
+            .map(|group| {
+                let values: Vec<f64> = group
+                    .split(',')
+                    .map(|value| value.trim().parse::<f64>())
+                    .collect::<Result<_, _>>()
+                    .map_err(|_| error())?;
+                match values.as_slice() {
+                    [a, b, c] if values.iter().all(|v| v.is_finite()) => Ok([*a, *b, *c]),
+                    _ => Err(error()),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        let [u, v, w, o] = groups.as_slice() else {
+            return Err(error());
+        };
+        Ok(Transform {
+            rotation: [[u[0], v[0], w[0]], [u[1], v[1], w[1]], [u[2], v[2], w[2]]],
+            offset: *o,
+        })
+    }
+
+    /// This transform applied after `child`: the child's frame expressed in
+    /// this one's parent frame.
+    fn then(&self, child: &Transform) -> Transform {
+        let r = &self.rotation;
+        Transform {
+            rotation: std::array::from_fn(|row| {
+                std::array::from_fn(|col| (0..3).map(|k| r[row][k] * child.rotation[k][col]).sum())
+            }),
+            offset: std::array::from_fn(|row| {
+                self.offset[row] + (0..3).map(|k| r[row][k] * child.offset[k]).sum::<f64>()
+            }),
+        }
+    }
+
+    /// The rotation as X-then-Y-then-Z angles in degrees (R = Rz·Ry·Rx).
+    fn euler_degrees(&self) -> [f64; 3] {
+        let r = &self.rotation;
+        let y = (-r[2][0]).clamp(-1.0, 1.0).asin();
+        let (x, z) = if y.cos().abs() < 1e-9 {
+            // Gimbal lock: X and Z turn about the same axis, so fold it all
+            // into X.
+            ((-r[1][2]).atan2(r[1][1]), 0.0)
+        } else {
+            (r[2][1].atan2(r[2][2]), r[1][0].atan2(r[0][0]))
+        };
+        [x, y, z].map(|angle| round(angle.to_degrees()))
+    }
+}
+
+/// Rounds away float noise from matrix math: millimeter / millidegree
+/// precision is well past anything a rig is focused to.
+fn round(value: f64) -> f64 {
+    let rounded = (value * 1000.0).round() / 1000.0;
+    if rounded == 0.0 {
+        0.0
+    } else {
+        rounded
+    }
+}
+
+/// Parses an MVR `Address`: an absolute address (universe 1 address 1 is 1,
+/// universe 2 address 1 is 513) or "universe.address".
+fn parse_address(text: &str) -> Result<(u16, u16), GdtfError> {
+    let text = text.trim();
+    let error = || GdtfError::new(format!("unparseable MVR DMX address \"{text}\""));
+    let (universe, address) = match text.split_once('.') {
+        Some((universe, address)) => (
+            universe.trim().parse::<u32>().map_err(|_| error())?,
+            address.trim().parse::<u32>().map_err(|_| error())?,
+        ),
+        None => {
+            let absolute = text.parse::<u32>().map_err(|_| error())?;
+            if absolute == 0 {
+                return Err(error());
+            }
+            ((absolute - 1) / 512 + 1, (absolute - 1) % 512 + 1)
+        }
+    };
+    if universe == 0 || universe > u16::MAX as u32 || !(1..=512).contains(&address) {
+        return Err(error());
+    }
+    Ok((universe as u16, address as u16))
+}
+
+/// An open placed object during the walk.
+struct Frame {
+    /// The element name.
+    element: String,
+    /// The stack depth the element opened at.
+    depth: usize,
+    /// The object's own matrix, identity until its `Matrix` child is read.
+    local: Transform,
+    /// The fixture being collected, when the object is a `Fixture`.
+    fixture: Option<SceneFixture>,
+}
+
+/// Parses `GeneralSceneDescription.xml` content into its fixtures.
+pub fn parse_scene(xml: &str) -> Result<Scene, GdtfError> {
+    if xml.len() > MAX_XML_BYTES {
+        return Err(GdtfError::new(format!(
+            "{SCENE_DESCRIPTION} is {} bytes; refusing more than {MAX_XML_BYTES}",
+            xml.len()
+        )));
+    }
+    let mut reader = Reader::from_str(xml);
+
+    let mut scene = Scene::default();
+    let mut saw_root = false;
+    let mut stack: Vec<String> = Vec::new();
+    let mut frames: Vec<Frame> = Vec::new();
+    let mut text = String::new();
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| GdtfError::new(format!("XML error in {SCENE_DESCRIPTION}: {e}")))?;
+        match event {
+            Event::Start(ref element) => {
+                let name = element_name(element)?;
+                saw_root |= name == "GeneralSceneDescription";
+                if PLACED_OBJECTS.contains(&name.as_str()) {
+                    frames.push(Frame {
+                        element: name.clone(),
+                        depth: stack.len(),
+                        local: Transform::IDENTITY,
+                        fixture: (name == "Fixture").then(|| SceneFixture {
+                            name: attr(element, "name").unwrap_or_default(),
+                            fixture_id: String::new(),
+                            gdtf_spec: String::new(),
+                            gdtf_mode: String::new(),
+                            address: None,
+                            position: [0.0; 3],
+                            rotation: [0.0; 3],
+                        }),
+                    });
+                }
+                stack.push(name);
+                if stack.len() > MAX_DEPTH {
+                    return Err(GdtfError::new(format!(
+                        "{SCENE_DESCRIPTION} nests deeper than {MAX_DEPTH} elements"
+                    )));
+                }
+                text.clear();
+            }
+            Event::Empty(ref element) => {
+                saw_root |= element_name(element)? == "GeneralSceneDescription";
+                text.clear();
+            }
+            Event::Text(ref content) => {
+                let content = content
+                    .decode()
+                    .map_err(|e| GdtfError::new(format!("malformed XML text: {e}")))?;
+                text.push_str(&content);
+            }
+            Event::GeneralRef(ref reference) => {
+                if let Ok(Some(c)) = reference.resolve_char_ref() {
+                    text.push(c);
+                } else {
+                    let entity = reference
+                        .decode()
+                        .map_err(|e| GdtfError::new(format!("malformed XML entity: {e}")))?;
+                    let resolved = quick_xml::escape::resolve_predefined_entity(&entity)
+                        .ok_or_else(|| {
+                            GdtfError::new(format!(
+                                "{SCENE_DESCRIPTION} uses undefined entity &{entity};"
+                            ))
+                        })?;
+                    text.push_str(resolved);
+                }
+            }
+            Event::End(_) => {
+                let Some(name) = stack.pop() else {
+                    continue;
+                };
+                close_element(&name, &stack, &mut frames, &text, &mut scene)?;
+                text.clear();
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if !saw_root {
+        return Err(GdtfError::new(format!(
+            "{SCENE_DESCRIPTION} has no GeneralSceneDescription element"
+        )));
+    }
+    Ok(scene)
+}
+
+/// Applies a closed element: a property of the innermost placed object, or
+/// the object itself.
+fn close_element(
+    name: &str,
+    stack: &[String],
+    frames: &mut Vec<Frame>,
+    text: &str,
+    scene: &mut Scene,
+) -> Result<(), GdtfError> {
+    let Some(frame) = frames.last_mut() else {
+        return Ok(());
+    };
+
+    // The closed element's parent is the innermost object: a direct property.
+    if stack.len() == frame.depth + 1 && stack.last().map(String::as_str) == Some(&frame.element) {
+        match (name, frame.fixture.as_mut()) {
+            ("Matrix", _) => frame.local = Transform::parse(text)?,
+            ("GDTFSpec", Some(fixture)) => fixture.gdtf_spec = text.trim().to_string(),
+            ("GDTFMode", Some(fixture)) => fixture.gdtf_mode = text.trim().to_string(),
+            ("FixtureID", Some(fixture)) => fixture.fixture_id = text.trim().to_string(),
+            _ => {}
+        }
+        return Ok(());
+    }
+
+    // Only the first break is patched; later breaks are a fixture's second
+    // DMX input, which mtrack doesn't model.
+    if name == "Address"
+        && stack.len() == frame.depth + 2
+        && stack.last().map(String::as_str) == Some("Addresses")
+    {
+        if let Some(fixture) = frame.fixture.as_mut().filter(|f| f.address.is_none()) {
+            fixture.address = Some(parse_address(text)?);
+        }
+        return Ok(());
+    }
+
+    if stack.len() == frame.depth && name == frame.element {
+        let Some(frame) = frames.pop() else {
+            return Ok(());
+        };
+        if let Some(mut fixture) = frame.fixture {
+            let world = frames
+                .iter()
+                .map(|f| f.local)
+                .chain(std::iter::once(frame.local))
+                .fold(Transform::IDENTITY, |parent, child| parent.then(&child));
+            fixture.position = world.offset.map(|mm| round(mm / 1000.0));
+            fixture.rotation = world.euler_degrees();
+            scene.fixtures.push(fixture);
+        }
+    }
+    Ok(())
+}
+
+fn element_name(element: &BytesStart<'_>) -> Result<String, GdtfError> {
+    std::str::from_utf8(element.name().as_ref())
+        .map(|s| s.to_string())
+        .map_err(|_| GdtfError::new(format!("non-UTF-8 element name in {SCENE_DESCRIPTION}")))
+}
+
+/// Reads an attribute's unescaped value, if present and well-formed.
+fn attr(element: &BytesStart<'_>, name: &str) -> Option<String> {
+    element
+        .attributes()
+        .filter_map(Result::ok)
+        .find(|attribute| attribute.key.as_ref() == name.as_bytes())
+        .and_then(|attribute| {
+            attribute
+                .normalized_value(quick_xml::XmlVersion::Implicit1_0)
+                .ok()
+                .map(|value| value.into_owned())
+        })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A small rig: a wash in a layer offset 1 m upstage, and a spot hung
+    /// upside down inside a group, plus an unpatched fixture.
+    pub(crate) const SYNTHETIC_SCENE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<GeneralSceneDescription verMajor="1" verMinor="6">
+  <Scene>
+    <Layers>
+      <Layer name="Rig" uuid="1">
+        <Matrix>{1,0,0}{0,1,0}{0,0,1}{0,1000,0}</Matrix>
+        <ChildList>
+          <Fixture name="Wash 1" uuid="2">
+            <Matrix>{1,0,0}{0,1,0}{0,0,1}{-2000,500,6000}</Matrix>
+            <GDTFSpec>synth.gdtf</GDTFSpec>
+            <GDTFMode>8: RGBS</GDTFMode>
+            <FixtureID>101</FixtureID>
+            <Addresses><Address break="0">1</Address></Addresses>
+          </Fixture>
+          <GroupObject name="Truss" uuid="3">
+            <Matrix>{1,0,0}{0,1,0}{0,0,1}{0,0,6000}</Matrix>
+            <ChildList>
+              <Fixture name="Spot &amp; Beam" uuid="4">
+                <Matrix>{1,0,0}{0,-1,0}{0,0,-1}{1500,0,0}</Matrix>
+                <GDTFSpec>synth</GDTFSpec>
+                <GDTFMode>mover 16 bit</GDTFMode>
+                <Addresses><Address break="0">2.17</Address><Address break="1">600</Address></Addresses>
+              </Fixture>
+            </ChildList>
+          </GroupObject>
+          <Fixture name="Spare" uuid="5">
+            <GDTFSpec>synth.gdtf</GDTFSpec>
+            <GDTFMode>8: RGBS</GDTFMode>
+          </Fixture>
+        </ChildList>
+      </Layer>
+    </Layers>
+  </Scene>
+</GeneralSceneDescription>"#;
+
+    /// The synthetic scene packed with the synthetic GDTF it references.
+    pub(crate) fn synthetic_mvr() -> Vec<u8> {
+        let gdtf = crate::lighting::gdtf::build_zip(&[(
+            "description.xml",
+            crate::lighting::gdtf::SYNTHETIC_DESCRIPTION.as_bytes(),
+        )]);
+        crate::lighting::gdtf::build_zip(&[
+            (SCENE_DESCRIPTION, SYNTHETIC_SCENE.as_bytes()),
+            ("synth.gdtf", &gdtf),
+        ])
+    }
+
+    #[test]
+    fn parses_archives() {
+        let bytes = synthetic_mvr();
+        let mut archive = Archive::open(&bytes, "MVR").unwrap();
+        assert_eq!(parse_archive(&mut archive).unwrap().fixtures.len(), 3);
+
+        let bytes = crate::lighting::gdtf::build_zip(&[("synth.gdtf", b"zip".as_slice())]);
+        let mut archive = Archive::open(&bytes, "MVR").unwrap();
+        let err = parse_archive(&mut archive).unwrap_err().to_string();
+        assert!(err.contains("every MVR archive carries one"), "{err}");
+    }
+
+    #[test]
+    fn parses_fixtures_with_world_placement() {
+        let scene = parse_scene(SYNTHETIC_SCENE).unwrap();
+        assert_eq!(scene.fixtures.len(), 3);
+
+        let wash = &scene.fixtures[0];
+        assert_eq!(wash.name, "Wash 1");
+        assert_eq!(wash.fixture_id, "101");
+        assert_eq!(wash.gdtf_spec, "synth.gdtf");
+        assert_eq!(wash.gdtf_mode, "8: RGBS");
+        assert_eq!(wash.address, Some((1, 1)));
+        assert_eq!(wash.position, [-2.0, 1.5, 6.0]);
+        assert_eq!(wash.rotation, [0.0, 0.0, 0.0]);
+
+        // Document order: the grouped spot closes before the spare.
+        let spot = &scene.fixtures[1];
+        assert_eq!(spot.name, "Spot & Beam");
+        assert_eq!(spot.address, Some((2, 17)));
+        assert_eq!(spot.position, [1.5, 1.0, 6.0]);
+        assert_eq!(spot.rotation, [180.0, 0.0, 0.0]);
+
+        let spare = &scene.fixtures[2];
+        assert_eq!(spare.address, None);
+        assert_eq!(spare.position, [0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn absolute_addresses_span_universes() {
+        assert_eq!(parse_address("1").unwrap(), (1, 1));
+        assert_eq!(parse_address("512").unwrap(), (1, 512));
+        assert_eq!(parse_address("513").unwrap(), (2, 1));
+        assert_eq!(parse_address("3.100").unwrap(), (3, 100));
+        assert!(parse_address("0").is_err());
+        assert!(parse_address("1.513").is_err());
+        assert!(parse_address("one").is_err());
+    }
+
+    #[test]
+    fn euler_angles_round_trip_through_matrices() {
+        let rotation = |[x, y, z]: [f64; 3]| {
+            let (x, y, z) = (x.to_radians(), y.to_radians(), z.to_radians());
+            let rx = [
+                [1.0, 0.0, 0.0],
+                [0.0, x.cos(), -x.sin()],
+                [0.0, x.sin(), x.cos()],
+            ];
+            let ry = [
+                [y.cos(), 0.0, y.sin()],
+                [0.0, 1.0, 0.0],
+                [-y.sin(), 0.0, y.cos()],
+            ];
+            let rz = [
+                [z.cos(), -z.sin(), 0.0],
+                [z.sin(), z.cos(), 0.0],
+                [0.0, 0.0, 1.0],
+            ];
+            let t = |rotation| Transform {
+                rotation,
+                offset: [0.0; 3],
+            };
+            t(rz).then(&t(ry)).then(&t(rx))
+        };
+        for angles in [
+            [0.0, 0.0, 90.0],
+            [30.0, -45.0, 120.0],
+            [-90.0, 10.0, 0.0],
+            [180.0, 0.0, -135.0],
+        ] {
+            assert_eq!(rotation(angles).euler_degrees(), angles, "{angles:?}");
+        }
+    }
+
+    #[test]
+    fn malformed_scenes_are_clear_errors() {
+        let err = parse_scene("<Other/>").unwrap_err().to_string();
+        assert!(err.contains("no GeneralSceneDescription"), "{err}");
+
+        let bad_matrix = SYNTHETIC_SCENE.replace("{-2000,500,6000}", "{-2000,500}");
+        let err = parse_scene(&bad_matrix).unwrap_err().to_string();
+        assert!(err.contains("unparseable MVR matrix"), "{err}");
+
+        let deep = format!(
+            "<GeneralSceneDescription>{}{}</GeneralSceneDescription>",
+            "<GroupObject>".repeat(MAX_DEPTH),
+            "</GroupObject>".repeat(MAX_DEPTH)
+        );
+        let err = parse_scene(&deep).unwrap_err().to_string();
+        assert!(err.contains("nests deeper"), "{err}");
+    }
+
+    #[test]
+    fn undefined_entities_are_refused() {
+        let xml = "<!DOCTYPE x [<!ENTITY boom \"boom\">]><GeneralSceneDescription>\
+                   <Scene><Layers><Layer><ChildList><Fixture name=\"a\">\
+                   <GDTFSpec>&boom;</GDTFSpec></Fixture></ChildList></Layer>\
+                   </Layers></Scene></GeneralSceneDescription>";
+        let err = parse_scene(xml).unwrap_err().to_string();
+        assert!(err.contains("undefined entity"), "{err}");
+    }
+}
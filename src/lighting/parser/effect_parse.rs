@@ -18,14 +18,14 @@ use std::time::Duration;
 
 use super::super::effects::{
     BlendMode, ChaseDirection, ChasePattern, Color, CycleDirection, CycleTransition, DimmerCurve,
-    EffectLayer, EffectType, TempoAwareFrequency, TempoAwareSpeed,
+    EffectLayer, EffectType, MovementShape, SweepPattern, TempoAwareFrequency, TempoAwareSpeed,
 };
 use super::super::tempo::TempoMap;
 use super::grammar::Rule;
 use super::types::{Effect, ParseContext};
 use super::utils::{
-    parse_color_string, parse_duration_string, parse_frequency_string, parse_percentage_to_f64,
-    parse_speed_string,
+    parse_angle_to_degrees, parse_color_string, parse_duration_string, parse_frequency_string,
+    parse_percentage_to_f64, parse_speed_string,
 };
 use pest::iterators::Pair;
 
@@ -133,6 +133,33 @@ pub(crate) fn parse_effect_definition(
                         brightness: 1.0,
                         duration: Duration::ZERO,
                     },
+                    "position" => EffectType::Movement {
+                        shape: MovementShape::Position {
+                            pan: 0.0,
+                            tilt: 0.0,
+                        },
+                        duration: Duration::ZERO,
+                    },
+                    "sweep" => EffectType::Movement {
+                        shape: MovementShape::Sweep {
+                            pattern: SweepPattern::Circle,
+                            pan: 0.0,
+                            tilt: 0.0,
+                            pan_radius: 30.0,
+                            tilt_radius: 30.0,
+                            speed: TempoAwareSpeed::Fixed(0.25),
+                        },
+                        duration: Duration::ZERO,
+                    },
+                    "fan" => EffectType::Movement {
+                        shape: MovementShape::Fan {
+                            pan: 0.0,
+                            tilt: 0.0,
+                            pan_spread: 90.0,
+                            tilt_spread: 0.0,
+                        },
+                        duration: Duration::ZERO,
+                    },
                     _ => return Err(format!("Unknown effect type: {}", inner_pair.as_str()).into()),
                 };
             }
@@ -277,6 +304,7 @@ pub(crate) fn parse_effect_definition(
                 EffectType::Pulse { .. } => "pulse",
                 EffectType::Chase { .. } => "chase",
                 EffectType::Rainbow { .. } => "rainbow",
+                EffectType::Movement { shape, .. } => shape.keyword(),
                 EffectType::Dimmer { .. } => unreachable!(),
             };
             return Err(format!(
@@ -618,6 +646,107 @@ pub(crate) fn apply_parameters_to_effect_type(
                 }
             }
         }
+        EffectType::Movement { shape, duration } => {
+            let angle = |key: &str, value: &str| {
+                parse_angle_to_degrees(value)
+                    .map_err(|e| format!("Invalid angle for '{}': '{}' ({})", key, value, e))
+            };
+            // A focus replaces a position's angles outright: each fixture
+            // works out its own from where the venue hangs it.
+            if let (Some(value), MovementShape::Position { .. }) =
+                (parameters.get("focus"), &*shape)
+            {
+                // Named as the venue names it, so case is kept.
+                *shape = MovementShape::Focus {
+                    point: value.trim_matches('"').trim().to_string(),
+                };
+            }
+            // The both-axes spellings go first so a per-axis one can refine
+            // them whatever order the author wrote them in.
+            for key in ["radius", "spread"] {
+                let Some(value) = parameters.get(key) else {
+                    continue;
+                };
+                match (key, &mut *shape) {
+                    (
+                        "radius",
+                        MovementShape::Sweep {
+                            pan_radius,
+                            tilt_radius,
+                            ..
+                        },
+                    ) => {
+                        let radius = angle(key, value)?;
+                        *pan_radius = radius;
+                        *tilt_radius = radius;
+                    }
+                    ("spread", MovementShape::Fan { pan_spread, .. }) => {
+                        *pan_spread = angle(key, value)?;
+                    }
+                    _ => {}
+                }
+            }
+            for (key, value) in parameters {
+                match (key.as_str(), &mut *shape) {
+                    ("radius", MovementShape::Sweep { .. })
+                    | ("spread", MovementShape::Fan { .. })
+                    | ("focus", MovementShape::Focus { .. }) => {}
+                    (
+                        "pan",
+                        MovementShape::Position { pan, .. }
+                        | MovementShape::Sweep { pan, .. }
+                        | MovementShape::Fan { pan, .. },
+                    ) => *pan = angle(key, value)?,
+                    (
+                        "tilt",
+                        MovementShape::Position { tilt, .. }
+                        | MovementShape::Sweep { tilt, .. }
+                        | MovementShape::Fan { tilt, .. },
+                    ) => *tilt = angle(key, value)?,
+                    ("pan_radius", MovementShape::Sweep { pan_radius, .. }) => {
+                        *pan_radius = angle(key, value)?;
+                    }
+                    ("tilt_radius", MovementShape::Sweep { tilt_radius, .. }) => {
+                        *tilt_radius = angle(key, value)?;
+                    }
+                    ("shape", MovementShape::Sweep { pattern, .. }) => {
+                        *pattern = match clean_string_value(value).as_str() {
+                            "circle" => SweepPattern::Circle,
+                            "figure_eight" => SweepPattern::FigureEight,
+                            other => {
+                                return Err(format!(
+                                    "Invalid sweep shape: '{}' (expected: circle, figure_eight)",
+                                    other
+                                )
+                                .into())
+                            }
+                        };
+                    }
+                    ("speed", MovementShape::Sweep { speed, .. }) => {
+                        match parse_speed_string(value, tempo_map) {
+                            Ok(val) => *speed = val,
+                            Err(e) => {
+                                return Err(
+                                    format!("Invalid speed value '{}': {}", value, e).into()
+                                );
+                            }
+                        }
+                    }
+                    ("pan_spread", MovementShape::Fan { pan_spread, .. }) => {
+                        *pan_spread = angle(key, value)?;
+                    }
+                    ("tilt_spread", MovementShape::Fan { tilt_spread, .. }) => {
+                        *tilt_spread = angle(key, value)?;
+                    }
+                    ("duration", _) => {
+                        let dur =
+                            parse_duration_in_score_space(value, tempo_map, cue_time, offset_secs)?;
+                        *duration = dur;
+                    }
+                    (other, _) => ignored.push(other.to_string()),
+                }
+            }
+        }
     }
 
     ignored.sort();
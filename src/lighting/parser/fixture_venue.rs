@@ -327,6 +327,7 @@ fn extract_string(pair: Pair<Rule>) -> String {
 fn parse_venue_definition(pair: Pair<Rule>) -> Result<Venue, Box<dyn Error>> {
     let mut name = String::new();
     let mut fixtures = HashMap::new();
+    let mut focus_points = HashMap::new();
 
     for pair in pair.into_inner() {
         match pair.as_rule() {
@@ -334,7 +335,7 @@ fn parse_venue_definition(pair: Pair<Rule>) -> Result<Venue, Box<dyn Error>> {
                 name = extract_string(pair);
             }
             Rule::venue_content => {
-                parse_venue_content(pair, &mut fixtures)?;
+                parse_venue_content(pair, &mut fixtures, &mut focus_points)?;
             }
             _ => {}
         }
@@ -344,12 +345,13 @@ fn parse_venue_definition(pair: Pair<Rule>) -> Result<Venue, Box<dyn Error>> {
         return Err("Venue name is required".into());
     }
 
-    Ok(Venue::new(name, fixtures))
+    Ok(Venue::new(name, fixtures).with_focus_points(focus_points))
 }
 
 fn parse_venue_content(
     pair: Pair<Rule>,
     fixtures: &mut HashMap<String, Fixture>,
+    focus_points: &mut HashMap<String, [f64; 3]>,
 ) -> Result<(), Box<dyn Error>> {
     for content_pair in pair.into_inner() {
         match content_pair.as_rule() {
@@ -357,6 +359,15 @@ fn parse_venue_content(
                 let fixture = parse_fixture_definition(content_pair)?;
                 fixtures.insert(fixture.name().to_string(), fixture);
             }
+            Rule::focus_point => {
+                let name = content_pair
+                    .clone()
+                    .into_inner()
+                    .find(|p| p.as_rule() == Rule::string)
+                    .map(extract_string)
+                    .unwrap_or_default();
+                focus_points.insert(name, parse_vector3(content_pair)?);
+            }
             // `group` still parses so this can say what to do about it. Venue
             // groups were superseded by fixture tags one release after they
             // landed; a bare pest failure here would only say "expected
@@ -403,6 +414,8 @@ pub(crate) fn parse_fixture_definition(pair: Pair<Rule>) -> Result<Fixture, Box<
     let mut universe = 0u16;
     let mut start_channel = 0u16;
     let mut tags = Vec::new();
+    let mut position = None;
+    let mut rotation = None;
 
     for pair in pair.into_inner() {
         match pair.as_rule() {
@@ -424,6 +437,12 @@ pub(crate) fn parse_fixture_definition(pair: Pair<Rule>) -> Result<Fixture, Box<
             Rule::tags => {
                 tags = parse_tags(pair);
             }
+            Rule::fixture_position => {
+                position = Some(parse_vector3(pair)?);
+            }
+            Rule::fixture_rotation => {
+                rotation = Some(parse_vector3(pair)?);
+            }
             _ => {}
         }
     }
@@ -434,7 +453,21 @@ pub(crate) fn parse_fixture_definition(pair: Pair<Rule>) -> Result<Fixture, Box<
         universe,
         start_channel,
         tags,
-    ))
+    )
+    .with_placement(position, rotation))
+}
+
+/// Parses the `(x, y, z)` under a position or rotation.
+fn parse_vector3(pair: Pair<Rule>) -> Result<[f64; 3], Box<dyn Error>> {
+    let mut values = [0.0; 3];
+    let numbers = pair
+        .into_inner()
+        .filter(|p| p.as_rule() == Rule::vector3)
+        .flat_map(|v| v.into_inner());
+    for (value, number) in values.iter_mut().zip(numbers) {
+        *value = number.as_str().parse()?;
+    }
+    Ok(values)
 }
 
 fn parse_tags(pair: Pair<Rule>) -> Vec<String> {
@@ -723,6 +756,59 @@ fixture_type "TypeB" {
         assert_eq!(w2.tags(), &["back"]);
     }
 
+    #[test]
+    fn venue_with_placement() {
+        let content = r#"venue "Placed" {
+    fixture "Spot1" Spot @ 1:1 tags ["spot"] position (-2.0, 3.5, 4.2) rotation (0, 0, 180)
+    fixture "Spot2" Spot @ 1:20 position (2, 3.5, 4.2)
+    fixture "Par1" Par @ 1:40
+}"#;
+        let result = parse_venues(content).unwrap();
+        let v = result.get("Placed").unwrap();
+
+        let s1 = v.fixtures().get("Spot1").unwrap();
+        assert_eq!(s1.tags(), &["spot"]);
+        assert_eq!(s1.position(), Some([-2.0, 3.5, 4.2]));
+        assert_eq!(s1.rotation(), Some([0.0, 0.0, 180.0]));
+
+        let s2 = v.fixtures().get("Spot2").unwrap();
+        assert_eq!(s2.position(), Some([2.0, 3.5, 4.2]));
+        assert_eq!(s2.rotation(), None);
+
+        let p1 = v.fixtures().get("Par1").unwrap();
+        assert_eq!(p1.position(), None);
+
+        // The displayed form parses back to the same placement.
+        let reparsed = parse_venues(&v.to_string()).unwrap();
+        let s1 = reparsed
+            .get("Placed")
+            .unwrap()
+            .fixtures()
+            .get("Spot1")
+            .unwrap();
+        assert_eq!(s1.position(), Some([-2.0, 3.5, 4.2]));
+        assert_eq!(s1.rotation(), Some([0.0, 0.0, 180.0]));
+    }
+
+    #[test]
+    fn venue_with_focus_points() {
+        let content = r#"venue "Club" {
+    fixture "Spot1" Spot @ 1:1 position (-2, 0, 5)
+    focus "drummer" (0, 3, 1.2)
+    focus "center-stage" (0, 1.5, 0)
+}"#;
+        let result = parse_venues(content).unwrap();
+        let v = result.get("Club").unwrap();
+        assert_eq!(v.fixtures().len(), 1);
+        assert_eq!(v.focus_points().len(), 2);
+        assert_eq!(v.focus_points()["drummer"], [0.0, 3.0, 1.2]);
+        assert_eq!(v.focus_points()["center-stage"], [0.0, 1.5, 0.0]);
+
+        // The displayed form parses back to the same points.
+        let reparsed = parse_venues(&v.to_string()).unwrap();
+        assert_eq!(reparsed["Club"].focus_points(), v.focus_points());
+    }
+
     #[test]
     fn venue_group_is_rejected_with_migration_advice() {
         // Venue groups were superseded by fixture tags (#104, one release after
@@ -292,3 +292,116 @@ all_wash: cycle, color: "red", color: "green", color: "blue", speed: 1.5, direct
         panic!("Expected ColorCycle effect type");
     }
 }
+
+#[test]
+fn test_movement_effects_parse_in_degrees() {
+    use crate::lighting::effects::{MovementShape, SweepPattern, TempoAwareSpeed};
+
+    let dsl = r#"show "Movement" {
+    @00:00.000
+    movers: position pan: -45deg, tilt: 30.5deg, duration: 4s
+    @00:04.000
+    movers: sweep shape: figure_eight, pan: 0deg, tilt: 60deg, radius: 20deg, tilt_radius: 10deg, speed: 0.5, duration: 8s
+    @00:12.000
+    movers: fan pan: 0, tilt: 50deg, spread: 120deg, duration: 4s
+}"#;
+
+    let shows = parse_light_shows(dsl).expect("movement DSL should parse");
+    let show = shows.get("Movement").unwrap();
+    let shapes: Vec<&MovementShape> = show
+        .cues
+        .iter()
+        .map(|cue| match &cue.effects[0].effect_type {
+            EffectType::Movement { shape, .. } => shape,
+            other => panic!("Expected movement, got {other:?}"),
+        })
+        .collect();
+
+    assert_eq!(
+        shapes[0],
+        &MovementShape::Position {
+            pan: -45.0,
+            tilt: 30.5
+        }
+    );
+    // `radius` sets both axes and `tilt_radius` refines one, in either order.
+    assert_eq!(
+        shapes[1],
+        &MovementShape::Sweep {
+            pattern: SweepPattern::FigureEight,
+            pan: 0.0,
+            tilt: 60.0,
+            pan_radius: 20.0,
+            tilt_radius: 10.0,
+            speed: TempoAwareSpeed::Fixed(0.5),
+        }
+    );
+    assert_eq!(
+        shapes[2],
+        &MovementShape::Fan {
+            pan: 0.0,
+            tilt: 50.0,
+            pan_spread: 120.0,
+            tilt_spread: 0.0,
+        }
+    );
+    assert_eq!(
+        show.cues[1].effects[0].effect_type.duration(),
+        std::time::Duration::from_secs(8)
+    );
+}
+
+#[test]
+fn test_movement_rejects_bad_values() {
+    let bad_shape = r#"show {
+    @00:00.000
+    movers: sweep shape: square, duration: 4s
+}"#;
+    let err = parse_light_shows(bad_shape).unwrap_err().to_string();
+    assert!(err.contains("Invalid sweep shape"), "{err}");
+
+    let no_duration = r#"show {
+    @00:00.000
+    movers: fan spread: 90deg
+}"#;
+    let err = parse_light_shows(no_duration).unwrap_err().to_string();
+    assert!(err.contains("Effect 'fan' requires a 'duration'"), "{err}");
+}
+
+#[test]
+fn test_movement_reports_parameters_it_ignores() {
+    // `spread` means nothing to a circle; the lint hears about it.
+    let dsl = r#"show {
+    @00:00.000
+    movers: sweep spread: 90deg, duration: 4s
+}"#;
+    let shows = parse_light_shows(dsl).unwrap();
+    let show = shows.values().next().unwrap();
+    assert_eq!(show.cues[0].effects[0].ignored_parameters, vec!["spread"]);
+}
+
+#[test]
+fn test_position_focus_names_a_venue_point() {
+    // Angles mean nothing next to a focus; the lint hears about them.
+    let dsl = r#"show {
+    @00:00.000
+    movers: position focus: "Drummer", tilt: 30deg, duration: 4s
+    @00:04.000
+    movers: sweep focus: "Drummer", duration: 4s
+}"#;
+    let shows = parse_light_shows(dsl).unwrap();
+    let show = shows.values().next().unwrap();
+    let effect = &show.cues[0].effects[0];
+    match &effect.effect_type {
+        EffectType::Movement { shape, .. } => assert_eq!(
+            shape,
+            &MovementShape::Focus {
+                point: "Drummer".to_string()
+            }
+        ),
+        other => panic!("Expected movement, got {other:?}"),
+    }
+    assert_eq!(effect.ignored_parameters, vec!["tilt"]);
+    // Only a position takes a focus.
+    assert_eq!(show.cues[1].effects[0].ignored_parameters, vec!["focus"]);
+}
@@ -32,6 +32,12 @@ pub(crate) fn parse_percentage_to_f64(value: &str) -> Result<f64, Box<dyn Error>
     }
 }
 
+/// Parses an angle string (e.g., "-45deg", "90") to degrees
+pub(crate) fn parse_angle_to_degrees(value: &str) -> Result<f64, Box<dyn Error>> {
+    let value = value.trim();
+    Ok(value.strip_suffix("deg").unwrap_or(value).parse::<f64>()?)
+}
+
 /// Parses a tempo-aware value string into a `TempoAwareValue`.
 /// Supports:
 /// - Numeric values (e.g., "4.0") -> Fixed
@@ -351,6 +357,24 @@ mod tests {
         assert!(parse_percentage_to_f64("abc%").is_err());
     }
 
+    // ── parse_angle_to_degrees ─────────────────────────────────────
+
+    #[test]
+    fn angle_with_unit() {
+        assert_eq!(parse_angle_to_degrees("45deg").unwrap(), 45.0);
+        assert_eq!(parse_angle_to_degrees("-22.5deg").unwrap(), -22.5);
+    }
+
+    #[test]
+    fn angle_bare_number() {
+        assert_eq!(parse_angle_to_degrees("90").unwrap(), 90.0);
+    }
+
+    #[test]
+    fn angle_invalid() {
+        assert!(parse_angle_to_degrees("fortydeg").is_err());
+    }
+
     // ── parse_frequency_string ─────────────────────────────────────
 
     #[test]
@@ -156,8 +156,12 @@ impl LightingSystem {
             if path.is_dir() {
                 // Recursively load subdirectories
                 self.load_venues_directory(&path)?;
-            } else if path.extension().is_some_and(|ext| ext == "light") {
-                // Load .light files
+            } else if path
+                .extension()
+                .is_some_and(|ext| ext == "venue" || ext == "light")
+            {
+                // .venue files are seeded by MVR import; .light files are the
+                // existing DSL. Both hold the same venue syntax.
                 self.load_venue_file(&path)?;
             }
         }
@@ -435,6 +439,25 @@ impl LightingSystem {
             );
             fixture_info.min_strobe_frequency = fixture_type.min_strobe_frequency();
             fixture_info.strobe_dmx_offset = fixture_type.strobe_dmx_offset();
+            fixture_info.movement = *fixture_type.movement();
+            fixture_info.position = fixture.position();
+            fixture_info.rotation = fixture.rotation();
+            for (channel, def) in fixture_type.channel_defs() {
+                // A hand-written channel map states a 16-bit channel as a
+                // separate `<name>_fine` entry rather than a fine offset.
+                let fine = def.fine.or_else(|| {
+                    fixture_type
+                        .channels()
+                        .get(&format!("{channel}_fine"))
+                        .copied()
+                });
+                if let Some(fine) = fine {
+                    fixture_info.fine_channels.insert(channel.clone(), fine);
+                }
+                if let Some(range) = def.range {
+                    fixture_info.physical_ranges.insert(channel.clone(), range);
+                }
+            }
 
             fixture_infos.push(fixture_info);
         }
@@ -1495,6 +1518,65 @@ mod tests {
         assert_eq!(infos[0].address, 10);
     }
 
+    #[test]
+    fn test_get_current_venue_fixtures_carry_movement_details() {
+        use super::super::types::{ChannelDef, MovementLimits, PhysicalRange, PhysicalUnit};
+
+        let mut system = LightingSystem::new();
+
+        // A distilled mover: 16-bit pan with a degree range.
+        let mut defs = HashMap::new();
+        let mut pan = ChannelDef::at(1);
+        pan.fine = Some(2);
+        pan.range = Some(PhysicalRange {
+            from: -270.0,
+            to: 270.0,
+            unit: PhysicalUnit::Degrees,
+        });
+        defs.insert("pan".to_string(), pan);
+        let mut ft = super::super::types::FixtureType::from_channel_defs("Mover".into(), defs);
+        ft.set_movement(MovementLimits {
+            max_pan_speed: Some(240.0),
+            max_tilt_speed: None,
+        });
+        system.fixture_types.insert("Mover".to_string(), ft);
+
+        // A hand-written one stating its fine byte as `tilt_fine`.
+        let mut channels = HashMap::new();
+        channels.insert("tilt".to_string(), 1);
+        channels.insert("tilt_fine".to_string(), 2);
+        let ft = super::super::types::FixtureType::new("Legacy".to_string(), channels);
+        system.fixture_types.insert("Legacy".to_string(), ft);
+
+        let mut fixtures = HashMap::new();
+        for (name, ty) in [("m1", "Mover"), ("l1", "Legacy")] {
+            fixtures.insert(
+                name.to_string(),
+                super::super::types::Fixture::new(name.to_string(), ty.to_string(), 1, 1, vec![]),
+            );
+        }
+        fixtures.insert(
+            "m1".to_string(),
+            super::super::types::Fixture::new("m1".to_string(), "Mover".to_string(), 1, 1, vec![])
+                .with_placement(Some([-2.0, 0.0, 5.0]), Some([0.0, 0.0, 90.0])),
+        );
+        let venue = super::super::types::Venue::new("TestVenue".to_string(), fixtures);
+        system.venues.insert("TestVenue".to_string(), venue);
+        system.current_venue = Some("TestVenue".to_string());
+
+        let infos = system.get_current_venue_fixtures().unwrap();
+        let mover = infos.iter().find(|i| i.name == "m1").unwrap();
+        assert_eq!(mover.fine_channels.get("pan"), Some(&2));
+        assert_eq!(mover.degrees_to_value("pan", 0.0), Some(0.5));
+        assert_eq!(mover.movement.max_pan_speed, Some(240.0));
+        assert_eq!(mover.position, Some([-2.0, 0.0, 5.0]));
+        assert_eq!(mover.rotation, Some([0.0, 0.0, 90.0]));
+
+        let legacy = infos.iter().find(|i| i.name == "l1").unwrap();
+        assert_eq!(legacy.fine_channels.get("tilt"), Some(&2));
+        assert!(legacy.degree_range("tilt").is_none());
+    }
+
     #[test]
     fn test_get_current_venue_fixtures_unknown_type() {
         let mut system = LightingSystem::new();
@@ -435,6 +435,16 @@ pub struct Fixture {
 
     /// Tags/roles/capabilities associated with this fixture.
     tags: Vec<String>,
+
+    /// Position on stage in meters: +x stage-left, +y upstage, +z up, from
+    /// downstage-center on the deck.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position: Option<[f64; 3]>,
+
+    /// Mounting rotation in degrees about the stage X, Y and Z axes, applied
+    /// in that order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rotation: Option<[f64; 3]>,
 }
 
 impl Fixture {
@@ -452,9 +462,22 @@ impl Fixture {
             universe,
             start_channel,
             tags,
+            position: None,
+            rotation: None,
         }
     }
 
+    /// Places the fixture on stage.
+    pub fn with_placement(
+        mut self,
+        position: Option<[f64; 3]>,
+        rotation: Option<[f64; 3]>,
+    ) -> Self {
+        self.position = position;
+        self.rotation = rotation;
+        self
+    }
+
     /// Gets the name.
     pub fn name(&self) -> &str {
         &self.name
@@ -479,6 +502,16 @@ impl Fixture {
     pub fn tags(&self) -> &[String] {
         &self.tags
     }
+
+    /// Gets the stage position in meters, if the venue places this fixture.
+    pub fn position(&self) -> Option<[f64; 3]> {
+        self.position
+    }
+
+    /// Gets the mounting rotation in degrees, if the venue gives one.
+    pub fn rotation(&self) -> Option<[f64; 3]> {
+        self.rotation
+    }
 }
 
 /// A venue definition.
@@ -489,12 +522,27 @@ pub struct Venue {
 
     /// The fixtures in the venue.
     fixtures: HashMap<String, Fixture>,
+
+    /// Named points on stage that shows aim movers at, in the same
+    /// coordinates as fixture positions.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    focus_points: HashMap<String, [f64; 3]>,
 }
 
 impl Venue {
     /// Creates a new venue.
     pub fn new(name: String, fixtures: HashMap<String, Fixture>) -> Venue {
-        Venue { name, fixtures }
+        Venue {
+            name,
+            fixtures,
+            focus_points: HashMap::new(),
+        }
+    }
+
+    /// Sets the venue's named focus points.
+    pub fn with_focus_points(mut self, focus_points: HashMap<String, [f64; 3]>) -> Self {
+        self.focus_points = focus_points;
+        self
     }
 
     /// Gets the name.
@@ -506,6 +554,11 @@ impl Venue {
     pub fn fixtures(&self) -> &HashMap<String, Fixture> {
         &self.fixtures
     }
+
+    /// Gets the named focus points.
+    pub fn focus_points(&self) -> &HashMap<String, [f64; 3]> {
+        &self.focus_points
+    }
 }
 
 impl fmt::Display for Venue {
@@ -523,8 +576,19 @@ impl fmt::Display for Venue {
                 let tags: Vec<String> = fix.tags.iter().map(|t| format!("\"{t}\"")).collect();
                 write!(f, " tags [{}]", tags.join(", "))?;
             }
+            if let Some([x, y, z]) = fix.position {
+                write!(f, " position ({x}, {y}, {z})")?;
+            }
+            if let Some([x, y, z]) = fix.rotation {
+                write!(f, " rotation ({x}, {y}, {z})")?;
+            }
             writeln!(f)?;
         }
+        let mut focus_points: Vec<_> = self.focus_points.iter().collect();
+        focus_points.sort_by_key(|(name, _)| *name);
+        for (name, [x, y, z]) in focus_points {
+            writeln!(f, "  focus \"{name}\" ({x}, {y}, {z})")?;
+        }
         write!(f, "}}")
     }
 }
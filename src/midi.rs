@@ -19,9 +19,11 @@ use tokio::sync::mpsc::Sender;
 use crate::{config, dmx::engine::Engine, playsync::PlaybackSync, songs::Song};
 
 pub(crate) mod beat_clock;
+pub mod chase;
 pub(crate) mod midir;
 pub(crate) mod mock;
 pub mod morningstar;
+pub(crate) mod mtc;
 pub(crate) mod playback;
 mod transform;
 
@@ -67,6 +69,12 @@ pub trait Device: Any + fmt::Display + std::marker::Send + std::marker::Sync {
     /// Sends raw SysEx bytes to the MIDI output.
     fn emit_sysex(&self, bytes: &[u8]) -> Result<(), Box<dyn Error>>;
 
+    /// The external transport arriving on the MIDI input, when the device is
+    /// configured to chase one.
+    fn external_transport(&self) -> Option<Arc<chase::ExternalTransport>> {
+        None
+    }
+
     #[cfg(test)]
     fn to_mock(&self) -> Result<Arc<mock::Device>, Box<dyn Error>>;
 }
@@ -95,7 +103,8 @@ pub fn get_device(
 
     let device = config.device();
     if device.starts_with("mock") {
-        return Ok(Some(Arc::new(mock::Device::get(device))));
+        let chase = config.chase().map(|chase| chase.source());
+        return Ok(Some(Arc::new(mock::Device::get(device).with_chase(chase))));
     };
 
     Ok(Some(Arc::new(midir::get(&config, dmx_engine)?)))
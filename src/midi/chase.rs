@@ -0,0 +1,648 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+
+//! Chasing an external transport: decodes incoming MIDI Timecode or MIDI beat
+//! clock into a position and a running state that playback can follow. Linear
+//! timecode, decoded off an audio input by the trigger engine, arrives here as
+//! whole frames.
+
+use std::time::{Duration, Instant};
+
+use crate::config::{ChaseSource, MtcFrameRate, Timecode};
+
+use super::mtc::to_frame_count;
+
+/// How long MIDI Timecode may go quiet before the external transport is
+/// taken to have stopped. Ten quarter-frames at the slowest rate.
+const MTC_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// How long the beat clock may go quiet while running before the external
+/// transport is taken to have stopped.
+const CLOCK_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// How long linear timecode may go quiet before the external transport is
+/// taken to have stopped. A couple of frames at the slowest rate.
+const LTC_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// MIDI beat clock pulses per quarter note.
+const CLOCKS_PER_BEAT: f64 = 24.0;
+
+/// How heavily each new timing clock interval weighs into the tempo estimate.
+const TEMPO_SMOOTHING: f64 = 0.1;
+
+/// Where an external transport is.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExternalPosition {
+    /// A timecode position, as time since 00:00:00:00.
+    Timecode {
+        position: Duration,
+        rate: MtcFrameRate,
+    },
+    /// Beats since the top of the song, and the tempo the beat clock is
+    /// arriving at once it has been measured.
+    Beats {
+        beats: f64,
+        seconds_per_beat: Option<f64>,
+    },
+}
+
+/// A snapshot of an external transport.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExternalState {
+    pub position: ExternalPosition,
+    pub running: bool,
+}
+
+/// An external transport fed from the MIDI input, or from a linear timecode
+/// reader on an audio input.
+///
+/// The input thread feeds every incoming message or frame in; playback reads
+/// the state back. Messages for the transport that isn't being chased are
+/// ignored.
+pub struct ExternalTransport {
+    decoder: parking_lot::Mutex<Decoder>,
+}
+
+enum Decoder {
+    Mtc(MtcDecoder),
+    Clock(ClockDecoder),
+    Ltc(LtcDecoder),
+}
+
+impl ExternalTransport {
+    /// Creates a transport following `source`.
+    pub fn new(source: ChaseSource) -> ExternalTransport {
+        ExternalTransport {
+            decoder: parking_lot::Mutex::new(match source {
+                ChaseSource::Mtc => Decoder::Mtc(MtcDecoder::default()),
+                ChaseSource::Clock => Decoder::Clock(ClockDecoder::default()),
+            }),
+        }
+    }
+
+    /// Creates a transport following linear timecode.
+    pub fn linear_timecode() -> ExternalTransport {
+        ExternalTransport {
+            decoder: parking_lot::Mutex::new(Decoder::Ltc(LtcDecoder::default())),
+        }
+    }
+
+    /// Feeds an incoming MIDI message, received at `at`.
+    pub fn feed(&self, bytes: &[u8], at: Instant) {
+        match &mut *self.decoder.lock() {
+            Decoder::Mtc(decoder) => decoder.feed(bytes, at),
+            Decoder::Clock(decoder) => decoder.feed(bytes, at),
+            Decoder::Ltc(_) => {}
+        }
+    }
+
+    /// Feeds a linear timecode frame that finished arriving at `at`.
+    pub fn feed_frame(&self, timecode: Timecode, rate: MtcFrameRate, at: Instant) {
+        if let Decoder::Ltc(decoder) = &mut *self.decoder.lock() {
+            decoder.frame(timecode, rate, at);
+        }
+    }
+
+    /// The transport's state now. None until it has been located.
+    pub fn state(&self) -> Option<ExternalState> {
+        self.state_at(Instant::now())
+    }
+
+    /// The transport's state at `now`, interpolated from the last message.
+    pub fn state_at(&self, now: Instant) -> Option<ExternalState> {
+        match &*self.decoder.lock() {
+            Decoder::Mtc(decoder) => decoder.state_at(now),
+            Decoder::Clock(decoder) => decoder.state_at(now),
+            Decoder::Ltc(decoder) => decoder.state_at(now),
+        }
+    }
+}
+
+/// Decodes MIDI Timecode.
+///
+/// Positions are counted in quarter-frames from 00:00:00:00, mirroring
+/// [`super::mtc::MtcGenerator`]. The eight pieces of a group carry the
+/// timecode of the frame the group started on, so the position is only known
+/// once piece 7 completes it; from then on each quarter-frame in sequence
+/// moves it on by one.
+#[derive(Default)]
+struct MtcDecoder {
+    /// Nibbles of the group being assembled.
+    pieces: [u8; 8],
+    /// Which pieces of the group have arrived.
+    received: u8,
+    rate: MtcFrameRate,
+    /// The quarter-frame the transport was last known to be at, and when.
+    quarter: Option<(u64, Instant)>,
+    /// Whether the position came from a completed group, and so moves on
+    /// with each quarter-frame in sequence. A full frame holds its position
+    /// until the next group completes.
+    sequenced: bool,
+    /// When the last quarter-frame arrived. Full frames locate without
+    /// running.
+    last_quarter_frame: Option<Instant>,
+}
+
+impl MtcDecoder {
+    fn feed(&mut self, bytes: &[u8], at: Instant) {
+        match bytes {
+            [0xF1, data] => self.quarter_frame(*data, at),
+            [0xF0, 0x7F, _, 0x01, 0x01, hours, minutes, seconds, frames, 0xF7] => {
+                self.rate = rate_from_code(hours >> 5);
+                let timecode = Timecode::new(hours & 0x1F, *minutes, *seconds, *frames);
+                self.quarter = Some((to_frame_count(timecode, self.rate) * 4, at));
+                self.sequenced = false;
+                self.last_quarter_frame = None;
+                self.received = 0;
+            }
+            _ => {}
+        }
+    }
+
+    fn quarter_frame(&mut self, data: u8, at: Instant) {
+        let piece = data >> 4 & 0x07;
+        self.pieces[usize::from(piece)] = data & 0x0F;
+        if piece == 0 {
+            self.received = 0;
+        }
+        self.received |= 1 << piece;
+        self.last_quarter_frame = Some(at);
+
+        if piece == 7 && self.received == 0xFF {
+            let p = &self.pieces;
+            self.rate = rate_from_code(p[7] >> 1 & 0x03);
+            let timecode = Timecode::new(
+                (p[7] & 0x01) << 4 | p[6],
+                p[5] << 4 | p[4],
+                p[3] << 4 | p[2],
+                p[1] << 4 | p[0],
+            );
+            self.quarter = Some((to_frame_count(timecode, self.rate) * 4 + 7, at));
+            self.sequenced = true;
+            return;
+        }
+        if !self.sequenced {
+            return;
+        }
+        // Between completed groups, a quarter-frame that follows on from the
+        // last moves the position on. Anything else is a jump or running
+        // backwards: wait for the next complete group to say where we are.
+        match self.quarter {
+            Some((quarter, _)) if (quarter + 1) % 8 == u64::from(piece) => {
+                self.quarter = Some((quarter + 1, at));
+            }
+            _ => {
+                self.quarter = None;
+                self.sequenced = false;
+            }
+        }
+    }
+
+    fn state_at(&self, now: Instant) -> Option<ExternalState> {
+        let (quarter, at) = self.quarter?;
+        let running = self
+            .last_quarter_frame
+            .is_some_and(|last| now.saturating_duration_since(last) < MTC_TIMEOUT);
+        let quarter_length = 1.0 / (self.rate.real_rate() * 4.0);
+        let mut seconds = quarter as f64 * quarter_length;
+        if running {
+            // Between quarter-frames, carry on at the frame rate — but never
+            // past where the next one is due.
+            seconds += now
+                .saturating_duration_since(at)
+                .as_secs_f64()
+                .min(quarter_length);
+        }
+        Some(ExternalState {
+            position: ExternalPosition::Timecode {
+                position: Duration::from_secs_f64(seconds),
+                rate: self.rate,
+            },
+            running,
+        })
+    }
+}
+
+/// Follows linear timecode frames.
+///
+/// A frame's timecode is that of its first bit, and it is only known once its
+/// sync word has arrived, so a frame that just finished puts the transport
+/// at the start of the next.
+#[derive(Default)]
+struct LtcDecoder {
+    rate: MtcFrameRate,
+    /// Frames since 00:00:00:00 at the end of the last frame, and when it
+    /// ended.
+    frame: Option<(u64, Instant)>,
+    /// Whether the last frame followed on from the one before. Parked
+    /// sources often repeat one frame, or jump: neither is running.
+    advancing: bool,
+}
+
+impl LtcDecoder {
+    fn frame(&mut self, timecode: Timecode, rate: MtcFrameRate, at: Instant) {
+        let end = to_frame_count(timecode, rate) + 1;
+        self.advancing = self
+            .frame
+            .is_some_and(|(last, _)| rate == self.rate && last + 1 == end);
+        self.rate = rate;
+        self.frame = Some((end, at));
+    }
+
+    fn state_at(&self, now: Instant) -> Option<ExternalState> {
+        let (frame, at) = self.frame?;
+        let since = now.saturating_duration_since(at);
+        let running = self.advancing && since < LTC_TIMEOUT;
+        let frame_length = 1.0 / self.rate.real_rate();
+        let mut seconds = frame as f64 * frame_length;
+        if running {
+            // Between frames, carry on at the frame rate up to where the
+            // next one is due.
+            seconds += since.as_secs_f64().min(frame_length);
+        }
+        Some(ExternalState {
+            position: ExternalPosition::Timecode {
+                position: Duration::from_secs_f64(seconds),
+                rate: self.rate,
+            },
+            running,
+        })
+    }
+}
+
+/// Maps the two-bit MTC rate code to a frame rate.
+fn rate_from_code(code: u8) -> MtcFrameRate {
+    match code & 0x03 {
+        0 => MtcFrameRate::Fps24,
+        1 => MtcFrameRate::Fps25,
+        2 => MtcFrameRate::Fps2997DropFrame,
+        _ => MtcFrameRate::Fps30,
+    }
+}
+
+/// Decodes MIDI beat clock: Song Position Pointer to locate, Start, Continue
+/// and Stop for the transport, and timing clocks to count beats and measure
+/// the tempo.
+#[derive(Default)]
+struct ClockDecoder {
+    /// Whether a Start, Continue or Song Position Pointer has said where the
+    /// transport is.
+    located: bool,
+    running: bool,
+    /// Beats counted at the last timing clock.
+    beats: f64,
+    /// The first timing clock after Start or Continue marks the position
+    /// rather than moving it on.
+    awaiting_first_clock: bool,
+    /// When the last timing clock arrived, or the transport started.
+    last_activity: Option<Instant>,
+    last_clock: Option<Instant>,
+    seconds_per_clock: Option<f64>,
+}
+
+impl ClockDecoder {
+    fn feed(&mut self, bytes: &[u8], at: Instant) {
+        match bytes {
+            [0xF2, lsb, msb] => {
+                // Song Position Pointer counts sixteenth notes.
+                let sixteenths = u16::from(*msb & 0x7F) << 7 | u16::from(*lsb & 0x7F);
+                self.beats = f64::from(sixteenths) / 4.0;
+                self.located = true;
+            }
+            [0xFA] => {
+                self.beats = 0.0;
+                self.start(at);
+            }
+            [0xFB] => self.start(at),
+            [0xFC] => self.running = false,
+            [0xF8] => self.clock(at),
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, at: Instant) {
+        self.located = true;
+        self.running = true;
+        self.awaiting_first_clock = true;
+        self.last_activity = Some(at);
+    }
+
+    fn clock(&mut self, at: Instant) {
+        // Timing clocks often keep coming while stopped, so the tempo is
+        // measured whatever the transport is doing.
+        if let Some(last) = self.last_clock {
+            let interval = at.saturating_duration_since(last).as_secs_f64();
+            if interval > 0.0 && interval < CLOCK_TIMEOUT.as_secs_f64() {
+                self.seconds_per_clock = Some(match self.seconds_per_clock {
+                    Some(current) => current + (interval - current) * TEMPO_SMOOTHING,
+                    None => interval,
+                });
+            }
+        }
+        self.last_clock = Some(at);
+        if !self.running {
+            return;
+        }
+        if self.awaiting_first_clock {
+            self.awaiting_first_clock = false;
+        } else {
+            self.beats += 1.0 / CLOCKS_PER_BEAT;
+        }
+        self.last_activity = Some(at);
+    }
+
+    fn state_at(&self, now: Instant) -> Option<ExternalState> {
+        if !self.located {
+            return None;
+        }
+        let running = self.running
+            && self
+                .last_activity
+                .is_some_and(|last| now.saturating_duration_since(last) < CLOCK_TIMEOUT);
+        let mut beats = self.beats;
+        if let (true, false, Some(last), Some(per_clock)) = (
+            running,
+            self.awaiting_first_clock,
+            self.last_clock,
+            self.seconds_per_clock,
+        ) {
+            // Between clocks, carry on at the measured tempo up to where the
+            // next one is due.
+            let since = now.saturating_duration_since(last).as_secs_f64();
+            beats += since.min(per_clock) / per_clock / CLOCKS_PER_BEAT;
+        }
+        Some(ExternalState {
+            position: ExternalPosition::Beats {
+                beats,
+                seconds_per_beat: self.seconds_per_clock.map(|s| s * CLOCKS_PER_BEAT),
+            },
+            running,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use super::*;
+    use crate::midi::midir::MidiSender;
+    use crate::midi::mtc::MtcGenerator;
+
+    /// Feeds whatever a timecode generator sends straight into a transport.
+    struct Loopback<'a> {
+        transport: &'a ExternalTransport,
+        at: Instant,
+    }
+
+    impl MidiSender for Loopback<'_> {
+        fn send(&mut self, bytes: &[u8]) -> Result<(), Box<dyn Error>> {
+            self.transport.feed(bytes, self.at);
+            Ok(())
+        }
+    }
+
+    fn timecode_position(state: ExternalState) -> Duration {
+        match state.position {
+            ExternalPosition::Timecode { position, .. } => position,
+            other => panic!("expected a timecode position, got {other:?}"),
+        }
+    }
+
+    fn beats(state: ExternalState) -> f64 {
+        match state.position {
+            ExternalPosition::Beats { beats, .. } => beats,
+            other => panic!("expected a beat position, got {other:?}"),
+        }
+    }
+
+    fn assert_near(actual: Duration, expected: Duration) {
+        let diff = actual.abs_diff(expected);
+        assert!(
+            diff < Duration::from_millis(2),
+            "expected {expected:?}, got {actual:?}"
+        );
+    }
+
+    #[test]
+    fn nothing_known_until_located() {
+        let transport = ExternalTransport::new(ChaseSource::Mtc);
+        assert!(transport.state().is_none());
+        let transport = ExternalTransport::new(ChaseSource::Clock);
+        transport.feed(&[0xF8], Instant::now());
+        assert!(transport.state().is_none());
+    }
+
+    #[test]
+    fn full_frame_locates_without_running() {
+        let transport = ExternalTransport::new(ChaseSource::Mtc);
+        let now = Instant::now();
+        // 01:00:10:12 at 25 fps.
+        transport.feed(
+            &[0xF0, 0x7F, 0x7F, 0x01, 0x01, 0x20 | 1, 0, 10, 12, 0xF7],
+            now,
+        );
+        let state = transport.state_at(now).unwrap();
+        assert!(!state.running);
+        assert_near(
+            timecode_position(state),
+            Duration::from_secs(3610) + Duration::from_millis(480),
+        );
+        assert!(matches!(
+            state.position,
+            ExternalPosition::Timecode {
+                rate: MtcFrameRate::Fps25,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn follows_generated_timecode() {
+        let transport = ExternalTransport::new(ChaseSource::Mtc);
+        let start = Timecode::new(0, 0, 5, 0);
+        let mut generator = MtcGenerator::new(MtcFrameRate::Fps30, start);
+        let origin = Instant::now();
+        let mut loopback = Loopback {
+            transport: &transport,
+            at: origin,
+        };
+        generator.locate(&mut loopback, Duration::ZERO);
+        // Play two seconds of quarter-frames, each arriving when it is due.
+        let quarter = Duration::from_secs_f64(1.0 / 120.0);
+        let mut position = Duration::ZERO;
+        while position < Duration::from_secs(2) {
+            position += quarter;
+            loopback.at = origin + position;
+            generator.advance(&mut loopback, position + Duration::from_micros(100));
+        }
+        let state = transport.state_at(origin + position).unwrap();
+        assert!(state.running);
+        assert_near(timecode_position(state), Duration::from_secs(5) + position);
+        // Interpolates between quarter-frames, but not past the next one.
+        let later = transport
+            .state_at(origin + position + Duration::from_millis(5))
+            .unwrap();
+        assert_near(
+            timecode_position(later),
+            Duration::from_secs(5) + position + Duration::from_millis(5),
+        );
+        let stalled = transport
+            .state_at(origin + position + Duration::from_millis(50))
+            .unwrap();
+        assert_near(
+            timecode_position(stalled),
+            Duration::from_secs(5) + position + quarter,
+        );
+        // And stops once the quarter-frames do.
+        let stopped = transport
+            .state_at(origin + position + Duration::from_millis(500))
+            .unwrap();
+        assert!(!stopped.running);
+    }
+
+    #[test]
+    fn out_of_sequence_quarter_frames_drop_the_lock() {
+        let transport = ExternalTransport::new(ChaseSource::Mtc);
+        let now = Instant::now();
+        for piece in 0..8u8 {
+            transport.feed(&[0xF1, piece << 4], now);
+        }
+        assert!(transport.state_at(now).is_some());
+        // Piece 0 should come next; piece 6 means the source ran backwards.
+        transport.feed(&[0xF1, 6 << 4], now);
+        assert!(transport.state_at(now).is_none());
+    }
+
+    #[test]
+    fn clock_counts_beats_from_start() {
+        let transport = ExternalTransport::new(ChaseSource::Clock);
+        let origin = Instant::now();
+        // 120 bpm: a clock every 1/48s.
+        let clock = Duration::from_secs_f64(0.5 / 24.0);
+        transport.feed(&[0xFA], origin);
+        for i in 0..=48u32 {
+            transport.feed(&[0xF8], origin + clock * i);
+        }
+        let state = transport.state_at(origin + clock * 48).unwrap();
+        assert!(state.running);
+        assert!((beats(state) - 2.0).abs() < 1e-9, "{state:?}");
+        let ExternalPosition::Beats {
+            seconds_per_beat, ..
+        } = state.position
+        else {
+            unreachable!();
+        };
+        assert!((seconds_per_beat.unwrap() - 0.5).abs() < 1e-6);
+        // Half a clock later, half a clock further on.
+        let between = transport.state_at(origin + clock * 48 + clock / 2).unwrap();
+        assert!((beats(between) - (2.0 + 0.5 / 24.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn clock_song_position_and_stop() {
+        let transport = ExternalTransport::new(ChaseSource::Clock);
+        let now = Instant::now();
+        // Sixteenth 34: beat 8.5.
+        transport.feed(&[0xF2, 34, 0], now);
+        let state = transport.state_at(now).unwrap();
+        assert!(!state.running);
+        assert_eq!(beats(state), 8.5);
+
+        transport.feed(&[0xFB], now);
+        transport.feed(&[0xF8], now);
+        transport.feed(&[0xF8], now + Duration::from_millis(20));
+        let state = transport.state_at(now + Duration::from_millis(20)).unwrap();
+        assert!(state.running);
+        assert!((beats(state) - (8.5 + 1.0 / 24.0)).abs() < 1e-9);
+
+        transport.feed(&[0xFC], now + Duration::from_millis(30));
+        // Clocks keep coming while stopped, but don't move the position.
+        transport.feed(&[0xF8], now + Duration::from_millis(40));
+        let state = transport.state_at(now + Duration::from_millis(40)).unwrap();
+        assert!(!state.running);
+        assert!((beats(state) - (8.5 + 1.0 / 24.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn clock_stops_when_clocks_stop() {
+        let transport = ExternalTransport::new(ChaseSource::Clock);
+        let now = Instant::now();
+        transport.feed(&[0xFA], now);
+        assert!(transport.state_at(now).unwrap().running);
+        assert!(!transport.state_at(now + CLOCK_TIMEOUT).unwrap().running);
+    }
+
+    #[test]
+    fn follows_linear_timecode_frames() {
+        let transport = ExternalTransport::linear_timecode();
+        let origin = Instant::now();
+        let frame = Duration::from_millis(40);
+        // 02:00:00:00 at 25 fps, the first frame alone locates.
+        transport.feed_frame(Timecode::new(2, 0, 0, 0), MtcFrameRate::Fps25, origin);
+        let state = transport.state_at(origin).unwrap();
+        assert!(!state.running);
+        assert_near(timecode_position(state), Duration::from_secs(7200) + frame);
+
+        for i in 1..25u8 {
+            transport.feed_frame(
+                Timecode::new(2, 0, 0, i),
+                MtcFrameRate::Fps25,
+                origin + frame * u32::from(i),
+            );
+        }
+        let last = origin + frame * 24;
+        let state = transport.state_at(last).unwrap();
+        assert!(state.running);
+        assert_near(timecode_position(state), Duration::from_secs(7201));
+        // Interpolates up to the next frame, then stops once frames do.
+        let state = transport
+            .state_at(last + Duration::from_millis(10))
+            .unwrap();
+        assert_near(
+            timecode_position(state),
+            Duration::from_secs(7201) + Duration::from_millis(10),
+        );
+        let state = transport
+            .state_at(last + Duration::from_millis(60))
+            .unwrap();
+        assert!(state.running);
+        assert_near(timecode_position(state), Duration::from_secs(7201) + frame);
+        assert!(!transport.state_at(last + LTC_TIMEOUT).unwrap().running);
+
+        // A repeated frame is parked, not running.
+        transport.feed_frame(Timecode::new(2, 0, 0, 24), MtcFrameRate::Fps25, last);
+        assert!(!transport.state_at(last).unwrap().running);
+    }
+
+    #[test]
+    fn ignores_the_other_source() {
+        let transport = ExternalTransport::new(ChaseSource::Clock);
+        let now = Instant::now();
+        for piece in 0..8u8 {
+            transport.feed(&[0xF1, piece << 4], now);
+        }
+        assert!(transport.state_at(now).is_none());
+
+        let transport = ExternalTransport::new(ChaseSource::Mtc);
+        transport.feed(&[0xFA], now);
+        transport.feed(&[0xF2, 4, 0], now);
+        transport.feed_frame(Timecode::new(0, 0, 1, 0), MtcFrameRate::Fps30, now);
+        assert!(transport.state_at(now).is_none());
+
+        let transport = ExternalTransport::linear_timecode();
+        transport.feed(&[0xFA], now);
+        assert!(transport.state_at(now).is_none());
+    }
+}
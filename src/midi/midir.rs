@@ -20,7 +20,7 @@ use std::{
         mpsc, Arc, Mutex,
     },
     thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use midir::{MidiInput, MidiInputConnection, MidiInputPort, MidiOutput, MidiOutputPort};
@@ -36,7 +36,11 @@ use crate::{
     songs::Song,
 };
 
-use super::transform::{ControlChangeMapper, MidiTransformer, NoteMapper};
+use super::{
+    chase::ExternalTransport,
+    mtc::{run_mtc, MtcPlay},
+    transform::{ControlChangeMapper, MidiTransformer, NoteMapper},
+};
 
 /// Trait abstracting MIDI output so we can test without hardware.
 pub(crate) trait MidiSender: Send {
@@ -69,6 +73,14 @@ pub struct Device {
     /// tick schedule; between songs it either free-runs the last tempo (when
     /// `persist_tempo` is set) or stays silent.
     beat_clock_engine: Option<Arc<BeatClockEngine>>,
+    /// The MIDI Timecode frame rate, when timecode output is enabled.
+    mtc_frame_rate: Option<config::MtcFrameRate>,
+    /// The external transport being chased, fed by `chase_connection`.
+    external_transport: Option<Arc<ExternalTransport>>,
+    /// A dedicated input connection for the chased transport, kept apart
+    /// from `event_connection` so it runs whether or not anything is
+    /// watching for control events.
+    chase_connection: Mutex<Option<MidiInputConnection<()>>>,
 }
 
 impl Device {
@@ -84,8 +96,42 @@ impl Device {
             dmx_engine: None,
             dmx_midi_transformers: HashMap::new(),
             beat_clock_engine: None,
+            mtc_frame_rate: None,
+            external_transport: None,
+            chase_connection: Mutex::new(None),
         }
     }
+
+    /// Starts MIDI Timecode for a song on its own output connection, if
+    /// timecode is enabled. It runs until the song ends or is cancelled,
+    /// whether or not the song has a MIDI file.
+    fn start_mtc(
+        &self,
+        song: &Song,
+        sync: &crate::playsync::PlaybackSync,
+    ) -> Result<Option<thread::JoinHandle<()>>, Box<dyn Error>> {
+        let (Some(rate), Some(output_port)) = (self.mtc_frame_rate, self.output_port.as_ref())
+        else {
+            return Ok(None);
+        };
+        let output = MidiOutput::new("mtrack timecode output")?;
+        let mut connection = output.connect(output_port, "mtrack timecode")?;
+        let play = MtcPlay {
+            rate,
+            start: song.mtc_start(),
+            start_time: sync.start_time,
+            playback_delay: self.playback_delay,
+            duration: song.duration(),
+            loop_playback: song.loop_playback(),
+            cancel: sync.cancel_handle.clone(),
+            clock: sync.clock.clone(),
+            loop_time_consumed: sync.loop_control.loop_time_consumed.clone(),
+        };
+        Ok(Some(thread::spawn(move || {
+            promote_to_realtime_thread();
+            run_mtc(&mut connection, &play);
+        })))
+    }
 }
 
 impl super::Device for Device {
@@ -196,6 +242,10 @@ impl super::Device for Device {
         song: Arc<Song>,
         sync: crate::playsync::PlaybackSync,
     ) -> Result<(), Box<dyn Error>> {
+        // Timecode follows the song whether or not it has anything else to
+        // play over MIDI. Waited on below, so the MIDI side of playback
+        // finishes when the timecode does.
+        let mtc = self.start_mtc(&song, &sync)?;
         let crate::playsync::PlaybackSync {
             cancel_handle,
             mut ready_tx,
@@ -218,6 +268,7 @@ impl super::Device for Device {
                 info!(song = song.name(), "Song has no MIDI sheet.");
                 // Guard sends on drop, but explicit send is clearer.
                 ready_tx.send();
+                join_mtc(mtc);
                 return Ok(());
             }
         };
@@ -244,6 +295,7 @@ impl super::Device for Device {
             duration = song.duration_string(),
             start_time = ?start_time,
             beat_clock = self.beat_clock_enabled,
+            mtc = ?self.mtc_frame_rate,
             "Playing song MIDI."
         );
 
@@ -303,6 +355,7 @@ impl super::Device for Device {
         if join_handle.join().is_err() {
             return Err("Error while joining thread!".into());
         }
+        join_mtc(mtc);
 
         info!("MIDI playback stopped.");
 
@@ -366,6 +419,10 @@ impl super::Device for Device {
         Ok(())
     }
 
+    fn external_transport(&self) -> Option<Arc<ExternalTransport>> {
+        self.external_transport.clone()
+    }
+
     #[cfg(test)]
     fn to_mock(&self) -> Result<Arc<super::mock::Device>, Box<dyn Error>> {
         Err("not a mock".into())
@@ -514,6 +571,7 @@ pub fn get(
     let mut midi_device = matches.swap_remove(0);
     midi_device.playback_delay = playback_delay;
     midi_device.beat_clock_enabled = config.beat_clock();
+    midi_device.mtc_frame_rate = config.mtc().map(|mtc| mtc.frame_rate());
     midi_device.midi_to_dmx_mappings = midi_to_dmx_mappings;
     midi_device.dmx_engine = dmx_engine;
     midi_device.dmx_midi_transformers = dmx_midi_transformers;
@@ -532,10 +590,42 @@ pub fn get(
         }
     }
 
+    if let Some(chase) = config.chase() {
+        match midi_device.input_port.as_ref() {
+            Some(input_port) => {
+                let transport = Arc::new(ExternalTransport::new(chase.source()));
+                let feed = transport.clone();
+                let input = MidiInput::new("mtrack chase input")?;
+                let connection = input.connect(
+                    input_port,
+                    "mtrack chase",
+                    move |_, raw_event, _| feed.feed(raw_event, Instant::now()),
+                    (),
+                )?;
+                info!(source = ?chase.source(), "Chasing an external transport.");
+                *midi_device
+                    .chase_connection
+                    .lock()
+                    .expect("unable to get lock") = Some(connection);
+                midi_device.external_transport = Some(transport);
+            }
+            None => warn!("chase is enabled but the MIDI device has no input port; ignoring."),
+        }
+    }
+
     // We've verified that there's only one element in the vector, so this should be safe.
     Ok(midi_device)
 }
 
+/// Waits for a song's timecode to finish.
+fn join_mtc(mtc: Option<thread::JoinHandle<()>>) {
+    if let Some(mtc) = mtc {
+        if mtc.join().is_err() {
+            error!("MIDI Timecode thread panicked");
+        }
+    }
+}
+
 /// Builds MIDI-to-DMX channel mappings and transformers from config.
 fn build_transformers(config: &config::Midi) -> Result<TransformerConfig, Box<dyn Error>> {
     let mut midi_to_dmx_mappings = HashMap::new();
@@ -678,15 +768,17 @@ fn run_playback(sender: &mut dyn MidiSender, mut ctx: PlaybackContext<'_>) {
     }
 
     // Sleep the playback delay in small increments so we can
-    // respond to cancellation promptly.
+    // respond to cancellation promptly. The delay is real time, so it reads
+    // as more or less song time at a rehearsal speed.
     {
-        while ctx.clock.elapsed() < ctx.playback_delay {
+        let playback_delay = ctx.playback_delay.mul_f64(ctx.clock.rate().speed);
+        while ctx.clock.elapsed() < playback_delay {
             if ctx.cancel_handle.is_cancelled() {
                 ctx.finished.store(true, Ordering::Relaxed);
                 ctx.cancel_handle.notify();
                 return;
             }
-            let remaining = ctx.playback_delay.saturating_sub(ctx.clock.elapsed());
+            let remaining = ctx.clock.wall_until(playback_delay);
             spin_sleep::sleep(remaining.min(Duration::from_millis(50)));
         }
     }
@@ -853,9 +945,19 @@ fn run_beat_clock(
         }
 
         let target_wall = *tick_time - start_time;
-        let elapsed = clock.elapsed();
-        if target_wall > elapsed {
-            spin_sleep::sleep(target_wall - elapsed);
+        // Stop downstream gear for the length of a pause and pick it back up
+        // where it left off.
+        let waited = wait_for_clock(clock, target_wall, cancel_handle, |held| {
+            let message = if held {
+                SystemRealtime::Stop
+            } else {
+                SystemRealtime::Continue
+            };
+            let _ = sender.send(&realtime_bytes(message));
+        });
+        if !waited {
+            let _ = sender.send(&stop_bytes);
+            return ScheduleOutcome::Finished(last_interval);
         }
 
         if cancel_handle.is_cancelled() {
@@ -1238,6 +1340,15 @@ fn wait_for_start_holding_tempo(
 ) {
     let Some(hold) = hold else {
         play.clock.wait_for_start_or_cancel(&play.cancel);
+        // A scheduled start (a hand-off from the previous song) starts the
+        // clock ahead of the sample it runs from; Start goes out on that.
+        while !play.cancel.is_cancelled() {
+            let until = play.clock.until_running();
+            if until.is_zero() {
+                break;
+            }
+            spin_sleep::sleep(until.min(Duration::from_millis(10)));
+        }
         return;
     };
 
@@ -1250,6 +1361,14 @@ fn wait_for_start_holding_tempo(
         }
         hold.tick_if_due(sender);
     }
+    while !play.cancel.is_cancelled() {
+        let until = play.clock.until_running();
+        if until.is_zero() {
+            break;
+        }
+        spin_sleep::sleep(until.min(hold.until_next()));
+        hold.tick_if_due(sender);
+    }
 }
 
 /// Parameters for playing pre-computed MIDI events.
@@ -1302,11 +1421,12 @@ fn play_precomputed(params: &MidiPlaybackParams<'_>, sender: &mut dyn MidiSender
         // target_wall is relative to the section start, then offset by
         // clock_base so it aligns with the continuously-advancing clock.
         let target_wall = params.clock_base + (event.time - params.start_time);
-        let elapsed = params.clock.elapsed();
-        if target_wall > elapsed {
-            spin_sleep::sleep(target_wall - elapsed);
-        }
-        if params.cancel_handle.is_cancelled() {
+        let waited = wait_for_clock(params.clock, target_wall, params.cancel_handle, |held| {
+            if held {
+                silence_notes(sender, params.exclude_channels);
+            }
+        });
+        if !waited {
             return;
         }
 
@@ -1318,6 +1438,66 @@ fn play_precomputed(params: &MidiPlaybackParams<'_>, sender: &mut dyn MidiSender
     }
 }
 
+/// Longest single sleep while waiting on the playback clock. Waits are taken in
+/// slices so a paused clock holds the next event rather than it firing on its
+/// original schedule, and so a resume is picked up promptly.
+const CLOCK_WAIT_SLICE: Duration = Duration::from_millis(20);
+
+/// Sleeps until `clock` reaches `target`. Returns false if playback was
+/// cancelled while waiting.
+///
+/// `on_hold` is called with `true` if the clock pauses short of `target` —
+/// nothing more will be sent until it resumes — and with `false` when it
+/// does resume.
+fn wait_for_clock(
+    clock: &PlaybackClock,
+    target: Duration,
+    cancel_handle: &CancelHandle,
+    mut on_hold: impl FnMut(bool),
+) -> bool {
+    let mut held = false;
+    let mut deadline = Instant::now() + clock.wall_until(target);
+    loop {
+        if cancel_handle.is_cancelled() {
+            return false;
+        }
+        let holding = clock.hold_position().is_some_and(|hold| hold < target);
+        if holding != held {
+            on_hold(holding);
+            held = holding;
+            if !holding {
+                // Re-anchor on the resumed clock.
+                deadline = Instant::now() + clock.wall_until(target);
+            }
+        }
+        if held {
+            spin_sleep::sleep(CLOCK_WAIT_SLICE);
+            continue;
+        }
+        let now = Instant::now();
+        if now >= deadline || clock.elapsed() >= target {
+            return true;
+        }
+        spin_sleep::sleep((deadline - now).min(CLOCK_WAIT_SLICE));
+    }
+}
+
+/// Sends sustain off and All Notes Off on every channel not excluded from
+/// playback, so nothing is left sounding while the transport is paused.
+fn silence_notes(sender: &mut dyn MidiSender, exclude_channels: &HashSet<u8>) {
+    for channel in 0..16u8 {
+        if exclude_channels.contains(&channel) {
+            continue;
+        }
+        // Sustain goes first: All Notes Off leaves pedal-held notes sounding.
+        for controller in [64u8, 123] {
+            if let Err(e) = sender.send(&[0xB0 | channel, controller, 0]) {
+                debug!("MIDI send failed: {:?}", e);
+            }
+        }
+    }
+}
+
 /// Promotes the current thread to real-time priority for low-jitter MIDI clock output.
 ///
 /// Uses the shared thread priority utility that sets a high crossplatform priority
@@ -2263,6 +2443,69 @@ mod test {
             assert_eq!(sent.len(), 1);
             assert_eq!(sent[0], vec![0x93, 72, 64]); // NoteOn ch3, key 72, vel 64
         }
+
+        #[test]
+        fn pause_silences_and_holds_next_event() {
+            let midi = make_events(&[0, 20]);
+            let cancel = CancelHandle::new();
+            let exclude: HashSet<u8> = [9].into_iter().collect();
+            let mut sender = MockSender::new();
+            let clock = PlaybackClock::wall();
+            clock.start();
+            clock.pause();
+
+            let resumer = {
+                let clock = clock.clone();
+                std::thread::spawn(move || {
+                    std::thread::sleep(Duration::from_millis(100));
+                    clock.resume();
+                })
+            };
+            run_play(
+                &midi,
+                Duration::ZERO,
+                &mut sender,
+                &cancel,
+                &exclude,
+                &clock,
+            );
+            resumer.join().unwrap();
+
+            let sent = sender.sent.lock();
+            // The first note, then sustain off + All Notes Off on the 15
+            // channels not excluded, then the held note after the resume.
+            assert_eq!(sent.len(), 1 + 15 * 2 + 1);
+            assert_eq!(sent[0], vec![0x90, 60, 100]);
+            let silence: Vec<Vec<u8>> = (0..16u8)
+                .filter(|&c| c != 9)
+                .flat_map(|c| [vec![0xB0 | c, 64, 0], vec![0xB0 | c, 123, 0]])
+                .collect();
+            assert_eq!(sent[1..31], silence[..]);
+            assert_eq!(sent[31], vec![0x90, 61, 100]);
+        }
+
+        #[test]
+        fn cancel_while_held_returns_false() {
+            let clock = PlaybackClock::wall();
+            clock.start();
+            clock.pause();
+            let cancel = CancelHandle::new();
+            let canceller = {
+                let cancel = cancel.clone();
+                std::thread::spawn(move || {
+                    std::thread::sleep(Duration::from_millis(50));
+                    cancel.cancel();
+                })
+            };
+
+            let mut holds = Vec::new();
+            let waited = wait_for_clock(&clock, Duration::from_secs(1), &cancel, |held| {
+                holds.push(held)
+            });
+            canceller.join().unwrap();
+            assert!(!waited);
+            assert_eq!(holds, vec![true]);
+        }
     }
 
     mod watch_events_tests {
@@ -2707,6 +2950,40 @@ mod test {
             assert_eq!(interval, Some(Duration::from_millis(3)));
         }
 
+        #[test]
+        fn pause_sends_stop_then_continue() {
+            let ticks = vec![Duration::from_millis(5), Duration::from_millis(10)];
+            let cancel = CancelHandle::new();
+            let mut sender = MockSender::new();
+            let clock = PlaybackClock::wall();
+            clock.start();
+            clock.pause();
+
+            let resumer = {
+                let clock = clock.clone();
+                std::thread::spawn(move || {
+                    std::thread::sleep(Duration::from_millis(50));
+                    clock.resume();
+                })
+            };
+            let (_tx, rx) = mpsc::channel();
+            run_beat_clock(&mut sender, &ticks, Duration::ZERO, &cancel, &clock, &rx);
+            resumer.join().unwrap();
+
+            let sent = sender.sent.lock();
+            assert_eq!(
+                *sent,
+                vec![
+                    start_bytes(),
+                    stop_bytes(),
+                    continue_bytes(),
+                    clock_bytes(),
+                    clock_bytes(),
+                    stop_bytes(),
+                ]
+            );
+        }
+
         #[test]
         fn returns_none_for_single_tick() {
             // A single tick can't establish an interval, so no tempo is reported.
@@ -26,7 +26,9 @@ use midly::live::LiveEvent;
 use tokio::{sync::mpsc::Sender, task::JoinHandle};
 use tracing::{info, span, Level};
 
-use crate::songs::Song;
+use crate::{config::ChaseSource, songs::Song};
+
+use super::chase::ExternalTransport;
 
 /// A mock device. Doesn't actually play anything.
 #[derive(Clone)]
@@ -38,6 +40,7 @@ pub struct Device {
     emit_called: Arc<Mutex<Option<Vec<u8>>>>,
     sysex_called: Arc<Mutex<Option<Vec<u8>>>>,
     event_thread: Arc<Mutex<Option<JoinHandle<()>>>>,
+    external_transport: Option<Arc<ExternalTransport>>,
 }
 
 impl Device {
@@ -51,9 +54,17 @@ impl Device {
             emit_called: Arc::new(Mutex::new(None)),
             sysex_called: Arc::new(Mutex::new(None)),
             event_thread: Arc::new(Mutex::new(None)),
+            external_transport: None,
         }
     }
 
+    /// Chases an external transport, fed by whoever holds
+    /// [`super::Device::external_transport`].
+    pub fn with_chase(mut self, source: Option<ChaseSource>) -> Device {
+        self.external_transport = source.map(|source| Arc::new(ExternalTransport::new(source)));
+        self
+    }
+
     #[cfg(test)]
     /// Sends the mock event through to the sender.
     pub fn mock_event(&self, event: &[u8]) {
@@ -193,7 +204,21 @@ impl super::Device for Device {
                     return;
                 }
 
-                let _ = sleep_rx.recv_timeout(remaining_duration);
+                // Follow the playback clock rather than sleeping the whole
+                // song, so a paused transport holds the mock too.
+                loop {
+                    let elapsed = clock.elapsed();
+                    if elapsed >= remaining_duration {
+                        break;
+                    }
+                    let wait = (remaining_duration - elapsed).min(Duration::from_millis(20));
+                    if !matches!(
+                        sleep_rx.recv_timeout(wait),
+                        Err(mpsc::RecvTimeoutError::Timeout)
+                    ) {
+                        break;
+                    }
+                }
 
                 // Expire at the end of playback.
                 finished.store(true, Ordering::Relaxed);
@@ -233,6 +258,10 @@ impl super::Device for Device {
         Ok(())
     }
 
+    fn external_transport(&self) -> Option<Arc<ExternalTransport>> {
+        self.external_transport.clone()
+    }
+
     #[cfg(test)]
     fn to_mock(&self) -> Result<Arc<Device>, Box<dyn Error>> {
         Ok(Arc::new(self.clone()))
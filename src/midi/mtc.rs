@@ -0,0 +1,518 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+
+//! MIDI Timecode generation: quarter-frame messages while the song plays,
+//! and a full-frame message whenever the position jumps.
+
+use std::{sync::Arc, time::Duration};
+
+use tracing::warn;
+
+use crate::{
+    clock::PlaybackClock,
+    config::{MtcFrameRate, Timecode},
+    playsync::CancelHandle,
+};
+
+use super::midir::MidiSender;
+
+/// Frames in ten minutes of 29.97 drop-frame timecode.
+const DROP_FRAME_TEN_MINUTES: u64 = 17_982;
+
+/// Frames in a minute of 29.97 drop-frame timecode that drops two frame numbers.
+const DROP_FRAME_MINUTE: u64 = 1_798;
+
+/// Timecode wraps back to zero after a day.
+const HOURS_PER_DAY: u64 = 24;
+
+/// Quarter-frames the position may run ahead of the next one due and still
+/// be caught up by sending the ones in between. Any further (or any step
+/// backwards) is a jump, which chasers are told about with a full frame.
+const MAX_CATCH_UP: u64 = 8;
+
+/// Converts a timecode to the number of frames since 00:00:00:00.
+pub(crate) fn to_frame_count(timecode: Timecode, rate: MtcFrameRate) -> u64 {
+    let fps = rate.frames_per_second();
+    let hours = u64::from(timecode.hours);
+    let minutes = u64::from(timecode.minutes);
+    let frames = u64::from(timecode.frames).min(fps - 1);
+    let nominal = ((hours * 60 + minutes) * 60 + u64::from(timecode.seconds)) * fps + frames;
+    if rate != MtcFrameRate::Fps2997DropFrame {
+        return nominal;
+    }
+    // Frame numbers 0 and 1 are skipped at the start of every minute
+    // except each tenth.
+    let total_minutes = hours * 60 + minutes;
+    nominal - 2 * (total_minutes - total_minutes / 10)
+}
+
+/// Converts a number of frames since 00:00:00:00 to a timecode, wrapping at
+/// 24 hours.
+pub(crate) fn from_frame_count(count: u64, rate: MtcFrameRate) -> Timecode {
+    let fps = rate.frames_per_second();
+    let mut count = count;
+    if rate == MtcFrameRate::Fps2997DropFrame {
+        let tens = count / DROP_FRAME_TEN_MINUTES;
+        let rest = count % DROP_FRAME_TEN_MINUTES;
+        let skipped = if rest < 2 {
+            0
+        } else {
+            2 * ((rest - 2) / DROP_FRAME_MINUTE)
+        };
+        count += 18 * tens + skipped;
+    }
+    Timecode::new(
+        ((count / (fps * 3600)) % HOURS_PER_DAY) as u8,
+        ((count / (fps * 60)) % 60) as u8,
+        ((count / fps) % 60) as u8,
+        (count % fps) as u8,
+    )
+}
+
+/// Builds the full-frame SysEx that tells chasers to locate to `timecode`.
+pub(crate) fn full_frame(timecode: Timecode, rate: MtcFrameRate) -> [u8; 10] {
+    [
+        0xF0,
+        0x7F,
+        // All devices.
+        0x7F,
+        // MIDI Time Code, full message.
+        0x01,
+        0x01,
+        rate.rate_code() << 5 | timecode.hours,
+        timecode.minutes,
+        timecode.seconds,
+        timecode.frames,
+        0xF7,
+    ]
+}
+
+/// Builds quarter-frame message `piece` (0-7) of `timecode`.
+pub(crate) fn quarter_frame(piece: u8, timecode: Timecode, rate: MtcFrameRate) -> [u8; 2] {
+    let nibble = match piece {
+        0 => timecode.frames & 0x0F,
+        1 => timecode.frames >> 4,
+        2 => timecode.seconds & 0x0F,
+        3 => timecode.seconds >> 4,
+        4 => timecode.minutes & 0x0F,
+        5 => timecode.minutes >> 4,
+        6 => timecode.hours & 0x0F,
+        _ => rate.rate_code() << 1 | timecode.hours >> 4,
+    };
+    [0xF1, (piece & 0x07) << 4 | nibble]
+}
+
+/// Turns song positions into MIDI Timecode.
+///
+/// Positions are counted in quarter-frames from 00:00:00:00. Each group of
+/// eight quarter-frames spans two frames and carries the timecode of the
+/// frame it starts on, as the spec has it.
+pub(crate) struct MtcGenerator {
+    rate: MtcFrameRate,
+    /// The song's start, in frames from 00:00:00:00.
+    start_frames: u64,
+    /// The next quarter-frame to send. `None` until the first position has
+    /// been located with a full frame.
+    next_quarter: Option<u64>,
+    /// The quarter-frame the last position fell in.
+    last_quarter: u64,
+}
+
+impl MtcGenerator {
+    /// Creates a generator for a song starting at `start`.
+    pub(crate) fn new(rate: MtcFrameRate, start: Timecode) -> MtcGenerator {
+        MtcGenerator {
+            rate,
+            start_frames: to_frame_count(start, rate),
+            next_quarter: None,
+            last_quarter: 0,
+        }
+    }
+
+    /// The quarter-frame the song is in at `position`.
+    fn quarter_at(&self, position: Duration) -> u64 {
+        self.start_frames * 4 + (position.as_secs_f64() * self.rate.real_rate() * 4.0) as u64
+    }
+
+    /// The song position quarter-frame `quarter` is due at.
+    fn position_of(&self, quarter: u64) -> Duration {
+        let quarters = quarter.saturating_sub(self.start_frames * 4);
+        Duration::from_secs_f64(quarters as f64 / (self.rate.real_rate() * 4.0))
+    }
+
+    /// Sends whatever is due at `position`: the quarter-frames up to it, or a
+    /// full frame if the position has jumped.
+    pub(crate) fn advance(&mut self, sender: &mut dyn MidiSender, position: Duration) {
+        let quarter = self.quarter_at(position);
+        let next = match self.next_quarter {
+            Some(next) if quarter >= self.last_quarter && quarter < next + MAX_CATCH_UP => next,
+            _ => return self.locate(sender, position),
+        };
+        for quarter in next..=quarter {
+            let piece = (quarter % 8) as u8;
+            let timecode = from_frame_count((quarter - u64::from(piece)) / 4, self.rate);
+            send(sender, &quarter_frame(piece, timecode, self.rate));
+        }
+        self.next_quarter = Some(next.max(quarter + 1));
+        self.last_quarter = quarter;
+    }
+
+    /// Sends a full frame for `position`, and picks the quarter-frames back up
+    /// from the next group boundary.
+    pub(crate) fn locate(&mut self, sender: &mut dyn MidiSender, position: Duration) {
+        let quarter = self.quarter_at(position);
+        send(
+            sender,
+            &full_frame(from_frame_count(quarter / 4, self.rate), self.rate),
+        );
+        self.next_quarter = Some((quarter / 8 + 1) * 8);
+        self.last_quarter = quarter;
+    }
+
+    /// How long after `position` the next quarter-frame is due.
+    pub(crate) fn until_next(&self, position: Duration) -> Duration {
+        match self.next_quarter {
+            Some(next) => self.position_of(next).saturating_sub(position),
+            None => Duration::ZERO,
+        }
+    }
+}
+
+/// Sends a timecode message, logging rather than failing: a dropped message
+/// is corrected by the ones that follow it.
+fn send(sender: &mut dyn MidiSender, bytes: &[u8]) {
+    if let Err(e) = sender.send(bytes) {
+        warn!(err = %e, "Failed to send MIDI Timecode");
+    }
+}
+
+/// Everything the timecode needs to follow a song's playback.
+pub(crate) struct MtcPlay {
+    pub(crate) rate: MtcFrameRate,
+    /// The timecode at the song's start.
+    pub(crate) start: Timecode,
+    /// Position in the song playback starts from.
+    pub(crate) start_time: Duration,
+    /// Delay before playback reaches the MIDI output, matching note playback.
+    pub(crate) playback_delay: Duration,
+    /// The song's length, at which the timecode stops (or wraps for a looping
+    /// song).
+    pub(crate) duration: Duration,
+    pub(crate) loop_playback: bool,
+    pub(crate) cancel: CancelHandle,
+    pub(crate) clock: PlaybackClock,
+    /// Time spent replaying section loops, taken off the clock to give the
+    /// song position.
+    pub(crate) loop_time_consumed: Arc<parking_lot::Mutex<Duration>>,
+}
+
+impl MtcPlay {
+    /// The song position once the clock has run for `elapsed`.
+    fn position(&self, elapsed: Duration) -> Duration {
+        let position = (self.start_time + elapsed.saturating_sub(self.playback_delay))
+            .saturating_sub(*self.loop_time_consumed.lock());
+        if self.loop_playback && !self.duration.is_zero() {
+            Duration::from_nanos((position.as_nanos() % self.duration.as_nanos()) as u64)
+        } else {
+            position
+        }
+    }
+}
+
+/// Generates timecode for one song's playback: locates chasers to the start
+/// position, then follows the playback clock until the song ends or is
+/// cancelled. While the clock is paused no quarter-frames are sent, so
+/// chasers hold; any jump in position (a section loop, a whole-song loop)
+/// is sent as a full frame.
+pub(crate) fn run_mtc(sender: &mut dyn MidiSender, play: &MtcPlay) {
+    let mut generator = MtcGenerator::new(play.rate, play.start);
+    generator.locate(sender, play.start_time);
+
+    play.clock.wait_for_start_or_cancel(&play.cancel);
+    // A quarter-frame's length, the longest the clock is left unwatched.
+    let quarter = Duration::from_secs_f64(1.0 / (play.rate.real_rate() * 4.0));
+    // The delay is real time; the clock reads song time.
+    let playback_delay = play.playback_delay.mul_f64(play.clock.rate().speed);
+    while !play.cancel.is_cancelled() {
+        let elapsed = play.clock.elapsed();
+        let position = play.position(elapsed);
+        if !play.loop_playback && position >= play.duration {
+            break;
+        }
+        if elapsed >= playback_delay && !play.clock.is_paused() {
+            generator.advance(sender, position);
+        }
+        spin_sleep::sleep(
+            generator
+                .until_next(position)
+                .clamp(Duration::from_millis(1), quarter),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use super::*;
+
+    struct RecordingSender {
+        sent: Vec<Vec<u8>>,
+    }
+
+    impl RecordingSender {
+        fn new() -> Self {
+            RecordingSender { sent: Vec::new() }
+        }
+    }
+
+    impl MidiSender for RecordingSender {
+        fn send(&mut self, bytes: &[u8]) -> Result<(), Box<dyn Error>> {
+            self.sent.push(bytes.to_vec());
+            Ok(())
+        }
+    }
+
+    fn tc(hours: u8, minutes: u8, seconds: u8, frames: u8) -> Timecode {
+        Timecode::new(hours, minutes, seconds, frames)
+    }
+
+    #[test]
+    fn non_drop_frame_counts_round_trip() {
+        for rate in [
+            MtcFrameRate::Fps24,
+            MtcFrameRate::Fps25,
+            MtcFrameRate::Fps30,
+        ] {
+            let timecode = tc(1, 2, 3, 4);
+            let count = to_frame_count(timecode, rate);
+            let fps = rate.frames_per_second();
+            assert_eq!(count, ((3600 + 2 * 60 + 3) * fps) + 4);
+            assert_eq!(from_frame_count(count, rate), timecode);
+        }
+    }
+
+    #[test]
+    fn drop_frame_skips_two_numbers_each_minute() {
+        let rate = MtcFrameRate::Fps2997DropFrame;
+        // The frame after 00:00:59:29 is 00:01:00:02.
+        let count = to_frame_count(tc(0, 0, 59, 29), rate);
+        assert_eq!(from_frame_count(count + 1, rate), tc(0, 1, 0, 2));
+        // ...but not on the tenth minute.
+        let count = to_frame_count(tc(0, 9, 59, 29), rate);
+        assert_eq!(from_frame_count(count + 1, rate), tc(0, 10, 0, 0));
+        // An hour of drop-frame is 107892 frames.
+        assert_eq!(to_frame_count(tc(1, 0, 0, 0), rate), 107_892);
+        for timecode in [tc(0, 1, 0, 2), tc(0, 10, 0, 0), tc(13, 27, 45, 17)] {
+            assert_eq!(
+                from_frame_count(to_frame_count(timecode, rate), rate),
+                timecode
+            );
+        }
+    }
+
+    #[test]
+    fn frame_counts_wrap_at_a_day() {
+        let rate = MtcFrameRate::Fps25;
+        let count = to_frame_count(tc(23, 59, 59, 24), rate);
+        assert_eq!(from_frame_count(count + 1, rate), tc(0, 0, 0, 0));
+    }
+
+    #[test]
+    fn full_frame_layout() {
+        assert_eq!(
+            full_frame(tc(1, 2, 3, 4), MtcFrameRate::Fps25),
+            [0xF0, 0x7F, 0x7F, 0x01, 0x01, 0x21, 0x02, 0x03, 0x04, 0xF7]
+        );
+        assert_eq!(
+            full_frame(tc(23, 0, 0, 0), MtcFrameRate::Fps2997DropFrame)[5],
+            0x40 | 23
+        );
+    }
+
+    #[test]
+    fn quarter_frames_carry_each_nibble() {
+        let timecode = tc(0x17, 0x3B, 0x2A, 0x1D);
+        let rate = MtcFrameRate::Fps30;
+        let pieces: Vec<[u8; 2]> = (0..8).map(|p| quarter_frame(p, timecode, rate)).collect();
+        assert_eq!(
+            pieces,
+            vec![
+                [0xF1, 0x0D],
+                [0xF1, 0x11],
+                [0xF1, 0x2A],
+                [0xF1, 0x32],
+                [0xF1, 0x4B],
+                [0xF1, 0x53],
+                [0xF1, 0x67],
+                // Rate 30 (3) and the top bit of the hours.
+                [0xF1, 0x77],
+            ]
+        );
+    }
+
+    #[test]
+    fn starts_with_a_full_frame_at_the_song_offset() {
+        let mut sender = RecordingSender::new();
+        let mut generator = MtcGenerator::new(MtcFrameRate::Fps25, tc(2, 0, 0, 0));
+        generator.locate(&mut sender, Duration::from_secs(10));
+        assert_eq!(
+            sender.sent,
+            vec![full_frame(tc(2, 0, 10, 0), MtcFrameRate::Fps25).to_vec()]
+        );
+    }
+
+    #[test]
+    fn plays_quarter_frames_in_order() {
+        let rate = MtcFrameRate::Fps25;
+        let mut sender = RecordingSender::new();
+        let mut generator = MtcGenerator::new(rate, tc(1, 0, 0, 0));
+        generator.locate(&mut sender, Duration::ZERO);
+        // One quarter-frame at 25 fps is 10ms: run through two frames' worth
+        // after the first group boundary.
+        for ms in (10..=160).step_by(10) {
+            generator.advance(&mut sender, Duration::from_micros(ms * 1000 + 500));
+        }
+
+        let quarters: Vec<u8> = sender.sent[1..].iter().map(|m| m[1]).collect();
+        // The first group starts on frame 2.
+        assert_eq!(
+            quarters[..8],
+            [0x02, 0x10, 0x20, 0x30, 0x40, 0x50, 0x61, 0x72]
+        );
+        // The next on frame 4.
+        assert_eq!(quarters[8], 0x04);
+        assert_eq!(quarters.len(), 9);
+    }
+
+    #[test]
+    fn catches_up_on_missed_quarter_frames() {
+        let rate = MtcFrameRate::Fps30;
+        let mut sender = RecordingSender::new();
+        let mut generator = MtcGenerator::new(rate, Timecode::default());
+        generator.locate(&mut sender, Duration::ZERO);
+        // Straight to the end of the first group.
+        let end = generator.position_of(15) + Duration::from_millis(1);
+        generator.advance(&mut sender, end);
+        let pieces: Vec<u8> = sender.sent[1..].iter().map(|m| m[1] >> 4).collect();
+        assert_eq!(pieces, vec![0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn holding_still_sends_nothing() {
+        let mut sender = RecordingSender::new();
+        let mut generator = MtcGenerator::new(MtcFrameRate::Fps30, Timecode::default());
+        generator.locate(&mut sender, Duration::from_secs(1));
+        let sent = sender.sent.len();
+        for _ in 0..10 {
+            generator.advance(&mut sender, Duration::from_secs(1));
+        }
+        assert_eq!(sender.sent.len(), sent);
+    }
+
+    #[test]
+    fn jumps_send_a_full_frame() {
+        let rate = MtcFrameRate::Fps30;
+        let mut sender = RecordingSender::new();
+        let mut generator = MtcGenerator::new(rate, Timecode::default());
+        generator.locate(&mut sender, Duration::from_secs(20));
+
+        // A section loop jumping back.
+        generator.advance(&mut sender, Duration::from_secs(12));
+        assert_eq!(
+            sender.sent.last().unwrap(),
+            &full_frame(tc(0, 0, 12, 0), rate).to_vec()
+        );
+
+        // And a jump forwards.
+        generator.advance(&mut sender, Duration::from_secs(30));
+        assert_eq!(
+            sender.sent.last().unwrap(),
+            &full_frame(tc(0, 0, 30, 0), rate).to_vec()
+        );
+        assert_eq!(sender.sent.len(), 3);
+    }
+
+    #[test]
+    fn until_next_points_at_the_next_quarter_frame() {
+        let mut sender = RecordingSender::new();
+        let mut generator = MtcGenerator::new(MtcFrameRate::Fps25, Timecode::default());
+        generator.locate(&mut sender, Duration::ZERO);
+        // Next group boundary is frame 2: 80ms in at 25 fps.
+        let until = generator.until_next(Duration::from_millis(30));
+        assert!(
+            until > Duration::from_millis(49) && until < Duration::from_millis(51),
+            "until next: {:?}",
+            until
+        );
+    }
+
+    #[test]
+    fn run_mtc_follows_the_clock_to_the_end_of_the_song() {
+        let clock = PlaybackClock::wall();
+        let cancel = CancelHandle::new();
+        let play = MtcPlay {
+            rate: MtcFrameRate::Fps25,
+            start: tc(3, 0, 0, 0),
+            start_time: Duration::ZERO,
+            playback_delay: Duration::ZERO,
+            duration: Duration::from_millis(200),
+            loop_playback: false,
+            cancel,
+            clock: clock.clone(),
+            loop_time_consumed: Arc::new(parking_lot::Mutex::new(Duration::ZERO)),
+        };
+        clock.start();
+        let mut sender = RecordingSender::new();
+        run_mtc(&mut sender, &play);
+
+        assert_eq!(
+            sender.sent[0],
+            full_frame(tc(3, 0, 0, 0), MtcFrameRate::Fps25).to_vec()
+        );
+        let quarters = &sender.sent[1..];
+        assert!(
+            quarters.len() >= 8,
+            "sent {} quarter-frames",
+            quarters.len()
+        );
+        // Every one is a quarter-frame, in piece order.
+        for (i, message) in quarters.iter().enumerate() {
+            assert_eq!(message[0], 0xF1);
+            assert_eq!(message[1] >> 4, (i % 8) as u8);
+        }
+    }
+
+    #[test]
+    fn run_mtc_stops_when_cancelled() {
+        let clock = PlaybackClock::wall();
+        let cancel = CancelHandle::new();
+        let play = MtcPlay {
+            rate: MtcFrameRate::Fps30,
+            start: Timecode::default(),
+            start_time: Duration::ZERO,
+            playback_delay: Duration::ZERO,
+            duration: Duration::from_secs(60),
+            loop_playback: false,
+            cancel: cancel.clone(),
+            clock,
+            loop_time_consumed: Arc::new(parking_lot::Mutex::new(Duration::ZERO)),
+        };
+        cancel.cancel();
+        let mut sender = RecordingSender::new();
+        // The clock never starts: cancellation alone has to end it.
+        run_mtc(&mut sender, &play);
+        assert_eq!(sender.sent.len(), 1);
+    }
+}
@@ -216,6 +216,7 @@ impl NotificationEngine {
             cancel_at_sample: None,
             gain: Default::default(),
             gain_envelope: None,
+            transport: None,
         };
 
         mixer.add_source(active_source);
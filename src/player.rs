@@ -11,6 +11,8 @@
 // You should have received a copy of the GNU General Public License along with
 // this program. If not, see <https://www.gnu.org/licenses/>.
 //
+mod advance;
+mod chase;
 mod hardware;
 mod navigation;
 mod playback;
@@ -144,6 +146,8 @@ pub struct TransportSnapshot {
     pub section_loop: Option<String>,
     pub reactive_loop_state: ReactiveLoopStateKind,
     pub locked: bool,
+    /// Playback is paused in place. `playing` stays true while paused.
+    pub paused: bool,
 }
 
 /// Variants of [`ReactiveLoopState`] without the inner `SectionBounds` payload.
@@ -173,6 +177,17 @@ impl From<&ReactiveLoopState> for ReactiveLoopStateKind {
 struct PlayHandles {
     join: JoinHandle<()>,
     cancel: CancelHandle,
+    /// Set when this playback has handed off to the next song early. Its
+    /// cleanup then leaves player state to the playback that took over.
+    handed_off: Arc<AtomicBool>,
+}
+
+/// The clock the current playback's subsystems follow and the song position
+/// it started from. Pause state lives on the clock; the player only reads it.
+#[derive(Clone)]
+struct Transport {
+    clock: crate::clock::PlaybackClock,
+    start_time: Duration,
 }
 
 /// Groups the parameters needed for `play_files` to avoid excessive argument counts.
@@ -257,6 +272,9 @@ pub struct AudioOutputHealth {
     /// because they are routine and self-healing — a rising count means the
     /// buffer is too small for the machine, not that anything has failed.
     pub underruns: u64,
+    /// Times an output channel's limiter started reducing gain since the
+    /// device was opened. Always zero with the limiter off.
+    pub limiter_engagements: u64,
     /// The most recent backend error, if there has been one. Retained after
     /// recovery on purpose — it is the thing worth reading afterwards.
     pub last_error: Option<String>,
@@ -292,6 +310,9 @@ pub struct Player {
     persisted_playlist: Arc<parking_lot::RwLock<String>>,
     /// The time that the last play action occurred.
     play_start_time: Arc<Mutex<Option<SystemTime>>>,
+    /// The transport of the current playback, if any. Set and cleared
+    /// alongside `join`.
+    transport: Arc<parking_lot::Mutex<Option<Transport>>>,
     /// Keeps track of the player joins. There should only be one task on here at a time.
     join: Arc<Mutex<Option<PlayHandles>>>,
     /// After stop is set, this will be set to true. This will prevent stop from being run again until
@@ -354,15 +375,33 @@ pub struct Player {
     loop_time_consumed: Arc<parking_lot::Mutex<Duration>>,
     /// Reactive loop state machine.
     reactive_loop_state: Arc<parking_lot::RwLock<ReactiveLoopState>>,
+    /// Cancels a pending auto-advance: the wait through the gap before the
+    /// next song starts by itself.
+    auto_advance_cancel: Arc<parking_lot::Mutex<Option<CancellationToken>>>,
+    /// The playback being handed off from during a gapless or crossfaded
+    /// auto-advance, until it reaches its end sample and is cancelled.
+    outgoing: Arc<parking_lot::Mutex<Option<CancelHandle>>>,
     /// Start position for the next play(), set by seeking while stopped.
     /// Cleared when the playlist position changes.
     pending_start: Arc<parking_lot::Mutex<Option<Duration>>>,
+    /// Rehearsal speed and transposition, applied to every playback from
+    /// its start. Not persisted: a restart plays at normal speed.
+    playback_rate: Arc<parking_lot::RwLock<crate::audio::PlaybackRate>>,
     /// Notification engine for section loop audio feedback.
     notification_engine: Arc<crate::notification::NotificationEngine>,
     /// Pending debounced task persisting track gains to the config store.
     /// Aborted and replaced on every gain change so rapid fader moves
     /// produce a single disk write.
     gain_persist_task: Arc<parking_lot::Mutex<Option<tokio::task::JoinHandle<()>>>>,
+    /// Pending debounced task persisting track pan, polarity and width, on
+    /// the same terms as `gain_persist_task`.
+    mix_persist_task: Arc<parking_lot::Mutex<Option<tokio::task::JoinHandle<()>>>>,
+    /// Pending debounced task persisting track EQ, on the same terms as
+    /// `gain_persist_task`.
+    eq_persist_task: Arc<parking_lot::Mutex<Option<tokio::task::JoinHandle<()>>>>,
+    /// Pending debounced task persisting monitor mixes, on the same terms as
+    /// `gain_persist_task`.
+    monitor_mix_persist_task: Arc<parking_lot::Mutex<Option<tokio::task::JoinHandle<()>>>>,
 }
 
 /// Errors from setting an output track gain. Variants are matched by the
@@ -376,6 +415,10 @@ pub enum TrackGainError {
     NoAudioProfile,
     #[error(transparent)]
     UnknownTrack(#[from] crate::audio::track_gains::UnknownTrackError),
+    #[error("unknown mix '{0}'")]
+    UnknownMix(String),
+    #[error("track '{track}' has no EQ band {band}")]
+    UnknownEqBand { track: String, band: usize },
 }
 
 /// Bounds of an active section loop.
@@ -569,6 +612,7 @@ impl Player {
                 },
             )),
             play_start_time: Arc::new(Mutex::new(None)),
+            transport: Arc::new(parking_lot::Mutex::new(None)),
             join: Arc::new(Mutex::new(None)),
             stop_run: Arc::new(AtomicBool::new(false)),
             span: span!(Level::INFO, "player"),
@@ -581,6 +625,9 @@ impl Player {
             state_tx: Arc::new(parking_lot::Mutex::new(None)),
             transport_tx: Arc::new(tokio::sync::watch::channel(TransportSnapshot::default()).0),
             gain_persist_task: Arc::new(parking_lot::Mutex::new(None)),
+            mix_persist_task: Arc::new(parking_lot::Mutex::new(None)),
+            eq_persist_task: Arc::new(parking_lot::Mutex::new(None)),
+            monitor_mix_persist_task: Arc::new(parking_lot::Mutex::new(None)),
             locked: Arc::new(AtomicBool::new(true)),
             controller: Arc::new(parking_lot::Mutex::new(None)),
             controller_config: Arc::new(parking_lot::Mutex::new(None)),
@@ -589,7 +636,10 @@ impl Player {
             section_loop_break: Arc::new(AtomicBool::new(false)),
             loop_time_consumed: Arc::new(parking_lot::Mutex::new(Duration::ZERO)),
             reactive_loop_state: Arc::new(parking_lot::RwLock::new(ReactiveLoopState::Idle)),
+            auto_advance_cancel: Arc::new(parking_lot::Mutex::new(None)),
+            outgoing: Arc::new(parking_lot::Mutex::new(None)),
             pending_start: Arc::new(parking_lot::Mutex::new(None)),
+            playback_rate: Arc::new(parking_lot::RwLock::new(crate::audio::PlaybackRate::NORMAL)),
             notification_engine: Arc::new(crate::notification::NotificationEngine::with_defaults(
                 44100,
             )),
@@ -635,11 +685,15 @@ impl Player {
     fn load_song_samples(&self, song: &Song) {
         let sample_engine = self.hardware.read().sample_engine.clone();
         if let Some(ref sample_engine) = sample_engine {
+            let mut engine = sample_engine.write();
+            engine.begin_song();
+
             // Load the new song's sample config if it has one
             let samples_config = song.samples_config();
-            if !samples_config.samples().is_empty() || !samples_config.sample_triggers().is_empty()
+            if !samples_config.samples().is_empty()
+                || !samples_config.sample_triggers().is_empty()
+                || !samples_config.sample_kits().is_empty()
             {
-                let mut engine = sample_engine.write();
                 if let Err(e) = engine.load_song_config(samples_config, song.base_path()) {
                     warn!(
                         song = song.name(),
@@ -702,8 +756,9 @@ impl Player {
         *self.broadcast_tx.lock() = Some(tx);
     }
 
-    /// Stores the state sampler watch sender. When the DMX engine comes up
-    /// during async init, the sampler will be started using this sender.
+    /// Stores the state sampler watch sender. When the DMX engine or a
+    /// metered audio device comes up during async init, the sampler will be
+    /// started using this sender.
     pub fn set_state_tx(&self, tx: tokio::sync::watch::Sender<Arc<crate::state::StateSnapshot>>) {
         *self.state_tx.lock() = Some(Arc::new(tx));
     }
@@ -745,6 +800,7 @@ impl Player {
         let section_loop = self.active_section.read().as_ref().map(|b| b.name.clone());
         let reactive_loop_state = ReactiveLoopStateKind::from(&*self.reactive_loop_state.read());
         let locked = self.is_locked();
+        let paused = self.is_paused();
         TransportSnapshot {
             playlist_name,
             current_song,
@@ -752,6 +808,7 @@ impl Player {
             section_loop,
             reactive_loop_state,
             locked,
+            paused,
         }
     }
 
@@ -880,6 +937,237 @@ impl Player {
             .map(|tg| tg.snapshot_muted())
     }
 
+    /// Sets the pan of an output track mapped to a stereo pair, from -1
+    /// (left) to 1 (right), returning the (clamped) applied value. Applied
+    /// and persisted like a gain change.
+    pub fn set_track_pan(&self, track: &str, pan: f32) -> Result<f32, TrackGainError> {
+        if !pan.is_finite() {
+            return Err(TrackGainError::NonFinite(pan));
+        }
+        self.update_track_mix(track, |tg| tg.set_pan(track, pan))
+    }
+
+    /// Sets the stereo width of an output track carried by two channels,
+    /// from 0 (mono) to 2, returning the (clamped) applied value. Applied
+    /// and persisted like a gain change.
+    pub fn set_track_width(&self, track: &str, width: f32) -> Result<f32, TrackGainError> {
+        if !width.is_finite() {
+            return Err(TrackGainError::NonFinite(width));
+        }
+        self.update_track_mix(track, |tg| tg.set_width(track, width))
+    }
+
+    /// Inverts an output track's polarity or puts it back, returning the
+    /// applied state. Applied and persisted like a gain change.
+    pub fn set_track_polarity(&self, track: &str, inverted: bool) -> Result<bool, TrackGainError> {
+        self.update_track_mix(track, |tg| tg.set_inverted(track, inverted))
+    }
+
+    /// Returns every output track's pan, polarity and width as (name, mix)
+    /// pairs, or None when no audio profile is active.
+    pub fn get_track_mix(&self) -> Option<Vec<(String, config::TrackMix)>> {
+        self.hardware
+            .read()
+            .track_gains
+            .as_ref()
+            .map(|tg| tg.snapshot_mix())
+    }
+
+    /// Applies a pan, polarity or width change and schedules the debounced
+    /// save of every track's mix.
+    fn update_track_mix<T: std::fmt::Debug>(
+        &self,
+        track: &str,
+        update: impl FnOnce(
+            &crate::audio::track_gains::TrackGains,
+        ) -> Result<T, crate::audio::track_gains::UnknownTrackError>,
+    ) -> Result<T, TrackGainError> {
+        let (track_gains, hostname) = {
+            let hw = self.hardware.read();
+            (hw.track_gains.clone(), hw.hostname.clone())
+        };
+        let track_gains = track_gains.ok_or(TrackGainError::NoAudioProfile)?;
+        let applied = update(&track_gains)?;
+
+        match (self.config_store(), hostname) {
+            (Some(store), Some(hostname)) => {
+                let mix = track_gains.snapshot_mix_map();
+                let mut pending = self.mix_persist_task.lock();
+                if let Some(task) = pending.take() {
+                    task.abort();
+                }
+                *pending = Some(tokio::spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(750)).await;
+                    if let Err(e) = store.set_track_mix(&hostname, mix).await {
+                        error!(err = %e, "Failed to persist track mix");
+                    }
+                }));
+            }
+            _ => {
+                debug!(
+                    track,
+                    ?applied,
+                    "track mix applied but not persisted (no config store or hostname)"
+                );
+            }
+        }
+
+        Ok(applied)
+    }
+
+    /// Updates band `band` (1-indexed, in config order) of a track's EQ,
+    /// leaving parameters passed as `None` unchanged, and returns the
+    /// (clamped) applied band. Coefficients ramp to the new settings over
+    /// the next audio callback. Applied and persisted like a gain change.
+    pub fn set_track_eq_band(
+        &self,
+        track: &str,
+        band: usize,
+        freq: Option<f32>,
+        gain_db: Option<f32>,
+        q: Option<f32>,
+    ) -> Result<config::EqBand, TrackGainError> {
+        if let Some(value) = [freq, gain_db, q]
+            .into_iter()
+            .flatten()
+            .find(|v| !v.is_finite())
+        {
+            return Err(TrackGainError::NonFinite(value));
+        }
+        let (track_gains, hostname) = {
+            let hw = self.hardware.read();
+            (hw.track_gains.clone(), hw.hostname.clone())
+        };
+        let track_gains = track_gains.ok_or(TrackGainError::NoAudioProfile)?;
+        let applied = band
+            .checked_sub(1)
+            .map(|index| track_gains.set_eq_band(track, index, freq, gain_db, q))
+            .transpose()?
+            .flatten()
+            .ok_or_else(|| TrackGainError::UnknownEqBand {
+                track: track.to_string(),
+                band,
+            })?;
+
+        match (self.config_store(), hostname) {
+            (Some(store), Some(hostname)) => {
+                let track_eq = track_gains.snapshot_eq_map();
+                let mut pending = self.eq_persist_task.lock();
+                if let Some(task) = pending.take() {
+                    task.abort();
+                }
+                *pending = Some(tokio::spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(750)).await;
+                    if let Err(e) = store.set_track_eq(&hostname, track_eq).await {
+                        error!(err = %e, "Failed to persist track EQ");
+                    }
+                }));
+            }
+            _ => {
+                debug!(
+                    track,
+                    band,
+                    ?applied,
+                    "track EQ applied but not persisted (no config store or hostname)"
+                );
+            }
+        }
+
+        Ok(applied)
+    }
+
+    /// Returns the EQ chain of every output track that has one, or None when
+    /// no audio profile is active.
+    pub fn get_track_eq(&self) -> Option<Vec<(String, Vec<config::EqBand>)>> {
+        self.hardware
+            .read()
+            .track_gains
+            .as_ref()
+            .map(|tg| tg.snapshot_eq())
+    }
+
+    /// Sets a track's gain in a monitor mix in dB, returning the (clamped)
+    /// applied value. Applied and persisted like a main gain change.
+    pub fn set_mix_track_gain(
+        &self,
+        mix: &str,
+        track: &str,
+        gain_db: f32,
+    ) -> Result<f32, TrackGainError> {
+        if !gain_db.is_finite() {
+            return Err(TrackGainError::NonFinite(gain_db));
+        }
+        self.update_monitor_mix(mix, |tg, index| tg.set_mix_db(index, track, gain_db))
+    }
+
+    /// Mutes or unmutes a track in a monitor mix, returning the applied
+    /// state. Unlike main mutes, a mix's mutes are part of its blend, so
+    /// they are persisted with it.
+    pub fn set_mix_track_mute(
+        &self,
+        mix: &str,
+        track: &str,
+        muted: bool,
+    ) -> Result<bool, TrackGainError> {
+        self.update_monitor_mix(mix, |tg, index| tg.set_mix_muted(index, track, muted))
+    }
+
+    /// Returns every monitor mix with each track's gain and mute, or None
+    /// when no audio profile is active.
+    pub fn get_monitor_mixes(&self) -> Option<Vec<crate::audio::track_gains::MixSnapshot>> {
+        self.hardware
+            .read()
+            .track_gains
+            .as_ref()
+            .map(|tg| tg.snapshot_mixes())
+    }
+
+    /// Applies a change to one monitor mix and schedules the debounced save
+    /// of every mix.
+    fn update_monitor_mix<T: std::fmt::Debug>(
+        &self,
+        mix: &str,
+        update: impl FnOnce(
+            &crate::audio::track_gains::TrackGains,
+            usize,
+        ) -> Result<T, crate::audio::track_gains::UnknownTrackError>,
+    ) -> Result<T, TrackGainError> {
+        let (track_gains, hostname) = {
+            let hw = self.hardware.read();
+            (hw.track_gains.clone(), hw.hostname.clone())
+        };
+        let track_gains = track_gains.ok_or(TrackGainError::NoAudioProfile)?;
+        let index = track_gains
+            .mix_index(mix)
+            .ok_or_else(|| TrackGainError::UnknownMix(mix.to_string()))?;
+        let applied = update(&track_gains, index)?;
+
+        match (self.config_store(), hostname) {
+            (Some(store), Some(hostname)) => {
+                let mixes = track_gains.snapshot_monitor_mixes();
+                let mut pending = self.monitor_mix_persist_task.lock();
+                if let Some(task) = pending.take() {
+                    task.abort();
+                }
+                *pending = Some(tokio::spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(750)).await;
+                    if let Err(e) = store.set_mixes(&hostname, mixes).await {
+                        error!(err = %e, "Failed to persist monitor mixes");
+                    }
+                }));
+            }
+            _ => {
+                debug!(
+                    mix,
+                    ?applied,
+                    "monitor mix change applied but not persisted (no config store or hostname)"
+                );
+            }
+        }
+
+        Ok(applied)
+    }
+
     /// Returns true if the player is in locked mode (state-altering operations blocked).
     pub fn is_locked(&self) -> bool {
         self.locked.load(Ordering::Relaxed)
@@ -1750,6 +2038,162 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_pause_holds_elapsed_and_resume_continues() -> Result<(), Box<dyn Error>> {
+        let player = make_test_player().await?;
+        let binding = player
+            .audio_device()
+            .expect("audio device should be present");
+        let device = binding.to_mock()?;
+
+        assert!(player.pause().await.is_none(), "nothing to pause yet");
+        player.play().await?;
+        eventually(|| device.is_playing(), "Song never started playing");
+        elapsed_eventually(&player).await?;
+
+        assert!(player.pause().await.is_some());
+        assert!(player.is_paused());
+        assert!(player.compute_transport_snapshot().await.paused);
+        assert!(player.pause().await.is_none(), "already paused");
+        let held = player.elapsed().await?.expect("elapsed while paused");
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(player.elapsed().await?, Some(held));
+        assert!(device.is_playing(), "pause must not end the song");
+
+        assert!(player.resume().await.is_some());
+        assert!(!player.is_paused());
+        assert!(player.resume().await.is_none(), "not paused any more");
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let resumed = player.elapsed().await?.expect("elapsed after resume");
+        assert!(
+            resumed > held && resumed < held + Duration::from_millis(100),
+            "elapsed should carry on from {held:?}, got {resumed:?}"
+        );
+
+        player.stop().await;
+        eventually(|| !device.is_playing(), "Song never stopped playing");
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_play_while_paused_resumes() -> Result<(), Box<dyn Error>> {
+        let player = make_test_player().await?;
+        let binding = player
+            .audio_device()
+            .expect("audio device should be present");
+        let device = binding.to_mock()?;
+
+        player.play().await?;
+        eventually(|| device.is_playing(), "Song never started playing");
+        elapsed_eventually(&player).await?;
+        player.pause().await;
+
+        assert!(player.play().await?.is_some());
+        assert!(!player.is_paused());
+
+        player.stop().await;
+        eventually(|| !device.is_playing(), "Song never stopped playing");
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_stop_while_paused_tears_down() -> Result<(), Box<dyn Error>> {
+        let player = make_test_player().await?;
+        let binding = player
+            .audio_device()
+            .expect("audio device should be present");
+        let device = binding.to_mock()?;
+
+        player.play().await?;
+        eventually(|| device.is_playing(), "Song never started playing");
+        elapsed_eventually(&player).await?;
+        player.pause().await;
+
+        assert!(player.stop().await.is_some());
+        eventually(|| !device.is_playing(), "Song never stopped playing");
+        assert!(!player.is_paused());
+        assert!(!player.is_playing().await);
+        assert_eq!(player.elapsed().await?, None);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_seek_while_paused_stays_paused() -> Result<(), Box<dyn Error>> {
+        let player = make_test_player().await?;
+        let binding = player
+            .audio_device()
+            .expect("audio device should be present");
+        let device = binding.to_mock()?;
+
+        player.play().await?;
+        eventually(|| device.is_playing(), "Song never started playing");
+        elapsed_eventually(&player).await?;
+        player.pause().await;
+
+        player.seek_to(Duration::from_millis(200)).await?;
+        assert!(player.is_paused(), "seek must keep the pause");
+        eventually(|| device.is_playing(), "Song never restarted after seek");
+        let held = elapsed_eventually(&player).await?;
+        assert!(
+            held >= Duration::from_millis(200) && held < Duration::from_millis(210),
+            "should hold at the seek target, got {held:?}"
+        );
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(player.elapsed().await?, Some(held));
+
+        assert!(player.resume().await.is_some());
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(player.elapsed().await?.expect("elapsed after resume") > held);
+
+        player.stop().await;
+        eventually(|| !device.is_playing(), "Song never stopped playing");
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_playback_rate_scales_elapsed() -> Result<(), Box<dyn Error>> {
+        let player = make_test_player().await?;
+        let binding = player
+            .audio_device()
+            .expect("audio device should be present");
+        let device = binding.to_mock()?;
+
+        player.play().await?;
+        eventually(|| device.is_playing(), "Song never started playing");
+        elapsed_eventually(&player).await?;
+        player.pause().await;
+        let before = player.elapsed().await?.expect("elapsed while paused");
+
+        // Changing the rate restarts where the song is, still paused.
+        let half = crate::audio::PlaybackRate::new(0.5, -2.0)?;
+        player.set_playback_rate(half).await?;
+        assert_eq!(player.playback_rate(), half);
+        assert!(player.is_paused(), "a rate change must keep the pause");
+        let held = elapsed_eventually(&player).await?;
+        assert!(
+            held >= before && held < before + Duration::from_millis(10),
+            "should hold where it was, {before:?}, got {held:?}"
+        );
+        assert_eq!(
+            player.transport.lock().as_ref().map(|t| t.clock.rate()),
+            Some(half)
+        );
+
+        let real = std::time::Instant::now();
+        assert!(player.resume().await.is_some());
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let advanced = player.elapsed().await?.expect("elapsed after resume") - held;
+        let real = real.elapsed();
+        assert!(
+            advanced <= real.mul_f64(0.5) + Duration::from_millis(20),
+            "song time should run at half speed: {advanced:?} in {real:?}"
+        );
+
+        player.stop().await;
+        eventually(|| !device.is_playing(), "Song never stopped playing");
+        Ok(())
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_seek_while_stopped_sets_pending_start() -> Result<(), Box<dyn Error>> {
         let player = make_test_player().await?;
@@ -2080,6 +2524,335 @@ mod test {
         Ok(())
     }
 
+    /// Creates a player over the standard test playlist with the given
+    /// auto-advance, and with mock audio when `audio` is set.
+    async fn make_auto_advance_player(
+        audio: bool,
+        auto_advance: config::AutoAdvance,
+    ) -> Result<Arc<Player>, Box<dyn Error>> {
+        let songs = songs::get_all_songs(Path::new("assets/songs"))?;
+        let playlist = Playlist::new(
+            "playlist",
+            &config::Playlist::deserialize(Path::new("assets/playlist.yaml"))?
+                .with_auto_advance(Some(auto_advance)),
+            songs.clone(),
+        )?;
+        let audio = audio.then(|| config::Audio::new("mock-device"));
+        let player = Player::new(
+            test_playlists(playlist, songs),
+            "playlist".to_string(),
+            &config::Player::new(vec![], audio, None, None, HashMap::new(), "assets/songs"),
+            None,
+        )?;
+        player.await_hardware_ready().await;
+        Ok(player)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_auto_advance_plays_through_playlist() -> Result<(), Box<dyn Error>> {
+        let player = make_auto_advance_player(
+            false,
+            config::AutoAdvance::new(config::SongTransition::Gap, Some("10ms".into()), None),
+        )
+        .await?;
+
+        player.play().await?;
+
+        // Each song starts the next; the last one stops at the end.
+        eventually_async(
+            || async {
+                player.get_playlist().current().unwrap().name() == "Song 9"
+                    && !player.is_playing().await
+            },
+            "Auto-advance never reached the end of the playlist",
+        )
+        .await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!player.is_playing().await);
+        assert_eq!(player.get_playlist().current().unwrap().name(), "Song 9");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_chase_decide() {
+        use chase::{decide, ChaseAction};
+        let secs = Duration::from_secs;
+        let threshold = Duration::from_millis(100);
+        let decide =
+            |running, target, playing| decide(running, target, secs(20), playing, threshold);
+
+        assert_eq!(
+            decide(true, Some(secs(5)), None),
+            ChaseAction::Start(secs(5))
+        );
+        assert_eq!(decide(false, Some(secs(5)), None), ChaseAction::Hold);
+        assert_eq!(
+            decide(false, Some(secs(5)), Some(secs(5))),
+            ChaseAction::Stop
+        );
+        // Within the threshold, playback is left alone.
+        assert_eq!(
+            decide(
+                true,
+                Some(secs(5)),
+                Some(secs(5) + Duration::from_millis(80))
+            ),
+            ChaseAction::Hold
+        );
+        assert_eq!(
+            decide(
+                true,
+                Some(secs(5)),
+                Some(secs(5) + Duration::from_millis(150))
+            ),
+            ChaseAction::Seek(secs(5))
+        );
+        assert_eq!(
+            decide(true, Some(secs(9)), Some(secs(5))),
+            ChaseAction::Seek(secs(9))
+        );
+        // Before the song: stop. Past its end: don't start, let it play out.
+        assert_eq!(decide(true, None, Some(secs(5))), ChaseAction::Stop);
+        assert_eq!(decide(true, None, None), ChaseAction::Hold);
+        assert_eq!(decide(true, Some(secs(25)), None), ChaseAction::Hold);
+        assert_eq!(
+            decide(true, Some(secs(25)), Some(secs(19))),
+            ChaseAction::Hold
+        );
+    }
+
+    #[test]
+    fn test_chase_song_position() {
+        use crate::midi::chase::ExternalPosition;
+        let song = Song::default();
+        let timecode = ExternalPosition::Timecode {
+            position: Duration::from_secs(7),
+            rate: config::MtcFrameRate::Fps25,
+        };
+        assert_eq!(
+            chase::song_position(&song, &timecode),
+            Some(Duration::from_secs(7))
+        );
+
+        // Without a tempo, beats wait for the beat clock's tempo to be measured.
+        let beats = |seconds_per_beat| ExternalPosition::Beats {
+            beats: 8.0,
+            seconds_per_beat,
+        };
+        assert_eq!(chase::song_position(&song, &beats(None)), None);
+        assert_eq!(
+            chase::song_position(&song, &beats(Some(0.5))),
+            Some(Duration::from_secs(4))
+        );
+    }
+
+    #[test]
+    fn test_chase_song_for_hour() {
+        use crate::midi::chase::ExternalPosition;
+        let hour = |hours| config::Timecode::new(hours, 0, 0, 0);
+        let minutes = |m: u64| Duration::from_secs(m * 60);
+        let songs: HashMap<String, Arc<Song>> = [
+            Song::new_for_test_with_timecode("Opener", hour(1), minutes(4)),
+            Song::new_for_test_with_timecode("Ballad", hour(2), minutes(5)),
+            Song::new_for_test_with_timecode("Encore", hour(3), minutes(3)),
+        ]
+        .into_iter()
+        .map(|song| (song.name().to_string(), Arc::new(song)))
+        .collect();
+        let playlist = Playlist::new(
+            "Set",
+            &config::Playlist::new(&[
+                "Opener".to_string(),
+                "Ballad".to_string(),
+                "Encore".to_string(),
+            ]),
+            Arc::new(Songs::new(songs)),
+        )
+        .unwrap();
+        let current = playlist.current().unwrap();
+        let at = |time: Duration| ExternalPosition::Timecode {
+            position: time,
+            rate: config::MtcFrameRate::Fps25,
+        };
+
+        // The current song's own hour leaves it be.
+        let position = at(minutes(61));
+        assert!(chase::song_for_hour(&playlist, &current, &position).is_none());
+        // Another song's hour selects it, while the timecode is within it.
+        let position = at(minutes(122));
+        let selected = chase::song_for_hour(&playlist, &current, &position).unwrap();
+        assert_eq!(selected.name(), "Ballad");
+        let position = at(minutes(126));
+        assert!(chase::song_for_hour(&playlist, &current, &position).is_none());
+        // An hour nobody starts in selects nothing.
+        let position = at(minutes(305));
+        assert!(chase::song_for_hour(&playlist, &current, &position).is_none());
+        // Nor does a beat position.
+        let beats = ExternalPosition::Beats {
+            beats: 8.0,
+            seconds_per_beat: Some(0.5),
+        };
+        assert!(chase::song_for_hour(&playlist, &current, &beats).is_none());
+    }
+
+    /// Feeds timecode straight into a chased transport, as if it had arrived
+    /// on the MIDI input.
+    struct TimecodeFeed(Arc<crate::midi::chase::ExternalTransport>);
+
+    impl crate::midi::midir::MidiSender for TimecodeFeed {
+        fn send(&mut self, bytes: &[u8]) -> Result<(), Box<dyn Error>> {
+            self.0.feed(bytes, std::time::Instant::now());
+            Ok(())
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_chase_follows_external_timecode() -> Result<(), Box<dyn Error>> {
+        let midi = config::Midi::new("mock-midi-device", None)
+            .with_chase(Some(config::Chase::new(config::ChaseSource::Mtc, None)));
+        let player = make_test_player_with_config(None, Some(midi), None).await?;
+        player.next().await;
+        player.next().await;
+        assert_eq!(player.get_playlist().current().unwrap().name(), "Song 5");
+        let transport = player
+            .midi_device()
+            .and_then(|device| device.external_transport())
+            .expect("the MIDI device should be chasing");
+
+        // Run timecode from five seconds in, as a DAW would.
+        let feeding = Arc::new(AtomicBool::new(true));
+        let feeder = {
+            let feeding = feeding.clone();
+            std::thread::spawn(move || {
+                let mut generator = crate::midi::mtc::MtcGenerator::new(
+                    config::MtcFrameRate::Fps30,
+                    config::Timecode::new(0, 0, 5, 0),
+                );
+                let mut feed = TimecodeFeed(transport);
+                let origin = std::time::Instant::now();
+                generator.locate(&mut feed, Duration::ZERO);
+                while feeding.load(Ordering::Relaxed) {
+                    generator.advance(&mut feed, origin.elapsed());
+                    std::thread::sleep(Duration::from_millis(2));
+                }
+            })
+        };
+
+        eventually_async(
+            || async { player.is_playing().await },
+            "Player never started with the external transport",
+        )
+        .await;
+        let elapsed = elapsed_eventually(&player).await?;
+        assert!(
+            elapsed >= Duration::from_secs(5) && elapsed < Duration::from_secs(8),
+            "expected playback to pick up near 5s, got {elapsed:?}"
+        );
+        let clock = player.transport.lock().as_ref().unwrap().clock.clone();
+        assert!(clock.is_external());
+
+        // The transport stops when the timecode does, and playback with it.
+        feeding.store(false, Ordering::Relaxed);
+        feeder.join().unwrap();
+        eventually_async(
+            || async { !player.is_playing().await },
+            "Player never stopped with the external transport",
+        )
+        .await;
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_stop_cancels_pending_auto_advance() -> Result<(), Box<dyn Error>> {
+        let player = make_auto_advance_player(
+            false,
+            config::AutoAdvance::new(config::SongTransition::Gap, Some("1s".into()), None),
+        )
+        .await?;
+
+        player.play().await?;
+        eventually_async(
+            || async {
+                player.get_playlist().current().unwrap().name() == "Song 3"
+                    && !player.is_playing().await
+            },
+            "Playlist never advanced to Song 3",
+        )
+        .await;
+
+        // Stopped inside the gap: Song 3 is not started.
+        player.stop().await;
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+        assert!(!player.is_playing().await);
+        assert_eq!(player.get_playlist().current().unwrap().name(), "Song 3");
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_navigation_cancels_pending_auto_advance() -> Result<(), Box<dyn Error>> {
+        let player = make_auto_advance_player(
+            false,
+            config::AutoAdvance::new(config::SongTransition::Gap, Some("1s".into()), None),
+        )
+        .await?;
+
+        player.play().await?;
+        eventually_async(
+            || async {
+                player.get_playlist().current().unwrap().name() == "Song 3"
+                    && !player.is_playing().await
+            },
+            "Playlist never advanced to Song 3",
+        )
+        .await;
+
+        player.prev().await;
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+        assert!(!player.is_playing().await);
+        assert_eq!(player.get_playlist().current().unwrap().name(), "Song 1");
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_gapless_without_audio_clock_follows_on() -> Result<(), Box<dyn Error>> {
+        // The mock device has no mixer, so there's no sample to hand off on:
+        // each song plays out and the next follows on without a gap.
+        let player = make_auto_advance_player(
+            true,
+            config::AutoAdvance::new(config::SongTransition::Gapless, None, None),
+        )
+        .await?;
+
+        player.play().await?;
+        for song in ["Song 3", "Song 5"] {
+            eventually_async(
+                || async { player.get_playlist().current().unwrap().name() == song },
+                &format!("Auto-advance never reached {}", song),
+            )
+            .await;
+        }
+        // Song 5 is long enough to still be playing.
+        eventually_async(
+            || async { player.is_playing().await },
+            "Song 5 never started",
+        )
+        .await;
+        assert!(!player.is_handing_off());
+
+        player.stop().await;
+        eventually_async(
+            || async { !player.is_playing().await },
+            "Player never stopped",
+        )
+        .await;
+
+        Ok(())
+    }
+
     // --- resolve_playback_outcome tests ---
 
     #[test]
@@ -0,0 +1,279 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, warn};
+
+use crate::{
+    audio::crossfade::{CrossfadeCurve, GainEnvelope},
+    clock::ScheduledEntry,
+    config::SongTransition,
+};
+
+use super::{Player, ReactiveLoopState, Transport};
+
+/// How far ahead of a gapless or crossfaded hand-off the next song is
+/// started, so its sources are open and warm by the time the outgoing song
+/// reaches the hand-off sample.
+const HANDOFF_PREROLL: Duration = Duration::from_secs(1);
+
+/// How often the hand-off waits to check whether the outgoing song has
+/// reached its end sample.
+const HANDOFF_POLL: Duration = Duration::from_millis(5);
+
+impl Player {
+    /// Starts the next song after `gap`, unless playback is started or
+    /// stopped in the meantime.
+    pub(super) fn schedule_auto_advance(&self, gap: Duration) {
+        let token = CancellationToken::new();
+        if let Some(previous) = self.auto_advance_cancel.lock().replace(token.clone()) {
+            previous.cancel();
+        }
+        info!(gap = ?gap, "Auto-advancing to the next song.");
+
+        let player = self.clone();
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = token.cancelled() => return,
+                _ = tokio::time::sleep(gap) => {}
+            }
+            // Same as the loop-break auto-play: play_from's future holds
+            // tokio mutex guards and isn't Send.
+            tokio::task::spawn_blocking(move || {
+                let rt = tokio::runtime::Handle::current();
+                rt.block_on(async move {
+                    let mut join = player.join.lock().await;
+                    // Checked under the join lock, which any explicit play
+                    // holds while it cancels the token.
+                    if token.is_cancelled() || join.is_some() {
+                        return;
+                    }
+                    let start_time = player.take_pending_start().unwrap_or(Duration::ZERO);
+                    if let Err(e) = player.play_from_locked(start_time, &mut join, None).await {
+                        error!(err = %e, "Failed to auto-advance to the next song");
+                    }
+                });
+            });
+        });
+    }
+
+    /// Cancels a pending auto-advance, if there is one.
+    pub(super) fn cancel_auto_advance(&self) {
+        if let Some(token) = self.auto_advance_cancel.lock().take() {
+            token.cancel();
+        }
+    }
+
+    /// Cancels the playback being handed off from, if a hand-off is under
+    /// way.
+    pub(super) fn cancel_outgoing(&self) {
+        if let Some(cancel) = self.outgoing.lock().take() {
+            cancel.cancel();
+        }
+    }
+
+    /// Returns true while a gapless or crossfaded hand-off is under way: the
+    /// next song is armed and the outgoing one is still playing out.
+    pub fn is_handing_off(&self) -> bool {
+        self.outgoing.lock().is_some()
+    }
+
+    /// Hands off to the next song once the playing one is within the
+    /// pre-roll of its end, for playlists that auto-advance gaplessly or
+    /// with a crossfade. Called from the playback's polling task.
+    ///
+    /// The next song is started early on a clock scheduled to begin at the
+    /// hand-off sample: the sample the outgoing song's audio ends on, less
+    /// the crossfade. Its sources are pre-queued into the mixer for that
+    /// sample, and the outgoing song's sources are cut (gapless) or faded
+    /// out across the crossfade (crossfade) from the same sample, so the
+    /// join is exact whatever the polling jitter.
+    ///
+    /// Songs that loop, or that have a section loop in play, aren't handed
+    /// off; they end however the loop ends and the playlist follows on
+    /// without a gap.
+    pub(super) fn check_auto_advance(&self, handed_off: &Arc<AtomicBool>) {
+        let playlist = self.get_playlist();
+        let Some(auto_advance) = playlist.auto_advance() else {
+            return;
+        };
+        let overlap = match auto_advance.transition() {
+            SongTransition::Gap => return,
+            SongTransition::Gapless => Duration::ZERO,
+            SongTransition::Crossfade => auto_advance.crossfade().unwrap_or(Duration::ZERO),
+        };
+        if !playlist.has_next() {
+            return;
+        }
+        let Some(song) = playlist.current() else {
+            return;
+        };
+        if song.loop_playback()
+            || self.active_section.read().is_some()
+            || !matches!(
+                *self.reactive_loop_state.read(),
+                ReactiveLoopState::Idle | ReactiveLoopState::SectionOffered(_)
+            )
+        {
+            return;
+        }
+        let Some(transport) = self.transport.lock().clone() else {
+            return;
+        };
+        if transport.clock.is_paused() {
+            return;
+        }
+
+        // Where the clock will be when the song ends: loop iterations run the
+        // clock on past the song position.
+        let consumed = *self.loop_time_consumed.lock();
+        let Some(clock_end) = (song.duration() + consumed).checked_sub(transport.start_time) else {
+            return;
+        };
+        let handoff_at = clock_end.saturating_sub(overlap);
+        let now = transport.clock.elapsed();
+        if now + HANDOFF_PREROLL < handoff_at || now >= handoff_at {
+            return;
+        }
+
+        // Claimed here so the next poll doesn't start a second hand-off.
+        if handed_off
+            .compare_exchange(false, true, Ordering::Relaxed, Ordering::Relaxed)
+            .is_err()
+        {
+            return;
+        }
+        let player = self.clone();
+        let handed_off = handed_off.clone();
+        tokio::task::spawn_blocking(move || {
+            let rt = tokio::runtime::Handle::current();
+            rt.block_on(player.hand_off(transport, handoff_at, overlap, handed_off));
+        });
+    }
+
+    /// Starts the next song scheduled to take over from the current one at
+    /// `handoff_at` on its clock. See [`Player::check_auto_advance`].
+    async fn hand_off(
+        &self,
+        transport: Transport,
+        handoff_at: Duration,
+        overlap: Duration,
+        handed_off: Arc<AtomicBool>,
+    ) {
+        let mut join = self.join.lock().await;
+        // The playback may have been stopped or replaced while this waited.
+        let handles = match join.take() {
+            Some(handles) if Arc::ptr_eq(&handles.handed_off, &handed_off) => handles,
+            other => {
+                *join = other;
+                handed_off.store(false, Ordering::Relaxed);
+                return;
+            }
+        };
+
+        let mixer = self.hardware.read().device.as_ref().and_then(|d| d.mixer());
+        let end = transport.clock.sample_at(handoff_at);
+        let (Some(mixer), Some(end)) = (mixer, end) else {
+            // A wall clock has no samples to hand off on; the song plays out
+            // and the playlist follows on from its cleanup.
+            *join = Some(handles);
+            handed_off.store(false, Ordering::Relaxed);
+            return;
+        };
+        let handoff_sample = end + transport.clock.audio_skew();
+        let overlap_samples = (overlap.as_secs_f64() * mixer.sample_rate() as f64).round() as u64;
+
+        // Outgoing sources are scheduled in their own transport's samples.
+        let outgoing_ids = mixer.source_ids_following(&transport.clock);
+        let outgoing_at = transport.clock.transport_sample(handoff_sample);
+        if overlap_samples == 0 {
+            mixer.set_cancel_at_sample(&outgoing_ids, outgoing_at);
+        } else {
+            mixer.set_gain_envelope(
+                &outgoing_ids,
+                Arc::new(
+                    GainEnvelope::fade_out(overlap_samples, CrossfadeCurve::EqualPower)
+                        .with_start_sample(outgoing_at),
+                ),
+            );
+        }
+
+        let playlist = self.get_playlist();
+        let Some(next) = playlist.next() else {
+            *join = Some(handles);
+            handed_off.store(false, Ordering::Relaxed);
+            return;
+        };
+        info!(
+            song = next.name(),
+            overlap = ?overlap,
+            "Handing off to the next song."
+        );
+        self.emit_song_change(&next);
+        *self.outgoing.lock() = Some(handles.cancel.clone());
+
+        let entry = ScheduledEntry {
+            start_sample: handoff_sample,
+            fade_in_samples: overlap_samples,
+        };
+        match self
+            .play_from_locked(Duration::ZERO, &mut join, Some(entry))
+            .await
+        {
+            Ok(Some(_)) => {}
+            result => {
+                if let Err(e) = result {
+                    warn!(err = %e, "Failed to hand off to the next song; letting this one finish");
+                }
+                // Put the outgoing song back as it was. Its cleanup advances
+                // the playlist again, so step back here.
+                self.outgoing.lock().take();
+                mixer.set_cancel_at_sample(&outgoing_ids, 0);
+                if overlap_samples > 0 {
+                    mixer.set_gain_envelope(
+                        &outgoing_ids,
+                        Arc::new(GainEnvelope::new(1.0, 1.0, 0, CrossfadeCurve::Linear)),
+                    );
+                }
+                if let Some(song) = playlist.prev() {
+                    self.emit_song_change(&song);
+                }
+                *join = Some(handles);
+                handed_off.store(false, Ordering::Relaxed);
+                return;
+            }
+        }
+        drop(join);
+
+        // The outgoing playback's audio plays out to the hand-off sample (or
+        // fades across the overlap); its MIDI and lighting stop there.
+        let player = self.clone();
+        tokio::spawn(async move {
+            while mixer.current_sample() < handoff_sample && !handles.cancel.is_cancelled() {
+                tokio::time::sleep(HANDOFF_POLL).await;
+            }
+            handles.cancel.cancel();
+            let mut outgoing = player.outgoing.lock();
+            if outgoing.as_ref().is_some_and(|c| c.is_cancelled()) {
+                *outgoing = None;
+            }
+        });
+    }
+}
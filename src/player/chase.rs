@@ -0,0 +1,278 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::{
+    clock::ExternalTime,
+    midi::{
+        chase::{ExternalPosition, ExternalTransport},
+        mtc::to_frame_count,
+    },
+    playlist::Playlist,
+    songs::Song,
+};
+
+use super::Player;
+
+/// How often the chase compares playback against the external transport.
+const CHASE_POLL: Duration = Duration::from_millis(10);
+
+/// How long after starting or relocating playback the chase leaves it alone,
+/// so it isn't judged on a position it hasn't had time to reach.
+const CHASE_SETTLE: Duration = Duration::from_millis(500);
+
+/// What the chase does to bring playback into line with the transport.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum ChaseAction {
+    /// Start playing from the given song position.
+    Start(Duration),
+    /// Stop playing.
+    Stop,
+    /// Relocate playback to the given song position.
+    Seek(Duration),
+    /// Leave playback as it is.
+    Hold,
+}
+
+/// Decides what playback should do, given whether the external transport is
+/// `running`, the song position it is at (`target`, None when it is before
+/// the song), and where playback is (`playing`, None when stopped).
+pub(super) fn decide(
+    running: bool,
+    target: Option<Duration>,
+    duration: Duration,
+    playing: Option<Duration>,
+    threshold: Duration,
+) -> ChaseAction {
+    match (running, playing, target) {
+        (false, Some(_), _) => ChaseAction::Stop,
+        // Located before the song starts: there is nothing to play yet.
+        (true, Some(_), None) => ChaseAction::Stop,
+        (true, None, Some(target)) if target < duration => ChaseAction::Start(target),
+        (true, Some(position), Some(target))
+            if target < duration && position.abs_diff(target) > threshold =>
+        {
+            ChaseAction::Seek(target)
+        }
+        // Past the end of the song, it plays out by itself.
+        _ => ChaseAction::Hold,
+    }
+}
+
+/// Where in `song` an external transport at `position` is. None if it is
+/// before the song starts, or if a beat position can't be placed because the
+/// song has no tempo and the beat clock's tempo hasn't been measured yet.
+pub(super) fn song_position(song: &Song, position: &ExternalPosition) -> Option<Duration> {
+    match *position {
+        ExternalPosition::Timecode { position, rate } => {
+            let start = to_frame_count(song.mtc_start(), rate) as f64 / rate.real_rate();
+            position.checked_sub(Duration::from_secs_f64(start))
+        }
+        ExternalPosition::Beats {
+            beats,
+            seconds_per_beat,
+        } => {
+            if let Some(tempo_map) = song.tempo_map() {
+                let start = tempo_map.start_offset;
+                return Some(start + tempo_map.beats_to_duration(beats, start, 0.0));
+            }
+            if let Some(grid) = song.beat_grid().filter(|grid| grid.beats.len() > 1) {
+                // Between detected beats, interpolate; past the last, carry on
+                // at the last beat's length.
+                let last = grid.beats.len() - 2;
+                let index = (beats.floor().max(0.0) as usize).min(last);
+                let length = grid.beats[index + 1] - grid.beats[index];
+                let seconds = grid.beats[index] + (beats - index as f64) * length;
+                return Some(Duration::from_secs_f64(seconds.max(0.0)));
+            }
+            seconds_per_beat.map(|seconds| Duration::from_secs_f64(beats * seconds))
+        }
+    }
+}
+
+/// The song an external timecode `position` selects by its hour: the first
+/// in the playlist whose `mtc_start` is in that hour, if the current song's
+/// isn't. Only a position within that song selects it.
+pub(super) fn song_for_hour(
+    playlist: &Playlist,
+    current: &Song,
+    position: &ExternalPosition,
+) -> Option<Arc<Song>> {
+    let ExternalPosition::Timecode { position: time, .. } = position else {
+        return None;
+    };
+    let hour = time.as_secs() / 3600;
+    if u64::from(current.mtc_start().hours) == hour {
+        return None;
+    }
+    playlist
+        .songs()
+        .iter()
+        .filter_map(|name| playlist.get_song(name))
+        .filter(|song| u64::from(song.mtc_start().hours) == hour)
+        .find(|song| song_position(song, position).is_some_and(|at| at < song.duration()))
+}
+
+/// An external transport seen through one song's timeline, for a playback
+/// clock to follow.
+pub(super) struct SongChase {
+    transport: Arc<ExternalTransport>,
+    song: Arc<Song>,
+}
+
+impl SongChase {
+    pub(super) fn new(transport: Arc<ExternalTransport>, song: Arc<Song>) -> SongChase {
+        SongChase { transport, song }
+    }
+}
+
+impl ExternalTime for SongChase {
+    fn position(&self) -> Option<Duration> {
+        let state = self.transport.state().filter(|state| state.running)?;
+        song_position(&self.song, &state.position)
+    }
+}
+
+impl Player {
+    /// Follows an external transport until `cancel` fires: starts, stops and
+    /// relocates playback of the current song as the transport does, and
+    /// seeks back into line whenever playback drifts more than `threshold`
+    /// from it. With `select_by_hour`, timecode running in another song's
+    /// hour switches to that song.
+    pub(super) fn start_chase(
+        &self,
+        transport: Arc<ExternalTransport>,
+        threshold: Duration,
+        select_by_hour: bool,
+        cancel: CancellationToken,
+    ) {
+        info!(threshold = ?threshold, select_by_hour, "Chasing external transport.");
+        let player = self.clone();
+        // play_from's future holds tokio mutex guards and isn't Send.
+        tokio::task::spawn_blocking(move || {
+            let rt = tokio::runtime::Handle::current();
+            rt.block_on(player.chase(transport, threshold, select_by_hour, cancel));
+        });
+    }
+
+    async fn chase(
+        &self,
+        transport: Arc<ExternalTransport>,
+        threshold: Duration,
+        select_by_hour: bool,
+        cancel: CancellationToken,
+    ) {
+        let mut settled_at = Instant::now();
+        // The song position the transport was last seen running at, and
+        // when. A clock that follows the transport can't show drift from
+        // it, so jumps are measured against this instead.
+        let mut last_seen: Option<(Duration, Instant)> = None;
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => return,
+                _ = tokio::time::sleep(CHASE_POLL) => {}
+            }
+            let now = Instant::now();
+            if now < settled_at {
+                continue;
+            }
+            let (Some(state), Some(song)) = (transport.state(), self.get_playlist().current())
+            else {
+                continue;
+            };
+
+            let selected = (select_by_hour && state.running)
+                .then(|| song_for_hour(&self.get_playlist(), &song, &state.position))
+                .flatten();
+            if let Some(selected) = selected {
+                info!(song = selected.name(), "External timecode selected a song.");
+                if self.is_playing().await {
+                    self.stop().await;
+                }
+                let position = song_position(&selected, &state.position).unwrap_or_default();
+                if let Err(e) = self.play_song_from(selected.name(), position).await {
+                    warn!(err = %e, "Failed to start the song selected by external timecode");
+                }
+                last_seen = None;
+                settled_at = Instant::now() + CHASE_SETTLE;
+                continue;
+            }
+
+            let target = song_position(&song, &state.position);
+
+            let playing = if self.is_playing().await {
+                // Starting up, or held by a local pause the transport knows
+                // nothing about: wait until there is a position to compare.
+                match self.chase_position(last_seen, now) {
+                    Some(position) if !(self.is_paused() && state.running) => Some(position),
+                    _ => continue,
+                }
+            } else {
+                None
+            };
+            last_seen = target.filter(|_| state.running).map(|target| (target, now));
+
+            match decide(state.running, target, song.duration(), playing, threshold) {
+                ChaseAction::Start(position) => {
+                    info!(song = song.name(), position = ?position, "External transport started.");
+                    if let Err(e) = self.play_from(position).await {
+                        warn!(err = %e, "Failed to start playback with the external transport");
+                    }
+                    settled_at = Instant::now() + CHASE_SETTLE;
+                }
+                ChaseAction::Seek(position) => {
+                    info!(
+                        song = song.name(),
+                        position = ?position,
+                        drift = ?playing.map(|p| p.abs_diff(position)),
+                        "Relocating to the external transport."
+                    );
+                    if let Err(e) = self.seek_to(position).await {
+                        warn!(err = %e, "Failed to relocate to the external transport");
+                    }
+                    settled_at = Instant::now() + CHASE_SETTLE;
+                }
+                ChaseAction::Stop => {
+                    info!(song = song.name(), "External transport stopped.");
+                    self.stop().await;
+                }
+                ChaseAction::Hold => {}
+            }
+        }
+    }
+
+    /// Where playback is in the song, for comparing against the transport.
+    /// None while playback is still starting up.
+    fn chase_position(
+        &self,
+        last_seen: Option<(Duration, Instant)>,
+        now: Instant,
+    ) -> Option<Duration> {
+        let transport = self.transport.lock().clone()?;
+        if !transport.clock.has_started() {
+            return None;
+        }
+        if transport.clock.is_external() {
+            return last_seen.map(|(position, at)| position + now.saturating_duration_since(at));
+        }
+        let position = transport.start_time + transport.clock.elapsed();
+        Some(position.saturating_sub(*self.loop_time_consumed.lock()))
+    }
+}
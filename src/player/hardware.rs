@@ -168,7 +168,10 @@ impl Player {
             async {
                 if let Some(audio_config) = audio_config {
                     let outcome = Self::retry_until_ready("audio device", cancel1, move || {
-                        match audio::get_device(Some(audio_config.audio().clone())) {
+                        match audio::get_device_for_mappings(
+                            Some(audio_config.audio().clone()),
+                            &audio_config.output_mappings_hash(),
+                        ) {
                             Ok(device) => {
                                 info!(
                                     device = audio_config.audio().device(),
@@ -211,7 +214,7 @@ impl Player {
         }
 
         // Write Phase 1 results to hardware state.
-        let (device, mappings, resolved_audio) = match audio_result {
+        let (device, mappings, resolved_audio, meters) = match audio_result {
             Some((device, mappings, resolved_audio)) => {
                 let clock_source = match device.sample_counter().zip(device.sample_rate()) {
                     Some((counter, rate)) => ClockSource::Audio {
@@ -221,17 +224,38 @@ impl Player {
                     None => ClockSource::Wall,
                 };
 
-                // Build the shared track gains from the profile config and
-                // install them into the device mixer so the audio callback
-                // can read them lock-free. Rebuilt on every (re)load from
-                // the persisted config, so gains survive restarts.
-                let track_gains = Arc::new(crate::audio::track_gains::TrackGains::from_config(
+                // Build the shared track gains, with pan, polarity, width,
+                // EQ and the monitor mixes, from the profile config and install
+                // them into the device mixer so the audio callback can read
+                // them lock-free. Rebuilt on every (re)load from the
+                // persisted config, so gains survive restarts.
+                let mut track_gains = crate::audio::track_gains::TrackGains::from_config(
                     &mappings,
                     profile.audio_config().map(|ac| ac.track_gains()),
-                ));
-                if let Some(mixer) = device.mixer() {
-                    mixer.set_track_gains(track_gains.clone());
+                );
+                if let Some(audio_config) = profile.audio_config() {
+                    track_gains = track_gains
+                        .with_mix(audio_config.track_mix())
+                        .with_eq(audio_config.track_eq())
+                        .with_monitor_mixes(audio_config.mixes());
                 }
+                let track_gains = Arc::new(track_gains);
+                // Level meters are indexed by track gain slot, so they are
+                // rebuilt alongside the gains.
+                let meters = device.mixer().map(|mixer| {
+                    mixer.set_track_gains(track_gains.clone());
+                    let meters = Arc::new(crate::audio::meters::Meters::new(
+                        mixer.num_channels(),
+                        track_gains.names(),
+                    ));
+                    mixer.set_meters(meters.clone());
+                    mixer.set_output_stage(output_stage(
+                        &resolved_audio,
+                        mixer.num_channels(),
+                        mixer.sample_rate(),
+                    ));
+                    meters
+                });
 
                 // Player-wide metronome defaults (master volume, click
                 // sounds) for songs that don't override them.
@@ -246,9 +270,9 @@ impl Player {
                 if !installed {
                     return;
                 }
-                (Some(device), Some(mappings), Some(resolved_audio))
+                (Some(device), Some(mappings), Some(resolved_audio), meters)
             }
-            None => (None, None, None),
+            None => (None, None, None, None),
         };
 
         if let Some(ref dmx_engine) = dmx_result {
@@ -261,12 +285,16 @@ impl Player {
             if let Some(ref tx) = *self.broadcast_tx.lock() {
                 dmx_engine.set_broadcast_tx(tx.clone());
             }
-            // Start the state sampler if a sender was provided. The cancel
-            // token ensures this sampler stops when hardware is reloaded.
+        }
+
+        // Start the state sampler if a sender was provided and there is
+        // lighting or metering to sample. The cancel token ensures this
+        // sampler stops when hardware is reloaded.
+        if dmx_result.is_some() || meters.is_some() {
             if let Some(ref state_tx) = *self.state_tx.lock() {
-                let effect_engine = dmx_engine.effect_engine();
                 crate::state::start_sampler_cancellable(
-                    effect_engine,
+                    dmx_result.as_ref().map(|engine| engine.effect_engine()),
+                    meters.zip(resolved_audio.as_ref().map(|audio| audio.meter_interval())),
                     state_tx.clone(),
                     cancel.clone(),
                 );
@@ -351,6 +379,13 @@ impl Player {
                 // Dropping it here closes the input stream it just opened.
                 return;
             }
+            let ltc = profile.trigger().and_then(|t| t.ltc_input());
+            if let (Some(transport), Some(ltc)) = (te.external_transport(), ltc) {
+                match ltc.resync_threshold() {
+                    Ok(threshold) => self.start_chase(transport, threshold, true, cancel.clone()),
+                    Err(e) => warn!(error = %e, "Invalid LTC resync threshold"),
+                }
+            }
         }
 
         // Nothing past here belongs to a round that has been replaced: starting
@@ -391,6 +426,17 @@ impl Player {
                     player.report_status(status_events).await;
                 });
             }
+
+            let transport = midi_result
+                .as_ref()
+                .and_then(|midi_device| midi_device.external_transport());
+            let chase = profile.midi().and_then(|midi| midi.chase());
+            if let (Some(transport), Some(chase)) = (transport, chase) {
+                match chase.resync_threshold() {
+                    Ok(threshold) => self.start_chase(transport, threshold, false, cancel.clone()),
+                    Err(e) => warn!(error = %e, "Invalid chase resync threshold"),
+                }
+            }
         }
 
         // Under the hardware lock, like every other write in this function: a
@@ -693,6 +739,11 @@ impl Player {
                 callbacks: h.callbacks,
                 recoveries: h.recoveries,
                 underruns: h.underruns,
+                limiter_engagements: hw
+                    .device
+                    .as_ref()
+                    .and_then(|d| d.mixer())
+                    .map_or(0, |mixer| mixer.limiter_engagements()),
                 last_error: h.last_error.clone(),
             }
         });
@@ -965,21 +1016,55 @@ fn install_if_current(
     true
 }
 
+/// Builds the mixer's output stage (master gains and limiter) from the
+/// profile's audio config.
+fn output_stage(
+    audio_config: &config::Audio,
+    num_channels: u16,
+    sample_rate: u32,
+) -> audio::mixer::OutputStage {
+    let limiter = audio_config.limiter().map(|limiter| {
+        let settings = audio::limiter::LimiterSettings::new(
+            limiter.ceiling_db(),
+            limiter.lookahead(),
+            limiter.release(),
+            sample_rate,
+        );
+        info!(
+            ceiling_db = limiter.ceiling_db(),
+            lookahead = ?limiter.lookahead(),
+            release = ?limiter.release(),
+            "Output limiter enabled"
+        );
+        audio::limiter::Limiter::new(num_channels as usize, settings)
+    });
+    audio::mixer::OutputStage::new(audio_config.master_gains(num_channels), limiter)
+}
+
 pub(super) fn init_trigger_engine(
     profile: &config::Profile,
     sample_engine: &Option<Arc<RwLock<SampleEngine>>>,
 ) -> Result<Option<Arc<TriggerEngine>>, Box<dyn Error>> {
-    let (trigger_config, sample_engine) = match (
-        profile.trigger().filter(|t| t.has_audio_inputs()),
-        sample_engine,
-    ) {
-        (Some(tc), Some(se)) => (tc, se),
+    // A timecode input or a recording is worth opening the device for even
+    // without samples to trigger.
+    let trigger_config = match profile
+        .trigger()
+        .filter(|t| t.has_audio_inputs() || t.record().is_some())
+    {
+        Some(tc)
+            if sample_engine.is_some() || tc.ltc_input().is_some() || tc.record().is_some() =>
+        {
+            tc
+        }
         _ => return Ok(None),
     };
 
     match TriggerEngine::new(trigger_config) {
         Ok(engine) => {
             let engine: Arc<TriggerEngine> = Arc::new(engine);
+            let Some(sample_engine) = sample_engine else {
+                return Ok(Some(engine));
+            };
 
             // Spawn a forwarding thread: reads TriggerActions and dispatches
             // to the sample engine. When the TriggerEngine drops, the sender
@@ -61,7 +61,7 @@ impl Player {
         self.clear_pending_start();
 
         // Start playback with the lock already held.
-        self.play_from_locked(start_time, &mut join).await
+        self.play_from_locked(start_time, &mut join, None).await
     }
 
     /// Navigates the playlist in the given direction, emitting the song-change
@@ -92,6 +92,8 @@ impl Player {
             }
             return current;
         }
+        // Moving by hand takes over from a pending auto-advance.
+        self.cancel_auto_advance();
         let playlist = self.get_playlist();
         let song = match direction {
             PlaylistDirection::Next => playlist.next()?,
@@ -129,6 +131,7 @@ impl Player {
                 }
                 return Err("Cannot switch playlist while playing".to_string());
             }
+            self.cancel_auto_advance();
         }
 
         // Validate the name exists.
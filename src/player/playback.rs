@@ -23,8 +23,8 @@ use tracing::{error, info};
 use crate::{dmx, playsync::CancelHandle, songs::Song};
 
 use super::{
-    decide_cleanup_action, resolve_playback_outcome, CleanupAction, PlayHandles, PlaybackContext,
-    PlaybackResult, Player, ReactiveLoopState,
+    decide_cleanup_action, resolve_playback_outcome, CleanupAction, ClockSource, PlayHandles,
+    PlaybackContext, PlaybackResult, Player, ReactiveLoopState, Transport,
 };
 
 impl Player {
@@ -33,8 +33,12 @@ impl Player {
     /// Returns an error if lighting show validation fails.
     ///
     /// A pending start position (set by seeking while stopped) is consumed
-    /// and playback starts there instead of the beginning.
+    /// and playback starts there instead of the beginning. A paused song is
+    /// resumed where it was paused.
     pub async fn play(&self) -> Result<Option<Arc<Song>>, Box<dyn Error>> {
+        if self.is_paused() {
+            return Ok(self.resume().await);
+        }
         let start_time = self.take_pending_start().unwrap_or(Duration::ZERO);
         self.play_from(start_time).await
     }
@@ -70,18 +74,25 @@ impl Player {
             return Ok(None);
         }
 
-        self.play_from_locked(start_time, &mut join).await
+        self.play_from_locked(start_time, &mut join, None).await
     }
 
     /// Inner implementation of play_from that assumes the caller already holds the join lock
     /// and has verified it is `None` (no active playback).
+    ///
+    /// With an `entry`, the song's clock is scheduled to start on that sample
+    /// rather than immediately; see [`Player::check_auto_advance`].
     pub(super) async fn play_from_locked(
         &self,
         start_time: Duration,
         join: &mut Option<PlayHandles>,
+        entry: Option<crate::clock::ScheduledEntry>,
     ) -> Result<Option<Arc<Song>>, Box<dyn Error>> {
         let _enter = self.span.enter();
 
+        // Starting playback by any route ends the wait for an auto-advance.
+        self.cancel_auto_advance();
+
         let playlist = self.get_playlist().clone();
         let song = match playlist.current() {
             Some(song) => song,
@@ -138,13 +149,42 @@ impl Player {
         // Reset loop time consumed for the new song.
         *self.loop_time_consumed.lock() = Duration::ZERO;
 
+        // Without an audio device to keep time, a chased transport does.
+        let external = hw
+            .midi_device
+            .as_ref()
+            .and_then(|midi_device| midi_device.external_transport());
+        let clock = match (&hw.clock_source, external) {
+            (ClockSource::Wall, Some(transport)) => crate::clock::PlaybackClock::external(
+                Arc::new(super::chase::SongChase::new(transport, song.clone())),
+                start_time,
+            ),
+            _ => hw.clock_source.new_clock(),
+        };
+        // A chased transport sets its own pace.
+        if !clock.is_external() {
+            clock.set_rate(*self.playback_rate.read());
+        }
+        if let Some(entry) = entry {
+            clock.schedule_start(entry.start_sample, entry.fade_in_samples);
+        }
+        // An armed recorder takes the song from where its clock starts.
+        let take = hw
+            .trigger_engine
+            .as_ref()
+            .and_then(|engine| engine.recorder())
+            .and_then(|recorder| {
+                let id = recorder.start_take(song.name(), clock.clone(), start_time)?;
+                Some((recorder, id))
+            });
+        let handed_off = Arc::new(std::sync::atomic::AtomicBool::new(false));
         let join_handle = {
             let ctx = PlaybackContext {
                 device: hw.device.clone(),
                 mappings: hw.mappings.clone(),
                 midi_device: hw.midi_device.clone(),
                 dmx_engine: hw.dmx_engine.clone(),
-                clock: hw.clock_source.new_clock(),
+                clock: clock.clone(),
                 song: song.clone(),
                 cancel_handle: cancel_handle.clone(),
                 play_tx,
@@ -164,24 +204,30 @@ impl Player {
         *join = Some(PlayHandles {
             join: join_handle,
             cancel: cancel_handle.clone(),
+            handed_off: handed_off.clone(),
         });
+        *self.transport.lock() = Some(Transport { clock, start_time });
 
         // Spawn section boundary polling task for reactive looping.
+        // The same task watches for the point to hand off to the next song.
         {
             let player = self.clone();
             let cancel = cancel_handle.clone();
+            let handed_off = handed_off.clone();
             // Reset reactive state for new song.
             *self.reactive_loop_state.write() = ReactiveLoopState::Idle;
             tokio::spawn(async move {
                 let mut interval = tokio::time::interval(Duration::from_millis(50));
                 loop {
                     interval.tick().await;
-                    if cancel.is_cancelled() {
+                    if cancel.is_cancelled() || handed_off.load(Ordering::Relaxed) {
                         break;
                     }
                     if let Ok(Some(elapsed)) = player.elapsed().await {
                         player.check_section_boundaries(elapsed);
+                        player.check_sample_kit(elapsed);
                     }
+                    player.check_auto_advance(&handed_off);
                 }
             });
         }
@@ -195,6 +241,16 @@ impl Player {
                     Ok(Err(e)) => PlaybackResult::Failed(e),
                     Err(_e) => PlaybackResult::SenderDropped,
                 };
+                if let Some((recorder, id)) = take {
+                    recorder.finish_take(id);
+                }
+
+                // The next song already took over; everything here is its state.
+                if handed_off.load(Ordering::Relaxed) {
+                    info!(song = song.name(), "Song finished after handing off.");
+                    return;
+                }
+                let succeeded = matches!(result, PlaybackResult::Success);
 
                 let cancelled = cancel_handle_for_cleanup.is_cancelled();
                 // Playback that ended itself, as distinct from one that stop()
@@ -220,6 +276,7 @@ impl Player {
 
                 // Natural finish or loop break: advance playlist and clean up.
                 let mut join = player.join.lock().await;
+                let advanced = playlist.has_next();
                 if let Some(song) = playlist.next() {
                     player.emit_song_change(&song);
                 }
@@ -227,11 +284,32 @@ impl Player {
                 {
                     let mut play_start_time = player.play_start_time.lock().await;
                     *play_start_time = None;
+                    *player.transport.lock() = None;
                 }
 
                 *join = None;
                 player.stop_run.store(false, Ordering::Relaxed);
                 let should_auto_play = action == CleanupAction::LoopBreakAndPlay;
+
+                // A song that played out on its own moves on by itself when
+                // the playlist auto-advances. A gapless or crossfaded
+                // transition only gets here when the hand-off couldn't be
+                // made, and then follows straight on.
+                if action == CleanupAction::AdvancePlaylist
+                    && succeeded
+                    && !self_cancelled
+                    && advanced
+                {
+                    if let Some(auto_advance) = playlist.auto_advance() {
+                        let gap = match auto_advance.transition() {
+                            crate::config::SongTransition::Gap => {
+                                auto_advance.gap().unwrap_or(Duration::ZERO)
+                            }
+                            _ => Duration::ZERO,
+                        };
+                        player.schedule_auto_advance(gap);
+                    }
+                }
                 drop(join);
 
                 // If loop was broken (play/next during loop), auto-play the next song.
@@ -409,8 +487,20 @@ impl Player {
         // Start the clock — this is the "go" signal for all subsystems.
         clock.start();
 
+        // A clock started on a schedule arms everything now but only begins
+        // counting at its scheduled sample, while the previous song plays
+        // out. The position is stamped from there.
+        while !cancel_handle.is_cancelled() {
+            let until = clock.until_running();
+            if until.is_zero() {
+                break;
+            }
+            thread::sleep(until.min(Duration::from_millis(10)));
+        }
+
         // Set play_start_time NOW, at the exact moment playback begins.
-        // Offset backwards by start_time so elapsed() reflects song position.
+        // Offset backwards by start_time so elapsed() reflects song position;
+        // the anchor is in real time, so the offset is scaled by the speed.
         // We use blocking_lock because we're in a spawn_blocking context.
         //
         // Skip it if this playback was cancelled during startup: stop() and
@@ -421,7 +511,7 @@ impl Player {
         {
             let mut pst = play_start_time.blocking_lock();
             if !cancel_handle.is_cancelled() {
-                *pst = Some(SystemTime::now() - start_time);
+                *pst = Some(SystemTime::now() - start_time.div_f64(clock.rate().speed));
             }
         }
 
@@ -452,6 +542,8 @@ impl Player {
     /// Stop will stop a song if a song is playing.
     pub async fn stop(&self) -> Option<Arc<Song>> {
         let mut join = self.join.lock().await;
+        self.cancel_auto_advance();
+        self.cancel_outgoing();
 
         let play_handles = match join.take() {
             Some(handles) => handles,
@@ -479,6 +571,7 @@ impl Player {
             let mut play_start_time = self.play_start_time.lock().await;
             play_handles.cancel.cancel();
             *play_start_time = None;
+            *self.transport.lock() = None;
         }
 
         drop(play_handles.join);
@@ -487,6 +580,94 @@ impl Player {
         Some(song)
     }
 
+    /// Pauses the current song in place. Returns the song if it was paused,
+    /// or None if nothing is playing or it is already paused.
+    ///
+    /// Pausing freezes the playback clock: the audio ramps out over a few
+    /// milliseconds and holds its position, MIDI playback holds its next
+    /// event and silences anything left sounding, and the lighting timeline
+    /// holds on its current frame. `resume()` picks all of them up from the
+    /// exact sample they stopped on.
+    pub async fn pause(&self) -> Option<Arc<Song>> {
+        let join = self.join.lock().await;
+        let transport = self.transport.lock().clone();
+        let (Some(_), Some(transport)) = (&*join, transport) else {
+            info!("Player is not active, nothing to pause.");
+            return None;
+        };
+
+        // play_start_time is stamped under this lock just after the clock
+        // starts, so holding it keeps a pause from landing in between.
+        if self.is_handing_off() {
+            info!("Changing songs, nothing to pause.");
+            return None;
+        }
+
+        let play_start_time = self.play_start_time.lock().await;
+        if play_start_time.is_none() || !transport.clock.pause() {
+            info!("Playback is starting up or already paused, nothing to pause.");
+            return None;
+        }
+        drop(play_start_time);
+        drop(join);
+
+        let song = self.get_playlist().current();
+        if let Some(ref song) = song {
+            info!(song = song.name(), "Pausing playback.");
+        }
+        song
+    }
+
+    /// Resumes a paused song from where it was paused. Returns the song if
+    /// it was resumed, or None if nothing is paused.
+    pub async fn resume(&self) -> Option<Arc<Song>> {
+        let join = self.join.lock().await;
+        let transport = self.transport.lock().clone();
+        let (Some(_), Some(transport)) = (&*join, transport) else {
+            info!("Player is not active, nothing to resume.");
+            return None;
+        };
+
+        let mut play_start_time = self.play_start_time.lock().await;
+        if !transport.clock.resume() {
+            info!("Player is not paused, nothing to resume.");
+            return None;
+        }
+        // Re-anchor on the clock rather than adding up wall-clock pause
+        // lengths, so elapsed() carries on from exactly where the audio
+        // picks up however many times the song is paused.
+        if let Some(ref mut start) = *play_start_time {
+            let position = transport.start_time + transport.clock.elapsed();
+            *start = SystemTime::now() - position.div_f64(transport.clock.rate().speed);
+        }
+        drop(play_start_time);
+        drop(join);
+
+        let song = self.get_playlist().current();
+        if let Some(ref song) = song {
+            info!(song = song.name(), "Resuming playback.");
+        }
+        song
+    }
+
+    /// Pauses a playing song or resumes a paused one. Returns the song
+    /// affected, if any.
+    pub async fn toggle_pause(&self) -> Option<Arc<Song>> {
+        if self.is_paused() {
+            self.resume().await
+        } else {
+            self.pause().await
+        }
+    }
+
+    /// Returns true if the current song is paused.
+    pub fn is_paused(&self) -> bool {
+        self.transport
+            .lock()
+            .as_ref()
+            .is_some_and(|t| t.clock.is_paused())
+    }
+
     /// Returns true if a song is currently playing.
     pub async fn is_playing(&self) -> bool {
         self.join.lock().await.is_some()
@@ -506,7 +687,21 @@ impl Player {
         let play_start_time = self.play_start_time.lock().await;
         Ok(match *play_start_time {
             Some(play_start_time) => {
-                let raw = play_start_time.elapsed()?;
+                // While paused, report where the transport holds rather
+                // than where the wall clock has got to.
+                let (held, speed) = match self.transport.lock().as_ref() {
+                    Some(t) => (
+                        t.clock
+                            .hold_position()
+                            .map(|position| t.start_time + position),
+                        t.clock.rate().speed,
+                    ),
+                    None => (None, 1.0),
+                };
+                let raw = match held {
+                    Some(raw) => raw,
+                    None => play_start_time.elapsed()?.mul_f64(speed),
+                };
                 let consumed = *self.loop_time_consumed.lock();
                 Some(raw.saturating_sub(consumed))
             }
@@ -514,6 +709,70 @@ impl Player {
         })
     }
 
+    /// The speed and transposition songs play at.
+    pub fn playback_rate(&self) -> crate::audio::PlaybackRate {
+        *self.playback_rate.read()
+    }
+
+    /// Sets the speed and transposition songs play at. A playing song
+    /// restarts where it is at the new rate, keeping its section loop; a
+    /// paused one stays paused. A song chasing an external transport keeps
+    /// the transport's pace until it next starts.
+    pub async fn set_playback_rate(
+        &self,
+        rate: crate::audio::PlaybackRate,
+    ) -> Result<(), Box<dyn Error>> {
+        {
+            let mut current = self.playback_rate.write();
+            if *current == rate {
+                return Ok(());
+            }
+            *current = rate;
+        }
+        info!(
+            speed = rate.speed,
+            semitones = rate.semitones,
+            "Playback rate changed"
+        );
+
+        let restart = self
+            .transport
+            .lock()
+            .as_ref()
+            .is_some_and(|t| !t.clock.is_external());
+        if !restart || !self.is_playing().await {
+            return Ok(());
+        }
+        let section = self.active_section.read().clone();
+        let position = self.elapsed().await?.unwrap_or(Duration::ZERO);
+        self.seek_to(position).await?;
+        if let Some(section) = section {
+            self.loop_section(&section.name).await?;
+        }
+        Ok(())
+    }
+
+    /// The input recorder's state, or None if recording isn't configured.
+    pub fn record_status(&self) -> Option<crate::trigger::RecordStatus> {
+        let recorder = self.hardware.read().trigger_engine.as_ref()?.recorder()?;
+        Some(recorder.status())
+    }
+
+    /// Arms or disarms input recording. An armed recorder records every song
+    /// from the next one that starts; disarming finishes the take in
+    /// progress.
+    pub fn set_record_armed(&self, armed: bool) -> Result<(), Box<dyn Error>> {
+        let recorder = self
+            .hardware
+            .read()
+            .trigger_engine
+            .as_ref()
+            .and_then(|engine| engine.recorder())
+            .ok_or("Input recording is not configured")?;
+        recorder.set_armed(armed);
+        Ok(())
+    }
+
     /// Adds time consumed by a section loop iteration. Called by subsystems
     /// when a section loop triggers to keep the reported elapsed time correct.
     pub fn add_loop_time_consumed(&self, duration: Duration) {
@@ -645,6 +904,40 @@ impl Player {
         }
     }
 
+    /// Finds the section the playhead is currently in.
+    fn section_at(song: &Song, elapsed: Duration) -> Option<super::SectionBounds> {
+        song.sections().iter().find_map(|s| {
+            let (start, end) = song.resolve_section(&s.name)?;
+            if elapsed >= start && elapsed < end {
+                Some(super::SectionBounds {
+                    name: s.name.clone(),
+                    start_time: start,
+                    end_time: end,
+                })
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Tells the sample engine which section the playhead is in, so sample kits
+    /// mapped to sections switch in and out.
+    ///
+    /// Called periodically by the section boundary polling task.
+    pub fn check_sample_kit(&self, elapsed: Duration) {
+        let sample_engine = match self.hardware.read().sample_engine.clone() {
+            Some(engine) => engine,
+            None => return,
+        };
+        let section = self
+            .get_playlist()
+            .current()
+            .and_then(|song| Self::section_at(&song, elapsed));
+        sample_engine
+            .read()
+            .enter_section(section.as_ref().map(|bounds| bounds.name.as_str()));
+    }
+
     /// Checks whether the playhead has entered or left a section boundary.
     ///
     /// Called periodically by the section boundary polling task. Handles
@@ -657,24 +950,11 @@ impl Player {
             None => return,
         };
 
-        let sections = song.sections();
-        if sections.is_empty() {
+        if song.sections().is_empty() {
             return;
         }
 
-        // Find which section the playhead is currently in.
-        let current_section = sections.iter().find_map(|s| {
-            let (start, end) = song.resolve_section(&s.name)?;
-            if elapsed >= start && elapsed < end {
-                Some(super::SectionBounds {
-                    name: s.name.clone(),
-                    start_time: start,
-                    end_time: end,
-                })
-            } else {
-                None
-            }
-        });
+        let current_section = Self::section_at(&song, elapsed);
 
         let mut state = self.reactive_loop_state.write();
 
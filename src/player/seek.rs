@@ -32,7 +32,9 @@ impl Player {
     /// at the target position fully re-synchronized. While stopped, the
     /// position is stored and consumed by the next `play()`.
     ///
-    /// Seeking clears any active section loop.
+    /// Seeking clears any active section loop. Seeking while paused stays
+    /// paused: playback restarts held at the new position and waits for
+    /// `resume()`.
     pub async fn seek_to(&self, position: Duration) -> Result<(), Box<dyn Error>> {
         let mut join = self.join.lock().await;
 
@@ -72,11 +74,16 @@ impl Player {
         // startup stamps play_start_time under that lock and skips the stamp
         // when cancelled, so this ordering keeps it from writing the pre-seek
         // position back after we clear it.
+        let was_paused = self.is_paused();
+        // A seek during a hand-off lands in the incoming song; the outgoing
+        // one stops here rather than playing out under it.
+        self.cancel_outgoing();
         self.fade_out_current_audio();
         {
             let mut play_start_time = self.play_start_time.lock().await;
             handles.cancel.cancel();
             *play_start_time = None;
+            *self.transport.lock() = None;
         }
 
         // Wait for all subsystems to wind down (play_files joins its audio/
@@ -108,7 +115,12 @@ impl Player {
         }
 
         // Restart at the target position under the same join lock.
-        self.play_from_locked(position, &mut join).await?;
+        self.play_from_locked(position, &mut join, None).await?;
+        if was_paused {
+            if let Some(transport) = self.transport.lock().as_ref() {
+                transport.clock.hold_at_start();
+            }
+        }
         Ok(())
     }
 
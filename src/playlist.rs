@@ -24,6 +24,8 @@ use std::sync::Arc;
 pub enum PlaylistError {
     #[error("Song not in registry: {0}")]
     SongNotFound(String),
+    #[error("Invalid auto_advance: {}", .0.join("; "))]
+    InvalidAutoAdvance(Vec<String>),
 }
 
 /// Playlist is a playlist for use by a player.
@@ -36,6 +38,8 @@ pub struct Playlist {
     position: Arc<RwLock<usize>>,
     /// The song registry.
     registry: Arc<Songs>,
+    /// How the playlist moves on by itself when a song finishes, if it does.
+    auto_advance: Option<config::AutoAdvance>,
     /// The logging span.
     span: Span,
 }
@@ -68,12 +72,18 @@ impl Playlist {
                 .get(song_name)
                 .map_err(|_| PlaylistError::SongNotFound(song_name.clone()))?;
         }
+        if let Some(auto_advance) = config.auto_advance() {
+            auto_advance
+                .validate()
+                .map_err(PlaylistError::InvalidAutoAdvance)?;
+        }
 
         Ok(Arc::new(Playlist {
             name: name.to_string(),
             songs: song_names.to_vec(),
             position: Arc::new(RwLock::new(0)),
             registry: Arc::clone(&registry),
+            auto_advance: config.auto_advance().cloned(),
             span: span!(Level::INFO, "playlist"),
         }))
     }
@@ -93,6 +103,16 @@ impl Playlist {
         &self.songs
     }
 
+    /// Returns how the playlist advances by itself, if it does.
+    pub fn auto_advance(&self) -> Option<&config::AutoAdvance> {
+        self.auto_advance.as_ref()
+    }
+
+    /// Returns true if there is a song after the current position.
+    pub fn has_next(&self) -> bool {
+        *self.position.read() + 1 < self.songs.len()
+    }
+
     /// Move to the next element of the playlist. If we're at the end of the playlist, the position will not
     /// increment. The song at the current position will be returned.
     /// Returns `None` if the playlist is empty.
@@ -252,6 +272,7 @@ mod test {
             songs: vec![],
             position: Arc::new(parking_lot::RwLock::new(0)),
             registry,
+            auto_advance: None,
             span: tracing::span!(tracing::Level::INFO, "test"),
         };
         assert!(playlist.current().is_none());
@@ -314,6 +335,49 @@ mod test {
         assert!(!names.is_empty());
     }
 
+    #[test]
+    fn auto_advance_carried_from_config() {
+        let registry = test_registry();
+        let playlist = super::Playlist::new(
+            "set",
+            &config::Playlist::new(&["Song 1".to_string(), "Song 2".to_string()])
+                .with_auto_advance(Some(config::AutoAdvance::new(
+                    config::SongTransition::Gapless,
+                    None,
+                    None,
+                ))),
+            registry,
+        )
+        .unwrap();
+        assert_eq!(
+            playlist.auto_advance().unwrap().transition(),
+            config::SongTransition::Gapless
+        );
+        assert!(playlist.has_next());
+        playlist.next();
+        assert!(!playlist.has_next());
+    }
+
+    #[test]
+    fn invalid_auto_advance_rejected() {
+        let registry = test_registry();
+        let result = super::Playlist::new(
+            "set",
+            &config::Playlist::new(&["Song 1".to_string()]).with_auto_advance(Some(
+                config::AutoAdvance::new(
+                    config::SongTransition::Gap,
+                    Some("later".to_string()),
+                    None,
+                ),
+            )),
+            registry,
+        );
+        assert!(matches!(
+            result,
+            Err(super::PlaylistError::InvalidAutoAdvance(_))
+        ));
+    }
+
     #[test]
     fn next_returns_correct_song() {
         let playlist = two_song_playlist(test_registry());
@@ -378,6 +442,7 @@ mod test {
             songs: vec!["Song 1".to_string(), "Ghost Song".to_string()],
             position: Arc::new(parking_lot::RwLock::new(0)),
             registry,
+            auto_advance: None,
             span: tracing::span!(tracing::Level::INFO, "test"),
         };
         let display = format!("{}", playlist);
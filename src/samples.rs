@@ -16,6 +16,8 @@
 //!
 //! This module provides:
 //! - Sample loading and caching (in-memory for zero-latency playback)
+//! - Round-robin, random and sequential selection between sample variations
+//! - Tuned, key-tracked playback with start offsets and sustain loops
 //! - MIDI event to sample trigger matching
 //! - Source-agnostic trigger/release via TriggerEvent and TriggerAction
 //! - Voice management with polyphony limits
@@ -23,7 +25,9 @@
 
 mod engine;
 pub(crate) mod loader;
+mod sampler;
 mod trigger;
+mod variation;
 mod voice;
 
 pub use engine::SampleEngine;
@@ -17,20 +17,26 @@
 use std::collections::HashMap;
 use std::error::Error;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use midly::live::LiveEvent;
 use midly::MidiMessage;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use tracing::{debug, error, info, warn};
 
 use super::loader::{LoadedSample, SampleLoader};
+use super::sampler::PlaybackRegion;
 use super::trigger::TriggerEvent;
-use super::voice::{Voice, VoiceManager};
+use super::variation::VariationSelector;
+use super::voice::{Voice, VoiceManager, VoiceStop};
 use crate::audio;
+use crate::audio::crossfade::{CrossfadeCurve, GainEnvelope};
 use crate::audio::sample_source::ChannelMappedSource;
-use crate::config::samples::{ReleaseBehavior, SampleDefinition, SampleTrigger, SamplesConfig};
+use crate::config::samples::{
+    ChokeConfig, KitConfig, KitsConfig, ReleaseBehavior, SampleDefinition, SampleTrigger,
+    SamplesConfig,
+};
 use crate::config::ToMidiEvent;
 use crate::playsync::CancelHandle;
 
@@ -42,6 +48,8 @@ struct PrecomputedSampleData {
     channel_labels: Vec<Vec<String>>,
     /// Precomputed track mappings for the mixer.
     track_mappings: HashMap<String, Vec<u16>>,
+    /// Where playback starts and loops, in frames of the loaded data.
+    region: PlaybackRegion,
 }
 
 /// Active sample definition with preloaded audio data.
@@ -52,6 +60,24 @@ struct ActiveSample {
     loaded_files: HashMap<PathBuf, PrecomputedSampleData>,
     /// Base path for resolving relative file paths.
     base_path: PathBuf,
+    /// Chooses between variation files on each trigger.
+    variations: Mutex<VariationSelector>,
+}
+
+impl ActiveSample {
+    /// Chooses the file to play for a trigger of the given velocity, advancing the
+    /// variation selection. Returns the resolved file path and the volume scale factor.
+    fn select_file(&self, velocity: u8) -> Option<(PathBuf, f32)> {
+        let (group, files, volume) = self.definition.variations_for_velocity(velocity)?;
+        let index = self.variations.lock().pick(group, files.len());
+        let file = files.get(index)?;
+        let path = if Path::new(file).is_absolute() {
+            PathBuf::from(file)
+        } else {
+            self.base_path.join(file)
+        };
+        Some((path, volume))
+    }
 }
 
 /// Data prepared for sample playback, produced by `prepare_sample`.
@@ -62,6 +88,8 @@ struct PreparedSample {
     channel_count: u16,
     retrigger: crate::config::samples::RetriggerBehavior,
     release_behavior: ReleaseBehavior,
+    sustained: Arc<AtomicBool>,
+    choke: Option<ChokeConfig>,
 }
 
 /// A trigger definition with pre-converted MIDI event for matching.
@@ -72,6 +100,21 @@ struct ActiveTrigger {
     sample_name: String,
 }
 
+/// Index stored in `SampleEngine::active_kit` when no kit is active.
+const NO_KIT: usize = usize::MAX;
+
+/// A named kit whose samples and triggers replace the base ones while it is active.
+struct Kit {
+    /// The kit name.
+    name: String,
+    /// The MIDI program number that selects this kit.
+    program: Option<u8>,
+    /// Samples that replace or add to the base samples.
+    samples: HashMap<String, ActiveSample>,
+    /// Triggers that replace or add to the base triggers.
+    triggers: Vec<ActiveTrigger>,
+}
+
 /// The sample engine manages MIDI-triggered sample playback.
 pub struct SampleEngine {
     /// Sample loader for loading audio files.
@@ -90,6 +133,22 @@ pub struct SampleEngine {
     fixed_delay_samples: u64,
     /// Track mappings from the active profile, for resolving output_track names.
     profile_track_mappings: HashMap<String, Vec<u16>>,
+    /// Seed for random variation selection, mixed with each sample's name.
+    variation_seed: u64,
+    /// Loaded sample kits.
+    kits: Vec<Kit>,
+    /// Index into `kits` of the active kit, or `NO_KIT`.
+    active_kit: AtomicUsize,
+    /// MIDI channel (1-16) on which program changes select kits.
+    kit_program_channel: Option<u8>,
+    /// Kits selected by section from the global config, keyed by section name.
+    section_kits: HashMap<String, String>,
+    /// Kits selected by section from the current song, keyed by section name.
+    song_section_kits: HashMap<String, String>,
+    /// The section playback is currently in.
+    current_section: Mutex<Option<String>>,
+    /// Whether the active kit was selected by entering a section.
+    kit_from_section: AtomicBool,
 }
 
 impl SampleEngine {
@@ -119,9 +178,24 @@ impl SampleEngine {
             mixer,
             fixed_delay_samples,
             profile_track_mappings,
+            variation_seed: rand::random(),
+            kits: Vec::new(),
+            active_kit: AtomicUsize::new(NO_KIT),
+            kit_program_channel: None,
+            section_kits: HashMap::new(),
+            song_section_kits: HashMap::new(),
+            current_section: Mutex::new(None),
+            kit_from_section: AtomicBool::new(false),
         }
     }
 
+    /// Sets the seed for random variation selection. Only samples loaded after
+    /// this call use the new seed.
+    #[cfg(test)]
+    fn set_variation_seed(&mut self, seed: u64) {
+        self.variation_seed = seed;
+    }
+
     /// Loads global sample configuration.
     /// This should be called at startup with the global config.
     pub fn load_global_config(
@@ -145,9 +219,15 @@ impl SampleEngine {
             self.add_trigger(trigger)?;
         }
 
+        let kits = config.sample_kits();
+        self.load_kits(kits, base_path)?;
+        self.kit_program_channel = kits.program_channel();
+        self.section_kits = kits.sections().clone();
+
         info!(
             loaded_samples = self.samples.len(),
             loaded_triggers = self.triggers.len(),
+            loaded_kits = self.kits.len(),
             memory_kb = self.loader.total_memory_usage() / 1024,
             "Global samples loaded"
         );
@@ -162,13 +242,16 @@ impl SampleEngine {
         config: &SamplesConfig,
         base_path: &Path,
     ) -> Result<(), Box<dyn Error>> {
-        if config.samples().is_empty() && config.sample_triggers().is_empty() {
+        let kits = config.sample_kits();
+        self.song_section_kits = kits.sections().clone();
+        if config.samples().is_empty() && config.sample_triggers().is_empty() && kits.is_empty() {
             return Ok(());
         }
 
         info!(
             samples = config.samples().len(),
             triggers = config.sample_triggers().len(),
+            kits = kits.kits().len(),
             "Loading song samples configuration"
         );
 
@@ -182,9 +265,70 @@ impl SampleEngine {
             self.add_trigger(trigger)?;
         }
 
+        // Add/override kits
+        self.load_kits(kits, base_path)?;
+        if kits.program_channel().is_some() {
+            self.kit_program_channel = kits.program_channel();
+        }
+
+        Ok(())
+    }
+
+    /// Loads kit definitions, replacing any loaded kit with the same name. A
+    /// replaced kit that is active stays active with its new contents.
+    fn load_kits(&mut self, kits: &KitsConfig, base_path: &Path) -> Result<(), Box<dyn Error>> {
+        for (name, kit) in kits.kits() {
+            let kit = self.build_kit(name, kit, base_path)?;
+            match self.kits.iter_mut().find(|k| k.name == kit.name) {
+                Some(existing) => *existing = kit,
+                None => self.kits.push(kit),
+            }
+        }
+
+        for (section, kit) in kits.sections() {
+            if !kits.kits().contains_key(kit) && !self.kits.iter().any(|k| &k.name == kit) {
+                warn!(section, kit, "Section selects an unknown sample kit");
+            }
+        }
         Ok(())
     }
 
+    /// Loads a kit's samples and triggers.
+    fn build_kit(
+        &mut self,
+        name: &str,
+        kit: &KitConfig,
+        base_path: &Path,
+    ) -> Result<Kit, Box<dyn Error>> {
+        let mut samples = HashMap::new();
+        for (sample_name, definition) in kit.samples() {
+            samples.insert(
+                sample_name.clone(),
+                self.build_sample(sample_name, definition, base_path)?,
+            );
+        }
+
+        let mut triggers: Vec<ActiveTrigger> = Vec::new();
+        for trigger in kit.sample_triggers() {
+            let trigger = Self::active_trigger(trigger)?;
+            triggers.retain(|t| t.midi_event != trigger.midi_event);
+            triggers.push(trigger);
+        }
+
+        debug!(
+            kit = name,
+            samples = samples.len(),
+            triggers = triggers.len(),
+            "Sample kit loaded"
+        );
+        Ok(Kit {
+            name: name.to_string(),
+            program: kit.program(),
+            samples,
+            triggers,
+        })
+    }
+
     /// Loads a sample definition and preloads its audio data.
     fn load_sample(
         &mut self,
@@ -192,6 +336,20 @@ impl SampleEngine {
         definition: &SampleDefinition,
         base_path: &Path,
     ) -> Result<(), Box<dyn Error>> {
+        let sample = self.build_sample(name, definition, base_path)?;
+        self.samples.insert(name.to_string(), sample);
+
+        debug!(name, "Sample loaded");
+        Ok(())
+    }
+
+    /// Preloads a sample definition's audio data and precomputes its mappings.
+    fn build_sample(
+        &mut self,
+        name: &str,
+        definition: &SampleDefinition,
+        base_path: &Path,
+    ) -> Result<ActiveSample, Box<dyn Error>> {
         // Load all files referenced by this definition
         let raw_loaded_files = self.loader.load_definition(definition, base_path)?;
 
@@ -248,12 +406,14 @@ impl SampleEngine {
                         )
                     };
 
+                let region = Self::playback_region(name, definition, &loaded);
                 (
                     path,
                     PrecomputedSampleData {
                         loaded,
                         channel_labels,
                         track_mappings,
+                        region,
                     },
                 )
             })
@@ -265,43 +425,165 @@ impl SampleEngine {
             vm.set_sample_limit(name, max_voices);
         }
 
-        self.samples.insert(
-            name.to_string(),
-            ActiveSample {
-                definition: definition.clone(),
-                loaded_files,
-                base_path: base_path.to_path_buf(),
-            },
-        );
+        Ok(ActiveSample {
+            definition: definition.clone(),
+            loaded_files,
+            base_path: base_path.to_path_buf(),
+            variations: Mutex::new(VariationSelector::new(
+                definition.variation(),
+                definition.variation_groups(),
+                self.variation_seed,
+                name,
+            )),
+        })
+    }
 
-        debug!(name, "Sample loaded");
-        Ok(())
+    /// Resolves a definition's start offset and sustain loop against a loaded file.
+    /// A loop that falls outside the file or before the start offset is dropped.
+    fn playback_region(
+        name: &str,
+        definition: &SampleDefinition,
+        loaded: &LoadedSample,
+    ) -> PlaybackRegion {
+        let frames = loaded.frame_count();
+        let start = loaded.ms_to_frame(definition.start_offset_ms()).min(frames);
+        let sustain_loop = definition.sustain_loop().and_then(|sustain_loop| {
+            let loop_start = loaded.source_frame_to_frame(sustain_loop.start());
+            let loop_end = loaded.source_frame_to_frame(sustain_loop.end()).min(frames);
+            if loop_start >= loop_end || loop_end <= start {
+                warn!(
+                    sample = name,
+                    start = sustain_loop.start(),
+                    end = sustain_loop.end(),
+                    "Sustain loop is empty or outside the played region, ignoring it"
+                );
+                None
+            } else {
+                Some((loop_start, loop_end))
+            }
+        });
+        PlaybackRegion {
+            start,
+            sustain_loop,
+        }
     }
 
     /// Adds a trigger mapping.
     fn add_trigger(&mut self, trigger: &SampleTrigger) -> Result<(), Box<dyn Error>> {
-        let midi_event = trigger.trigger().to_midi_event()?;
+        let trigger = Self::active_trigger(trigger)?;
 
         // Remove any existing trigger with the same MIDI event
-        self.triggers.retain(|t| t.midi_event != midi_event);
+        self.triggers.retain(|t| t.midi_event != trigger.midi_event);
 
-        self.triggers.push(ActiveTrigger {
-            midi_event,
+        debug!(sample = trigger.sample_name.as_str(), "Trigger added");
+        self.triggers.push(trigger);
+        Ok(())
+    }
+
+    /// Converts a trigger mapping into its matchable form.
+    fn active_trigger(trigger: &SampleTrigger) -> Result<ActiveTrigger, Box<dyn Error>> {
+        Ok(ActiveTrigger {
+            midi_event: trigger.trigger().to_midi_event()?,
             sample_name: trigger.sample().to_string(),
+        })
+    }
+
+    /// Returns the active kit, if any.
+    fn current_kit(&self) -> Option<&Kit> {
+        self.kits.get(self.active_kit.load(Ordering::Relaxed))
+    }
+
+    /// Gets the name of the active kit, or None when playing the base samples.
+    pub fn active_kit(&self) -> Option<&str> {
+        self.current_kit().map(|kit| kit.name.as_str())
+    }
+
+    /// Looks up a sample by name, preferring the active kit's definition.
+    fn sample(&self, name: &str) -> Option<&ActiveSample> {
+        self.current_kit()
+            .and_then(|kit| kit.samples.get(name))
+            .or_else(|| self.samples.get(name))
+    }
+
+    /// Switches to the named kit, or back to the base samples for None. Voices
+    /// that are already sounding keep playing. Returns false if no kit has the name.
+    pub fn select_kit(&self, name: Option<&str>) -> bool {
+        self.kit_from_section.store(false, Ordering::Relaxed);
+        self.activate_kit(name)
+    }
+
+    /// Makes the named kit active without touching how it was selected.
+    fn activate_kit(&self, name: Option<&str>) -> bool {
+        let index = match name {
+            Some(name) => match self.kits.iter().position(|kit| kit.name == name) {
+                Some(index) => index,
+                None => {
+                    warn!(kit = name, "Sample kit not found");
+                    return false;
+                }
+            },
+            None => NO_KIT,
+        };
+        if self.active_kit.swap(index, Ordering::Relaxed) != index {
+            info!(kit = name.unwrap_or("(base)"), "Sample kit selected");
+        }
+        true
+    }
+
+    /// Notes the section playback is in, or None between sections. Entering a
+    /// section mapped to a kit selects it; leaving it returns to the base samples
+    /// unless a kit was chosen some other way since.
+    pub fn enter_section(&self, section: Option<&str>) {
+        {
+            let mut current = self.current_section.lock();
+            if current.as_deref() == section {
+                return;
+            }
+            *current = section.map(str::to_string);
+        }
+
+        let kit = section.and_then(|section| {
+            self.song_section_kits
+                .get(section)
+                .or_else(|| self.section_kits.get(section))
         });
+        match kit {
+            Some(kit) => {
+                if self.activate_kit(Some(kit)) {
+                    self.kit_from_section.store(true, Ordering::Relaxed);
+                }
+            }
+            None => {
+                if self.kit_from_section.swap(false, Ordering::Relaxed) {
+                    self.activate_kit(None);
+                }
+            }
+        }
+    }
 
-        debug!(sample = trigger.sample(), "Trigger added");
-        Ok(())
+    /// Resets kit selection for a newly loaded song: the song starts on the base
+    /// samples and forgets the previous song's section kits.
+    pub fn begin_song(&mut self) {
+        self.song_section_kits.clear();
+        *self.current_section.get_mut() = None;
+        self.kit_from_section.store(false, Ordering::Relaxed);
+        self.activate_kit(None);
     }
 
     /// Triggers a sample by name using a source-agnostic TriggerEvent.
     /// This is the common entry point for all trigger sources (MIDI, audio triggers, etc.).
     pub fn trigger(&self, event: &TriggerEvent) {
-        let prepared = match self.prepare_sample(&event.sample_name, event.velocity) {
+        let prepared = match self.prepare_sample(&event.sample_name, event.velocity, event.note) {
             Some(p) => p,
             None => return,
         };
 
+        // Without a release group nothing can let go of a sustain loop, so play
+        // straight through it.
+        if event.release_group.is_none() {
+            prepared.sustained.store(false, Ordering::Relaxed);
+        }
+
         let source_cancel_handle = CancelHandle::new();
         let source_cancel_at_sample = Arc::new(AtomicU64::new(0));
         let is_finished = Arc::new(AtomicBool::new(false));
@@ -314,7 +596,9 @@ impl SampleEngine {
             source_cancel_handle.clone(),
             source_cancel_at_sample.clone(),
             is_finished.clone(),
-        );
+            prepared.sustained.clone(),
+        )
+        .with_choke(prepared.choke);
 
         let start_at_sample = self.mixer.current_sample() + self.fixed_delay_samples;
 
@@ -323,8 +607,8 @@ impl SampleEngine {
             vm.add_voice(voice, prepared.retrigger)
         };
 
-        for cancel_at in to_stop {
-            cancel_at.store(start_at_sample, Ordering::Relaxed);
+        for stop in to_stop {
+            self.schedule_stop(&stop, start_at_sample);
         }
 
         let active_source = crate::audio::mixer::ActiveSource {
@@ -339,6 +623,7 @@ impl SampleEngine {
             cancel_at_sample: Some(source_cancel_at_sample),
             gain: Default::default(),
             gain_envelope: None,
+            transport: None,
         };
 
         // Prepare on this thread so the audio callback's drain only inserts.
@@ -359,6 +644,25 @@ impl SampleEngine {
         );
     }
 
+    /// Schedules a voice to stop at the given sample. Voices choked with a fade
+    /// ramp down from that sample and stop once the fade completes.
+    fn schedule_stop(&self, stop: &VoiceStop, at_sample: u64) {
+        if stop.fade_ms == 0 {
+            stop.cancel_at_sample.store(at_sample, Ordering::Relaxed);
+            return;
+        }
+        let fade_samples = stop.fade_ms as u64 * self.mixer.sample_rate() as u64 / 1000;
+        self.mixer.set_gain_envelope(
+            &[stop.source_id],
+            Arc::new(
+                GainEnvelope::fade_out(fade_samples, CrossfadeCurve::Linear)
+                    .with_start_sample(at_sample),
+            ),
+        );
+        stop.cancel_at_sample
+            .store(at_sample + fade_samples, Ordering::Relaxed);
+    }
+
     /// Releases all voices in the named release group.
     /// Each voice's own ReleaseBehavior (stored at trigger time) determines
     /// whether it is stopped or allowed to play to completion.
@@ -378,10 +682,16 @@ impl SampleEngine {
     }
 
     /// Prepares a sample for playback: looks up the definition, resolves the file
-    /// for the given velocity, and creates the audio source. Returns None if the
-    /// sample cannot be prepared (not found, no file for velocity, etc.).
-    fn prepare_sample(&self, sample_name: &str, velocity: u8) -> Option<PreparedSample> {
-        let sample = match self.samples.get(sample_name) {
+    /// for the given velocity, and creates the audio source pitched for the given
+    /// note. Returns None if the sample cannot be prepared (not found, no file for
+    /// velocity, etc.).
+    fn prepare_sample(
+        &self,
+        sample_name: &str,
+        velocity: u8,
+        note: Option<u8>,
+    ) -> Option<PreparedSample> {
+        let sample = match self.sample(sample_name) {
             Some(s) => s,
             None => {
                 warn!(sample = sample_name, "Sample not found");
@@ -389,15 +699,8 @@ impl SampleEngine {
             }
         };
 
-        let (file_path, volume) = match sample.definition.file_for_velocity(velocity) {
-            Some((file, vol)) => {
-                let path = if Path::new(file).is_absolute() {
-                    PathBuf::from(file)
-                } else {
-                    sample.base_path.join(file)
-                };
-                (path, vol)
-            }
+        let (file_path, volume) = match sample.select_file(velocity) {
+            Some(selected) => selected,
             None => {
                 warn!(
                     sample = sample_name,
@@ -419,11 +722,23 @@ impl SampleEngine {
             }
         };
 
-        let source = precomputed.loaded.create_source(volume);
+        let rate = sample.definition.pitch().rate_for_note(note);
+        let sustained = Arc::new(AtomicBool::new(true));
+        let source: Box<dyn audio::sample_source::traits::SampleSource> =
+            if rate == 1.0 && precomputed.region.is_full() {
+                Box::new(precomputed.loaded.create_source(volume))
+            } else {
+                Box::new(precomputed.loaded.create_sampler_source(
+                    volume,
+                    rate,
+                    precomputed.region,
+                    sustained.clone(),
+                ))
+            };
         let source_id = audio::next_source_id();
 
         let channel_mapped = ChannelMappedSource::new(
-            Box::new(source),
+            source,
             precomputed.channel_labels.clone(),
             precomputed.loaded.channel_count(),
         );
@@ -436,6 +751,8 @@ impl SampleEngine {
             channel_count: precomputed.loaded.channel_count(),
             retrigger: sample.definition.retrigger(),
             release_behavior: sample.definition.release_behavior(),
+            sustained,
+            choke: sample.definition.choke().cloned(),
         })
     }
 
@@ -466,6 +783,17 @@ impl SampleEngine {
             }
         }
 
+        // Program changes on the kit channel switch kits.
+        if let LiveEvent::Midi {
+            channel,
+            message: MidiMessage::ProgramChange { program },
+        } = &event
+        {
+            if self.kit_program_channel == Some(u8::from(*channel) + 1) {
+                self.select_kit_by_program(u8::from(*program));
+            }
+        }
+
         // NoteOn with velocity 0 was already handled as a release above — skip trigger matching
         let is_note_off_as_note_on = matches!(
             &event,
@@ -473,16 +801,31 @@ impl SampleEngine {
             if u8::from(*vel) == 0
         );
 
+        // The active kit's triggers shadow base triggers for the same event.
+        let kit_triggers = self
+            .current_kit()
+            .map(|kit| kit.triggers.as_slice())
+            .unwrap_or_default();
+        let base_triggers = self.triggers.iter().filter(|trigger| {
+            !kit_triggers
+                .iter()
+                .any(|kit_trigger| kit_trigger.midi_event == trigger.midi_event)
+        });
+
         // Check against triggers (hot path - minimal overhead)
-        for trigger in &self.triggers {
-            if !is_note_off_as_note_on && self.matches_trigger(&event, &trigger.midi_event) {
+        for trigger in kit_triggers.iter().chain(base_triggers) {
+            if !is_note_off_as_note_on
+                && (self.matches_trigger(&event, &trigger.midi_event)
+                    || self.matches_key_range(&event, trigger))
+            {
                 let velocity = self.extract_velocity(&event);
-                let release_group = self
-                    .extract_note_channel(&event)
-                    .map(|(note, channel)| Self::midi_release_group(channel, note));
+                let note_channel = self.extract_note_channel(&event);
+                let release_group =
+                    note_channel.map(|(note, channel)| Self::midi_release_group(channel, note));
                 let trigger_event = TriggerEvent {
                     sample_name: trigger.sample_name.clone(),
                     velocity,
+                    note: note_channel.map(|(note, _)| note),
                     release_group,
                 };
                 self.trigger(&trigger_event);
@@ -490,6 +833,16 @@ impl SampleEngine {
         }
     }
 
+    /// Selects the kit mapped to a MIDI program number. Unmapped programs are ignored.
+    fn select_kit_by_program(&self, program: u8) {
+        match self.kits.iter().find(|kit| kit.program == Some(program)) {
+            Some(kit) => {
+                self.select_kit(Some(&kit.name));
+            }
+            None => debug!(program, "No sample kit for program change"),
+        }
+    }
+
     /// Checks if a MIDI event matches a trigger.
     fn matches_trigger(&self, event: &LiveEvent, trigger_event: &LiveEvent) -> bool {
         // For now, we do exact matching on the event type and channel/key
@@ -548,6 +901,26 @@ impl SampleEngine {
         }
     }
 
+    /// Checks if a Note On event falls in the key range of a key-tracked sample
+    /// whose trigger is a Note On on the same channel.
+    fn matches_key_range(&self, event: &LiveEvent, trigger: &ActiveTrigger) -> bool {
+        match (event, &trigger.midi_event) {
+            (
+                LiveEvent::Midi {
+                    channel: c1,
+                    message: MidiMessage::NoteOn { key, .. },
+                },
+                LiveEvent::Midi {
+                    channel: c2,
+                    message: MidiMessage::NoteOn { .. },
+                },
+            ) if c1 == c2 => self
+                .sample(&trigger.sample_name)
+                .is_some_and(|sample| sample.definition.pitch().plays_note(u8::from(*key))),
+            _ => false,
+        }
+    }
+
     /// Extracts velocity from a MIDI event.
     fn extract_velocity(&self, event: &LiveEvent) -> u8 {
         match event {
@@ -613,6 +986,8 @@ impl std::fmt::Debug for SampleEngine {
         f.debug_struct("SampleEngine")
             .field("samples", &self.samples.len())
             .field("triggers", &self.triggers.len())
+            .field("kits", &self.kits.len())
+            .field("active_kit", &self.active_kit())
             .field("active_voices", &self.active_voice_count())
             .field("memory_kb", &(self.memory_usage() / 1024))
             .finish()
@@ -623,6 +998,8 @@ impl std::fmt::Debug for SampleEngine {
 mod tests {
     use super::*;
     use crate::audio::mixer::AudioMixer;
+    use crate::audio::sample_source::ChannelMappedSampleSource;
+    use crate::config::samples::{PitchConfig, SustainLoop, VariationMode};
 
     fn create_test_mixer_and_sender() -> (Arc<AudioMixer>, crate::audio::SourceSender) {
         let mixer = Arc::new(AudioMixer::new(2, 44100));
@@ -672,6 +1049,7 @@ mod tests {
         let event = TriggerEvent {
             sample_name: "kick".to_string(),
             velocity: 100,
+            note: None,
             release_group: Some("test:group".to_string()),
         };
 
@@ -687,6 +1065,7 @@ mod tests {
         let event = TriggerEvent {
             sample_name: "nonexistent".to_string(),
             velocity: 100,
+            note: None,
             release_group: None,
         };
 
@@ -702,6 +1081,7 @@ mod tests {
         let event = TriggerEvent {
             sample_name: "kick".to_string(),
             velocity: 100,
+            note: None,
             release_group: Some("midi:10:36".to_string()),
         };
 
@@ -738,6 +1118,7 @@ mod tests {
         let event = TriggerEvent {
             sample_name: "pad".to_string(),
             velocity: 100,
+            note: None,
             release_group: Some("group:pad".to_string()),
         };
 
@@ -755,6 +1136,7 @@ mod tests {
         let event = TriggerEvent {
             sample_name: "kick".to_string(),
             velocity: 100,
+            note: None,
             release_group: Some("midi:10:36".to_string()),
         };
         engine.trigger(&event);
@@ -770,6 +1152,7 @@ mod tests {
         let event = TriggerEvent {
             sample_name: "kick".to_string(),
             velocity: 100,
+            note: None,
             release_group: None,
         };
 
@@ -837,6 +1220,361 @@ mod tests {
         assert_eq!(engine.active_voice_count(), 0);
     }
 
+    /// Creates an engine with a base kick on channel 10 note 36 and two kits:
+    /// "big" (program 3) swaps in a stereo kick and adds a trigger on note 38,
+    /// and "brushes" (program 5) maps note 36 to its own "brush" sample. Program
+    /// changes on channel 16 select kits and the "chorus" section selects "big".
+    fn create_kit_engine() -> SampleEngine {
+        let (mixer, source_tx) = create_test_mixer_and_sender();
+        let mut engine = SampleEngine::new(mixer, source_tx, 32, 256, HashMap::new());
+        let base_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("assets");
+
+        let stereo = make_samples_config("kick", "2Channel44.1k.wav", 10, 38);
+        let brushes = make_samples_config("brush", "1Channel44.1k.wav", 10, 36);
+        let kits = KitsConfig::new(
+            Some(16),
+            HashMap::from([("chorus".to_string(), "big".to_string())]),
+            HashMap::from([
+                (
+                    "big".to_string(),
+                    KitConfig::new(
+                        Some(3),
+                        stereo.samples().clone(),
+                        stereo.sample_triggers().to_vec(),
+                    ),
+                ),
+                (
+                    "brushes".to_string(),
+                    KitConfig::new(
+                        Some(5),
+                        brushes.samples().clone(),
+                        brushes.sample_triggers().to_vec(),
+                    ),
+                ),
+            ]),
+        );
+        let config = make_samples_config("kick", "1Channel44.1k.wav", 10, 36).with_kits(kits);
+        engine.load_global_config(&config, &base_path).unwrap();
+        engine
+    }
+
+    #[test]
+    fn test_kit_samples_override_base() {
+        let engine = create_kit_engine();
+        let channels = |engine: &SampleEngine| {
+            engine
+                .prepare_sample("kick", 100, None)
+                .map(|prepared| prepared.channel_count)
+        };
+
+        assert_eq!(channels(&engine), Some(1));
+        assert!(engine.select_kit(Some("big")));
+        assert_eq!(engine.active_kit(), Some("big"));
+        assert_eq!(channels(&engine), Some(2));
+
+        // Samples the kit doesn't define fall back to the base, and kit-only
+        // samples are unavailable without the kit.
+        assert!(engine.select_kit(Some("brushes")));
+        assert_eq!(channels(&engine), Some(1));
+        assert!(engine.prepare_sample("brush", 100, None).is_some());
+        assert!(engine.select_kit(None));
+        assert!(engine.prepare_sample("brush", 100, None).is_none());
+
+        assert!(!engine.select_kit(Some("missing")));
+        assert_eq!(engine.active_kit(), None);
+    }
+
+    #[test]
+    fn test_kit_triggers_add_and_shadow_base() {
+        let engine = create_kit_engine();
+
+        // The kit-only trigger does nothing without the kit.
+        engine.process_midi_event(&[0x99, 38, 100]);
+        assert_eq!(engine.active_voice_count(), 0);
+
+        engine.select_kit(Some("big"));
+        engine.process_midi_event(&[0x99, 38, 100]);
+        assert_eq!(engine.active_voice_count(), 1);
+
+        // The brushes kit's trigger on note 36 replaces the base kick trigger
+        // rather than playing alongside it.
+        engine.stop_all();
+        engine.select_kit(Some("brushes"));
+        engine.process_midi_event(&[0x99, 36, 100]);
+        assert_eq!(engine.active_voice_count(), 1);
+    }
+
+    #[test]
+    fn test_kit_switch_keeps_sounding_voices() {
+        let engine = create_kit_engine();
+
+        engine.process_midi_event(&[0x99, 36, 100]);
+        engine.select_kit(Some("brushes"));
+        engine.process_midi_event(&[0x99, 36, 100]);
+        // The base kick keeps sounding under the kit's brush.
+        assert_eq!(engine.active_voice_count(), 2);
+
+        engine.select_kit(None);
+        assert_eq!(engine.active_voice_count(), 2);
+    }
+
+    #[test]
+    fn test_program_change_selects_kit() {
+        let engine = create_kit_engine();
+
+        engine.process_midi_event(&[0xCF, 3]);
+        assert_eq!(engine.active_kit(), Some("big"));
+
+        // Program changes on other channels are ignored.
+        engine.process_midi_event(&[0xC0, 5]);
+        assert_eq!(engine.active_kit(), Some("big"));
+
+        engine.process_midi_event(&[0xCF, 5]);
+        assert_eq!(engine.active_kit(), Some("brushes"));
+
+        // Unmapped programs leave the kit alone.
+        engine.process_midi_event(&[0xCF, 99]);
+        assert_eq!(engine.active_kit(), Some("brushes"));
+    }
+
+    #[test]
+    fn test_section_selects_kit() {
+        let engine = create_kit_engine();
+
+        engine.enter_section(Some("verse"));
+        assert_eq!(engine.active_kit(), None);
+        engine.enter_section(Some("chorus"));
+        assert_eq!(engine.active_kit(), Some("big"));
+
+        // Leaving the section returns to the base samples.
+        engine.enter_section(Some("bridge"));
+        assert_eq!(engine.active_kit(), None);
+
+        // A kit chosen by program change survives leaving the section.
+        engine.enter_section(Some("chorus"));
+        engine.process_midi_event(&[0xCF, 5]);
+        engine.enter_section(None);
+        assert_eq!(engine.active_kit(), Some("brushes"));
+    }
+
+    #[test]
+    fn test_song_section_kits_reset_with_song() {
+        let mut engine = create_kit_engine();
+        let base_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("assets");
+
+        engine.begin_song();
+        let song = SamplesConfig::new(HashMap::new(), vec![], 0).with_kits(KitsConfig::new(
+            None,
+            HashMap::from([("chorus".to_string(), "brushes".to_string())]),
+            HashMap::new(),
+        ));
+        engine.load_song_config(&song, &base_path).unwrap();
+
+        // The song's section mapping overrides the global one.
+        engine.enter_section(Some("chorus"));
+        assert_eq!(engine.active_kit(), Some("brushes"));
+
+        // The next song starts on the base samples with the global mapping.
+        engine.begin_song();
+        assert_eq!(engine.active_kit(), None);
+        engine.enter_section(Some("chorus"));
+        assert_eq!(engine.active_kit(), Some("big"));
+    }
+
+    #[test]
+    fn test_trigger_choke_group() {
+        let (mixer, source_tx) = create_test_mixer_and_sender();
+        let mut engine = SampleEngine::new(mixer, source_tx, 32, 256, HashMap::new());
+        let base_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("assets");
+        let hat = |choke| {
+            SampleDefinition::new(
+                Some("1Channel44.1k.wav".to_string()),
+                vec![1, 2],
+                crate::config::samples::VelocityConfig::ignore(None),
+                ReleaseBehavior::PlayToCompletion,
+                crate::config::samples::RetriggerBehavior::Polyphonic,
+                None,
+                50,
+            )
+            .with_choke(choke)
+        };
+        engine
+            .load_sample(
+                "open-hat",
+                &hat(ChokeConfig::new("hihat", Some(vec![]), 30)),
+                &base_path,
+            )
+            .unwrap();
+        engine
+            .load_sample(
+                "closed-hat",
+                &hat(ChokeConfig::new("hihat", None, 10)),
+                &base_path,
+            )
+            .unwrap();
+
+        let hit = |sample: &str| TriggerEvent {
+            sample_name: sample.to_string(),
+            velocity: 100,
+            note: None,
+            release_group: None,
+        };
+
+        engine.trigger(&hit("closed-hat"));
+        engine.trigger(&hit("open-hat"));
+        assert_eq!(engine.active_voice_count(), 2);
+
+        // The closed hat chokes the open hat but keeps its own earlier voice.
+        engine.trigger(&hit("closed-hat"));
+        assert_eq!(engine.active_voice_count(), 2);
+        engine.trigger(&hit("open-hat"));
+        engine.trigger(&hit("closed-hat"));
+        assert_eq!(engine.active_voice_count(), 3);
+    }
+
+    fn tracked_pad(sustain_loop: Option<SustainLoop>) -> SampleDefinition {
+        SampleDefinition::new(
+            Some("1Channel44.1k.wav".to_string()),
+            vec![1, 2],
+            crate::config::samples::VelocityConfig::ignore(None),
+            ReleaseBehavior::PlayToCompletion,
+            crate::config::samples::RetriggerBehavior::Polyphonic,
+            None,
+            50,
+        )
+        .with_playback(
+            PitchConfig::new(0.0, Some(60), Some([48, 72])),
+            0,
+            sustain_loop,
+        )
+    }
+
+    #[test]
+    fn test_process_midi_event_key_range() {
+        let (mixer, source_tx) = create_test_mixer_and_sender();
+        let mut engine = SampleEngine::new(mixer, source_tx, 32, 256, HashMap::new());
+        let base_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("assets");
+
+        let samples = HashMap::from([("pad".to_string(), tracked_pad(None))]);
+        let triggers = vec![SampleTrigger::new(
+            crate::config::midi::note_on(1, 60, 127),
+            "pad".to_string(),
+        )];
+        let config = SamplesConfig::new(samples, triggers, 32);
+        engine.load_global_config(&config, &base_path).unwrap();
+
+        // Notes inside the key range on the trigger's channel play the sample.
+        engine.process_midi_event(&[0x90, 64, 100]);
+        engine.process_midi_event(&[0x90, 48, 100]);
+        assert_eq!(engine.active_voice_count(), 2);
+
+        // Notes outside the range or on another channel do not.
+        engine.process_midi_event(&[0x90, 73, 100]);
+        engine.process_midi_event(&[0x91, 64, 100]);
+        assert_eq!(engine.active_voice_count(), 2);
+
+        // Each note releases only its own voice.
+        engine.process_midi_event(&[0x80, 64, 0]);
+        engine.process_midi_event(&[0x80, 48, 0]);
+        assert_eq!(engine.active_voice_count(), 2); // play_to_completion keeps them
+    }
+
+    #[test]
+    fn test_prepare_sample_key_tracking_changes_length() {
+        let (mixer, source_tx) = create_test_mixer_and_sender();
+        let mut engine = SampleEngine::new(mixer, source_tx, 32, 256, HashMap::new());
+        let base_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("assets");
+        engine
+            .load_sample("pad", &tracked_pad(None), &base_path)
+            .unwrap();
+
+        let frames = |note| {
+            let mut prepared = engine.prepare_sample("pad", 100, note).unwrap();
+            let mut buffer = vec![0.0; 2];
+            let mut count = 0;
+            while let Ok(Some(_)) = prepared.channel_mapped.next_frame(&mut buffer) {
+                count += 1;
+            }
+            count
+        };
+
+        let root = frames(Some(60));
+        let octave_up = frames(Some(72));
+        let octave_down = frames(Some(48));
+        assert_eq!(root, frames(None));
+        assert!((octave_up as i64 - (root / 2) as i64).abs() <= 1);
+        assert!((octave_down as i64 - (root * 2) as i64).abs() <= 2);
+    }
+
+    #[test]
+    fn test_playback_region_resolution() {
+        let mut loader = SampleLoader::new(44100);
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("assets")
+            .join("1Channel44.1k.wav");
+        let loaded = loader.load(&path).unwrap();
+        let frames = loaded.frame_count();
+
+        let definition = tracked_pad(None).with_playback(
+            PitchConfig::default(),
+            10,
+            Some(SustainLoop::new(100, frames as u64 + 1000)),
+        );
+        let region = SampleEngine::playback_region("pad", &definition, &loaded);
+        assert_eq!(region.start, 441);
+        // The loop end is clamped to the end of the file.
+        assert_eq!(region.sustain_loop, Some((100, frames)));
+
+        // A loop that ends before the start offset is dropped.
+        let definition = tracked_pad(None).with_playback(
+            PitchConfig::default(),
+            10,
+            Some(SustainLoop::new(100, 200)),
+        );
+        let region = SampleEngine::playback_region("pad", &definition, &loaded);
+        assert_eq!(region.sustain_loop, None);
+
+        // So is an empty loop.
+        let definition = tracked_pad(None).with_playback(
+            PitchConfig::default(),
+            0,
+            Some(SustainLoop::new(300, 300)),
+        );
+        let region = SampleEngine::playback_region("pad", &definition, &loaded);
+        assert!(region.is_full());
+    }
+
+    #[test]
+    fn test_prepare_sample_sustain_loop_holds_until_released() {
+        let (mixer, source_tx) = create_test_mixer_and_sender();
+        let mut engine = SampleEngine::new(mixer, source_tx, 32, 256, HashMap::new());
+        let base_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("assets");
+        engine
+            .load_sample(
+                "pad",
+                &tracked_pad(Some(SustainLoop::new(0, 100))),
+                &base_path,
+            )
+            .unwrap();
+
+        // With a held loop the source never finishes; released, it reaches the end.
+        let mut prepared = engine.prepare_sample("pad", 100, Some(60)).unwrap();
+        let mut buffer = vec![0.0; 2];
+        for _ in 0..1000 {
+            assert!(prepared
+                .channel_mapped
+                .next_frame(&mut buffer)
+                .unwrap()
+                .is_some());
+        }
+        prepared.sustained.store(false, Ordering::Relaxed);
+        let mut remaining = 0;
+        while let Ok(Some(_)) = prepared.channel_mapped.next_frame(&mut buffer) {
+            remaining += 1;
+        }
+        assert!(remaining > 0);
+    }
+
     #[test]
     fn test_process_midi_event_vel0_as_note_off() {
         let (mixer, source_tx) = create_test_mixer_and_sender();
@@ -924,6 +1662,7 @@ mod tests {
             let event = TriggerEvent {
                 sample_name: "limited".to_string(),
                 velocity: 100,
+                note: None,
                 release_group: None,
             };
             engine.trigger(&event);
@@ -960,7 +1699,7 @@ mod tests {
             .unwrap();
 
         // The file should have been loaded using the absolute path, not base_path.join
-        let prepared = engine.prepare_sample("abs_sample", 100);
+        let prepared = engine.prepare_sample("abs_sample", 100, None);
         assert!(prepared.is_some());
     }
 
@@ -970,7 +1709,7 @@ mod tests {
         let engine = SampleEngine::new(mixer, source_tx, 32, 256, HashMap::new());
 
         // Try to prepare a sample that hasn't been loaded
-        let prepared = engine.prepare_sample("nonexistent", 100);
+        let prepared = engine.prepare_sample("nonexistent", 100, None);
         assert!(prepared.is_none());
     }
 
@@ -1000,18 +1739,93 @@ mod tests {
             .unwrap();
 
         // Velocity 5 is below the layer range [10, 50] — no file found
-        let prepared = engine.prepare_sample("layered", 5);
+        let prepared = engine.prepare_sample("layered", 5, None);
         assert!(prepared.is_none());
 
         // Velocity 100 is above the layer range — no file found
-        let prepared = engine.prepare_sample("layered", 100);
+        let prepared = engine.prepare_sample("layered", 100, None);
         assert!(prepared.is_none());
 
         // Velocity 30 is within the range — should find a file
-        let prepared = engine.prepare_sample("layered", 30);
+        let prepared = engine.prepare_sample("layered", 30, None);
         assert!(prepared.is_some());
     }
 
+    fn variation_definition(variation: VariationMode) -> SampleDefinition {
+        SampleDefinition::new(
+            None,
+            vec![1],
+            crate::config::samples::VelocityConfig::ignore(None),
+            ReleaseBehavior::PlayToCompletion,
+            crate::config::samples::RetriggerBehavior::Polyphonic,
+            None,
+            50,
+        )
+        .with_variations(
+            vec![
+                "1Channel44.1k.wav".to_string(),
+                "2Channel44.1k.wav".to_string(),
+                "8Channel44.1k.wav".to_string(),
+            ],
+            variation,
+        )
+    }
+
+    #[test]
+    fn test_prepare_sample_round_robin_variations() {
+        let (mixer, source_tx) = create_test_mixer_and_sender();
+        let mut engine = SampleEngine::new(mixer, source_tx, 32, 256, HashMap::new());
+        let base_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("assets");
+        engine
+            .load_sample(
+                "tom",
+                &variation_definition(VariationMode::RoundRobin),
+                &base_path,
+            )
+            .unwrap();
+
+        // Each variation has a different channel count, so the prepared sources
+        // show which file was picked.
+        let channels: Vec<u16> = (0..4)
+            .map(|_| {
+                engine
+                    .prepare_sample("tom", 100, None)
+                    .unwrap()
+                    .channel_count
+            })
+            .collect();
+        assert_eq!(channels, vec![1, 2, 8, 1]);
+    }
+
+    #[test]
+    fn test_prepare_sample_random_variations_are_seeded() {
+        let base_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("assets");
+        let picks = |seed| {
+            let (mixer, source_tx) = create_test_mixer_and_sender();
+            let mut engine = SampleEngine::new(mixer, source_tx, 32, 256, HashMap::new());
+            engine.set_variation_seed(seed);
+            engine
+                .load_sample(
+                    "snare",
+                    &variation_definition(VariationMode::Random),
+                    &base_path,
+                )
+                .unwrap();
+            (0..16)
+                .map(|_| {
+                    engine
+                        .prepare_sample("snare", 100, None)
+                        .unwrap()
+                        .channel_count
+                })
+                .collect::<Vec<u16>>()
+        };
+
+        let first = picks(11);
+        assert_eq!(first, picks(11));
+        assert!(first.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+
     #[test]
     fn test_trigger_matching_non_midi_event_returns_false() {
         let (mixer, source_tx) = create_test_mixer_and_sender();
@@ -19,11 +19,13 @@
 use std::collections::HashMap;
 use std::error::Error;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 use std::time::Duration;
 
 use tracing::{debug, info, warn};
 
+use super::sampler::{PlaybackRegion, SamplerSource};
 use crate::audio::sample_source::{create_sample_source_from_file, MemorySampleSource};
 use crate::config::samples::SampleDefinition;
 
@@ -40,6 +42,8 @@ pub struct LoadedSample {
     channel_count: u16,
     /// Sample rate of the audio data.
     sample_rate: u32,
+    /// Sample rate of the file the data was loaded from, before transcoding.
+    source_sample_rate: u32,
 }
 
 impl LoadedSample {
@@ -53,11 +57,50 @@ impl LoadedSample {
         )
     }
 
+    /// Creates a SamplerSource that plays the region at the given rate, holding its
+    /// sustain loop until `sustained` is cleared.
+    pub(super) fn create_sampler_source(
+        &self,
+        volume: f32,
+        rate: f64,
+        region: PlaybackRegion,
+        sustained: Arc<AtomicBool>,
+    ) -> SamplerSource {
+        SamplerSource::new(
+            self.data.clone(),
+            self.channel_count,
+            self.sample_rate,
+            volume,
+            rate,
+            region,
+            sustained,
+        )
+    }
+
     /// Returns the number of channels.
     pub fn channel_count(&self) -> u16 {
         self.channel_count
     }
 
+    /// Returns the number of frames in the loaded data.
+    pub fn frame_count(&self) -> usize {
+        self.data.len() / (self.channel_count.max(1) as usize)
+    }
+
+    /// Converts a frame position in the original file to a frame in the loaded,
+    /// possibly transcoded, data.
+    pub fn source_frame_to_frame(&self, frame: u64) -> usize {
+        if self.source_sample_rate == self.sample_rate || self.source_sample_rate == 0 {
+            return frame as usize;
+        }
+        (frame as f64 * self.sample_rate as f64 / self.source_sample_rate as f64).round() as usize
+    }
+
+    /// Converts milliseconds to a frame in the loaded data.
+    pub fn ms_to_frame(&self, ms: u32) -> usize {
+        (ms as u64 * self.sample_rate as u64 / 1000) as usize
+    }
+
     /// Returns the memory size in bytes.
     pub fn memory_size(&self) -> usize {
         self.data.len() * std::mem::size_of::<f32>()
@@ -135,6 +178,7 @@ impl SampleLoader {
             data: Arc::new(final_samples),
             channel_count,
             sample_rate: final_sample_rate,
+            source_sample_rate,
         };
 
         info!(
@@ -335,6 +379,7 @@ mod tests {
             data: Arc::new(data),
             channel_count: 2,
             sample_rate: 44100,
+            source_sample_rate: 44100,
         };
 
         assert_eq!(sample.memory_size(), 1000 * std::mem::size_of::<f32>());
@@ -348,6 +393,7 @@ mod tests {
             data: Arc::new(data),
             channel_count: 1,
             sample_rate: 44100,
+            source_sample_rate: 44100,
         };
 
         // Should be able to create multiple sources from the same sample
@@ -488,6 +534,40 @@ mod tests {
         assert_eq!(loaded.len(), 2);
     }
 
+    #[test]
+    fn test_load_definition_with_layer_variations() {
+        let mut loader = SampleLoader::new(44100);
+        let base_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("assets");
+        let layers = vec![
+            VelocityLayer::with_files(
+                [1, 80],
+                vec![
+                    "1Channel44.1k.wav".to_string(),
+                    "1Channel22.05k.wav".to_string(),
+                ],
+            ),
+            VelocityLayer::with_files(
+                [81, 127],
+                vec![
+                    "2Channel44.1k.wav".to_string(),
+                    "8Channel44.1k.wav".to_string(),
+                ],
+            ),
+        ];
+        let definition = SampleDefinition::new(
+            None,
+            vec![1],
+            VelocityConfig::with_layers(layers, false),
+            crate::config::samples::ReleaseBehavior::PlayToCompletion,
+            crate::config::samples::RetriggerBehavior::Cut,
+            None,
+            50,
+        );
+
+        let loaded = loader.load_definition(&definition, &base_path).unwrap();
+        assert_eq!(loaded.len(), 4);
+    }
+
     #[test]
     fn test_transcode_preserves_stereo_channels() {
         let loader = SampleLoader::new(48000);
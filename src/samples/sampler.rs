@@ -0,0 +1,305 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+
+//! Pitched, offset and looping playback of preloaded samples.
+//!
+//! Samples that play from the start at their recorded pitch use a plain
+//! `MemorySampleSource`. Anything else plays through a `SamplerSource`, which
+//! reads the shared sample data at a fractional rate with linear interpolation
+//! and repeats a sustain loop while its voice is held.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::audio::sample_source::error::SampleSourceError;
+use crate::audio::sample_source::traits::SampleSource;
+
+/// Where playback starts and loops within a loaded sample, in output frames.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(super) struct PlaybackRegion {
+    /// The frame playback starts at.
+    pub(super) start: usize,
+    /// The loop held while the voice is sustained, as [start, end) frames.
+    pub(super) sustain_loop: Option<(usize, usize)>,
+}
+
+impl PlaybackRegion {
+    /// Returns true if the region plays the whole sample once from the start.
+    pub(super) fn is_full(&self) -> bool {
+        self.start == 0 && self.sustain_loop.is_none()
+    }
+}
+
+/// A sample source that plays shared sample data at a given rate, from a start
+/// offset, with an optional sustain loop.
+pub(super) struct SamplerSource {
+    /// The interleaved sample data, shared with the loader's cache.
+    data: Arc<Vec<f32>>,
+    /// Number of channels.
+    channel_count: u16,
+    /// Sample rate.
+    sample_rate: u32,
+    /// Volume scale factor (0.0 to 1.0).
+    volume: f32,
+    /// Frames advanced per output frame: 1.0 is the recorded pitch.
+    step: f64,
+    /// The current read position, in frames.
+    position: f64,
+    /// The sustain loop as [start, end) frames.
+    sustain_loop: Option<(usize, usize)>,
+    /// Cleared when the voice is released, letting playback run past the loop.
+    sustained: Arc<AtomicBool>,
+    /// The frame currently being emitted, one sample per channel.
+    frame: Vec<f32>,
+    /// The next channel of `frame` to emit.
+    channel: usize,
+}
+
+impl SamplerSource {
+    /// Creates a new sampler source.
+    pub(super) fn new(
+        data: Arc<Vec<f32>>,
+        channel_count: u16,
+        sample_rate: u32,
+        volume: f32,
+        step: f64,
+        region: PlaybackRegion,
+        sustained: Arc<AtomicBool>,
+    ) -> Self {
+        Self {
+            data,
+            channel_count,
+            sample_rate,
+            volume,
+            step,
+            position: region.start as f64,
+            sustain_loop: region.sustain_loop,
+            sustained,
+            frame: vec![0.0; channel_count as usize],
+            channel: 0,
+        }
+    }
+
+    /// Returns the number of frames in the sample data.
+    fn frames(&self) -> usize {
+        self.data.len() / (self.channel_count.max(1) as usize)
+    }
+
+    /// Renders the frame at the current position and advances it. Returns false
+    /// once the position has run past the end of the data.
+    fn render_frame(&mut self) -> bool {
+        let held_loop = self
+            .sustain_loop
+            .filter(|_| self.sustained.load(Ordering::Relaxed));
+        if let Some((start, end)) = held_loop {
+            let length = (end - start) as f64;
+            while self.position >= end as f64 {
+                self.position -= length;
+            }
+        }
+
+        let frames = self.frames();
+        let index = self.position as usize;
+        if index >= frames {
+            return false;
+        }
+
+        // Interpolate towards the next frame, wrapping to the loop start at the
+        // loop end so held loops stay seamless.
+        let fraction = (self.position - index as f64) as f32;
+        let next = match held_loop {
+            Some((start, end)) if index + 1 == end => start,
+            _ if index + 1 < frames => index + 1,
+            _ => index,
+        };
+        let channels = self.channel_count as usize;
+        for channel in 0..channels {
+            let current = self.data[index * channels + channel];
+            let following = self.data[next * channels + channel];
+            self.frame[channel] = (current + (following - current) * fraction) * self.volume;
+        }
+
+        self.position += self.step;
+        true
+    }
+}
+
+impl SampleSource for SamplerSource {
+    fn next_sample(&mut self) -> Result<Option<f32>, SampleSourceError> {
+        if self.channel == 0 && !self.render_frame() {
+            return Ok(None);
+        }
+        let sample = self.frame[self.channel];
+        self.channel = (self.channel + 1) % self.frame.len();
+        Ok(Some(sample))
+    }
+
+    fn channel_count(&self) -> u16 {
+        self.channel_count
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn bits_per_sample(&self) -> u16 {
+        32
+    }
+
+    fn sample_format(&self) -> crate::audio::SampleFormat {
+        crate::audio::SampleFormat::Float
+    }
+
+    fn duration(&self) -> Option<std::time::Duration> {
+        // A held loop plays for as long as the note is down.
+        if self.sustain_loop.is_some() {
+            return None;
+        }
+        let remaining = (self.frames() as f64 - self.position).max(0.0) / self.step;
+        Some(std::time::Duration::from_secs_f64(
+            remaining / self.sample_rate as f64,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(
+        data: Vec<f32>,
+        channel_count: u16,
+        step: f64,
+        region: PlaybackRegion,
+    ) -> (SamplerSource, Arc<AtomicBool>) {
+        let sustained = Arc::new(AtomicBool::new(true));
+        let source = SamplerSource::new(
+            Arc::new(data),
+            channel_count,
+            44100,
+            1.0,
+            step,
+            region,
+            sustained.clone(),
+        );
+        (source, sustained)
+    }
+
+    fn drain(source: &mut SamplerSource, limit: usize) -> Vec<f32> {
+        let mut out = Vec::new();
+        while out.len() < limit {
+            match source.next_sample().unwrap() {
+                Some(sample) => out.push(sample),
+                None => break,
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_start_offset_skips_frames() {
+        let region = PlaybackRegion {
+            start: 2,
+            sustain_loop: None,
+        };
+        let (mut src, _) = source(vec![0.0, 1.0, 2.0, 3.0, 4.0], 1, 1.0, region);
+        assert_eq!(drain(&mut src, 10), vec![2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_double_rate_skips_every_other_frame() {
+        let (mut src, _) = source(
+            vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0],
+            1,
+            2.0,
+            PlaybackRegion::default(),
+        );
+        assert_eq!(drain(&mut src, 10), vec![0.0, 2.0, 4.0]);
+    }
+
+    #[test]
+    fn test_half_rate_interpolates() {
+        let (mut src, _) = source(vec![0.0, 1.0, 2.0], 1, 0.5, PlaybackRegion::default());
+        assert_eq!(drain(&mut src, 10), vec![0.0, 0.5, 1.0, 1.5, 2.0, 2.0]);
+    }
+
+    #[test]
+    fn test_interleaved_channels_stay_together() {
+        let (mut src, _) = source(
+            vec![0.0, -0.0, 1.0, -1.0, 2.0, -2.0, 3.0, -3.0],
+            2,
+            2.0,
+            PlaybackRegion::default(),
+        );
+        assert_eq!(drain(&mut src, 10), vec![0.0, -0.0, 2.0, -2.0]);
+    }
+
+    #[test]
+    fn test_sustain_loop_repeats_while_held() {
+        let region = PlaybackRegion {
+            start: 0,
+            sustain_loop: Some((1, 3)),
+        };
+        let (mut src, _) = source(vec![0.0, 1.0, 2.0, 3.0, 4.0], 1, 1.0, region);
+        assert_eq!(drain(&mut src, 7), vec![0.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0]);
+        assert!(src.duration().is_none());
+    }
+
+    #[test]
+    fn test_release_plays_past_loop() {
+        let region = PlaybackRegion {
+            start: 0,
+            sustain_loop: Some((1, 3)),
+        };
+        let (mut src, sustained) = source(vec![0.0, 1.0, 2.0, 3.0, 4.0], 1, 1.0, region);
+        assert_eq!(drain(&mut src, 4), vec![0.0, 1.0, 2.0, 1.0]);
+
+        sustained.store(false, Ordering::Relaxed);
+        assert_eq!(drain(&mut src, 10), vec![2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_held_loop_interpolates_across_loop_end() {
+        let region = PlaybackRegion {
+            start: 2,
+            sustain_loop: Some((1, 3)),
+        };
+        let (mut src, _) = source(vec![0.0, 1.0, 3.0, 9.0], 1, 0.5, region);
+        // Halfway between the loop's last frame (3.0) and its first (1.0).
+        assert_eq!(drain(&mut src, 3), vec![3.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_duration_accounts_for_rate() {
+        let data = vec![0.0; 44100];
+        let (src, _) = source(data, 1, 2.0, PlaybackRegion::default());
+        let duration = src.duration().unwrap();
+        assert!((duration.as_secs_f64() - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_region_is_full() {
+        assert!(PlaybackRegion::default().is_full());
+        assert!(!PlaybackRegion {
+            start: 1,
+            sustain_loop: None
+        }
+        .is_full());
+        assert!(!PlaybackRegion {
+            start: 0,
+            sustain_loop: Some((0, 10))
+        }
+        .is_full());
+    }
+}
@@ -24,6 +24,8 @@ pub struct TriggerEvent {
     pub sample_name: String,
     /// Velocity value (0-127) controlling volume/layer selection.
     pub velocity: u8,
+    /// The MIDI note that fired the trigger, used for key tracking.
+    pub note: Option<u8>,
     /// Optional release group — voices created by this trigger can be
     /// released later by matching on this group name.
     pub release_group: Option<String>,
@@ -0,0 +1,164 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+
+//! Variation selection for samples with several files per velocity layer.
+//!
+//! Each sample keeps its own selector so that repeated hits cycle or shuffle
+//! through its files independently of every other sample.
+
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use rand::rngs::StdRng;
+use rand::{RngExt, SeedableRng};
+
+use crate::config::samples::VariationMode;
+
+/// Chooses which variation file a hit plays.
+pub(super) struct VariationSelector {
+    /// How files are chosen.
+    mode: VariationMode,
+    /// Next round-robin position for each variation group.
+    next: Vec<usize>,
+    /// Hits so far across all groups, for sequential selection.
+    hits: usize,
+    /// The file last picked in each group, so random selection never repeats it.
+    last: Vec<Option<usize>>,
+    /// Random source for random selection.
+    rng: StdRng,
+}
+
+impl VariationSelector {
+    /// Creates a selector for a sample with the given number of variation groups.
+    /// The random source is seeded from `seed` and the sample name, so a fixed
+    /// seed gives the same sequence of picks for the same sample every time.
+    pub(super) fn new(mode: VariationMode, groups: usize, seed: u64, sample_name: &str) -> Self {
+        let mut hasher = DefaultHasher::new();
+        seed.hash(&mut hasher);
+        sample_name.hash(&mut hasher);
+        let groups = groups.max(1);
+        Self {
+            mode,
+            next: vec![0; groups],
+            hits: 0,
+            last: vec![None; groups],
+            rng: StdRng::seed_from_u64(hasher.finish()),
+        }
+    }
+
+    /// Picks the index of the file to play from a group holding `count` files.
+    pub(super) fn pick(&mut self, group: usize, count: usize) -> usize {
+        if count <= 1 || group >= self.next.len() {
+            return 0;
+        }
+
+        match self.mode {
+            VariationMode::RoundRobin => {
+                let index = self.next[group] % count;
+                self.next[group] = (index + 1) % count;
+                index
+            }
+            VariationMode::Sequential => {
+                let index = self.hits % count;
+                self.hits = self.hits.wrapping_add(1);
+                index
+            }
+            VariationMode::Random => {
+                let index = match self.last[group] {
+                    // Draw from the other files by skipping over the last pick.
+                    Some(last) if last < count => {
+                        let index = self.rng.random_range(0..count - 1);
+                        if index >= last {
+                            index + 1
+                        } else {
+                            index
+                        }
+                    }
+                    _ => self.rng.random_range(0..count),
+                };
+                self.last[group] = Some(index);
+                index
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_robin_cycles_each_group() {
+        let mut selector = VariationSelector::new(VariationMode::RoundRobin, 2, 1, "snare");
+
+        let picks: Vec<usize> = (0..5).map(|_| selector.pick(0, 3)).collect();
+        assert_eq!(picks, vec![0, 1, 2, 0, 1]);
+
+        // The second group keeps its own position.
+        assert_eq!(selector.pick(1, 2), 0);
+        assert_eq!(selector.pick(1, 2), 1);
+        assert_eq!(selector.pick(0, 3), 2);
+    }
+
+    #[test]
+    fn test_sequential_shares_position_across_groups() {
+        let mut selector = VariationSelector::new(VariationMode::Sequential, 2, 1, "snare");
+
+        assert_eq!(selector.pick(0, 3), 0);
+        assert_eq!(selector.pick(1, 3), 1);
+        assert_eq!(selector.pick(0, 3), 2);
+        assert_eq!(selector.pick(1, 3), 0);
+    }
+
+    #[test]
+    fn test_random_never_repeats() {
+        let mut selector = VariationSelector::new(VariationMode::Random, 1, 7, "tom");
+
+        let picks: Vec<usize> = (0..200).map(|_| selector.pick(0, 4)).collect();
+        assert!(picks.iter().all(|&index| index < 4));
+        assert!(picks.windows(2).all(|pair| pair[0] != pair[1]));
+        for file in 0..4 {
+            assert!(picks.contains(&file), "file {} never picked", file);
+        }
+    }
+
+    #[test]
+    fn test_random_is_deterministic_for_a_seed() {
+        let picks = |seed, name| {
+            let mut selector = VariationSelector::new(VariationMode::Random, 1, seed, name);
+            (0..32).map(|_| selector.pick(0, 5)).collect::<Vec<usize>>()
+        };
+
+        assert_eq!(picks(42, "tom"), picks(42, "tom"));
+        assert_ne!(picks(42, "tom"), picks(43, "tom"));
+        assert_ne!(picks(42, "tom"), picks(42, "snare"));
+    }
+
+    #[test]
+    fn test_single_file_always_picks_it() {
+        for mode in [
+            VariationMode::RoundRobin,
+            VariationMode::Random,
+            VariationMode::Sequential,
+        ] {
+            let mut selector = VariationSelector::new(mode, 1, 3, "kick");
+            assert!((0..4).all(|_| selector.pick(0, 1) == 0));
+        }
+    }
+
+    #[test]
+    fn test_unknown_group_picks_first_file() {
+        let mut selector = VariationSelector::new(VariationMode::RoundRobin, 1, 3, "kick");
+        assert_eq!(selector.pick(5, 3), 0);
+    }
+}
@@ -14,7 +14,7 @@
 
 //! Voice management for polyphonic sample playback.
 //!
-//! Handles voice allocation, stealing, and release group behavior.
+//! Handles voice allocation, stealing, choke groups and release group behavior.
 //! Voice management is source-agnostic — it works with any trigger source
 //! (MIDI, audio triggers, etc.) via generic release groups.
 
@@ -25,12 +25,22 @@ use std::time::Instant;
 
 use tracing::{debug, warn};
 
-use crate::config::samples::{ReleaseBehavior, RetriggerBehavior};
+use crate::config::samples::{ChokeConfig, ReleaseBehavior, RetriggerBehavior};
 use crate::playsync::CancelHandle;
 
 /// Global voice ID counter.
 static NEXT_VOICE_ID: AtomicU64 = AtomicU64::new(1);
 
+/// A voice the caller should stop, as returned by [`VoiceManager::add_voice`].
+pub(super) struct VoiceStop {
+    /// The audio source ID in the mixer.
+    pub(super) source_id: u64,
+    /// Scheduled sample at which the voice stops.
+    pub(super) cancel_at_sample: Arc<AtomicU64>,
+    /// Milliseconds to fade the voice out over before it stops; 0 cuts it.
+    pub(super) fade_ms: u32,
+}
+
 /// Represents an active voice playing a sample.
 pub(super) struct Voice {
     /// Unique ID for this voice.
@@ -44,8 +54,7 @@ pub(super) struct Voice {
     release_behavior: ReleaseBehavior,
     /// When this voice started playing.
     start_time: Instant,
-    /// The audio source ID in the mixer (used for testing/debugging).
-    #[allow(dead_code)]
+    /// The audio source ID in the mixer.
     mixer_source_id: u64,
     /// Cancel handle for stopping this voice without lock contention.
     cancel_handle: CancelHandle,
@@ -53,10 +62,15 @@ pub(super) struct Voice {
     cancel_at_sample: Arc<AtomicU64>,
     /// Shared flag set by the mixer when the audio source finishes playing.
     is_finished: Arc<AtomicBool>,
+    /// Shared flag held while the voice's note is down, keeping its sustain loop going.
+    sustained: Arc<AtomicBool>,
+    /// The sample's choke settings.
+    choke: Option<ChokeConfig>,
 }
 
 impl Voice {
     /// Creates a new voice.
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn new(
         sample_name: String,
         release_group: Option<String>,
@@ -65,6 +79,7 @@ impl Voice {
         cancel_handle: CancelHandle,
         cancel_at_sample: Arc<AtomicU64>,
         is_finished: Arc<AtomicBool>,
+        sustained: Arc<AtomicBool>,
     ) -> Self {
         Self {
             id: NEXT_VOICE_ID.fetch_add(1, Ordering::Relaxed),
@@ -76,9 +91,17 @@ impl Voice {
             cancel_handle,
             cancel_at_sample,
             is_finished,
+            sustained,
+            choke: None,
         }
     }
 
+    /// Sets the voice's choke settings.
+    pub(super) fn with_choke(mut self, choke: Option<ChokeConfig>) -> Self {
+        self.choke = choke;
+        self
+    }
+
     /// Checks if this voice belongs to the given release group.
     fn matches_release(&self, group: &str) -> bool {
         self.release_group.as_deref() == Some(group)
@@ -89,9 +112,27 @@ impl Voice {
         self.cancel_handle.clone()
     }
 
-    /// Returns a clone of this voice's cancel_at_sample.
-    fn cancel_at_sample(&self) -> Arc<AtomicU64> {
-        self.cancel_at_sample.clone()
+    /// Returns a stop for this voice that cuts it immediately.
+    fn cut(&self) -> VoiceStop {
+        VoiceStop {
+            source_id: self.mixer_source_id,
+            cancel_at_sample: self.cancel_at_sample.clone(),
+            fade_ms: 0,
+        }
+    }
+
+    /// Returns a stop for this voice if a hit on a sample with the given choke
+    /// settings chokes it, fading over this voice's own choke fade time.
+    fn choked_by(&self, sample_name: &str, choke: &ChokeConfig) -> Option<VoiceStop> {
+        // A sample's own voices are governed by its retrigger behavior.
+        if self.sample_name == sample_name {
+            return None;
+        }
+        let own = self.choke.as_ref()?;
+        choke.chokes_group(own.group()).then(|| VoiceStop {
+            fade_ms: own.fade_ms(),
+            ..self.cut()
+        })
     }
 }
 
@@ -127,26 +168,46 @@ impl VoiceManager {
             .retain(|v| !v.is_finished.load(Ordering::Relaxed));
     }
 
-    /// Adds a new voice, potentially stealing old voices if limits are exceeded.
-    /// Returns the cancel_at_sample Arcs for any voices that should be stopped.
-    /// The caller can set these to schedule the stop at a specific sample time.
+    /// Adds a new voice, choking voices in the groups it chokes and stealing old
+    /// voices if limits are exceeded. Returns the voices that should be stopped;
+    /// the caller schedules each stop at a specific sample time.
     pub(super) fn add_voice(
         &mut self,
         voice: Voice,
         retrigger: RetriggerBehavior,
-    ) -> Vec<Arc<AtomicU64>> {
+    ) -> Vec<VoiceStop> {
         // Sweep finished voices before checking limits.
         self.sweep_finished();
 
         let mut voices_to_stop = Vec::new();
 
+        // Choke first, so choked voices free their slots before any stealing.
+        if let Some(choke) = &voice.choke {
+            self.voices
+                .retain(|v| match v.choked_by(&voice.sample_name, choke) {
+                    Some(stop) => {
+                        voices_to_stop.push(stop);
+                        false
+                    }
+                    None => true,
+                });
+            if !voices_to_stop.is_empty() {
+                debug!(
+                    sample = voice.sample_name,
+                    group = choke.group(),
+                    choked = voices_to_stop.len(),
+                    "Choked voices"
+                );
+            }
+        }
+
         // Handle retrigger behavior
         match retrigger {
             RetriggerBehavior::Cut => {
                 // Stop all existing voices for this sample
                 for v in self.voices.iter() {
                     if v.sample_name == voice.sample_name {
-                        voices_to_stop.push(v.cancel_at_sample());
+                        voices_to_stop.push(v.cut());
                     }
                 }
                 self.voices.retain(|v| v.sample_name != voice.sample_name);
@@ -167,7 +228,7 @@ impl VoiceManager {
                             .filter(|v| v.sample_name == voice.sample_name)
                             .min_by_key(|v| v.start_time)
                         {
-                            voices_to_stop.push(oldest.cancel_at_sample());
+                            voices_to_stop.push(oldest.cut());
                             let oldest_id = oldest.id;
                             self.voices.retain(|v| v.id != oldest_id);
                             debug!(
@@ -184,7 +245,7 @@ impl VoiceManager {
         if self.voices.len() >= self.max_voices as usize {
             // Steal oldest voice globally
             if let Some(oldest) = self.voices.iter().min_by_key(|v| v.start_time) {
-                voices_to_stop.push(oldest.cancel_at_sample());
+                voices_to_stop.push(oldest.cut());
                 let oldest_id = oldest.id;
                 self.voices.retain(|v| v.id != oldest_id);
                 warn!(
@@ -206,6 +267,8 @@ impl VoiceManager {
 
         for v in self.voices.iter() {
             if v.matches_release(group) {
+                // Let any sustain loop run out into the rest of the sample
+                v.sustained.store(false, Ordering::Relaxed);
                 match v.release_behavior {
                     ReleaseBehavior::PlayToCompletion => {
                         // Let this voice play to completion
@@ -271,9 +334,154 @@ mod tests {
             CancelHandle::new(),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicBool::new(false)),
+            Arc::new(AtomicBool::new(true)),
         )
     }
 
+    fn make_choke_voice(sample: &str, id: u64, choke: ChokeConfig) -> Voice {
+        make_voice(sample, None, id).with_choke(Some(choke))
+    }
+
+    fn closed_hat() -> ChokeConfig {
+        ChokeConfig::new("hihat", None, 5)
+    }
+
+    fn open_hat() -> ChokeConfig {
+        ChokeConfig::new("hihat", Some(vec![]), 40)
+    }
+
+    #[test]
+    fn test_choke_group_cuts_other_samples_with_their_fade() {
+        let mut manager = VoiceManager::new(32);
+        manager.add_voice(
+            make_choke_voice("pedal-hat", 1, ChokeConfig::new("hihat", None, 15)),
+            RetriggerBehavior::Polyphonic,
+        );
+
+        let stopped = manager.add_voice(
+            make_choke_voice("closed-hat", 2, closed_hat()),
+            RetriggerBehavior::Polyphonic,
+        );
+        assert_eq!(stopped.len(), 1);
+        assert_eq!(stopped[0].source_id, 1);
+        // The choked voice fades over its own time, not the choking sample's.
+        assert_eq!(stopped[0].fade_ms, 15);
+        assert_eq!(manager.active_count(), 1);
+
+        // And the other way around.
+        let stopped = manager.add_voice(
+            make_choke_voice("pedal-hat", 3, ChokeConfig::new("hihat", None, 15)),
+            RetriggerBehavior::Polyphonic,
+        );
+        assert_eq!(stopped.len(), 1);
+        assert_eq!(stopped[0].source_id, 2);
+        assert_eq!(stopped[0].fade_ms, 5);
+    }
+
+    #[test]
+    fn test_one_way_choke() {
+        let mut manager = VoiceManager::new(32);
+        manager.add_voice(
+            make_choke_voice("closed-hat", 1, closed_hat()),
+            RetriggerBehavior::Polyphonic,
+        );
+
+        // The open hat chokes nothing, so the closed hat keeps ringing.
+        let stopped = manager.add_voice(
+            make_choke_voice("open-hat", 2, open_hat()),
+            RetriggerBehavior::Polyphonic,
+        );
+        assert!(stopped.is_empty());
+        assert_eq!(manager.active_count(), 2);
+
+        // The closed hat chokes the open hat.
+        let stopped = manager.add_voice(
+            make_choke_voice("closed-hat", 3, closed_hat()),
+            RetriggerBehavior::Polyphonic,
+        );
+        assert_eq!(stopped.len(), 1);
+        assert_eq!(stopped[0].source_id, 2);
+        assert_eq!(stopped[0].fade_ms, 40);
+        assert_eq!(manager.active_count(), 2);
+    }
+
+    #[test]
+    fn test_choke_leaves_other_groups_and_unchoked_voices() {
+        let mut manager = VoiceManager::new(32);
+        manager.add_voice(make_voice("kick", None, 1), RetriggerBehavior::Polyphonic);
+        manager.add_voice(
+            make_choke_voice("crash", 2, ChokeConfig::new("cymbals", None, 20)),
+            RetriggerBehavior::Polyphonic,
+        );
+
+        let stopped = manager.add_voice(
+            make_choke_voice("closed-hat", 3, closed_hat()),
+            RetriggerBehavior::Polyphonic,
+        );
+        assert!(stopped.is_empty());
+        assert_eq!(manager.active_count(), 3);
+    }
+
+    #[test]
+    fn test_choke_frees_slots_before_stealing() {
+        let mut manager = VoiceManager::new(2);
+        manager.add_voice(make_voice("kick", None, 1), RetriggerBehavior::Polyphonic);
+        manager.add_voice(
+            make_choke_voice("open-hat", 2, open_hat()),
+            RetriggerBehavior::Polyphonic,
+        );
+
+        // At the global limit, but choking the open hat makes room: the kick is
+        // not stolen and the open hat fades rather than being cut.
+        let stopped = manager.add_voice(
+            make_choke_voice("closed-hat", 3, closed_hat()),
+            RetriggerBehavior::Polyphonic,
+        );
+        assert_eq!(stopped.len(), 1);
+        assert_eq!(stopped[0].source_id, 2);
+        assert_eq!(stopped[0].fade_ms, 40);
+        assert_eq!(manager.active_count(), 2);
+    }
+
+    #[test]
+    fn test_choke_with_cut_retrigger() {
+        let mut manager = VoiceManager::new(32);
+        manager.add_voice(
+            make_choke_voice("closed-hat", 1, closed_hat()),
+            RetriggerBehavior::Cut,
+        );
+        manager.add_voice(
+            make_choke_voice("open-hat", 2, open_hat()),
+            RetriggerBehavior::Cut,
+        );
+
+        // The retrigger cuts the closed hat's own voice outright, while the
+        // choke fades the open hat.
+        let mut stopped = manager.add_voice(
+            make_choke_voice("closed-hat", 3, closed_hat()),
+            RetriggerBehavior::Cut,
+        );
+        stopped.sort_by_key(|stop| stop.source_id);
+        assert_eq!(stopped.len(), 2);
+        assert_eq!((stopped[0].source_id, stopped[0].fade_ms), (1, 0));
+        assert_eq!((stopped[1].source_id, stopped[1].fade_ms), (2, 40));
+        assert_eq!(manager.active_count(), 1);
+    }
+
+    #[test]
+    fn test_stolen_choke_voice_is_cut() {
+        let mut manager = VoiceManager::new(1);
+        manager.add_voice(
+            make_choke_voice("open-hat", 1, open_hat()),
+            RetriggerBehavior::Polyphonic,
+        );
+
+        // A voice stolen for the global limit is cut, even if it has a choke fade.
+        let stopped = manager.add_voice(make_voice("kick", None, 2), RetriggerBehavior::Polyphonic);
+        assert_eq!(stopped.len(), 1);
+        assert_eq!((stopped[0].source_id, stopped[0].fade_ms), (1, 0));
+    }
+
     #[test]
     fn test_voice_release_matching() {
         let voice = make_voice("test", Some("midi:10:60"), 1);
@@ -429,6 +637,7 @@ mod tests {
             CancelHandle::new(),
             Arc::new(AtomicU64::new(0)),
             is_finished.clone(),
+            Arc::new(AtomicBool::new(true)),
         );
         manager.add_voice(voice, RetriggerBehavior::Polyphonic);
         assert_eq!(manager.active_count(), 1);
@@ -549,6 +758,7 @@ mod tests {
             CancelHandle::new(),
             Arc::new(AtomicU64::new(0)),
             finished1.clone(),
+            Arc::new(AtomicBool::new(true)),
         );
         let voice2 = Voice::new(
             "b".to_string(),
@@ -558,6 +768,7 @@ mod tests {
             CancelHandle::new(),
             Arc::new(AtomicU64::new(0)),
             finished2.clone(),
+            Arc::new(AtomicBool::new(true)),
         );
         let voice3 = make_voice("c", None, 3);
 
@@ -577,6 +788,30 @@ mod tests {
         assert_eq!(manager.active_count(), 2);
     }
 
+    #[test]
+    fn test_release_ends_sustain() {
+        let mut manager = VoiceManager::new(32);
+
+        let sustained = Arc::new(AtomicBool::new(true));
+        let voice = Voice::new(
+            "pad".to_string(),
+            Some("midi:1:60".to_string()),
+            ReleaseBehavior::PlayToCompletion,
+            1,
+            CancelHandle::new(),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicBool::new(false)),
+            sustained.clone(),
+        );
+        manager.add_voice(voice, RetriggerBehavior::Polyphonic);
+
+        // The voice keeps playing, but its sustain loop is let go.
+        let stopped = manager.release("midi:1:60");
+        assert!(stopped.is_empty());
+        assert!(!sustained.load(Ordering::Relaxed));
+        assert_eq!(manager.active_count(), 1);
+    }
+
     #[test]
     fn test_release_mixed_behaviors_in_same_group() {
         let mut manager = VoiceManager::new(32);
@@ -161,6 +161,8 @@ pub struct Song {
     pilot: Option<config::PilotConfig>,
     /// Pilot hints resolved to absolute song times, sorted by anchor.
     pilot_hints: Vec<ResolvedPilotHint>,
+    /// The linear timecode configuration, when present.
+    ltc: Option<config::LtcConfig>,
     /// The song's beat grid: generated from the explicit tempo map when one
     /// is configured, otherwise derived from click track analysis.
     beat_grid: Option<crate::audio::click_analysis::BeatGrid>,
@@ -170,6 +172,8 @@ pub struct Song {
     sections: Vec<config::Section>,
     /// Per-song notification audio overrides.
     notification_audio: Option<config::SongNotificationConfig>,
+    /// The MIDI Timecode the song starts at.
+    mtc_start: config::Timecode,
 }
 
 /// A simple sample for songs. Boils down to i32 or f32, which we can be reasonably assured that
@@ -282,10 +286,12 @@ impl Song {
             metronome: config.metronome().cloned(),
             pilot: config.pilot().cloned(),
             pilot_hints,
+            ltc: config.ltc().cloned(),
             beat_grid,
             loop_playback: config.loop_playback(),
             sections: config.sections().to_vec(),
             notification_audio: config.notification_audio().cloned(),
+            mtc_start: config.mtc_start().unwrap_or_default(),
         })
     }
 
@@ -678,8 +684,13 @@ impl Song {
         &self.pilot_hints
     }
 
+    /// Gets the linear timecode configuration, if configured.
+    pub fn ltc(&self) -> Option<&config::LtcConfig> {
+        self.ltc.as_ref()
+    }
+
     /// Names of all output tracks this song produces: real audio tracks plus
-    /// virtual tracks (metronome, pilot). These are the names that can appear
+    /// virtual tracks (metronome, pilot, ltc). These are the names that can appear
     /// in `track_mappings` and carry per-track gains.
     /// Resolves the metronome tri-state against the player-wide default:
     /// `enabled: false` blocks turn the metronome off, absent blocks follow
@@ -704,6 +715,9 @@ impl Song {
         if let Some(pilot) = &self.pilot {
             names.push(pilot.track.clone());
         }
+        if let Some(ltc) = &self.ltc {
+            names.push(ltc.track.clone());
+        }
         names
     }
 
@@ -717,6 +731,11 @@ impl Song {
         &self.sections
     }
 
+    /// Gets the MIDI Timecode the song starts at.
+    pub fn mtc_start(&self) -> config::Timecode {
+        self.mtc_start
+    }
+
     /// Gets the per-song notification audio overrides.
     pub fn notification_audio(&self) -> Option<&config::SongNotificationConfig> {
         self.notification_audio.as_ref()
@@ -869,6 +888,10 @@ impl Song {
             // Get the channel count from the source we just created
             let wav_channels = sample_source.channel_count();
 
+            // Stretch at the file's own rate, before transcoding, so all of
+            // it runs on the fill pool.
+            let sample_source = crate::audio::stretch::at_rate(sample_source, context.rate);
+
             // Create channel mappings for each channel in the WAV file
             let mut channel_mappings = Vec::new();
             for channel in 0..wav_channels {
@@ -923,7 +946,10 @@ impl Song {
                         self.duration,
                     )?;
                     let source = create_channel_mapped_sample_source(
-                        Box::new(metronome_source),
+                        crate::audio::stretch::at_rate(
+                            Box::new(metronome_source),
+                            context.rate.untransposed(),
+                        ),
                         context.target_format.clone(),
                         vec![vec![metronome.track.clone()]],
                         context.resampler_type,
@@ -962,7 +988,10 @@ impl Song {
                         self.duration,
                     )?;
                     let source = create_channel_mapped_sample_source(
-                        Box::new(pilot_source),
+                        crate::audio::stretch::at_rate(
+                            Box::new(pilot_source),
+                            context.rate.untransposed(),
+                        ),
                         context.target_format.clone(),
                         vec![vec![pilot.track.clone()]],
                         context.resampler_type,
@@ -971,6 +1000,29 @@ impl Song {
                 }
             }
         }
+
+        // Append the virtual timecode track when configured and mapped in
+        // the profile. Rendered from the absolute song position, so it lands
+        // on the same sample however playback got there.
+        if let Some(ltc) = &self.ltc {
+            if track_mappings.contains_key(&ltc.track) {
+                let ltc_source = crate::audio::ltc::LtcSource::new(
+                    ltc.frame_rate,
+                    ltc.start.unwrap_or(self.mtc_start),
+                    context.target_format.sample_rate,
+                    start_time,
+                    self.duration,
+                )
+                .with_speed(context.rate.speed);
+                let source = create_channel_mapped_sample_source(
+                    Box::new(ltc_source),
+                    context.target_format.clone(),
+                    vec![vec![ltc.track.clone()]],
+                    context.resampler_type,
+                )?;
+                sources.push(source);
+            }
+        }
         Ok(sources)
     }
 
@@ -1048,10 +1100,12 @@ impl Default for Song {
             metronome: None,
             pilot: None,
             pilot_hints: Vec::new(),
+            ltc: None,
             beat_grid: None,
             loop_playback: false,
             sections: Vec::new(),
             notification_audio: None,
+            mtc_start: config::Timecode::default(),
         }
     }
 }
@@ -1092,6 +1146,21 @@ impl Song {
         song.sections = sections;
         song
     }
+
+    /// Test helper: a song of the given length whose timecode starts at
+    /// `mtc_start`, for exercising chase song selection.
+    #[cfg(test)]
+    pub fn new_for_test_with_timecode(
+        name: &str,
+        mtc_start: config::Timecode,
+        duration: Duration,
+    ) -> Song {
+        Song {
+            mtc_start,
+            duration,
+            ..Self::new_for_test(name, &[])
+        }
+    }
 }
 
 /// Midi playback configuration for the song.
@@ -3424,6 +3493,59 @@ pilot:
         Ok(())
     }
 
+    #[test]
+    fn ltc_track_renders_from_the_song_position() -> Result<(), Box<dyn Error>> {
+        let tempdir = tempfile::tempdir()?;
+        let song_dir = tempdir.path().join("ltc_song");
+        fs::create_dir(&song_dir)?;
+        crate::testutil::write_wav(
+            song_dir.join("track.wav"),
+            vec![vec![0_i32; 44100 * 2]],
+            44100,
+        )?;
+        fs::write(
+            song_dir.join("song.yaml"),
+            "name: LTC Song\ntracks:\n  - name: track\n    file: track.wav\nltc:\n  frame_rate: 25\n",
+        )?;
+        let song_config = crate::config::Song::deserialize(song_dir.join("song.yaml").as_path())?;
+        let song = super::Song::new(&song_dir, &song_config)?;
+        assert_eq!(
+            song.output_track_names(),
+            vec!["track".to_string(), "ltc".to_string()]
+        );
+
+        let target = crate::audio::TargetFormat::new(44100, crate::audio::SampleFormat::Int, 16)?;
+        let context = crate::audio::PlaybackContext::new(target, 1024, None, Default::default());
+        let mut mappings = std::collections::HashMap::new();
+        mappings.insert("ltc".to_string(), vec![1_u16]);
+        let render_from = |start: std::time::Duration| -> Result<Vec<f32>, Box<dyn Error>> {
+            let mut sources =
+                song.create_channel_mapped_sources_from(&context, start, &mappings)?;
+            let ltc_source = sources
+                .iter_mut()
+                .find(|s| s.channel_mappings() == [vec!["ltc".to_string()]])
+                .expect("ltc source present");
+            let mut rendered = Vec::new();
+            let mut buf = vec![0.0f32; 1024];
+            loop {
+                let frames = ltc_source.read_frames(&mut buf, 1024)?;
+                if frames == 0 {
+                    return Ok(rendered);
+                }
+                rendered.extend_from_slice(&buf[..frames]);
+            }
+        };
+
+        // A source started mid-song, as seeks and section loops make, lines
+        // up sample for sample with one started from the top.
+        let whole = render_from(std::time::Duration::ZERO)?;
+        assert_eq!(whole.len(), 44100 * 2);
+        assert!(whole.iter().all(|s| s.abs() > 0.1));
+        let tail = render_from(std::time::Duration::from_millis(750))?;
+        assert_eq!(tail[..], whole[33075..]);
+        Ok(())
+    }
+
     #[test]
     fn pilot_measure_hint_without_grid_fails() -> Result<(), Box<dyn Error>> {
         let tempdir = tempfile::tempdir()?;
@@ -20,6 +20,7 @@ use tokio::sync::watch;
 use tokio::task::JoinHandle;
 use tokio::time;
 
+use crate::audio::meters::{MeterSnapshot, Meters};
 use crate::lighting::effects::{is_multiplier_channel, FixtureState};
 use crate::lighting::EffectEngine;
 
@@ -39,6 +40,8 @@ pub struct FixtureSnapshot {
 pub struct StateSnapshot {
     pub fixtures: Vec<FixtureSnapshot>,
     pub active_effects: Vec<String>,
+    /// The latest level meter reading, when the audio device is metered.
+    pub meters: Option<MeterSnapshot>,
 }
 
 /// Starts a 20Hz sampler that produces `StateSnapshot` values via a `watch` channel.
@@ -57,12 +60,16 @@ pub fn start_sampler(
 
 /// Starts a sampler using a shared watch sender and a cancellation token.
 /// The sampler stops when the token is cancelled (e.g. on hardware reload).
+///
+/// Fixture state is sampled at 20Hz when there is an effect engine; level
+/// meters, when given, are read at the interval given with them.
 pub fn start_sampler_cancellable(
-    effect_engine: Arc<Mutex<EffectEngine>>,
+    effect_engine: Option<Arc<Mutex<EffectEngine>>>,
+    meters: Option<(Arc<Meters>, Duration)>,
     tx: Arc<watch::Sender<Arc<StateSnapshot>>>,
     cancel: tokio_util::sync::CancellationToken,
 ) -> JoinHandle<()> {
-    tokio::spawn(sampler_loop_cancellable(effect_engine, tx, cancel))
+    tokio::spawn(sampler_loop_cancellable(effect_engine, meters, tx, cancel))
 }
 
 /// Builds the dimmer map by querying the fixture registry on the blocking
@@ -104,6 +111,7 @@ async fn sample_tick(
     Some(Arc::new(StateSnapshot {
         fixtures,
         active_effects,
+        meters: None,
     }))
 }
 
@@ -125,25 +133,49 @@ async fn sampler_loop(
     }
 }
 
-/// Cancellable variant of `sampler_loop`. Stops when the token is cancelled.
+/// Cancellable variant of `sampler_loop`, which also reads level meters on
+/// their own interval. Stops when the token is cancelled.
 async fn sampler_loop_cancellable(
-    effect_engine: Arc<Mutex<EffectEngine>>,
+    effect_engine: Option<Arc<Mutex<EffectEngine>>>,
+    meters: Option<(Arc<Meters>, Duration)>,
     tx: Arc<watch::Sender<Arc<StateSnapshot>>>,
     cancel: tokio_util::sync::CancellationToken,
 ) {
     let mut interval = time::interval(Duration::from_millis(50));
     interval.set_missed_tick_behavior(time::MissedTickBehavior::Skip);
-    let has_dimmer_map = init_dimmer_map(&effect_engine).await;
+    let meter_interval = meters
+        .as_ref()
+        .map_or(Duration::from_millis(50), |(_, interval)| *interval);
+    let mut meter_ticks = time::interval(meter_interval);
+    meter_ticks.set_missed_tick_behavior(time::MissedTickBehavior::Skip);
+    let has_dimmer_map = match effect_engine {
+        Some(ref engine) => init_dimmer_map(engine).await,
+        None => HashMap::new(),
+    };
+    let mut current = StateSnapshot::default();
 
     loop {
         tokio::select! {
             _ = cancel.cancelled() => break,
-            _ = interval.tick() => {}
+            _ = interval.tick(), if effect_engine.is_some() => {
+                let Some(ref engine) = effect_engine else {
+                    continue;
+                };
+                let Some(snapshot) = sample_tick(engine, &has_dimmer_map).await else {
+                    continue;
+                };
+                current.fixtures = snapshot.fixtures.clone();
+                current.active_effects = snapshot.active_effects.clone();
+            }
+            _ = meter_ticks.tick(), if meters.is_some() => {
+                let Some((ref meters, _)) = meters else {
+                    continue;
+                };
+                current.meters = Some(meters.snapshot());
+            }
         }
 
-        if let Some(snapshot) = sample_tick(&effect_engine, &has_dimmer_map).await {
-            let _ = tx.send(snapshot);
-        }
+        let _ = tx.send(Arc::new(current.clone()));
     }
 }
 
@@ -349,6 +381,7 @@ mod tests {
                 channels: HashMap::new(),
             }],
             active_effects: vec!["effect1".to_string()],
+            meters: None,
         };
         let cloned = snapshot.clone();
         assert_eq!(cloned.fixtures.len(), 1);
@@ -422,6 +455,31 @@ mod tests {
         handle.abort();
     }
 
+    #[tokio::test]
+    async fn test_cancellable_sampler_reads_meters_without_lighting() {
+        let meters = Arc::new(Meters::new(2, &["click".to_string()]));
+        meters.record_output(&[0.5, 0.25], 2);
+        let (tx, mut rx) = watch::channel(Arc::new(StateSnapshot::default()));
+        let cancel = tokio_util::sync::CancellationToken::new();
+        let handle = start_sampler_cancellable(
+            None,
+            Some((meters, Duration::from_millis(10))),
+            Arc::new(tx),
+            cancel.clone(),
+        );
+
+        let result = tokio::time::timeout(std::time::Duration::from_secs(2), rx.changed()).await;
+        assert!(result.is_ok(), "timed out waiting for meters");
+        let snapshot = rx.borrow().clone();
+        let meters = snapshot.meters.as_ref().expect("meters published");
+        assert_eq!(meters.channels.len(), 2);
+        assert_eq!(meters.tracks[0].0, "click");
+        assert!(snapshot.fixtures.is_empty());
+
+        cancel.cancel();
+        let _ = handle.await;
+    }
+
     #[tokio::test]
     async fn test_sampler_stops_when_receiver_dropped() {
         let engine = Arc::new(Mutex::new(EffectEngine::new()));
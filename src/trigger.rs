@@ -15,13 +15,18 @@
 //! Audio trigger detection for piezo drum triggers.
 //!
 //! Captures audio input via cpal, detects transient hits using per-channel
-//! state machines, and produces source-agnostic `TriggerAction` events.
+//! state machines, and produces source-agnostic `TriggerAction` events. An
+//! input channel can instead carry linear timecode for playback to chase.
+//! Input channels can also be recorded to disk while songs play.
 
 mod detector;
 mod engine;
 mod filter;
+mod ltc;
+mod recorder;
 
 pub use engine::TriggerEngine;
+pub use recorder::{RecordStatus, Recorder};
 
 /// Converts milliseconds to samples, rounding up.
 fn ms_to_samples(ms: u32, sample_rate: u32) -> u32 {
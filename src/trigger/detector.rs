@@ -288,6 +288,7 @@ impl TriggerDetector {
                 TriggerAction::Trigger(TriggerEvent {
                     sample_name: self.sample_name.clone().unwrap_or_default(),
                     velocity,
+                    note: None,
                     release_group: self.release_group.clone(),
                 })
             }
@@ -15,10 +15,13 @@
 //! Audio trigger engine: cpal input stream + event forwarding.
 //!
 //! Opens a cpal input device, routes per-channel samples to `TriggerDetector`
-//! instances, and produces `TriggerAction` events via a crossbeam channel.
+//! instances, and produces `TriggerAction` events via a crossbeam channel. A
+//! linear timecode input channel feeds an `ExternalTransport` instead, and
+//! recorded channels are copied out to a `Recorder`.
 
 use std::error::Error;
 use std::sync::Arc;
+use std::time::Instant;
 
 use cpal::traits::{DeviceTrait, StreamTrait};
 use crossbeam_channel::{Receiver, Sender};
@@ -26,9 +29,12 @@ use parking_lot::{Condvar, Mutex};
 use tracing::{debug, error, info, warn};
 
 use super::detector::TriggerDetector;
+use super::ltc::LtcChannel;
 use super::ms_to_samples;
+use super::recorder::{RecordTap, Recorder};
 use crate::audio::format::SampleFormat;
 use crate::config::trigger::{AudioTriggerInput, TriggerConfig, TriggerInput, TriggerInputAction};
+use crate::midi::chase::ExternalTransport;
 use crate::samples::TriggerAction;
 use crate::thread_priority::{callback_thread_priority, promote_to_realtime, rt_audio_enabled};
 
@@ -69,6 +75,10 @@ pub struct TriggerEngine {
     _thread: Option<std::thread::JoinHandle<()>>,
     /// Shutdown signal to stop the recovery thread on drop.
     shutdown: ShutdownNotify,
+    /// The transport decoded from the linear timecode input, if configured.
+    external_transport: Option<Arc<ExternalTransport>>,
+    /// Records input channels to disk, if configured.
+    recorder: Option<Arc<Recorder>>,
 }
 
 /// Parameters captured from config for rebuilding the input stream on recovery.
@@ -78,6 +88,8 @@ struct StreamParams {
     sample_format: cpal::SampleFormat,
     crosstalk: Option<(u32, f32)>,
     inputs: Vec<TriggerInput>,
+    external_transport: Option<Arc<ExternalTransport>>,
+    recorder: Option<Arc<Recorder>>,
 }
 
 impl TriggerEngine {
@@ -168,12 +180,30 @@ impl TriggerEngine {
         // Create the event channel (bounded to prevent unbounded growth under load)
         let (tx, rx) = crossbeam_channel::bounded(256);
 
+        let external_transport = config.ltc_input().map(|ltc| {
+            info!(channel = ltc.channel(), "Reading linear timecode");
+            Arc::new(ExternalTransport::linear_timecode())
+        });
+
+        // A recording that can't be set up shouldn't cost the triggers.
+        let recorder = config.record().and_then(|record| {
+            match Recorder::new(record, &config.recorded_channels(), channels, sample_rate) {
+                Ok(recorder) => Some(Arc::new(recorder)),
+                Err(e) => {
+                    warn!(error = %e, "Failed to set up input recording, continuing without it");
+                    None
+                }
+            }
+        });
+
         let params = StreamParams {
             device_name: device_name.to_string(),
             stream_config,
             sample_format: stream_format,
             crosstalk,
             inputs: config.inputs().to_vec(),
+            external_transport: external_transport.clone(),
+            recorder: recorder.clone(),
         };
 
         let error_notify: ErrorNotify = Arc::new((Mutex::new(false), Condvar::new()));
@@ -186,6 +216,8 @@ impl TriggerEngine {
             &device,
             &params.stream_config,
             detector_map,
+            build_ltc_channel(&params),
+            params.recorder.as_ref().map(|r| r.tap()),
             params.stream_config.channels,
             tx.clone(),
             params.sample_format,
@@ -209,6 +241,8 @@ impl TriggerEngine {
             receiver: rx,
             _thread: Some(thread),
             shutdown,
+            external_transport,
+            recorder,
         })
     }
 
@@ -217,6 +251,17 @@ impl TriggerEngine {
         self.receiver.clone()
     }
 
+    /// Returns the transport decoded from the linear timecode input, if one
+    /// is configured.
+    pub fn external_transport(&self) -> Option<Arc<ExternalTransport>> {
+        self.external_transport.clone()
+    }
+
+    /// Returns the input recorder, if recording is configured.
+    pub fn recorder(&self) -> Option<Arc<Recorder>> {
+        self.recorder.clone()
+    }
+
     /// Runs the stream recovery loop. Blocks until shutdown.
     fn recovery_loop(
         mut stream: cpal::Stream,
@@ -297,6 +342,8 @@ impl TriggerEngine {
                     &device,
                     &params.stream_config,
                     detector_map,
+                    build_ltc_channel(&params),
+                    params.recorder.as_ref().map(|r| r.tap()),
                     params.stream_config.channels,
                     tx.clone(),
                     params.sample_format,
@@ -329,6 +376,8 @@ impl TriggerEngine {
         device: &cpal::Device,
         config: &cpal::StreamConfig,
         detectors: Vec<Option<TriggerDetector>>,
+        ltc: Option<LtcChannel>,
+        tap: Option<RecordTap>,
         channels: u16,
         tx: Sender<TriggerAction>,
         sample_format: cpal::SampleFormat,
@@ -340,6 +389,8 @@ impl TriggerEngine {
                 device,
                 config,
                 detectors,
+                ltc,
+                tap,
                 channels,
                 tx,
                 crosstalk,
@@ -349,6 +400,8 @@ impl TriggerEngine {
                 device,
                 config,
                 detectors,
+                ltc,
+                tap,
                 channels,
                 tx,
                 crosstalk,
@@ -358,6 +411,8 @@ impl TriggerEngine {
                 device,
                 config,
                 detectors,
+                ltc,
+                tap,
                 channels,
                 tx,
                 crosstalk,
@@ -372,6 +427,8 @@ impl TriggerEngine {
         device: &cpal::Device,
         config: &cpal::StreamConfig,
         mut detectors: Vec<Option<TriggerDetector>>,
+        mut ltc: Option<LtcChannel>,
+        mut tap: Option<RecordTap>,
         channels: u16,
         tx: Sender<TriggerAction>,
         crosstalk: Option<(u32, f32)>,
@@ -389,13 +446,25 @@ impl TriggerEngine {
             *config,
             move |data: &[T], _: &cpal::InputCallbackInfo| {
                 promote_to_realtime(callback_priority, rt_audio, &mut priority_set);
+                // The buffer has just been captured: its last frame is now.
+                let received = Instant::now();
+                let frame_count = data.len() / channels as usize;
                 // Data is interleaved: [ch0, ch1, ch2, ..., ch0, ch1, ...]
-                for frame in data.chunks_exact(channels as usize) {
+                for (i, frame) in data.chunks_exact(channels as usize).enumerate() {
                     let f32_frame: Vec<f32> = frame
                         .iter()
                         .map(|s| <f32 as cpal::FromSample<T>>::from_sample_(*s))
                         .collect();
                     process_frame(&f32_frame, &mut detectors, &tx, crosstalk);
+                    if let Some(ltc) = ltc.as_mut() {
+                        let period = ltc.sample_period();
+                        ltc.process(&f32_frame, || {
+                            received - period * (frame_count - 1 - i) as u32
+                        });
+                    }
+                }
+                if let Some(tap) = tap.as_mut() {
+                    tap.capture(data, channels as usize, received);
                 }
             },
             move |err: cpal::Error| {
@@ -444,6 +513,22 @@ fn resolve_crosstalk(
     }
 }
 
+/// Builds the linear timecode reader for the configured input channel, if
+/// there is one the device has.
+fn build_ltc_channel(params: &StreamParams) -> Option<LtcChannel> {
+    let transport = params.external_transport.clone()?;
+    let input = params.inputs.iter().find_map(|i| match i {
+        TriggerInput::Ltc(ltc) => Some(ltc),
+        _ => None,
+    })?;
+    let index = validate_channel_index(input.channel(), params.stream_config.channels)?;
+    Some(LtcChannel::new(
+        index,
+        params.stream_config.sample_rate,
+        transport,
+    ))
+}
+
 /// Builds the detector map from config inputs.
 /// Returns a Vec of `Option<TriggerDetector>` indexed by 0-based channel number.
 fn build_detector_map(
@@ -520,7 +605,7 @@ fn validate_audio_input(input: &AudioTriggerInput) -> Result<(), Box<dyn Error>>
 
 /// Validates that a 1-indexed channel number is valid for the device.
 /// Returns the 0-indexed channel index, or `None` if the channel exceeds device capacity.
-fn validate_channel_index(channel: u16, device_channels: u16) -> Option<usize> {
+pub(super) fn validate_channel_index(channel: u16, device_channels: u16) -> Option<usize> {
     let ch_idx = channel.checked_sub(1)? as usize;
     if ch_idx >= device_channels as usize {
         warn!(
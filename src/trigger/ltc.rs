@@ -0,0 +1,296 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+
+//! Linear timecode reader for an audio input channel.
+//!
+//! Times the transitions of the incoming biphase mark signal to recover bits,
+//! and hands each frame that ends in a sync word to an [`ExternalTransport`]
+//! for playback to chase.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::audio::ltc::{decode_frame_bits, BITS_PER_FRAME};
+use crate::config::{MtcFrameRate, Timecode};
+use crate::midi::chase::ExternalTransport;
+
+/// How far the signal must swing past zero to count as a transition, so
+/// noise on a quiet line doesn't read as bits.
+const HYSTERESIS: f32 = 0.02;
+
+/// How quickly the bit length estimate follows the incoming signal, so it
+/// tracks a source that varies speed.
+const BIT_LENGTH_SMOOTHING: f32 = 0.1;
+
+/// Decodes linear timecode from a stream of samples.
+pub(super) struct LtcReader {
+    sample_rate: u32,
+    /// Which side of zero the signal is on.
+    high: bool,
+    /// Samples since the last transition.
+    since_edge: u32,
+    /// Samples per bit, once measured.
+    bit_length: Option<f32>,
+    /// Whether the first half of a one has arrived.
+    half_pending: bool,
+    /// The most recent bits, newest in the lowest bit.
+    bits: u128,
+    /// How many bits since the last frame, up to a frame's worth.
+    bit_count: usize,
+}
+
+impl LtcReader {
+    pub(super) fn new(sample_rate: u32) -> LtcReader {
+        LtcReader {
+            sample_rate,
+            high: false,
+            since_edge: 0,
+            bit_length: None,
+            half_pending: false,
+            bits: 0,
+            bit_count: 0,
+        }
+    }
+
+    /// Feeds one sample. Returns a frame's timecode and rate when this sample
+    /// completes one.
+    pub(super) fn push(&mut self, sample: f32) -> Option<(Timecode, MtcFrameRate)> {
+        self.since_edge = self.since_edge.saturating_add(1);
+        let edge = if self.high {
+            sample < -HYSTERESIS
+        } else {
+            sample > HYSTERESIS
+        };
+        if !edge {
+            // A line gone quiet loses the frame in progress.
+            if self
+                .bit_length
+                .is_some_and(|bit| self.since_edge as f32 > bit * 4.0)
+            {
+                self.half_pending = false;
+                self.bit_count = 0;
+            }
+            return None;
+        }
+        self.high = !self.high;
+        let interval = self.since_edge as f32;
+        self.since_edge = 0;
+        self.interval(interval)
+    }
+
+    fn interval(&mut self, interval: f32) -> Option<(Timecode, MtcFrameRate)> {
+        let Some(bit) = self.bit_length else {
+            self.bit_length = Some(interval);
+            return None;
+        };
+        if interval > bit * 1.5 {
+            // What was taken for a bit was half of one.
+            self.bit_length = Some(interval);
+            self.half_pending = false;
+            self.bit_count = 0;
+            return None;
+        }
+        if interval < bit * 0.25 {
+            return None;
+        }
+        if interval > bit * 0.75 {
+            self.bit_length = Some(bit + (interval - bit) * BIT_LENGTH_SMOOTHING);
+            if self.half_pending {
+                // Half a one, then a zero: out of step. Start again.
+                self.half_pending = false;
+                self.bit_count = 0;
+            }
+            return self.bit(false);
+        }
+        self.bit_length = Some(bit + (interval * 2.0 - bit) * BIT_LENGTH_SMOOTHING);
+        if self.half_pending {
+            self.half_pending = false;
+            return self.bit(true);
+        }
+        self.half_pending = true;
+        None
+    }
+
+    fn bit(&mut self, bit: bool) -> Option<(Timecode, MtcFrameRate)> {
+        self.bits = (self.bits << 1 | u128::from(bit)) & ((1 << BITS_PER_FRAME) - 1);
+        self.bit_count = (self.bit_count + 1).min(BITS_PER_FRAME);
+        if self.bit_count < BITS_PER_FRAME {
+            return None;
+        }
+        let frame: [bool; BITS_PER_FRAME] =
+            std::array::from_fn(|i| self.bits >> (BITS_PER_FRAME - 1 - i) & 1 == 1);
+        let (timecode, drop_frame) = decode_frame_bits(&frame)?;
+        self.bit_count = 0;
+        Some((timecode, self.rate(drop_frame)))
+    }
+
+    /// The frame rate, from the drop-frame flag and the measured bit length.
+    fn rate(&self, drop_frame: bool) -> MtcFrameRate {
+        if drop_frame {
+            return MtcFrameRate::Fps2997DropFrame;
+        }
+        let bit = self.bit_length.unwrap_or(1.0);
+        let fps = self.sample_rate as f32 / (bit * BITS_PER_FRAME as f32);
+        [
+            MtcFrameRate::Fps24,
+            MtcFrameRate::Fps25,
+            MtcFrameRate::Fps30,
+        ]
+        .into_iter()
+        .min_by(|a, b| {
+            let distance = |rate: &MtcFrameRate| (rate.frames_per_second() as f32 - fps).abs();
+            distance(a).total_cmp(&distance(b))
+        })
+        .unwrap_or_default()
+    }
+}
+
+/// The input channel carrying linear timecode, and where its frames go.
+pub(super) struct LtcChannel {
+    /// 0-indexed channel within a frame of input.
+    index: usize,
+    reader: LtcReader,
+    transport: Arc<ExternalTransport>,
+}
+
+impl LtcChannel {
+    pub(super) fn new(
+        index: usize,
+        sample_rate: u32,
+        transport: Arc<ExternalTransport>,
+    ) -> LtcChannel {
+        LtcChannel {
+            index,
+            reader: LtcReader::new(sample_rate),
+            transport,
+        }
+    }
+
+    /// Feeds one frame of input. `captured_at` says when it was captured, and
+    /// is only asked when a timecode frame ends on it.
+    pub(super) fn process(&mut self, frame: &[f32], captured_at: impl FnOnce() -> Instant) {
+        let Some(sample) = frame.get(self.index) else {
+            return;
+        };
+        if let Some((timecode, rate)) = self.reader.push(*sample) {
+            self.transport.feed_frame(timecode, rate, captured_at());
+        }
+    }
+
+    /// How long one sample lasts.
+    pub(super) fn sample_period(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.reader.sample_rate as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::ltc::LtcSource;
+    use crate::audio::sample_source::traits::SampleSource;
+
+    fn read(reader: &mut LtcReader, samples: impl IntoIterator<Item = f32>) -> Vec<Timecode> {
+        samples
+            .into_iter()
+            .filter_map(|sample| reader.push(sample))
+            .map(|(timecode, _)| timecode)
+            .collect()
+    }
+
+    fn generated(rate: MtcFrameRate, start: Timecode, sample_rate: u32) -> Vec<f32> {
+        let mut source = LtcSource::new(
+            rate,
+            start,
+            sample_rate,
+            Duration::ZERO,
+            Duration::from_secs(1),
+        );
+        std::iter::from_fn(|| source.next_sample().unwrap()).collect()
+    }
+
+    #[test]
+    fn reads_generated_timecode_at_every_rate() {
+        for (rate, sample_rate) in [
+            (MtcFrameRate::Fps24, 48000),
+            (MtcFrameRate::Fps25, 44100),
+            (MtcFrameRate::Fps2997DropFrame, 48000),
+            (MtcFrameRate::Fps30, 96000),
+        ] {
+            let start = Timecode::new(3, 0, 0, 0);
+            let mut reader = LtcReader::new(sample_rate);
+            let samples = generated(rate, start, sample_rate);
+            let mut frames = Vec::new();
+            for sample in samples {
+                if let Some(frame) = reader.push(sample) {
+                    frames.push(frame);
+                }
+            }
+            // The first frame goes on measuring the bit length.
+            assert!(
+                frames.len() >= rate.frames_per_second() as usize - 2,
+                "{rate:?}"
+            );
+            let first = crate::midi::mtc::to_frame_count(frames[0].0, rate);
+            for (i, (timecode, read_rate)) in frames.iter().enumerate() {
+                assert_eq!(*read_rate, rate);
+                assert_eq!(
+                    *timecode,
+                    crate::midi::mtc::from_frame_count(first + i as u64, rate),
+                    "{rate:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reads_through_inversion_and_level() {
+        // Biphase mark doesn't care which way up the signal is, or how loud.
+        let start = Timecode::new(1, 2, 3, 4);
+        let samples = generated(MtcFrameRate::Fps25, start, 48000);
+        let mut reader = LtcReader::new(48000);
+        let frames = read(&mut reader, samples.iter().map(|s| -s * 0.2));
+        assert!(frames.len() > 20);
+        for pair in frames.windows(2) {
+            let count = |timecode| crate::midi::mtc::to_frame_count(timecode, MtcFrameRate::Fps25);
+            assert_eq!(count(pair[1]), count(pair[0]) + 1);
+        }
+    }
+
+    #[test]
+    fn silence_reads_nothing() {
+        let mut reader = LtcReader::new(48000);
+        assert!(read(&mut reader, (0..48000).map(|i| (i % 7) as f32 * 0.001)).is_empty());
+    }
+
+    #[test]
+    fn channel_feeds_the_transport() {
+        let transport = Arc::new(ExternalTransport::linear_timecode());
+        let mut channel = LtcChannel::new(1, 48000, transport.clone());
+        let origin = Instant::now();
+        let period = channel.sample_period();
+        let samples = generated(MtcFrameRate::Fps30, Timecode::new(0, 0, 10, 0), 48000);
+        for (i, sample) in samples.iter().enumerate() {
+            channel.process(&[0.0, *sample], || origin + period * i as u32);
+        }
+        let state = transport.state_at(origin + Duration::from_secs(1)).unwrap();
+        assert!(state.running);
+        match state.position {
+            crate::midi::chase::ExternalPosition::Timecode { position, rate } => {
+                assert_eq!(rate, MtcFrameRate::Fps30);
+                assert!(position.abs_diff(Duration::from_secs(11)) < Duration::from_millis(40));
+            }
+            other => panic!("expected timecode, got {other:?}"),
+        }
+    }
+}
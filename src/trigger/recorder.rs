@@ -0,0 +1,949 @@
+// Copyright (C) 2026 Michael Wilson <mike@mdwn.dev>
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+//
+
+//! Input recording: writes trigger input channels to disk during playback.
+//!
+//! The input callback copies the recorded channels into a lock-free ring
+//! ([`RecordTap`]) and never touches the disk. A writer thread drains the
+//! ring into one mono file per channel per take. A take starts when an armed
+//! recorder sees a song start and ends when the song does.
+//!
+//! Sample zero of every take file is the start of the song, as the song's
+//! `PlaybackClock` has it: the writer pads the front with silence up to where
+//! the first captured frame fell, so takes drop straight onto a song's
+//! timeline. From there a file is a continuous record of the input in real
+//! time, pauses included. Audio the ring had no room for, and any gap while
+//! the input stream was being rebuilt, is filled with silence so what follows
+//! stays in place.
+
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossbeam_channel::{Receiver, RecvTimeoutError, Sender};
+use parking_lot::Mutex;
+use rtrb::{Consumer, Producer, RingBuffer};
+use tracing::{error, info, warn};
+
+use crate::audio::flac::FlacWriter;
+use crate::clock::PlaybackClock;
+use crate::config::trigger::{RecordConfig, RecordFormat};
+
+/// How much audio the ring holds before the callback starts dropping it.
+const RING_SECONDS: usize = 2;
+
+/// Capacity of the ring of block headers: one per input callback, so far
+/// more than two seconds' worth at any practical buffer size.
+const BLOCK_RING: usize = 4096;
+
+/// How often the writer drains the ring.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Frames of silence written at a time when padding.
+const SILENCE_CHUNK: usize = 4096;
+
+/// One input callback's worth of captured frames.
+#[derive(Clone, Copy, Debug)]
+struct Block {
+    /// Frames in the block.
+    frames: u32,
+    /// When the last frame was captured.
+    last_frame: Instant,
+    /// Frames the tap had dropped for lack of room, in total, before this
+    /// block.
+    dropped_before: u64,
+}
+
+/// The input callback's end of the recorder.
+///
+/// Lives in the cpal input callback, so it never blocks or allocates: a
+/// block that doesn't fit is counted and dropped.
+pub(super) struct RecordTap {
+    capturing: Arc<AtomicBool>,
+    /// 0-indexed device channels to record.
+    channels: Vec<usize>,
+    samples: Producer<f32>,
+    blocks: Producer<Block>,
+    dropped: u64,
+}
+
+impl RecordTap {
+    /// Copies the recorded channels of an interleaved buffer into the ring,
+    /// if a take is capturing. `received` is when the last frame was
+    /// captured.
+    pub(super) fn capture<T>(&mut self, data: &[T], device_channels: usize, received: Instant)
+    where
+        T: cpal::SizedSample,
+        f32: cpal::FromSample<T>,
+    {
+        if !self.capturing.load(Ordering::Relaxed) {
+            return;
+        }
+        let frames = data.len() / device_channels;
+        if frames == 0 {
+            return;
+        }
+        let needed = frames * self.channels.len();
+        if self.blocks.is_full() || self.samples.slots() < needed {
+            self.dropped += frames as u64;
+            return;
+        }
+        let Ok(chunk) = self.samples.write_chunk_uninit(needed) else {
+            self.dropped += frames as u64;
+            return;
+        };
+        let channels = &self.channels;
+        chunk.fill_from_iter(data.chunks_exact(device_channels).flat_map(|frame| {
+            channels
+                .iter()
+                .map(move |&c| <f32 as cpal::FromSample<T>>::from_sample_(frame[c]))
+        }));
+        // Room was checked above, and this is the only producer.
+        let _ = self.blocks.push(Block {
+            frames: frames as u32,
+            last_frame: received,
+            dropped_before: self.dropped,
+        });
+    }
+}
+
+/// Whether the recorder is armed and what it is recording.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordStatus {
+    /// Whether songs that start are recorded.
+    pub armed: bool,
+    /// The take being recorded, e.g. `Song-take3`.
+    pub take: Option<String>,
+}
+
+/// Records input channels to disk while songs play.
+pub struct Recorder {
+    armed: AtomicBool,
+    capturing: Arc<AtomicBool>,
+    /// 0-indexed device channels to record.
+    channels: Vec<usize>,
+    sample_rate: u32,
+    next_id: AtomicU64,
+    take: Arc<Mutex<Option<String>>>,
+    commands: Option<Sender<Command>>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+/// Requests to the writer thread.
+enum Command {
+    /// The input stream was (re)built; read from this ring from now on.
+    Tap {
+        samples: Consumer<f32>,
+        blocks: Consumer<Block>,
+    },
+    /// Record a take of `song` once `clock` is running.
+    Start {
+        id: u64,
+        song: String,
+        clock: PlaybackClock,
+        start_time: Duration,
+    },
+    /// Finish the take with this id, or whichever is running.
+    Stop { id: Option<u64> },
+}
+
+impl Recorder {
+    /// Creates a recorder for the given 1-indexed channels of an input
+    /// device. Channels the device doesn't have are skipped.
+    pub(super) fn new(
+        config: &RecordConfig,
+        channels: &[u16],
+        device_channels: u16,
+        sample_rate: u32,
+    ) -> Result<Self, Box<dyn Error>> {
+        let bits_per_sample = config.bits_per_sample();
+        if bits_per_sample != 16 && bits_per_sample != 24 {
+            return Err(format!(
+                "Recording supports 16 or 24 bits per sample, not {}",
+                bits_per_sample
+            )
+            .into());
+        }
+        let indices: Vec<usize> = channels
+            .iter()
+            .filter_map(|&c| super::engine::validate_channel_index(c, device_channels))
+            .collect();
+        if indices.is_empty() {
+            return Err("Recording is configured but there are no input channels to record".into());
+        }
+        let directory = PathBuf::from(config.directory());
+        std::fs::create_dir_all(&directory)
+            .map_err(|e| format!("{}: {}", directory.display(), e))?;
+
+        let capturing = Arc::new(AtomicBool::new(false));
+        let take = Arc::new(Mutex::new(None));
+        let (tx, rx) = crossbeam_channel::unbounded();
+        let writer = Writer {
+            directory,
+            format: config.format(),
+            bits_per_sample,
+            sample_rate,
+            channels: indices.iter().map(|&i| i as u16 + 1).collect(),
+            capturing: capturing.clone(),
+            status: take.clone(),
+            taps: VecDeque::new(),
+            pending: None,
+            take: None,
+        };
+        let thread = thread::Builder::new()
+            .name("input-recorder".into())
+            .spawn(move || writer.run(rx))?;
+
+        info!(
+            directory = config.directory(),
+            channels = ?channels,
+            format = ?config.format(),
+            "Input recording available"
+        );
+
+        Ok(Recorder {
+            armed: AtomicBool::new(false),
+            capturing,
+            channels: indices,
+            sample_rate,
+            next_id: AtomicU64::new(1),
+            take,
+            commands: Some(tx),
+            thread: Some(thread),
+        })
+    }
+
+    /// Creates the callback's end of a fresh ring for a newly built input
+    /// stream. The writer moves over to it once the old one is drained.
+    pub(super) fn tap(&self) -> RecordTap {
+        let capacity = self.sample_rate as usize * self.channels.len() * RING_SECONDS;
+        let (samples, samples_consumer) = RingBuffer::new(capacity);
+        let (blocks, blocks_consumer) = RingBuffer::new(BLOCK_RING);
+        self.send(Command::Tap {
+            samples: samples_consumer,
+            blocks: blocks_consumer,
+        });
+        RecordTap {
+            capturing: self.capturing.clone(),
+            channels: self.channels.clone(),
+            samples,
+            blocks,
+            dropped: 0,
+        }
+    }
+
+    /// Whether songs that start are recorded.
+    pub fn is_armed(&self) -> bool {
+        self.armed.load(Ordering::Relaxed)
+    }
+
+    /// Arms or disarms recording. Arming takes effect from the next song
+    /// that starts; disarming finishes the take being recorded.
+    pub fn set_armed(&self, armed: bool) {
+        let was = self.armed.swap(armed, Ordering::Relaxed);
+        if was && !armed {
+            self.send(Command::Stop { id: None });
+        }
+        if was != armed {
+            info!(armed, "Input recording arm changed");
+        }
+    }
+
+    /// Starts a take of `song` if armed, to begin when `clock` starts
+    /// running from `start_time`. Returns the id to finish it with.
+    pub fn start_take(
+        &self,
+        song: &str,
+        clock: PlaybackClock,
+        start_time: Duration,
+    ) -> Option<u64> {
+        if !self.is_armed() {
+            return None;
+        }
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        self.send(Command::Start {
+            id,
+            song: song.to_string(),
+            clock,
+            start_time,
+        });
+        Some(id)
+    }
+
+    /// Finishes a take. Does nothing if a later take has replaced it.
+    pub fn finish_take(&self, id: u64) {
+        self.send(Command::Stop { id: Some(id) });
+    }
+
+    /// Whether recording is armed and what it is recording.
+    pub fn status(&self) -> RecordStatus {
+        RecordStatus {
+            armed: self.is_armed(),
+            take: self.take.lock().clone(),
+        }
+    }
+
+    fn send(&self, command: Command) {
+        if let Some(commands) = &self.commands {
+            if commands.send(command).is_err() {
+                error!("Input recorder writer thread has exited");
+            }
+        }
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        // Closing the channel tells the writer to finish up; waiting for it
+        // means a take open at shutdown is a complete file.
+        self.commands = None;
+        if let Some(thread) = self.thread.take() {
+            if thread.join().is_err() {
+                error!("Input recorder writer thread panicked");
+            }
+        }
+    }
+}
+
+/// A take waiting for its song's clock to start.
+struct PendingTake {
+    id: u64,
+    song: String,
+    clock: PlaybackClock,
+    start_time: Duration,
+}
+
+/// A take being written.
+struct Take {
+    id: u64,
+    name: String,
+    files: Vec<ChannelFile>,
+    /// Real time at which the song was at zero: sample zero of the files.
+    origin: Instant,
+    /// Frames written to each file.
+    written: u64,
+    /// The tap's drop count already accounted for.
+    dropped: u64,
+    /// Frames of silence written for audio that was lost.
+    lost: u64,
+    /// Whether the next block is placed by its timestamp rather than
+    /// straight after the last: the first block of the take, and the first
+    /// from a rebuilt stream.
+    resync: bool,
+    /// Per-channel scratch for deinterleaving.
+    scratch: Vec<Vec<i32>>,
+}
+
+/// One channel's file.
+enum ChannelFile {
+    Wav(hound::WavWriter<BufWriter<File>>),
+    Flac(FlacWriter<BufWriter<File>>),
+}
+
+impl ChannelFile {
+    fn create(
+        path: &Path,
+        format: RecordFormat,
+        sample_rate: u32,
+        bits_per_sample: u16,
+    ) -> Result<Self, Box<dyn Error>> {
+        Ok(match format {
+            RecordFormat::Wav => ChannelFile::Wav(hound::WavWriter::create(
+                path,
+                hound::WavSpec {
+                    channels: 1,
+                    sample_rate,
+                    bits_per_sample,
+                    sample_format: hound::SampleFormat::Int,
+                },
+            )?),
+            RecordFormat::Flac => ChannelFile::Flac(FlacWriter::new(
+                BufWriter::new(File::create(path)?),
+                1,
+                sample_rate,
+                bits_per_sample,
+            )?),
+        })
+    }
+
+    fn write(&mut self, samples: &[i32]) -> Result<(), Box<dyn Error>> {
+        match self {
+            ChannelFile::Wav(wav) => {
+                for &sample in samples {
+                    wav.write_sample(sample)?;
+                }
+            }
+            ChannelFile::Flac(flac) => flac.write_samples(samples)?,
+        }
+        Ok(())
+    }
+
+    fn finalize(self) -> Result<(), Box<dyn Error>> {
+        match self {
+            ChannelFile::Wav(wav) => wav.finalize()?,
+            ChannelFile::Flac(flac) => {
+                let mut writer = flac.finalize()?;
+                io::Write::flush(&mut writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A tap's rings, as the writer reads them.
+struct TapReader {
+    samples: Consumer<f32>,
+    blocks: Consumer<Block>,
+}
+
+/// The writer thread's state.
+struct Writer {
+    directory: PathBuf,
+    format: RecordFormat,
+    bits_per_sample: u16,
+    sample_rate: u32,
+    /// 1-indexed channels, for file names.
+    channels: Vec<u16>,
+    capturing: Arc<AtomicBool>,
+    status: Arc<Mutex<Option<String>>>,
+    /// The ring being read, then any from streams built since.
+    taps: VecDeque<TapReader>,
+    pending: Option<PendingTake>,
+    take: Option<Take>,
+}
+
+impl Writer {
+    fn run(mut self, commands: Receiver<Command>) {
+        loop {
+            match commands.recv_timeout(POLL_INTERVAL) {
+                Ok(command) => self.handle(command),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => {
+                    self.pending = None;
+                    self.finish();
+                    return;
+                }
+            }
+            self.begin_if_running();
+            self.drain();
+        }
+    }
+
+    fn handle(&mut self, command: Command) {
+        match command {
+            Command::Tap { samples, blocks } => {
+                self.taps.push_back(TapReader { samples, blocks });
+            }
+            Command::Start {
+                id,
+                song,
+                clock,
+                start_time,
+            } => {
+                // The previous song handing straight off to this one.
+                self.finish();
+                self.pending = Some(PendingTake {
+                    id,
+                    song,
+                    clock,
+                    start_time,
+                });
+            }
+            Command::Stop { id } => {
+                if self
+                    .pending
+                    .as_ref()
+                    .is_some_and(|p| id.is_none_or(|id| id == p.id))
+                {
+                    self.pending = None;
+                }
+                if self
+                    .take
+                    .as_ref()
+                    .is_some_and(|t| id.is_none_or(|id| id == t.id))
+                {
+                    self.finish();
+                }
+            }
+        }
+    }
+
+    /// Opens the pending take's files once its clock is running.
+    fn begin_if_running(&mut self) {
+        let Some(pending) = &self.pending else {
+            return;
+        };
+        if !pending.clock.has_started() || !pending.clock.until_running().is_zero() {
+            return;
+        }
+        let pending = self.pending.take().expect("checked above");
+
+        // Where song time zero falls in real time.
+        let now = Instant::now();
+        let song_time = pending.start_time + pending.clock.elapsed();
+        let since_zero = song_time.div_f64(pending.clock.rate().speed);
+        let origin = now.checked_sub(since_zero).unwrap_or(now);
+
+        let name = format!(
+            "{}-take{}",
+            file_stem(&pending.song),
+            next_take_number(&self.directory, &file_stem(&pending.song))
+        );
+        let mut files = Vec::with_capacity(self.channels.len());
+        for &channel in &self.channels {
+            let path = self.directory.join(format!(
+                "{}-ch{}.{}",
+                name,
+                channel,
+                self.format.extension()
+            ));
+            match ChannelFile::create(&path, self.format, self.sample_rate, self.bits_per_sample) {
+                Ok(file) => files.push(file),
+                Err(e) => {
+                    error!(path = %path.display(), err = %e, "Failed to start recording take");
+                    return;
+                }
+            }
+        }
+
+        // Anything left in the ring predates this take.
+        self.discard();
+        self.take = Some(Take {
+            id: pending.id,
+            name: name.clone(),
+            files,
+            origin,
+            written: 0,
+            dropped: 0,
+            lost: 0,
+            resync: true,
+            scratch: vec![Vec::new(); self.channels.len()],
+        });
+        *self.status.lock() = Some(name.clone());
+        self.capturing.store(true, Ordering::Relaxed);
+        info!(take = name, song = pending.song, "Recording take");
+    }
+
+    /// Writes out everything in the rings, moving on to newer rings as each
+    /// runs dry.
+    fn drain(&mut self) {
+        loop {
+            let Some(tap) = self.taps.front_mut() else {
+                return;
+            };
+            let mut out_of_step = false;
+            let mut failed = false;
+            while let Ok(block) = tap.blocks.pop() {
+                let len = block.frames as usize * self.channels.len();
+                let Ok(chunk) = tap.samples.read_chunk(len) else {
+                    // Samples are pushed before their header, so this can't
+                    // happen; if it does, the ring is out of step for good.
+                    out_of_step = true;
+                    break;
+                };
+                if let Some(take) = &mut self.take {
+                    let (first, second) = chunk.as_slices();
+                    let result = take.write_block(
+                        &block,
+                        first.iter().chain(second).copied(),
+                        self.channels.len(),
+                        self.sample_rate,
+                        self.bits_per_sample,
+                    );
+                    if let Err(e) = result {
+                        error!(take = take.name, err = %e, "Failed to write recording");
+                        failed = true;
+                    }
+                }
+                chunk.commit_all();
+                if failed {
+                    break;
+                }
+            }
+            if failed {
+                self.abandon();
+            }
+            if out_of_step {
+                error!("Input recording ring out of step; abandoning it");
+            } else if self.taps.len() < 2 {
+                // Only a ring that has been replaced is finished with.
+                return;
+            }
+            self.taps.pop_front();
+            if let Some(take) = &mut self.take {
+                take.resync = true;
+            }
+        }
+    }
+
+    /// Drops everything in the rings unread.
+    fn discard(&mut self) {
+        while self.taps.len() > 1 {
+            self.taps.pop_front();
+        }
+        if let Some(tap) = self.taps.front_mut() {
+            while tap.blocks.pop().is_ok() {}
+            let available = tap.samples.slots();
+            if let Ok(chunk) = tap.samples.read_chunk(available) {
+                chunk.commit_all();
+            }
+        }
+    }
+
+    /// Stops capturing and closes the take's files.
+    fn finish(&mut self) {
+        self.capturing.store(false, Ordering::Relaxed);
+        if self.take.is_none() {
+            return;
+        }
+        // What was captured before capturing stopped still belongs to it.
+        self.drain();
+        let Some(take) = self.take.take() else {
+            return;
+        };
+        *self.status.lock() = None;
+        let duration = Duration::from_secs_f64(take.written as f64 / self.sample_rate as f64);
+        let mut failed = false;
+        for file in take.files {
+            if let Err(e) = file.finalize() {
+                error!(take = take.name, err = %e, "Failed to finish recording file");
+                failed = true;
+            }
+        }
+        if take.lost > 0 {
+            warn!(
+                take = take.name,
+                lost = ?Duration::from_secs_f64(take.lost as f64 / self.sample_rate as f64),
+                "Recording lost audio; the gaps are filled with silence"
+            );
+        }
+        if !failed {
+            info!(take = take.name, duration = ?duration, "Recorded take");
+        }
+    }
+
+    /// Gives up on the take after a write failure, keeping what was written.
+    fn abandon(&mut self) {
+        self.capturing.store(false, Ordering::Relaxed);
+        *self.status.lock() = None;
+        if let Some(take) = self.take.take() {
+            for file in take.files {
+                let _ = file.finalize();
+            }
+        }
+    }
+}
+
+impl Take {
+    /// Writes one block, padding first for anything lost before it.
+    fn write_block(
+        &mut self,
+        block: &Block,
+        samples: impl Iterator<Item = f32>,
+        channels: usize,
+        sample_rate: u32,
+        bits_per_sample: u16,
+    ) -> Result<(), Box<dyn Error>> {
+        let frames = block.frames as u64;
+        let mut skip = 0u64;
+        if self.resync {
+            self.resync = false;
+            self.dropped = block.dropped_before;
+            // Where the block's first frame falls relative to song zero.
+            let period = Duration::from_secs_f64(1.0 / sample_rate as f64);
+            let first = block
+                .last_frame
+                .checked_sub(period * (block.frames - 1))
+                .unwrap_or(block.last_frame);
+            let position = match first.checked_duration_since(self.origin) {
+                Some(since) => (since.as_secs_f64() * sample_rate as f64).round() as i64,
+                None => -((self.origin - first).as_secs_f64() * sample_rate as f64).round() as i64,
+            };
+            let gap = position - self.written as i64;
+            if self.written == 0 && -gap >= frames as i64 {
+                // Captured entirely before the song began.
+                self.resync = true;
+                return Ok(());
+            }
+            if gap > 0 {
+                // Silence before the first frame is the song's lead-in, not
+                // loss, on the first block of a take.
+                if self.written > 0 {
+                    self.lost += gap as u64;
+                }
+                self.pad(gap as u64)?;
+            } else if self.written == 0 {
+                // Captured before the song began.
+                skip = (-gap) as u64;
+            }
+        } else if block.dropped_before > self.dropped {
+            let gap = block.dropped_before - self.dropped;
+            self.dropped = block.dropped_before;
+            self.lost += gap;
+            self.pad(gap)?;
+        }
+
+        for buffer in &mut self.scratch {
+            buffer.clear();
+        }
+        for (i, sample) in samples.enumerate() {
+            if ((i / channels) as u64) < skip {
+                continue;
+            }
+            self.scratch[i % channels].push(to_int(sample, bits_per_sample));
+        }
+        for (file, buffer) in self.files.iter_mut().zip(&self.scratch) {
+            file.write(buffer)?;
+        }
+        self.written += frames.saturating_sub(skip);
+        Ok(())
+    }
+
+    /// Writes `frames` frames of silence to every file.
+    fn pad(&mut self, mut frames: u64) -> Result<(), Box<dyn Error>> {
+        let silence = [0i32; SILENCE_CHUNK];
+        while frames > 0 {
+            let n = frames.min(SILENCE_CHUNK as u64) as usize;
+            for file in &mut self.files {
+                file.write(&silence[..n])?;
+            }
+            frames -= n as u64;
+            self.written += n as u64;
+        }
+        Ok(())
+    }
+}
+
+/// Converts a sample to an integer of the given depth.
+fn to_int(sample: f32, bits_per_sample: u16) -> i32 {
+    let scale = (1i64 << (bits_per_sample - 1)) as f32;
+    let max = (1i64 << (bits_per_sample - 1)) - 1;
+    ((sample.clamp(-1.0, 1.0) * scale).round() as i64).min(max) as i32
+}
+
+/// A song name made safe to use in a file name.
+fn file_stem(song: &str) -> String {
+    let stem: String = song
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, ' ' | '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let stem = stem.trim_matches('.').trim();
+    if stem.is_empty() {
+        "song".to_string()
+    } else {
+        stem.to_string()
+    }
+}
+
+/// The take number after the highest already in `directory` for this stem.
+fn next_take_number(directory: &Path, stem: &str) -> u32 {
+    let prefix = format!("{}-take", stem);
+    let highest = std::fs::read_dir(directory)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| {
+                    let name = entry.file_name().into_string().ok()?;
+                    let rest = name.strip_prefix(&prefix)?;
+                    let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+                    digits.parse::<u32>().ok()
+                })
+                .max()
+                .unwrap_or(0)
+        })
+        .unwrap_or(0);
+    highest + 1
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn recorder(dir: &Path, format: RecordFormat, channels: &[u16]) -> Recorder {
+        let config = RecordConfig::new(dir.to_str().unwrap(), None, format, 16);
+        Recorder::new(&config, channels, 4, 1000).unwrap()
+    }
+
+    /// Waits for the writer to begin the take.
+    fn wait_for_capture(recorder: &Recorder) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !recorder.capturing.load(Ordering::Relaxed) {
+            assert!(Instant::now() < deadline, "take never began");
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    /// An interleaved 4-channel buffer whose channel `c` holds `value * (c + 1)`.
+    fn buffer(frames: usize, value: f32) -> Vec<f32> {
+        (0..frames * 4)
+            .map(|i| value * ((i % 4) + 1) as f32)
+            .collect()
+    }
+
+    fn read_wav(path: &Path) -> Vec<i16> {
+        hound::WavReader::open(path)
+            .unwrap()
+            .samples::<i16>()
+            .map(|s| s.unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn takes_are_aligned_to_song_zero() {
+        let dir = tempfile::tempdir().unwrap();
+        let recorder = recorder(dir.path(), RecordFormat::Wav, &[2, 4]);
+        let mut tap = recorder.tap();
+        recorder.set_armed(true);
+
+        let clock = PlaybackClock::wall();
+        let zero = Instant::now();
+        clock.start();
+        let id = recorder.start_take("Song", clock, Duration::ZERO).unwrap();
+        wait_for_capture(&recorder);
+        assert_eq!(recorder.status().take.as_deref(), Some("Song-take1"));
+
+        // 100 frames at 1kHz whose first frame came 300ms into the song.
+        tap.capture(&buffer(100, 0.25), 4, zero + Duration::from_millis(399));
+        // 50 frames lost, then 10 more.
+        tap.dropped += 50;
+        tap.capture(&buffer(10, 0.5), 4, zero + Duration::from_millis(559));
+        recorder.finish_take(id);
+        drop(recorder);
+
+        let ch2 = read_wav(&dir.path().join("Song-take1-ch2.wav"));
+        let ch4 = read_wav(&dir.path().join("Song-take1-ch4.wav"));
+        let first = ch2.iter().position(|&s| s != 0).unwrap();
+        assert!((298..=302).contains(&first), "first frame at {}", first);
+        assert_eq!(ch2.len(), first + 160);
+        assert_eq!(ch2[first], to_int(0.5, 16) as i16);
+        assert_eq!(ch4[first], to_int(1.0, 16) as i16);
+        assert!(ch2[first + 100..first + 150].iter().all(|&s| s == 0));
+        assert_eq!(ch2[first + 150], to_int(1.0, 16) as i16);
+    }
+
+    #[test]
+    fn nothing_is_recorded_unless_armed() {
+        let dir = tempfile::tempdir().unwrap();
+        let recorder = recorder(dir.path(), RecordFormat::Wav, &[1]);
+        let clock = PlaybackClock::wall();
+        assert_eq!(recorder.start_take("Song", clock, Duration::ZERO), None);
+        assert_eq!(
+            recorder.status(),
+            RecordStatus {
+                armed: false,
+                take: None
+            }
+        );
+        drop(recorder);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn disarming_finishes_the_take() {
+        let dir = tempfile::tempdir().unwrap();
+        let recorder = recorder(dir.path(), RecordFormat::Flac, &[1]);
+        let mut tap = recorder.tap();
+        recorder.set_armed(true);
+        let clock = PlaybackClock::wall();
+        clock.start();
+        recorder.start_take("Song", clock, Duration::ZERO).unwrap();
+        wait_for_capture(&recorder);
+        tap.capture(&buffer(64, 0.1), 4, Instant::now());
+
+        recorder.set_armed(false);
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while recorder.status().take.is_some() {
+            assert!(Instant::now() < deadline, "take never finished");
+            thread::sleep(Duration::from_millis(1));
+        }
+        assert!(!recorder.capturing.load(Ordering::Relaxed));
+        assert!(dir.path().join("Song-take1-ch1.flac").exists());
+    }
+
+    #[test]
+    fn a_later_take_is_not_finished_by_an_earlier_id() {
+        let dir = tempfile::tempdir().unwrap();
+        let recorder = recorder(dir.path(), RecordFormat::Wav, &[1]);
+        let _tap = recorder.tap();
+        recorder.set_armed(true);
+        let first_clock = PlaybackClock::wall();
+        first_clock.start();
+        let first = recorder
+            .start_take("Song", first_clock, Duration::ZERO)
+            .unwrap();
+        wait_for_capture(&recorder);
+        let second_clock = PlaybackClock::wall();
+        second_clock.start();
+        recorder
+            .start_take("Song", second_clock, Duration::ZERO)
+            .unwrap();
+        recorder.finish_take(first);
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while recorder.status().take.as_deref() != Some("Song-take2") {
+            assert!(Instant::now() < deadline, "second take never began");
+            thread::sleep(Duration::from_millis(1));
+        }
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(recorder.status().take.as_deref(), Some("Song-take2"));
+    }
+
+    #[test]
+    fn take_numbers_follow_existing_files() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(next_take_number(dir.path(), "Song"), 1);
+        std::fs::write(dir.path().join("Song-take1-ch1.wav"), b"").unwrap();
+        std::fs::write(dir.path().join("Song-take7-ch1.wav"), b"").unwrap();
+        std::fs::write(dir.path().join("Other-take9-ch1.wav"), b"").unwrap();
+        assert_eq!(next_take_number(dir.path(), "Song"), 8);
+    }
+
+    #[test]
+    fn song_names_are_made_file_safe() {
+        assert_eq!(file_stem("Sweet Child O' Mine"), "Sweet Child O_ Mine");
+        assert_eq!(file_stem("AC/DC: Thunder"), "AC_DC_ Thunder");
+        assert_eq!(file_stem("../"), "_");
+        assert_eq!(file_stem("  "), "song");
+    }
+
+    #[test]
+    fn samples_convert_to_the_file_depth() {
+        assert_eq!(to_int(1.0, 16), i16::MAX as i32);
+        assert_eq!(to_int(-1.0, 16), i16::MIN as i32);
+        assert_eq!(to_int(0.5, 24), 1 << 22);
+        assert_eq!(to_int(2.0, 24), (1 << 23) - 1);
+    }
+
+    #[test]
+    fn rejects_unsupported_depths_and_missing_channels() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = RecordConfig::new(dir.path().to_str().unwrap(), None, RecordFormat::Wav, 32);
+        assert!(Recorder::new(&config, &[1], 2, 48_000).is_err());
+        let config = RecordConfig::new(dir.path().to_str().unwrap(), None, RecordFormat::Wav, 24);
+        assert!(Recorder::new(&config, &[5], 2, 48_000).is_err());
+    }
+}
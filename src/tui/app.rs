@@ -49,6 +49,7 @@ pub struct App {
     pub current_song_duration: Duration,
     pub current_song_tracks: Vec<String>,
     pub is_playing: bool,
+    pub is_paused: bool,
     pub elapsed: Option<Duration>,
 
     // Lighting state
@@ -84,6 +85,7 @@ impl App {
                 .map(|s| s.tracks().iter().map(|t| t.name().to_string()).collect())
                 .unwrap_or_default(),
             is_playing: false,
+            is_paused: false,
             elapsed: None,
             fixture_colors: Vec::new(),
             active_effects: Vec::new(),
@@ -115,6 +117,7 @@ impl App {
 
         // Update playback state
         self.is_playing = self.player.is_playing().await;
+        self.is_paused = self.player.is_paused();
         self.elapsed = self.player.elapsed().await.unwrap_or(None);
 
         // Update lighting state from the shared watch channel
@@ -144,6 +147,10 @@ impl App {
                 }
                 Action::None
             }
+            KeyCode::Char('k') => {
+                self.player.toggle_pause().await;
+                Action::None
+            }
             KeyCode::Right | KeyCode::Char('n') => {
                 self.player.next().await;
                 Action::None
@@ -421,6 +428,67 @@ mod tests {
             assert!(matches!(action, Action::None));
         }
 
+        #[tokio::test(flavor = "multi_thread")]
+        async fn k_key_toggles_pause() {
+            let songs = crate::songs::get_all_songs(std::path::Path::new("assets/songs")).unwrap();
+            let pl = playlist::Playlist::new(
+                "playlist",
+                &config::Playlist::deserialize(std::path::Path::new("assets/playlist.yaml"))
+                    .unwrap(),
+                songs.clone(),
+            )
+            .unwrap();
+            let mut playlists = HashMap::new();
+            playlists.insert(
+                "all_songs".to_string(),
+                playlist::from_songs(songs.clone()).unwrap(),
+            );
+            playlists.insert("playlist".to_string(), pl);
+            let player = Player::new(
+                playlists,
+                "playlist".to_string(),
+                &config::Player::new(
+                    vec![],
+                    Some(config::Audio::new("mock-device")),
+                    None,
+                    None,
+                    HashMap::new(),
+                    "assets/songs",
+                ),
+                None,
+            )
+            .unwrap();
+            player.await_hardware_ready().await;
+            let (_state_tx, state_rx) = watch::channel(Arc::new(StateSnapshot::default()));
+            let mut app = App::new(player, state_rx);
+
+            app.player.play().await.unwrap();
+            crate::testutil::eventually_async(
+                || {
+                    let player = app.player.clone();
+                    async move { player.elapsed().await.ok().flatten().is_some() }
+                },
+                "Playback never started",
+            )
+            .await;
+
+            let action = app.handle_key_event(key(KeyCode::Char('k'))).await;
+            assert!(matches!(action, Action::None));
+            assert!(app.player.is_paused());
+            assert!(app.player.compute_transport_snapshot().await.paused);
+            app.tick().await;
+            assert!(app.is_playing);
+            assert!(app.is_paused);
+
+            app.handle_key_event(key(KeyCode::Char('k'))).await;
+            assert!(!app.player.is_paused());
+            assert!(!app.player.compute_transport_snapshot().await.paused);
+            app.tick().await;
+            assert!(!app.is_paused);
+
+            app.player.stop().await;
+        }
+
         #[tokio::test]
         async fn right_arrow_next() {
             let mut app = test_app(&["Song A", "Song B"]);
@@ -480,6 +548,7 @@ mod tests {
             let snapshot = Arc::new(StateSnapshot {
                 fixtures: vec![make_fixture("spot", &[("red", 255)])],
                 active_effects: vec!["chase".to_string()],
+                meters: None,
             });
             let (_tx, state_rx) = watch::channel(snapshot);
             let mut app = App::new(player, state_rx);
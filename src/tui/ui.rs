@@ -134,15 +134,17 @@ fn draw_now_playing(frame: &mut Frame, app: &App, area: Rect) {
         return;
     }
 
-    let play_indicator = if app.is_playing { "▶" } else { "■" };
+    let (play_indicator, indicator_color) = if app.is_paused {
+        ("⏸", Color::Yellow)
+    } else if app.is_playing {
+        ("▶", Color::Green)
+    } else {
+        ("■", Color::Red)
+    };
     let title_line = Line::from(vec![
         Span::styled(
             format!(" {} ", play_indicator),
-            Style::default().fg(if app.is_playing {
-                Color::Green
-            } else {
-                Color::Red
-            }),
+            Style::default().fg(indicator_color),
         ),
         Span::raw(&app.current_song_name),
     ]);
@@ -287,6 +289,8 @@ fn draw_key_hints(frame: &mut Frame, area: Rect) {
     let hints = Line::from(vec![
         Span::styled(" Space", Style::default().fg(Color::Yellow)),
         Span::raw("=play/stop  "),
+        Span::styled("k", Style::default().fg(Color::Yellow)),
+        Span::raw("=pause/resume  "),
         Span::styled("\u{2190}/\u{2192}", Style::default().fg(Color::Yellow)),
         Span::raw("=prev/next  "),
         Span::styled("a", Style::default().fg(Color::Yellow)),
@@ -489,6 +493,19 @@ mod tests {
             terminal.draw(|frame| draw(frame, &app)).unwrap();
         }
 
+        #[test]
+        fn draw_paused() {
+            let mut app = make_app();
+            app.is_playing = true;
+            app.is_paused = true;
+            app.elapsed = Some(Duration::from_secs(30));
+            app.current_song_duration = Duration::from_secs(180);
+
+            let backend = TestBackend::new(80, 24);
+            let mut terminal = Terminal::new(backend).unwrap();
+            terminal.draw(|frame| draw(frame, &app)).unwrap();
+        }
+
         #[test]
         fn draw_with_log_lines() {
             let mut app = make_app();
@@ -161,6 +161,58 @@ pub(super) async fn import_gdtf(
     )
 }
 
+/// Query parameters for the MVR import endpoint. `origin_fixture` names the
+/// scene fixture whose spot on the deck becomes the stage origin; `origin`
+/// gives the point directly as "x,y,z" in meters.
+#[derive(serde::Deserialize)]
+pub(super) struct MvrImportQuery {
+    name: Option<String>,
+    origin_fixture: Option<String>,
+    origin: Option<String>,
+}
+
+/// POST /api/lighting/mvr/import?name=...&origin_fixture=... — imports an
+/// uploaded MVR through the shared importer: a fixture type per referenced
+/// GDTF mode and a seeded .venue file. Returns the import report.
+pub(super) async fn import_mvr(
+    State(state): State<WebUiState>,
+    Query(query): Query<MvrImportQuery>,
+    mut multipart: axum::extract::Multipart,
+) -> impl IntoResponse {
+    let (filename, bytes) = first_multipart_file(&mut multipart).await?;
+    let project = project_root(&state.config_path)?;
+    let report = super::helpers::spawn_blocking_io("import mvr", move || {
+        let import = || {
+            let origin = match (query.origin_fixture, query.origin.as_deref()) {
+                (Some(fixture), _) => Some(lighting::import::MvrOrigin::Fixture(fixture)),
+                (None, Some(point)) => Some(lighting::import::MvrOrigin::Point(
+                    lighting::import::parse_origin_point(point)?,
+                )),
+                (None, None) => None,
+            };
+            lighting::import::import_mvr_bytes(
+                &bytes,
+                &filename,
+                query.name.as_deref(),
+                origin.as_ref(),
+                &project,
+                DEFAULT_FIXTURE_TYPES_DIR,
+                DEFAULT_VENUES_DIR,
+            )
+        };
+        Ok::<_, String>(import().map_err(|e| e.to_string()))
+    })
+    .await?
+    .map_err(|e| (StatusCode::BAD_REQUEST, Json(json!({"error": e}))).into_response())?;
+    Ok::<_, axum::response::Response>(
+        (
+            StatusCode::OK,
+            Json(serde_json::to_value(&report).unwrap_or_default()),
+        )
+            .into_response(),
+    )
+}
+
 /// GET /api/lighting — lists available .light files from the songs directory.
 pub(super) async fn get_lighting_files(State(state): State<WebUiState>) -> impl IntoResponse {
     let songs_path = state.songs_path.clone();
@@ -469,17 +521,16 @@ pub(super) async fn get_fixture_types(
     }
     let all = super::helpers::spawn_blocking_io("load fixture types", move || {
         let mut all = std::collections::HashMap::new();
-        let errors =
-            load_light_files_from_dir(&dir, |content| match lighting::parser::parse_fixture_types(
-                content,
-            ) {
+        let errors = load_light_files_from_dir(&dir, &["light"], |content| {
+            match lighting::parser::parse_fixture_types(content) {
                 Ok(types) => {
                     all.extend(types);
                     Ok(())
                 }
                 Err(e) => Err(e),
-            })
-            .map_err(|e| e.to_string())?;
+            }
+        })
+        .map_err(|e| e.to_string())?;
         Ok::<_, String>((all, errors))
     })
     .await?;
@@ -777,17 +828,16 @@ pub(super) async fn get_venues(
     }
     let all = super::helpers::spawn_blocking_io("load venues", move || {
         let mut all = std::collections::HashMap::new();
-        let errors =
-            load_light_files_from_dir(&dir, |content| {
-                match lighting::parser::parse_venues(content) {
-                    Ok(venues) => {
-                        all.extend(venues);
-                        Ok(())
-                    }
-                    Err(e) => Err(e),
+        let errors = load_light_files_from_dir(&dir, &["light", "venue"], |content| {
+            match lighting::parser::parse_venues(content) {
+                Ok(venues) => {
+                    all.extend(venues);
+                    Ok(())
                 }
-            })
-            .map_err(|e| e.to_string())?;
+                Err(e) => Err(e),
+            }
+        })
+        .map_err(|e| e.to_string())?;
         Ok::<_, String>((all, errors))
     })
     .await?;
@@ -930,16 +980,22 @@ pub(super) async fn delete_venue(
 // Lighting helpers
 // ---------------------------------------------------------------------------
 
-/// Reads all .light files from a directory, calling the processor for each.
+/// Reads all lighting DSL files with one of the given extensions from a
+/// directory, calling the processor for each.
 fn load_light_files_from_dir(
     dir: &std::path::Path,
+    extensions: &[&str],
     mut processor: impl FnMut(&str) -> Result<(), Box<dyn std::error::Error>>,
 ) -> Result<Vec<FileError>, Box<dyn std::error::Error>> {
     let mut errors = Vec::new();
     for entry in std::fs::read_dir(dir)? {
         let entry = entry?;
         let path = entry.path();
-        if path.is_file() && path.extension().and_then(|e| e.to_str()) == Some("light") {
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default();
+        if path.is_file() && extensions.contains(&extension) {
             let content = std::fs::read_to_string(&path)?;
             // Per-file, not fatal: a directory is a set of independent files,
             // and one that no longer parses must not hide the rest. The caller
@@ -1980,7 +2036,7 @@ show "test" {
         std::fs::write(dir.path().join("b.txt"), "not a light file").unwrap();
 
         let mut count = 0;
-        load_light_files_from_dir(dir.path(), |_content| {
+        load_light_files_from_dir(dir.path(), &["light"], |_content| {
             count += 1;
             Ok(())
         })
@@ -1992,7 +2048,7 @@ show "test" {
     fn load_light_files_from_dir_empty() {
         let dir = tempfile::tempdir().unwrap();
         let mut count = 0;
-        load_light_files_from_dir(dir.path(), |_content| {
+        load_light_files_from_dir(dir.path(), &["light"], |_content| {
             count += 1;
             Ok(())
         })
@@ -2272,6 +2328,55 @@ show "test" {
         );
     }
 
+    #[tokio::test]
+    async fn import_mvr_seeds_a_venue_through_the_shared_importer() {
+        let (state, dir) = test_state();
+        let app = router().with_state(state);
+        let (content_type, body) =
+            multipart_body("club.mvr", &crate::lighting::mvr::synthetic_mvr());
+
+        let response = app
+            .clone()
+            .oneshot(
+                http::Request::builder()
+                    .method("POST")
+                    .uri("/lighting/mvr/import?origin_fixture=Wash%201")
+                    .header("content-type", content_type.clone())
+                    .body(Body::from(body.clone()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let parsed: serde_json::Value =
+            serde_json::from_str(&response_body(response).await).unwrap();
+        assert_eq!(parsed["venue_file"], "lighting/venues/club.venue");
+        assert_eq!(parsed["fixtures"].as_array().unwrap().len(), 2);
+        assert_eq!(parsed["todos"][0]["fixture"], "Spare");
+        assert_eq!(parsed["fixture_types"][1]["mode_match"], "normalized");
+        assert!(dir.path().join("lighting/venues/club.venue").exists());
+
+        // Importing over the seeded venue is the caller's error.
+        let response = app
+            .oneshot(
+                http::Request::builder()
+                    .method("POST")
+                    .uri("/lighting/mvr/import?origin=0,0,0")
+                    .header("content-type", content_type)
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let parsed: serde_json::Value =
+            serde_json::from_str(&response_body(response).await).unwrap();
+        assert!(
+            parsed["error"].as_str().unwrap().contains("already exists"),
+            "{parsed}"
+        );
+    }
+
     #[tokio::test]
     async fn put_fixture_type_json() {
         let (state, _dir) = test_state();
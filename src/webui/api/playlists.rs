@@ -82,6 +82,7 @@ pub(super) async fn get_playlist_by_name(
         Json(json!({
             "name": name,
             "songs": pl.songs(),
+            "auto_advance": pl.auto_advance(),
             "available_songs": all_songs,
         })),
     )
@@ -91,6 +92,9 @@ pub(super) async fn get_playlist_by_name(
 #[derive(serde::Deserialize)]
 pub(super) struct PlaylistBody {
     songs: Vec<String>,
+    /// Left as it is on the existing playlist when absent.
+    #[serde(default)]
+    auto_advance: Option<config::AutoAdvance>,
 }
 
 /// PUT /api/playlists/:name — create or update a playlist.
@@ -103,8 +107,24 @@ pub(super) async fn put_playlist_by_name(
     let playlists_dir =
         require_configured_dir(&state.playlists_dir, "playlists", StatusCode::NOT_FOUND)?;
 
+    let auto_advance = match body.auto_advance {
+        Some(auto_advance) => Some(auto_advance),
+        None => state
+            .player
+            .playlists_snapshot()
+            .get(&name)
+            .and_then(|pl| pl.auto_advance().cloned()),
+    };
+    if let Some(Err(errors)) = auto_advance.as_ref().map(|a| a.validate()) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(json!({"error": format!("Invalid auto_advance: {}", errors.join("; "))})),
+        )
+            .into_response());
+    }
+
     // Write the playlist YAML file.
-    let playlist_config = config::Playlist::new(&body.songs);
+    let playlist_config = config::Playlist::new(&body.songs).with_auto_advance(auto_advance);
     let yaml = match crate::util::to_yaml_string(&playlist_config) {
         Ok(y) => y,
         Err(e) => {
@@ -978,7 +978,8 @@ fn remove_song_from_playlists(
         }
         // Rebuild without the deleted song
         let filtered: Vec<String> = songs.iter().filter(|s| *s != song_name).cloned().collect();
-        let updated = config::Playlist::new(&filtered);
+        let updated = config::Playlist::new(&filtered)
+            .with_auto_advance(playlist_config.auto_advance().cloned());
         if let Ok(yaml) = crate::util::to_yaml_string(&updated) {
             let _ = std::fs::write(path, yaml);
         }
@@ -158,6 +158,10 @@ pub async fn playback_poller(player: Arc<Player>, tx: broadcast::Sender<String>)
                 .get_track_mutes()
                 .map(|mutes| mutes.into_iter().collect())
                 .unwrap_or_default();
+            let track_eq: std::collections::HashMap<String, Vec<crate::config::EqBand>> = player
+                .get_track_eq()
+                .map(|eq| eq.into_iter().collect())
+                .unwrap_or_default();
             // Output tracks include virtual tracks (e.g. the metronome)
             // so they show up in the gains mixer like any other track.
             let tracks: Vec<serde_json::Value> = current_song
@@ -174,6 +178,19 @@ pub async fn playback_poller(player: Arc<Player>, tx: broadcast::Sender<String>)
                         "output_channels": output_channels,
                         "gain_db": track_gains.get(name).copied().unwrap_or(0.0),
                         "muted": track_mutes.get(name).copied().unwrap_or(false),
+                        "eq": track_eq.get(name).map(|bands| {
+                            bands
+                                .iter()
+                                .map(|band| {
+                                    json!({
+                                        "type": band.kind().as_str(),
+                                        "freq_hz": band.freq(),
+                                        "gain_db": band.gain(),
+                                        "q": band.q(),
+                                    })
+                                })
+                                .collect::<Vec<_>>()
+                        }).unwrap_or_default(),
                     })
                 })
                 .collect();
@@ -299,6 +316,33 @@ pub async fn playback_poller(player: Arc<Player>, tx: broadcast::Sender<String>)
             })
         });
 
+        // Monitor mixes are per profile, not per song, so they show even
+        // with nothing loaded.
+        let mixes: Vec<serde_json::Value> = player
+            .get_monitor_mixes()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|mix| {
+                let tracks: Vec<serde_json::Value> = mix
+                    .tracks
+                    .into_iter()
+                    .map(|(name, gain_db, muted)| {
+                        json!({
+                            "name": name,
+                            "gain_db": gain_db,
+                            "muted": muted,
+                        })
+                    })
+                    .collect();
+                json!({
+                    "name": mix.name,
+                    "channels": mix.channels,
+                    "tracks": tracks,
+                })
+            })
+            .collect();
+
+        let playback_rate = player.playback_rate();
         let msg = json!({
             "type": "playback",
             "is_playing": is_playing,
@@ -309,6 +353,7 @@ pub async fn playback_poller(player: Arc<Player>, tx: broadcast::Sender<String>)
             "playlist_position": playlist_position,
             "playlist_songs": playlist_songs,
             "tracks": tracks,
+            "mixes": mixes,
             "available_playlists": available_playlists,
             "persisted_playlist_name": persisted_playlist_name,
             "locked": player.is_locked(),
@@ -320,18 +365,24 @@ pub async fn playback_poller(player: Arc<Player>, tx: broadcast::Sender<String>)
             "pending_start_ms": player.pending_start().map(|d| d.as_millis() as u64),
             "pilot_hints": pilot_hints,
             "tempo": tempo,
+            "playback_rate": {
+                "speed": playback_rate.speed,
+                "semitones": playback_rate.semitones,
+            },
         });
 
         let _ = tx.send(msg.to_string());
     }
 }
 
-/// Watches the shared state snapshot (fixtures + active effects) and broadcasts changes.
+/// Watches the shared state snapshot and broadcasts changes: fixtures and
+/// active effects as a `state` message, level meters as a `meters` message.
 #[tracing::instrument(skip_all, name = "state_poller")]
 pub async fn state_poller(
     mut state_rx: watch::Receiver<Arc<crate::state::StateSnapshot>>,
     tx: broadcast::Sender<String>,
 ) {
+    let mut last: Option<Arc<crate::state::StateSnapshot>> = None;
     loop {
         // Wait for the state to change
         if state_rx.changed().await.is_err() {
@@ -343,6 +394,26 @@ pub async fn state_poller(
         }
 
         let snapshot = state_rx.borrow_and_update().clone();
+        let previous = last.replace(snapshot.clone());
+
+        if let Some(ref meters) = snapshot.meters {
+            if previous
+                .as_ref()
+                .is_none_or(|p| p.meters != snapshot.meters)
+            {
+                let _ = tx.send(meters_message(meters).to_string());
+            }
+        }
+        // A meter reading on its own doesn't resend the lighting state; a
+        // lighting tick does, changed or not, so late joiners catch up.
+        let meters_only = previous.is_some_and(|p| {
+            p.meters != snapshot.meters
+                && p.fixtures == snapshot.fixtures
+                && p.active_effects == snapshot.active_effects
+        });
+        if meters_only {
+            continue;
+        }
 
         let fixtures: serde_json::Map<String, serde_json::Value> = snapshot
             .fixtures
@@ -367,6 +438,42 @@ pub async fn state_poller(
     }
 }
 
+/// Builds the websocket message for a level meter reading. Channels are
+/// numbered from 1, as in track mappings.
+fn meters_message(meters: &crate::audio::meters::MeterSnapshot) -> serde_json::Value {
+    let level = |level: &crate::audio::meters::MeterLevel| {
+        json!({
+            "peak_db": level.peak_db,
+            "rms_db": level.rms_db,
+            "clips": level.clips,
+        })
+    };
+    let channels: Vec<serde_json::Value> = meters
+        .channels
+        .iter()
+        .enumerate()
+        .map(|(index, l)| {
+            let mut value = level(l);
+            value["channel"] = json!(index + 1);
+            value
+        })
+        .collect();
+    let tracks: Vec<serde_json::Value> = meters
+        .tracks
+        .iter()
+        .map(|(name, l)| {
+            let mut value = level(l);
+            value["name"] = json!(name);
+            value
+        })
+        .collect();
+    json!({
+        "type": "meters",
+        "channels": channels,
+        "tracks": tracks,
+    })
+}
+
 /// Polls the log ring buffer at ~2Hz and broadcasts log lines.
 #[tracing::instrument(skip_all, name = "log_poller")]
 pub async fn log_poller(tx: broadcast::Sender<String>) {
@@ -1159,6 +1266,7 @@ metronome: {}
                 },
             }],
             active_effects: vec!["chase".to_string()],
+            meters: None,
         });
         state_tx.send(snapshot).unwrap();
 
@@ -1176,6 +1284,65 @@ metronome: {}
         handle.abort();
     }
 
+    #[tokio::test]
+    async fn state_poller_sends_meters_without_repeating_state() {
+        use crate::audio::meters::{MeterLevel, MeterSnapshot};
+
+        let initial = Arc::new(crate::state::StateSnapshot::default());
+        let (state_tx, state_rx) = watch::channel(initial);
+        let (tx, mut rx) = broadcast::channel(16);
+        let handle = tokio::spawn(state_poller(state_rx, tx));
+
+        let reading = |peak_db| MeterSnapshot {
+            channels: vec![MeterLevel {
+                peak_db,
+                rms_db: -20.0,
+                clips: 3,
+            }],
+            tracks: vec![(
+                "click".to_string(),
+                MeterLevel {
+                    peak_db,
+                    rms_db: -30.0,
+                    clips: 0,
+                },
+            )],
+        };
+        let next = |message: &mut broadcast::Receiver<String>| {
+            let message = message.try_recv().expect("message");
+            serde_json::from_str::<serde_json::Value>(&message).unwrap()
+        };
+
+        state_tx
+            .send(Arc::new(crate::state::StateSnapshot {
+                meters: Some(reading(-6.0)),
+                ..Default::default()
+            }))
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let meters = next(&mut rx);
+        assert_eq!(meters["type"], "meters");
+        assert_eq!(meters["channels"][0]["channel"], 1);
+        assert_eq!(meters["channels"][0]["peak_db"], -6.0);
+        assert_eq!(meters["channels"][0]["clips"], 3);
+        assert_eq!(meters["tracks"][0]["name"], "click");
+        assert_eq!(meters["tracks"][0]["rms_db"], -30.0);
+        assert_eq!(next(&mut rx)["type"], "state");
+
+        // A new reading alone sends only meters.
+        state_tx
+            .send(Arc::new(crate::state::StateSnapshot {
+                meters: Some(reading(-12.0)),
+                ..Default::default()
+            }))
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(next(&mut rx)["type"], "meters");
+        assert!(rx.try_recv().is_err());
+
+        handle.abort();
+    }
+
     #[tokio::test]
     async fn state_poller_exits_when_sender_dropped() {
         let initial = Arc::new(crate::state::StateSnapshot::default());
@@ -1521,6 +1688,7 @@ metronome: {}
                 channels: std::collections::HashMap::new(),
             }],
             active_effects: vec![],
+            meters: None,
         });
         state_tx.send(snapshot).unwrap();
 
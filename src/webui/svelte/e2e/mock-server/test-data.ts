@@ -175,6 +175,7 @@ export const STATUS = {
       callbacks: 4821,
       recoveries: 0,
       underruns: 0,
+      limiter_engagements: 0,
       last_error: null,
     },
     midi: { status: "not_connected", name: null },
@@ -297,12 +298,14 @@ export const PLAYBACK_STATE = {
     { name: "bass", output_channels: [4, 5], gain_db: -60 },
   ],
   available_playlists: ["all_songs", "setlist"],
+  mixes: [],
   persisted_playlist_name: "setlist",
   locked: false,
   beat_grid: null,
   looping: false,
   available_sections: [],
   active_section: null,
+  playback_rate: { speed: 1, semitones: 0 },
 };
 
 export const METADATA_STATE = {
@@ -173,6 +173,7 @@ test.describe("Status Page", () => {
         callbacks: 1477,
         recoveries: 0,
         underruns: 0,
+        limiter_engagements: 0,
         last_error: null,
       };
       await route.fulfill({ json: body });
@@ -270,6 +271,7 @@ test.describe("Status Page", () => {
         callbacks: 1477,
         recoveries: 2,
         underruns: 0,
+        limiter_engagements: 0,
         last_error: "ALSA function 'snd_pcm_open' failed",
       };
       await route.fulfill({ json: body });
@@ -80,3 +80,82 @@ export function sendTrackGainThrottled(track: string, gainDb: number): void {
     }, THROTTLE_MS),
   );
 }
+
+/** Immediately sends a track's gain within one monitor mix. */
+export async function sendMixTrackGain(
+  mix: string,
+  track: string,
+  gainDb: number,
+): Promise<void> {
+  const key = `${mix}/${track}`;
+  const timer = timers.get(key);
+  if (timer !== undefined) {
+    clearTimeout(timer);
+    timers.delete(key);
+  }
+  pending.delete(key);
+  try {
+    await playerClient.setMixTrackGain({ mix, track, gainDb });
+  } catch (e) {
+    console.error(`Failed to set gain for "${track}" in mix "${mix}":`, e);
+  }
+}
+
+/** Mutes or unmutes a track within one monitor mix. */
+export async function sendMixTrackMute(
+  mix: string,
+  track: string,
+  muted: boolean,
+): Promise<void> {
+  try {
+    await playerClient.setMixTrackMute({ mix, track, muted });
+  } catch (e) {
+    console.error(`Failed to set mute for "${track}" in mix "${mix}":`, e);
+  }
+}
+
+/** Trailing-throttled variant of `sendMixTrackGain` for slider drags. */
+export function sendMixTrackGainThrottled(
+  mix: string,
+  track: string,
+  gainDb: number,
+): void {
+  const key = `${mix}/${track}`;
+  pending.set(key, gainDb);
+  if (timers.has(key)) return;
+  timers.set(
+    key,
+    setTimeout(() => {
+      timers.delete(key);
+      const value = pending.get(key);
+      pending.delete(key);
+      if (value !== undefined) {
+        void sendMixTrackGain(mix, track, value);
+      }
+    }, THROTTLE_MS),
+  );
+}
+
+/** One live-adjustable EQ band parameter. */
+export interface EqBandChange {
+  frequencyHz?: number;
+  gainDb?: number;
+  q?: number;
+}
+
+/**
+ * Sends a change to one of a track's EQ bands (numbered from 1). Omitted
+ * parameters keep their current values; the player ramps to the new
+ * response, so steppers can send on every tick.
+ */
+export async function sendTrackEqBand(
+  track: string,
+  band: number,
+  change: EqBandChange,
+): Promise<void> {
+  try {
+    await playerClient.setTrackEqBand({ track, band, ...change });
+  } catch (e) {
+    console.error(`Failed to set EQ band ${band} for track "${track}":`, e);
+  }
+}
@@ -25,6 +25,28 @@ export interface TrackInfo {
   /** Whether the track is muted (independently of the gain); absent on
    * older backends (treat as false). */
   muted?: boolean;
+  /** The track's EQ bands in chain order; absent on older backends. */
+  eq?: EqBandInfo[];
+}
+
+export interface EqBandInfo {
+  type: "high_pass" | "low_shelf" | "high_shelf" | "peak";
+  freq_hz: number;
+  gain_db: number;
+  q: number;
+}
+
+export interface MixTrack {
+  name: string;
+  gain_db: number;
+  muted: boolean;
+}
+
+export interface MonitorMix {
+  name: string;
+  /** Output channels, numbered from 1. */
+  channels: number[];
+  tracks: MixTrack[];
 }
 
 export interface BeatGrid {
@@ -75,6 +97,13 @@ export interface ReactiveLoopState {
   section_name?: string;
 }
 
+export interface PlaybackRate {
+  /** Multiple of normal speed; pitch is unaffected. */
+  speed: number;
+  /** Transposition in semitones; speed is unaffected. */
+  semitones: number;
+}
+
 export interface PlaybackState {
   is_playing: boolean;
   elapsed_ms: number;
@@ -84,6 +113,8 @@ export interface PlaybackState {
   playlist_position: number;
   playlist_songs: string[];
   tracks: TrackInfo[];
+  /** Monitor mixes from the active profile. */
+  mixes: MonitorMix[];
   available_playlists: string[];
   persisted_playlist_name: string;
   locked: boolean;
@@ -99,6 +130,8 @@ export interface PlaybackState {
   pilot_hints: PilotHintInfo[];
   /** Current tempo/meter from the song's tempo map, sampled at the playhead. */
   tempo: { bpm: number; time_signature: [number, number] } | null;
+  /** Rehearsal speed and transposition songs play at. */
+  playback_rate: PlaybackRate;
   /** performance.now() when this frame was received; lets consumers
    *  extrapolate elapsed_ms smoothly between the 5Hz frames. */
   received_at: number;
@@ -124,6 +157,19 @@ export interface WaveformData {
   tracks: { name: string; peaks: number[] }[];
 }
 
+export interface MeterLevel {
+  peak_db: number;
+  rms_db: number;
+  /** Samples past full scale since the meters were built. */
+  clips: number;
+}
+
+export interface MeterReading {
+  /** Output channels, numbered from 1. */
+  channels: (MeterLevel & { channel: number })[];
+  tracks: (MeterLevel & { name: string })[];
+}
+
 export interface ReloadEvent {
   status: "ok" | "error";
   error?: string;
@@ -142,6 +188,7 @@ export const playbackStore = writable<PlaybackState>({
   playlist_position: 0,
   playlist_songs: [],
   tracks: [],
+  mixes: [],
   available_playlists: [],
   persisted_playlist_name: "",
   locked: true,
@@ -153,6 +200,7 @@ export const playbackStore = writable<PlaybackState>({
   reactive_loop_state: null,
   pilot_hints: [],
   tempo: null,
+  playback_rate: { speed: 1, semitones: 0 },
   received_at: 0,
 });
 
@@ -172,6 +220,8 @@ export const waveformStore = writable<WaveformData>({
 
 export const reloadStore = writable<ReloadEvent | null>(null);
 
+export const meterStore = writable<MeterReading>({ channels: [], tracks: [] });
+
 // --- Wire up ---
 
 onConnectionStatus((connected) => {
@@ -189,6 +239,7 @@ on("playback", (msg) => {
     playlist_position: m.playlist_position,
     playlist_songs: m.playlist_songs,
     tracks: m.tracks ?? [],
+    mixes: m.mixes ?? [],
     available_playlists: m.available_playlists ?? [],
     persisted_playlist_name: m.persisted_playlist_name ?? "",
     locked: m.locked ?? true,
@@ -200,6 +251,7 @@ on("playback", (msg) => {
     reactive_loop_state: m.reactive_loop_state ?? null,
     pilot_hints: m.pilot_hints ?? [],
     tempo: m.tempo ?? null,
+    playback_rate: m.playback_rate ?? { speed: 1, semitones: 0 },
     received_at: performance.now(),
   });
 });
@@ -214,6 +266,11 @@ on("state", (msg) => {
   effectsStore.set(m.active_effects ?? []);
 });
 
+on("meters", (msg) => {
+  const m = msg as MeterReading & { type: string };
+  meterStore.set({ channels: m.channels ?? [], tracks: m.tracks ?? [] });
+});
+
 on("metadata", (msg) => {
   const m = msg as {
     type: string;